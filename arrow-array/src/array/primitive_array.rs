@@ -391,6 +391,75 @@ impl<T: ArrowPrimitiveType> PrimitiveArray<T> {
         unsafe { build_primitive_array(len, buffer, null_count, null_buffer) }
     }
 
+    /// Applies an unary and infallible function to a primitive array, consuming it.
+    ///
+    /// Unlike [`Self::unary`] this writes the results into the array's own values buffer
+    /// when no other array or buffer references it, avoiding an allocation, and otherwise
+    /// falls back to allocating a new buffer. The data type, including any timezone or
+    /// precision, is preserved.
+    ///
+    /// As with [`Self::unary`] the function is applied to all values, including those on
+    /// null slots.
+    /// # Example
+    /// ```rust
+    /// # use arrow_array::Int32Array;
+    /// # fn main() {
+    /// let array = Int32Array::from(vec![Some(5), Some(7), None]);
+    /// let c = array.unary_mut(|x| x * 2 + 1);
+    /// assert_eq!(c, Int32Array::from(vec![Some(11), Some(15), None]));
+    /// # }
+    /// ```
+    pub fn unary_mut<F>(self, op: F) -> PrimitiveArray<T>
+    where
+        F: Fn(T::Native) -> T::Native,
+    {
+        let data = self.data;
+        let data_type = data.data_type().clone();
+        let len = data.len();
+        let offset = data.offset();
+        let null_count = data.null_count();
+        let null_buffer = data.null_buffer().cloned();
+        let mut buffer = data.buffers()[0].clone();
+        // release the array's reference so that `buffer` may be uniquely owned
+        drop(data);
+
+        let (buffer, offset, null_buffer) = match buffer.typed_data_mut::<T::Native>() {
+            Some(values) => {
+                values[offset..offset + len]
+                    .iter_mut()
+                    .for_each(|v| *v = op(*v));
+                (buffer, offset, null_buffer)
+            }
+            None => {
+                let values = buffer.typed_data::<T::Native>()[offset..offset + len]
+                    .iter()
+                    .map(|v| op(*v));
+                // JUSTIFICATION
+                //  Benefit
+                //      ~60% speedup
+                //  Soundness
+                //      `values` is an iterator with a known size because arrays are sized.
+                let buffer = unsafe { Buffer::from_trusted_len_iter(values) };
+                let null_buffer = null_buffer.map(|b| b.bit_slice(offset, len));
+                (buffer, 0, null_buffer)
+            }
+        };
+
+        // SAFETY
+        // the buffers keep the length, offset and validity of the original array
+        PrimitiveArray::from(unsafe {
+            ArrayData::new_unchecked(
+                data_type,
+                len,
+                Some(null_count),
+                null_buffer,
+                offset,
+                vec![buffer],
+                vec![],
+            )
+        })
+    }
+
     /// Applies a unary and fallible function to all valid values in a primitive array
     ///
     /// This is unlike [`Self::unary`] which will apply an infallible function to all rows
@@ -831,6 +900,7 @@ impl<T: ArrowPrimitiveType> From<ArrayData> for PrimitiveArray<T> {
 mod tests {
     use super::*;
     use crate::BooleanArray;
+    use arrow_schema::TimeUnit;
 
     #[test]
     fn test_primitive_array_from_vec() {
@@ -1384,4 +1454,31 @@ mod tests {
         let array = PrimitiveArray::<Decimal256Type>::from(array.data().clone());
         assert_eq!(array.values(), &values);
     }
+
+    #[test]
+    fn test_unary_mut() {
+        let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let ptr = array.data().buffers()[0].as_ptr();
+        let array = array.unary_mut(|x| x * 2);
+        assert_eq!(array, Int32Array::from(vec![Some(2), None, Some(6)]));
+        // uniquely owned, so updated in place
+        assert_eq!(array.data().buffers()[0].as_ptr(), ptr);
+
+        // shared with another array, so must not be mutated
+        let shared = Int32Array::from(array.data().clone());
+        let sliced = shared.slice(1, 2);
+        let sliced = Int32Array::from(sliced.data().clone()).unary_mut(|x| x + 1);
+        assert_eq!(sliced, Int32Array::from(vec![None, Some(7)]));
+        assert_ne!(sliced.data().buffers()[0].as_ptr(), ptr);
+        assert_eq!(shared, Int32Array::from(vec![Some(2), None, Some(6)]));
+
+        let array = TimestampSecondArray::from(vec![1, 2])
+            .with_timezone("+01:00".to_string())
+            .unary_mut(|x| x + 1);
+        assert_eq!(array.values(), &[2, 3]);
+        assert_eq!(
+            array.data_type(),
+            &DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()))
+        );
+    }
 }
@@ -16,8 +16,9 @@
 // under the License.
 
 use crate::{make_array, Array, ArrayRef};
-use arrow_buffer::buffer::buffer_bin_or;
-use arrow_buffer::Buffer;
+use arrow_buffer::bit_mask::copy_bits;
+use arrow_buffer::buffer::{buffer_bin_and, buffer_bin_or};
+use arrow_buffer::{Buffer, MutableBuffer};
 use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType, Field};
 use std::any::Any;
@@ -280,6 +281,51 @@ impl From<(Vec<(Field, ArrayRef)>, Buffer)> for StructArray {
     }
 }
 
+/// Returns `child`, a child of the struct array `parent`, sliced to the length of
+/// `parent` and with any nulls of `parent` applied to its null buffer.
+///
+/// This is the logical value of a field of a struct array, which is null wherever
+/// either the struct or the field is null. Children of type [`DataType::Null`] and
+/// [`DataType::Union`] have no null buffer, and are returned as is.
+pub fn struct_child_with_parent_nulls(
+    parent: &ArrayData,
+    child: &ArrayData,
+) -> Result<ArrayData, ArrowError> {
+    let child = child.slice(0, parent.len());
+    let parent_nulls = match parent.null_buffer() {
+        Some(nulls) if parent.null_count() != 0 => nulls,
+        _ => return Ok(child),
+    };
+    if matches!(child.data_type(), DataType::Null | DataType::Union(_, _, _)) {
+        // These types have no null buffer
+        return Ok(child);
+    }
+
+    let len = parent.len();
+    let nulls = match child.null_buffer() {
+        Some(child_nulls) => buffer_bin_and(
+            child_nulls,
+            child.offset(),
+            parent_nulls,
+            parent.offset(),
+            len,
+        ),
+        None => parent_nulls.bit_slice(parent.offset(), len),
+    };
+
+    // The null buffer is indexed taking into account the child's offset
+    let nulls = match child.offset() {
+        0 => nulls,
+        offset => {
+            let mut buffer = MutableBuffer::new_null(offset + len);
+            copy_bits(buffer.as_slice_mut(), offset, nulls.as_slice(), 0, len);
+            buffer.into()
+        }
+    };
+
+    child.into_builder().null_bit_buffer(Some(nulls)).build()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -15,8 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use arrow_buffer::{bit_util, Buffer, MutableBuffer};
-use arrow_data::bit_mask;
+use arrow_buffer::{bit_mask, bit_util, Buffer, MutableBuffer};
 use std::ops::Range;
 
 #[derive(Debug)]
@@ -107,9 +106,7 @@ impl BooleanBufferBuilder {
         self.advance(additional);
         if additional > 0 && v {
             let offset = self.len() - additional;
-            (0..additional).for_each(|i| unsafe {
-                bit_util::set_bit_raw(self.buffer.as_mut_ptr(), offset + i)
-            })
+            bit_mask::fill_bits(self.buffer.as_slice_mut(), offset, additional, true);
         }
     }
 
@@ -725,6 +725,7 @@ array_downcast_fn!(as_null_array, NullArray);
 array_downcast_fn!(as_struct_array, StructArray);
 array_downcast_fn!(as_union_array, UnionArray);
 array_downcast_fn!(as_map_array, MapArray);
+array_downcast_fn!(as_fixed_size_list_array, FixedSizeListArray);
 array_downcast_fn!(as_decimal_array, Decimal128Array);
 
 #[cfg(test)]
@@ -49,35 +49,31 @@ fn normalise_day(year: i32, month: u32, day: u32) -> u32 {
 
 /// Shift a date by the given number of months.
 /// Ambiguous month-ends are shifted backwards as necessary.
+///
+/// # Panics
+///
+/// Panics if the resulting date is out of range, see [`checked_shift_months`]
 pub(crate) fn shift_months<D: Datelike>(date: D, months: i32) -> D {
-    let mut year = date.year() + (date.month() as i32 + months) / 12;
-    let mut month = (date.month() as i32 + months) % 12;
-    let mut day = date.day();
-
-    if month < 1 {
-        year -= 1;
-        month += 12;
-    }
+    checked_shift_months(date, months).expect("date out of range")
+}
 
-    day = normalise_day(year, month as u32, day);
+/// Shift a date by the given number of months, returning `None` if the resulting
+/// date is out of range. Ambiguous month-ends are shifted backwards as necessary.
+pub(crate) fn checked_shift_months<D: Datelike>(date: D, months: i32) -> Option<D> {
+    // the number of months since January of year 0
+    let total = (date.month0() as i32).checked_add(months)?;
+    let year = date.year().checked_add(total.div_euclid(12))?;
+    let month = total.rem_euclid(12) as u32 + 1;
+    let day = normalise_day(year, month, date.day());
 
-    // This is slow but guaranteed to succeed (short of interger overflow)
+    // This is slow but guaranteed to succeed for dates in range
     if day <= 28 {
-        date.with_day(day)
-            .unwrap()
-            .with_month(month as u32)
-            .unwrap()
-            .with_year(year)
-            .unwrap()
+        date.with_day(day)?.with_month(month)?.with_year(year)
     } else {
-        date.with_day(1)
-            .unwrap()
-            .with_month(month as u32)
-            .unwrap()
-            .with_year(year)
-            .unwrap()
+        date.with_day(1)?
+            .with_month(month)?
+            .with_year(year)?
             .with_day(day)
-            .unwrap()
     }
 }
 
@@ -179,4 +175,29 @@ mod tests {
         assert_eq!(shift_months(base, 1).time(), o_clock);
         assert_eq!(shift_months(base, 2).time(), o_clock);
     }
+
+    #[test]
+    fn test_checked_shift_months() {
+        let base = NaiveDate::from_ymd(2020, 1, 31);
+        assert_eq!(
+            checked_shift_months(base, 13),
+            Some(NaiveDate::from_ymd(2021, 2, 28))
+        );
+        assert_eq!(
+            checked_shift_months(base, -25),
+            Some(NaiveDate::from_ymd(2017, 12, 31))
+        );
+
+        assert_eq!(checked_shift_months(base, i32::MAX), None);
+        assert_eq!(checked_shift_months(base, i32::MIN), None);
+        assert_eq!(checked_shift_months(base, 12 * 300_000), None);
+        assert_eq!(checked_shift_months(base, -12 * 300_000), None);
+
+        let max = NaiveDate::MAX;
+        assert_eq!(checked_shift_months(max, 1), None);
+        assert_eq!(
+            checked_shift_months(max, -1),
+            Some(NaiveDate::from_ymd(262143, 11, 30))
+        );
+    }
 }
@@ -164,6 +164,9 @@ pub use array::*;
 mod record_batch;
 pub use record_batch::{RecordBatch, RecordBatchOptions};
 
+mod row_accessor;
+pub use row_accessor::{RecordBatchRowIter, RowAccessor};
+
 pub mod builder;
 pub mod cast;
 pub mod decimal;
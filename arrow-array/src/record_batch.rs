@@ -18,8 +18,16 @@
 //! A two-dimensional batch of column-oriented data with a defined
 //! [schema](arrow_schema::Schema).
 
-use crate::{new_empty_array, Array, ArrayRef, StructArray};
+use crate::row_accessor::{RecordBatchRowIter, RowAccessor};
+use crate::{
+    make_array, new_empty_array, struct_child_with_parent_nulls, Array, ArrayRef,
+    StructArray,
+};
+use arrow_data::transform::MutableArrayData;
+use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+use std::collections::HashSet;
+use std::ptr::NonNull;
 use std::sync::Arc;
 
 /// A two-dimensional batch of column-oriented data with a defined
@@ -141,14 +149,24 @@ impl RecordBatch {
             }
         }
 
-        if columns.iter().any(|c| c.len() != row_count) {
+        let mismatch = columns
+            .iter()
+            .zip(schema.fields())
+            .find(|(c, _)| c.len() != row_count);
+        if let Some((column, field)) = mismatch {
             let err = match options.row_count {
                 Some(_) => {
                     "all columns in a record batch must have the specified row count"
                 }
                 None => "all columns in a record batch must have the same length",
             };
-            return Err(ArrowError::InvalidArgumentError(err.to_string()));
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "{}, expected {} but column '{}' has length {}",
+                err,
+                row_count,
+                field.name(),
+                column.len()
+            )));
         }
 
         // function for comparing column type and field type
@@ -191,7 +209,14 @@ impl RecordBatch {
         self.schema.clone()
     }
 
+    /// Returns a reference to the [`Schema`](arrow_schema::Schema) of the record batch.
+    pub fn schema_ref(&self) -> &SchemaRef {
+        &self.schema
+    }
+
     /// Projects the schema onto the specified columns
+    ///
+    /// The columns are not copied, the returned batch shares their data with `self`
     pub fn project(&self, indices: &[usize]) -> Result<RecordBatch, ArrowError> {
         let projected_schema = self.schema.project(indices)?;
         let batch_fields = indices
@@ -217,6 +242,205 @@ impl RecordBatch {
         )
     }
 
+    /// Projects the schema onto the columns named `names`, in the given order
+    ///
+    /// The columns are not copied, the returned batch shares their data with `self`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    /// let batch = RecordBatch::try_from_iter(vec![
+    ///     ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+    ///     ("b", Arc::new(StringArray::from(vec!["x", "y"]))),
+    ///     ("c", Arc::new(Int32Array::from(vec![3, 4]))),
+    /// ])
+    /// .unwrap();
+    ///
+    /// let selected = batch.select_by_name(&["c", "a"]).unwrap();
+    /// assert_eq!(selected.schema().field(0).name(), "c");
+    /// assert_eq!(selected.schema().field(1).name(), "a");
+    /// ```
+    pub fn select_by_name(&self, names: &[&str]) -> Result<RecordBatch, ArrowError> {
+        let indices = names
+            .iter()
+            .map(|name| self.schema.index_of(name))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.project(&indices)
+    }
+
+    /// Normalizes a [`RecordBatch`] containing struct columns into a flat batch
+    ///
+    /// Each column of type [`DataType::Struct`] is replaced by one top-level column
+    /// per child, named `{parent}{separator}{child}`, recursing into nested structs
+    /// up to `max_depth` levels, or fully if `None`. As with pyarrow's `flatten`, a
+    /// value in a flattened column is null if it or any of its parents is null.
+    ///
+    /// See [`RecordBatch::nest`] for the inverse operation.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray, StructArray};
+    /// # use arrow_schema::{DataType, Field};
+    /// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    /// let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y"]));
+    /// let s: ArrayRef = Arc::new(StructArray::from(vec![
+    ///     (Field::new("b", DataType::Utf8, false), b),
+    /// ]));
+    /// let batch = RecordBatch::try_from_iter([("a", a), ("s", s)]).unwrap();
+    ///
+    /// let flat = batch.normalize(".", None).unwrap();
+    /// assert_eq!(flat.schema().field(1).name(), "s.b");
+    /// assert_eq!(flat.nest(".").unwrap(), batch);
+    /// ```
+    pub fn normalize(
+        &self,
+        separator: &str,
+        max_depth: Option<usize>,
+    ) -> Result<RecordBatch, ArrowError> {
+        let max_depth = max_depth.unwrap_or(usize::MAX);
+        let mut fields = Vec::with_capacity(self.columns.len());
+        let mut columns = Vec::with_capacity(self.columns.len());
+        for (field, column) in self.schema.fields().iter().zip(&self.columns) {
+            flatten_column(
+                field.clone(),
+                column.data(),
+                separator,
+                max_depth,
+                &mut fields,
+                &mut columns,
+            )?;
+        }
+
+        self.with_columns(fields, columns)
+    }
+
+    /// Nests the columns of a flat [`RecordBatch`] into struct columns, reversing
+    /// [`RecordBatch::normalize`]
+    ///
+    /// Column names are split on `separator`, with columns sharing a prefix
+    /// grouped into a non-nullable struct column placed at the position of the
+    /// first such column. As the validity of the original structs is not recorded
+    /// by [`RecordBatch::normalize`], it is not restored.
+    ///
+    /// Returns an error if `separator` is empty, or a column name is also
+    /// used as the prefix of another column.
+    pub fn nest(&self, separator: &str) -> Result<RecordBatch, ArrowError> {
+        if separator.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "nest requires a non-empty separator".to_string(),
+            ));
+        }
+
+        let mut nodes = Vec::new();
+        for (field, column) in self.schema.fields().iter().zip(&self.columns) {
+            let path: Vec<_> = field.name().split(separator).collect();
+            NestNode::insert(&mut nodes, &path, field, column)?;
+        }
+
+        let (fields, columns) = nodes.into_iter().map(NestNode::finish).unzip();
+        self.with_columns(fields, columns)
+    }
+
+    /// Returns a new [`RecordBatch`] with the column `name` set to `array`
+    ///
+    /// If a column named `name` already exists it is replaced, keeping its position,
+    /// otherwise the column is appended. As with [`RecordBatch::try_from_iter`] the
+    /// new field is nullable if `array` contains nulls. The other columns are not copied.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    /// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    /// let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+    ///
+    /// let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y"]));
+    /// let batch = batch.with_column("b", b).unwrap();
+    /// assert_eq!(batch.schema().field(1).name(), "b");
+    ///
+    /// let batch = batch.remove_column(0).unwrap();
+    /// assert_eq!(batch.num_columns(), 1);
+    /// ```
+    pub fn with_column(
+        &self,
+        name: &str,
+        array: ArrayRef,
+    ) -> Result<RecordBatch, ArrowError> {
+        let field = Field::new(name, array.data_type().clone(), array.null_count() > 0);
+        let mut fields = self.schema.fields().clone();
+        let mut columns = self.columns.clone();
+        match self.schema.column_with_name(name) {
+            Some((idx, _)) => {
+                fields[idx] = field;
+                columns[idx] = array;
+            }
+            None => {
+                fields.push(field);
+                columns.push(array);
+            }
+        }
+        self.with_columns(fields, columns)
+    }
+
+    /// Returns a new [`RecordBatch`] with the column at `index` replaced by `array`
+    ///
+    /// The field keeps its name and metadata, with its data type updated to that
+    /// of `array`, and made nullable if `array` contains nulls. The other columns
+    /// are not copied.
+    pub fn set_column(
+        &self,
+        index: usize,
+        array: ArrayRef,
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut fields = self.schema.fields().clone();
+        let mut columns = self.columns.clone();
+        let field = fields
+            .get_mut(index)
+            .ok_or_else(|| self.column_out_of_bounds(index))?;
+        let nullable = field.is_nullable() || array.null_count() > 0;
+        *field = field
+            .clone()
+            .with_data_type(array.data_type().clone())
+            .with_nullable(nullable);
+        columns[index] = array;
+        self.with_columns(fields, columns)
+    }
+
+    /// Returns a new [`RecordBatch`] without the column at `index`
+    ///
+    /// The other columns are not copied.
+    pub fn remove_column(&self, index: usize) -> Result<RecordBatch, ArrowError> {
+        if index >= self.columns.len() {
+            return Err(self.column_out_of_bounds(index));
+        }
+        let mut fields = self.schema.fields().clone();
+        let mut columns = self.columns.clone();
+        fields.remove(index);
+        columns.remove(index);
+        self.with_columns(fields, columns)
+    }
+
+    /// Creates a [`RecordBatch`] with the same row count and schema metadata
+    /// as this batch, but with the given `fields` and `columns`
+    fn with_columns(
+        &self,
+        fields: Vec<Field>,
+        columns: Vec<ArrayRef>,
+    ) -> Result<RecordBatch, ArrowError> {
+        let schema = Schema::new_with_metadata(fields, self.schema.metadata().clone());
+        let options = RecordBatchOptions::new().with_row_count(Some(self.row_count));
+        RecordBatch::try_new_with_options(Arc::new(schema), columns, &options)
+    }
+
+    fn column_out_of_bounds(&self, index: usize) -> ArrowError {
+        ArrowError::InvalidArgumentError(format!(
+            "column index {} out of bounds, max field {}",
+            index,
+            self.columns.len()
+        ))
+    }
+
     /// Returns the number of columns in the record batch.
     ///
     /// # Example
@@ -270,6 +494,21 @@ impl RecordBatch {
         &self.columns[index]
     }
 
+    /// Returns an iterator over the rows of this [`RecordBatch`], providing
+    /// typed access to the values of each row
+    pub fn rows(&self) -> RecordBatchRowIter<'_> {
+        RecordBatchRowIter::new(self)
+    }
+
+    /// Returns a [`RowAccessor`] for row `index`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than [`RecordBatch::num_rows`]
+    pub fn row(&self, index: usize) -> RowAccessor<'_> {
+        RowAccessor::new_batch(self, index)
+    }
+
     /// Get a reference to all columns in the record batch.
     pub fn columns(&self) -> &[ArrayRef] {
         &self.columns[..]
@@ -297,6 +536,33 @@ impl RecordBatch {
         }
     }
 
+    /// Returns the total number of bytes of memory occupied by the buffers of
+    /// this [`RecordBatch`].
+    ///
+    /// Unlike summing [`Array::get_buffer_memory_size`] over the columns, each
+    /// underlying allocation is only counted once, even if it is referenced by
+    /// several columns or children, e.g. as a result of [`RecordBatch::slice`]
+    /// or of columns sharing a dictionary. Note that slicing does not reduce
+    /// the memory reported, as the sliced columns keep the whole allocation alive.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// let a: ArrayRef = Arc::new(Int32Array::from_iter_values(0..1024));
+    /// let batch = RecordBatch::try_from_iter([("a", a.clone()), ("b", a)]).unwrap();
+    ///
+    /// let column_size = batch.column(0).get_buffer_memory_size();
+    /// assert_eq!(batch.get_batch_memory_size(), column_size);
+    /// assert_eq!(batch.slice(10, 10).get_batch_memory_size(), column_size);
+    /// ```
+    pub fn get_batch_memory_size(&self) -> usize {
+        let mut seen = HashSet::new();
+        self.columns
+            .iter()
+            .map(|column| buffer_memory_size(column.data(), &mut seen))
+            .sum()
+    }
+
     /// Create a `RecordBatch` from an iterable list of pairs of the
     /// form `(field_name, array)`, with the same requirements on
     /// fields and arrays as [`RecordBatch::try_new`]. This method is
@@ -377,6 +643,169 @@ impl RecordBatch {
         let schema = Arc::new(Schema::new(fields));
         RecordBatch::try_new(schema, columns)
     }
+
+    /// Create a `RecordBatch` from an iterable list of tuples of the
+    /// form `(field_name, array, nullable)`, as [`RecordBatch::try_from_iter_with_nullable`],
+    /// but broadcasting any array of length 1 to the length of the longest array.
+    ///
+    /// This makes it easy to add columns holding a single literal value
+    /// when constructing batches by hand.
+    ///
+    /// Example:
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    /// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+    /// let b: ArrayRef = Arc::new(StringArray::from(vec!["literal"]));
+    ///
+    /// let record_batch =
+    ///     RecordBatch::try_from_iter_with_broadcast(vec![("a", a, false), ("b", b, false)])
+    ///         .unwrap();
+    /// assert_eq!(record_batch.num_rows(), 3);
+    /// assert_eq!(
+    ///     record_batch.column(1).as_ref(),
+    ///     &StringArray::from(vec!["literal"; 3]),
+    /// );
+    /// ```
+    pub fn try_from_iter_with_broadcast<I, F>(value: I) -> Result<Self, ArrowError>
+    where
+        I: IntoIterator<Item = (F, ArrayRef, bool)>,
+        F: AsRef<str>,
+    {
+        let columns: Vec<_> = value.into_iter().collect();
+        let row_count = columns.iter().map(|(_, a, _)| a.len()).max();
+
+        let columns = columns.into_iter().map(|(field_name, array, nullable)| {
+            let array = match row_count {
+                Some(len) if array.len() == 1 && len != 1 => broadcast(&array, len),
+                _ => array,
+            };
+            (field_name, array, nullable)
+        });
+        Self::try_from_iter_with_nullable(columns)
+    }
+}
+
+/// Returns an array of length `len` with every element equal to the only
+/// element of `array`
+fn broadcast(array: &ArrayRef, len: usize) -> ArrayRef {
+    let mut mutable = MutableArrayData::new(vec![array.data()], false, len);
+    for _ in 0..len {
+        mutable.extend(0, 0, 1);
+    }
+    make_array(mutable.freeze())
+}
+
+/// Returns the capacity of the buffers of `data` and its children whose
+/// allocations are not already in `seen`
+fn buffer_memory_size(data: &ArrayData, seen: &mut HashSet<NonNull<u8>>) -> usize {
+    let mut size = 0;
+    for buffer in data.buffers().iter().chain(data.null_buffer()) {
+        if seen.insert(buffer.data_ptr()) {
+            size += buffer.capacity();
+        }
+    }
+    for child in data.child_data() {
+        size += buffer_memory_size(child, seen);
+    }
+    size
+}
+
+/// Appends `field` and `data` to `fields` and `columns`, replacing struct
+/// columns by their children up to `depth` levels of nesting
+fn flatten_column(
+    field: Field,
+    data: &ArrayData,
+    separator: &str,
+    depth: usize,
+    fields: &mut Vec<Field>,
+    columns: &mut Vec<ArrayRef>,
+) -> Result<(), ArrowError> {
+    let children = match field.data_type() {
+        DataType::Struct(children) if depth > 0 => children,
+        _ => {
+            fields.push(field);
+            columns.push(make_array(data.clone()));
+            return Ok(());
+        }
+    };
+
+    for (child_field, child_data) in children.iter().zip(data.child_data()) {
+        let child_data = struct_child_with_parent_nulls(data, child_data)?;
+        let name = format!("{}{}{}", field.name(), separator, child_field.name());
+        let nullable = field.is_nullable() || child_field.is_nullable();
+        let child_field = child_field.clone().with_name(name).with_nullable(nullable);
+        flatten_column(
+            child_field,
+            &child_data,
+            separator,
+            depth - 1,
+            fields,
+            columns,
+        )?;
+    }
+    Ok(())
+}
+
+/// A column of a [`RecordBatch`] being nested by [`RecordBatch::nest`]
+enum NestNode<'a> {
+    Leaf(Field, ArrayRef),
+    Struct(&'a str, Vec<NestNode<'a>>),
+}
+
+impl<'a> NestNode<'a> {
+    fn name(&self) -> &str {
+        match self {
+            Self::Leaf(field, _) => field.name(),
+            Self::Struct(name, _) => name,
+        }
+    }
+
+    fn insert(
+        nodes: &mut Vec<Self>,
+        path: &[&'a str],
+        field: &Field,
+        column: &ArrayRef,
+    ) -> Result<(), ArrowError> {
+        let conflict = || {
+            ArrowError::InvalidArgumentError(format!(
+                "Cannot nest column '{}' as it conflicts with another column",
+                field.name()
+            ))
+        };
+
+        let existing = nodes.iter_mut().position(|node| node.name() == path[0]);
+        match (path, existing) {
+            ([name], None) => {
+                let field = field.clone().with_name(*name);
+                nodes.push(Self::Leaf(field, column.clone()));
+                Ok(())
+            }
+            ([name, rest @ ..], None) => {
+                let mut children = Vec::new();
+                Self::insert(&mut children, rest, field, column)?;
+                nodes.push(Self::Struct(name, children));
+                Ok(())
+            }
+            ([_, rest @ ..], Some(idx)) if !rest.is_empty() => match &mut nodes[idx] {
+                Self::Struct(_, children) => Self::insert(children, rest, field, column),
+                Self::Leaf(_, _) => Err(conflict()),
+            },
+            _ => Err(conflict()),
+        }
+    }
+
+    fn finish(self) -> (Field, ArrayRef) {
+        match self {
+            Self::Leaf(field, column) => (field, column),
+            Self::Struct(name, children) => {
+                let children = children.into_iter().map(Self::finish).collect::<Vec<_>>();
+                let array = StructArray::from(children);
+                let field = Field::new(name, array.data_type().clone(), false);
+                (field, Arc::new(array))
+            }
+        }
+    }
 }
 
 /// Options that control the behaviour used when creating a [`RecordBatch`].
@@ -454,6 +883,7 @@ mod tests {
     };
     use arrow_buffer::{Buffer, ToByteSlice};
     use arrow_data::ArrayDataBuilder;
+    use std::collections::HashMap;
 
     #[test]
     fn create_record_batch() {
@@ -515,6 +945,179 @@ mod tests {
         let _record_batch_slice = record_batch.slice(offset, length);
     }
 
+    #[test]
+    fn normalize_nest() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            None,
+            Some("c"),
+            Some("d"),
+        ]));
+        let c: ArrayRef = Arc::new(BooleanArray::from(vec![true, false, true, false]));
+        let inner: ArrayRef = Arc::new(StructArray::from(vec![(
+            Field::new("c", DataType::Boolean, false),
+            c.clone(),
+        )]));
+        let outer: ArrayRef = Arc::new(StructArray::from((
+            vec![
+                (Field::new("b", DataType::Utf8, true), b.clone()),
+                (Field::new("inner", inner.data_type().clone(), false), inner),
+            ],
+            Buffer::from([0b00001011]),
+        )));
+        let batch = RecordBatch::try_from_iter_with_nullable(vec![
+            ("a", a.clone(), false),
+            ("outer", outer, true),
+        ])
+        .unwrap();
+
+        let flat = batch.normalize(".", None).unwrap();
+        let names: Vec<_> = flat
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect();
+        assert_eq!(names, vec!["a", "outer.b", "outer.inner.c"]);
+        assert!(!flat.schema().field(0).is_nullable());
+        assert!(flat.schema().field(2).is_nullable());
+        assert_eq!(flat.column(0), &a);
+        assert_eq!(
+            flat.column(1).as_ref(),
+            &StringArray::from(vec![Some("a"), None, None, Some("d")]) as &dyn Array
+        );
+        assert_eq!(
+            flat.column(2).as_ref(),
+            &BooleanArray::from(vec![Some(true), Some(false), None, Some(false)])
+                as &dyn Array
+        );
+
+        let sliced = batch.slice(1, 3).normalize("_", None).unwrap();
+        assert_eq!(sliced.schema().field(2).name(), "outer_inner_c");
+        assert_eq!(
+            sliced.column(2).as_ref(),
+            &BooleanArray::from(vec![Some(false), None, Some(false)]) as &dyn Array
+        );
+
+        let partial = batch.normalize(".", Some(1)).unwrap();
+        assert_eq!(partial.num_columns(), 3);
+        assert_eq!(partial.schema().field(2).name(), "outer.inner");
+        assert_eq!(batch.normalize(".", Some(0)).unwrap(), batch);
+
+        let nested = flat.nest(".").unwrap();
+        assert_eq!(nested.num_columns(), 2);
+        assert_eq!(nested.schema().field(1).name(), "outer");
+        let outer = nested
+            .column(1)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert_eq!(outer.column(0), flat.column(1));
+        assert_eq!(outer.column_names(), vec!["b", "inner"]);
+        let inner = outer
+            .column(1)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert_eq!(inner.column(0), flat.column(2));
+
+        let conflicting =
+            RecordBatch::try_from_iter(vec![("x", a.clone()), ("x.y", a)]).unwrap();
+        let err = conflicting.nest(".").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot nest column 'x.y' as it conflicts with another column"
+        );
+        assert!(conflicting.nest("").is_err());
+    }
+
+    #[test]
+    fn add_remove_set_column() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)])
+            .with_metadata(metadata.clone());
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![a.clone()]).unwrap();
+
+        let added = batch.with_column("b", b.clone()).unwrap();
+        assert_eq!(added.num_columns(), 2);
+        assert_eq!(added.schema().metadata(), &metadata);
+        assert!(Arc::ptr_eq(added.column(0), &a));
+        assert_eq!(
+            added.schema().field(1),
+            &Field::new("b", DataType::Utf8, false)
+        );
+
+        let nulls: ArrayRef = Arc::new(Int8Array::from(vec![Some(1), None, None]));
+        let replaced = added.with_column("a", nulls.clone()).unwrap();
+        assert_eq!(
+            replaced.schema().field(0),
+            &Field::new("a", DataType::Int8, true)
+        );
+        assert_eq!(replaced.column(0), &nulls);
+        assert_eq!(replaced.schema().field(1).name(), "b");
+
+        let set = added.set_column(1, a.clone()).unwrap();
+        assert_eq!(
+            set.schema().field(1),
+            &Field::new("b", DataType::Int32, false)
+        );
+        assert_eq!(set.column(1), &a);
+
+        let removed = added.remove_column(0).unwrap();
+        assert_eq!(removed.num_columns(), 1);
+        assert_eq!(removed.schema().field(0).name(), "b");
+        let removed = removed.remove_column(0).unwrap();
+        assert_eq!(removed.num_columns(), 0);
+        assert_eq!(removed.num_rows(), 3);
+
+        let err = added.remove_column(2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: column index 2 out of bounds, max field 2"
+        );
+        assert!(added.set_column(2, a).is_err());
+
+        let short: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        assert!(added.with_column("c", short).is_err());
+    }
+
+    #[test]
+    fn batch_memory_size() {
+        let a: ArrayRef = Arc::new(Int32Array::from_iter(
+            (0..64).map(|x| (x % 3 != 0).then_some(x)),
+        ));
+        let b: ArrayRef = Arc::new(StringArray::from_iter_values(
+            (0..64).map(|x| x.to_string()),
+        ));
+        let s: ArrayRef = Arc::new(StructArray::from(vec![
+            (Field::new("a", DataType::Int32, true), a.clone()),
+            (Field::new("b", DataType::Utf8, false), b.clone()),
+        ]));
+
+        let a_size = a.get_buffer_memory_size();
+        let b_size = b.get_buffer_memory_size();
+
+        let batch = RecordBatch::try_from_iter(vec![("a", a.clone())]).unwrap();
+        assert_eq!(batch.get_batch_memory_size(), a_size);
+
+        // Struct children share the buffers of the top-level columns
+        let batch =
+            RecordBatch::try_from_iter(vec![("a", a), ("b", b), ("s", s.clone())])
+                .unwrap();
+        assert_eq!(batch.get_batch_memory_size(), a_size + b_size);
+
+        let sliced = batch.slice(5, 20);
+        assert_eq!(sliced.get_batch_memory_size(), a_size + b_size);
+        assert_eq!(sliced.column(0).null_count(), 7);
+        assert_eq!(sliced.column(2).data().child_data()[0].null_count(), 7);
+
+        let batch = RecordBatch::try_from_iter(vec![("s", s)]).unwrap();
+        assert_eq!(batch.get_batch_memory_size(), a_size + b_size);
+    }
+
     #[test]
     #[should_panic(expected = "assertion failed: (offset + length) <= self.num_rows()")]
     fn create_record_batch_slice_empty_batch() {
@@ -574,6 +1177,67 @@ mod tests {
         check_batch(record_batch, 5);
     }
 
+    #[test]
+    fn create_record_batch_try_from_iter_with_broadcast() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec![Some("x")]));
+        let c: ArrayRef = Arc::new(Int8Array::from(vec![None]));
+
+        let record_batch = RecordBatch::try_from_iter_with_broadcast(vec![
+            ("a", a.clone(), false),
+            ("b", b.clone(), false),
+            ("c", c.clone(), true),
+        ])
+        .unwrap();
+        assert_eq!(record_batch.num_rows(), 5);
+        assert_eq!(
+            record_batch.column(1).as_ref(),
+            &StringArray::from(vec!["x"; 5]) as &dyn Array
+        );
+        assert_eq!(record_batch.column(2).null_count(), 5);
+
+        // All single element arrays are not broadcast
+        let record_batch = RecordBatch::try_from_iter_with_broadcast(vec![
+            ("b", b, false),
+            ("c", c, true),
+        ])
+        .unwrap();
+        assert_eq!(record_batch.num_rows(), 1);
+
+        let d: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let err = RecordBatch::try_from_iter_with_broadcast(vec![
+            ("a", a, false),
+            ("d", d, false),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all columns in a record batch must have the same length, expected 5 but column 'd' has length 2"
+        );
+    }
+
+    #[test]
+    fn create_record_batch_length_mismatch() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b"]));
+
+        let err =
+            RecordBatch::try_from_iter(vec![("a", a.clone()), ("b", b)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all columns in a record batch must have the same length, expected 3 but column 'b' has length 2"
+        );
+
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let options = RecordBatchOptions::new().with_row_count(Some(2));
+        let err =
+            RecordBatch::try_new_with_options(schema, vec![a], &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all columns in a record batch must have the specified row count, expected 2 but column 'a' has length 3"
+        );
+    }
+
     #[test]
     fn create_record_batch_schema_mismatch() {
         let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
@@ -848,6 +1512,29 @@ mod tests {
         assert_eq!(expected, record_batch.project(&[0, 2]).unwrap());
     }
 
+    #[test]
+    fn select_by_name() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let c: ArrayRef = Arc::new(StringArray::from(vec!["d", "e", "f"]));
+
+        let record_batch = RecordBatch::try_from_iter(vec![
+            ("a", a.clone()),
+            ("b", b),
+            ("c", c.clone()),
+        ])
+        .expect("valid conversion");
+
+        let expected = RecordBatch::try_from_iter(vec![("c", c), ("a", a)])
+            .expect("valid conversion");
+
+        let selected = record_batch.select_by_name(&["c", "a"]).unwrap();
+        assert_eq!(expected, selected);
+        assert!(Arc::ptr_eq(selected.column(0), record_batch.column(2)));
+
+        assert!(record_batch.select_by_name(&["d"]).is_err());
+    }
+
     #[test]
     fn project_empty() {
         let c: ArrayRef = Arc::new(StringArray::from(vec!["d", "e", "f"]));
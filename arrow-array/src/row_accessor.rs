@@ -0,0 +1,428 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Row-oriented access to the values of a [`RecordBatch`]
+
+use crate::cast::*;
+use crate::types::*;
+use crate::{
+    downcast_dictionary_array, Array, ArrayRef, ArrowPrimitiveType, FixedSizeBinaryArray,
+    FixedSizeListArray, RecordBatch, StructArray,
+};
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::{ArrowError, DataType, Field};
+
+/// An iterator over the rows of a [`RecordBatch`], returned by [`RecordBatch::rows`]
+#[derive(Debug, Clone)]
+pub struct RecordBatchRowIter<'a> {
+    batch: &'a RecordBatch,
+    current: usize,
+    end: usize,
+}
+
+impl<'a> RecordBatchRowIter<'a> {
+    pub(crate) fn new(batch: &'a RecordBatch) -> Self {
+        Self {
+            batch,
+            current: 0,
+            end: batch.num_rows(),
+        }
+    }
+}
+
+impl<'a> Iterator for RecordBatchRowIter<'a> {
+    type Item = RowAccessor<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current == self.end {
+            return None;
+        }
+        let row = RowAccessor::new(RowSource::Batch(self.batch), self.current);
+        self.current += 1;
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.current;
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for RecordBatchRowIter<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.current == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(RowAccessor::new(RowSource::Batch(self.batch), self.end))
+    }
+}
+
+impl<'a> ExactSizeIterator for RecordBatchRowIter<'a> {}
+
+#[derive(Debug, Clone, Copy)]
+enum RowSource<'a> {
+    Batch(&'a RecordBatch),
+    Struct(&'a StructArray),
+}
+
+/// A lightweight reference to a single row of a [`RecordBatch`], or of a
+/// [`StructArray`], providing typed access to its values
+///
+/// Each getter takes the index of a column, see [`RowAccessor::column_index`]
+/// to look one up by name, and returns `Ok(None)` if the value is null, or an
+/// error if the column's type cannot be read as the requested type. Integer
+/// and floating point getters accept any narrower type that converts losslessly,
+/// and all getters look through dictionary encoding.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+/// let b: ArrayRef = Arc::new(StringArray::from(vec![Some("x"), None]));
+/// let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+///
+/// let b = batch.schema().index_of("b").unwrap();
+/// let rows: Vec<_> = batch
+///     .rows()
+///     .map(|row| (row.get_i64(0).unwrap(), row.get_str(b).unwrap()))
+///     .collect();
+/// assert_eq!(rows, vec![(Some(1), Some("x")), (Some(2), None)]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RowAccessor<'a> {
+    source: RowSource<'a>,
+    row: usize,
+}
+
+impl<'a> RowAccessor<'a> {
+    fn new(source: RowSource<'a>, row: usize) -> Self {
+        Self { source, row }
+    }
+
+    pub(crate) fn new_batch(batch: &'a RecordBatch, row: usize) -> Self {
+        assert!(
+            row < batch.num_rows(),
+            "row index {} out of bounds for batch with {} rows",
+            row,
+            batch.num_rows()
+        );
+        Self::new(RowSource::Batch(batch), row)
+    }
+
+    /// Returns the index of this row
+    pub fn index(&self) -> usize {
+        self.row
+    }
+
+    /// Returns the number of columns in this row
+    pub fn num_columns(&self) -> usize {
+        match self.source {
+            RowSource::Batch(batch) => batch.num_columns(),
+            RowSource::Struct(array) => array.num_columns(),
+        }
+    }
+
+    /// Returns the index of the column named `name`
+    pub fn column_index(&self, name: &str) -> Result<usize, ArrowError> {
+        (0..self.num_columns())
+            .find(|i| self.field(*i).name() == name)
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Unable to get column named \"{}\"",
+                    name
+                ))
+            })
+    }
+
+    /// Returns the [`Field`] of column `col`
+    pub fn field(&self, col: usize) -> &'a Field {
+        match self.source {
+            RowSource::Batch(batch) => batch.schema_ref().field(col),
+            RowSource::Struct(array) => match array.data_type() {
+                DataType::Struct(fields) => &fields[col],
+                _ => unreachable!(),
+            },
+        }
+    }
+
+    /// Returns the array of column `col`
+    pub fn column(&self, col: usize) -> &'a ArrayRef {
+        match self.source {
+            RowSource::Batch(batch) => batch.column(col),
+            RowSource::Struct(array) => array.column(col),
+        }
+    }
+
+    /// Returns true if the value of column `col` is null
+    pub fn is_null(&self, col: usize) -> bool {
+        let (array, idx) = self.resolve(col);
+        idx.map(|idx| array.is_null(idx)).unwrap_or(true)
+    }
+
+    /// Returns the value of column `col` as a `bool`
+    pub fn get_bool(&self, col: usize) -> Result<Option<bool>, ArrowError> {
+        self.get(col, "bool", |data_type| match data_type {
+            DataType::Boolean => Some(|a, i| as_boolean_array(a).value(i)),
+            _ => None,
+        })
+    }
+
+    /// Returns the value of column `col` as an `i64`
+    pub fn get_i64(&self, col: usize) -> Result<Option<i64>, ArrowError> {
+        self.get(col, "i64", |data_type| match data_type {
+            DataType::Int8 => Some(|a, i| value::<Int8Type>(a, i) as i64),
+            DataType::Int16 => Some(|a, i| value::<Int16Type>(a, i) as i64),
+            DataType::Int32 => Some(|a, i| value::<Int32Type>(a, i) as i64),
+            DataType::Int64 => Some(value::<Int64Type>),
+            DataType::UInt8 => Some(|a, i| value::<UInt8Type>(a, i) as i64),
+            DataType::UInt16 => Some(|a, i| value::<UInt16Type>(a, i) as i64),
+            DataType::UInt32 => Some(|a, i| value::<UInt32Type>(a, i) as i64),
+            _ => None,
+        })
+    }
+
+    /// Returns the value of column `col` as a `u64`
+    pub fn get_u64(&self, col: usize) -> Result<Option<u64>, ArrowError> {
+        self.get(col, "u64", |data_type| match data_type {
+            DataType::UInt8 => Some(|a, i| value::<UInt8Type>(a, i) as u64),
+            DataType::UInt16 => Some(|a, i| value::<UInt16Type>(a, i) as u64),
+            DataType::UInt32 => Some(|a, i| value::<UInt32Type>(a, i) as u64),
+            DataType::UInt64 => Some(value::<UInt64Type>),
+            _ => None,
+        })
+    }
+
+    /// Returns the value of column `col` as an `f64`
+    pub fn get_f64(&self, col: usize) -> Result<Option<f64>, ArrowError> {
+        self.get(col, "f64", |data_type| match data_type {
+            DataType::Float16 => Some(|a, i| value::<Float16Type>(a, i).to_f64()),
+            DataType::Float32 => Some(|a, i| value::<Float32Type>(a, i) as f64),
+            DataType::Float64 => Some(value::<Float64Type>),
+            _ => None,
+        })
+    }
+
+    /// Returns the value of column `col`, of type [`DataType::Utf8`] or
+    /// [`DataType::LargeUtf8`], as a `&str`
+    pub fn get_str(&self, col: usize) -> Result<Option<&'a str>, ArrowError> {
+        self.get(col, "str", |data_type| match data_type {
+            DataType::Utf8 => Some(|a, i| as_string_array(a).value(i)),
+            DataType::LargeUtf8 => Some(|a, i| as_largestring_array(a).value(i)),
+            _ => None,
+        })
+    }
+
+    /// Returns the value of column `col`, of type [`DataType::Binary`],
+    /// [`DataType::LargeBinary`] or [`DataType::FixedSizeBinary`], as a `&[u8]`
+    pub fn get_binary(&self, col: usize) -> Result<Option<&'a [u8]>, ArrowError> {
+        self.get(col, "binary", |data_type| match data_type {
+            DataType::Binary => Some(|a, i| as_generic_binary_array::<i32>(a).value(i)),
+            DataType::LargeBinary => {
+                Some(|a, i| as_generic_binary_array::<i64>(a).value(i))
+            }
+            DataType::FixedSizeBinary(_) => {
+                Some(|a, i| downcast::<FixedSizeBinaryArray>(a).value(i))
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the value of column `col`, of type [`DataType::Struct`], as a
+    /// [`RowAccessor`] over the fields of the struct
+    pub fn get_struct(&self, col: usize) -> Result<Option<RowAccessor<'a>>, ArrowError> {
+        self.get(col, "struct", |data_type| match data_type {
+            DataType::Struct(_) => Some(|a, i| {
+                RowAccessor::new(RowSource::Struct(downcast::<StructArray>(a)), i)
+            }),
+            _ => None,
+        })
+    }
+
+    /// Returns the value of column `col`, of type [`DataType::List`],
+    /// [`DataType::LargeList`] or [`DataType::FixedSizeList`], as an [`ArrayRef`]
+    pub fn get_list(&self, col: usize) -> Result<Option<ArrayRef>, ArrowError> {
+        self.get(col, "list", |data_type| match data_type {
+            DataType::List(_) => Some(|a, i| as_list_array(a).value(i)),
+            DataType::LargeList(_) => Some(|a, i| as_large_list_array(a).value(i)),
+            DataType::FixedSizeList(_, _) => {
+                Some(|a, i| downcast::<FixedSizeListArray>(a).value(i))
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the array and index holding the value of column `col`, looking
+    /// through any dictionary encoding, with `None` for a null dictionary key
+    fn resolve(&self, col: usize) -> (&'a dyn Array, Option<usize>) {
+        let array = self.column(col).as_ref();
+        downcast_dictionary_array! {
+            array => {
+                let key = array.is_valid(self.row).then(|| array.keys().value(self.row));
+                (array.values().as_ref(), key.map(|key| key.as_usize()))
+            },
+            _ => (array, Some(self.row))
+        }
+    }
+
+    /// Reads the value of column `col` with the function returned by `reader`
+    /// for its data type, or returns an error if `reader` returns `None`
+    fn get<T>(
+        &self,
+        col: usize,
+        name: &str,
+        reader: impl FnOnce(&DataType) -> Option<fn(&'a dyn Array, usize) -> T>,
+    ) -> Result<Option<T>, ArrowError> {
+        let (array, idx) = self.resolve(col);
+        let read = reader(array.data_type()).ok_or_else(|| {
+            ArrowError::CastError(format!(
+                "Cannot read column '{}' of type {} as {}",
+                self.field(col).name(),
+                self.column(col).data_type(),
+                name
+            ))
+        })?;
+        Ok(idx
+            .filter(|idx| array.is_valid(*idx))
+            .map(|idx| read(array, idx)))
+    }
+}
+
+fn downcast<T: 'static>(array: &dyn Array) -> &T {
+    array.as_any().downcast_ref().unwrap()
+}
+
+fn value<T: ArrowPrimitiveType>(array: &dyn Array, idx: usize) -> T::Native {
+    as_primitive_array::<T>(array).value(idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BinaryArray, BooleanArray, DictionaryArray, Float32Array, Int16Array, ListArray,
+        StringArray, UInt64Array,
+    };
+    use std::sync::Arc;
+
+    fn batch() -> RecordBatch {
+        let ints: ArrayRef = Arc::new(Int16Array::from(vec![Some(1), None, Some(-3)]));
+        let strs: ArrayRef =
+            Arc::new(StringArray::from(vec![Some("a"), Some("b"), None]));
+        let dict: DictionaryArray<Int32Type> =
+            vec![Some("x"), None, Some("y")].into_iter().collect();
+        let floats: ArrayRef = Arc::new(Float32Array::from(vec![1.5, 2.5, 3.5]));
+        let bools: ArrayRef = Arc::new(BooleanArray::from(vec![true, false, true]));
+        let bins: ArrayRef = Arc::new(BinaryArray::from(vec![&b"1"[..], b"22", b""]));
+        let list: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+                Some(vec![Some(1), Some(2)]),
+                None,
+                Some(vec![]),
+            ]));
+        let nested: ArrayRef = Arc::new(StructArray::from(vec![
+            (
+                Field::new("u", DataType::UInt64, false),
+                Arc::new(UInt64Array::from(vec![7, 8, 9])) as ArrayRef,
+            ),
+            (Field::new("s", DataType::Utf8, true), strs.clone()),
+        ]));
+
+        RecordBatch::try_from_iter([
+            ("ints", ints),
+            ("strs", strs),
+            ("dict", Arc::new(dict) as ArrayRef),
+            ("floats", floats),
+            ("bools", bools),
+            ("bins", bins),
+            ("list", list),
+            ("nested", nested),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_row_accessor() {
+        let batch = batch();
+        let rows: Vec<_> = batch.rows().collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(batch.rows().next_back().unwrap().index(), 2);
+
+        let row = rows[0];
+        assert_eq!(row.num_columns(), 8);
+        assert_eq!(row.get_i64(0).unwrap(), Some(1));
+        assert_eq!(row.get_str(1).unwrap(), Some("a"));
+        assert_eq!(row.get_str(2).unwrap(), Some("x"));
+        assert_eq!(row.get_f64(3).unwrap(), Some(1.5));
+        assert_eq!(row.get_bool(4).unwrap(), Some(true));
+        assert_eq!(row.get_binary(5).unwrap(), Some(&b"1"[..]));
+        let list = row.get_list(6).unwrap().unwrap();
+        assert_eq!(list.len(), 2);
+
+        let nested = row.get_struct(7).unwrap().unwrap();
+        assert_eq!(nested.column_index("s").unwrap(), 1);
+        assert_eq!(nested.get_u64(0).unwrap(), Some(7));
+        assert_eq!(nested.get_str(1).unwrap(), Some("a"));
+
+        let row = batch.row(1);
+        assert!(row.is_null(0));
+        assert_eq!(row.get_i64(0).unwrap(), None);
+        assert!(row.is_null(2));
+        assert_eq!(row.get_str(2).unwrap(), None);
+        assert_eq!(row.get_list(6).unwrap(), None);
+        assert!(!row.is_null(7));
+
+        let row = batch.row(2);
+        assert_eq!(row.get_i64(0).unwrap(), Some(-3));
+        assert_eq!(row.get_str(1).unwrap(), None);
+        assert_eq!(row.get_str(2).unwrap(), Some("y"));
+        assert_eq!(
+            row.get_struct(7).unwrap().unwrap().get_str(1).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_row_accessor_errors() {
+        let batch = batch();
+        let row = batch.row(1);
+
+        // Types are checked even when the value is null
+        let err = row.get_str(0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot read column 'ints' of type Int16 as str"
+        );
+        let err = row.get_u64(0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot read column 'ints' of type Int16 as u64"
+        );
+        let err = row.get_i64(2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot read column 'dict' of type Dictionary(Int32, Utf8) as i64"
+        );
+        assert!(row.column_index("missing").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "row index 3 out of bounds for batch with 3 rows")]
+    fn test_row_out_of_bounds() {
+        batch().row(3);
+    }
+}
@@ -18,14 +18,15 @@
 //! Zero-sized types used to parameterize generic array implementations
 
 use crate::array::ArrowPrimitiveType;
-use crate::delta::shift_months;
+use crate::delta::{checked_shift_months, shift_months};
+use crate::temporal_conversions::time_unit_multiple;
 use arrow_buffer::i256;
 use arrow_data::decimal::{
     DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE, DECIMAL256_MAX_PRECISION,
     DECIMAL256_MAX_SCALE, DECIMAL_DEFAULT_SCALE,
 };
-use arrow_schema::{DataType, IntervalUnit, TimeUnit};
-use chrono::{Duration, NaiveDate};
+use arrow_schema::{ArrowError, DataType, IntervalUnit, TimeUnit};
+use chrono::{Duration, NaiveDate, NaiveDateTime};
 use half::f16;
 use std::ops::{Add, Sub};
 
@@ -388,6 +389,57 @@ impl Date32Type {
         let res = res.add(Duration::nanoseconds(nanos));
         Date32Type::from_naive_date(res)
     }
+
+    /// Subtracts the given IntervalYearMonthType from an arrow Date32Type
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date on which to perform the operation
+    /// * `delta` - The interval to subtract
+    pub fn subtract_year_months(
+        date: <Date32Type as ArrowPrimitiveType>::Native,
+        delta: <IntervalYearMonthType as ArrowPrimitiveType>::Native,
+    ) -> <Date32Type as ArrowPrimitiveType>::Native {
+        let prior = Date32Type::to_naive_date(date);
+        let months = IntervalYearMonthType::to_months(delta);
+        let posterior = shift_months(prior, -months);
+        Date32Type::from_naive_date(posterior)
+    }
+
+    /// Subtracts the given IntervalDayTimeType from an arrow Date32Type
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date on which to perform the operation
+    /// * `delta` - The interval to subtract
+    pub fn subtract_day_time(
+        date: <Date32Type as ArrowPrimitiveType>::Native,
+        delta: <IntervalDayTimeType as ArrowPrimitiveType>::Native,
+    ) -> <Date32Type as ArrowPrimitiveType>::Native {
+        let (days, ms) = IntervalDayTimeType::to_parts(delta);
+        let res = Date32Type::to_naive_date(date);
+        let res = res.sub(Duration::days(days as i64));
+        let res = res.sub(Duration::milliseconds(ms as i64));
+        Date32Type::from_naive_date(res)
+    }
+
+    /// Subtracts the given IntervalMonthDayNanoType from an arrow Date32Type
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date on which to perform the operation
+    /// * `delta` - The interval to subtract
+    pub fn subtract_month_day_nano(
+        date: <Date32Type as ArrowPrimitiveType>::Native,
+        delta: <IntervalMonthDayNanoType as ArrowPrimitiveType>::Native,
+    ) -> <Date32Type as ArrowPrimitiveType>::Native {
+        let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(delta);
+        let res = Date32Type::to_naive_date(date);
+        let res = shift_months(res, -months);
+        let res = res.sub(Duration::days(days as i64));
+        let res = res.sub(Duration::nanoseconds(nanos));
+        Date32Type::from_naive_date(res)
+    }
 }
 
 impl Date64Type {
@@ -461,8 +513,227 @@ impl Date64Type {
         let res = res.add(Duration::nanoseconds(nanos));
         Date64Type::from_naive_date(res)
     }
+
+    /// Subtracts the given IntervalYearMonthType from an arrow Date64Type
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date on which to perform the operation
+    /// * `delta` - The interval to subtract
+    pub fn subtract_year_months(
+        date: <Date64Type as ArrowPrimitiveType>::Native,
+        delta: <IntervalYearMonthType as ArrowPrimitiveType>::Native,
+    ) -> <Date64Type as ArrowPrimitiveType>::Native {
+        let prior = Date64Type::to_naive_date(date);
+        let months = IntervalYearMonthType::to_months(delta);
+        let posterior = shift_months(prior, -months);
+        Date64Type::from_naive_date(posterior)
+    }
+
+    /// Subtracts the given IntervalDayTimeType from an arrow Date64Type
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date on which to perform the operation
+    /// * `delta` - The interval to subtract
+    pub fn subtract_day_time(
+        date: <Date64Type as ArrowPrimitiveType>::Native,
+        delta: <IntervalDayTimeType as ArrowPrimitiveType>::Native,
+    ) -> <Date64Type as ArrowPrimitiveType>::Native {
+        let (days, ms) = IntervalDayTimeType::to_parts(delta);
+        let res = Date64Type::to_naive_date(date);
+        let res = res.sub(Duration::days(days as i64));
+        let res = res.sub(Duration::milliseconds(ms as i64));
+        Date64Type::from_naive_date(res)
+    }
+
+    /// Subtracts the given IntervalMonthDayNanoType from an arrow Date64Type
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date on which to perform the operation
+    /// * `delta` - The interval to subtract
+    pub fn subtract_month_day_nano(
+        date: <Date64Type as ArrowPrimitiveType>::Native,
+        delta: <IntervalMonthDayNanoType as ArrowPrimitiveType>::Native,
+    ) -> <Date64Type as ArrowPrimitiveType>::Native {
+        let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(delta);
+        let res = Date64Type::to_naive_date(date);
+        let res = shift_months(res, -months);
+        let res = res.sub(Duration::days(days as i64));
+        let res = res.sub(Duration::nanoseconds(nanos));
+        Date64Type::from_naive_date(res)
+    }
+}
+
+/// Adds `months`, `days` and `duration` to the timestamp of type `T`, returning an
+/// error if the result cannot be represented
+fn add_timestamp_interval<T: ArrowTimestampType>(
+    timestamp: i64,
+    months: i64,
+    days: i64,
+    duration: Duration,
+) -> Result<i64, ArrowError> {
+    let units_per_second = time_unit_multiple(&T::get_time_unit());
+    let out_of_range = || {
+        ArrowError::ComputeError(format!(
+            "Timestamp {} of {:?} out of range",
+            timestamp,
+            T::get_time_unit()
+        ))
+    };
+
+    let seconds = timestamp.div_euclid(units_per_second);
+    let nanos =
+        timestamp.rem_euclid(units_per_second) * (1_000_000_000 / units_per_second);
+    let res = NaiveDateTime::from_timestamp_opt(seconds, nanos as u32)
+        .ok_or_else(out_of_range)?;
+
+    let months = i32::try_from(months).map_err(|_| out_of_range())?;
+    let res = checked_shift_months(res, months).ok_or_else(out_of_range)?;
+    let res = res
+        .checked_add_signed(Duration::days(days))
+        .and_then(|res| res.checked_add_signed(duration))
+        .ok_or_else(out_of_range)?;
+
+    let sub_second =
+        res.timestamp_subsec_nanos() as i64 / (1_000_000_000 / units_per_second);
+    res.timestamp()
+        .checked_mul(units_per_second)
+        .and_then(|res| res.checked_add(sub_second))
+        .ok_or_else(out_of_range)
 }
 
+macro_rules! timestamp_interval_ops {
+    ($t:ty) => {
+        impl $t {
+            /// Adds the given IntervalYearMonthType to an arrow timestamp, returning an
+            /// error if the result cannot be represented
+            ///
+            /// The timestamp is interpreted as a UTC datetime, with the day of the month
+            /// clamped to the last day of the resulting month if necessary
+            ///
+            /// # Arguments
+            ///
+            /// * `timestamp` - The timestamp on which to perform the operation
+            /// * `delta` - The interval to add
+            pub fn add_year_months(
+                timestamp: i64,
+                delta: <IntervalYearMonthType as ArrowPrimitiveType>::Native,
+            ) -> Result<i64, ArrowError> {
+                let months = IntervalYearMonthType::to_months(delta);
+                add_timestamp_interval::<Self>(
+                    timestamp,
+                    months as i64,
+                    0,
+                    Duration::zero(),
+                )
+            }
+
+            /// Adds the given IntervalDayTimeType to an arrow timestamp, returning an
+            /// error if the result cannot be represented
+            ///
+            /// # Arguments
+            ///
+            /// * `timestamp` - The timestamp on which to perform the operation
+            /// * `delta` - The interval to add
+            pub fn add_day_time(
+                timestamp: i64,
+                delta: <IntervalDayTimeType as ArrowPrimitiveType>::Native,
+            ) -> Result<i64, ArrowError> {
+                let (days, ms) = IntervalDayTimeType::to_parts(delta);
+                let duration = Duration::milliseconds(ms as i64);
+                add_timestamp_interval::<Self>(timestamp, 0, days as i64, duration)
+            }
+
+            /// Adds the given IntervalMonthDayNanoType to an arrow timestamp, returning
+            /// an error if the result cannot be represented
+            ///
+            /// The months are added first, as for [`Self::add_year_months`]
+            ///
+            /// # Arguments
+            ///
+            /// * `timestamp` - The timestamp on which to perform the operation
+            /// * `delta` - The interval to add
+            pub fn add_month_day_nano(
+                timestamp: i64,
+                delta: <IntervalMonthDayNanoType as ArrowPrimitiveType>::Native,
+            ) -> Result<i64, ArrowError> {
+                let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(delta);
+                let duration = Duration::nanoseconds(nanos);
+                add_timestamp_interval::<Self>(
+                    timestamp,
+                    months as i64,
+                    days as i64,
+                    duration,
+                )
+            }
+
+            /// Subtracts the given IntervalYearMonthType from an arrow timestamp,
+            /// returning an error if the result cannot be represented
+            ///
+            /// # Arguments
+            ///
+            /// * `timestamp` - The timestamp on which to perform the operation
+            /// * `delta` - The interval to subtract
+            pub fn subtract_year_months(
+                timestamp: i64,
+                delta: <IntervalYearMonthType as ArrowPrimitiveType>::Native,
+            ) -> Result<i64, ArrowError> {
+                let months = IntervalYearMonthType::to_months(delta);
+                add_timestamp_interval::<Self>(
+                    timestamp,
+                    -(months as i64),
+                    0,
+                    Duration::zero(),
+                )
+            }
+
+            /// Subtracts the given IntervalDayTimeType from an arrow timestamp,
+            /// returning an error if the result cannot be represented
+            ///
+            /// # Arguments
+            ///
+            /// * `timestamp` - The timestamp on which to perform the operation
+            /// * `delta` - The interval to subtract
+            pub fn subtract_day_time(
+                timestamp: i64,
+                delta: <IntervalDayTimeType as ArrowPrimitiveType>::Native,
+            ) -> Result<i64, ArrowError> {
+                let (days, ms) = IntervalDayTimeType::to_parts(delta);
+                let duration = -Duration::milliseconds(ms as i64);
+                add_timestamp_interval::<Self>(timestamp, 0, -(days as i64), duration)
+            }
+
+            /// Subtracts the given IntervalMonthDayNanoType from an arrow timestamp,
+            /// returning an error if the result cannot be represented
+            ///
+            /// # Arguments
+            ///
+            /// * `timestamp` - The timestamp on which to perform the operation
+            /// * `delta` - The interval to subtract
+            pub fn subtract_month_day_nano(
+                timestamp: i64,
+                delta: <IntervalMonthDayNanoType as ArrowPrimitiveType>::Native,
+            ) -> Result<i64, ArrowError> {
+                let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(delta);
+                let duration = -Duration::nanoseconds(nanos);
+                add_timestamp_interval::<Self>(
+                    timestamp,
+                    -(months as i64),
+                    -(days as i64),
+                    duration,
+                )
+            }
+        }
+    };
+}
+
+timestamp_interval_ops!(TimestampSecondType);
+timestamp_interval_ops!(TimestampMillisecondType);
+timestamp_interval_ops!(TimestampMicrosecondType);
+timestamp_interval_ops!(TimestampNanosecondType);
+
 mod private {
     use super::*;
 
@@ -0,0 +1,230 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Compression codecs for the blocks of an Avro object container file
+
+use arrow_schema::ArrowError;
+
+/// The compression codec used for the blocks of an Avro object container file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompressionCodec {
+    /// No compression
+    #[default]
+    Null,
+    /// Raw deflate, as specified by RFC 1951
+    Deflate,
+    /// Snappy block compression, followed by the big-endian CRC32 checksum
+    /// of the uncompressed data
+    Snappy,
+}
+
+impl CompressionCodec {
+    /// Returns the codec with the given `avro.codec` name
+    pub fn from_name(name: &str) -> Result<Self, ArrowError> {
+        match name {
+            "null" => Ok(Self::Null),
+            "deflate" => Ok(Self::Deflate),
+            "snappy" => Ok(Self::Snappy),
+            _ => Err(ArrowError::NotYetImplemented(format!(
+                "Avro compression codec {}",
+                name
+            ))),
+        }
+    }
+
+    /// Returns the `avro.codec` name of this codec
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Deflate => "deflate",
+            Self::Snappy => "snappy",
+        }
+    }
+
+    /// Decompresses a block of data compressed with this codec
+    pub(crate) fn decompress(&self, block: &[u8]) -> Result<Vec<u8>, ArrowError> {
+        match self {
+            Self::Null => Ok(block.to_vec()),
+            Self::Deflate => decompress_deflate(block),
+            Self::Snappy => decompress_snappy(block),
+        }
+    }
+
+    /// Compresses a block of data with this codec
+    pub(crate) fn compress(&self, block: &[u8]) -> Result<Vec<u8>, ArrowError> {
+        match self {
+            Self::Null => Ok(block.to_vec()),
+            Self::Deflate => compress_deflate(block),
+            Self::Snappy => compress_snappy(block),
+        }
+    }
+}
+
+#[cfg(feature = "deflate")]
+fn decompress_deflate(block: &[u8]) -> Result<Vec<u8>, ArrowError> {
+    use std::io::Read;
+    let mut out = Vec::with_capacity(block.len() * 2);
+    flate2::read::DeflateDecoder::new(block)
+        .read_to_end(&mut out)
+        .map_err(|e| ArrowError::IoError(format!("Invalid deflate block: {}", e)))?;
+    Ok(out)
+}
+
+#[cfg(feature = "deflate")]
+fn compress_deflate(block: &[u8]) -> Result<Vec<u8>, ArrowError> {
+    use std::io::Write;
+    let mut encoder = flate2::write::DeflateEncoder::new(
+        Vec::with_capacity(block.len()),
+        flate2::Compression::default(),
+    );
+    encoder
+        .write_all(block)
+        .map_err(|e| ArrowError::IoError(e.to_string()))?;
+    encoder
+        .finish()
+        .map_err(|e| ArrowError::IoError(e.to_string()))
+}
+
+#[cfg(not(feature = "deflate"))]
+fn decompress_deflate(_block: &[u8]) -> Result<Vec<u8>, ArrowError> {
+    Err(feature_disabled("deflate"))
+}
+
+#[cfg(not(feature = "deflate"))]
+fn compress_deflate(_block: &[u8]) -> Result<Vec<u8>, ArrowError> {
+    Err(feature_disabled("deflate"))
+}
+
+#[cfg(feature = "snappy")]
+fn decompress_snappy(block: &[u8]) -> Result<Vec<u8>, ArrowError> {
+    let (data, checksum) = match block.len().checked_sub(4) {
+        Some(len) => block.split_at(len),
+        None => {
+            return Err(ArrowError::IoError(
+                "Snappy block too short for checksum".to_string(),
+            ))
+        }
+    };
+    let out = snap::raw::Decoder::new()
+        .decompress_vec(data)
+        .map_err(|e| ArrowError::IoError(format!("Invalid snappy block: {}", e)))?;
+    let expected = u32::from_be_bytes(checksum.try_into().unwrap());
+    let actual = crc32(&out);
+    if expected != actual {
+        return Err(ArrowError::IoError(format!(
+            "Snappy block checksum mismatch, expected {:#010x} got {:#010x}",
+            expected, actual
+        )));
+    }
+    Ok(out)
+}
+
+#[cfg(feature = "snappy")]
+fn compress_snappy(block: &[u8]) -> Result<Vec<u8>, ArrowError> {
+    let mut out = snap::raw::Encoder::new()
+        .compress_vec(block)
+        .map_err(|e| ArrowError::IoError(e.to_string()))?;
+    out.extend_from_slice(&crc32(block).to_be_bytes());
+    Ok(out)
+}
+
+#[cfg(not(feature = "snappy"))]
+fn decompress_snappy(_block: &[u8]) -> Result<Vec<u8>, ArrowError> {
+    Err(feature_disabled("snappy"))
+}
+
+#[cfg(not(feature = "snappy"))]
+fn compress_snappy(_block: &[u8]) -> Result<Vec<u8>, ArrowError> {
+    Err(feature_disabled("snappy"))
+}
+
+#[cfg(any(not(feature = "deflate"), not(feature = "snappy")))]
+fn feature_disabled(codec: &str) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "Avro codec {} requires the {} feature",
+        codec, codec
+    ))
+}
+
+/// The CRC-32 (IEEE) checksum of `data`, as used by the snappy codec
+#[cfg(feature = "snappy")]
+fn crc32(data: &[u8]) -> u32 {
+    const fn make_table() -> [u32; 256] {
+        let mut table = [0_u32; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u32;
+            let mut j = 0;
+            while j < 8 {
+                crc = match crc & 1 {
+                    1 => 0xEDB88320 ^ (crc >> 1),
+                    _ => crc >> 1,
+                };
+                j += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+        table
+    }
+    const TABLE: [u32; 256] = make_table();
+
+    !data.iter().fold(!0_u32, |crc, b| {
+        TABLE[((crc ^ *b as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+#[cfg(all(test, feature = "deflate", feature = "snappy"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codec_roundtrip() {
+        let data: Vec<u8> = (0..1000_u32).flat_map(|x| (x % 7).to_le_bytes()).collect();
+        for codec in [
+            CompressionCodec::Null,
+            CompressionCodec::Deflate,
+            CompressionCodec::Snappy,
+        ] {
+            assert_eq!(CompressionCodec::from_name(codec.name()).unwrap(), codec);
+            let compressed = codec.compress(&data).unwrap();
+            assert_eq!(codec.decompress(&compressed).unwrap(), data);
+        }
+
+        let err = CompressionCodec::from_name("lz4").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: Avro compression codec lz4"
+        );
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b"123456789"), 0xCBF43926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn test_snappy_checksum() {
+        let mut compressed = CompressionCodec::Snappy.compress(b"hello").unwrap();
+        *compressed.last_mut().unwrap() ^= 1;
+        let err = CompressionCodec::Snappy
+            .decompress(&compressed)
+            .unwrap_err();
+        assert!(err.to_string().contains("checksum mismatch"), "{}", err);
+    }
+}
@@ -0,0 +1,50 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Support for reading and writing [Apache Avro] object container files
+//! as Arrow [`RecordBatch`](arrow_array::RecordBatch)
+//!
+//! Avro types are mapped to Arrow types as follows:
+//!
+//! * A union of `null` and another type is read as a nullable field of that type
+//! * `record` is read as [`DataType::Struct`](arrow_schema::DataType::Struct)
+//! * `enum` is read as a dictionary of `Int32` keys and `Utf8` values
+//! * `map` is read as [`DataType::Map`](arrow_schema::DataType::Map) with `Utf8` keys
+//! * `fixed` is read as [`DataType::FixedSizeBinary`](arrow_schema::DataType::FixedSizeBinary)
+//! * the `decimal`, `date`, `time-*` and `*timestamp-*` logical types are read
+//!   as the corresponding Arrow decimal and temporal types
+//!
+//! Data can be read with a reader schema different from the schema it was
+//! written with, following the Avro [schema resolution] rules.
+//!
+//! [Apache Avro]: https://avro.apache.org/
+//! [schema resolution]: https://avro.apache.org/docs/1.11.1/specification/#schema-resolution
+
+mod codec;
+pub use codec::CompressionCodec;
+mod reader;
+pub use reader::{Reader, ReaderBuilder};
+pub mod schema;
+pub use schema::Schema;
+mod writer;
+pub use writer::{Writer, WriterBuilder};
+
+/// The magic bytes at the start of an Avro object container file
+const MAGIC: &[u8; 4] = b"Obj\x01";
+
+/// The length of the sync marker following the header and every data block
+const SYNC_LENGTH: usize = 16;
@@ -0,0 +1,1531 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reading Avro object container files as [`RecordBatch`]
+
+use crate::codec::CompressionCodec;
+use crate::schema::{Record, Schema};
+use crate::writer::{encode_bytes, encode_long};
+use crate::{MAGIC, SYNC_LENGTH};
+use arrow_array::builder::{BooleanBufferBuilder, BufferBuilder};
+use arrow_array::{
+    make_array, Array, ArrayRef, NullArray, RecordBatch, RecordBatchOptions, StringArray,
+};
+use arrow_buffer::{ArrowNativeType, Buffer};
+use arrow_data::{ArrayData, ArrayDataBuilder};
+use arrow_schema::{ArrowError, DataType, SchemaRef};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+
+/// Avro object container file reader builder
+#[derive(Debug, Clone)]
+pub struct ReaderBuilder {
+    batch_size: usize,
+    reader_schema: Option<Schema>,
+}
+
+impl Default for ReaderBuilder {
+    fn default() -> Self {
+        Self {
+            batch_size: 1024,
+            reader_schema: None,
+        }
+    }
+}
+
+impl ReaderBuilder {
+    /// Create a new builder for configuring Avro reading options
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{Int64Array, RecordBatch};
+    /// # use arrow_avro::{ReaderBuilder, Schema, Writer};
+    /// # let batch = RecordBatch::try_from_iter([
+    /// #     ("a", Arc::new(Int64Array::from(vec![1, 2, 3])) as _),
+    /// # ]).unwrap();
+    /// # let mut writer = Writer::try_new(Vec::new(), batch.schema()).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # let file = std::io::Cursor::new(writer.into_inner().unwrap());
+    /// // Read the data as doubles, filling in a missing field with its default
+    /// let reader_schema = Schema::parse(r#"{
+    ///     "type": "record",
+    ///     "name": "topLevelRecord",
+    ///     "fields": [
+    ///         {"name": "a", "type": "double"},
+    ///         {"name": "b", "type": "string", "default": "none"}
+    ///     ]
+    /// }"#).unwrap();
+    ///
+    /// let mut reader = ReaderBuilder::new()
+    ///     .with_batch_size(2)
+    ///     .with_reader_schema(reader_schema)
+    ///     .build(file)
+    ///     .unwrap();
+    ///
+    /// let batch = reader.next().unwrap().unwrap();
+    /// assert_eq!(batch.num_rows(), 2);
+    /// assert_eq!(batch.num_columns(), 2);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of rows per [`RecordBatch`], defaults to 1024
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the schema to resolve the data against, defaults to the schema
+    /// the file was written with
+    pub fn with_reader_schema(mut self, schema: Schema) -> Self {
+        self.reader_schema = Some(schema);
+        self
+    }
+
+    /// Create a new [`Reader`] from the provided reader, reading the file header
+    pub fn build<R: Read>(self, reader: R) -> Result<Reader<R>, ArrowError> {
+        if self.batch_size == 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "batch size must be greater than 0".to_string(),
+            ));
+        }
+
+        let mut reader = BufReader::new(reader);
+        let mut magic = [0_u8; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(ArrowError::ParseError(
+                "Not an Avro object container file".to_string(),
+            ));
+        }
+
+        let mut metadata = HashMap::new();
+        loop {
+            let count = match read_long(&mut reader)? {
+                0 => break,
+                c if c < 0 => {
+                    // The block size is not needed
+                    read_long(&mut reader)?;
+                    c.unsigned_abs()
+                }
+                c => c as u64,
+            };
+            for _ in 0..count {
+                let key = String::from_utf8(read_bytes(&mut reader)?).map_err(|_| {
+                    ArrowError::ParseError("Invalid Avro metadata key".to_string())
+                })?;
+                let value = read_bytes(&mut reader)?;
+                metadata.insert(key, value);
+            }
+        }
+
+        let mut sync = [0_u8; SYNC_LENGTH];
+        reader.read_exact(&mut sync)?;
+
+        let writer_schema = match metadata.get("avro.schema") {
+            Some(schema) => {
+                let json: Value = serde_json::from_slice(schema).map_err(|e| {
+                    ArrowError::ParseError(format!("Invalid Avro schema JSON: {}", e))
+                })?;
+                Schema::from_json(&json)?
+            }
+            None => {
+                return Err(ArrowError::ParseError(
+                    "Avro file header missing avro.schema".to_string(),
+                ))
+            }
+        };
+        let codec = match metadata.get("avro.codec") {
+            Some(codec) => CompressionCodec::from_name(&String::from_utf8_lossy(codec))?,
+            None => CompressionCodec::Null,
+        };
+
+        let reader_schema = self.reader_schema.as_ref().unwrap_or(&writer_schema);
+        let schema = Arc::new(reader_schema.to_arrow()?);
+        let decoder = match (&writer_schema, reader_schema) {
+            (Schema::Record(w), Schema::Record(r)) => RecordDecoder::try_new(w, r)?,
+            _ => {
+                return Err(ArrowError::SchemaError(
+                    "Top-level Avro schema must be a record".to_string(),
+                ))
+            }
+        };
+
+        Ok(Reader {
+            reader,
+            schema,
+            writer_schema,
+            codec,
+            sync,
+            batch_size: self.batch_size,
+            decoder,
+            block: vec![],
+            block_offset: 0,
+            block_remaining: 0,
+        })
+    }
+}
+
+/// Reads [`RecordBatch`] from an Avro object container file
+#[derive(Debug)]
+pub struct Reader<R: Read> {
+    reader: BufReader<R>,
+    schema: SchemaRef,
+    writer_schema: Schema,
+    codec: CompressionCodec,
+    sync: [u8; SYNC_LENGTH],
+    batch_size: usize,
+    decoder: RecordDecoder,
+    /// The decompressed data of the current block
+    block: Vec<u8>,
+    /// The offset of the next record in `block`
+    block_offset: usize,
+    /// The number of records remaining in `block`
+    block_remaining: u64,
+}
+
+impl<R: Read> Reader<R> {
+    /// Create a new [`Reader`] with the default options
+    pub fn try_new(reader: R) -> Result<Self, ArrowError> {
+        ReaderBuilder::new().build(reader)
+    }
+
+    /// Returns the Arrow schema of the batches produced by this reader
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns the Avro schema the file was written with
+    pub fn writer_schema(&self) -> &Schema {
+        &self.writer_schema
+    }
+
+    /// Reads the next data block, returning `false` at the end of the file
+    fn read_block(&mut self) -> Result<bool, ArrowError> {
+        let count = match read_long_or_eof(&mut self.reader)? {
+            Some(count) => count,
+            None => return Ok(false),
+        };
+        let size = read_long(&mut self.reader)?;
+        let (count, size) = match (u64::try_from(count), usize::try_from(size)) {
+            (Ok(count), Ok(size)) => (count, size),
+            _ => {
+                return Err(ArrowError::ParseError(format!(
+                    "Invalid Avro block with {} records of size {}",
+                    count, size
+                )))
+            }
+        };
+
+        let data = read_exact_vec(&mut self.reader, size, "block")?;
+        let mut sync = [0_u8; SYNC_LENGTH];
+        self.reader.read_exact(&mut sync)?;
+        if sync != self.sync {
+            return Err(ArrowError::ParseError(
+                "Avro block sync marker does not match file header".to_string(),
+            ));
+        }
+
+        self.block = self.codec.decompress(&data)?;
+        self.block_offset = 0;
+        self.block_remaining = count;
+        Ok(true)
+    }
+
+    fn read_batch(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        let mut rows = 0;
+        while rows < self.batch_size {
+            if self.block_remaining == 0 {
+                if self.block_offset != self.block.len() {
+                    return Err(ArrowError::ParseError(format!(
+                        "Found {} trailing bytes in Avro block",
+                        self.block.len() - self.block_offset
+                    )));
+                }
+                if !self.read_block()? {
+                    break;
+                }
+                continue;
+            }
+
+            let mut cursor = Cursor::new(&self.block[self.block_offset..]);
+            let to_read = (self.batch_size - rows).min(self.block_remaining as usize);
+            for _ in 0..to_read {
+                self.decoder.decode(&mut cursor)?;
+            }
+            self.block_offset = self.block.len() - cursor.remaining();
+            self.block_remaining -= to_read as u64;
+            rows += to_read;
+        }
+
+        if rows == 0 {
+            return Ok(None);
+        }
+        let columns = self.decoder.flush()?;
+        let options = RecordBatchOptions::new().with_row_count(Some(rows));
+        RecordBatch::try_new_with_options(self.schema.clone(), columns, &options)
+            .map(Some)
+    }
+}
+
+impl<R: Read> Iterator for Reader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_batch().transpose()
+    }
+}
+
+/// Reads a zig-zag encoded long from `reader`, returning `None` if at the end
+fn read_long_or_eof<R: Read>(reader: &mut R) -> Result<Option<i64>, ArrowError> {
+    let mut value = 0_u64;
+    for i in 0..10 {
+        let mut byte = [0_u8];
+        if reader.read(&mut byte)? == 0 {
+            return match i {
+                0 => Ok(None),
+                _ => Err(eof()),
+            };
+        }
+        value |= ((byte[0] & 0x7F) as u64) << (7 * i);
+        if byte[0] & 0x80 == 0 {
+            return Ok(Some(zigzag(value)));
+        }
+    }
+    Err(ArrowError::ParseError("Invalid Avro varint".to_string()))
+}
+
+fn read_long<R: Read>(reader: &mut R) -> Result<i64, ArrowError> {
+    read_long_or_eof(reader)?.ok_or_else(eof)
+}
+
+fn read_bytes<R: Read>(reader: &mut R) -> Result<Vec<u8>, ArrowError> {
+    let len = read_long(reader)?;
+    let len = usize::try_from(len)
+        .map_err(|_| ArrowError::ParseError(format!("Invalid Avro length {}", len)))?;
+    read_exact_vec(reader, len, "value")
+}
+
+/// Reads `len` bytes of `reader` into a new `Vec`, describing them as `name` in errors
+fn read_exact_vec<R: Read>(
+    reader: &mut R,
+    len: usize,
+    name: &str,
+) -> Result<Vec<u8>, ArrowError> {
+    // the length is untrusted, so only allocate as much as the input contains
+    let mut buf = Vec::new();
+    reader.take(len as u64).read_to_end(&mut buf)?;
+    if buf.len() != len {
+        return Err(ArrowError::ParseError(format!(
+            "Avro {} of size {} is truncated after {} bytes",
+            name,
+            len,
+            buf.len()
+        )));
+    }
+    Ok(buf)
+}
+
+fn zigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+fn eof() -> ArrowError {
+    ArrowError::ParseError("Unexpected end of Avro data".to_string())
+}
+
+/// A cursor over Avro binary encoded data
+#[derive(Debug)]
+struct Cursor<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    fn remaining(&self) -> usize {
+        self.buf.len()
+    }
+
+    fn get_long(&mut self) -> Result<i64, ArrowError> {
+        let mut value = 0_u64;
+        for (i, byte) in self.buf.iter().take(10).enumerate() {
+            value |= ((byte & 0x7F) as u64) << (7 * i);
+            if byte & 0x80 == 0 {
+                self.buf = &self.buf[i + 1..];
+                return Ok(zigzag(value));
+            }
+        }
+        match self.buf.len() < 10 {
+            true => Err(eof()),
+            false => Err(ArrowError::ParseError("Invalid Avro varint".to_string())),
+        }
+    }
+
+    fn get_int(&mut self) -> Result<i32, ArrowError> {
+        let value = self.get_long()?;
+        i32::try_from(value).map_err(|_| {
+            ArrowError::ParseError(format!("Avro int {} out of range", value))
+        })
+    }
+
+    fn get_len(&mut self) -> Result<usize, ArrowError> {
+        let value = self.get_long()?;
+        usize::try_from(value)
+            .map_err(|_| ArrowError::ParseError(format!("Invalid Avro length {}", value)))
+    }
+
+    fn get_fixed(&mut self, len: usize) -> Result<&'a [u8], ArrowError> {
+        if self.buf.len() < len {
+            return Err(eof());
+        }
+        let (value, remaining) = self.buf.split_at(len);
+        self.buf = remaining;
+        Ok(value)
+    }
+
+    fn get_bytes(&mut self) -> Result<&'a [u8], ArrowError> {
+        let len = self.get_len()?;
+        self.get_fixed(len)
+    }
+
+    fn get_bool(&mut self) -> Result<bool, ArrowError> {
+        match self.get_fixed(1)?[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            b => Err(ArrowError::ParseError(format!(
+                "Invalid Avro boolean {}",
+                b
+            ))),
+        }
+    }
+
+    fn get_float(&mut self) -> Result<f32, ArrowError> {
+        Ok(f32::from_le_bytes(self.get_fixed(4)?.try_into().unwrap()))
+    }
+
+    fn get_double(&mut self) -> Result<f64, ArrowError> {
+        Ok(f64::from_le_bytes(self.get_fixed(8)?.try_into().unwrap()))
+    }
+
+    /// Reads the item count of the next block of an array or map, returning 0
+    /// at the end of the array or map
+    fn get_block_count(&mut self) -> Result<usize, ArrowError> {
+        let count = self.get_long()?;
+        if count < 0 {
+            // Negative counts are followed by the size of the block in bytes
+            self.get_long()?;
+        }
+        Ok(count.unsigned_abs() as usize)
+    }
+
+    /// Skips over a value written with `schema`
+    fn skip(&mut self, schema: &Schema) -> Result<(), ArrowError> {
+        match schema {
+            Schema::Null => {}
+            Schema::Boolean => {
+                self.get_fixed(1)?;
+            }
+            Schema::Int
+            | Schema::Long
+            | Schema::Date
+            | Schema::TimeMillis
+            | Schema::TimeMicros
+            | Schema::TimestampMillis { .. }
+            | Schema::TimestampMicros { .. }
+            | Schema::Enum(_) => {
+                self.get_long()?;
+            }
+            Schema::Float => {
+                self.get_fixed(4)?;
+            }
+            Schema::Double => {
+                self.get_fixed(8)?;
+            }
+            Schema::Bytes | Schema::String => {
+                self.get_bytes()?;
+            }
+            Schema::Decimal(d) => match &d.fixed {
+                Some(f) => {
+                    self.get_fixed(f.size)?;
+                }
+                None => {
+                    self.get_bytes()?;
+                }
+            },
+            Schema::Fixed(f) => {
+                self.get_fixed(f.size)?;
+            }
+            Schema::Record(r) => {
+                for field in &r.fields {
+                    self.skip(&field.schema)?;
+                }
+            }
+            Schema::Array(items) => loop {
+                let count = self.get_long()?;
+                match count {
+                    0 => break,
+                    c if c < 0 => {
+                        let size = self.get_len()?;
+                        self.get_fixed(size)?;
+                    }
+                    c => {
+                        for _ in 0..c {
+                            self.skip(items)?;
+                        }
+                    }
+                }
+            },
+            Schema::Map(values) => loop {
+                let count = self.get_long()?;
+                match count {
+                    0 => break,
+                    c if c < 0 => {
+                        let size = self.get_len()?;
+                        self.get_fixed(size)?;
+                    }
+                    c => {
+                        for _ in 0..c {
+                            self.get_bytes()?;
+                            self.skip(values)?;
+                        }
+                    }
+                }
+            },
+            Schema::Union(variants) => {
+                let branch = self.get_long()?;
+                match usize::try_from(branch).ok().and_then(|b| variants.get(b)) {
+                    Some(variant) => self.skip(variant)?,
+                    None => return Err(invalid_branch(branch)),
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn invalid_branch(branch: i64) -> ArrowError {
+    ArrowError::ParseError(format!("Invalid Avro union branch {}", branch))
+}
+
+/// How a field of the writer schema is handled when decoding a record
+#[derive(Debug)]
+enum WriterField {
+    /// Decode into the reader field with the given index
+    Read(usize),
+    /// Skip over the value as it is not present in the reader schema
+    Skip(Schema),
+}
+
+/// Decodes Avro records into Arrow arrays
+#[derive(Debug)]
+struct RecordDecoder {
+    /// The decoders for each field of the reader schema
+    children: Vec<Decoder>,
+    /// The fields of the writer schema, in the order they are encoded
+    writer: Vec<WriterField>,
+    /// The encoded default values of reader fields not in the writer schema
+    defaults: Vec<(usize, Vec<u8>)>,
+    len: usize,
+}
+
+impl RecordDecoder {
+    fn try_new(writer: &Record, reader: &Record) -> Result<Self, ArrowError> {
+        let writer_fields = writer
+            .fields
+            .iter()
+            .map(
+                |w| match reader.fields.iter().position(|r| r.name == w.name) {
+                    Some(idx) => WriterField::Read(idx),
+                    None => WriterField::Skip(w.schema.clone()),
+                },
+            )
+            .collect();
+
+        let mut defaults = vec![];
+        let children = reader
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(idx, r)| match writer.fields.iter().find(|w| w.name == r.name) {
+                Some(w) => Decoder::try_new(&w.schema, &r.schema),
+                None => match &r.default {
+                    Some(default) => {
+                        let mut encoded = vec![];
+                        encode_default(default, &r.schema, &mut encoded)?;
+                        defaults.push((idx, encoded));
+                        Decoder::try_new(&r.schema, &r.schema)
+                    }
+                    None => Err(ArrowError::SchemaError(format!(
+                        "Field '{}' of reader schema record {} is missing from the writer schema and has no default",
+                        r.name, reader.name
+                    ))),
+                },
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            children,
+            writer: writer_fields,
+            defaults,
+            len: 0,
+        })
+    }
+
+    fn decode(&mut self, cursor: &mut Cursor<'_>) -> Result<(), ArrowError> {
+        for field in &self.writer {
+            match field {
+                WriterField::Read(idx) => self.children[*idx].decode(cursor)?,
+                WriterField::Skip(schema) => cursor.skip(schema)?,
+            }
+        }
+        for (idx, default) in &self.defaults {
+            self.children[*idx].decode(&mut Cursor::new(default))?;
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    fn append_null(&mut self) {
+        self.children.iter_mut().for_each(Decoder::append_null);
+        self.len += 1;
+    }
+
+    fn flush(&mut self) -> Result<Vec<ArrayRef>, ArrowError> {
+        self.len = 0;
+        self.children.iter_mut().map(|c| c.flush(None)).collect()
+    }
+}
+
+/// The physical type of a numeric value in the writer schema
+#[derive(Debug, Clone, Copy)]
+enum Numeric {
+    Int,
+    Long,
+    Float,
+    Double,
+}
+
+impl Numeric {
+    fn get_i64(self, cursor: &mut Cursor<'_>) -> Result<i64, ArrowError> {
+        match self {
+            Self::Int | Self::Long => cursor.get_long(),
+            Self::Float | Self::Double => unreachable!(),
+        }
+    }
+
+    fn get_f64(self, cursor: &mut Cursor<'_>) -> Result<f64, ArrowError> {
+        match self {
+            Self::Int | Self::Long => Ok(cursor.get_long()? as f64),
+            Self::Float => Ok(cursor.get_float()? as f64),
+            Self::Double => cursor.get_double(),
+        }
+    }
+}
+
+/// Decodes Avro values into an Arrow array
+#[derive(Debug)]
+enum Decoder {
+    Null(usize),
+    Boolean(BooleanBufferBuilder),
+    Int32(DataType, BufferBuilder<i32>),
+    Int64(DataType, Numeric, BufferBuilder<i64>),
+    Float32(Numeric, BufferBuilder<f32>),
+    Float64(Numeric, BufferBuilder<f64>),
+    /// A `bytes` or `string`, decoded as `DataType::Binary` or `DataType::Utf8`
+    Binary(DataType, BufferBuilder<i32>, BufferBuilder<u8>),
+    Fixed(usize, usize, BufferBuilder<u8>),
+    /// A `decimal` with the size of the writer's `fixed`, if any
+    Decimal(DataType, Option<usize>, BufferBuilder<i128>),
+    /// An `enum` with a mapping from writer to reader symbol indices
+    Enum(ArrayData, Vec<Option<i32>>, BufferBuilder<i32>),
+    Record(DataType, RecordDecoder),
+    List(DataType, BufferBuilder<i32>, Box<Decoder>),
+    Map(
+        DataType,
+        BufferBuilder<i32>,
+        BufferBuilder<i32>,
+        BufferBuilder<u8>,
+        Box<Decoder>,
+    ),
+    /// A nullable value, with the index of the `null` branch of the writer's
+    /// union or `None` if the writer schema is not a union
+    Nullable(Option<usize>, BooleanBufferBuilder, Box<Decoder>),
+}
+
+impl Decoder {
+    /// Create a [`Decoder`] for data written with `writer` and read as `reader`
+    fn try_new(writer: &Schema, reader: &Schema) -> Result<Self, ArrowError> {
+        match (writer.as_nullable(), reader.as_nullable()) {
+            (Some((null_idx, w)), Some((_, r))) => {
+                let decoder = Self::try_new(w, r)?;
+                return Ok(Self::Nullable(
+                    Some(null_idx),
+                    BooleanBufferBuilder::new(0),
+                    Box::new(decoder),
+                ));
+            }
+            (None, Some((_, r))) => {
+                let decoder = Self::try_new(writer, r)?;
+                return Ok(Self::Nullable(
+                    None,
+                    BooleanBufferBuilder::new(0),
+                    Box::new(decoder),
+                ));
+            }
+            _ => {}
+        }
+
+        let numeric = match writer {
+            Schema::Int => Some(Numeric::Int),
+            Schema::Long => Some(Numeric::Long),
+            Schema::Float => Some(Numeric::Float),
+            Schema::Double => Some(Numeric::Double),
+            _ => None,
+        };
+
+        Ok(match (writer, reader) {
+            (Schema::Null, Schema::Null) => Self::Null(0),
+            (Schema::Boolean, Schema::Boolean) => {
+                Self::Boolean(BooleanBufferBuilder::new(0))
+            }
+            (Schema::Int, Schema::Int | Schema::Date | Schema::TimeMillis) => {
+                Self::Int32(reader.to_arrow_type()?, BufferBuilder::new(0))
+            }
+            (_, Schema::Date | Schema::TimeMillis) if writer == reader => {
+                Self::Int32(reader.to_arrow_type()?, BufferBuilder::new(0))
+            }
+            (
+                Schema::Int | Schema::Long,
+                Schema::Long
+                | Schema::TimeMicros
+                | Schema::TimestampMillis { .. }
+                | Schema::TimestampMicros { .. },
+            ) => Self::Int64(
+                reader.to_arrow_type()?,
+                numeric.unwrap(),
+                BufferBuilder::new(0),
+            ),
+            (
+                _,
+                Schema::TimeMicros
+                | Schema::TimestampMillis { .. }
+                | Schema::TimestampMicros { .. },
+            ) if writer == reader => Self::Int64(
+                reader.to_arrow_type()?,
+                Numeric::Long,
+                BufferBuilder::new(0),
+            ),
+            (Schema::Int | Schema::Long | Schema::Float, Schema::Float) => {
+                Self::Float32(numeric.unwrap(), BufferBuilder::new(0))
+            }
+            (
+                Schema::Int | Schema::Long | Schema::Float | Schema::Double,
+                Schema::Double,
+            ) => Self::Float64(numeric.unwrap(), BufferBuilder::new(0)),
+            (Schema::Bytes | Schema::String, Schema::Bytes | Schema::String) => {
+                let mut offsets = BufferBuilder::new(1);
+                offsets.append(0);
+                Self::Binary(reader.to_arrow_type()?, offsets, BufferBuilder::new(0))
+            }
+            (Schema::Fixed(w), Schema::Fixed(r)) if w.size == r.size => {
+                Self::Fixed(r.size, 0, BufferBuilder::new(0))
+            }
+            (Schema::Decimal(w), Schema::Decimal(r))
+                if w.precision == r.precision && w.scale == r.scale =>
+            {
+                let size = w.fixed.as_ref().map(|f| f.size);
+                Self::Decimal(reader.to_arrow_type()?, size, BufferBuilder::new(0))
+            }
+            (Schema::Enum(w), Schema::Enum(r)) => {
+                let mapping = w
+                    .symbols
+                    .iter()
+                    .map(|s| r.symbols.iter().position(|x| x == s).map(|i| i as i32))
+                    .collect();
+                let values = StringArray::from_iter_values(&r.symbols);
+                Self::Enum(values.data().clone(), mapping, BufferBuilder::new(0))
+            }
+            (Schema::Record(w), Schema::Record(r)) => {
+                Self::Record(reader.to_arrow_type()?, RecordDecoder::try_new(w, r)?)
+            }
+            (Schema::Array(w), Schema::Array(r)) => {
+                let mut offsets = BufferBuilder::new(1);
+                offsets.append(0);
+                let items = Self::try_new(w, r)?;
+                Self::List(reader.to_arrow_type()?, offsets, Box::new(items))
+            }
+            (Schema::Map(w), Schema::Map(r)) => {
+                let mut offsets = BufferBuilder::new(1);
+                offsets.append(0);
+                let mut key_offsets = BufferBuilder::new(1);
+                key_offsets.append(0);
+                let values = Self::try_new(w, r)?;
+                Self::Map(
+                    reader.to_arrow_type()?,
+                    offsets,
+                    key_offsets,
+                    BufferBuilder::new(0),
+                    Box::new(values),
+                )
+            }
+            (Schema::Union(_), _) | (_, Schema::Union(_)) => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Resolving Avro union {} as {}",
+                    writer.to_json(),
+                    reader.to_json()
+                )))
+            }
+            _ => {
+                return Err(ArrowError::SchemaError(format!(
+                    "Cannot resolve Avro writer schema {} as reader schema {}",
+                    writer.to_json(),
+                    reader.to_json()
+                )))
+            }
+        })
+    }
+
+    fn decode(&mut self, cursor: &mut Cursor<'_>) -> Result<(), ArrowError> {
+        match self {
+            Self::Null(len) => *len += 1,
+            Self::Boolean(values) => values.append(cursor.get_bool()?),
+            Self::Int32(_, values) => values.append(cursor.get_int()?),
+            Self::Int64(_, numeric, values) => values.append(numeric.get_i64(cursor)?),
+            Self::Float32(numeric, values) => match numeric {
+                Numeric::Float => values.append(cursor.get_float()?),
+                _ => values.append(numeric.get_f64(cursor)? as f32),
+            },
+            Self::Float64(numeric, values) => values.append(numeric.get_f64(cursor)?),
+            Self::Binary(_, offsets, values) => {
+                values.append_slice(cursor.get_bytes()?);
+                offsets.append(offset(values.len())?);
+            }
+            Self::Fixed(size, len, values) => {
+                values.append_slice(cursor.get_fixed(*size)?);
+                *len += 1;
+            }
+            Self::Decimal(_, size, values) => {
+                let bytes = match size {
+                    Some(size) => cursor.get_fixed(*size)?,
+                    None => cursor.get_bytes()?,
+                };
+                values.append(decode_decimal(bytes)?);
+            }
+            Self::Enum(_, mapping, keys) => {
+                let idx = cursor.get_int()?;
+                match usize::try_from(idx).ok().and_then(|i| mapping.get(i)) {
+                    Some(Some(key)) => keys.append(*key),
+                    Some(None) => {
+                        return Err(ArrowError::ParseError(format!(
+                            "Avro enum symbol {} not present in reader schema",
+                            idx
+                        )))
+                    }
+                    None => {
+                        return Err(ArrowError::ParseError(format!(
+                            "Invalid Avro enum index {}",
+                            idx
+                        )))
+                    }
+                }
+            }
+            Self::Record(_, decoder) => decoder.decode(cursor)?,
+            Self::List(_, offsets, items) => {
+                let mut len = offsets.as_slice().last().copied().unwrap() as usize;
+                loop {
+                    let count = cursor.get_block_count()?;
+                    if count == 0 {
+                        break;
+                    }
+                    for _ in 0..count {
+                        items.decode(cursor)?;
+                    }
+                    len += count;
+                }
+                offsets.append(offset(len)?);
+            }
+            Self::Map(_, offsets, key_offsets, key_values, values) => {
+                let mut len = offsets.as_slice().last().copied().unwrap() as usize;
+                loop {
+                    let count = cursor.get_block_count()?;
+                    if count == 0 {
+                        break;
+                    }
+                    for _ in 0..count {
+                        key_values.append_slice(cursor.get_bytes()?);
+                        key_offsets.append(offset(key_values.len())?);
+                        values.decode(cursor)?;
+                    }
+                    len += count;
+                }
+                offsets.append(offset(len)?);
+            }
+            Self::Nullable(null_idx, nulls, decoder) => {
+                let is_null = match null_idx {
+                    Some(null_idx) => match cursor.get_long()? {
+                        b @ (0 | 1) => b as usize == *null_idx,
+                        b => return Err(invalid_branch(b)),
+                    },
+                    None => false,
+                };
+                nulls.append(!is_null);
+                match is_null {
+                    true => decoder.append_null(),
+                    false => decoder.decode(cursor)?,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends a placeholder value for a null slot
+    fn append_null(&mut self) {
+        match self {
+            Self::Null(len) => *len += 1,
+            Self::Boolean(values) => values.append(false),
+            Self::Int32(_, values) => values.append(0),
+            Self::Int64(_, _, values) => values.append(0),
+            Self::Float32(_, values) => values.append(0.),
+            Self::Float64(_, values) => values.append(0.),
+            Self::Binary(_, offsets, values) => offsets.append(values.len() as i32),
+            Self::Fixed(size, len, values) => {
+                values.append_n_zeroed(*size);
+                *len += 1;
+            }
+            Self::Decimal(_, _, values) => values.append(0),
+            Self::Enum(_, _, keys) => keys.append(0),
+            Self::Record(_, decoder) => decoder.append_null(),
+            Self::List(_, offsets, _) | Self::Map(_, offsets, _, _, _) => {
+                let last = offsets.as_slice().last().copied().unwrap();
+                offsets.append(last)
+            }
+            Self::Nullable(_, nulls, decoder) => {
+                nulls.append(false);
+                decoder.append_null()
+            }
+        }
+    }
+
+    /// Returns the decoded values as an array, resetting the decoder
+    fn flush(&mut self, nulls: Option<Buffer>) -> Result<ArrayRef, ArrowError> {
+        let data = match self {
+            Self::Null(len) => {
+                let len = std::mem::take(len);
+                return Ok(Arc::new(NullArray::new(len)));
+            }
+            Self::Boolean(values) => {
+                let len = values.len();
+                ArrayDataBuilder::new(DataType::Boolean)
+                    .len(len)
+                    .add_buffer(values.finish())
+            }
+            Self::Int32(data_type, values) => flush_values(data_type.clone(), values),
+            Self::Int64(data_type, _, values) => flush_values(data_type.clone(), values),
+            Self::Float32(_, values) => flush_values(DataType::Float32, values),
+            Self::Float64(_, values) => flush_values(DataType::Float64, values),
+            Self::Decimal(data_type, _, values) => {
+                flush_values(data_type.clone(), values)
+            }
+            Self::Binary(data_type, offsets, values) => {
+                let len = offsets.len() - 1;
+                let offsets = flush_offsets(offsets);
+                ArrayDataBuilder::new(data_type.clone())
+                    .len(len)
+                    .add_buffer(offsets)
+                    .add_buffer(values.finish())
+            }
+            Self::Fixed(size, len, values) => {
+                ArrayDataBuilder::new(DataType::FixedSizeBinary(*size as i32))
+                    .len(std::mem::take(len))
+                    .add_buffer(values.finish())
+            }
+            Self::Enum(values, _, keys) => {
+                let len = keys.len();
+                let data_type = DataType::Dictionary(
+                    Box::new(DataType::Int32),
+                    Box::new(DataType::Utf8),
+                );
+                ArrayDataBuilder::new(data_type)
+                    .len(len)
+                    .add_buffer(keys.finish())
+                    .add_child_data(values.clone())
+            }
+            Self::Record(data_type, decoder) => {
+                let len = decoder.len;
+                let children = decoder.flush()?;
+                ArrayDataBuilder::new(data_type.clone())
+                    .len(len)
+                    .child_data(children.iter().map(|c| c.data().clone()).collect())
+            }
+            Self::List(data_type, offsets, items) => {
+                let len = offsets.len() - 1;
+                let offsets = flush_offsets(offsets);
+                let items = items.flush(None)?;
+                ArrayDataBuilder::new(data_type.clone())
+                    .len(len)
+                    .add_buffer(offsets)
+                    .add_child_data(items.data().clone())
+            }
+            Self::Map(data_type, offsets, key_offsets, key_values, values) => {
+                let len = offsets.len() - 1;
+                let offsets = flush_offsets(offsets);
+
+                let entries_len = key_offsets.len() - 1;
+                let keys = ArrayDataBuilder::new(DataType::Utf8)
+                    .len(entries_len)
+                    .add_buffer(flush_offsets(key_offsets))
+                    .add_buffer(key_values.finish())
+                    .build()?;
+                let values = values.flush(None)?;
+                let entries_type = match data_type {
+                    DataType::Map(entries, _) => entries.data_type().clone(),
+                    _ => unreachable!(),
+                };
+                let entries = ArrayDataBuilder::new(entries_type)
+                    .len(entries_len)
+                    .child_data(vec![keys, values.data().clone()])
+                    .build()?;
+
+                ArrayDataBuilder::new(data_type.clone())
+                    .len(len)
+                    .add_buffer(offsets)
+                    .add_child_data(entries)
+            }
+            Self::Nullable(_, nulls, decoder) => {
+                return decoder.flush(Some(nulls.finish()));
+            }
+        };
+        Ok(make_array(data.null_bit_buffer(nulls).build()?))
+    }
+}
+
+fn flush_values<T: ArrowNativeType>(
+    data_type: DataType,
+    values: &mut BufferBuilder<T>,
+) -> ArrayDataBuilder {
+    let len = values.len();
+    ArrayDataBuilder::new(data_type)
+        .len(len)
+        .add_buffer(values.finish())
+}
+
+/// Returns the offsets buffer, resetting `offsets` to contain a single 0
+fn flush_offsets(offsets: &mut BufferBuilder<i32>) -> Buffer {
+    let buffer = offsets.finish();
+    offsets.append(0);
+    buffer
+}
+
+fn offset(len: usize) -> Result<i32, ArrowError> {
+    i32::try_from(len).map_err(|_| {
+        ArrowError::ParseError("Avro data too large for 32-bit offsets".to_string())
+    })
+}
+
+/// Decodes a big-endian two's complement decimal
+fn decode_decimal(bytes: &[u8]) -> Result<i128, ArrowError> {
+    if bytes.len() > 16 {
+        return Err(ArrowError::ParseError(format!(
+            "Avro decimal of {} bytes does not fit in 128 bits",
+            bytes.len()
+        )));
+    }
+    let negative = bytes.first().map(|b| b & 0x80 != 0).unwrap_or_default();
+    let mut buf = [if negative { 0xFF } else { 0 }; 16];
+    buf[16 - bytes.len()..].copy_from_slice(bytes);
+    Ok(i128::from_be_bytes(buf))
+}
+
+/// Encodes the JSON default `value` of a field with `schema` as Avro binary
+fn encode_default(
+    value: &Value,
+    schema: &Schema,
+    out: &mut Vec<u8>,
+) -> Result<(), ArrowError> {
+    let invalid = || {
+        ArrowError::SchemaError(format!(
+            "Invalid default {} for Avro schema {}",
+            value,
+            schema.to_json()
+        ))
+    };
+    match (schema, value) {
+        (Schema::Null, Value::Null) => {}
+        (Schema::Boolean, Value::Bool(b)) => out.push(*b as u8),
+        (
+            Schema::Int
+            | Schema::Long
+            | Schema::Date
+            | Schema::TimeMillis
+            | Schema::TimeMicros
+            | Schema::TimestampMillis { .. }
+            | Schema::TimestampMicros { .. },
+            Value::Number(n),
+        ) => encode_long(n.as_i64().ok_or_else(invalid)?, out),
+        (Schema::Float, Value::Number(n)) => {
+            let v = n.as_f64().ok_or_else(invalid)? as f32;
+            out.extend_from_slice(&v.to_le_bytes())
+        }
+        (Schema::Double, Value::Number(n)) => {
+            let v = n.as_f64().ok_or_else(invalid)?;
+            out.extend_from_slice(&v.to_le_bytes())
+        }
+        (Schema::String, Value::String(s)) => encode_bytes(s.as_bytes(), out),
+        // Binary defaults are strings of code points 0-255, one per byte
+        (Schema::Bytes | Schema::Fixed(_) | Schema::Decimal(_), Value::String(s)) => {
+            let bytes = s
+                .chars()
+                .map(|c| u8::try_from(c).map_err(|_| invalid()))
+                .collect::<Result<Vec<_>, _>>()?;
+            match schema {
+                Schema::Fixed(f)
+                | Schema::Decimal(crate::schema::Decimal { fixed: Some(f), .. }) => {
+                    if bytes.len() != f.size {
+                        return Err(invalid());
+                    }
+                    out.extend_from_slice(&bytes)
+                }
+                _ => encode_bytes(&bytes, out),
+            }
+        }
+        (Schema::Enum(e), Value::String(s)) => {
+            let idx = e.symbols.iter().position(|x| x == s).ok_or_else(invalid)?;
+            encode_long(idx as i64, out)
+        }
+        (Schema::Record(r), Value::Object(o)) => {
+            for field in &r.fields {
+                match o.get(&field.name).or(field.default.as_ref()) {
+                    Some(v) => encode_default(v, &field.schema, out)?,
+                    None => return Err(invalid()),
+                }
+            }
+        }
+        (Schema::Array(items), Value::Array(a)) => {
+            if !a.is_empty() {
+                encode_long(a.len() as i64, out);
+                for v in a {
+                    encode_default(v, items, out)?;
+                }
+            }
+            encode_long(0, out)
+        }
+        (Schema::Map(values), Value::Object(o)) => {
+            if !o.is_empty() {
+                encode_long(o.len() as i64, out);
+                for (k, v) in o {
+                    encode_bytes(k.as_bytes(), out);
+                    encode_default(v, values, out)?;
+                }
+            }
+            encode_long(0, out)
+        }
+        // The default of a union corresponds to its first branch
+        (Schema::Union(variants), _) if !variants.is_empty() => {
+            encode_long(0, out);
+            encode_default(value, &variants[0], out)?
+        }
+        _ => return Err(invalid()),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::WriterBuilder;
+    use arrow_array::builder::{Int32Builder, MapBuilder, MapFieldNames, StringBuilder};
+    use arrow_array::cast::*;
+    use arrow_array::types::*;
+    use arrow_array::*;
+    use arrow_schema::{Field, TimeUnit};
+
+    fn write(batches: &[RecordBatch], codec: CompressionCodec) -> Vec<u8> {
+        let mut writer = WriterBuilder::new()
+            .with_codec(codec)
+            .build(vec![], batches[0].schema())
+            .unwrap();
+        for batch in batches {
+            writer.write(batch).unwrap();
+        }
+        writer.into_inner().unwrap()
+    }
+
+    fn read(data: &[u8], builder: ReaderBuilder) -> Vec<RecordBatch> {
+        builder
+            .build(data)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut map = MapBuilder::new(
+            Some(MapFieldNames {
+                entry: "entries".to_string(),
+                key: "key".to_string(),
+                value: "value".to_string(),
+            }),
+            StringBuilder::new(),
+            Int32Builder::new(),
+        );
+        map.keys().append_value("a");
+        map.values().append_value(1);
+        map.keys().append_value("b");
+        map.values().append_null();
+        map.append(true).unwrap();
+        map.append(false).unwrap();
+        map.append(true).unwrap();
+        let map = map.finish();
+
+        let list = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+        ]);
+        let strukt = StructArray::from(vec![
+            (
+                Field::new("x", DataType::Float64, false),
+                Arc::new(Float64Array::from(vec![1.5, 2.5, 3.5])) as ArrayRef,
+            ),
+            (
+                Field::new("y", DataType::Utf8, true),
+                Arc::new(StringArray::from(vec![Some("a"), None, Some("c")])) as ArrayRef,
+            ),
+        ]);
+        let decimal = [Some(12345_i128), None, Some(-1)]
+            .into_iter()
+            .collect::<Decimal128Array>()
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+
+        let batch = RecordBatch::try_from_iter_with_nullable([
+            (
+                "bool",
+                Arc::new(BooleanArray::from(vec![true, false, true])) as ArrayRef,
+                false,
+            ),
+            (
+                "int",
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(-3)])) as _,
+                true,
+            ),
+            (
+                "long",
+                Arc::new(Int64Array::from(vec![i64::MIN, 0, i64::MAX])) as _,
+                false,
+            ),
+            (
+                "float",
+                Arc::new(Float32Array::from(vec![1., 2., 3.])) as _,
+                false,
+            ),
+            (
+                "binary",
+                Arc::new(BinaryArray::from(vec![&b"a"[..], b"", b"c"])) as _,
+                false,
+            ),
+            (
+                "string",
+                Arc::new(StringArray::from(vec!["x", "yy", "zzz"])) as _,
+                false,
+            ),
+            (
+                "date",
+                Arc::new(Date32Array::from(vec![1, 2, 3])) as _,
+                false,
+            ),
+            (
+                "time",
+                Arc::new(Time64MicrosecondArray::from(vec![1, 2, 3])) as _,
+                false,
+            ),
+            (
+                "ts",
+                Arc::new(
+                    TimestampMillisecondArray::from(vec![Some(1), None, Some(3)])
+                        .with_timezone("+00:00".to_string()),
+                ) as _,
+                true,
+            ),
+            ("decimal", Arc::new(decimal) as _, true),
+            (
+                "fixed",
+                Arc::new(
+                    FixedSizeBinaryArray::try_from_iter(
+                        vec![b"ab", b"cd", b"ef"].into_iter(),
+                    )
+                    .unwrap(),
+                ) as _,
+                false,
+            ),
+            ("list", Arc::new(list) as _, true),
+            ("struct", Arc::new(strukt) as _, false),
+            ("map", Arc::new(map) as _, true),
+        ])
+        .unwrap();
+
+        for codec in [
+            CompressionCodec::Null,
+            CompressionCodec::Deflate,
+            CompressionCodec::Snappy,
+        ] {
+            let data = write(&[batch.clone(), batch.slice(1, 2)], codec);
+            let batches = read(&data, ReaderBuilder::new());
+            assert_eq!(batches.len(), 1);
+            assert_eq!(batches[0].num_rows(), 5);
+            assert_eq!(batches[0].slice(0, 3), batch);
+            assert_eq!(batches[0].slice(3, 2), batch.slice(1, 2));
+
+            // Batches can span blocks
+            let batches = read(&data, ReaderBuilder::new().with_batch_size(2));
+            let rows: Vec<_> = batches.iter().map(|b| b.num_rows()).collect();
+            assert_eq!(rows, vec![2, 2, 1]);
+            assert_eq!(batches[1].slice(0, 1), batch.slice(2, 1));
+            assert_eq!(batches[1].slice(1, 1), batch.slice(1, 1));
+        }
+    }
+
+    #[test]
+    fn test_enum() {
+        // [Obj\x01, metadata, sync, block] written by hand
+        let schema = r#"{"type":"record","name":"r","fields":[{"name":"e","type":{"type":"enum","name":"E","symbols":["A","B","C"]}}]}"#;
+        let sync = [7_u8; 16];
+        let mut data = MAGIC.to_vec();
+        encode_long(1, &mut data);
+        encode_bytes(b"avro.schema", &mut data);
+        encode_bytes(schema.as_bytes(), &mut data);
+        encode_long(0, &mut data);
+        data.extend_from_slice(&sync);
+        let mut block = vec![];
+        for idx in [2, 0, 2] {
+            encode_long(idx, &mut block);
+        }
+        encode_long(3, &mut data);
+        encode_long(block.len() as i64, &mut data);
+        data.extend_from_slice(&block);
+        data.extend_from_slice(&sync);
+
+        let batches = read(&data, ReaderBuilder::new());
+        let dict = as_dictionary_array::<Int32Type>(batches[0].column(0));
+        assert_eq!(dict.keys(), &Int32Array::from(vec![2, 0, 2]));
+        assert_eq!(as_string_array(dict.values()).value(2), "C");
+
+        // Resolve against reordered symbols
+        let reader_schema = Schema::parse(
+            r#"{"type":"record","name":"r","fields":[{"name":"e","type":{"type":"enum","name":"E","symbols":["C","A"]}}]}"#,
+        )
+        .unwrap();
+        let batches = read(
+            &data,
+            ReaderBuilder::new().with_reader_schema(reader_schema),
+        );
+        let dict = as_dictionary_array::<Int32Type>(batches[0].column(0));
+        assert_eq!(dict.keys(), &Int32Array::from(vec![0, 1, 0]));
+
+        // Symbols missing from the reader schema are an error
+        let reader_schema = Schema::parse(
+            r#"{"type":"record","name":"r","fields":[{"name":"e","type":{"type":"enum","name":"E","symbols":["A"]}}]}"#,
+        )
+        .unwrap();
+        let mut reader = ReaderBuilder::new()
+            .with_reader_schema(reader_schema)
+            .build(data.as_slice())
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Avro enum symbol 2 not present in reader schema"
+        );
+    }
+
+    #[test]
+    fn test_schema_resolution() {
+        let batch = RecordBatch::try_from_iter_with_nullable([
+            (
+                "a",
+                Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+                false,
+            ),
+            ("b", Arc::new(StringArray::from(vec!["x", "y"])) as _, false),
+            (
+                "c",
+                Arc::new(Float32Array::from(vec![Some(1.5), None])) as _,
+                true,
+            ),
+        ])
+        .unwrap();
+        let data = write(&[batch], CompressionCodec::Null);
+
+        let reader_schema = Schema::parse(
+            r#"{
+                "type": "record",
+                "name": "topLevelRecord",
+                "fields": [
+                    {"name": "c", "type": ["null", "double"]},
+                    {"name": "a", "type": ["null", "long"]},
+                    {"name": "d", "type": {"type": "map", "values": "int"}, "default": {"k": 5}},
+                    {"name": "e", "type": ["null", "string"], "default": null},
+                    {"name": "f", "type": "bytes", "default": "ÿ"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let batches = read(
+            &data,
+            ReaderBuilder::new().with_reader_schema(reader_schema),
+        );
+        let batch = &batches[0];
+        assert_eq!(batch.num_columns(), 5);
+        assert_eq!(
+            as_primitive_array::<Float64Type>(batch.column(0)),
+            &Float64Array::from(vec![Some(1.5), None])
+        );
+        assert_eq!(
+            as_primitive_array::<Int64Type>(batch.column(1)),
+            &Int64Array::from(vec![1, 2])
+        );
+        assert!(batch.schema().field(1).is_nullable());
+        let map = as_map_array(batch.column(2));
+        assert_eq!(map.value_offsets(), &[0, 1, 2]);
+        assert_eq!(as_string_array(&map.keys()).value(1), "k");
+        assert_eq!(as_primitive_array::<Int32Type>(&map.values()).value(1), 5);
+        assert_eq!(batch.column(3).null_count(), 2);
+        let binary = as_generic_binary_array::<i32>(batch.column(4));
+        assert_eq!(binary.value(1), &[0xFF]);
+
+        let reader_schema = Schema::parse(
+            r#"{"type": "record", "name": "topLevelRecord", "fields": [{"name": "x", "type": "int"}]}"#,
+        )
+        .unwrap();
+        let err = ReaderBuilder::new()
+            .with_reader_schema(reader_schema)
+            .build(data.as_slice())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Field 'x' of reader schema record topLevelRecord is missing from the writer schema and has no default"
+        );
+
+        let reader_schema = Schema::parse(
+            r#"{"type": "record", "name": "topLevelRecord", "fields": [{"name": "b", "type": "int"}]}"#,
+        )
+        .unwrap();
+        let err = ReaderBuilder::new()
+            .with_reader_schema(reader_schema)
+            .build(data.as_slice())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Cannot resolve Avro writer schema \"string\" as reader schema \"int\""
+        );
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        let err = Reader::try_new(&b"Obj\x02"[..]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Not an Avro object container file"
+        );
+
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef,
+        )])
+        .unwrap();
+        let mut data = write(&[batch], CompressionCodec::Null);
+        let len = data.len();
+        data[len - 1] ^= 1;
+        let mut reader = Reader::try_new(data.as_slice()).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Avro block sync marker does not match file header"
+        );
+
+        let mut reader = Reader::try_new(&data[..len - 5]).unwrap();
+        assert!(reader.next().unwrap().is_err());
+
+        // a block claiming to be larger than the input
+        data[len - 1] ^= 1;
+        let sync = &data[len - SYNC_LENGTH..];
+        let header_len = data
+            .windows(SYNC_LENGTH)
+            .position(|window| window == sync)
+            .unwrap()
+            + SYNC_LENGTH;
+        let mut data = data[..header_len].to_vec();
+        crate::writer::encode_long(1, &mut data);
+        crate::writer::encode_long(i64::MAX, &mut data);
+        data.extend_from_slice(&[0; 4]);
+        let mut reader = Reader::try_new(data.as_slice()).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Parser error: Avro block of size {} is truncated after 4 bytes",
+                i64::MAX
+            )
+        );
+
+        // a header metadata value claiming to be larger than the input
+        let mut data = MAGIC.to_vec();
+        crate::writer::encode_long(1, &mut data);
+        crate::writer::encode_long(1, &mut data);
+        data.push(b'k');
+        crate::writer::encode_long(i64::MAX, &mut data);
+        data.extend_from_slice(&[0; 4]);
+        let err = Reader::try_new(data.as_slice()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Parser error: Avro value of size {} is truncated after 4 bytes",
+                i64::MAX
+            )
+        );
+    }
+
+    #[test]
+    fn test_timestamps() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "local",
+                Arc::new(TimestampMicrosecondArray::from(vec![1, -1])) as ArrayRef,
+            ),
+            (
+                "time",
+                Arc::new(Time32MillisecondArray::from(vec![5, 6])) as _,
+            ),
+        ])
+        .unwrap();
+        let data = write(std::slice::from_ref(&batch), CompressionCodec::Null);
+        let reader = Reader::try_new(data.as_slice()).unwrap();
+        assert_eq!(
+            reader.schema().field(0).data_type(),
+            &DataType::Timestamp(TimeUnit::Microsecond, None)
+        );
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches, vec![batch]);
+    }
+}
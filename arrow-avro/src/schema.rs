@@ -0,0 +1,893 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Avro schemas and their conversion to and from Arrow schemas
+
+use arrow_schema::{ArrowError, DataType, Field, TimeUnit};
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, HashSet};
+
+/// The name of the single Arrow field in a [`DataType::Map`] entry
+const MAP_ENTRIES: &str = "entries";
+
+/// The default name of the top-level record written by [`Schema::try_from_arrow`]
+const TOP_LEVEL_RECORD: &str = "topLevelRecord";
+
+/// An [Avro schema](https://avro.apache.org/docs/1.11.1/specification/#schema-declaration)
+///
+/// Logical types are parsed into dedicated variants, while unknown logical types
+/// fall back to their underlying type as required by the specification.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Schema {
+    /// No value
+    Null,
+    /// A binary value
+    Boolean,
+    /// A 32-bit signed integer
+    Int,
+    /// A 64-bit signed integer
+    Long,
+    /// A single precision IEEE 754 floating-point number
+    Float,
+    /// A double precision IEEE 754 floating-point number
+    Double,
+    /// A sequence of bytes
+    Bytes,
+    /// A sequence of UTF-8 characters
+    String,
+    /// The number of days since the UNIX epoch, stored as an `int`
+    Date,
+    /// The number of milliseconds after midnight, stored as an `int`
+    TimeMillis,
+    /// The number of microseconds after midnight, stored as a `long`
+    TimeMicros,
+    /// The number of milliseconds since the UNIX epoch, stored as a `long`
+    ///
+    /// If `utc` is false this is a `local-timestamp-millis`
+    TimestampMillis {
+        /// If the timestamp is relative to UTC
+        utc: bool,
+    },
+    /// The number of microseconds since the UNIX epoch, stored as a `long`
+    ///
+    /// If `utc` is false this is a `local-timestamp-micros`
+    TimestampMicros {
+        /// If the timestamp is relative to UTC
+        utc: bool,
+    },
+    /// An arbitrary-precision signed decimal, stored as `bytes` or `fixed`
+    Decimal(Decimal),
+    /// A record with named fields
+    Record(Record),
+    /// One of a set of named symbols
+    Enum(Enum),
+    /// A variable length sequence of items
+    Array(Box<Schema>),
+    /// A map from string keys to values
+    Map(Box<Schema>),
+    /// A value of one of several schemas
+    Union(Vec<Schema>),
+    /// A fixed number of bytes
+    Fixed(Fixed),
+}
+
+/// An Avro `record`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    /// The full name of this record, including any namespace
+    pub name: String,
+    /// The fields of this record
+    pub fields: Vec<RecordField>,
+}
+
+/// A field of an Avro [`Record`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordField {
+    /// The name of this field
+    pub name: String,
+    /// The schema of this field
+    pub schema: Schema,
+    /// The value to use for this field when reading data written without it
+    pub default: Option<Value>,
+}
+
+/// An Avro `enum`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Enum {
+    /// The full name of this enum, including any namespace
+    pub name: String,
+    /// The symbols of this enum
+    pub symbols: Vec<String>,
+}
+
+/// An Avro `fixed`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fixed {
+    /// The full name of this fixed, including any namespace
+    pub name: String,
+    /// The number of bytes per value
+    pub size: usize,
+}
+
+/// An Avro `decimal` logical type
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decimal {
+    /// The maximum number of digits
+    pub precision: usize,
+    /// The number of digits to the right of the decimal point
+    pub scale: usize,
+    /// The underlying `fixed` type, or `None` if stored as `bytes`
+    pub fixed: Option<Fixed>,
+}
+
+impl Schema {
+    /// Parses an Avro schema from its JSON representation
+    ///
+    /// ```
+    /// # use arrow_avro::Schema;
+    /// let schema = Schema::parse(r#"{
+    ///     "type": "record",
+    ///     "name": "test",
+    ///     "fields": [{"name": "a", "type": ["null", "long"]}]
+    /// }"#).unwrap();
+    ///
+    /// let arrow = schema.to_arrow().unwrap();
+    /// assert!(arrow.field(0).is_nullable());
+    /// ```
+    pub fn parse(json: &str) -> Result<Self, ArrowError> {
+        let value: Value = serde_json::from_str(json).map_err(|e| {
+            ArrowError::ParseError(format!("Invalid Avro schema JSON: {}", e))
+        })?;
+        Self::from_json(&value)
+    }
+
+    /// Parses an Avro schema from a parsed JSON [`Value`]
+    pub fn from_json(value: &Value) -> Result<Self, ArrowError> {
+        Parser::default().parse(value, None)
+    }
+
+    /// Returns the JSON representation of this schema
+    pub fn to_json(&self) -> Value {
+        self.to_json_impl(&mut HashSet::new())
+    }
+
+    fn to_json_impl(&self, defined: &mut HashSet<String>) -> Value {
+        match self {
+            Schema::Null => json!("null"),
+            Schema::Boolean => json!("boolean"),
+            Schema::Int => json!("int"),
+            Schema::Long => json!("long"),
+            Schema::Float => json!("float"),
+            Schema::Double => json!("double"),
+            Schema::Bytes => json!("bytes"),
+            Schema::String => json!("string"),
+            Schema::Date => json!({"type": "int", "logicalType": "date"}),
+            Schema::TimeMillis => json!({"type": "int", "logicalType": "time-millis"}),
+            Schema::TimeMicros => json!({"type": "long", "logicalType": "time-micros"}),
+            Schema::TimestampMillis { utc } => {
+                let logical = match utc {
+                    true => "timestamp-millis",
+                    false => "local-timestamp-millis",
+                };
+                json!({"type": "long", "logicalType": logical})
+            }
+            Schema::TimestampMicros { utc } => {
+                let logical = match utc {
+                    true => "timestamp-micros",
+                    false => "local-timestamp-micros",
+                };
+                json!({"type": "long", "logicalType": logical})
+            }
+            Schema::Decimal(d) => {
+                let mut value = match &d.fixed {
+                    Some(fixed) => match defined.insert(fixed.name.clone()) {
+                        true => json!({
+                            "type": "fixed",
+                            "name": fixed.name,
+                            "size": fixed.size
+                        }),
+                        // A named type can only be annotated where it is defined
+                        false => return json!(fixed.name),
+                    },
+                    None => json!({"type": "bytes"}),
+                };
+                value["logicalType"] = json!("decimal");
+                value["precision"] = json!(d.precision);
+                value["scale"] = json!(d.scale);
+                value
+            }
+            Schema::Record(r) => {
+                if !defined.insert(r.name.clone()) {
+                    return json!(r.name);
+                }
+                let fields: Vec<_> = r
+                    .fields
+                    .iter()
+                    .map(|f| {
+                        let mut field = json!({
+                            "name": f.name,
+                            "type": f.schema.to_json_impl(defined)
+                        });
+                        if let Some(default) = &f.default {
+                            field["default"] = default.clone();
+                        }
+                        field
+                    })
+                    .collect();
+                json!({"type": "record", "name": r.name, "fields": fields})
+            }
+            Schema::Enum(e) => match defined.insert(e.name.clone()) {
+                true => json!({"type": "enum", "name": e.name, "symbols": e.symbols}),
+                false => json!(e.name),
+            },
+            Schema::Fixed(f) => match defined.insert(f.name.clone()) {
+                true => json!({"type": "fixed", "name": f.name, "size": f.size}),
+                false => json!(f.name),
+            },
+            Schema::Array(items) => {
+                json!({"type": "array", "items": items.to_json_impl(defined)})
+            }
+            Schema::Map(values) => {
+                json!({"type": "map", "values": values.to_json_impl(defined)})
+            }
+            Schema::Union(variants) => {
+                Value::Array(variants.iter().map(|v| v.to_json_impl(defined)).collect())
+            }
+        }
+    }
+
+    /// If this is a union of `null` and one other schema, returns the index
+    /// of the `null` variant and the other schema
+    pub(crate) fn as_nullable(&self) -> Option<(usize, &Schema)> {
+        match self {
+            Schema::Union(variants) if variants.len() == 2 => {
+                match (&variants[0], &variants[1]) {
+                    (Schema::Null, Schema::Null) => None,
+                    (Schema::Null, s) => Some((0, s)),
+                    (s, Schema::Null) => Some((1, s)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Converts a top-level Avro `record` to an Arrow [`Schema`](arrow_schema::Schema)
+    pub fn to_arrow(&self) -> Result<arrow_schema::Schema, ArrowError> {
+        match self {
+            Schema::Record(r) => {
+                let fields = r
+                    .fields
+                    .iter()
+                    .map(|f| f.schema.to_arrow_field(&f.name))
+                    .collect::<Result<_, _>>()?;
+                Ok(arrow_schema::Schema::new(fields))
+            }
+            _ => Err(ArrowError::SchemaError(
+                "Top-level Avro schema must be a record".to_string(),
+            )),
+        }
+    }
+
+    /// Converts this schema to an Arrow [`Field`] named `name`
+    pub fn to_arrow_field(&self, name: &str) -> Result<Field, ArrowError> {
+        match self.as_nullable() {
+            Some((_, schema)) => Ok(Field::new(name, schema.to_arrow_type()?, true)),
+            None => {
+                let nullable = matches!(self, Schema::Null);
+                Ok(Field::new(name, self.to_arrow_type()?, nullable))
+            }
+        }
+    }
+
+    /// Returns the Arrow [`DataType`] corresponding to this schema, ignoring
+    /// the nullability of any top-level union with `null`
+    pub fn to_arrow_type(&self) -> Result<DataType, ArrowError> {
+        if let Some((_, schema)) = self.as_nullable() {
+            return schema.to_arrow_type();
+        }
+        Ok(match self {
+            Schema::Null => DataType::Null,
+            Schema::Boolean => DataType::Boolean,
+            Schema::Int => DataType::Int32,
+            Schema::Long => DataType::Int64,
+            Schema::Float => DataType::Float32,
+            Schema::Double => DataType::Float64,
+            Schema::Bytes => DataType::Binary,
+            Schema::String => DataType::Utf8,
+            Schema::Date => DataType::Date32,
+            Schema::TimeMillis => DataType::Time32(TimeUnit::Millisecond),
+            Schema::TimeMicros => DataType::Time64(TimeUnit::Microsecond),
+            Schema::TimestampMillis { utc } => {
+                DataType::Timestamp(TimeUnit::Millisecond, utc_timezone(*utc))
+            }
+            Schema::TimestampMicros { utc } => {
+                DataType::Timestamp(TimeUnit::Microsecond, utc_timezone(*utc))
+            }
+            Schema::Decimal(d) => {
+                if d.precision > 38 || d.scale > d.precision {
+                    return Err(ArrowError::SchemaError(format!(
+                        "Unsupported Avro decimal with precision {} and scale {}",
+                        d.precision, d.scale
+                    )));
+                }
+                DataType::Decimal128(d.precision as u8, d.scale as u8)
+            }
+            Schema::Record(r) => DataType::Struct(
+                r.fields
+                    .iter()
+                    .map(|f| f.schema.to_arrow_field(&f.name))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Schema::Enum(_) => {
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+            }
+            Schema::Array(items) => {
+                DataType::List(Box::new(items.to_arrow_field("item")?))
+            }
+            Schema::Map(values) => {
+                let entries = DataType::Struct(vec![
+                    Field::new("key", DataType::Utf8, false),
+                    values.to_arrow_field("value")?,
+                ]);
+                DataType::Map(Box::new(Field::new(MAP_ENTRIES, entries, false)), false)
+            }
+            Schema::Union(_) => {
+                return Err(ArrowError::NotYetImplemented(
+                    "Avro unions other than a union of null and one other type"
+                        .to_string(),
+                ))
+            }
+            Schema::Fixed(f) => DataType::FixedSizeBinary(f.size as i32),
+        })
+    }
+
+    /// Creates an Avro schema from an Arrow [`Schema`](arrow_schema::Schema),
+    /// with a top-level record named `topLevelRecord`
+    ///
+    /// Nullable fields are written as a union of `null` and their type, and
+    /// nested records are named by their path from the top-level record.
+    pub fn try_from_arrow(schema: &arrow_schema::Schema) -> Result<Self, ArrowError> {
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|f| record_field_from_arrow(f, TOP_LEVEL_RECORD))
+            .collect::<Result<_, _>>()?;
+        Ok(Schema::Record(Record {
+            name: TOP_LEVEL_RECORD.to_string(),
+            fields,
+        }))
+    }
+
+    /// Creates an Avro schema from an Arrow [`Field`], using `path` to name any
+    /// nested named types
+    fn try_from_arrow_field(field: &Field, path: &str) -> Result<Self, ArrowError> {
+        let schema = Self::try_from_arrow_type(field.data_type(), path)?;
+        match field.is_nullable() && schema != Schema::Null {
+            true => Ok(Schema::Union(vec![Schema::Null, schema])),
+            false => Ok(schema),
+        }
+    }
+
+    fn try_from_arrow_type(data_type: &DataType, path: &str) -> Result<Self, ArrowError> {
+        Ok(match data_type {
+            DataType::Null => Schema::Null,
+            DataType::Boolean => Schema::Boolean,
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::UInt8
+            | DataType::UInt16 => Schema::Int,
+            DataType::Int64 | DataType::UInt32 => Schema::Long,
+            DataType::Float32 => Schema::Float,
+            DataType::Float64 => Schema::Double,
+            DataType::Binary | DataType::LargeBinary => Schema::Bytes,
+            DataType::Utf8 | DataType::LargeUtf8 => Schema::String,
+            DataType::Date32 => Schema::Date,
+            DataType::Time32(TimeUnit::Millisecond) => Schema::TimeMillis,
+            DataType::Time64(TimeUnit::Microsecond) => Schema::TimeMicros,
+            DataType::Timestamp(TimeUnit::Millisecond, tz) => {
+                Schema::TimestampMillis { utc: tz.is_some() }
+            }
+            DataType::Timestamp(TimeUnit::Microsecond, tz) => {
+                Schema::TimestampMicros { utc: tz.is_some() }
+            }
+            DataType::Decimal128(precision, scale) => Schema::Decimal(Decimal {
+                precision: *precision as usize,
+                scale: *scale as usize,
+                fixed: None,
+            }),
+            DataType::FixedSizeBinary(size) => Schema::Fixed(Fixed {
+                name: path.to_string(),
+                size: *size as usize,
+            }),
+            DataType::Struct(fields) => Schema::Record(Record {
+                name: path.to_string(),
+                fields: fields
+                    .iter()
+                    .map(|f| record_field_from_arrow(f, path))
+                    .collect::<Result<_, _>>()?,
+            }),
+            DataType::List(item) | DataType::LargeList(item) => Schema::Array(Box::new(
+                Self::try_from_arrow_field(item, &format!("{}_item", path))?,
+            )),
+            DataType::Map(entries, _) => match entries.data_type() {
+                DataType::Struct(fields)
+                    if fields.len() == 2 && fields[0].data_type() == &DataType::Utf8 =>
+                {
+                    Schema::Map(Box::new(Self::try_from_arrow_field(
+                        &fields[1],
+                        &format!("{}_value", path),
+                    )?))
+                }
+                _ => {
+                    return Err(ArrowError::SchemaError(
+                        "Avro maps must have string keys".to_string(),
+                    ))
+                }
+            },
+            DataType::Dictionary(_, values) => Self::try_from_arrow_type(values, path)?,
+            d => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Writing {} to Avro",
+                    d
+                )))
+            }
+        })
+    }
+}
+
+fn record_field_from_arrow(field: &Field, path: &str) -> Result<RecordField, ArrowError> {
+    let path = format!("{}_{}", path, sanitize_name(field.name()));
+    let schema = Schema::try_from_arrow_field(field, &path)?;
+    // Nullable fields can be omitted by later versions of a schema
+    let default = matches!(schema, Schema::Union(_)).then_some(Value::Null);
+    Ok(RecordField {
+        name: field.name().clone(),
+        schema,
+        default,
+    })
+}
+
+/// Replaces any characters not permitted in Avro names with `_`
+fn sanitize_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c,
+            false => '_',
+        })
+        .collect()
+}
+
+fn utc_timezone(utc: bool) -> Option<String> {
+    utc.then(|| "+00:00".to_string())
+}
+
+/// Parses [`Schema`] from JSON, tracking named types for later references
+#[derive(Debug, Default)]
+struct Parser {
+    named: HashMap<String, Schema>,
+    /// Named types whose definition is currently being parsed
+    defining: HashSet<String>,
+}
+
+impl Parser {
+    fn parse(
+        &mut self,
+        value: &Value,
+        namespace: Option<&str>,
+    ) -> Result<Schema, ArrowError> {
+        match value {
+            Value::String(name) => self.parse_name(name, namespace),
+            Value::Array(variants) => {
+                let variants = variants
+                    .iter()
+                    .map(|v| self.parse(v, namespace))
+                    .collect::<Result<_, _>>()?;
+                Ok(Schema::Union(variants))
+            }
+            Value::Object(object) => self.parse_object(object, namespace),
+            _ => Err(invalid(format!("unexpected schema {}", value))),
+        }
+    }
+
+    fn parse_name(
+        &self,
+        name: &str,
+        namespace: Option<&str>,
+    ) -> Result<Schema, ArrowError> {
+        Ok(match name {
+            "null" => Schema::Null,
+            "boolean" => Schema::Boolean,
+            "int" => Schema::Int,
+            "long" => Schema::Long,
+            "float" => Schema::Float,
+            "double" => Schema::Double,
+            "bytes" => Schema::Bytes,
+            "string" => Schema::String,
+            _ => {
+                let full_name = full_name(name, namespace);
+                if self.defining.contains(&full_name) {
+                    return Err(ArrowError::NotYetImplemented(format!(
+                        "Recursive Avro schema referencing {}",
+                        full_name
+                    )));
+                }
+                match self.named.get(&full_name).or_else(|| self.named.get(name)) {
+                    Some(schema) => schema.clone(),
+                    None => return Err(invalid(format!("unknown type {}", name))),
+                }
+            }
+        })
+    }
+
+    fn parse_object(
+        &mut self,
+        object: &Map<String, Value>,
+        namespace: Option<&str>,
+    ) -> Result<Schema, ArrowError> {
+        let type_name = match object.get("type") {
+            Some(Value::String(t)) => t.as_str(),
+            Some(nested) => return self.parse(nested, namespace),
+            None => return Err(invalid("missing type".to_string())),
+        };
+
+        let schema = match type_name {
+            "record" | "error" => {
+                let (name, namespace) = self.define(object, namespace)?;
+                let fields = match object.get("fields") {
+                    Some(Value::Array(fields)) => fields,
+                    _ => return Err(invalid(format!("record {} missing fields", name))),
+                };
+                let fields = fields
+                    .iter()
+                    .map(|field| {
+                        let field_name = get_str(field, "name")?;
+                        let schema = match field.get("type") {
+                            Some(t) => self.parse(t, namespace.as_deref())?,
+                            None => {
+                                return Err(invalid(format!(
+                                    "field {} missing type",
+                                    field_name
+                                )))
+                            }
+                        };
+                        Ok(RecordField {
+                            name: field_name.to_string(),
+                            schema,
+                            default: field.get("default").cloned(),
+                        })
+                    })
+                    .collect::<Result<_, _>>()?;
+                Schema::Record(Record { name, fields })
+            }
+            "enum" => {
+                let (name, _) = self.define(object, namespace)?;
+                let symbols = match object.get("symbols") {
+                    Some(Value::Array(symbols)) => symbols
+                        .iter()
+                        .map(|s| match s {
+                            Value::String(s) => Ok(s.clone()),
+                            _ => Err(invalid(format!("invalid enum symbol {}", s))),
+                        })
+                        .collect::<Result<_, _>>()?,
+                    _ => return Err(invalid(format!("enum {} missing symbols", name))),
+                };
+                Schema::Enum(Enum { name, symbols })
+            }
+            "fixed" => {
+                let (name, _) = self.define(object, namespace)?;
+                let size = object
+                    .get("size")
+                    .and_then(Value::as_u64)
+                    .ok_or_else(|| invalid(format!("fixed {} missing size", name)))?;
+                let fixed = Fixed {
+                    name,
+                    size: size as usize,
+                };
+                match logical_type(object) {
+                    Some("decimal") => {
+                        Schema::Decimal(parse_decimal(object, Some(fixed))?)
+                    }
+                    _ => Schema::Fixed(fixed),
+                }
+            }
+            "array" => match object.get("items") {
+                Some(items) => Schema::Array(Box::new(self.parse(items, namespace)?)),
+                None => return Err(invalid("array missing items".to_string())),
+            },
+            "map" => match object.get("values") {
+                Some(values) => Schema::Map(Box::new(self.parse(values, namespace)?)),
+                None => return Err(invalid("map missing values".to_string())),
+            },
+            primitive => {
+                let schema = self.parse_name(primitive, namespace)?;
+                match (logical_type(object), &schema) {
+                    (Some("date"), Schema::Int) => Schema::Date,
+                    (Some("time-millis"), Schema::Int) => Schema::TimeMillis,
+                    (Some("time-micros"), Schema::Long) => Schema::TimeMicros,
+                    (Some("timestamp-millis"), Schema::Long) => {
+                        Schema::TimestampMillis { utc: true }
+                    }
+                    (Some("timestamp-micros"), Schema::Long) => {
+                        Schema::TimestampMicros { utc: true }
+                    }
+                    (Some("local-timestamp-millis"), Schema::Long) => {
+                        Schema::TimestampMillis { utc: false }
+                    }
+                    (Some("local-timestamp-micros"), Schema::Long) => {
+                        Schema::TimestampMicros { utc: false }
+                    }
+                    (Some("decimal"), Schema::Bytes) => {
+                        Schema::Decimal(parse_decimal(object, None)?)
+                    }
+                    _ => schema,
+                }
+            }
+        };
+
+        if let Some(name) = schema_name(&schema) {
+            self.defining.remove(name);
+            self.named.insert(name.to_string(), schema.clone());
+        }
+        Ok(schema)
+    }
+
+    /// Registers the definition of a named type, returning its full name and namespace
+    fn define(
+        &mut self,
+        object: &Map<String, Value>,
+        namespace: Option<&str>,
+    ) -> Result<(String, Option<String>), ArrowError> {
+        let name = match object.get("name") {
+            Some(Value::String(name)) => name,
+            _ => return Err(invalid("named type missing name".to_string())),
+        };
+        let namespace = match object.get("namespace") {
+            Some(Value::String(ns)) => Some(ns.as_str()),
+            _ => namespace,
+        };
+        let full_name = full_name(name, namespace);
+        if self.named.contains_key(&full_name) || !self.defining.insert(full_name.clone())
+        {
+            return Err(invalid(format!("duplicate definition of {}", full_name)));
+        }
+        let namespace = full_name.rsplit_once('.').map(|(ns, _)| ns.to_string());
+        Ok((full_name, namespace))
+    }
+}
+
+fn schema_name(schema: &Schema) -> Option<&str> {
+    match schema {
+        Schema::Record(r) => Some(&r.name),
+        Schema::Enum(e) => Some(&e.name),
+        Schema::Fixed(f) => Some(&f.name),
+        Schema::Decimal(Decimal { fixed: Some(f), .. }) => Some(&f.name),
+        _ => None,
+    }
+}
+
+fn full_name(name: &str, namespace: Option<&str>) -> String {
+    match namespace {
+        Some(ns) if !name.contains('.') && !ns.is_empty() => format!("{}.{}", ns, name),
+        _ => name.to_string(),
+    }
+}
+
+fn logical_type(object: &Map<String, Value>) -> Option<&str> {
+    object.get("logicalType").and_then(Value::as_str)
+}
+
+fn parse_decimal(
+    object: &Map<String, Value>,
+    fixed: Option<Fixed>,
+) -> Result<Decimal, ArrowError> {
+    let precision = object
+        .get("precision")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| invalid("decimal missing precision".to_string()))?;
+    let scale = object.get("scale").and_then(Value::as_u64).unwrap_or(0);
+    Ok(Decimal {
+        precision: precision as usize,
+        scale: scale as usize,
+        fixed,
+    })
+}
+
+fn get_str<'a>(value: &'a Value, key: &str) -> Result<&'a str, ArrowError> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| invalid(format!("missing {} in {}", key, value)))
+}
+
+fn invalid(message: String) -> ArrowError {
+    ArrowError::SchemaError(format!("Invalid Avro schema: {}", message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_schema() {
+        let schema = Schema::parse(
+            r#"{
+                "type": "record",
+                "name": "test",
+                "namespace": "org.example",
+                "fields": [
+                    {"name": "id", "type": "long"},
+                    {"name": "name", "type": ["null", "string"], "default": null},
+                    {"name": "day", "type": {"type": "int", "logicalType": "date"}},
+                    {"name": "ts", "type": {"type": "long", "logicalType": "timestamp-micros"}},
+                    {"name": "price", "type": {"type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2}},
+                    {"name": "tags", "type": {"type": "array", "items": "string"}},
+                    {"name": "attrs", "type": {"type": "map", "values": "int"}},
+                    {"name": "kind", "type": {"type": "enum", "name": "Kind", "symbols": ["A", "B"]}},
+                    {"name": "other_kind", "type": "Kind"},
+                    {"name": "hash", "type": {"type": "fixed", "name": "md5", "size": 16}},
+                    {"name": "inner", "type": {
+                        "type": "record",
+                        "name": "Inner",
+                        "fields": [{"name": "x", "type": "double"}]
+                    }},
+                    {"name": "unknown", "type": {"type": "string", "logicalType": "uuid"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let record = match &schema {
+            Schema::Record(r) => r,
+            _ => unreachable!(),
+        };
+        assert_eq!(record.name, "org.example.test");
+        assert_eq!(record.fields[1].default, Some(Value::Null));
+        let kind = Schema::Enum(Enum {
+            name: "org.example.Kind".to_string(),
+            symbols: vec!["A".to_string(), "B".to_string()],
+        });
+        assert_eq!(record.fields[7].schema, kind);
+        assert_eq!(record.fields[8].schema, kind);
+        assert_eq!(record.fields[11].schema, Schema::String);
+
+        let arrow = schema.to_arrow().unwrap();
+        let expected = arrow_schema::Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+            Field::new("day", DataType::Date32, false),
+            Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".to_string())),
+                false,
+            ),
+            Field::new("price", DataType::Decimal128(10, 2), false),
+            Field::new(
+                "tags",
+                DataType::List(Box::new(Field::new("item", DataType::Utf8, false))),
+                false,
+            ),
+            Field::new(
+                "attrs",
+                DataType::Map(
+                    Box::new(Field::new(
+                        "entries",
+                        DataType::Struct(vec![
+                            Field::new("key", DataType::Utf8, false),
+                            Field::new("value", DataType::Int32, false),
+                        ]),
+                        false,
+                    )),
+                    false,
+                ),
+                false,
+            ),
+            Field::new(
+                "kind",
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+                false,
+            ),
+            Field::new(
+                "other_kind",
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+                false,
+            ),
+            Field::new("hash", DataType::FixedSizeBinary(16), false),
+            Field::new(
+                "inner",
+                DataType::Struct(vec![Field::new("x", DataType::Float64, false)]),
+                false,
+            ),
+            Field::new("unknown", DataType::Utf8, false),
+        ]);
+        assert_eq!(arrow, expected);
+
+        // Round trip through JSON
+        assert_eq!(Schema::from_json(&schema.to_json()).unwrap(), schema);
+    }
+
+    #[test]
+    fn test_invalid_schema() {
+        let err = Schema::parse(r#"{"type": "record", "name": "a"}"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Invalid Avro schema: record a missing fields"
+        );
+
+        let err = Schema::parse(r#""foo""#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Invalid Avro schema: unknown type foo"
+        );
+
+        let err = Schema::parse(
+            r#"{"type": "record", "name": "a", "fields": [{"name": "b", "type": "a"}]}"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: Recursive Avro schema referencing a"
+        );
+
+        let schema = Schema::parse(r#"["int", "string"]"#).unwrap();
+        assert!(schema.to_arrow_type().is_err());
+    }
+
+    #[test]
+    fn test_from_arrow() {
+        let arrow = arrow_schema::Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b c", DataType::Utf8, true),
+            Field::new(
+                "s",
+                DataType::Struct(vec![Field::new(
+                    "x",
+                    DataType::FixedSizeBinary(4),
+                    true,
+                )]),
+                true,
+            ),
+        ]);
+        let schema = Schema::try_from_arrow(&arrow).unwrap();
+        assert_eq!(
+            schema.to_json(),
+            json!({
+                "type": "record",
+                "name": "topLevelRecord",
+                "fields": [
+                    {"name": "a", "type": "int"},
+                    {"name": "b c", "type": ["null", "string"], "default": null},
+                    {"name": "s", "type": ["null", {
+                        "type": "record",
+                        "name": "topLevelRecord_s",
+                        "fields": [{"name": "x", "type": ["null", {
+                            "type": "fixed",
+                            "name": "topLevelRecord_s_x",
+                            "size": 4
+                        }], "default": null}]
+                    }], "default": null}
+                ]
+            })
+        );
+        assert_eq!(schema.to_arrow().unwrap(), arrow);
+
+        let arrow =
+            arrow_schema::Schema::new(vec![Field::new("a", DataType::Float16, false)]);
+        assert!(Schema::try_from_arrow(&arrow).is_err());
+    }
+}
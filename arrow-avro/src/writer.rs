@@ -0,0 +1,412 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Writing [`RecordBatch`] to Avro object container files
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{Int32Array, RecordBatch, StringArray};
+//! # use arrow_avro::{CompressionCodec, WriterBuilder};
+//! let batch = RecordBatch::try_from_iter([
+//!     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as _),
+//!     ("b", Arc::new(StringArray::from(vec![Some("x"), None, Some("z")])) as _),
+//! ])
+//! .unwrap();
+//!
+//! let mut writer = WriterBuilder::new()
+//!     .with_codec(CompressionCodec::Deflate)
+//!     .build(Vec::new(), batch.schema())
+//!     .unwrap();
+//! writer.write(&batch).unwrap();
+//! let bytes = writer.into_inner().unwrap();
+//! assert_eq!(&bytes[..4], b"Obj\x01");
+//! ```
+
+use crate::codec::CompressionCodec;
+use crate::schema::Schema;
+use crate::{MAGIC, SYNC_LENGTH};
+use arrow_array::cast::*;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::ArrowNativeType;
+use arrow_data::ArrayData;
+use arrow_schema::{ArrowError, DataType, SchemaRef, TimeUnit};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io::Write;
+
+/// Avro object container file writer builder
+#[derive(Debug, Clone, Default)]
+pub struct WriterBuilder {
+    codec: CompressionCodec,
+}
+
+impl WriterBuilder {
+    /// Create a new builder for configuring Avro writing options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the compression codec for data blocks, defaults to [`CompressionCodec::Null`]
+    pub fn with_codec(mut self, codec: CompressionCodec) -> Self {
+        self.codec = codec;
+        self
+    }
+
+    /// Create a new [`Writer`] writing batches with `schema` to `writer`
+    ///
+    /// The file header is written immediately
+    pub fn build<W: Write>(
+        self,
+        writer: W,
+        schema: SchemaRef,
+    ) -> Result<Writer<W>, ArrowError> {
+        let avro_schema = Schema::try_from_arrow(&schema)?;
+        let mut sync = [0_u8; SYNC_LENGTH];
+        let mut hasher = RandomState::new().build_hasher();
+        for chunk in sync.chunks_mut(8) {
+            hasher.write_usize(chunk.len());
+            chunk.copy_from_slice(&hasher.finish().to_le_bytes());
+        }
+
+        let mut writer = Writer {
+            writer,
+            schema,
+            avro_schema,
+            codec: self.codec,
+            sync,
+            block: Vec::with_capacity(1024),
+        };
+        writer.write_header()?;
+        Ok(writer)
+    }
+}
+
+/// Writes [`RecordBatch`] to an Avro object container file, writing one data
+/// block per batch
+#[derive(Debug)]
+pub struct Writer<W: Write> {
+    writer: W,
+    schema: SchemaRef,
+    avro_schema: Schema,
+    codec: CompressionCodec,
+    sync: [u8; SYNC_LENGTH],
+    /// Reusable buffer for encoding data blocks
+    block: Vec<u8>,
+}
+
+impl<W: Write> Writer<W> {
+    /// Create a new [`Writer`] with no compression
+    pub fn try_new(writer: W, schema: SchemaRef) -> Result<Self, ArrowError> {
+        WriterBuilder::new().build(writer, schema)
+    }
+
+    /// Returns the Avro schema written to the file header
+    pub fn avro_schema(&self) -> &Schema {
+        &self.avro_schema
+    }
+
+    fn write_header(&mut self) -> Result<(), ArrowError> {
+        let schema = self.avro_schema.to_json().to_string();
+        let mut header = Vec::with_capacity(schema.len() + 64);
+        header.extend_from_slice(MAGIC);
+        encode_long(2, &mut header);
+        encode_bytes(b"avro.schema", &mut header);
+        encode_bytes(schema.as_bytes(), &mut header);
+        encode_bytes(b"avro.codec", &mut header);
+        encode_bytes(self.codec.name().as_bytes(), &mut header);
+        encode_long(0, &mut header);
+        header.extend_from_slice(&self.sync);
+        self.writer.write_all(&header)?;
+        Ok(())
+    }
+
+    /// Write a [`RecordBatch`] as a single data block
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        if batch.schema().fields() != self.schema.fields() {
+            return Err(ArrowError::SchemaError(
+                "Cannot write record batch with a different schema".to_string(),
+            ));
+        }
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let encoders = batch
+            .columns()
+            .iter()
+            .zip(self.schema.fields())
+            .map(|(c, f)| make_encoder(c.as_ref(), f.is_nullable()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.block.clear();
+        for row in 0..batch.num_rows() {
+            for encoder in &encoders {
+                encoder(row, &mut self.block);
+            }
+        }
+        let data = self.codec.compress(&self.block)?;
+
+        let mut header = Vec::with_capacity(20);
+        encode_long(batch.num_rows() as i64, &mut header);
+        encode_long(data.len() as i64, &mut header);
+        self.writer.write_all(&header)?;
+        self.writer.write_all(&data)?;
+        self.writer.write_all(&self.sync)?;
+        Ok(())
+    }
+
+    /// Flush any buffered data to the underlying writer
+    pub fn flush(&mut self) -> Result<(), ArrowError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Flush and return the underlying writer
+    pub fn into_inner(mut self) -> Result<W, ArrowError> {
+        self.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Encodes the value at a given index of an array
+type Encoder = Box<dyn Fn(usize, &mut Vec<u8>)>;
+
+/// Returns an [`Encoder`] for `array` matching [`Schema::try_from_arrow`]
+fn make_encoder(array: &dyn Array, nullable: bool) -> Result<Encoder, ArrowError> {
+    let encoder = make_value_encoder(array)?;
+    match nullable {
+        true => {
+            let array = make_array(array.data().clone());
+            Ok(Box::new(move |idx, out| match array.is_null(idx) {
+                true => encode_long(0, out),
+                false => {
+                    encode_long(1, out);
+                    encoder(idx, out)
+                }
+            }))
+        }
+        false if array.null_count() != 0 => Err(ArrowError::InvalidArgumentError(
+            "Found null value in non-nullable field".to_string(),
+        )),
+        false => Ok(encoder),
+    }
+}
+
+/// Returns an [`Encoder`] for the values of `array`, ignoring nulls
+fn make_value_encoder(array: &dyn Array) -> Result<Encoder, ArrowError> {
+    Ok(match array.data_type() {
+        DataType::Null => Box::new(|_, _| {}),
+        DataType::Boolean => {
+            let array: BooleanArray = typed(array);
+            Box::new(move |idx, out| out.push(array.value(idx) as u8))
+        }
+        DataType::Int8 => long_encoder::<Int8Type>(array),
+        DataType::Int16 => long_encoder::<Int16Type>(array),
+        DataType::Int32 => long_encoder::<Int32Type>(array),
+        DataType::Int64 => long_encoder::<Int64Type>(array),
+        DataType::UInt8 => long_encoder::<UInt8Type>(array),
+        DataType::UInt16 => long_encoder::<UInt16Type>(array),
+        DataType::UInt32 => long_encoder::<UInt32Type>(array),
+        DataType::Date32 => long_encoder::<Date32Type>(array),
+        DataType::Time32(TimeUnit::Millisecond) => {
+            long_encoder::<Time32MillisecondType>(array)
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            long_encoder::<Time64MicrosecondType>(array)
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            long_encoder::<TimestampMillisecondType>(array)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            long_encoder::<TimestampMicrosecondType>(array)
+        }
+        DataType::Float32 => {
+            let array: Float32Array = typed(array);
+            Box::new(move |idx, out| {
+                out.extend_from_slice(&array.value(idx).to_le_bytes())
+            })
+        }
+        DataType::Float64 => {
+            let array: Float64Array = typed(array);
+            Box::new(move |idx, out| {
+                out.extend_from_slice(&array.value(idx).to_le_bytes())
+            })
+        }
+        DataType::Binary => {
+            let array: BinaryArray = typed(array);
+            Box::new(move |idx, out| encode_bytes(array.value(idx), out))
+        }
+        DataType::LargeBinary => {
+            let array: LargeBinaryArray = typed(array);
+            Box::new(move |idx, out| encode_bytes(array.value(idx), out))
+        }
+        DataType::Utf8 => {
+            let array: StringArray = typed(array);
+            Box::new(move |idx, out| encode_bytes(array.value(idx).as_bytes(), out))
+        }
+        DataType::LargeUtf8 => {
+            let array: LargeStringArray = typed(array);
+            Box::new(move |idx, out| encode_bytes(array.value(idx).as_bytes(), out))
+        }
+        DataType::FixedSizeBinary(_) => {
+            let array: FixedSizeBinaryArray = typed(array);
+            Box::new(move |idx, out| out.extend_from_slice(array.value(idx)))
+        }
+        DataType::Decimal128(_, _) => {
+            let array: Decimal128Array = typed(array);
+            Box::new(move |idx, out| {
+                let bytes = array.value(idx).as_i128().to_be_bytes();
+                // Strip redundant sign extension bytes
+                let skip = bytes
+                    .windows(2)
+                    .take_while(|w| {
+                        (w[0] == 0 && w[1] & 0x80 == 0)
+                            || (w[0] == 0xFF && w[1] & 0x80 != 0)
+                    })
+                    .count();
+                encode_bytes(&bytes[skip..], out)
+            })
+        }
+        DataType::Struct(fields) => {
+            let array = as_struct_array(array);
+            let encoders = array
+                .columns()
+                .iter()
+                .zip(fields)
+                .map(|(c, f)| make_encoder(c.as_ref(), f.is_nullable()))
+                .collect::<Result<Vec<_>, _>>()?;
+            Box::new(move |idx, out| encoders.iter().for_each(|e| e(idx, out)))
+        }
+        DataType::List(field) => {
+            let array: ListArray = typed(array);
+            let items = make_encoder(array.values().as_ref(), field.is_nullable())?;
+            Box::new(move |idx, out| {
+                let offsets = &array.value_offsets()[idx..idx + 2];
+                encode_block(offsets[0] as usize..offsets[1] as usize, &items, out)
+            })
+        }
+        DataType::LargeList(field) => {
+            let array: LargeListArray = typed(array);
+            let items = make_encoder(array.values().as_ref(), field.is_nullable())?;
+            Box::new(move |idx, out| {
+                let offsets = &array.value_offsets()[idx..idx + 2];
+                encode_block(offsets[0] as usize..offsets[1] as usize, &items, out)
+            })
+        }
+        DataType::Map(entries, _) => {
+            let value_nullable = match entries.data_type() {
+                DataType::Struct(fields) if fields.len() == 2 => fields[1].is_nullable(),
+                _ => unreachable!("invalid map entries"),
+            };
+            let array: MapArray = typed(array);
+            let keys = make_value_encoder(array.keys().as_ref())?;
+            let values = make_encoder(array.values().as_ref(), value_nullable)?;
+            let entry: Encoder = Box::new(move |idx, out| {
+                keys(idx, out);
+                values(idx, out)
+            });
+            Box::new(move |idx, out| {
+                let offsets = &array.value_offsets()[idx..idx + 2];
+                encode_block(offsets[0] as usize..offsets[1] as usize, &entry, out)
+            })
+        }
+        DataType::Dictionary(_, _) => downcast_dictionary_array! {
+            array => dictionary_encoder(array)?,
+            t => unreachable!("invalid dictionary type {}", t)
+        },
+        d => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Writing {} to Avro",
+                d
+            )))
+        }
+    })
+}
+
+fn long_encoder<T: ArrowPrimitiveType>(array: &dyn Array) -> Encoder
+where
+    T::Native: Into<i64>,
+{
+    let array: PrimitiveArray<T> = typed(array);
+    Box::new(move |idx, out| encode_long(array.value(idx).into(), out))
+}
+
+fn dictionary_encoder<K: ArrowPrimitiveType>(
+    array: &DictionaryArray<K>,
+) -> Result<Encoder, ArrowError> {
+    let keys: PrimitiveArray<K> = typed(array.keys());
+    let values = make_value_encoder(array.values().as_ref())?;
+    Ok(Box::new(move |idx, out| {
+        values(keys.value(idx).as_usize(), out)
+    }))
+}
+
+/// Returns an owned typed array from `array`
+fn typed<T: From<ArrayData>>(array: &dyn Array) -> T {
+    T::from(array.data().clone())
+}
+
+/// Encodes the items in `range` as a single Avro array or map block
+fn encode_block(range: std::ops::Range<usize>, items: &Encoder, out: &mut Vec<u8>) {
+    if !range.is_empty() {
+        encode_long(range.len() as i64, out);
+        range.for_each(|idx| items(idx, out));
+    }
+    encode_long(0, out);
+}
+
+/// Encodes `value` as a zig-zag variable length integer
+pub(crate) fn encode_long(value: i64, out: &mut Vec<u8>) {
+    let mut v = ((value << 1) ^ (value >> 63)) as u64;
+    while v >= 0x80 {
+        out.push(v as u8 | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+/// Encodes `value` prefixed by its length
+pub(crate) fn encode_bytes(value: &[u8], out: &mut Vec<u8>) {
+    encode_long(value.len() as i64, out);
+    out.extend_from_slice(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_long() {
+        let cases: &[(i64, &[u8])] = &[
+            (0, &[0x00]),
+            (-1, &[0x01]),
+            (1, &[0x02]),
+            (-64, &[0x7F]),
+            (64, &[0x80, 0x01]),
+            (
+                i64::MAX,
+                &[0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+            ),
+        ];
+        for (value, expected) in cases {
+            let mut out = vec![];
+            encode_long(*value, &mut out);
+            assert_eq!(&out, expected, "{}", value);
+        }
+    }
+}
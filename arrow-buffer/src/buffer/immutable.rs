@@ -181,6 +181,15 @@ impl Buffer {
         unsafe { self.data.ptr().as_ptr().add(self.offset) }
     }
 
+    /// Returns a pointer to the start of the allocation backing this buffer.
+    ///
+    /// Unlike [`Buffer::as_ptr`] this ignores any offset, and so can be used to
+    /// identify buffers that share the same underlying memory, e.g. after slicing.
+    #[inline]
+    pub fn data_ptr(&self) -> NonNull<u8> {
+        self.data.ptr()
+    }
+
     /// View buffer as a slice of a specific type.
     ///
     /// # Panics
@@ -196,6 +205,38 @@ impl Buffer {
         offsets
     }
 
+    /// Returns a mutable slice of this buffer's bytes if this [`Buffer`] is the only
+    /// reference to its memory and that memory was allocated by this crate, or `None`
+    /// otherwise, e.g. if it is shared with a clone or slice, or imported via FFI.
+    pub fn get_mut(&mut self) -> Option<&mut [u8]> {
+        let bytes = Arc::get_mut(&mut self.data)?;
+        if !matches!(bytes.deallocation(), Deallocation::Arrow(_)) {
+            return None;
+        }
+        // SAFETY
+        // the memory is uniquely owned through `&mut self` and valid for `bytes.len()` bytes
+        let slice =
+            unsafe { std::slice::from_raw_parts_mut(bytes.ptr().as_ptr(), bytes.len()) };
+        Some(&mut slice[self.offset..self.offset + self.length])
+    }
+
+    /// View buffer as a mutable slice of a specific type, see [`Buffer::get_mut`]
+    /// for when this returns `None`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the underlying buffer is not aligned
+    /// correctly for type `T`.
+    pub fn typed_data_mut<T: ArrowNativeType>(&mut self) -> Option<&mut [T]> {
+        let bytes = self.get_mut()?;
+        // SAFETY
+        // ArrowNativeType is trivially transmutable, is sealed to prevent potentially incorrect
+        // implementation outside this crate, and this method checks alignment
+        let (prefix, values, suffix) = unsafe { bytes.align_to_mut::<T>() };
+        assert!(prefix.is_empty() && suffix.is_empty());
+        Some(values)
+    }
+
     /// Returns a slice of this buffer starting at a certain bit offset.
     /// If the offset is byte-aligned the returned buffer is a shallow clone,
     /// otherwise a new buffer is allocated and filled with a copy of the bits in the range.
@@ -214,6 +255,17 @@ impl Buffer {
         BitChunks::new(self.as_slice(), offset, len)
     }
 
+    /// Returns an [`UnalignedBitChunk`] which can be used to iterate over this buffers
+    /// bits in 64-bit words, aligned to the underlying memory, starting at arbitrary
+    /// bit offsets. Note that both `offset` and `length` are measured in bits.
+    pub fn unaligned_bit_chunks(
+        &self,
+        offset: usize,
+        len: usize,
+    ) -> UnalignedBitChunk<'_> {
+        UnalignedBitChunk::new(self.as_slice(), offset, len)
+    }
+
     /// Returns the number of 1-bits in this buffer.
     pub fn count_set_bits(&self) -> usize {
         let len_in_bits = self.len() * 8;
@@ -224,7 +276,7 @@ impl Buffer {
     /// Returns the number of 1-bits in this buffer, starting from `offset` with `length` bits
     /// inspected. Note that both `offset` and `length` are measured in bits.
     pub fn count_set_bits_offset(&self, offset: usize, len: usize) -> usize {
-        UnalignedBitChunk::new(self.as_slice(), offset, len).count_ones()
+        self.unaligned_bit_chunks(offset, len).count_ones()
     }
 }
 
@@ -416,6 +468,17 @@ mod tests {
         assert_eq!(buf2.slice_with_length(2, 1).as_slice(), &[10]);
     }
 
+    #[test]
+    fn test_data_ptr() {
+        let buf = Buffer::from(&[2, 4, 6, 8, 10]);
+        let sliced = buf.slice(2);
+        assert_eq!(buf.data_ptr(), sliced.data_ptr());
+        assert_ne!(buf.as_ptr(), sliced.as_ptr());
+
+        let other = Buffer::from(&[2, 4, 6, 8, 10]);
+        assert_ne!(buf.data_ptr(), other.data_ptr());
+    }
+
     #[test]
     #[should_panic(
         expected = "the offset of the new Buffer cannot exceed the existing length"
@@ -574,4 +637,30 @@ mod tests {
         let slice = buffer.typed_data::<i32>();
         assert_eq!(slice, &[2, 3, 4, 5]);
     }
+
+    #[test]
+    fn test_get_mut() {
+        let mut buffer = Buffer::from_slice_ref(&[1_i32, 2, 3, 4]);
+        buffer.typed_data_mut::<i32>().unwrap()[1] = 5;
+        assert_eq!(buffer.typed_data::<i32>(), &[1, 5, 3, 4]);
+
+        // shared with a slice
+        let mut sliced = buffer.slice(4);
+        assert!(sliced.get_mut().is_none());
+        assert!(buffer.get_mut().is_none());
+
+        drop(buffer);
+        sliced.typed_data_mut::<i32>().unwrap()[0] = 6;
+        assert_eq!(sliced.typed_data::<i32>(), &[6, 3, 4]);
+
+        let mut vector = vec![1_i32, 2, 3];
+        let mut foreign = unsafe {
+            Buffer::from_custom_allocation(
+                NonNull::new_unchecked(vector.as_mut_ptr() as *mut u8),
+                vector.len() * std::mem::size_of::<i32>(),
+                Arc::new(vector),
+            )
+        };
+        assert!(foreign.get_mut().is_none());
+    }
 }
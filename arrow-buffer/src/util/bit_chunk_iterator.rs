@@ -27,7 +27,31 @@ use std::fmt::Debug;
 ///
 /// This is unlike [`BitChunkIterator`] which only exposes a trailing u64,
 /// and consequently has to perform more work for each read
-#[derive(Debug)]
+///
+/// The bits of the prefix before the start of the range, and of the suffix
+/// after its end, are always zero. Bit `i` of the range is therefore bit
+/// `i + lead_padding()` of the concatenation of all yielded words, which allows
+/// processing a bitmap one word at a time without any per-bit branches
+///
+/// ```
+/// # use arrow_buffer::bit_chunk_iterator::UnalignedBitChunk;
+/// let bitmap = [0b1111_0000_u8, 0b0000_1111, 0xFF];
+/// let chunk = UnalignedBitChunk::new(&bitmap, 2, 18);
+///
+/// let mut set_bits = vec![];
+/// let mut base = -(chunk.lead_padding() as isize);
+/// for mut word in chunk.iter() {
+///     while word != 0 {
+///         let bit = word.trailing_zeros() as isize;
+///         set_bits.push((base + bit) as usize);
+///         word &= word - 1;
+///     }
+///     base += 64;
+/// }
+/// assert_eq!(set_bits, vec![2, 3, 4, 5, 6, 7, 8, 9, 14, 15, 16, 17]);
+/// assert_eq!(chunk.count_ones(), 12);
+/// ```
+#[derive(Debug, Clone, Copy)]
 pub struct UnalignedBitChunk<'a> {
     lead_padding: usize,
     trailing_padding: usize,
@@ -133,26 +157,34 @@ impl<'a> UnalignedBitChunk<'a> {
         }
     }
 
+    /// Returns the number of zero bits preceding the start of the range in the
+    /// first yielded word
     pub fn lead_padding(&self) -> usize {
         self.lead_padding
     }
 
+    /// Returns the number of zero bits following the end of the range in the
+    /// last yielded word
     pub fn trailing_padding(&self) -> usize {
         self.trailing_padding
     }
 
+    /// Returns the word preceding [`Self::chunks`], if any
     pub fn prefix(&self) -> Option<u64> {
         self.prefix
     }
 
+    /// Returns the word following [`Self::chunks`], if any
     pub fn suffix(&self) -> Option<u64> {
         self.suffix
     }
 
+    /// Returns the aligned words between [`Self::prefix`] and [`Self::suffix`]
     pub fn chunks(&self) -> &'a [u64] {
         self.chunks
     }
 
+    /// Returns an iterator over the prefix, the aligned chunks and the suffix
     pub fn iter(&self) -> UnalignedBitChunkIterator<'a> {
         self.prefix
             .into_iter()
@@ -166,6 +198,16 @@ impl<'a> UnalignedBitChunk<'a> {
     }
 }
 
+impl<'a> IntoIterator for UnalignedBitChunk<'a> {
+    type Item = u64;
+    type IntoIter = UnalignedBitChunkIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator returned by [`UnalignedBitChunk::iter`]
 pub type UnalignedBitChunkIterator<'a> = std::iter::Chain<
     std::iter::Chain<
         std::option::IntoIter<u64>,
@@ -399,6 +441,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unaligned_bit_chunks_buffer() {
+        let buffer = Buffer::from(&[0xFF_u8; 24]).slice(3);
+
+        let chunks = buffer.unaligned_bit_chunks(5, 130);
+        let words: Vec<_> = chunks.into_iter().collect();
+        assert_eq!(
+            words.len(),
+            (chunks.lead_padding() + 130 + chunks.trailing_padding()) / 64
+        );
+        assert_eq!(words[0].trailing_zeros() as usize, chunks.lead_padding());
+        assert_eq!(
+            words[words.len() - 1].leading_zeros() as usize,
+            chunks.trailing_padding()
+        );
+        assert_eq!(chunks.count_ones(), 130);
+    }
+
     #[test]
     fn test_iter_unaligned_remainder_1_byte() {
         let input: &[u8] = &[
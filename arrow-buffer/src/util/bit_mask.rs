@@ -0,0 +1,299 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Utils for working with packed bit masks
+
+use crate::bit_chunk_iterator::BitChunks;
+use crate::bit_util::ceil;
+
+/// Sets all bits on `write_data` in the range `[offset_write..offset_write+len]` to be equal to the
+/// bits in `data` in the range `[offset_read..offset_read+len]`
+/// returns the number of `0` bits `data[offset_read..offset_read+len]`
+pub fn set_bits(
+    write_data: &mut [u8],
+    data: &[u8],
+    offset_write: usize,
+    offset_read: usize,
+    len: usize,
+) -> usize {
+    copy_bits(write_data, offset_write, data, offset_read, len)
+}
+
+/// Copies the bits of `src` in the range `[src_offset..src_offset+len]` to `dst` in the
+/// range `[dst_offset..dst_offset+len]`, overwriting the existing bits and leaving the
+/// bits outside of the range untouched. Returns the number of `0` bits copied.
+///
+/// Bits are copied 64 at a time, shifting words of `src` into place, rather than
+/// bit by bit, regardless of the alignment of `src_offset` and `dst_offset`
+///
+/// # Panics
+///
+/// Panics if either range is out of bounds
+pub fn copy_bits(
+    dst: &mut [u8],
+    dst_offset: usize,
+    src: &[u8],
+    src_offset: usize,
+    len: usize,
+) -> usize {
+    assert!(ceil(dst_offset + len, 8) <= dst.len(), "dst out of bounds");
+    assert!(ceil(src_offset + len, 8) <= src.len(), "src out of bounds");
+
+    // Copy the bits preceding the first byte boundary of dst
+    let head = ((8 - dst_offset % 8) % 8).min(len);
+    let head_bits = BitChunks::new(src, src_offset, head).remainder_bits();
+    write_partial_byte(dst, dst_offset, head_bits as u8, head);
+    let mut null_count = head - head_bits.count_ones() as usize;
+
+    let chunks = BitChunks::new(src, src_offset + head, len - head);
+    let mut byte_idx = ceil(dst_offset + head, 8);
+    for chunk in chunks.iter() {
+        null_count += chunk.count_zeros() as usize;
+        dst[byte_idx..byte_idx + 8].copy_from_slice(&chunk.to_le_bytes());
+        byte_idx += 8;
+    }
+
+    // Copy the remaining whole bytes, followed by the trailing bits
+    let remainder_len = chunks.remainder_len();
+    let remainder = chunks.remainder_bits();
+    null_count += remainder_len - remainder.count_ones() as usize;
+    let remainder_bytes = remainder_len / 8;
+    dst[byte_idx..byte_idx + remainder_bytes]
+        .copy_from_slice(&remainder.to_le_bytes()[..remainder_bytes]);
+    write_partial_byte(
+        dst,
+        (byte_idx + remainder_bytes) * 8,
+        (remainder >> (remainder_bytes * 8)) as u8,
+        remainder_len % 8,
+    );
+
+    null_count
+}
+
+/// Sets all bits of `dst` in the range `[offset..offset+len]` to `value`, a byte at a
+/// time, leaving the bits outside of the range untouched
+///
+/// # Panics
+///
+/// Panics if the range is out of bounds
+pub fn fill_bits(dst: &mut [u8], offset: usize, len: usize, value: bool) {
+    assert!(ceil(offset + len, 8) <= dst.len(), "dst out of bounds");
+    let byte = if value { u8::MAX } else { 0 };
+
+    let head = ((8 - offset % 8) % 8).min(len);
+    write_partial_byte(dst, offset, byte, head);
+
+    let start = ceil(offset + head, 8);
+    let end = (offset + len) / 8;
+    // the range ends within the first byte if start > end
+    if start <= end {
+        dst[start..end].fill(byte);
+        write_partial_byte(dst, end * 8, byte, (offset + len) % 8);
+    }
+}
+
+/// Writes the `len` least significant bits of `bits` to `dst` starting at bit
+/// `offset`, which must not cross a byte boundary
+#[inline]
+fn write_partial_byte(dst: &mut [u8], offset: usize, bits: u8, len: usize) {
+    if len == 0 {
+        return;
+    }
+    let shift = offset % 8;
+    debug_assert!(shift + len <= 8);
+    let mask = (u8::MAX >> (8 - len)) << shift;
+    let byte = &mut dst[offset / 8];
+    *byte = (*byte & !mask) | ((bits << shift) & mask);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bit_util::{get_bit, set_bit, unset_bit};
+
+    #[test]
+    fn test_set_bits_aligned() {
+        let mut destination: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let source: &[u8] = &[
+            0b11100111, 0b10100101, 0b10011001, 0b11011011, 0b11101011, 0b11000011,
+            0b11100111, 0b10100101,
+        ];
+
+        let destination_offset = 8;
+        let source_offset = 0;
+
+        let len = 64;
+
+        let expected_data: &[u8] = &[
+            0, 0b11100111, 0b10100101, 0b10011001, 0b11011011, 0b11101011, 0b11000011,
+            0b11100111, 0b10100101, 0,
+        ];
+        let expected_null_count = 24;
+        let result = set_bits(
+            destination.as_mut_slice(),
+            source,
+            destination_offset,
+            source_offset,
+            len,
+        );
+
+        assert_eq!(destination, expected_data);
+        assert_eq!(result, expected_null_count);
+    }
+
+    #[test]
+    fn test_set_bits_unaligned_destination_start() {
+        let mut destination: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let source: &[u8] = &[
+            0b11100111, 0b10100101, 0b10011001, 0b11011011, 0b11101011, 0b11000011,
+            0b11100111, 0b10100101,
+        ];
+
+        let destination_offset = 3;
+        let source_offset = 0;
+
+        let len = 64;
+
+        let expected_data: &[u8] = &[
+            0b00111000, 0b00101111, 0b11001101, 0b11011100, 0b01011110, 0b00011111,
+            0b00111110, 0b00101111, 0b00000101, 0b00000000,
+        ];
+        let expected_null_count = 24;
+        let result = set_bits(
+            destination.as_mut_slice(),
+            source,
+            destination_offset,
+            source_offset,
+            len,
+        );
+
+        assert_eq!(destination, expected_data);
+        assert_eq!(result, expected_null_count);
+    }
+
+    #[test]
+    fn test_set_bits_unaligned_destination_end() {
+        let mut destination: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let source: &[u8] = &[
+            0b11100111, 0b10100101, 0b10011001, 0b11011011, 0b11101011, 0b11000011,
+            0b11100111, 0b10100101,
+        ];
+
+        let destination_offset = 8;
+        let source_offset = 0;
+
+        let len = 62;
+
+        let expected_data: &[u8] = &[
+            0, 0b11100111, 0b10100101, 0b10011001, 0b11011011, 0b11101011, 0b11000011,
+            0b11100111, 0b00100101, 0,
+        ];
+        let expected_null_count = 23;
+        let result = set_bits(
+            destination.as_mut_slice(),
+            source,
+            destination_offset,
+            source_offset,
+            len,
+        );
+
+        assert_eq!(destination, expected_data);
+        assert_eq!(result, expected_null_count);
+    }
+
+    #[test]
+    fn test_set_bits_unaligned() {
+        let mut destination: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let source: &[u8] = &[
+            0b11100111, 0b10100101, 0b10011001, 0b11011011, 0b11101011, 0b11000011,
+            0b11100111, 0b10100101, 0b10011001, 0b11011011, 0b11101011, 0b11000011,
+            0b11100111, 0b10100101, 0b10011001, 0b11011011, 0b11101011, 0b11000011,
+        ];
+
+        let destination_offset = 3;
+        let source_offset = 5;
+
+        let len = 95;
+
+        let expected_data: &[u8] = &[
+            0b01111000, 0b01101001, 0b11100110, 0b11110110, 0b11111010, 0b11110000,
+            0b01111001, 0b01101001, 0b11100110, 0b11110110, 0b11111010, 0b11110000,
+            0b00000001,
+        ];
+        let expected_null_count = 35;
+        let result = set_bits(
+            destination.as_mut_slice(),
+            source,
+            destination_offset,
+            source_offset,
+            len,
+        );
+
+        assert_eq!(destination, expected_data);
+        assert_eq!(result, expected_null_count);
+    }
+
+    #[test]
+    fn test_copy_bits_overwrites() {
+        let mut destination: Vec<u8> = vec![0xFF, 0xFF, 0xFF];
+        let source: &[u8] = &[0b10100101, 0b00000000];
+
+        let null_count = copy_bits(&mut destination, 3, source, 2, 11);
+
+        assert_eq!(destination, &[0b01001111, 0b11000001, 0xFF]);
+        assert_eq!(null_count, 8);
+    }
+
+    #[test]
+    fn test_copy_bits_fuzz() {
+        use rand::prelude::*;
+        let mut rng = thread_rng();
+
+        for _ in 0..100 {
+            let src: Vec<u8> = (0..40).map(|_| rng.gen()).collect();
+            let mut dst: Vec<u8> = (0..40).map(|_| rng.gen()).collect();
+            let src_offset = rng.gen_range(0..64);
+            let dst_offset = rng.gen_range(0..64);
+            let len = rng.gen_range(0..250);
+
+            let mut expected = dst.clone();
+            let mut expected_null_count = 0;
+            for i in 0..len {
+                let bit = get_bit(&src, src_offset + i);
+                match bit {
+                    true => set_bit(&mut expected, dst_offset + i),
+                    false => unset_bit(&mut expected, dst_offset + i),
+                }
+                expected_null_count += !bit as usize;
+            }
+
+            let null_count = copy_bits(&mut dst, dst_offset, &src, src_offset, len);
+            assert_eq!(dst, expected);
+            assert_eq!(null_count, expected_null_count);
+
+            let value = rng.gen();
+            for i in 0..len {
+                match value {
+                    true => set_bit(&mut expected, dst_offset + i),
+                    false => unset_bit(&mut expected, dst_offset + i),
+                }
+            }
+            fill_bits(&mut dst, dst_offset, len, value);
+            assert_eq!(dst, expected);
+        }
+    }
+}
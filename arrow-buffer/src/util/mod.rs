@@ -16,4 +16,5 @@
 // under the License.
 
 pub mod bit_chunk_iterator;
+pub mod bit_mask;
 pub mod bit_util;
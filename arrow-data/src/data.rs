@@ -498,7 +498,7 @@ impl ArrayData {
             let new_data = ArrayData {
                 data_type: self.data_type().clone(),
                 len: length,
-                null_count: count_nulls(self.null_buffer(), new_offset, length),
+                null_count: self.sliced_null_count(new_offset, length),
                 offset: new_offset,
                 buffers: self.buffers.clone(),
                 // Slice child data, to propagate offsets down to them
@@ -517,13 +517,23 @@ impl ArrayData {
             new_data.len = length;
             new_data.offset = offset + self.offset;
 
-            new_data.null_count =
-                count_nulls(new_data.null_buffer(), new_data.offset, new_data.len);
+            new_data.null_count = self.sliced_null_count(new_data.offset, length);
 
             new_data
         }
     }
 
+    /// Returns the null count of a slice of this [`ArrayData`] starting at the
+    /// absolute `offset`, avoiding a scan of the null bitmap when the array
+    /// contains either no nulls or only nulls
+    fn sliced_null_count(&self, offset: usize, length: usize) -> usize {
+        match self.null_count {
+            0 => 0,
+            n if n == self.len => length,
+            _ => count_nulls(self.null_buffer(), offset, length),
+        }
+    }
+
     /// Returns the `buffer` as a slice of type `T` starting at self.offset
     /// # Panics
     /// This function panics if:
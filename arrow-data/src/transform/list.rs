@@ -16,8 +16,9 @@
 // under the License.
 
 use super::{
-    Extend, _MutableArrayData,
+    _MutableArrayData,
     utils::{extend_offsets, get_last_offset},
+    Extend,
 };
 use crate::ArrayData;
 use arrow_buffer::ArrowNativeType;
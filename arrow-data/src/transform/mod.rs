@@ -19,7 +19,7 @@ use super::{
     data::{into_buffers, new_buffers},
     ArrayData, ArrayDataBuilder,
 };
-use crate::bit_mask::set_bits;
+use arrow_buffer::bit_mask::{fill_bits, set_bits};
 use arrow_buffer::{bit_util, ArrowNativeType, MutableBuffer};
 use arrow_schema::{ArrowError, DataType, IntervalUnit, UnionMode};
 use half::f16;
@@ -102,11 +102,7 @@ fn build_extend_null_bits(array: &ArrayData, use_nulls: bool) -> ExtendNullBits
     } else if use_nulls {
         Box::new(|mutable, _, len| {
             utils::resize_for_bits(&mut mutable.null_buffer, mutable.len + len);
-            let write_data = mutable.null_buffer.as_slice_mut();
-            let offset = mutable.len;
-            (0..len).for_each(|i| {
-                bit_util::set_bit(write_data, offset + i);
-            });
+            fill_bits(mutable.null_buffer.as_slice_mut(), mutable.len, len, true);
         })
     } else {
         Box::new(|_, _, _| {})
@@ -325,6 +321,87 @@ pub enum Capacities {
     /// Don't preallocate inner buffers and rely on array growth strategy
     Array(usize),
 }
+
+impl Capacities {
+    /// Returns the [Capacities] needed to append all the slots of `arrays`, e.g. when
+    /// concatenating them, so that no buffer has to be reallocated while extending.
+    ///
+    /// The values of binary and string arrays and the children of list, map and struct
+    /// arrays are accounted for. The values of dictionary arrays are not, as they
+    /// are not copied by [MutableArrayData::extend].
+    pub fn for_arrays(arrays: &[&ArrayData]) -> Self {
+        let len = arrays.iter().map(|array| array.len()).sum();
+        let data_type = match arrays.first() {
+            Some(array) => array.data_type(),
+            None => return Capacities::Array(len),
+        };
+
+        match data_type {
+            DataType::Utf8 | DataType::Binary => {
+                Capacities::Binary(len, Some(values_length::<i32>(arrays)))
+            }
+            DataType::LargeUtf8 | DataType::LargeBinary => {
+                Capacities::Binary(len, Some(values_length::<i64>(arrays)))
+            }
+            DataType::List(_) | DataType::Map(_, _) => {
+                let children = list_children::<i32>(arrays);
+                let children = children.iter().collect::<Vec<_>>();
+                Capacities::List(len, Some(Box::new(Self::for_arrays(&children))))
+            }
+            DataType::LargeList(_) => {
+                let children = list_children::<i64>(arrays);
+                let children = children.iter().collect::<Vec<_>>();
+                Capacities::List(len, Some(Box::new(Self::for_arrays(&children))))
+            }
+            DataType::Struct(fields) => {
+                let children = (0..fields.len())
+                    .map(|i| {
+                        // the children of a struct are aligned with its slots
+                        let children = arrays
+                            .iter()
+                            .map(|array| array.child_data()[i].slice(0, array.len()))
+                            .collect::<Vec<_>>();
+                        Self::for_arrays(&children.iter().collect::<Vec<_>>())
+                    })
+                    .collect();
+                Capacities::Struct(len, Some(children))
+            }
+            _ => Capacities::Array(len),
+        }
+    }
+}
+
+/// Returns the total length of the values referenced by the slots of the binary or
+/// string `arrays`
+fn values_length<T: ArrowNativeType + Integer>(arrays: &[&ArrayData]) -> usize {
+    arrays
+        .iter()
+        .filter(|array| !array.is_empty())
+        .map(|array| {
+            let offsets = array.buffer::<T>(0);
+            (offsets[array.len()] - offsets[0]).to_usize().unwrap()
+        })
+        .sum()
+}
+
+/// Returns the slices of the children of the list `arrays` referenced by their slots
+fn list_children<T: ArrowNativeType + Integer>(arrays: &[&ArrayData]) -> Vec<ArrayData> {
+    arrays
+        .iter()
+        .map(|array| {
+            let child = &array.child_data()[0];
+            match array.len() {
+                0 => child.slice(0, 0),
+                len => {
+                    let offsets = array.buffer::<T>(0);
+                    let start = offsets[0].to_usize().unwrap();
+                    let end = offsets[len].to_usize().unwrap();
+                    child.slice(start, end - start)
+                }
+            }
+        })
+        .collect()
+}
 impl<'a> MutableArrayData<'a> {
     /// returns a new [MutableArrayData] with capacity to `capacity` slots and specialized to create an
     /// [ArrayData] from multiple `arrays`.
@@ -375,12 +452,16 @@ impl<'a> MutableArrayData<'a> {
                 new_buffers(data_type, *capacity)
             }
             (
-                DataType::List(_) | DataType::LargeList(_),
+                DataType::List(_) | DataType::LargeList(_) | DataType::Map(_, _),
                 Capacities::List(capacity, _),
             ) => {
                 array_capacity = *capacity;
                 new_buffers(data_type, *capacity)
             }
+            (DataType::Struct(_), Capacities::Struct(capacity, _)) => {
+                array_capacity = *capacity;
+                new_buffers(data_type, *capacity)
+            }
             _ => panic!("Capacities: {:?} not yet supported", capacities),
         };
 
@@ -475,12 +556,13 @@ impl<'a> MutableArrayData<'a> {
                     })
                     .collect::<Vec<_>>(),
             },
-            DataType::FixedSizeList(_, _) => {
+            DataType::FixedSizeList(_, size) => {
                 let childs = arrays
                     .iter()
                     .map(|array| &array.child_data()[0])
                     .collect::<Vec<_>>();
-                vec![MutableArrayData::new(childs, use_nulls, array_capacity)]
+                let capacity = array_capacity * *size as usize;
+                vec![MutableArrayData::new(childs, use_nulls, capacity)]
             }
             DataType::Union(fields, _, _) => (0..fields.len())
                 .map(|i| {
@@ -669,4 +751,47 @@ mod test {
         assert_eq!(mutable.data.buffer1.capacity(), 64);
         assert_eq!(mutable.data.child_data[0].data.buffer1.capacity(), 192);
     }
+
+    #[test]
+    fn test_capacities_for_arrays() {
+        let strings = ArrayData::builder(DataType::Utf8)
+            .len(3)
+            .add_buffer(arrow_buffer::Buffer::from_slice_ref(&[0_i32, 2, 2, 5]))
+            .add_buffer(arrow_buffer::Buffer::from_slice_ref(b"abcde"))
+            .build()
+            .unwrap();
+        let sliced = strings.slice(2, 1);
+
+        let capacities = Capacities::for_arrays(&[&strings, &sliced]);
+        assert!(matches!(capacities, Capacities::Binary(4, Some(8))));
+
+        let list = ArrayData::builder(DataType::List(Box::new(Field::new(
+            "item",
+            DataType::Utf8,
+            true,
+        ))))
+        .len(2)
+        .add_buffer(arrow_buffer::Buffer::from_slice_ref(&[0_i32, 1, 3]))
+        .add_child_data(strings)
+        .build()
+        .unwrap();
+        let sliced = list.slice(1, 1);
+
+        let capacities = Capacities::for_arrays(&[&list, &sliced]);
+        match &capacities {
+            Capacities::List(3, Some(child)) => {
+                assert!(matches!(child.as_ref(), Capacities::Binary(5, Some(8))))
+            }
+            c => panic!("unexpected capacities {:?}", c),
+        }
+
+        let mut mutable =
+            MutableArrayData::with_capacities(vec![&list, &sliced], false, capacities);
+        mutable.extend(0, 0, 2);
+        mutable.extend(1, 0, 1);
+        let child = &mutable.data.child_data[0].data;
+        assert_eq!(child.buffer2.len(), 8);
+        assert_eq!(child.buffer2.capacity(), 64);
+        assert_eq!(mutable.freeze().len(), 3);
+    }
 }
@@ -0,0 +1,438 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decoding of ORC column streams into Arrow [`ArrayData`]
+
+use crate::encoding::{decode_bools, decode_bytes, decode_decimals, decode_ints};
+use crate::proto::{ColumnEncoding, StreamKind, Type, TypeKind};
+use arrow_buffer::Buffer;
+use arrow_data::transform::MutableArrayData;
+use arrow_data::{ArrayData, ArrayDataBuilder};
+use arrow_schema::{ArrowError, DataType, Field, TimeUnit};
+use std::collections::HashMap;
+
+/// The number of seconds between the UNIX epoch and the ORC timestamp epoch of 2015-01-01
+const ORC_EPOCH_SECONDS: i64 = 1_420_070_400;
+
+/// The precision and scale of decimals in files written without them
+const DEFAULT_DECIMAL: (u8, u8) = (38, 10);
+
+/// Returns the Arrow [`DataType`] of the ORC column `column`
+pub(crate) fn data_type(types: &[Type], column: usize) -> Result<DataType, ArrowError> {
+    let ty = get_type(types, column)?;
+    Ok(match ty.kind {
+        TypeKind::Boolean => DataType::Boolean,
+        TypeKind::Byte => DataType::Int8,
+        TypeKind::Short => DataType::Int16,
+        TypeKind::Int => DataType::Int32,
+        TypeKind::Long => DataType::Int64,
+        TypeKind::Float => DataType::Float32,
+        TypeKind::Double => DataType::Float64,
+        TypeKind::String | TypeKind::Varchar | TypeKind::Char => DataType::Utf8,
+        TypeKind::Binary => DataType::Binary,
+        TypeKind::Date => DataType::Date32,
+        TypeKind::Timestamp => DataType::Timestamp(TimeUnit::Nanosecond, None),
+        TypeKind::TimestampInstant => {
+            DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".to_string()))
+        }
+        TypeKind::Decimal => {
+            let (precision, scale) = match (ty.precision, ty.scale) {
+                (Some(p), Some(s)) if p != 0 => (p as u8, s as u8),
+                _ => DEFAULT_DECIMAL,
+            };
+            DataType::Decimal128(precision, scale)
+        }
+        TypeKind::Struct => DataType::Struct(struct_fields(types, column)?),
+        TypeKind::List => {
+            let item = subtype(ty, 0)?;
+            DataType::List(Box::new(Field::new("item", data_type(types, item)?, true)))
+        }
+        TypeKind::Map => {
+            let entries = DataType::Struct(vec![
+                Field::new("key", data_type(types, subtype(ty, 0)?)?, false),
+                Field::new("value", data_type(types, subtype(ty, 1)?)?, true),
+            ]);
+            DataType::Map(Box::new(Field::new("entries", entries, false)), false)
+        }
+        TypeKind::Union => {
+            return Err(ArrowError::NotYetImplemented(
+                "Reading ORC union types".to_string(),
+            ))
+        }
+    })
+}
+
+/// Returns the fields of the ORC struct column `column`
+pub(crate) fn struct_fields(
+    types: &[Type],
+    column: usize,
+) -> Result<Vec<Field>, ArrowError> {
+    let ty = get_type(types, column)?;
+    if ty.field_names.len() != ty.subtypes.len() {
+        return Err(ArrowError::ParseError(format!(
+            "ORC struct column {} has {} field names for {} fields",
+            column,
+            ty.field_names.len(),
+            ty.subtypes.len()
+        )));
+    }
+    ty.subtypes
+        .iter()
+        .zip(&ty.field_names)
+        .map(|(child, name)| {
+            Ok(Field::new(name, data_type(types, *child as usize)?, true))
+        })
+        .collect()
+}
+
+fn get_type(types: &[Type], column: usize) -> Result<&Type, ArrowError> {
+    types.get(column).ok_or_else(|| {
+        ArrowError::ParseError(format!("ORC column {} missing from file footer", column))
+    })
+}
+
+fn subtype(ty: &Type, idx: usize) -> Result<usize, ArrowError> {
+    match ty.subtypes.get(idx) {
+        Some(subtype) => Ok(*subtype as usize),
+        None => Err(ArrowError::ParseError(format!(
+            "ORC {:?} type missing subtype {}",
+            ty.kind, idx
+        ))),
+    }
+}
+
+/// The decompressed streams and column encodings of a stripe
+#[derive(Debug)]
+pub(crate) struct Stripe<'a> {
+    pub types: &'a [Type],
+    pub encodings: Vec<ColumnEncoding>,
+    pub streams: HashMap<(usize, StreamKind), Vec<u8>>,
+}
+
+impl<'a> Stripe<'a> {
+    fn stream(&self, column: usize, kind: StreamKind) -> Option<&[u8]> {
+        self.streams.get(&(column, kind)).map(Vec::as_slice)
+    }
+
+    fn required_stream(
+        &self,
+        column: usize,
+        kind: StreamKind,
+    ) -> Result<&[u8], ArrowError> {
+        self.stream(column, kind).ok_or_else(|| {
+            ArrowError::ParseError(format!(
+                "ORC column {} missing {:?} stream",
+                column, kind
+            ))
+        })
+    }
+
+    fn encoding(&self, column: usize) -> Result<ColumnEncoding, ArrowError> {
+        self.encodings.get(column).copied().ok_or_else(|| {
+            ArrowError::ParseError(format!("ORC column {} missing encoding", column))
+        })
+    }
+
+    /// Decodes the integer stream `kind` of `column`
+    fn ints(
+        &self,
+        column: usize,
+        kind: StreamKind,
+        len: usize,
+        signed: bool,
+    ) -> Result<Vec<i64>, ArrowError> {
+        let v2 = self.encoding(column)?.kind.is_v2();
+        decode_ints(self.required_stream(column, kind)?, len, signed, v2)
+    }
+
+    /// Decodes `len` rows of `column` as `data_type`
+    ///
+    /// For children of compound types `len` is the number of non-null values
+    /// of the parent, as ORC does not store values for null parents
+    pub fn decode(
+        &self,
+        column: usize,
+        data_type: &DataType,
+        len: usize,
+    ) -> Result<ArrayData, ArrowError> {
+        let present = match self.stream(column, StreamKind::Present) {
+            Some(present) => Some(decode_bools(present, len)?),
+            None => None,
+        };
+        let valid = match &present {
+            Some(present) => present.iter().filter(|p| **p).count(),
+            None => len,
+        };
+
+        let values = self.decode_values(column, data_type, valid)?;
+        match present {
+            Some(present) if valid != len => Ok(spread_nulls(&values, &present)),
+            _ => Ok(values),
+        }
+    }
+
+    /// Decodes `len` non-null values of `column`
+    fn decode_values(
+        &self,
+        column: usize,
+        data_type: &DataType,
+        len: usize,
+    ) -> Result<ArrayData, ArrowError> {
+        let ty = get_type(self.types, column)?;
+        let builder = ArrayDataBuilder::new(data_type.clone()).len(len);
+        let builder = match ty.kind {
+            TypeKind::Boolean => {
+                let values =
+                    decode_bools(self.required_stream(column, StreamKind::Data)?, len)?;
+                builder.add_buffer(Buffer::from_iter(values))
+            }
+            TypeKind::Byte => {
+                let values =
+                    decode_bytes(self.required_stream(column, StreamKind::Data)?, len)?;
+                builder.add_buffer(Buffer::from(values))
+            }
+            TypeKind::Short => {
+                let values = self.ints(column, StreamKind::Data, len, true)?;
+                builder.add_buffer(values.into_iter().map(|v| v as i16).collect())
+            }
+            TypeKind::Int | TypeKind::Date => {
+                let values = self.ints(column, StreamKind::Data, len, true)?;
+                builder.add_buffer(values.into_iter().map(|v| v as i32).collect())
+            }
+            TypeKind::Long => {
+                let values = self.ints(column, StreamKind::Data, len, true)?;
+                builder.add_buffer(Buffer::from_slice_ref(&values))
+            }
+            TypeKind::Float | TypeKind::Double => {
+                let width = match ty.kind {
+                    TypeKind::Float => 4,
+                    _ => 8,
+                };
+                let data = self.required_stream(column, StreamKind::Data)?;
+                let values = len.checked_mul(width).and_then(|n| data.get(..n));
+                let values = values.ok_or_else(|| {
+                    ArrowError::ParseError(format!(
+                        "ORC column {} DATA stream too short",
+                        column
+                    ))
+                })?;
+                builder.add_buffer(Buffer::from(values))
+            }
+            TypeKind::String | TypeKind::Varchar | TypeKind::Char | TypeKind::Binary => {
+                let (offsets, values) = self.decode_binary(column, len)?;
+                builder.add_buffer(offsets).add_buffer(values)
+            }
+            TypeKind::Timestamp | TypeKind::TimestampInstant => {
+                let seconds = self.ints(column, StreamKind::Data, len, true)?;
+                let nanos = self.ints(column, StreamKind::Secondary, len, false)?;
+                let values: Buffer = seconds
+                    .into_iter()
+                    .zip(nanos)
+                    .map(|(seconds, nanos)| {
+                        // The low 3 bits encode the number of trailing zeros removed
+                        let zeros = nanos & 7;
+                        let mut nanos = nanos >> 3;
+                        if zeros != 0 {
+                            nanos *= 10_i64.pow(zeros as u32 + 1);
+                        }
+                        let mut seconds = seconds + ORC_EPOCH_SECONDS;
+                        if seconds < 0 && nanos > 999_999 {
+                            seconds -= 1;
+                        }
+                        seconds.wrapping_mul(1_000_000_000).wrapping_add(nanos)
+                    })
+                    .collect();
+                builder.add_buffer(values)
+            }
+            TypeKind::Decimal => {
+                let scale = match data_type {
+                    DataType::Decimal128(_, scale) => *scale as i64,
+                    _ => unreachable!(),
+                };
+                let data = self.required_stream(column, StreamKind::Data)?;
+                let values = decode_decimals(data, len)?;
+                let scales = self.ints(column, StreamKind::Secondary, len, true)?;
+                let values: Buffer = values
+                    .into_iter()
+                    .zip(scales)
+                    .map(|(value, value_scale)| match value_scale.cmp(&scale) {
+                        std::cmp::Ordering::Less => {
+                            value * 10_i128.pow((scale - value_scale) as u32)
+                        }
+                        std::cmp::Ordering::Equal => value,
+                        std::cmp::Ordering::Greater => {
+                            value / 10_i128.pow((value_scale - scale) as u32)
+                        }
+                    })
+                    .collect();
+                builder.add_buffer(values)
+            }
+            TypeKind::Struct => {
+                let fields = match data_type {
+                    DataType::Struct(fields) => fields,
+                    _ => unreachable!(),
+                };
+                let children = ty
+                    .subtypes
+                    .iter()
+                    .zip(fields)
+                    .map(|(child, f)| self.decode(*child as usize, f.data_type(), len))
+                    .collect::<Result<_, _>>()?;
+                builder.child_data(children)
+            }
+            TypeKind::List => {
+                let (offsets, child_len) = self.decode_lengths(column, len)?;
+                let item = match data_type {
+                    DataType::List(item) => item.data_type(),
+                    _ => unreachable!(),
+                };
+                let items = self.decode(subtype(ty, 0)?, item, child_len)?;
+                builder.add_buffer(offsets).add_child_data(items)
+            }
+            TypeKind::Map => {
+                let (offsets, child_len) = self.decode_lengths(column, len)?;
+                let entries = match data_type {
+                    DataType::Map(entries, _) => entries.data_type(),
+                    _ => unreachable!(),
+                };
+                let fields = match entries {
+                    DataType::Struct(fields) => fields,
+                    _ => unreachable!(),
+                };
+                let keys =
+                    self.decode(subtype(ty, 0)?, fields[0].data_type(), child_len)?;
+                let values =
+                    self.decode(subtype(ty, 1)?, fields[1].data_type(), child_len)?;
+                let entries = ArrayDataBuilder::new(entries.clone())
+                    .len(child_len)
+                    .child_data(vec![keys, values])
+                    .build()?;
+                builder.add_buffer(offsets).add_child_data(entries)
+            }
+            TypeKind::Union => unreachable!(),
+        };
+        builder.build()
+    }
+
+    /// Decodes the LENGTH stream of a list or map column into offsets,
+    /// returning the offsets and the total number of child values
+    fn decode_lengths(
+        &self,
+        column: usize,
+        len: usize,
+    ) -> Result<(Buffer, usize), ArrowError> {
+        let lengths = self.ints(column, StreamKind::Length, len, false)?;
+        let offsets = lengths_to_offsets(&lengths)?;
+        let total = *offsets.last().unwrap() as usize;
+        Ok((Buffer::from_slice_ref(&offsets), total))
+    }
+
+    /// Decodes a string or binary column, returning its offsets and values buffers
+    fn decode_binary(
+        &self,
+        column: usize,
+        len: usize,
+    ) -> Result<(Buffer, Buffer), ArrowError> {
+        let encoding = self.encoding(column)?;
+        let data = self.required_stream(column, StreamKind::Data)?;
+        if !encoding.kind.is_dictionary() {
+            let lengths = self.ints(column, StreamKind::Length, len, false)?;
+            let offsets = lengths_to_offsets(&lengths)?;
+            let total = *offsets.last().unwrap() as usize;
+            let values = data.get(..total).ok_or_else(|| {
+                ArrowError::ParseError(format!(
+                    "ORC column {} DATA stream too short",
+                    column
+                ))
+            })?;
+            return Ok((Buffer::from_slice_ref(&offsets), Buffer::from(values)));
+        }
+
+        let dictionary_size = encoding.dictionary_size as usize;
+        let lengths = self.ints(column, StreamKind::Length, dictionary_size, false)?;
+        let dictionary_offsets = lengths_to_offsets(&lengths)?;
+        let dictionary = self.required_stream(column, StreamKind::DictionaryData)?;
+        if dictionary.len() < *dictionary_offsets.last().unwrap() as usize {
+            return Err(ArrowError::ParseError(format!(
+                "ORC column {} DICTIONARY_DATA stream too short",
+                column
+            )));
+        }
+
+        let indices = decode_ints(data, len, false, encoding.kind.is_v2())?;
+        let mut offsets = Vec::with_capacity(len + 1);
+        let mut values = Vec::with_capacity(dictionary.len());
+        offsets.push(0_i32);
+        for idx in indices {
+            let idx = idx as usize;
+            if idx >= dictionary_size {
+                return Err(ArrowError::ParseError(format!(
+                    "ORC dictionary index {} out of bounds for column {} with {} entries",
+                    idx, column, dictionary_size
+                )));
+            }
+            let start = dictionary_offsets[idx] as usize;
+            let end = dictionary_offsets[idx + 1] as usize;
+            values.extend_from_slice(&dictionary[start..end]);
+            offsets.push(to_offset(values.len())?);
+        }
+        Ok((Buffer::from_slice_ref(&offsets), Buffer::from(values)))
+    }
+}
+
+fn lengths_to_offsets(lengths: &[i64]) -> Result<Vec<i32>, ArrowError> {
+    let mut offsets = Vec::with_capacity(lengths.len() + 1);
+    let mut offset = 0_usize;
+    offsets.push(0);
+    for length in lengths {
+        let length = usize::try_from(*length).map_err(|_| {
+            ArrowError::ParseError(format!("Invalid ORC length {}", length))
+        })?;
+        offset += length;
+        offsets.push(to_offset(offset)?);
+    }
+    Ok(offsets)
+}
+
+fn to_offset(offset: usize) -> Result<i32, ArrowError> {
+    i32::try_from(offset).map_err(|_| {
+        ArrowError::ParseError("ORC column too large for 32-bit offsets".to_string())
+    })
+}
+
+/// Expands the non-null `values` to the rows of `present`, inserting nulls
+/// where `present` is false
+fn spread_nulls(values: &ArrayData, present: &[bool]) -> ArrayData {
+    let mut mutable = MutableArrayData::new(vec![values], true, present.len());
+    let mut value_idx = 0;
+    let mut row = 0;
+    while row < present.len() {
+        let run = present[row..]
+            .iter()
+            .take_while(|p| **p == present[row])
+            .count();
+        match present[row] {
+            true => {
+                mutable.extend(0, value_idx, value_idx + run);
+                value_idx += run;
+            }
+            false => mutable.extend_nulls(run),
+        }
+        row += run;
+    }
+    mutable.freeze()
+}
@@ -0,0 +1,138 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decompression of ORC streams and metadata sections
+
+use crate::proto::CompressionKind;
+use arrow_schema::ArrowError;
+
+/// Decompresses a section of an ORC file compressed with `kind`
+///
+/// Compressed sections are a sequence of chunks, each prefixed by a 3 byte
+/// little-endian header containing the chunk length and a flag indicating if
+/// the chunk is stored uncompressed.
+pub(crate) fn decompress(
+    kind: CompressionKind,
+    mut data: &[u8],
+) -> Result<Vec<u8>, ArrowError> {
+    if kind == CompressionKind::None {
+        return Ok(data.to_vec());
+    }
+
+    let mut out = Vec::with_capacity(data.len() * 2);
+    while !data.is_empty() {
+        if data.len() < 3 {
+            return Err(ArrowError::ParseError(
+                "Truncated ORC compression chunk header".to_string(),
+            ));
+        }
+        let header = u32::from_le_bytes([data[0], data[1], data[2], 0]);
+        let is_original = header & 1 == 1;
+        let len = (header >> 1) as usize;
+        let chunk = data.get(3..3 + len).ok_or_else(|| {
+            ArrowError::ParseError("Truncated ORC compression chunk".to_string())
+        })?;
+        data = &data[3 + len..];
+
+        match is_original {
+            true => out.extend_from_slice(chunk),
+            false => decompress_chunk(kind, chunk, &mut out)?,
+        }
+    }
+    Ok(out)
+}
+
+fn decompress_chunk(
+    kind: CompressionKind,
+    chunk: &[u8],
+    out: &mut Vec<u8>,
+) -> Result<(), ArrowError> {
+    match kind {
+        CompressionKind::None => out.extend_from_slice(chunk),
+        #[cfg(feature = "zlib")]
+        CompressionKind::Zlib => {
+            use std::io::Read;
+            flate2::read::DeflateDecoder::new(chunk)
+                .read_to_end(out)
+                .map_err(|e| ArrowError::IoError(format!("Invalid zlib chunk: {}", e)))?;
+        }
+        #[cfg(feature = "snappy")]
+        CompressionKind::Snappy => {
+            let decompressed =
+                snap::raw::Decoder::new()
+                    .decompress_vec(chunk)
+                    .map_err(|e| {
+                        ArrowError::IoError(format!("Invalid snappy chunk: {}", e))
+                    })?;
+            out.extend_from_slice(&decompressed);
+        }
+        #[cfg(feature = "zstd")]
+        CompressionKind::Zstd => {
+            use std::io::Read;
+            zstd::Decoder::new(chunk)
+                .and_then(|mut d| d.read_to_end(out))
+                .map_err(|e| ArrowError::IoError(format!("Invalid zstd chunk: {}", e)))?;
+        }
+        kind => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "ORC compression kind {:?}",
+                kind
+            )))
+        }
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "zlib"))]
+mod tests {
+    use super::*;
+
+    fn chunk_header(len: usize, is_original: bool) -> [u8; 3] {
+        let header = ((len as u32) << 1) | is_original as u32;
+        let bytes = header.to_le_bytes();
+        [bytes[0], bytes[1], bytes[2]]
+    }
+
+    #[test]
+    fn test_decompress() {
+        use std::io::Write;
+        let mut encoder =
+            flate2::write::DeflateEncoder::new(vec![], flate2::Compression::default());
+        encoder.write_all(b"hello hello hello").unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut data = chunk_header(compressed.len(), false).to_vec();
+        data.extend_from_slice(&compressed);
+        data.extend_from_slice(&chunk_header(6, true));
+        data.extend_from_slice(b" world");
+
+        let out = decompress(CompressionKind::Zlib, &data).unwrap();
+        assert_eq!(out, b"hello hello hello world");
+
+        let err = decompress(CompressionKind::Zlib, &data[..data.len() - 1]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Truncated ORC compression chunk"
+        );
+
+        let err = decompress(CompressionKind::Lzo, &data).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: ORC compression kind Lzo"
+        );
+    }
+}
@@ -0,0 +1,431 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decoders for the run length encodings used by ORC streams
+//!
+//! See <https://orc.apache.org/specification/ORCv1/#run-length-encoding>
+
+use arrow_schema::ArrowError;
+
+fn eof() -> ArrowError {
+    ArrowError::ParseError("Unexpected end of ORC stream".to_string())
+}
+
+fn next_byte(buf: &mut &[u8]) -> Result<u8, ArrowError> {
+    let (first, remaining) = buf.split_first().ok_or_else(eof)?;
+    *buf = remaining;
+    Ok(*first)
+}
+
+fn read_varint(buf: &mut &[u8]) -> Result<u64, ArrowError> {
+    let mut value = 0_u64;
+    let mut shift = 0;
+    loop {
+        let byte = next_byte(buf)?;
+        if shift < 64 {
+            value |= ((byte & 0x7F) as u64) << shift;
+        }
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+fn read_signed_varint(buf: &mut &[u8]) -> Result<i64, ArrowError> {
+    read_varint(buf).map(unzigzag)
+}
+
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// Reads a big-endian unsigned integer of `width` bytes
+fn read_be(buf: &mut &[u8], width: usize) -> Result<u64, ArrowError> {
+    if buf.len() < width {
+        return Err(eof());
+    }
+    let value = buf[..width]
+        .iter()
+        .fold(0_u64, |acc, b| (acc << 8) | *b as u64);
+    *buf = &buf[width..];
+    Ok(value)
+}
+
+/// Decodes `n` values of the byte run length encoding
+pub(crate) fn decode_bytes(mut buf: &[u8], n: usize) -> Result<Vec<u8>, ArrowError> {
+    // `n` is untrusted, so don't pre-allocate more than the input can hold
+    let mut out = Vec::with_capacity(n.min(buf.len() * 8));
+    while out.len() < n {
+        let header = next_byte(&mut buf)?;
+        match header < 0x80 {
+            true => {
+                let value = next_byte(&mut buf)?;
+                out.extend(std::iter::repeat(value).take(header as usize + 3));
+            }
+            false => {
+                let len = 0x100 - header as usize;
+                let literals = buf.get(..len).ok_or_else(eof)?;
+                out.extend_from_slice(literals);
+                buf = &buf[len..];
+            }
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+/// Decodes `n` values of the boolean run length encoding, a byte run length
+/// encoding of bits packed most significant bit first
+pub(crate) fn decode_bools(buf: &[u8], n: usize) -> Result<Vec<bool>, ArrowError> {
+    let bytes = decode_bytes(buf, (n + 7) / 8)?;
+    Ok((0..n)
+        .map(|i| bytes[i / 8] & (0x80 >> (i % 8)) != 0)
+        .collect())
+}
+
+/// Decodes `n` values of the integer run length encoding
+///
+/// `v2` selects between run length encoding versions 1 and 2, and `signed`
+/// whether the values are zigzag encoded
+pub(crate) fn decode_ints(
+    mut buf: &[u8],
+    n: usize,
+    signed: bool,
+    v2: bool,
+) -> Result<Vec<i64>, ArrowError> {
+    let mut out = Vec::with_capacity(n.min(buf.len() * 8));
+    while out.len() < n {
+        match v2 {
+            true => decode_v2_run(&mut buf, signed, &mut out)?,
+            false => decode_v1_run(&mut buf, signed, &mut out)?,
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+fn read_int(buf: &mut &[u8], signed: bool) -> Result<i64, ArrowError> {
+    match signed {
+        true => read_signed_varint(buf),
+        false => read_varint(buf).map(|v| v as i64),
+    }
+}
+
+fn decode_v1_run(
+    buf: &mut &[u8],
+    signed: bool,
+    out: &mut Vec<i64>,
+) -> Result<(), ArrowError> {
+    let header = next_byte(buf)? as i8;
+    match header >= 0 {
+        true => {
+            let len = header as i64 + 3;
+            let delta = next_byte(buf)? as i8 as i64;
+            let base = read_int(buf, signed)?;
+            out.extend((0..len).map(|i| base.wrapping_add(i * delta)));
+        }
+        false => {
+            for _ in 0..-(header as i64) {
+                out.push(read_int(buf, signed)?);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Decodes the 5-bit encoded bit width used by run length encoding version 2
+fn decode_width(code: u8) -> usize {
+    match code {
+        0..=23 => code as usize + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+/// Rounds `width` up to a bit width supported by run length encoding version 2
+fn closest_fixed_width(width: usize) -> usize {
+    match width {
+        0 => 1,
+        1..=24 => width,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+/// Reads `len` big-endian bit packed values of `width` bits, consuming whole bytes
+fn read_packed(
+    buf: &mut &[u8],
+    len: usize,
+    width: usize,
+) -> Result<Vec<u64>, ArrowError> {
+    let bytes = (len * width + 7) / 8;
+    let data = buf.get(..bytes).ok_or_else(eof)?;
+    *buf = &buf[bytes..];
+
+    let mut pos = 0;
+    let values = (0..len)
+        .map(|_| {
+            let mut value = 0_u64;
+            let mut remaining = width;
+            while remaining > 0 {
+                let available = 8 - pos % 8;
+                let take = available.min(remaining);
+                let byte = data[pos / 8] as u64;
+                let bits = (byte >> (available - take)) & ((1 << take) - 1);
+                value = (value << take) | bits;
+                remaining -= take;
+                pos += take;
+            }
+            value
+        })
+        .collect();
+    Ok(values)
+}
+
+fn decode_v2_run(
+    buf: &mut &[u8],
+    signed: bool,
+    out: &mut Vec<i64>,
+) -> Result<(), ArrowError> {
+    let header = next_byte(buf)?;
+    let to_signed = |v: u64| match signed {
+        true => unzigzag(v),
+        false => v as i64,
+    };
+
+    match header >> 6 {
+        // Short repeat
+        0 => {
+            let width = ((header >> 3) & 0x07) as usize + 1;
+            let len = (header & 0x07) as usize + 3;
+            let value = to_signed(read_be(buf, width)?);
+            out.extend(std::iter::repeat(value).take(len));
+        }
+        // Direct
+        1 => {
+            let width = decode_width((header >> 1) & 0x1F);
+            let len = ((((header & 1) as usize) << 8) | next_byte(buf)? as usize) + 1;
+            out.extend(read_packed(buf, len, width)?.into_iter().map(to_signed));
+        }
+        // Patched base
+        2 => {
+            let width = decode_width((header >> 1) & 0x1F);
+            let len = ((((header & 1) as usize) << 8) | next_byte(buf)? as usize) + 1;
+            let third = next_byte(buf)?;
+            let base_width = ((third >> 5) & 0x07) as usize + 1;
+            let patch_width = decode_width(third & 0x1F);
+            let fourth = next_byte(buf)?;
+            let gap_width = ((fourth >> 5) & 0x07) as usize + 1;
+            let patch_len = (fourth & 0x1F) as usize;
+
+            // The base is stored in sign-magnitude form
+            let base = read_be(buf, base_width)?;
+            let sign_bit = 1_u64 << (base_width * 8 - 1);
+            let base = match base & sign_bit {
+                0 => base as i64,
+                _ => -((base & !sign_bit) as i64),
+            };
+
+            let mut values = read_packed(buf, len, width)?;
+            let patch_entry_width = closest_fixed_width(gap_width + patch_width);
+            let patches = read_packed(buf, patch_len, patch_entry_width)?;
+            let patch_mask = match patch_width {
+                64 => u64::MAX,
+                w => (1 << w) - 1,
+            };
+
+            let mut idx = 0;
+            for entry in patches {
+                let gap = (entry >> patch_width) as usize;
+                let patch = entry & patch_mask;
+                idx += gap;
+                // A gap of 255 with no patch is used to encode larger gaps
+                if gap == 255 && patch == 0 {
+                    continue;
+                }
+                let value = values.get_mut(idx).ok_or_else(|| {
+                    ArrowError::ParseError("Invalid ORC patch index".to_string())
+                })?;
+                *value |= patch << width;
+            }
+            out.extend(values.into_iter().map(|v| base.wrapping_add(v as i64)));
+        }
+        // Delta
+        _ => {
+            let code = (header >> 1) & 0x1F;
+            let len = ((((header & 1) as usize) << 8) | next_byte(buf)? as usize) + 1;
+            let base = read_int(buf, signed)?;
+            let delta_base = read_signed_varint(buf)?;
+
+            out.push(base);
+            if len == 1 {
+                return Ok(());
+            }
+            let mut prev = base.wrapping_add(delta_base);
+            out.push(prev);
+            match code {
+                0 => {
+                    for _ in 2..len {
+                        prev = prev.wrapping_add(delta_base);
+                        out.push(prev);
+                    }
+                }
+                _ => {
+                    let deltas = read_packed(buf, len - 2, decode_width(code))?;
+                    for delta in deltas {
+                        prev = match delta_base < 0 {
+                            true => prev.wrapping_sub(delta as i64),
+                            false => prev.wrapping_add(delta as i64),
+                        };
+                        out.push(prev);
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Decodes `n` unbounded zigzag encoded base 128 varints, as used by decimals
+pub(crate) fn decode_decimals(mut buf: &[u8], n: usize) -> Result<Vec<i128>, ArrowError> {
+    (0..n)
+        .map(|_| {
+            let mut value = 0_u128;
+            let mut shift = 0;
+            loop {
+                let byte = next_byte(&mut buf)?;
+                if shift >= 128 {
+                    return Err(ArrowError::ParseError(
+                        "ORC decimal does not fit in 128 bits".to_string(),
+                    ));
+                }
+                value |= ((byte & 0x7F) as u128) << shift;
+                if byte & 0x80 == 0 {
+                    break;
+                }
+                shift += 7;
+            }
+            Ok(((value >> 1) as i128) ^ -((value & 1) as i128))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_rle() {
+        // Examples from the ORC specification
+        assert_eq!(decode_bytes(&[0x61, 0x00], 100).unwrap(), vec![0; 100]);
+        assert_eq!(
+            decode_bytes(&[0xfe, 0x44, 0x45], 2).unwrap(),
+            vec![0x44, 0x45]
+        );
+        assert!(decode_bytes(&[0xfe, 0x44], 2).is_err());
+        // an untrusted number of values errors once the input is exhausted
+        assert!(decode_bytes(&[0x61, 0x00], usize::MAX).is_err());
+
+        let bools = decode_bools(&[0xff, 0x80], 3).unwrap();
+        assert_eq!(bools, vec![true, false, false]);
+    }
+
+    #[test]
+    fn test_int_rle_v1() {
+        // Examples from the ORC specification
+        let values = decode_ints(&[0x61, 0x00, 0x07], 100, false, false).unwrap();
+        assert_eq!(values, vec![7; 100]);
+        assert!(decode_ints(&[0x61, 0x00, 0x07], usize::MAX, false, false).is_err());
+
+        let values = decode_ints(&[0x61, 0xff, 0x64], 100, false, false).unwrap();
+        assert_eq!(values, (1..=100).rev().collect::<Vec<_>>());
+
+        let values =
+            decode_ints(&[0xfb, 0x02, 0x03, 0x06, 0x07, 0x0b], 5, false, false).unwrap();
+        assert_eq!(values, vec![2, 3, 6, 7, 11]);
+
+        let values = decode_ints(&[0xfe, 0x03, 0x04], 2, true, false).unwrap();
+        assert_eq!(values, vec![-2, 2]);
+    }
+
+    #[test]
+    fn test_int_rle_v2() {
+        // Examples from the ORC specification
+        let values = decode_ints(&[0x0a, 0x27, 0x10], 5, false, true).unwrap();
+        assert_eq!(values, vec![10000; 5]);
+
+        let values = decode_ints(
+            &[0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef],
+            4,
+            false,
+            true,
+        )
+        .unwrap();
+        assert_eq!(values, vec![23713, 43806, 57005, 48879]);
+
+        let values = decode_ints(
+            &[
+                0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32,
+                0x3c, 0x46, 0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa,
+                0xb4, 0xbe, 0xfc, 0xe8,
+            ],
+            20,
+            false,
+            true,
+        )
+        .unwrap();
+        assert_eq!(
+            values,
+            vec![
+                2030, 2000, 2020, 1000000, 2040, 2050, 2060, 2070, 2080, 2090, 2100,
+                2110, 2120, 2130, 2140, 2150, 2160, 2170, 2180, 2190
+            ]
+        );
+
+        let values = decode_ints(
+            &[0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46],
+            10,
+            false,
+            true,
+        )
+        .unwrap();
+        assert_eq!(values, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+
+        // Fixed delta of -1 from a signed base of -1
+        let values = decode_ints(&[0xc0, 0x03, 0x01, 0x01], 4, true, true).unwrap();
+        assert_eq!(values, vec![-1, -2, -3, -4]);
+    }
+
+    #[test]
+    fn test_decimals() {
+        let values = decode_decimals(&[0x00, 0x01, 0x02, 0xff, 0x01], 4).unwrap();
+        assert_eq!(values, vec![0, -1, 1, -128]);
+    }
+}
@@ -0,0 +1,49 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Support for reading [Apache ORC] files as Arrow [`RecordBatch`](arrow_array::RecordBatch)
+//!
+//! ORC types are mapped to Arrow types as follows:
+//!
+//! * `tinyint`, `smallint`, `int` and `bigint` are read as `Int8` to `Int64`
+//! * `string`, `varchar` and `char` are read as `Utf8`, materializing any dictionary encoding
+//! * `timestamp` is read as a nanosecond timestamp without a timezone, and
+//!   `timestamp with local time zone` as a nanosecond timestamp in `UTC`
+//! * `decimal` is read as `Decimal128`
+//! * `struct`, `array` and `map` are read as `Struct`, `List` and `Map`
+//!
+//! `uniontype` columns are not yet supported. The `zlib`, `snappy` and `zstd`
+//! compression kinds are supported, behind the features of the same name.
+//!
+//! ```no_run
+//! # use arrow_orc::ReaderBuilder;
+//! let file = std::fs::File::open("data.orc").unwrap();
+//! let reader = ReaderBuilder::new().with_batch_size(8192).build(file).unwrap();
+//! for batch in reader {
+//!     println!("{} rows", batch.unwrap().num_rows());
+//! }
+//! ```
+//!
+//! [Apache ORC]: https://orc.apache.org/
+
+mod column;
+mod compression;
+mod encoding;
+mod proto;
+pub use proto::CompressionKind;
+mod reader;
+pub use reader::{Reader, ReaderBuilder};
@@ -0,0 +1,421 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decoding of the protobuf messages in the ORC file tail and stripe footers
+//!
+//! See <https://orc.apache.org/specification/ORCv1/> for the message definitions.
+//! Only the fields needed to read data are decoded, unknown fields are skipped.
+
+use arrow_schema::ArrowError;
+
+/// A decoded protobuf field value
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Wire<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    Fixed32(u32),
+}
+
+impl<'a> Wire<'a> {
+    fn as_u64(self) -> Result<u64, ArrowError> {
+        match self {
+            Wire::Varint(v) | Wire::Fixed64(v) => Ok(v),
+            Wire::Fixed32(v) => Ok(v as u64),
+            Wire::Bytes(_) => Err(invalid("expected integer field")),
+        }
+    }
+
+    fn as_bytes(self) -> Result<&'a [u8], ArrowError> {
+        match self {
+            Wire::Bytes(b) => Ok(b),
+            _ => Err(invalid("expected length delimited field")),
+        }
+    }
+
+    fn as_string(self) -> Result<String, ArrowError> {
+        String::from_utf8(self.as_bytes()?.to_vec())
+            .map_err(|_| invalid("invalid UTF-8 string"))
+    }
+
+    fn as_message<T: Message>(self) -> Result<T, ArrowError> {
+        decode(self.as_bytes()?)
+    }
+
+    /// Appends a repeated integer field, which may or may not be packed
+    fn extend_u64(self, out: &mut Vec<u64>) -> Result<(), ArrowError> {
+        match self {
+            Wire::Bytes(mut packed) => {
+                while !packed.is_empty() {
+                    out.push(read_varint(&mut packed)?);
+                }
+            }
+            v => out.push(v.as_u64()?),
+        }
+        Ok(())
+    }
+}
+
+/// A protobuf message
+pub(crate) trait Message: Default {
+    /// Merges a field of this message
+    fn merge_field(&mut self, field: u32, value: Wire<'_>) -> Result<(), ArrowError>;
+}
+
+/// Decodes a protobuf message from `buf`
+pub(crate) fn decode<T: Message>(mut buf: &[u8]) -> Result<T, ArrowError> {
+    let mut message = T::default();
+    while !buf.is_empty() {
+        let key = read_varint(&mut buf)?;
+        let field = (key >> 3) as u32;
+        let value = match key & 7 {
+            0 => Wire::Varint(read_varint(&mut buf)?),
+            1 => {
+                Wire::Fixed64(u64::from_le_bytes(take(&mut buf, 8)?.try_into().unwrap()))
+            }
+            2 => {
+                let len = read_varint(&mut buf)?;
+                Wire::Bytes(take(&mut buf, len as usize)?)
+            }
+            5 => {
+                Wire::Fixed32(u32::from_le_bytes(take(&mut buf, 4)?.try_into().unwrap()))
+            }
+            wire_type => {
+                return Err(invalid(&format!("unsupported wire type {}", wire_type)))
+            }
+        };
+        message.merge_field(field, value)?;
+    }
+    Ok(message)
+}
+
+fn read_varint(buf: &mut &[u8]) -> Result<u64, ArrowError> {
+    let mut value = 0_u64;
+    for (i, byte) in buf.iter().take(10).enumerate() {
+        value |= ((byte & 0x7F) as u64) << (7 * i);
+        if byte & 0x80 == 0 {
+            *buf = &buf[i + 1..];
+            return Ok(value);
+        }
+    }
+    Err(invalid("invalid varint"))
+}
+
+fn take<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8], ArrowError> {
+    if buf.len() < len {
+        return Err(invalid("unexpected end of message"));
+    }
+    let (value, remaining) = buf.split_at(len);
+    *buf = remaining;
+    Ok(value)
+}
+
+fn invalid(message: &str) -> ArrowError {
+    ArrowError::ParseError(format!("Invalid ORC metadata: {}", message))
+}
+
+/// The compression kind of an ORC file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompressionKind {
+    /// No compression
+    #[default]
+    None,
+    /// Raw deflate
+    Zlib,
+    /// Snappy
+    Snappy,
+    /// LZO
+    Lzo,
+    /// LZ4
+    Lz4,
+    /// Zstandard
+    Zstd,
+}
+
+/// The final section of an ORC file, describing how to read the footer
+#[derive(Debug, Default)]
+pub(crate) struct PostScript {
+    pub footer_length: u64,
+    pub compression: CompressionKind,
+    pub compression_block_size: Option<u64>,
+    pub magic: String,
+}
+
+impl Message for PostScript {
+    fn merge_field(&mut self, field: u32, value: Wire<'_>) -> Result<(), ArrowError> {
+        match field {
+            1 => self.footer_length = value.as_u64()?,
+            2 => {
+                self.compression = match value.as_u64()? {
+                    0 => CompressionKind::None,
+                    1 => CompressionKind::Zlib,
+                    2 => CompressionKind::Snappy,
+                    3 => CompressionKind::Lzo,
+                    4 => CompressionKind::Lz4,
+                    5 => CompressionKind::Zstd,
+                    k => return Err(invalid(&format!("unknown compression kind {}", k))),
+                }
+            }
+            3 => self.compression_block_size = Some(value.as_u64()?),
+            8000 => self.magic = value.as_string()?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// The file footer, describing the stripes and types of the file
+#[derive(Debug, Default)]
+pub(crate) struct Footer {
+    pub stripes: Vec<StripeInformation>,
+    pub types: Vec<Type>,
+    pub metadata: Vec<UserMetadataItem>,
+    pub number_of_rows: u64,
+}
+
+impl Message for Footer {
+    fn merge_field(&mut self, field: u32, value: Wire<'_>) -> Result<(), ArrowError> {
+        match field {
+            3 => self.stripes.push(value.as_message()?),
+            4 => self.types.push(value.as_message()?),
+            5 => self.metadata.push(value.as_message()?),
+            6 => self.number_of_rows = value.as_u64()?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// A user-defined key value pair in the file footer
+#[derive(Debug, Default)]
+pub(crate) struct UserMetadataItem {
+    pub name: String,
+    pub value: Vec<u8>,
+}
+
+impl Message for UserMetadataItem {
+    fn merge_field(&mut self, field: u32, value: Wire<'_>) -> Result<(), ArrowError> {
+        match field {
+            1 => self.name = value.as_string()?,
+            2 => self.value = value.as_bytes()?.to_vec(),
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// The location and size of a stripe
+#[derive(Debug, Default, Clone)]
+pub(crate) struct StripeInformation {
+    pub offset: u64,
+    pub index_length: u64,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub number_of_rows: u64,
+}
+
+impl Message for StripeInformation {
+    fn merge_field(&mut self, field: u32, value: Wire<'_>) -> Result<(), ArrowError> {
+        match field {
+            1 => self.offset = value.as_u64()?,
+            2 => self.index_length = value.as_u64()?,
+            3 => self.data_length = value.as_u64()?,
+            4 => self.footer_length = value.as_u64()?,
+            5 => self.number_of_rows = value.as_u64()?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// The kind of an ORC [`Type`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum TypeKind {
+    #[default]
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    Binary,
+    Timestamp,
+    List,
+    Map,
+    Struct,
+    Union,
+    Decimal,
+    Date,
+    Varchar,
+    Char,
+    TimestampInstant,
+}
+
+/// A type in the flattened type tree of the file, with column id equal to its index
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Type {
+    pub kind: TypeKind,
+    pub subtypes: Vec<u64>,
+    pub field_names: Vec<String>,
+    pub precision: Option<u64>,
+    pub scale: Option<u64>,
+}
+
+impl Message for Type {
+    fn merge_field(&mut self, field: u32, value: Wire<'_>) -> Result<(), ArrowError> {
+        match field {
+            1 => {
+                self.kind = match value.as_u64()? {
+                    0 => TypeKind::Boolean,
+                    1 => TypeKind::Byte,
+                    2 => TypeKind::Short,
+                    3 => TypeKind::Int,
+                    4 => TypeKind::Long,
+                    5 => TypeKind::Float,
+                    6 => TypeKind::Double,
+                    7 => TypeKind::String,
+                    8 => TypeKind::Binary,
+                    9 => TypeKind::Timestamp,
+                    10 => TypeKind::List,
+                    11 => TypeKind::Map,
+                    12 => TypeKind::Struct,
+                    13 => TypeKind::Union,
+                    14 => TypeKind::Decimal,
+                    15 => TypeKind::Date,
+                    16 => TypeKind::Varchar,
+                    17 => TypeKind::Char,
+                    18 => TypeKind::TimestampInstant,
+                    k => return Err(invalid(&format!("unknown type kind {}", k))),
+                }
+            }
+            2 => value.extend_u64(&mut self.subtypes)?,
+            3 => self.field_names.push(value.as_string()?),
+            5 => self.precision = Some(value.as_u64()?),
+            6 => self.scale = Some(value.as_u64()?),
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// The footer of a stripe, describing its streams and column encodings
+#[derive(Debug, Default)]
+pub(crate) struct StripeFooter {
+    pub streams: Vec<Stream>,
+    pub columns: Vec<ColumnEncoding>,
+}
+
+impl Message for StripeFooter {
+    fn merge_field(&mut self, field: u32, value: Wire<'_>) -> Result<(), ArrowError> {
+        match field {
+            1 => self.streams.push(value.as_message()?),
+            2 => self.columns.push(value.as_message()?),
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// The kind of a [`Stream`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub(crate) enum StreamKind {
+    #[default]
+    Present,
+    Data,
+    Length,
+    DictionaryData,
+    Secondary,
+    /// Index and statistics streams, which are not needed to read data
+    Other(u64),
+}
+
+/// A stream of data for a column within a stripe
+#[derive(Debug, Default)]
+pub(crate) struct Stream {
+    pub kind: StreamKind,
+    pub column: u64,
+    pub length: u64,
+}
+
+impl Message for Stream {
+    fn merge_field(&mut self, field: u32, value: Wire<'_>) -> Result<(), ArrowError> {
+        match field {
+            1 => {
+                self.kind = match value.as_u64()? {
+                    0 => StreamKind::Present,
+                    1 => StreamKind::Data,
+                    2 => StreamKind::Length,
+                    3 => StreamKind::DictionaryData,
+                    5 => StreamKind::Secondary,
+                    k => StreamKind::Other(k),
+                }
+            }
+            2 => self.column = value.as_u64()?,
+            3 => self.length = value.as_u64()?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// The encoding of a column within a stripe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum ColumnEncodingKind {
+    #[default]
+    Direct,
+    Dictionary,
+    DirectV2,
+    DictionaryV2,
+}
+
+impl ColumnEncodingKind {
+    /// Returns true if integer streams use run length encoding version 2
+    pub fn is_v2(&self) -> bool {
+        matches!(self, Self::DirectV2 | Self::DictionaryV2)
+    }
+
+    pub fn is_dictionary(&self) -> bool {
+        matches!(self, Self::Dictionary | Self::DictionaryV2)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct ColumnEncoding {
+    pub kind: ColumnEncodingKind,
+    pub dictionary_size: u64,
+}
+
+impl Message for ColumnEncoding {
+    fn merge_field(&mut self, field: u32, value: Wire<'_>) -> Result<(), ArrowError> {
+        match field {
+            1 => {
+                self.kind = match value.as_u64()? {
+                    0 => ColumnEncodingKind::Direct,
+                    1 => ColumnEncodingKind::Dictionary,
+                    2 => ColumnEncodingKind::DirectV2,
+                    3 => ColumnEncodingKind::DictionaryV2,
+                    k => return Err(invalid(&format!("unknown column encoding {}", k))),
+                }
+            }
+            2 => self.dictionary_size = value.as_u64()?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,673 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reading ORC files as [`RecordBatch`]
+
+use crate::column::{struct_fields, Stripe};
+use crate::compression::decompress;
+use crate::proto::{
+    decode, CompressionKind, Footer, PostScript, StreamKind, StripeFooter,
+    StripeInformation, Type, TypeKind,
+};
+use arrow_array::{make_array, RecordBatch, RecordBatchOptions, StructArray};
+use arrow_schema::{ArrowError, DataType, Schema, SchemaRef};
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
+
+/// The magic bytes at the start of an ORC file, and in its postscript
+const MAGIC: &[u8] = b"ORC";
+
+/// ORC file reader builder
+#[derive(Debug, Clone)]
+pub struct ReaderBuilder {
+    batch_size: usize,
+}
+
+impl Default for ReaderBuilder {
+    fn default() -> Self {
+        Self { batch_size: 1024 }
+    }
+}
+
+impl ReaderBuilder {
+    /// Create a new builder for configuring ORC reading options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of rows per [`RecordBatch`], defaults to 1024
+    ///
+    /// Batches do not span stripes, and so may contain fewer rows
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Create a new [`Reader`] from the provided reader, reading the file tail
+    pub fn build<R: Read + Seek>(self, mut reader: R) -> Result<Reader<R>, ArrowError> {
+        if self.batch_size == 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "batch size must be greater than 0".to_string(),
+            ));
+        }
+
+        let file_len = reader.seek(SeekFrom::End(0))?;
+        if file_len < MAGIC.len() as u64 + 1 {
+            return Err(ArrowError::ParseError("ORC file too short".to_string()));
+        }
+
+        let postscript_len = read_at(&mut reader, file_len - 1, 1)?[0] as u64;
+        let postscript_start =
+            file_len.checked_sub(postscript_len + 1).ok_or_else(|| {
+                ArrowError::ParseError("Invalid ORC postscript length".to_string())
+            })?;
+        let postscript: PostScript = decode(&read_at(
+            &mut reader,
+            postscript_start,
+            postscript_len as usize,
+        )?)?;
+        if postscript.magic.as_bytes() != MAGIC {
+            return Err(ArrowError::ParseError("Not an ORC file".to_string()));
+        }
+
+        let footer_start = postscript_start
+            .checked_sub(postscript.footer_length)
+            .ok_or_else(|| {
+                ArrowError::ParseError("Invalid ORC footer length".to_string())
+            })?;
+        let footer =
+            read_at(&mut reader, footer_start, postscript.footer_length as usize)?;
+        let footer: Footer = decode(&decompress(postscript.compression, &footer)?)?;
+
+        match footer.types.first() {
+            Some(root) if root.kind == TypeKind::Struct => {}
+            _ => {
+                return Err(ArrowError::ParseError(
+                    "ORC file root type must be a struct".to_string(),
+                ))
+            }
+        }
+        let metadata = footer
+            .metadata
+            .iter()
+            .map(|m| {
+                (
+                    m.name.clone(),
+                    String::from_utf8_lossy(&m.value).into_owned(),
+                )
+            })
+            .collect();
+        let schema =
+            Schema::new_with_metadata(struct_fields(&footer.types, 0)?, metadata);
+
+        Ok(Reader {
+            reader,
+            file_len,
+            schema: Arc::new(schema),
+            compression: postscript.compression,
+            stripes: footer.stripes,
+            types: footer.types,
+            num_rows: footer.number_of_rows,
+            batch_size: self.batch_size,
+            next_stripe: 0,
+            current: None,
+        })
+    }
+}
+
+/// Reads [`RecordBatch`] from an ORC file, decoding one stripe at a time
+///
+/// User metadata of the file is exposed as lossily UTF-8 decoded schema metadata.
+#[derive(Debug)]
+pub struct Reader<R> {
+    reader: R,
+    file_len: u64,
+    schema: SchemaRef,
+    compression: CompressionKind,
+    stripes: Vec<StripeInformation>,
+    types: Vec<Type>,
+    num_rows: u64,
+    batch_size: usize,
+    next_stripe: usize,
+    /// The decoded current stripe and the offset of the next batch within it
+    current: Option<(RecordBatch, usize)>,
+}
+
+impl<R: Read + Seek> Reader<R> {
+    /// Create a new [`Reader`] with the default options
+    pub fn try_new(reader: R) -> Result<Self, ArrowError> {
+        ReaderBuilder::new().build(reader)
+    }
+
+    /// Returns the Arrow schema of the batches produced by this reader
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns the total number of rows in the file
+    pub fn num_rows(&self) -> u64 {
+        self.num_rows
+    }
+
+    /// Returns the compression kind of the file
+    pub fn compression(&self) -> CompressionKind {
+        self.compression
+    }
+
+    /// Returns the number of stripes in the file
+    pub fn num_stripes(&self) -> usize {
+        self.stripes.len()
+    }
+
+    /// Reads and decodes the stripe with index `idx`
+    fn read_stripe(&mut self, idx: usize) -> Result<RecordBatch, ArrowError> {
+        let info = &self.stripes[idx];
+        let len = info
+            .index_length
+            .checked_add(info.data_length)
+            .and_then(|len| len.checked_add(info.footer_length))
+            .ok_or_else(|| {
+                ArrowError::ParseError(format!("Invalid ORC stripe {} length", idx))
+            })?;
+        match info.offset.checked_add(len) {
+            Some(end) if end <= self.file_len => {}
+            _ => {
+                return Err(ArrowError::ParseError(format!(
+                    "ORC stripe {} exceeds file length",
+                    idx
+                )))
+            }
+        }
+        let data = read_at(&mut self.reader, info.offset, len as usize)?;
+        let (streams_data, footer) = data.split_at((len - info.footer_length) as usize);
+        let footer: StripeFooter = decode(&decompress(self.compression, footer)?)?;
+
+        let mut streams = HashMap::with_capacity(footer.streams.len());
+        let mut offset = 0_usize;
+        for stream in &footer.streams {
+            let bytes = usize::try_from(stream.length)
+                .ok()
+                .and_then(|len| offset.checked_add(len))
+                .and_then(|end| streams_data.get(offset..end))
+                .ok_or_else(|| {
+                    ArrowError::ParseError(format!(
+                        "ORC stream for column {} exceeds stripe",
+                        stream.column
+                    ))
+                })?;
+            offset += bytes.len();
+            if !matches!(stream.kind, StreamKind::Other(_)) {
+                let bytes = decompress(self.compression, bytes)?;
+                streams.insert((stream.column as usize, stream.kind), bytes);
+            }
+        }
+
+        let stripe = Stripe {
+            types: &self.types,
+            encodings: footer.columns,
+            streams,
+        };
+        let num_rows = info.number_of_rows as usize;
+        let data = stripe.decode(
+            0,
+            &DataType::Struct(self.schema.fields().clone()),
+            num_rows,
+        )?;
+        let columns = StructArray::from(data)
+            .columns()
+            .into_iter()
+            .map(|c| make_array(c.data().clone()))
+            .collect();
+        let options = RecordBatchOptions::new().with_row_count(Some(num_rows));
+        RecordBatch::try_new_with_options(self.schema.clone(), columns, &options)
+    }
+
+    fn read_batch(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        loop {
+            if let Some((stripe, offset)) = &mut self.current {
+                if *offset < stripe.num_rows() {
+                    let len = self.batch_size.min(stripe.num_rows() - *offset);
+                    let batch = stripe.slice(*offset, len);
+                    *offset += len;
+                    return Ok(Some(batch));
+                }
+            }
+            if self.next_stripe == self.stripes.len() {
+                return Ok(None);
+            }
+            // advance past the stripe before reading it, so that an error
+            // isn't returned again by the next call
+            let idx = self.next_stripe;
+            self.next_stripe += 1;
+            let stripe = self.read_stripe(idx)?;
+            self.current = Some((stripe, 0));
+        }
+    }
+}
+
+impl<R: Read + Seek> Iterator for Reader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_batch().transpose()
+    }
+}
+
+fn read_at<R: Read + Seek>(
+    reader: &mut R,
+    offset: u64,
+    len: usize,
+) -> Result<Vec<u8>, ArrowError> {
+    reader.seek(SeekFrom::Start(offset))?;
+    // the length is untrusted, so only allocate as much as the input contains
+    let mut buf = Vec::new();
+    reader.take(len as u64).read_to_end(&mut buf)?;
+    if buf.len() != len {
+        return Err(ArrowError::ParseError(format!(
+            "ORC file truncated reading {} bytes at offset {}",
+            len, offset
+        )));
+    }
+    Ok(buf)
+}
+
+#[cfg(all(test, feature = "zlib"))]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{Int32Builder, MapBuilder, MapFieldNames, StringBuilder};
+    use arrow_array::cast::*;
+    use arrow_array::types::*;
+    use arrow_array::*;
+    use std::io::{Cursor, Write};
+
+    fn varint(out: &mut Vec<u8>, mut v: u64) {
+        while v >= 0x80 {
+            out.push(v as u8 | 0x80);
+            v >>= 7;
+        }
+        out.push(v as u8);
+    }
+
+    fn uint_field(out: &mut Vec<u8>, field: u64, v: u64) {
+        varint(out, field << 3);
+        varint(out, v);
+    }
+
+    fn bytes_field(out: &mut Vec<u8>, field: u64, v: &[u8]) {
+        varint(out, (field << 3) | 2);
+        varint(out, v.len() as u64);
+        out.extend_from_slice(v);
+    }
+
+    fn zigzag(v: i64) -> u64 {
+        ((v << 1) ^ (v >> 63)) as u64
+    }
+
+    /// Integer run length encoding version 1 using only literal runs
+    fn rle_v1(values: &[i64], signed: bool) -> Vec<u8> {
+        let mut out = vec![];
+        for chunk in values.chunks(128) {
+            out.push((-(chunk.len() as i64)) as u8);
+            for v in chunk {
+                varint(&mut out, if signed { zigzag(*v) } else { *v as u64 });
+            }
+        }
+        out
+    }
+
+    /// Boolean run length encoding using only literal runs
+    fn bools(values: &[bool]) -> Vec<u8> {
+        let mut bytes = vec![0_u8; (values.len() + 7) / 8];
+        for (i, v) in values.iter().enumerate() {
+            if *v {
+                bytes[i / 8] |= 0x80 >> (i % 8);
+            }
+        }
+        let mut out = vec![];
+        for chunk in bytes.chunks(128) {
+            out.push((0x100 - chunk.len()) as u8);
+            out.extend_from_slice(chunk);
+        }
+        out
+    }
+
+    fn orc_type(
+        kind: u64,
+        subtypes: &[u64],
+        names: &[&str],
+        decimal: Option<(u64, u64)>,
+    ) -> Vec<u8> {
+        let mut out = vec![];
+        uint_field(&mut out, 1, kind);
+        for s in subtypes {
+            uint_field(&mut out, 2, *s);
+        }
+        for n in names {
+            bytes_field(&mut out, 3, n.as_bytes());
+        }
+        if let Some((precision, scale)) = decimal {
+            uint_field(&mut out, 5, precision);
+            uint_field(&mut out, 6, scale);
+        }
+        out
+    }
+
+    fn compress(data: &[u8], zlib: bool) -> Vec<u8> {
+        if !zlib {
+            return data.to_vec();
+        }
+        let mut encoder =
+            flate2::write::DeflateEncoder::new(vec![], flate2::Compression::default());
+        encoder.write_all(data).unwrap();
+        let compressed = encoder.finish().unwrap();
+        let header = ((compressed.len() as u32) << 1).to_le_bytes();
+        let mut out = header[..3].to_vec();
+        out.extend_from_slice(&compressed);
+        out
+    }
+
+    /// Writes an ORC file with a single stripe
+    fn write_file(
+        types: &[Vec<u8>],
+        encodings: &[(u64, u64)],
+        streams: &[(u64, u64, Vec<u8>)],
+        rows: u64,
+        zlib: bool,
+    ) -> Vec<u8> {
+        let mut file = MAGIC.to_vec();
+        let mut stripe_footer = vec![];
+        let mut index_length = 0;
+        let mut data_length = 0;
+        for (column, kind, data) in streams {
+            let data = compress(data, zlib);
+            file.extend_from_slice(&data);
+            match *kind {
+                6 => index_length += data.len() as u64,
+                _ => data_length += data.len() as u64,
+            }
+            let mut stream = vec![];
+            uint_field(&mut stream, 1, *kind);
+            uint_field(&mut stream, 2, *column);
+            uint_field(&mut stream, 3, data.len() as u64);
+            bytes_field(&mut stripe_footer, 1, &stream);
+        }
+        for (kind, dictionary_size) in encodings {
+            let mut encoding = vec![];
+            uint_field(&mut encoding, 1, *kind);
+            uint_field(&mut encoding, 2, *dictionary_size);
+            bytes_field(&mut stripe_footer, 2, &encoding);
+        }
+        let stripe_footer = compress(&stripe_footer, zlib);
+        file.extend_from_slice(&stripe_footer);
+
+        let mut stripe = vec![];
+        uint_field(&mut stripe, 1, MAGIC.len() as u64);
+        uint_field(&mut stripe, 2, index_length);
+        uint_field(&mut stripe, 3, data_length);
+        uint_field(&mut stripe, 4, stripe_footer.len() as u64);
+        uint_field(&mut stripe, 5, rows);
+
+        let mut footer = vec![];
+        uint_field(&mut footer, 1, MAGIC.len() as u64);
+        bytes_field(&mut footer, 3, &stripe);
+        for t in types {
+            bytes_field(&mut footer, 4, t);
+        }
+        let mut metadata = vec![];
+        bytes_field(&mut metadata, 1, b"writer");
+        bytes_field(&mut metadata, 2, b"test");
+        bytes_field(&mut footer, 5, &metadata);
+        uint_field(&mut footer, 6, rows);
+        let footer = compress(&footer, zlib);
+        file.extend_from_slice(&footer);
+
+        let mut postscript = vec![];
+        uint_field(&mut postscript, 1, footer.len() as u64);
+        uint_field(&mut postscript, 2, zlib as u64);
+        uint_field(&mut postscript, 3, 256 * 1024);
+        bytes_field(&mut postscript, 8000, MAGIC);
+        file.extend_from_slice(&postscript);
+        file.push(postscript.len() as u8);
+        file
+    }
+
+    fn test_file(zlib: bool) -> Vec<u8> {
+        let types = vec![
+            orc_type(
+                12,
+                &[1, 2, 3, 4, 6, 8, 9, 10, 11],
+                &["a", "b", "c", "d", "e", "f", "g", "h", "m"],
+                None,
+            ),
+            orc_type(3, &[], &[], None),
+            orc_type(7, &[], &[], None),
+            orc_type(16, &[], &[], None),
+            orc_type(10, &[5], &[], None),
+            orc_type(4, &[], &[], None),
+            orc_type(12, &[7], &["x"], None),
+            orc_type(6, &[], &[], None),
+            orc_type(0, &[], &[], None),
+            orc_type(14, &[], &[], Some((10, 2))),
+            orc_type(9, &[], &[], None),
+            orc_type(11, &[12, 13], &[], None),
+            orc_type(7, &[], &[], None),
+            orc_type(3, &[], &[], None),
+        ];
+        // Column 1 uses DIRECT_V2 and column 3 DICTIONARY, the rest DIRECT
+        let mut encodings = vec![(0, 0); 14];
+        encodings[1] = (2, 0);
+        encodings[3] = (1, 2);
+
+        let epoch = ORC_EPOCH;
+        let streams = vec![
+            // A row index stream which should be skipped
+            (1, 6, vec![0xFF; 4]),
+            (1, 0, bools(&[true, false, true])),
+            // DIRECT_V2 run of zigzag encoded [1, -3] with 4 bit width
+            (1, 1, vec![0x46, 0x01, 0x25]),
+            (2, 0, bools(&[true, true, false])),
+            (2, 2, rle_v1(&[1, 2], false)),
+            (2, 1, b"xyy".to_vec()),
+            (3, 1, rle_v1(&[1, 0, 1], false)),
+            (3, 2, rle_v1(&[3, 3], false)),
+            (3, 3, b"foobar".to_vec()),
+            (4, 0, bools(&[true, false, true])),
+            (4, 2, rle_v1(&[2, 0], false)),
+            (5, 1, rle_v1(&[1, 2], true)),
+            (6, 0, bools(&[true, true, false])),
+            (7, 0, bools(&[true, false])),
+            (7, 1, 1.5_f64.to_le_bytes().to_vec()),
+            (8, 1, bools(&[true, false, true])),
+            (9, 1, {
+                let mut out = vec![];
+                for v in [123, -5, 100] {
+                    varint(&mut out, zigzag(v));
+                }
+                out
+            }),
+            (9, 5, rle_v1(&[2, 1, 0], true)),
+            (10, 1, rle_v1(&[-epoch, 1, -1 - epoch], true)),
+            // 500_000_000 nanos is 5 with 8 trailing zeros, stored as (5 << 3) | 7
+            (10, 5, rle_v1(&[47, 0, 0], false)),
+            (11, 2, rle_v1(&[1, 0, 2], false)),
+            (12, 2, rle_v1(&[1, 1, 1], false)),
+            (12, 1, b"kab".to_vec()),
+            (13, 0, bools(&[true, true, false])),
+            (13, 1, rle_v1(&[1, 2], true)),
+        ];
+        write_file(&types, &encodings, &streams, 3, zlib)
+    }
+
+    const ORC_EPOCH: i64 = 1_420_070_400;
+
+    #[test]
+    fn test_read() {
+        for zlib in [false, true] {
+            let data = test_file(zlib);
+            let reader = ReaderBuilder::new()
+                .with_batch_size(2)
+                .build(Cursor::new(data))
+                .unwrap();
+            assert_eq!(reader.num_rows(), 3);
+            assert_eq!(reader.num_stripes(), 1);
+            let schema = reader.schema();
+            assert_eq!(schema.metadata()["writer"], "test");
+
+            let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+            assert_eq!(batches.len(), 2);
+            assert_eq!(batches[0].num_rows(), 2);
+            assert_eq!(batches[1].num_rows(), 1);
+            let batch = arrow_select_concat(&batches);
+
+            assert_eq!(
+                as_primitive_array::<Int32Type>(batch.column(0)),
+                &Int32Array::from(vec![Some(1), None, Some(-3)])
+            );
+            assert_eq!(
+                as_string_array(batch.column(1)),
+                &StringArray::from(vec![Some("x"), Some("yy"), None])
+            );
+            assert_eq!(
+                as_string_array(batch.column(2)),
+                &StringArray::from(vec!["bar", "foo", "bar"])
+            );
+
+            let list = as_list_array(batch.column(3));
+            let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), Some(2)]),
+                None,
+                Some(vec![]),
+            ]);
+            assert_eq!(list, &expected);
+
+            let strukt = as_struct_array(batch.column(4));
+            assert_eq!(strukt.null_count(), 1);
+            assert!(strukt.is_null(2));
+            let x = as_primitive_array::<Float64Type>(strukt.column(0));
+            assert_eq!(x.value(0), 1.5);
+            assert!(x.is_null(1));
+
+            assert_eq!(
+                as_boolean_array(batch.column(5)),
+                &BooleanArray::from(vec![true, false, true])
+            );
+
+            let expected = [Some(123_i128), Some(-50), Some(10000)]
+                .into_iter()
+                .collect::<Decimal128Array>()
+                .with_precision_and_scale(10, 2)
+                .unwrap();
+            assert_eq!(as_decimal_array(batch.column(6)), &expected);
+
+            assert_eq!(
+                as_primitive_array::<TimestampNanosecondType>(batch.column(7)),
+                &TimestampNanosecondArray::from(vec![
+                    500_000_000,
+                    (ORC_EPOCH + 1) * 1_000_000_000,
+                    -1_000_000_000
+                ])
+            );
+
+            let mut map = MapBuilder::new(
+                Some(MapFieldNames {
+                    entry: "entries".to_string(),
+                    key: "key".to_string(),
+                    value: "value".to_string(),
+                }),
+                StringBuilder::new(),
+                Int32Builder::new(),
+            );
+            map.keys().append_value("k");
+            map.values().append_value(1);
+            map.append(true).unwrap();
+            map.append(true).unwrap();
+            map.keys().append_value("a");
+            map.values().append_value(2);
+            map.keys().append_value("b");
+            map.values().append_null();
+            map.append(true).unwrap();
+            let map = map.finish();
+            let actual = as_map_array(batch.column(8));
+            assert_eq!(actual.value_offsets(), map.value_offsets());
+            assert_eq!(actual.keys().data(), map.keys().data());
+            assert_eq!(actual.values().data(), map.values().data());
+        }
+    }
+
+    /// Concatenates batches by appending their rows, avoiding a dependency on arrow-select
+    fn arrow_select_concat(batches: &[RecordBatch]) -> RecordBatch {
+        let columns = (0..batches[0].num_columns())
+            .map(|i| {
+                let data: Vec<_> = batches.iter().map(|b| b.column(i).data()).collect();
+                let len = data.iter().map(|d| d.len()).sum();
+                let mut mutable =
+                    arrow_data::transform::MutableArrayData::new(data.clone(), true, len);
+                for (idx, d) in data.iter().enumerate() {
+                    mutable.extend(idx, 0, d.len());
+                }
+                make_array(mutable.freeze())
+            })
+            .collect();
+        RecordBatch::try_new(batches[0].schema(), columns).unwrap()
+    }
+
+    #[test]
+    fn test_invalid() {
+        let err = Reader::try_new(Cursor::new(b"ORC")).unwrap_err();
+        assert_eq!(err.to_string(), "Parser error: ORC file too short");
+
+        let mut data = test_file(false);
+        let len = data.len();
+        data[len - 2] = b'X';
+        let err = Reader::try_new(Cursor::new(data)).unwrap_err();
+        assert_eq!(err.to_string(), "Parser error: Not an ORC file");
+
+        let types = vec![
+            orc_type(12, &[1], &["a"], None),
+            orc_type(3, &[], &[], None),
+        ];
+        let file = write_file(&types, &[(0, 0), (0, 0)], &[], 1, false);
+        let err = Reader::try_new(Cursor::new(file))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: ORC column 1 missing Data stream"
+        );
+    }
+
+    #[test]
+    fn test_invalid_stripe() {
+        let mut reader = Reader::try_new(Cursor::new(test_file(false))).unwrap();
+        reader.stripes[0].data_length = u64::MAX;
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "Parser error: Invalid ORC stripe 0 length");
+        // the failed stripe is skipped rather than read again
+        assert!(reader.next().is_none());
+
+        let mut reader = Reader::try_new(Cursor::new(test_file(false))).unwrap();
+        reader.stripes[0].data_length += 1000;
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: ORC stripe 0 exceeds file length"
+        );
+    }
+}
@@ -335,3 +335,67 @@ def test_record_batch_reader():
     assert b.schema == schema
     got_batches = list(b)
     assert got_batches == batches
+
+
+class UuidType(pa.ExtensionType):
+    def __init__(self):
+        super().__init__(pa.binary(16), "arrow_rs.uuid")
+
+    def __arrow_ext_serialize__(self):
+        return b""
+
+    @classmethod
+    def __arrow_ext_deserialize__(cls, storage_type, serialized):
+        return cls()
+
+
+@pytest.fixture
+def uuid_type():
+    pa.register_extension_type(UuidType())
+    try:
+        yield UuidType()
+    finally:
+        pa.unregister_extension_type("arrow_rs.uuid")
+
+
+def test_field_metadata_roundtrip():
+    field = pa.field("test", pa.int32(), metadata={"key": "value", "empty": ""})
+    result = rust.round_trip_field(field)
+    assert result.equals(field, check_metadata=True)
+
+
+def test_extension_field_roundtrip(uuid_type):
+    field = pa.field("uuid", uuid_type, metadata={"key": "value"})
+    result = rust.round_trip_field(field)
+    assert result.type == uuid_type
+    assert result.equals(field, check_metadata=True)
+
+    nested = pa.field("nested", pa.struct([field]))
+    result = rust.round_trip_field(nested)
+    assert result.type.field(0).type == uuid_type
+    assert result.equals(nested, check_metadata=True)
+
+
+def test_extension_schema_roundtrip(uuid_type):
+    schema = pa.schema(
+        [pa.field("uuid", uuid_type), pa.field("ints", pa.int32())],
+        metadata={"key": "value"},
+    )
+    result = rust.round_trip_schema(schema)
+    assert result.field("uuid").type == uuid_type
+    assert result.equals(schema, check_metadata=True)
+
+
+def test_extension_record_batch_roundtrip(uuid_type):
+    storage = pa.array([b"0" * 16, None, b"1" * 16], pa.binary(16))
+    schema = pa.schema(
+        [pa.field("uuid", uuid_type, metadata={"key": "value"})],
+        metadata={"schema_key": "value"},
+    )
+    batch = pa.record_batch(
+        [pa.ExtensionArray.from_storage(uuid_type, storage)], schema=schema
+    )
+    result = rust.round_trip_record_batch(batch)
+    assert result.schema.equals(schema, check_metadata=True)
+    assert result.equals(batch)
+    del result
@@ -62,6 +62,12 @@ impl From<::std::string::FromUtf8Error> for ArrowError {
     }
 }
 
+impl From<::std::fmt::Error> for ArrowError {
+    fn from(error: std::fmt::Error) -> Self {
+        ArrowError::ExternalError(Box::new(error))
+    }
+}
+
 impl<W: Write> From<::std::io::IntoInnerError<W>> for ArrowError {
     fn from(error: std::io::IntoInnerError<W>) -> Self {
         ArrowError::IoError(error.to_string())
@@ -114,6 +114,16 @@ impl Schema {
         Ok(Self::new_with_metadata(new_fields, self.metadata.clone()))
     }
 
+    /// Returns a new schema with only the columns named `names`, in the given order.
+    /// This carries metadata from the parent schema over as well
+    pub fn select_by_name(&self, names: &[&str]) -> Result<Schema, ArrowError> {
+        let indices = names
+            .iter()
+            .map(|name| self.index_of(name))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.project(&indices)
+    }
+
     /// Merge schema into self if it is compatible. Struct fields will be merged recursively.
     ///
     /// Example:
@@ -336,6 +346,32 @@ mod tests {
         assert_eq!(projected.metadata.get("meta").unwrap(), "data")
     }
 
+    #[test]
+    fn test_select_by_name() {
+        let mut metadata = HashMap::new();
+        metadata.insert("meta".to_string(), "data".to_string());
+
+        let schema = Schema::new(vec![
+            Field::new("name", DataType::Utf8, false),
+            Field::new("address", DataType::Utf8, false),
+            Field::new("priority", DataType::UInt8, false),
+        ])
+        .with_metadata(metadata);
+
+        let selected = schema.select_by_name(&["priority", "name"]).unwrap();
+
+        assert_eq!(selected.fields().len(), 2);
+        assert_eq!(selected.fields()[0].name(), "priority");
+        assert_eq!(selected.fields()[1].name(), "name");
+        assert_eq!(selected.metadata.get("meta").unwrap(), "data");
+
+        let err = schema.select_by_name(&["name", "age"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Unable to get field named \"age\". Valid fields: [\"name\", \"address\", \"priority\"]"
+        );
+    }
+
     #[test]
     fn test_oob_projection() {
         let mut metadata = HashMap::new();
@@ -20,7 +20,7 @@ extern crate criterion;
 extern crate core;
 
 use arrow::array::ArrayRef;
-use arrow::datatypes::{Int64Type, UInt64Type};
+use arrow::datatypes::{Float64Type, Int64Type, UInt32Type, UInt64Type};
 use arrow::row::{RowConverter, SortField};
 use arrow::util::bench_util::{
     create_primitive_array, create_string_array_with_len, create_string_dict_array,
@@ -58,6 +58,20 @@ fn row_bench(c: &mut Criterion) {
     let cols = vec![Arc::new(create_primitive_array::<Int64Type>(4096, 0.)) as ArrayRef];
     do_bench(c, "4096 i64(0)", cols);
 
+    let cols = vec![Arc::new(create_primitive_array::<Int64Type>(4096, 0.3)) as ArrayRef];
+    do_bench(c, "4096 i64(0.3)", cols);
+
+    let cols = (0..8)
+        .flat_map(|_| {
+            [
+                Arc::new(create_primitive_array::<Int64Type>(4096, 0.)) as ArrayRef,
+                Arc::new(create_primitive_array::<UInt32Type>(4096, 0.1)) as ArrayRef,
+                Arc::new(create_primitive_array::<Float64Type>(4096, 0.)) as ArrayRef,
+            ]
+        })
+        .collect();
+    do_bench(c, "4096 8 x (i64(0), u32(0.1), f64(0))", cols);
+
     let cols =
         vec![Arc::new(create_string_array_with_len::<i32>(4096, 0., 10)) as ArrayRef];
     do_bench(c, "4096 string(10, 0)", cols);
@@ -0,0 +1,58 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Benchmarks the row encoding of variable length values around the boundaries
+//! of its blocks, reporting the throughput in bytes of input values
+
+#[macro_use]
+extern crate criterion;
+
+use arrow::array::{Array, ArrayRef};
+use arrow::row::{RowConverter, SortField};
+use arrow::util::bench_util::create_string_array_with_len;
+use criterion::{black_box, Criterion, Throughput};
+use std::sync::Arc;
+
+fn row_variable_length_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("row_variable_length");
+
+    for len in [0, 4, 8, 10, 24, 32, 33, 64, 100, 1000] {
+        let array = create_string_array_with_len::<i32>(4096, 0., len);
+        let values = array.value_data().len() as u64;
+        let cols = vec![Arc::new(array) as ArrayRef];
+        let fields = vec![SortField::new(cols[0].data_type().clone())];
+
+        group.throughput(Throughput::Bytes(values));
+        group.bench_function(format!("convert_columns 4096 string({})", len), |b| {
+            b.iter(|| {
+                let mut converter = RowConverter::new(fields.clone());
+                black_box(converter.convert_columns(&cols).unwrap())
+            });
+        });
+
+        let mut converter = RowConverter::new(fields);
+        let rows = converter.convert_columns(&cols).unwrap();
+        group.bench_function(format!("convert_rows 4096 string({})", len), |b| {
+            b.iter(|| black_box(converter.convert_rows(&rows).unwrap()));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, row_variable_length_bench);
+criterion_main!(benches);
@@ -18,6 +18,7 @@
 //! Contains functions and function factories to compare arrays.
 
 use std::cmp::Ordering;
+use std::ops::Range;
 
 use crate::array::*;
 use crate::datatypes::TimeUnit;
@@ -155,9 +156,109 @@ where
     })
 }
 
+/// Returns a comparator that, unlike [`build_compare`], orders null values
+/// before valid values, as required to compare the children of nested arrays
+fn compare_nulls_first(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let cmp = build_compare(left, right)?;
+    let left = left.data().clone();
+    let right = right.data().clone();
+    Ok(Box::new(move |i, j| {
+        match (left.is_valid(i), right.is_valid(j)) {
+            (true, true) => cmp(i, j),
+            (false, false) => Ordering::Equal,
+            (false, true) => Ordering::Less,
+            (true, false) => Ordering::Greater,
+        }
+    }))
+}
+
+/// Lexicographically compares the values of `left` with those of `right`
+fn compare_ranges(
+    cmp: &DynComparator,
+    left: Range<usize>,
+    right: Range<usize>,
+) -> Ordering {
+    let (left_len, right_len) = (left.len(), right.len());
+    left.zip(right)
+        .map(|(i, j)| cmp(i, j))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| left_len.cmp(&right_len))
+}
+
+fn compare_struct(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<StructArray>().unwrap();
+    let right = right.as_any().downcast_ref::<StructArray>().unwrap();
+    let comparators = left
+        .columns()
+        .iter()
+        .zip(right.columns())
+        .map(|(l, r)| compare_nulls_first(l.as_ref(), r.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Box::new(move |i, j| {
+        comparators
+            .iter()
+            .map(|cmp| cmp(i, j))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }))
+}
+
+fn compare_list<O: OffsetSizeTrait>(
+    left: &dyn Array,
+    right: &dyn Array,
+) -> Result<DynComparator> {
+    let left = GenericListArray::<O>::from(left.data().clone());
+    let right = GenericListArray::<O>::from(right.data().clone());
+    let cmp = compare_nulls_first(left.values().as_ref(), right.values().as_ref())?;
+
+    Ok(Box::new(move |i, j| {
+        let (l, r) = (left.value_offsets(), right.value_offsets());
+        compare_ranges(
+            &cmp,
+            l[i].as_usize()..l[i + 1].as_usize(),
+            r[j].as_usize()..r[j + 1].as_usize(),
+        )
+    }))
+}
+
+fn compare_fixed_size_list(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = FixedSizeListArray::from(left.data().clone());
+    let right = FixedSizeListArray::from(right.data().clone());
+    let cmp = compare_nulls_first(left.values().as_ref(), right.values().as_ref())?;
+
+    Ok(Box::new(move |i, j| {
+        let size = left.value_length() as usize;
+        let l = left.value_offset(i) as usize;
+        let r = right.value_offset(j) as usize;
+        compare_ranges(&cmp, l..l + size, r..r + size)
+    }))
+}
+
+fn compare_map(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = MapArray::from(left.data().clone());
+    let right = MapArray::from(right.data().clone());
+    let left_entries = make_array(left.data().child_data()[0].clone());
+    let right_entries = make_array(right.data().child_data()[0].clone());
+    let cmp = compare_nulls_first(left_entries.as_ref(), right_entries.as_ref())?;
+
+    Ok(Box::new(move |i, j| {
+        let (l, r) = (left.value_offsets(), right.value_offsets());
+        compare_ranges(
+            &cmp,
+            l[i] as usize..l[i + 1] as usize,
+            r[j] as usize..r[j + 1] as usize,
+        )
+    }))
+}
+
 /// returns a comparison function that compares two values at two different positions
 /// between the two arrays.
 /// The arrays' types must be equal.
+///
+/// Struct, list and map arrays are compared lexicographically by their fields
+/// or elements, ordering null fields and elements before valid ones. The
+/// comparator does not check the validity of the values at the two positions.
 /// # Example
 /// ```
 /// use arrow::array::{build_compare, Int32Array};
@@ -295,6 +396,13 @@ pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparato
             let right: Decimal128Array = Decimal128Array::from(right.data().clone());
             Box::new(move |i, j| left.value(i).cmp(&right.value(j)))
         }
+        (Struct(_), Struct(_)) => compare_struct(left, right)?,
+        (List(_), List(_)) => compare_list::<i32>(left, right)?,
+        (LargeList(_), LargeList(_)) => compare_list::<i64>(left, right)?,
+        (FixedSizeList(_, _), FixedSizeList(_, _)) => {
+            compare_fixed_size_list(left, right)?
+        }
+        (Map(_, _), Map(_, _)) => compare_map(left, right)?,
         (lhs, _) => {
             return Err(ArrowError::InvalidArgumentError(format!(
                 "The data type type {:?} has no natural order",
@@ -310,6 +418,7 @@ pub mod tests {
     use crate::array::{Float64Array, Int32Array};
     use crate::error::Result;
     use std::cmp::Ordering;
+    use std::sync::Arc;
 
     #[test]
     fn test_i32() -> Result<()> {
@@ -424,4 +533,92 @@ pub mod tests {
         assert_eq!(Ordering::Greater, (cmp)(3, 2));
         Ok(())
     }
+
+    #[test]
+    fn test_struct() -> Result<()> {
+        let a: ArrayRef =
+            Arc::new(Int32Array::from(vec![Some(1), Some(1), None, Some(2)]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "x", "a"]));
+        let array = StructArray::try_from(vec![("a", a), ("b", b)])?;
+
+        let cmp = build_compare(&array, &array)?;
+        assert_eq!(Ordering::Less, (cmp)(0, 1));
+        assert_eq!(Ordering::Equal, (cmp)(1, 1));
+        // null fields are ordered first
+        assert_eq!(Ordering::Less, (cmp)(2, 0));
+        assert_eq!(Ordering::Greater, (cmp)(3, 1));
+
+        let sliced = array.slice(1, 3);
+        let cmp = build_compare(sliced.as_ref(), &array)?;
+        assert_eq!(Ordering::Equal, (cmp)(0, 1));
+        assert_eq!(Ordering::Equal, (cmp)(2, 3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_list() -> Result<()> {
+        let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1)]),
+            Some(vec![None, Some(5)]),
+            Some(vec![Some(1), Some(3)]),
+            Some(vec![]),
+        ]);
+
+        let cmp = build_compare(&array, &array)?;
+        // a prefix is ordered first
+        assert_eq!(Ordering::Greater, (cmp)(0, 1));
+        assert_eq!(Ordering::Less, (cmp)(0, 3));
+        assert_eq!(Ordering::Less, (cmp)(2, 1));
+        assert_eq!(Ordering::Less, (cmp)(4, 1));
+        assert_eq!(Ordering::Equal, (cmp)(3, 3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_size_list() -> Result<()> {
+        let values = Int32Array::from(vec![1, 2, 1, 3, 1, 2]);
+        let data = ArrayData::builder(DataType::FixedSizeList(
+            Box::new(Field::new("item", DataType::Int32, false)),
+            2,
+        ))
+        .len(3)
+        .add_child_data(values.into_data())
+        .build()?;
+        let array = FixedSizeListArray::from(data);
+
+        let cmp = build_compare(&array, &array)?;
+        assert_eq!(Ordering::Less, (cmp)(0, 1));
+        assert_eq!(Ordering::Equal, (cmp)(0, 2));
+
+        let sliced = array.slice(1, 2);
+        let cmp = build_compare(sliced.as_ref(), &array)?;
+        assert_eq!(Ordering::Greater, (cmp)(0, 0));
+        assert_eq!(Ordering::Equal, (cmp)(1, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_map() -> Result<()> {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.append(true)?;
+        builder.keys().append_value("a");
+        builder.values().append_value(2);
+        builder.append(true)?;
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("b");
+        builder.values().append_null();
+        builder.append(true)?;
+        let array = builder.finish();
+
+        let cmp = build_compare(&array, &array)?;
+        assert_eq!(Ordering::Less, (cmp)(0, 1));
+        assert_eq!(Ordering::Less, (cmp)(0, 2));
+        assert_eq!(Ordering::Greater, (cmp)(1, 2));
+        Ok(())
+    }
 }
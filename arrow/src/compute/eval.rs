@@ -0,0 +1,484 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A minimal expression evaluator over [`RecordBatch`]
+//!
+//! Expressions are built from column references, literals, arithmetic,
+//! comparisons, boolean logic and casts, and are evaluated a whole batch at
+//! a time using the existing compute kernels. This is intended for computed
+//! columns and simple filters, and is not a replacement for a query engine:
+//! no type coercion is performed, so operands must have the same type or be
+//! explicitly [cast](Expr::cast).
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow::array::{ArrayRef, Int32Array};
+//! # use arrow::compute::eval::{col, lit};
+//! # use arrow::record_batch::RecordBatch;
+//! let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+//! let b: ArrayRef = Arc::new(Int32Array::from(vec![10, 20, 30]));
+//! let batch = RecordBatch::try_from_iter(vec![("a", a), ("b", b)]).unwrap();
+//!
+//! // a * 2 + b
+//! let expr = col("a") * lit(2) + col("b");
+//! let result = expr.evaluate(&batch).unwrap();
+//! assert_eq!(result.as_ref(), &Int32Array::from(vec![12, 24, 36]));
+//!
+//! // b > 15 AND a != 3
+//! let predicate = col("b").gt(lit(15)).and(col("a").not_eq(lit(3)));
+//! let filtered = predicate.filter(&batch).unwrap();
+//! assert_eq!(filtered.num_rows(), 1);
+//! ```
+
+use std::ops;
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::compute::kernels::datum::{self, Datum};
+use crate::compute::kernels::{boolean, cast, filter};
+use crate::datatypes::{DataType, Field, Schema};
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+
+/// A binary operator of an [`Expr`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    /// `left + right`
+    Plus,
+    /// `left - right`
+    Minus,
+    /// `left * right`
+    Multiply,
+    /// `left / right`
+    Divide,
+    /// `left = right`
+    Eq,
+    /// `left != right`
+    NotEq,
+    /// `left < right`
+    Lt,
+    /// `left <= right`
+    LtEq,
+    /// `left > right`
+    Gt,
+    /// `left >= right`
+    GtEq,
+    /// `left AND right`, using Kleene logic for nulls
+    And,
+    /// `left OR right`, using Kleene logic for nulls
+    Or,
+}
+
+/// An expression that can be evaluated against a [`RecordBatch`]
+#[derive(Debug, Clone)]
+pub enum Expr {
+    /// A column of the batch, referenced by name
+    Column(String),
+    /// A literal value, stored as an array of length one
+    Literal(ArrayRef),
+    /// A binary operation
+    Binary {
+        left: Box<Expr>,
+        op: Operator,
+        right: Box<Expr>,
+    },
+    /// Logical negation of a boolean expression
+    Not(Box<Expr>),
+    /// Whether the value of an expression is null
+    IsNull(Box<Expr>),
+    /// Whether the value of an expression is not null
+    IsNotNull(Box<Expr>),
+    /// Cast the value of an expression to a data type
+    Cast {
+        expr: Box<Expr>,
+        data_type: DataType,
+    },
+}
+
+/// Returns an expression referencing the column `name`
+pub fn col(name: impl Into<String>) -> Expr {
+    Expr::Column(name.into())
+}
+
+/// Returns a literal expression for `value`
+pub fn lit(value: impl Literal) -> Expr {
+    Expr::Literal(value.into_array())
+}
+
+/// A value that can be used as a literal in an [`Expr`]
+pub trait Literal {
+    /// Returns this value as an array of length one
+    fn into_array(self) -> ArrayRef;
+}
+
+macro_rules! primitive_literal {
+    ($native:ty, $array:ty) => {
+        impl Literal for $native {
+            fn into_array(self) -> ArrayRef {
+                Arc::new(<$array>::from(vec![self]))
+            }
+        }
+    };
+}
+
+primitive_literal!(i8, Int8Array);
+primitive_literal!(i16, Int16Array);
+primitive_literal!(i32, Int32Array);
+primitive_literal!(i64, Int64Array);
+primitive_literal!(u8, UInt8Array);
+primitive_literal!(u16, UInt16Array);
+primitive_literal!(u32, UInt32Array);
+primitive_literal!(u64, UInt64Array);
+primitive_literal!(f32, Float32Array);
+primitive_literal!(f64, Float64Array);
+primitive_literal!(bool, BooleanArray);
+
+impl Literal for &str {
+    fn into_array(self) -> ArrayRef {
+        Arc::new(StringArray::from(vec![self]))
+    }
+}
+
+impl Literal for String {
+    fn into_array(self) -> ArrayRef {
+        self.as_str().into_array()
+    }
+}
+
+impl Expr {
+    /// Returns an expression that applies `op` to `self` and `other`
+    pub fn binary(self, op: Operator, other: Expr) -> Expr {
+        Expr::Binary {
+            left: Box::new(self),
+            op,
+            right: Box::new(other),
+        }
+    }
+
+    /// Returns `self = other`
+    pub fn eq(self, other: Expr) -> Expr {
+        self.binary(Operator::Eq, other)
+    }
+
+    /// Returns `self != other`
+    pub fn not_eq(self, other: Expr) -> Expr {
+        self.binary(Operator::NotEq, other)
+    }
+
+    /// Returns `self < other`
+    pub fn lt(self, other: Expr) -> Expr {
+        self.binary(Operator::Lt, other)
+    }
+
+    /// Returns `self <= other`
+    pub fn lt_eq(self, other: Expr) -> Expr {
+        self.binary(Operator::LtEq, other)
+    }
+
+    /// Returns `self > other`
+    pub fn gt(self, other: Expr) -> Expr {
+        self.binary(Operator::Gt, other)
+    }
+
+    /// Returns `self >= other`
+    pub fn gt_eq(self, other: Expr) -> Expr {
+        self.binary(Operator::GtEq, other)
+    }
+
+    /// Returns `self AND other`
+    pub fn and(self, other: Expr) -> Expr {
+        self.binary(Operator::And, other)
+    }
+
+    /// Returns `self OR other`
+    pub fn or(self, other: Expr) -> Expr {
+        self.binary(Operator::Or, other)
+    }
+
+    /// Returns `self IS NULL`
+    pub fn is_null(self) -> Expr {
+        Expr::IsNull(Box::new(self))
+    }
+
+    /// Returns `self IS NOT NULL`
+    pub fn is_not_null(self) -> Expr {
+        Expr::IsNotNull(Box::new(self))
+    }
+
+    /// Returns `CAST(self AS data_type)`
+    pub fn cast(self, data_type: DataType) -> Expr {
+        Expr::Cast {
+            expr: Box::new(self),
+            data_type,
+        }
+    }
+
+    /// Evaluate this expression against `batch`, returning an array with one
+    /// value per row
+    pub fn evaluate(&self, batch: &RecordBatch) -> Result<ArrayRef> {
+        self.evaluate_datum(batch)?.into_array(batch.num_rows())
+    }
+
+    /// Returns the rows of `batch` for which this boolean expression
+    /// evaluates to true
+    pub fn filter(&self, batch: &RecordBatch) -> Result<RecordBatch> {
+        let predicate = self.evaluate(batch)?;
+        filter::filter_record_batch(batch, as_boolean(predicate.as_ref())?)
+    }
+
+    fn evaluate_datum(&self, batch: &RecordBatch) -> Result<Datum> {
+        match self {
+            Expr::Column(name) => {
+                let index = batch.schema().index_of(name)?;
+                Ok(Datum::Array(batch.column(index).clone()))
+            }
+            Expr::Literal(array) => match array.len() {
+                1 => Ok(Datum::Scalar(array.clone())),
+                len => Err(ArrowError::InvalidArgumentError(format!(
+                    "Literal must contain exactly one value, got {}",
+                    len
+                ))),
+            },
+            Expr::Binary { left, op, right } => {
+                let left = left.evaluate_datum(batch)?;
+                let right = right.evaluate_datum(batch)?;
+                evaluate_binary(left, *op, right, batch.num_rows())
+            }
+            Expr::Not(expr) => expr.map_value(batch, |array| {
+                Ok(Arc::new(boolean::not(as_boolean(array.as_ref())?)?))
+            }),
+            Expr::IsNull(expr) => expr.map_value(batch, |array| {
+                Ok(Arc::new(boolean::is_null(array.as_ref())?))
+            }),
+            Expr::IsNotNull(expr) => expr.map_value(batch, |array| {
+                Ok(Arc::new(boolean::is_not_null(array.as_ref())?))
+            }),
+            Expr::Cast { expr, data_type } => {
+                expr.map_value(batch, |array| cast::cast(array, data_type))
+            }
+        }
+    }
+
+    /// Evaluate this expression and apply `f` to the result
+    fn map_value<F>(&self, batch: &RecordBatch, f: F) -> Result<Datum>
+    where
+        F: FnOnce(&ArrayRef) -> Result<ArrayRef>,
+    {
+        Ok(match self.evaluate_datum(batch)? {
+            Datum::Array(array) => Datum::Array(f(&array)?),
+            Datum::Scalar(scalar) => Datum::Scalar(f(&scalar)?),
+        })
+    }
+}
+
+impl ops::Add for Expr {
+    type Output = Expr;
+
+    fn add(self, rhs: Expr) -> Expr {
+        self.binary(Operator::Plus, rhs)
+    }
+}
+
+impl ops::Sub for Expr {
+    type Output = Expr;
+
+    fn sub(self, rhs: Expr) -> Expr {
+        self.binary(Operator::Minus, rhs)
+    }
+}
+
+impl ops::Mul for Expr {
+    type Output = Expr;
+
+    fn mul(self, rhs: Expr) -> Expr {
+        self.binary(Operator::Multiply, rhs)
+    }
+}
+
+impl ops::Div for Expr {
+    type Output = Expr;
+
+    fn div(self, rhs: Expr) -> Expr {
+        self.binary(Operator::Divide, rhs)
+    }
+}
+
+impl ops::Not for Expr {
+    type Output = Expr;
+
+    fn not(self) -> Expr {
+        Expr::Not(Box::new(self))
+    }
+}
+
+/// Evaluate a list of named expressions against `batch`, returning a new
+/// [`RecordBatch`] with one column per expression
+pub fn project(batch: &RecordBatch, exprs: &[(&str, Expr)]) -> Result<RecordBatch> {
+    let columns = exprs
+        .iter()
+        .map(|(_, expr)| expr.evaluate(batch))
+        .collect::<Result<Vec<_>>>()?;
+    let fields = exprs
+        .iter()
+        .zip(&columns)
+        .map(|((name, _), column)| Field::new(name, column.data_type().clone(), true))
+        .collect();
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+}
+
+/// Evaluate `left op right`, where the result has `len` rows unless both
+/// operands are scalars
+fn evaluate_binary(left: Datum, op: Operator, right: Datum, len: usize) -> Result<Datum> {
+    match op {
+        Operator::Plus => datum::add_datum(&left, &right),
+        Operator::Minus => datum::subtract_datum(&left, &right),
+        Operator::Multiply => datum::multiply_datum(&left, &right),
+        Operator::Divide => datum::divide_datum(&left, &right),
+        Operator::Eq => datum::eq_datum(&left, &right),
+        Operator::NotEq => datum::neq_datum(&left, &right),
+        Operator::Lt => datum::lt_datum(&left, &right),
+        Operator::LtEq => datum::lt_eq_datum(&left, &right),
+        Operator::Gt => datum::gt_datum(&left, &right),
+        Operator::GtEq => datum::gt_eq_datum(&left, &right),
+        Operator::And | Operator::Or => {
+            let kleene = |l: &dyn Array, r: &dyn Array| -> Result<ArrayRef> {
+                let (l, r) = (as_boolean(l)?, as_boolean(r)?);
+                Ok(Arc::new(match op {
+                    Operator::And => boolean::and_kleene(l, r)?,
+                    _ => boolean::or_kleene(l, r)?,
+                }))
+            };
+            match (left, right) {
+                (Datum::Scalar(l), Datum::Scalar(r)) => {
+                    Ok(Datum::Scalar(kleene(l.as_ref(), r.as_ref())?))
+                }
+                (l, r) => {
+                    let (l, r) = (l.into_array(len)?, r.into_array(len)?);
+                    Ok(Datum::Array(kleene(l.as_ref(), r.as_ref())?))
+                }
+            }
+        }
+    }
+}
+
+fn as_boolean(array: &dyn Array) -> Result<&BooleanArray> {
+    array
+        .as_any()
+        .downcast_ref::<BooleanArray>()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Expected boolean expression, got {}",
+                array.data_type()
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch() -> RecordBatch {
+        let a: ArrayRef =
+            Arc::new(Int32Array::from(vec![Some(1), Some(2), None, Some(4)]));
+        let b: ArrayRef = Arc::new(Float64Array::from(vec![0.5, 1.5, 2.5, 3.5]));
+        let c: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "x", "z"]));
+        RecordBatch::try_from_iter(vec![("a", a), ("b", b), ("c", c)]).unwrap()
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let batch = batch();
+        let result = (col("a") + lit(1) * lit(2)).evaluate(&batch).unwrap();
+        let expected = Int32Array::from(vec![Some(3), Some(4), None, Some(6)]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let expr = col("a").cast(DataType::Float64) / lit(2.0) - col("b");
+        let result = expr.evaluate(&batch).unwrap();
+        let expected = Float64Array::from(vec![Some(0.), Some(-0.5), None, Some(-1.5)]);
+        assert_eq!(result.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_literal() {
+        let batch = batch();
+        let result = lit("foo").evaluate(&batch).unwrap();
+        assert_eq!(result.as_ref(), &StringArray::from(vec!["foo"; 4]));
+
+        let expr = Expr::Literal(Arc::new(Int32Array::from(vec![1, 2])));
+        let err = expr.evaluate(&batch).unwrap_err();
+        assert!(err.to_string().contains("exactly one value"), "{}", err);
+    }
+
+    #[test]
+    fn test_predicates() {
+        let batch = batch();
+        let expr = col("c").eq(lit("x")).or(col("a").gt_eq(lit(4)));
+        let result = expr.evaluate(&batch).unwrap();
+        let expected = BooleanArray::from(vec![true, false, true, true]);
+        assert_eq!(result.as_ref(), &expected);
+
+        // null AND false is false, null AND true is null
+        let expr = col("a").lt(lit(3)).and(col("b").lt(lit(2.0)));
+        let result = expr.evaluate(&batch).unwrap();
+        let expected =
+            BooleanArray::from(vec![Some(true), Some(true), Some(false), Some(false)]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let result = (!col("a").is_null()).evaluate(&batch).unwrap();
+        let expected = BooleanArray::from(vec![true, true, false, true]);
+        assert_eq!(result.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_filter() {
+        let batch = batch();
+        let filtered = col("a").not_eq(lit(2)).filter(&batch).unwrap();
+        assert_eq!(filtered.num_rows(), 2);
+        assert_eq!(
+            filtered.column(2).as_ref(),
+            &StringArray::from(vec!["x", "z"])
+        );
+
+        let err = col("a").filter(&batch).unwrap_err();
+        assert!(err.to_string().contains("Expected boolean"), "{}", err);
+    }
+
+    #[test]
+    fn test_project() {
+        let batch = batch();
+        let projected = project(
+            &batch,
+            &[("c", col("c")), ("a_plus_one", col("a") + lit(1))],
+        )
+        .unwrap();
+        assert_eq!(projected.schema().field(1).name(), "a_plus_one");
+        assert_eq!(
+            projected.column(1).as_ref(),
+            &Int32Array::from(vec![Some(2), Some(3), None, Some(5)])
+        );
+
+        let err = project(&batch, &[("x", col("missing"))]).unwrap_err();
+        assert!(err.to_string().contains("missing"), "{}", err);
+    }
+
+    #[test]
+    fn test_type_mismatch() {
+        let batch = batch();
+        assert!((col("a") + col("b")).evaluate(&batch).is_err());
+        assert!(col("a").and(col("a")).evaluate(&batch).is_err());
+    }
+}
@@ -20,14 +20,22 @@
 use arrow_data::bit_iterator::try_for_each_valid_idx;
 use arrow_schema::ArrowError;
 use multiversion::multiversion;
+use num::One;
+use std::cmp::Ordering;
 #[allow(unused_imports)]
 use std::ops::{Add, Deref};
+use std::ops::{BitAnd, BitOr, BitXor};
 
 use crate::array::{
     as_primitive_array, Array, ArrayAccessor, ArrayIter, BooleanArray,
     GenericBinaryArray, GenericStringArray, OffsetSizeTrait, PrimitiveArray,
+    TypedDictionaryArray,
 };
-use crate::datatypes::{ArrowNativeType, ArrowNativeTypeOp, ArrowNumericType, DataType};
+use crate::compute::kernels::hash::hash;
+use crate::datatypes::{
+    ArrowNativeType, ArrowNativeTypeOp, ArrowNumericType, ArrowPrimitiveType, DataType,
+};
+use crate::downcast_dictionary_array;
 use crate::error::Result;
 use crate::util::bit_iterator::BitIndexIterator;
 
@@ -155,6 +163,22 @@ pub fn min_binary<T: OffsetSizeTrait>(array: &GenericBinaryArray<T>) -> Option<&
     min_max_helper::<&[u8], _, _>(array, |a, b| *a > *b)
 }
 
+/// Returns the maximum value in the binary array, or dictionary array with binary values,
+/// according to the natural order.
+pub fn max_binary_array<'a, A: ArrayAccessor<Item = &'a [u8]>>(
+    array: A,
+) -> Option<&'a [u8]> {
+    min_max_helper::<&[u8], _, _>(array, |a, b| *a < *b)
+}
+
+/// Returns the minimum value in the binary array, or dictionary array with binary values,
+/// according to the natural order.
+pub fn min_binary_array<'a, A: ArrayAccessor<Item = &'a [u8]>>(
+    array: A,
+) -> Option<&'a [u8]> {
+    min_max_helper::<&[u8], _, _>(array, |a, b| *a > *b)
+}
+
 /// Returns the maximum value in the string array, according to the natural order.
 pub fn max_string<T: OffsetSizeTrait>(array: &GenericStringArray<T>) -> Option<&str> {
     min_max_helper::<&str, _, _>(array, |a, b| *a < *b)
@@ -387,6 +411,330 @@ where
     }
 }
 
+/// Returns the bitwise and of all non-null values in the primitive array.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn bit_and<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: BitAnd<Output = T::Native>,
+{
+    bit_operation(array, |a, b| a & b)
+}
+
+/// Returns the bitwise or of all non-null values in the primitive array.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn bit_or<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: BitOr<Output = T::Native>,
+{
+    bit_operation(array, |a, b| a | b)
+}
+
+/// Returns the bitwise xor of all non-null values in the primitive array.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn bit_xor<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: BitXor<Output = T::Native>,
+{
+    bit_operation(array, |a, b| a ^ b)
+}
+
+/// Helper to reduce the non-null values of a primitive array with a bitwise operation
+fn bit_operation<T, F>(array: &PrimitiveArray<T>, op: F) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    let values = array.values();
+    match array.data().null_buffer() {
+        None => values.iter().copied().reduce(op),
+        Some(buffer) => BitIndexIterator::new(buffer, array.offset(), array.len())
+            .map(|idx| values[idx])
+            .reduce(op),
+    }
+}
+
+/// Returns the median of the non-null values in the primitive array, ordering floats
+/// according to their total order.
+///
+/// If there is an even number of values, the result for floating point arrays is the
+/// mean of the two middle values, and for integer arrays the lower of these.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn median<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp + One,
+{
+    let mut values: Vec<T::Native> = array.iter().flatten().collect();
+    if values.is_empty() {
+        return None;
+    }
+    let len = values.len();
+    let (lower, upper, _) = values.select_nth_unstable_by(len / 2, total_cmp);
+    let upper = *upper;
+    if len % 2 == 1 {
+        return Some(upper);
+    }
+    // the lower middle value is the largest of the values before the upper one
+    let lower = lower
+        .iter()
+        .copied()
+        .reduce(|a, b| if a.is_lt(b) { b } else { a })?;
+    match T::DATA_TYPE {
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => {
+            let two = T::Native::one() + T::Native::one();
+            Some(lower / two + upper / two)
+        }
+        _ => Some(lower),
+    }
+}
+
+/// Returns the most frequent of the non-null values in the primitive array, or the
+/// smallest of these if several are equally frequent.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn mode<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    mode_of_counts(array.iter().flatten().map(|v| (v, 1)).collect())
+}
+
+/// Returns the most frequent of the non-null values in the dictionary array, or the
+/// smallest of these if several are equally frequent.
+///
+/// This counts the occurrences of each key, and so only compares the values once.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn mode_dictionary<K, T>(
+    array: TypedDictionaryArray<'_, K, PrimitiveArray<T>>,
+) -> Option<T::Native>
+where
+    K: ArrowPrimitiveType,
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    let values = array.values();
+    let mut counts = vec![0_usize; values.len()];
+    array
+        .keys()
+        .iter()
+        .flatten()
+        .for_each(|key| counts[key.as_usize()] += 1);
+
+    let counts = counts
+        .into_iter()
+        .enumerate()
+        .filter(|(idx, count)| *count > 0 && values.is_valid(*idx))
+        .map(|(idx, count)| (values.value(idx), count))
+        .collect();
+    mode_of_counts(counts)
+}
+
+/// Returns the value with the highest total count, adding up the counts of equal values
+fn mode_of_counts<T: ArrowNativeTypeOp>(mut counts: Vec<(T, usize)>) -> Option<T> {
+    counts.sort_unstable_by(|a, b| total_cmp(&a.0, &b.0));
+    let mut iter = counts.into_iter();
+    let mut current = iter.next()?;
+    let mut mode = current;
+    for (value, count) in iter {
+        if value.is_eq(current.0) {
+            current.1 += count;
+        } else {
+            current = (value, count);
+        }
+        if current.1 > mode.1 {
+            mode = current;
+        }
+    }
+    Some(mode.0)
+}
+
+/// Compares two values, using the total order for floats
+fn total_cmp<T: ArrowNativeTypeOp>(a: &T, b: &T) -> Ordering {
+    if a.is_lt(*b) {
+        Ordering::Less
+    } else if a.is_gt(*b) {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Returns an estimate of the number of distinct non-null values in the array, see
+/// [`HyperLogLog`].
+///
+/// Returns an error if the array's type can't be hashed, see [`hash`].
+pub fn approx_distinct(array: &dyn Array) -> Result<u64> {
+    let mut sketch = HyperLogLog::new();
+    sketch.update(array)?;
+    Ok(sketch.count())
+}
+
+/// The number of bits of a hash used to select a register of a [`HyperLogLog`]
+const HLL_PRECISION: u32 = 14;
+
+/// The number of registers of a [`HyperLogLog`]
+const HLL_REGISTERS: usize = 1 << HLL_PRECISION;
+
+/// The seed of the hashes of the values added to a [`HyperLogLog`]
+const HLL_SEED: u64 = 0x5ca1_ab1e;
+
+/// A [HyperLogLog](https://en.wikipedia.org/wiki/HyperLogLog) sketch, estimating the
+/// number of distinct values added to it in constant memory, with a standard error of
+/// about 0.8%.
+///
+/// Sketches of parts of a dataset can be merged to estimate the number of distinct
+/// values of the whole, e.g. to combine the results of several partitions or batches.
+/// As the values are hashed with [`hash`], only sketches built by the same build of
+/// this crate should be merged.
+///
+/// ```
+/// use arrow::array::Int32Array;
+/// use arrow::compute::HyperLogLog;
+///
+/// let mut sketch = HyperLogLog::new();
+/// sketch.update(&Int32Array::from_iter_values(0..1000)).unwrap();
+///
+/// let mut other = HyperLogLog::new();
+/// other.update(&Int32Array::from_iter_values(500..1500)).unwrap();
+///
+/// sketch.merge(&other);
+/// assert!((1485..1515).contains(&sketch.count()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HyperLogLog {
+    /// Creates an empty sketch
+    pub fn new() -> Self {
+        Self {
+            registers: vec![0; HLL_REGISTERS],
+        }
+    }
+
+    /// Creates a sketch from the registers of another, see [`Self::registers`]
+    pub fn try_from_registers(registers: Vec<u8>) -> Result<Self> {
+        let max_rank = (u64::BITS - HLL_PRECISION + 1) as u8;
+        if registers.len() != HLL_REGISTERS {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "HyperLogLog requires {} registers, got {}",
+                HLL_REGISTERS,
+                registers.len()
+            )));
+        }
+        if let Some(rank) = registers.iter().find(|rank| **rank > max_rank) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "HyperLogLog register {} exceeds the maximum of {}",
+                rank, max_rank
+            )));
+        }
+        Ok(Self { registers })
+    }
+
+    /// Returns the registers of the sketch, e.g. to serialize it
+    pub fn registers(&self) -> &[u8] {
+        &self.registers
+    }
+
+    /// Adds the non-null values of the array to the sketch.
+    ///
+    /// The values of dictionary arrays are only hashed once, rather than once per key
+    /// referencing them.
+    ///
+    /// Returns an error if the array's type can't be hashed, see [`hash`].
+    pub fn update(&mut self, array: &dyn Array) -> Result<()> {
+        match array.data_type() {
+            DataType::Dictionary(_, _) => downcast_dictionary_array!(
+                array => {
+                    let values = array.values();
+                    let mut referenced = vec![false; values.len()];
+                    array
+                        .keys()
+                        .iter()
+                        .flatten()
+                        .for_each(|key| referenced[key.as_usize()] = true);
+                    let hashes = hash(values.as_ref(), HLL_SEED)?;
+                    hashes
+                        .values()
+                        .iter()
+                        .zip(referenced)
+                        .enumerate()
+                        .filter(|(idx, (_, referenced))| *referenced && values.is_valid(*idx))
+                        .for_each(|(_, (hash, _))| self.insert(*hash));
+                }
+                t => unreachable!("Unexpected dictionary type {}", t)
+            ),
+            _ => {
+                let hashes = hash(array, HLL_SEED)?;
+                hashes
+                    .values()
+                    .iter()
+                    .enumerate()
+                    .filter(|(idx, _)| array.is_valid(*idx))
+                    .for_each(|(_, hash)| self.insert(*hash));
+            }
+        }
+        Ok(())
+    }
+
+    /// Merges the values added to `other` into this sketch
+    pub fn merge(&mut self, other: &HyperLogLog) {
+        self.registers
+            .iter_mut()
+            .zip(&other.registers)
+            .for_each(|(a, b)| *a = (*a).max(*b));
+    }
+
+    /// Returns the estimated number of distinct values added to the sketch
+    pub fn count(&self) -> u64 {
+        let m = HLL_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|rank| 2_f64.powi(-(*rank as i32)))
+            .sum();
+        let estimate = alpha * m * m / sum;
+
+        // use linear counting for small cardinalities, for which HyperLogLog is biased.
+        // As the hashes are 64-bit, no correction is needed for large ones.
+        let zeros = self.registers.iter().filter(|rank| **rank == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+
+    /// Adds the hash of a value to the sketch
+    #[inline]
+    fn insert(&mut self, hash: u64) {
+        let idx = (hash >> (u64::BITS - HLL_PRECISION)) as usize;
+        // the position of the first set bit of the remaining bits, with a sentinel bit
+        // bounding it if they are all zero
+        let rank = ((hash << HLL_PRECISION) | (1 << (HLL_PRECISION - 1))).leading_zeros()
+            as u8
+            + 1;
+        self.registers[idx] = self.registers[idx].max(rank);
+    }
+}
+
 #[cfg(feature = "simd")]
 mod simd {
     use super::is_nan;
@@ -774,9 +1122,173 @@ mod tests {
     use super::*;
     use crate::array::*;
     use crate::compute::add;
-    use crate::datatypes::{Float32Type, Int32Type, Int8Type};
+    use crate::datatypes::{Float32Type, Int32Type, Int8Type, UInt8Type};
     use arrow_array::types::Float64Type;
 
+    #[test]
+    fn test_primitive_array_bit_operations() {
+        let a = Int32Array::from(vec![Some(0b1110), None, Some(0b0111), Some(0b0100)]);
+        assert_eq!(Some(0b0100), bit_and(&a));
+        assert_eq!(Some(0b1111), bit_or(&a));
+        assert_eq!(Some(0b1101), bit_xor(&a));
+
+        let a = UInt8Array::from(vec![0b1100_u8, 0b1010]);
+        assert_eq!(Some(0b1000), bit_and(&a));
+        assert_eq!(Some(0b1110), bit_or(&a));
+        assert_eq!(Some(0b0110), bit_xor(&a));
+
+        let sliced = a.slice(1, 1);
+        let sliced = as_primitive_array::<UInt8Type>(&sliced);
+        assert_eq!(Some(0b1010), bit_and(sliced));
+
+        let a = Int64Array::from(vec![None, None]);
+        assert_eq!(None, bit_and(&a));
+        assert_eq!(None, bit_or(&a));
+        assert_eq!(None, bit_xor(&a));
+
+        let a = Int16Array::from(Vec::<i16>::new());
+        assert_eq!(None, bit_xor(&a));
+    }
+
+    #[test]
+    fn test_median() {
+        let a = Int32Array::from(vec![Some(5), None, Some(1), Some(3)]);
+        assert_eq!(Some(3), median(&a));
+        let a = Int32Array::from(vec![Some(5), None, Some(1), Some(3), Some(4)]);
+        assert_eq!(Some(3), median(&a));
+        let a = Int64Array::from(vec![i64::MAX, i64::MIN + 1]);
+        assert_eq!(Some(i64::MIN + 1), median(&a));
+
+        let a =
+            Float64Array::from(vec![Some(2.0), None, Some(1.0), Some(4.0), Some(8.0)]);
+        assert_eq!(Some(3.0), median(&a));
+        let a = Float64Array::from(vec![f64::MAX, f64::MAX]);
+        assert_eq!(Some(f64::MAX), median(&a));
+        let a = Float32Array::from(vec![f32::NAN, 1.0, 2.0]);
+        assert_eq!(Some(2.0), median(&a));
+
+        let sliced = Int32Array::from(vec![10, 1, 2, 3, 10]).slice(1, 3);
+        assert_eq!(Some(2), median(as_primitive_array::<Int32Type>(&sliced)));
+
+        let a = Int32Array::from(vec![None, None]);
+        assert_eq!(None, median(&a));
+        let a = Float64Array::from(Vec::<f64>::new());
+        assert_eq!(None, median(&a));
+    }
+
+    #[test]
+    fn test_mode() {
+        let a = Int32Array::from(vec![
+            Some(5),
+            None,
+            Some(1),
+            Some(5),
+            None,
+            Some(1),
+            Some(3),
+        ]);
+        assert_eq!(Some(1), mode(&a));
+        let a = Int32Array::from(vec![Some(5), Some(3), Some(5)]);
+        assert_eq!(Some(5), mode(&a));
+        let a = Float64Array::from(vec![-0.0, 0.0, 0.0, f64::NAN, f64::NAN, f64::NAN]);
+        assert!(mode(&a).unwrap().is_nan());
+        let a = Int32Array::from(vec![None, None]);
+        assert_eq!(None, mode(&a));
+
+        // duplicate and null values in the dictionary
+        let values = Int16Array::from(vec![Some(7), Some(2), None, Some(7), Some(9)]);
+        let keys = Int8Array::from(vec![
+            Some(0),
+            Some(1),
+            Some(2),
+            Some(2),
+            Some(2),
+            Some(3),
+            None,
+            Some(1),
+            Some(4),
+        ]);
+        let dict = DictionaryArray::try_new(&keys, &values).unwrap();
+        let typed = dict.downcast_dict::<Int16Array>().unwrap();
+        assert_eq!(Some(2), mode_dictionary(typed));
+
+        let keys = Int8Array::from(vec![Some(0), Some(1), Some(3), Some(1), Some(0)]);
+        let dict = DictionaryArray::try_new(&keys, &values).unwrap();
+        let typed = dict.downcast_dict::<Int16Array>().unwrap();
+        assert_eq!(Some(7), mode_dictionary(typed));
+
+        let keys = Int8Array::from(vec![None, Some(2)]);
+        let dict = DictionaryArray::try_new(&keys, &values).unwrap();
+        let typed = dict.downcast_dict::<Int16Array>().unwrap();
+        assert_eq!(None, mode_dictionary(typed));
+    }
+
+    #[test]
+    fn test_approx_distinct() {
+        let a = Int32Array::from(vec![Some(5), None, Some(1), Some(5), None, Some(3)]);
+        assert_eq!(3, approx_distinct(&a).unwrap());
+        let a = Int32Array::from(vec![None, None]);
+        assert_eq!(0, approx_distinct(&a).unwrap());
+        let a = StringArray::from(vec!["foo", "bar", "foo", "baz"]);
+        assert_eq!(3, approx_distinct(&a).unwrap());
+
+        for n in [1_000, 100_000, 1_000_000] {
+            let a = Int64Array::from_iter_values((0..n).map(|v| v % (n / 2)));
+            let estimate = approx_distinct(&a).unwrap() as f64;
+            let error = (estimate - (n / 2) as f64).abs() / (n / 2) as f64;
+            assert!(error < 0.03, "estimated {} for {}", estimate, n / 2);
+        }
+
+        // only the valid values referenced by the keys are counted
+        let values = Int16Array::from(vec![Some(7), Some(2), None, Some(7), Some(9)]);
+        let keys = Int8Array::from(vec![Some(0), Some(1), Some(2), Some(3), None]);
+        let dict = DictionaryArray::try_new(&keys, &values).unwrap();
+        assert_eq!(2, approx_distinct(&dict).unwrap());
+
+        let a =
+            ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)])]);
+        assert!(approx_distinct(&a).is_err());
+    }
+
+    #[test]
+    fn test_hyperloglog_merge() {
+        let mut a = HyperLogLog::new();
+        a.update(&Int32Array::from_iter_values(0..10_000)).unwrap();
+        let mut b = HyperLogLog::default();
+        b.update(&Int32Array::from_iter_values(5_000..15_000))
+            .unwrap();
+
+        let mut merged = a.clone();
+        merged.merge(&b);
+        let estimate = merged.count() as f64;
+        assert!(
+            (estimate - 15_000.0).abs() < 450.0,
+            "estimated {}",
+            estimate
+        );
+
+        // merging is commutative and idempotent
+        b.merge(&a);
+        assert_eq!(b, merged);
+        merged.merge(&a);
+        assert_eq!(b, merged);
+
+        let registers = merged.registers().to_vec();
+        let restored = HyperLogLog::try_from_registers(registers).unwrap();
+        assert_eq!(restored, merged);
+
+        let err = HyperLogLog::try_from_registers(vec![0; 3]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: HyperLogLog requires 16384 registers, got 3"
+        );
+        let err = HyperLogLog::try_from_registers(vec![52; 16384]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: HyperLogLog register 52 exceeds the maximum of 51"
+        );
+    }
+
     #[test]
     fn test_primitive_array_sum() {
         let a = Int32Array::from(vec![1, 2, 3, 4, 5]);
@@ -1209,6 +1721,28 @@ mod tests {
         assert!(min_array::<Int8Type, _>(array).is_none());
     }
 
+    #[test]
+    fn test_binary_max_min_dyn() {
+        let values = BinaryArray::from(vec![&b"b"[..], b"", b"ab", b"c"]);
+        let keys = Int8Array::from(vec![Some(0_i8), None, Some(2), Some(0)]);
+        let dict_array = DictionaryArray::try_new(&keys, &values).unwrap();
+        let array = dict_array.downcast_dict::<BinaryArray>().unwrap();
+        assert_eq!(Some(&b"b"[..]), max_binary_array(array));
+        let array = dict_array.downcast_dict::<BinaryArray>().unwrap();
+        assert_eq!(Some(&b"ab"[..]), min_binary_array(array));
+
+        let values = LargeBinaryArray::from(vec![&b"b"[..], b"a"]);
+        assert_eq!(Some(&b"b"[..]), max_binary_array(&values));
+        assert_eq!(Some(&b"a"[..]), min_binary_array(&values));
+
+        let keys = Int8Array::from(vec![None, None]);
+        let dict_array = DictionaryArray::try_new(&keys, &values).unwrap();
+        let array = dict_array.downcast_dict::<LargeBinaryArray>().unwrap();
+        assert!(max_binary_array(array).is_none());
+        let array = dict_array.downcast_dict::<LargeBinaryArray>().unwrap();
+        assert!(min_binary_array(array).is_none());
+    }
+
     #[test]
     fn test_max_min_dyn_nan() {
         let values = Float32Array::from(vec![5.0_f32, 2.0_f32, f32::NAN]);
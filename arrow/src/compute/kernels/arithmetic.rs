@@ -30,12 +30,20 @@ use crate::array::*;
 #[cfg(feature = "simd")]
 use crate::buffer::MutableBuffer;
 use crate::compute::kernels::arity::unary;
+use crate::compute::kernels::decimal::{
+    add_decimal, divide_decimal, multiply_decimal, subtract_decimal,
+};
+use crate::compute::kernels::temporal::timestamp_tz;
 use crate::compute::{
     binary, binary_opt, try_binary, try_unary, try_unary_dyn, unary_dyn,
 };
 use crate::datatypes::{
-    ArrowNativeTypeOp, ArrowNumericType, DataType, Date32Type, Date64Type,
-    IntervalDayTimeType, IntervalMonthDayNanoType, IntervalUnit, IntervalYearMonthType,
+    ArrowNativeTypeOp, ArrowNumericType, ArrowPrimitiveType, ArrowTimestampType,
+    DataType, Date32Type, Date64Type, DurationMicrosecondType, DurationMillisecondType,
+    DurationNanosecondType, DurationSecondType, IntervalDayTimeType,
+    IntervalMonthDayNanoType, IntervalUnit, IntervalYearMonthType, TimeUnit,
+    TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType,
 };
 #[cfg(feature = "dyn_arith_dict")]
 use crate::datatypes::{
@@ -43,7 +51,8 @@ use crate::datatypes::{
     UInt32Type, UInt64Type, UInt8Type,
 };
 use crate::error::{ArrowError, Result};
-use crate::{datatypes, downcast_primitive_array};
+use crate::temporal_conversions::time_unit_multiple;
+use crate::{datatypes, downcast_dictionary_array, downcast_primitive_array};
 use num::traits::Pow;
 #[cfg(feature = "simd")]
 use std::borrow::BorrowMut;
@@ -88,7 +97,80 @@ where
     LT::Native: ArrowNativeTypeOp,
     RT::Native: ArrowNativeTypeOp,
 {
-    try_binary(left, right, op)
+    check_same_len(left.len(), right.len())?;
+    let result = try_binary(left, right, &op);
+    with_row_index(result, left.len().min(right.len()), |i| {
+        left.is_valid(i)
+            && right.is_valid(i)
+            && op(left.value(i), right.value(i)).is_err()
+    })
+}
+
+/// Returns the error [`try_binary`] returns for arrays of different lengths, so that it
+/// is returned before [`with_row_index`] could evaluate rows past the end of either array
+fn check_same_len(left: usize, right: usize) -> Result<()> {
+    if left != right {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform binary operation on arrays of different length".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Appends the index of the first row for which `failed` returns true to the message of
+/// an [`ArrowError::ComputeError`], such as an overflow, returned by a checked kernel
+///
+/// The rows are only evaluated if `result` is such an error, and so this has no cost
+/// unless the kernel fails
+fn with_row_index<T>(
+    result: Result<T>,
+    len: usize,
+    failed: impl Fn(usize) -> bool,
+) -> Result<T> {
+    result.map_err(|e| match e {
+        ArrowError::ComputeError(msg) => match (0..len).find(|i| failed(*i)) {
+            Some(row) => ArrowError::ComputeError(format!("{} at row {}", msg, row)),
+            None => ArrowError::ComputeError(msg),
+        },
+        e => e,
+    })
+}
+
+/// This is similar to `math_checked_op`, performing the given operation on each value of
+/// a primitive array, and returning an `Err` identifying the row if it fails.
+fn math_checked_unary<T, F>(array: &PrimitiveArray<T>, op: F) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native) -> Result<T::Native>,
+{
+    let result = try_unary(array, &op);
+    with_row_index(result, array.len(), |i| {
+        array.is_valid(i) && op(array.value(i)).is_err()
+    })
+}
+
+/// This is similar to `math_checked_unary`, performing the given operation on each value
+/// of a primitive array, or a dictionary array of primitive values.
+fn math_checked_unary_dyn<T, F>(array: &dyn Array, op: F) -> Result<ArrayRef>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native) -> Result<T::Native>,
+{
+    let result = try_unary_dyn::<_, T>(array, &op);
+    downcast_dictionary_array! {
+        array => match array.downcast_dict::<PrimitiveArray<T>>() {
+            Some(dict) => with_row_index(result, array.len(), |i| {
+                dict.is_valid(i) && op(dict.value(i)).is_err()
+            }),
+            None => result,
+        },
+        _ => match array.as_any().downcast_ref::<PrimitiveArray<T>>() {
+            Some(array) => with_row_index(result, array.len(), |i| {
+                array.is_valid(i) && op(array.value(i)).is_err()
+            }),
+            None => result,
+        }
+    }
 }
 
 /// Helper function for operations where a valid `0` on the right array should
@@ -110,7 +192,13 @@ where
     RT::Native: One + Zero,
     F: Fn(LT::Native, RT::Native) -> Result<LT::Native>,
 {
-    try_binary(left, right, op)
+    check_same_len(left.len(), right.len())?;
+    let result = try_binary(left, right, &op);
+    with_row_index(result, left.len().min(right.len()), |i| {
+        left.is_valid(i)
+            && right.is_valid(i)
+            && op(left.value(i), right.value(i)).is_err()
+    })
 }
 
 /// Helper function for operations where a valid `0` on the right array should
@@ -428,44 +516,34 @@ macro_rules! typed_dict_op {
     ($LEFT: expr, $RIGHT: expr, $OP: expr, $KT: tt, $MATH_OP: ident) => {{
         match ($LEFT.value_type(), $RIGHT.value_type()) {
             (DataType::Int8, DataType::Int8) => {
-                let array = $MATH_OP::<$KT, Int8Type, _>($LEFT, $RIGHT, $OP)?;
-                Ok(Arc::new(array))
+                $MATH_OP::<$KT, Int8Type, _>($LEFT, $RIGHT, $OP)
             }
             (DataType::Int16, DataType::Int16) => {
-                let array = $MATH_OP::<$KT, Int16Type, _>($LEFT, $RIGHT, $OP)?;
-                Ok(Arc::new(array))
+                $MATH_OP::<$KT, Int16Type, _>($LEFT, $RIGHT, $OP)
             }
             (DataType::Int32, DataType::Int32) => {
-                let array = $MATH_OP::<$KT, Int32Type, _>($LEFT, $RIGHT, $OP)?;
-                Ok(Arc::new(array))
+                $MATH_OP::<$KT, Int32Type, _>($LEFT, $RIGHT, $OP)
             }
             (DataType::Int64, DataType::Int64) => {
-                let array = $MATH_OP::<$KT, Int64Type, _>($LEFT, $RIGHT, $OP)?;
-                Ok(Arc::new(array))
+                $MATH_OP::<$KT, Int64Type, _>($LEFT, $RIGHT, $OP)
             }
             (DataType::UInt8, DataType::UInt8) => {
-                let array = $MATH_OP::<$KT, UInt8Type, _>($LEFT, $RIGHT, $OP)?;
-                Ok(Arc::new(array))
+                $MATH_OP::<$KT, UInt8Type, _>($LEFT, $RIGHT, $OP)
             }
             (DataType::UInt16, DataType::UInt16) => {
-                let array = $MATH_OP::<$KT, UInt16Type, _>($LEFT, $RIGHT, $OP)?;
-                Ok(Arc::new(array))
+                $MATH_OP::<$KT, UInt16Type, _>($LEFT, $RIGHT, $OP)
             }
             (DataType::UInt32, DataType::UInt32) => {
-                let array = $MATH_OP::<$KT, UInt32Type, _>($LEFT, $RIGHT, $OP)?;
-                Ok(Arc::new(array))
+                $MATH_OP::<$KT, UInt32Type, _>($LEFT, $RIGHT, $OP)
             }
             (DataType::UInt64, DataType::UInt64) => {
-                let array = $MATH_OP::<$KT, UInt64Type, _>($LEFT, $RIGHT, $OP)?;
-                Ok(Arc::new(array))
+                $MATH_OP::<$KT, UInt64Type, _>($LEFT, $RIGHT, $OP)
             }
             (DataType::Float32, DataType::Float32) => {
-                let array = $MATH_OP::<$KT, Float32Type, _>($LEFT, $RIGHT, $OP)?;
-                Ok(Arc::new(array))
+                $MATH_OP::<$KT, Float32Type, _>($LEFT, $RIGHT, $OP)
             }
             (DataType::Float64, DataType::Float64) => {
-                let array = $MATH_OP::<$KT, Float64Type, _>($LEFT, $RIGHT, $OP)?;
-                Ok(Arc::new(array))
+                $MATH_OP::<$KT, Float64Type, _>($LEFT, $RIGHT, $OP)
             }
             (t1, t2) => Err(ArrowError::CastError(format!(
                 "Cannot perform arithmetic operation on two dictionary arrays of different value types ({} and {})",
@@ -547,14 +625,58 @@ macro_rules! typed_dict_math_op {
     }};
 }
 
-/// Perform given operation on two `DictionaryArray`s.
-/// Returns an error if the two arrays have different value type
+/// Returns the values of `left` and `right` if both dictionaries have the same keys, and
+/// values of the same length, in which case an operation can be performed on the values
+/// alone, see [`take_dict_values`]
+#[cfg(feature = "dyn_arith_dict")]
+fn shared_keys_values<'a, K, T>(
+    left: &'a DictionaryArray<K>,
+    right: &'a DictionaryArray<K>,
+) -> Option<(&'a PrimitiveArray<T>, &'a PrimitiveArray<T>)>
+where
+    K: ArrowNumericType,
+    T: ArrowNumericType,
+{
+    let left_values = left.values().as_any().downcast_ref::<PrimitiveArray<T>>()?;
+    let right_values = right
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()?;
+    let same_keys =
+        left.keys().data().ptr_eq(right.keys().data()) || left.keys() == right.keys();
+    (same_keys && left_values.len() == right_values.len())
+        .then_some((left_values, right_values))
+}
+
+/// Returns the `values` computed from the values of dictionaries sharing the keys of
+/// `dict`, see [`shared_keys_values`], for each of its keys
+#[cfg(feature = "dyn_arith_dict")]
+fn take_dict_values<K, T>(
+    dict: &DictionaryArray<K>,
+    values: &PrimitiveArray<T>,
+) -> Result<ArrayRef>
+where
+    K: ArrowNumericType,
+    T: ArrowNumericType,
+{
+    let result = dict
+        .keys_iter()
+        .map(|key| key.filter(|key| values.is_valid(*key)))
+        // Safety justification: `values` has the length of the values of `dict`, and
+        // so all its keys are valid indexes into it
+        .map(|key| key.map(|key| unsafe { values.value_unchecked(key) }))
+        .collect::<PrimitiveArray<T>>();
+    Ok(Arc::new(result))
+}
+
+/// Perform given operation on two `DictionaryArray`s, evaluating it once per value if
+/// both have the same keys. Returns an error if the two arrays have different value type
 #[cfg(feature = "dyn_arith_dict")]
 fn math_op_dict<K, T, F>(
     left: &DictionaryArray<K>,
     right: &DictionaryArray<K>,
     op: F,
-) -> Result<PrimitiveArray<T>>
+) -> Result<ArrayRef>
 where
     K: ArrowNumericType,
     T: ArrowNumericType,
@@ -569,6 +691,12 @@ where
         )));
     }
 
+    // If both sides share their keys, evaluate `op` once per value
+    if let Some((left_values, right_values)) = shared_keys_values::<K, T>(left, right) {
+        let values: PrimitiveArray<T> = binary(left_values, right_values, op)?;
+        return take_dict_values(left, &values);
+    }
+
     // Safety justification: Since the inputs are valid Arrow arrays, all values are
     // valid indexes into the dictionary (which is verified during construction)
 
@@ -598,19 +726,19 @@ where
                 None
             }
         })
-        .collect();
+        .collect::<PrimitiveArray<T>>();
 
-    Ok(result)
+    Ok(Arc::new(result))
 }
 
-/// Perform given operation on two `DictionaryArray`s.
-/// Returns an error if the two arrays have different value type
+/// Perform given operation on two `DictionaryArray`s, evaluating it once per value if
+/// both have the same keys. Returns an error if the two arrays have different value type
 #[cfg(feature = "dyn_arith_dict")]
 fn math_checked_op_dict<K, T, F>(
     left: &DictionaryArray<K>,
     right: &DictionaryArray<K>,
     op: F,
-) -> Result<PrimitiveArray<T>>
+) -> Result<ArrayRef>
 where
     K: ArrowNumericType,
     T: ArrowNumericType,
@@ -625,10 +753,25 @@ where
         )));
     }
 
+    // If both sides share their keys, evaluate `op` once per value. This may fail on a
+    // value no key refers to, in which case fall back to evaluating it per row
+    if let Some((left_values, right_values)) = shared_keys_values::<K, T>(left, right) {
+        if let Ok(values) = try_binary::<_, _, _, T>(left_values, right_values, &op) {
+            return take_dict_values(left, &values);
+        }
+    }
+
     let left = left.downcast_dict::<PrimitiveArray<T>>().unwrap();
     let right = right.downcast_dict::<PrimitiveArray<T>>().unwrap();
 
-    try_binary(left, right, op)
+    check_same_len(left.len(), right.len())?;
+    let result = try_binary::<_, _, _, T>(left, right, &op);
+    with_row_index(result, left.len().min(right.len()), |i| {
+        left.is_valid(i)
+            && right.is_valid(i)
+            && op(left.value(i), right.value(i)).is_err()
+    })
+    .map(|a| Arc::new(a) as ArrayRef)
 }
 
 /// Helper function for operations where a valid `0` on the right array should
@@ -644,7 +787,7 @@ fn math_divide_checked_op_dict<K, T, F>(
     left: &DictionaryArray<K>,
     right: &DictionaryArray<K>,
     op: F,
-) -> Result<PrimitiveArray<T>>
+) -> Result<ArrayRef>
 where
     K: ArrowNumericType,
     T: ArrowNumericType,
@@ -659,6 +802,20 @@ where
         )));
     }
 
+    // If both sides share their keys, evaluate `op` once per value. This may fail on a
+    // value no key refers to, in which case fall back to evaluating it per row
+    if let Some((left_values, right_values)) = shared_keys_values::<K, T>(left, right) {
+        if let Ok(values) = try_binary::<_, _, _, T>(left_values, right_values, |a, b| {
+            if b.is_zero() {
+                Err(ArrowError::DivideByZero)
+            } else {
+                op(a, b)
+            }
+        }) {
+            return take_dict_values(left, &values);
+        }
+    }
+
     let null_bit_buffer = crate::compute::util::combine_option_bitmap(
         &[left.data_ref(), right.data_ref()],
         left.len(),
@@ -684,13 +841,14 @@ where
             .take_iter_unchecked(right.keys_iter())
     };
 
-    math_checked_divide_op_on_iters(
+    math_checked_divide_op_on_iters::<T, _>(
         left_iter,
         right_iter,
         op,
         left.len(),
         null_bit_buffer,
     )
+    .map(|a| Arc::new(a) as ArrayRef)
 }
 
 #[cfg(feature = "dyn_arith_dict")]
@@ -705,6 +863,13 @@ where
     T::Native: One + Zero,
     F: Fn(T::Native, T::Native) -> Option<T::Native>,
 {
+    // If both sides share their keys, evaluate `op` once per value
+    if let Some((left_values, right_values)) = shared_keys_values::<K, T>(left, right) {
+        let values: PrimitiveArray<T> =
+            binary_opt::<_, _, _, T>(left_values, right_values, op)?;
+        return take_dict_values(left, &values);
+    }
+
     let left = left.downcast_dict::<PrimitiveArray<T>>().unwrap();
     let right = right.downcast_dict::<PrimitiveArray<T>>().unwrap();
     let array: PrimitiveArray<T> = binary_opt::<_, _, _, T>(left, right, op)?;
@@ -745,8 +910,8 @@ where
 /// Perform `left + right` operation on two arrays. If either left or right value is null
 /// then the result is also null.
 ///
-/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
-/// use `add` instead.
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `add` instead.
 pub fn add_checked<T>(
     left: &PrimitiveArray<T>,
     right: &PrimitiveArray<T>,
@@ -755,7 +920,7 @@ where
     T: ArrowNumericType,
     T::Native: ArrowNativeTypeOp,
 {
-    try_binary(left, right, |a, b| a.add_checked(b))
+    math_checked_op(left, right, |a, b| a.add_checked(b))
 }
 
 /// Perform `left + right` operation on two arrays. If either left or right value is null
@@ -763,58 +928,19 @@ where
 ///
 /// This doesn't detect overflow. Once overflowing, the result will wrap around.
 /// For an overflow-checking variant, use `add_dyn_checked` instead.
+///
+/// Interval arrays can be added to date and timestamp arrays, see [`add_interval_scalar_dyn`]
+/// for the semantics.
 pub fn add_dyn(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
     match left.data_type() {
         DataType::Dictionary(_, _) => {
             typed_dict_math_op!(left, right, |a, b| a.add_wrapping(b), math_op_dict)
         }
-        DataType::Date32 => {
-            let l = as_primitive_array::<Date32Type>(left);
-            match right.data_type() {
-                DataType::Interval(IntervalUnit::YearMonth) => {
-                    let r = as_primitive_array::<IntervalYearMonthType>(right);
-                    let res = math_op(l, r, Date32Type::add_year_months)?;
-                    Ok(Arc::new(res))
-                }
-                DataType::Interval(IntervalUnit::DayTime) => {
-                    let r = as_primitive_array::<IntervalDayTimeType>(right);
-                    let res = math_op(l, r, Date32Type::add_day_time)?;
-                    Ok(Arc::new(res))
-                }
-                DataType::Interval(IntervalUnit::MonthDayNano) => {
-                    let r = as_primitive_array::<IntervalMonthDayNanoType>(right);
-                    let res = math_op(l, r, Date32Type::add_month_day_nano)?;
-                    Ok(Arc::new(res))
-                }
-                _ => Err(ArrowError::CastError(format!(
-                    "Cannot perform arithmetic operation between array of type {} and array of type {}",
-                    left.data_type(), right.data_type()
-                ))),
-            }
-        }
-        DataType::Date64 => {
-            let l = as_primitive_array::<Date64Type>(left);
-            match right.data_type() {
-                DataType::Interval(IntervalUnit::YearMonth) => {
-                    let r = as_primitive_array::<IntervalYearMonthType>(right);
-                    let res = math_op(l, r, Date64Type::add_year_months)?;
-                    Ok(Arc::new(res))
-                }
-                DataType::Interval(IntervalUnit::DayTime) => {
-                    let r = as_primitive_array::<IntervalDayTimeType>(right);
-                    let res = math_op(l, r, Date64Type::add_day_time)?;
-                    Ok(Arc::new(res))
-                }
-                DataType::Interval(IntervalUnit::MonthDayNano) => {
-                    let r = as_primitive_array::<IntervalMonthDayNanoType>(right);
-                    let res = math_op(l, r, Date64Type::add_month_day_nano)?;
-                    Ok(Arc::new(res))
-                }
-                _ => Err(ArrowError::CastError(format!(
-                    "Cannot perform arithmetic operation between array of type {} and array of type {}",
-                    left.data_type(), right.data_type()
-                ))),
-            }
+        DataType::Date32 | DataType::Date64 => interval_op_dyn(left, right, false),
+        DataType::Timestamp(_, _)
+            if matches!(right.data_type(), DataType::Interval(_)) =>
+        {
+            interval_op_dyn(left, right, false)
         }
         _ => {
             downcast_primitive_array!(
@@ -833,10 +959,18 @@ pub fn add_dyn(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
 /// Perform `left + right` operation on two arrays. If either left or right value is null
 /// then the result is also null.
 ///
-/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
-/// use `add_dyn` instead.
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `add_dyn` instead.
+///
+/// Interval arrays can be added to date and timestamp arrays, see [`add_interval_scalar_dyn`]
+/// for the semantics.
+///
+/// Decimal arrays are supported with the rules of [`add_decimal`], which detects overflow.
 pub fn add_dyn_checked(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
     match left.data_type() {
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+            add_decimal(left, right)
+        }
         DataType::Dictionary(_, _) => {
             typed_dict_math_op!(
                 left,
@@ -845,68 +979,199 @@ pub fn add_dyn_checked(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef>
                 math_checked_op_dict
             )
         }
-        DataType::Date32 => {
-            let l = as_primitive_array::<Date32Type>(left);
-            match right.data_type() {
-                DataType::Interval(IntervalUnit::YearMonth) => {
-                    let r = as_primitive_array::<IntervalYearMonthType>(right);
-                    let res = math_op(l, r, Date32Type::add_year_months)?;
-                    Ok(Arc::new(res))
-                }
-                DataType::Interval(IntervalUnit::DayTime) => {
-                    let r = as_primitive_array::<IntervalDayTimeType>(right);
-                    let res = math_op(l, r, Date32Type::add_day_time)?;
-                    Ok(Arc::new(res))
-                }
-                DataType::Interval(IntervalUnit::MonthDayNano) => {
-                    let r = as_primitive_array::<IntervalMonthDayNanoType>(right);
-                    let res = math_op(l, r, Date32Type::add_month_day_nano)?;
-                    Ok(Arc::new(res))
+        DataType::Date32 | DataType::Date64 => interval_op_dyn(left, right, false),
+        DataType::Timestamp(_, _)
+            if matches!(right.data_type(), DataType::Interval(_)) =>
+        {
+            interval_op_dyn(left, right, false)
+        }
+        _ => {
+            downcast_primitive_array!(
+                (left, right) => {
+                    math_checked_op(left, right, |a, b| a.add_checked(b)).map(|a| Arc::new(a) as ArrayRef)
                 }
                 _ => Err(ArrowError::CastError(format!(
-                    "Cannot perform arithmetic operation between array of type {} and array of type {}",
+                    "Unsupported data type {}, {}",
                     left.data_type(), right.data_type()
-                ))),
-            }
+                )))
+            )
         }
-        DataType::Date64 => {
-            let l = as_primitive_array::<Date64Type>(left);
+    }
+}
+
+/// Perform `left + right`, or `left - right` if `subtract`, where `left` is a date or
+/// timestamp array and `right` an interval array, see [`add_interval_scalar_dyn`]
+fn interval_op_dyn(
+    left: &dyn Array,
+    right: &dyn Array,
+    subtract: bool,
+) -> Result<ArrayRef> {
+    macro_rules! date_op {
+        ($t:ty, $i:ty, $add:ident, $subtract:ident) => {{
+            let l = as_primitive_array::<$t>(left);
+            let r = as_primitive_array::<$i>(right);
+            let op = if subtract {
+                <$t>::$subtract
+            } else {
+                <$t>::$add
+            };
+            Ok(Arc::new(math_op(l, r, op)?) as ArrayRef)
+        }};
+    }
+    macro_rules! timestamp_op {
+        ($t:ty, $i:ty, $add:ident, $subtract:ident) => {{
+            let l = as_primitive_array::<$t>(left);
+            let r = as_primitive_array::<$i>(right);
+            let op = if subtract {
+                <$t>::$subtract
+            } else {
+                <$t>::$add
+            };
+            // Calendar arithmetic is performed on the local datetimes of timestamps
+            // with a timezone
+            let res = match timestamp_tz(left.data_type())? {
+                Some(tz) => math_checked_op(l, r, |a, b| {
+                    let out_of_range = || {
+                        ArrowError::ComputeError(format!(
+                            "Timestamp {} of {:?} out of range",
+                            a,
+                            <$t>::get_time_unit()
+                        ))
+                    };
+                    let local =
+                        tz.timestamp_to_local::<$t>(a).ok_or_else(out_of_range)?;
+                    tz.timestamp_to_utc::<$t>(op(local, b)?)
+                        .ok_or_else(out_of_range)
+                })?,
+                None => math_checked_op(l, r, op)?,
+            };
+            // Preserve the timezone of the timestamps
+            Ok(Arc::new(PrimitiveArray::<$t>::from(
+                res.into_data()
+                    .into_builder()
+                    .data_type(left.data_type().clone())
+                    .build()?,
+            )) as ArrayRef)
+        }};
+    }
+    macro_rules! interval_op {
+        ($op:ident, $t:ty) => {
             match right.data_type() {
-                DataType::Interval(IntervalUnit::YearMonth) => {
-                    let r = as_primitive_array::<IntervalYearMonthType>(right);
-                    let res = math_op(l, r, Date64Type::add_year_months)?;
-                    Ok(Arc::new(res))
-                }
+                DataType::Interval(IntervalUnit::YearMonth) => $op!(
+                    $t,
+                    IntervalYearMonthType,
+                    add_year_months,
+                    subtract_year_months
+                ),
                 DataType::Interval(IntervalUnit::DayTime) => {
-                    let r = as_primitive_array::<IntervalDayTimeType>(right);
-                    let res = math_op(l, r, Date64Type::add_day_time)?;
-                    Ok(Arc::new(res))
-                }
-                DataType::Interval(IntervalUnit::MonthDayNano) => {
-                    let r = as_primitive_array::<IntervalMonthDayNanoType>(right);
-                    let res = math_op(l, r, Date64Type::add_month_day_nano)?;
-                    Ok(Arc::new(res))
+                    $op!($t, IntervalDayTimeType, add_day_time, subtract_day_time)
                 }
+                DataType::Interval(IntervalUnit::MonthDayNano) => $op!(
+                    $t,
+                    IntervalMonthDayNanoType,
+                    add_month_day_nano,
+                    subtract_month_day_nano
+                ),
                 _ => Err(ArrowError::CastError(format!(
                     "Cannot perform arithmetic operation between array of type {} and array of type {}",
                     left.data_type(), right.data_type()
                 ))),
             }
+        };
+    }
+
+    match left.data_type() {
+        DataType::Date32 => interval_op!(date_op, Date32Type),
+        DataType::Date64 => interval_op!(date_op, Date64Type),
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            interval_op!(timestamp_op, TimestampSecondType)
         }
-        _ => {
-            downcast_primitive_array!(
-                (left, right) => {
-                    math_checked_op(left, right, |a, b| a.add_checked(b)).map(|a| Arc::new(a) as ArrayRef)
-                }
-                _ => Err(ArrowError::CastError(format!(
-                    "Unsupported data type {}, {}",
-                    left.data_type(), right.data_type()
-                )))
-            )
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            interval_op!(timestamp_op, TimestampMillisecondType)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            interval_op!(timestamp_op, TimestampMicrosecondType)
         }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            interval_op!(timestamp_op, TimestampNanosecondType)
+        }
+        _ => Err(ArrowError::CastError(format!(
+            "Cannot perform arithmetic operation between array of type {} and array of type {}",
+            left.data_type(), right.data_type()
+        ))),
     }
 }
 
+/// Add an interval scalar of type `T` to every value in a date or timestamp array. If any
+/// value in the array is null then the result is also null.
+///
+/// Months are added with calendar semantics, clamping the day of the month to the last
+/// day of the resulting month if necessary, for example `2020-01-31 + 1 month` is
+/// `2020-02-29`. Timestamps with a timezone are interpreted as local datetimes in that
+/// timezone, and those without as UTC datetimes.
+///
+/// This returns an `Err` if `T` is not an interval type, the array is not a date or
+/// timestamp array, or the result of a timestamp cannot be represented.
+///
+/// ```
+/// # use arrow::array::Date32Array;
+/// # use arrow::compute::kernels::arithmetic::add_interval_scalar_dyn;
+/// # use arrow::datatypes::{Date32Type, IntervalYearMonthType};
+/// # use chrono::NaiveDate;
+/// let dates = Date32Array::from(vec![
+///     Date32Type::from_naive_date(NaiveDate::from_ymd(2020, 1, 31)),
+///     Date32Type::from_naive_date(NaiveDate::from_ymd(2021, 1, 31)),
+/// ]);
+/// let one_month = IntervalYearMonthType::make_value(0, 1);
+/// let res = add_interval_scalar_dyn::<IntervalYearMonthType>(&dates, one_month).unwrap();
+/// let res = res.as_any().downcast_ref::<Date32Array>().unwrap();
+/// assert_eq!(res.value_as_date(0), Some(NaiveDate::from_ymd(2020, 2, 29)));
+/// assert_eq!(res.value_as_date(1), Some(NaiveDate::from_ymd(2021, 2, 28)));
+/// ```
+pub fn add_interval_scalar_dyn<T>(
+    array: &dyn Array,
+    scalar: T::Native,
+) -> Result<ArrayRef>
+where
+    T: ArrowPrimitiveType,
+{
+    interval_scalar_op_dyn::<T>(array, scalar, false)
+}
+
+/// Subtract an interval scalar of type `T` from every value in a date or timestamp array.
+/// If any value in the array is null then the result is also null.
+///
+/// Months are subtracted with calendar semantics, see [`add_interval_scalar_dyn`]. This
+/// returns an `Err` if `T` is not an interval type, the array is not a date or timestamp
+/// array, or the result of a timestamp cannot be represented.
+pub fn subtract_interval_scalar_dyn<T>(
+    array: &dyn Array,
+    scalar: T::Native,
+) -> Result<ArrayRef>
+where
+    T: ArrowPrimitiveType,
+{
+    interval_scalar_op_dyn::<T>(array, scalar, true)
+}
+
+fn interval_scalar_op_dyn<T>(
+    array: &dyn Array,
+    scalar: T::Native,
+    subtract: bool,
+) -> Result<ArrayRef>
+where
+    T: ArrowPrimitiveType,
+{
+    if !matches!(T::DATA_TYPE, DataType::Interval(_)) {
+        return Err(ArrowError::CastError(format!(
+            "Expected interval scalar, got {}",
+            T::DATA_TYPE
+        )));
+    }
+    let interval = PrimitiveArray::<T>::from_value(scalar, array.len());
+    interval_op_dyn(array, &interval, subtract)
+}
+
 /// Add every value in an array by a scalar. If any value in the array is null then the
 /// result is also null.
 ///
@@ -926,8 +1191,8 @@ where
 /// Add every value in an array by a scalar. If any value in the array is null then the
 /// result is also null.
 ///
-/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
-/// use `add_scalar` instead.
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `add_scalar` instead.
 pub fn add_scalar_checked<T>(
     array: &PrimitiveArray<T>,
     scalar: T::Native,
@@ -936,7 +1201,7 @@ where
     T: ArrowNumericType,
     T::Native: ArrowNativeTypeOp,
 {
-    try_unary(array, |value| value.add_checked(scalar))
+    math_checked_unary(array, |value| value.add_checked(scalar))
 }
 
 /// Add every value in an array by a scalar. If any value in the array is null then the
@@ -959,8 +1224,8 @@ where
 /// result is also null. The given array must be a `PrimitiveArray` of the type same as
 /// the scalar, or a `DictionaryArray` of the value type same as the scalar.
 ///
-/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
-/// use `add_scalar_dyn` instead.
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `add_scalar_dyn` instead.
 ///
 /// As this kernel has the branching costs and also prevents LLVM from vectorising it correctly,
 /// it is usually much slower than non-checking variant.
@@ -969,8 +1234,105 @@ where
     T: ArrowNumericType,
     T::Native: ArrowNativeTypeOp,
 {
-    try_unary_dyn::<_, T>(array, |value| value.add_checked(scalar))
-        .map(|a| Arc::new(a) as ArrayRef)
+    math_checked_unary_dyn::<T, _>(array, |value| value.add_checked(scalar))
+}
+
+/// Perform `left + right` operation on two arrays. If either left or right value is null
+/// then the result is also null.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around, as for
+/// [`add`]. For a saturating variant, use `add_saturating` instead.
+pub fn add_wrapping<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    math_op(left, right, |a, b| a.add_wrapping(b))
+}
+
+/// Perform `left + right` operation on two arrays. If either left or right value is null
+/// then the result is also null.
+///
+/// This doesn't detect overflow. Once overflowing, the result will saturate at the
+/// boundary of the type. For a wrapping variant, use `add_wrapping` instead.
+pub fn add_saturating<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    math_op(left, right, |a, b| a.add_saturating(b))
+}
+
+/// Perform `left + right` operation on two arrays. If either left or right value is null
+/// then the result is also null.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around, as for
+/// [`add_dyn`]. For a saturating variant, use `add_dyn_saturating` instead.
+pub fn add_dyn_wrapping(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    add_dyn(left, right)
+}
+
+/// Perform `left + right` operation on two arrays. If either left or right value is null
+/// then the result is also null.
+///
+/// This doesn't detect overflow. Once overflowing, the result will saturate at the
+/// boundary of the type. For a wrapping variant, use `add_dyn_wrapping` instead.
+pub fn add_dyn_saturating(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    match left.data_type() {
+        DataType::Dictionary(_, _) => {
+            typed_dict_math_op!(left, right, |a, b| a.add_saturating(b), math_op_dict)
+        }
+        _ => {
+            downcast_primitive_array!(
+                (left, right) => {
+                    math_op(left, right, |a, b| a.add_saturating(b)).map(|a| Arc::new(a) as ArrayRef)
+                }
+                _ => Err(ArrowError::CastError(format!(
+                    "Unsupported data type {}, {}",
+                    left.data_type(), right.data_type()
+                )))
+            )
+        }
+    }
+}
+
+/// Add every value in an array by a scalar. If any value in the array is null then the
+/// result is also null.
+///
+/// This doesn't detect overflow. Once overflowing, the result will saturate at the
+/// boundary of the type.
+pub fn add_scalar_saturating<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    Ok(unary(array, |value| value.add_saturating(scalar)))
+}
+
+/// Add every value in an array by a scalar. If any value in the array is null then the
+/// result is also null. The given array must be a `PrimitiveArray` of the type same as
+/// the scalar, or a `DictionaryArray` of the value type same as the scalar.
+///
+/// This doesn't detect overflow. Once overflowing, the result will saturate at the
+/// boundary of the type.
+pub fn add_scalar_saturating_dyn<T>(
+    array: &dyn Array,
+    scalar: T::Native,
+) -> Result<ArrayRef>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    unary_dyn::<_, T>(array, |value| value.add_saturating(scalar))
 }
 
 /// Perform `left - right` operation on two arrays. If either left or right value is null
@@ -992,8 +1354,8 @@ where
 /// Perform `left - right` operation on two arrays. If either left or right value is null
 /// then the result is also null.
 ///
-/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
-/// use `subtract` instead.
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `subtract` instead.
 pub fn subtract_checked<T>(
     left: &PrimitiveArray<T>,
     right: &PrimitiveArray<T>,
@@ -1002,7 +1364,7 @@ where
     T: ArrowNumericType,
     T::Native: ArrowNativeTypeOp,
 {
-    try_binary(left, right, |a, b| a.sub_checked(b))
+    math_checked_op(left, right, |a, b| a.sub_checked(b))
 }
 
 /// Perform `left - right` operation on two arrays. If either left or right value is null
@@ -1010,11 +1372,19 @@ where
 ///
 /// This doesn't detect overflow. Once overflowing, the result will wrap around.
 /// For an overflow-checking variant, use `subtract_dyn_checked` instead.
+///
+/// Interval arrays can be subtracted from date and timestamp arrays, see
+/// [`add_interval_scalar_dyn`] for the semantics.
 pub fn subtract_dyn(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
     match left.data_type() {
         DataType::Dictionary(_, _) => {
             typed_dict_math_op!(left, right, |a, b| a.sub_wrapping(b), math_op_dict)
         }
+        DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, _)
+            if matches!(right.data_type(), DataType::Interval(_)) =>
+        {
+            interval_op_dyn(left, right, true)
+        }
         _ => {
             downcast_primitive_array!(
                 (left, right) => {
@@ -1032,10 +1402,18 @@ pub fn subtract_dyn(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
 /// Perform `left - right` operation on two arrays. If either left or right value is null
 /// then the result is also null.
 ///
-/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
-/// use `subtract_dyn` instead.
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `subtract_dyn` instead.
+///
+/// Interval arrays can be subtracted from date and timestamp arrays, see
+/// [`add_interval_scalar_dyn`] for the semantics.
+///
+/// Decimal arrays are supported with the rules of [`subtract_decimal`], which detects overflow.
 pub fn subtract_dyn_checked(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
     match left.data_type() {
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+            subtract_decimal(left, right)
+        }
         DataType::Dictionary(_, _) => {
             typed_dict_math_op!(
                 left,
@@ -1044,6 +1422,11 @@ pub fn subtract_dyn_checked(left: &dyn Array, right: &dyn Array) -> Result<Array
                 math_checked_op_dict
             )
         }
+        DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, _)
+            if matches!(right.data_type(), DataType::Interval(_)) =>
+        {
+            interval_op_dyn(left, right, true)
+        }
         _ => {
             downcast_primitive_array!(
                 (left, right) => {
@@ -1058,6 +1441,132 @@ pub fn subtract_dyn_checked(left: &dyn Array, right: &dyn Array) -> Result<Array
     }
 }
 
+/// Perform `left - right` operation on two timestamp arrays of the same [`TimeUnit`],
+/// returning a duration array of `unit`. If either left or right value is null then the
+/// result is also null.
+///
+/// The timestamps are instants, and so their difference is independent of any timezone.
+/// Converting to a coarser `unit` truncates towards zero.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around.
+/// For an overflow-checking variant, use `subtract_timestamps_dyn_checked` instead.
+///
+/// ```
+/// # use arrow::array::{DurationMillisecondArray, TimestampSecondArray};
+/// # use arrow::compute::kernels::arithmetic::subtract_timestamps_dyn;
+/// # use arrow::datatypes::TimeUnit;
+/// let a = TimestampSecondArray::from(vec![Some(90), None]);
+/// let b = TimestampSecondArray::from(vec![Some(30), Some(0)]);
+/// let res = subtract_timestamps_dyn(&a, &b, TimeUnit::Millisecond).unwrap();
+/// let res = res.as_any().downcast_ref::<DurationMillisecondArray>().unwrap();
+/// assert_eq!(res, &DurationMillisecondArray::from(vec![Some(60_000), None]));
+/// ```
+pub fn subtract_timestamps_dyn(
+    left: &dyn Array,
+    right: &dyn Array,
+    unit: TimeUnit,
+) -> Result<ArrayRef> {
+    timestamp_diff_dyn(left, right, unit, false)
+}
+
+/// Perform `left - right` operation on two timestamp arrays of the same [`TimeUnit`],
+/// returning a duration array of `unit`. If either left or right value is null then the
+/// result is also null.
+///
+/// This detects overflow, including when converting to a finer `unit`, and returns an
+/// `Err` for that. For an non-overflow-checking variant, use `subtract_timestamps_dyn`
+/// instead.
+pub fn subtract_timestamps_dyn_checked(
+    left: &dyn Array,
+    right: &dyn Array,
+    unit: TimeUnit,
+) -> Result<ArrayRef> {
+    timestamp_diff_dyn(left, right, unit, true)
+}
+
+fn timestamp_diff_dyn(
+    left: &dyn Array,
+    right: &dyn Array,
+    unit: TimeUnit,
+    checked: bool,
+) -> Result<ArrayRef> {
+    macro_rules! diff {
+        ($t:ty) => {
+            timestamp_diff(
+                as_primitive_array::<$t>(left),
+                as_primitive_array::<$t>(right),
+                unit,
+                checked,
+            )
+        };
+    }
+
+    match (left.data_type(), right.data_type()) {
+        (DataType::Timestamp(l, _), DataType::Timestamp(r, _)) if l == r => match l {
+            TimeUnit::Second => diff!(TimestampSecondType),
+            TimeUnit::Millisecond => diff!(TimestampMillisecondType),
+            TimeUnit::Microsecond => diff!(TimestampMicrosecondType),
+            TimeUnit::Nanosecond => diff!(TimestampNanosecondType),
+        },
+        _ => Err(ArrowError::CastError(format!(
+            "Cannot perform arithmetic operation between array of type {} and array of type {}",
+            left.data_type(), right.data_type()
+        ))),
+    }
+}
+
+fn timestamp_diff<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    unit: TimeUnit,
+    checked: bool,
+) -> Result<ArrayRef>
+where
+    T: ArrowTimestampType<Native = i64>,
+{
+    fn diff<T, D>(
+        left: &PrimitiveArray<T>,
+        right: &PrimitiveArray<T>,
+        unit: TimeUnit,
+        checked: bool,
+    ) -> Result<ArrayRef>
+    where
+        T: ArrowTimestampType<Native = i64>,
+        D: ArrowPrimitiveType<Native = i64>,
+    {
+        let from = time_unit_multiple(&T::get_time_unit());
+        let to = time_unit_multiple(&unit);
+        let res: PrimitiveArray<D> = match (checked, to >= from) {
+            (false, true) => binary(left, right, |a, b| {
+                a.sub_wrapping(b).mul_wrapping(to / from)
+            })?,
+            (false, false) => {
+                binary(left, right, |a, b| a.sub_wrapping(b) / (from / to))?
+            }
+            (true, true) => {
+                try_binary(left, right, |a, b| a.sub_checked(b)?.mul_checked(to / from))?
+            }
+            (true, false) => {
+                try_binary(left, right, |a, b| Ok(a.sub_checked(b)? / (from / to)))?
+            }
+        };
+        Ok(Arc::new(res))
+    }
+
+    match unit {
+        TimeUnit::Second => diff::<T, DurationSecondType>(left, right, unit, checked),
+        TimeUnit::Millisecond => {
+            diff::<T, DurationMillisecondType>(left, right, unit, checked)
+        }
+        TimeUnit::Microsecond => {
+            diff::<T, DurationMicrosecondType>(left, right, unit, checked)
+        }
+        TimeUnit::Nanosecond => {
+            diff::<T, DurationNanosecondType>(left, right, unit, checked)
+        }
+    }
+}
+
 /// Subtract every value in an array by a scalar. If any value in the array is null then the
 /// result is also null.
 ///
@@ -1077,8 +1586,8 @@ where
 /// Subtract every value in an array by a scalar. If any value in the array is null then the
 /// result is also null.
 ///
-/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
-/// use `subtract_scalar` instead.
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `subtract_scalar` instead.
 pub fn subtract_scalar_checked<T>(
     array: &PrimitiveArray<T>,
     scalar: T::Native,
@@ -1087,7 +1596,7 @@ where
     T: ArrowNumericType,
     T::Native: ArrowNativeTypeOp + Zero,
 {
-    try_unary(array, |value| value.sub_checked(scalar))
+    math_checked_unary(array, |value| value.sub_checked(scalar))
 }
 
 /// Subtract every value in an array by a scalar. If any value in the array is null then the
@@ -1108,8 +1617,8 @@ where
 /// result is also null. The given array must be a `PrimitiveArray` of the type same as
 /// the scalar, or a `DictionaryArray` of the value type same as the scalar.
 ///
-/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
-/// use `subtract_scalar_dyn` instead.
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `subtract_scalar_dyn` instead.
 pub fn subtract_scalar_checked_dyn<T>(
     array: &dyn Array,
     scalar: T::Native,
@@ -1118,8 +1627,105 @@ where
     T: ArrowNumericType,
     T::Native: ArrowNativeTypeOp,
 {
-    try_unary_dyn::<_, T>(array, |value| value.sub_checked(scalar))
-        .map(|a| Arc::new(a) as ArrayRef)
+    math_checked_unary_dyn::<T, _>(array, |value| value.sub_checked(scalar))
+}
+
+/// Perform `left - right` operation on two arrays. If either left or right value is null
+/// then the result is also null.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around, as for
+/// [`subtract`]. For a saturating variant, use `subtract_saturating` instead.
+pub fn subtract_wrapping<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    math_op(left, right, |a, b| a.sub_wrapping(b))
+}
+
+/// Perform `left - right` operation on two arrays. If either left or right value is null
+/// then the result is also null.
+///
+/// This doesn't detect overflow. Once overflowing, the result will saturate at the
+/// boundary of the type. For a wrapping variant, use `subtract_wrapping` instead.
+pub fn subtract_saturating<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    math_op(left, right, |a, b| a.sub_saturating(b))
+}
+
+/// Perform `left - right` operation on two arrays. If either left or right value is null
+/// then the result is also null.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around, as for
+/// [`subtract_dyn`]. For a saturating variant, use `subtract_dyn_saturating` instead.
+pub fn subtract_dyn_wrapping(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    subtract_dyn(left, right)
+}
+
+/// Perform `left - right` operation on two arrays. If either left or right value is null
+/// then the result is also null.
+///
+/// This doesn't detect overflow. Once overflowing, the result will saturate at the
+/// boundary of the type. For a wrapping variant, use `subtract_dyn_wrapping` instead.
+pub fn subtract_dyn_saturating(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    match left.data_type() {
+        DataType::Dictionary(_, _) => {
+            typed_dict_math_op!(left, right, |a, b| a.sub_saturating(b), math_op_dict)
+        }
+        _ => {
+            downcast_primitive_array!(
+                (left, right) => {
+                    math_op(left, right, |a, b| a.sub_saturating(b)).map(|a| Arc::new(a) as ArrayRef)
+                }
+                _ => Err(ArrowError::CastError(format!(
+                    "Unsupported data type {}, {}",
+                    left.data_type(), right.data_type()
+                )))
+            )
+        }
+    }
+}
+
+/// Subtract every value in an array by a scalar. If any value in the array is null then the
+/// result is also null.
+///
+/// This doesn't detect overflow. Once overflowing, the result will saturate at the
+/// boundary of the type.
+pub fn subtract_scalar_saturating<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    Ok(unary(array, |value| value.sub_saturating(scalar)))
+}
+
+/// Subtract every value in an array by a scalar. If any value in the array is null then the
+/// result is also null. The given array must be a `PrimitiveArray` of the type same as
+/// the scalar, or a `DictionaryArray` of the value type same as the scalar.
+///
+/// This doesn't detect overflow. Once overflowing, the result will saturate at the
+/// boundary of the type.
+pub fn subtract_scalar_saturating_dyn<T>(
+    array: &dyn Array,
+    scalar: T::Native,
+) -> Result<ArrayRef>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    unary_dyn::<_, T>(array, |value| value.sub_saturating(scalar))
 }
 
 /// Perform `-` operation on an array. If value is null then the result is also null.
@@ -1162,9 +1768,162 @@ where
 /// Perform `left * right` operation on two arrays. If either left or right value is null
 /// then the result is also null.
 ///
-/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
-/// use `multiply` instead.
-pub fn multiply_checked<T>(
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `multiply` instead.
+pub fn multiply_checked<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    math_checked_op(left, right, |a, b| a.mul_checked(b))
+}
+
+/// Perform `left * right` operation on two arrays. If either left or right value is null
+/// then the result is also null.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around.
+/// For an overflow-checking variant, use `multiply_dyn_checked` instead.
+pub fn multiply_dyn(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    match left.data_type() {
+        DataType::Dictionary(_, _) => {
+            typed_dict_math_op!(left, right, |a, b| a.mul_wrapping(b), math_op_dict)
+        }
+        _ => {
+            downcast_primitive_array!(
+                (left, right) => {
+                    math_op(left, right, |a, b| a.mul_wrapping(b)).map(|a| Arc::new(a) as ArrayRef)
+                }
+                _ => Err(ArrowError::CastError(format!(
+                    "Unsupported data type {}, {}",
+                    left.data_type(), right.data_type()
+                )))
+            )
+        }
+    }
+}
+
+/// Perform `left * right` operation on two arrays. If either left or right value is null
+/// then the result is also null.
+///
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `multiply_dyn` instead.
+///
+/// Decimal arrays are supported with the rules of [`multiply_decimal`], which detects overflow.
+pub fn multiply_dyn_checked(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    match left.data_type() {
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+            multiply_decimal(left, right)
+        }
+        DataType::Dictionary(_, _) => {
+            typed_dict_math_op!(
+                left,
+                right,
+                |a, b| a.mul_checked(b),
+                math_checked_op_dict
+            )
+        }
+        _ => {
+            downcast_primitive_array!(
+                (left, right) => {
+                    math_checked_op(left, right, |a, b| a.mul_checked(b)).map(|a| Arc::new(a) as ArrayRef)
+                }
+                _ => Err(ArrowError::CastError(format!(
+                    "Unsupported data type {}, {}",
+                    left.data_type(), right.data_type()
+                )))
+            )
+        }
+    }
+}
+
+/// Multiply every value in an array by a scalar. If any value in the array is null then the
+/// result is also null.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around.
+/// For an overflow-checking variant, use `multiply_scalar_checked` instead.
+pub fn multiply_scalar<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: ArrowNativeTypeOp + Zero + One,
+{
+    Ok(unary(array, |value| value.mul_wrapping(scalar)))
+}
+
+/// Multiply every value in an array by a scalar. If any value in the array is null then the
+/// result is also null.
+///
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `multiply_scalar` instead.
+pub fn multiply_scalar_checked<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp + Zero + One,
+{
+    math_checked_unary(array, |value| value.mul_checked(scalar))
+}
+
+/// Multiply every value in an array by a scalar. If any value in the array is null then the
+/// result is also null. The given array must be a `PrimitiveArray` of the type same as
+/// the scalar, or a `DictionaryArray` of the value type same as the scalar.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around.
+/// For an overflow-checking variant, use `multiply_scalar_checked_dyn` instead.
+pub fn multiply_scalar_dyn<T>(array: &dyn Array, scalar: T::Native) -> Result<ArrayRef>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    unary_dyn::<_, T>(array, |value| value.mul_wrapping(scalar))
+}
+
+/// Subtract every value in an array by a scalar. If any value in the array is null then the
+/// result is also null. The given array must be a `PrimitiveArray` of the type same as
+/// the scalar, or a `DictionaryArray` of the value type same as the scalar.
+///
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `multiply_scalar_dyn` instead.
+pub fn multiply_scalar_checked_dyn<T>(
+    array: &dyn Array,
+    scalar: T::Native,
+) -> Result<ArrayRef>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    math_checked_unary_dyn::<T, _>(array, |value| value.mul_checked(scalar))
+}
+
+/// Perform `left * right` operation on two arrays. If either left or right value is null
+/// then the result is also null.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around, as for
+/// [`multiply`]. For a saturating variant, use `multiply_saturating` instead.
+pub fn multiply_wrapping<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    math_op(left, right, |a, b| a.mul_wrapping(b))
+}
+
+/// Perform `left * right` operation on two arrays. If either left or right value is null
+/// then the result is also null.
+///
+/// This doesn't detect overflow. Once overflowing, the result will saturate at the
+/// boundary of the type. For a wrapping variant, use `multiply_wrapping` instead.
+pub fn multiply_saturating<T>(
     left: &PrimitiveArray<T>,
     right: &PrimitiveArray<T>,
 ) -> Result<PrimitiveArray<T>>
@@ -1172,52 +1931,32 @@ where
     T: ArrowNumericType,
     T::Native: ArrowNativeTypeOp,
 {
-    try_binary(left, right, |a, b| a.mul_checked(b))
+    math_op(left, right, |a, b| a.mul_saturating(b))
 }
 
 /// Perform `left * right` operation on two arrays. If either left or right value is null
 /// then the result is also null.
 ///
-/// This doesn't detect overflow. Once overflowing, the result will wrap around.
-/// For an overflow-checking variant, use `multiply_dyn_checked` instead.
-pub fn multiply_dyn(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
-    match left.data_type() {
-        DataType::Dictionary(_, _) => {
-            typed_dict_math_op!(left, right, |a, b| a.mul_wrapping(b), math_op_dict)
-        }
-        _ => {
-            downcast_primitive_array!(
-                (left, right) => {
-                    math_op(left, right, |a, b| a.mul_wrapping(b)).map(|a| Arc::new(a) as ArrayRef)
-                }
-                _ => Err(ArrowError::CastError(format!(
-                    "Unsupported data type {}, {}",
-                    left.data_type(), right.data_type()
-                )))
-            )
-        }
-    }
+/// This doesn't detect overflow. Once overflowing, the result will wrap around, as for
+/// [`multiply_dyn`]. For a saturating variant, use `multiply_dyn_saturating` instead.
+pub fn multiply_dyn_wrapping(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    multiply_dyn(left, right)
 }
 
 /// Perform `left * right` operation on two arrays. If either left or right value is null
 /// then the result is also null.
 ///
-/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
-/// use `multiply_dyn` instead.
-pub fn multiply_dyn_checked(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+/// This doesn't detect overflow. Once overflowing, the result will saturate at the
+/// boundary of the type. For a wrapping variant, use `multiply_dyn_wrapping` instead.
+pub fn multiply_dyn_saturating(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
     match left.data_type() {
         DataType::Dictionary(_, _) => {
-            typed_dict_math_op!(
-                left,
-                right,
-                |a, b| a.mul_checked(b),
-                math_checked_op_dict
-            )
+            typed_dict_math_op!(left, right, |a, b| a.mul_saturating(b), math_op_dict)
         }
         _ => {
             downcast_primitive_array!(
                 (left, right) => {
-                    math_checked_op(left, right, |a, b| a.mul_checked(b)).map(|a| Arc::new(a) as ArrayRef)
+                    math_op(left, right, |a, b| a.mul_saturating(b)).map(|a| Arc::new(a) as ArrayRef)
                 }
                 _ => Err(ArrowError::CastError(format!(
                     "Unsupported data type {}, {}",
@@ -1231,56 +1970,26 @@ pub fn multiply_dyn_checked(left: &dyn Array, right: &dyn Array) -> Result<Array
 /// Multiply every value in an array by a scalar. If any value in the array is null then the
 /// result is also null.
 ///
-/// This doesn't detect overflow. Once overflowing, the result will wrap around.
-/// For an overflow-checking variant, use `multiply_scalar_checked` instead.
-pub fn multiply_scalar<T>(
-    array: &PrimitiveArray<T>,
-    scalar: T::Native,
-) -> Result<PrimitiveArray<T>>
-where
-    T: datatypes::ArrowNumericType,
-    T::Native: ArrowNativeTypeOp + Zero + One,
-{
-    Ok(unary(array, |value| value.mul_wrapping(scalar)))
-}
-
-/// Multiply every value in an array by a scalar. If any value in the array is null then the
-/// result is also null.
-///
-/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
-/// use `multiply_scalar` instead.
-pub fn multiply_scalar_checked<T>(
+/// This doesn't detect overflow. Once overflowing, the result will saturate at the
+/// boundary of the type.
+pub fn multiply_scalar_saturating<T>(
     array: &PrimitiveArray<T>,
     scalar: T::Native,
 ) -> Result<PrimitiveArray<T>>
-where
-    T: ArrowNumericType,
-    T::Native: ArrowNativeTypeOp + Zero + One,
-{
-    try_unary(array, |value| value.mul_checked(scalar))
-}
-
-/// Multiply every value in an array by a scalar. If any value in the array is null then the
-/// result is also null. The given array must be a `PrimitiveArray` of the type same as
-/// the scalar, or a `DictionaryArray` of the value type same as the scalar.
-///
-/// This doesn't detect overflow. Once overflowing, the result will wrap around.
-/// For an overflow-checking variant, use `multiply_scalar_checked_dyn` instead.
-pub fn multiply_scalar_dyn<T>(array: &dyn Array, scalar: T::Native) -> Result<ArrayRef>
 where
     T: ArrowNumericType,
     T::Native: ArrowNativeTypeOp,
 {
-    unary_dyn::<_, T>(array, |value| value.mul_wrapping(scalar))
+    Ok(unary(array, |value| value.mul_saturating(scalar)))
 }
 
-/// Subtract every value in an array by a scalar. If any value in the array is null then the
+/// Multiply every value in an array by a scalar. If any value in the array is null then the
 /// result is also null. The given array must be a `PrimitiveArray` of the type same as
 /// the scalar, or a `DictionaryArray` of the value type same as the scalar.
 ///
-/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
-/// use `multiply_scalar_dyn` instead.
-pub fn multiply_scalar_checked_dyn<T>(
+/// This doesn't detect overflow. Once overflowing, the result will saturate at the
+/// boundary of the type.
+pub fn multiply_scalar_saturating_dyn<T>(
     array: &dyn Array,
     scalar: T::Native,
 ) -> Result<ArrayRef>
@@ -1288,8 +1997,7 @@ where
     T: ArrowNumericType,
     T::Native: ArrowNativeTypeOp,
 {
-    try_unary_dyn::<_, T>(array, |value| value.mul_checked(scalar))
-        .map(|a| Arc::new(a) as ArrayRef)
+    unary_dyn::<_, T>(array, |value| value.mul_saturating(scalar))
 }
 
 /// Perform `left % right` operation on two arrays. If either left or right value is null
@@ -1321,8 +2029,9 @@ where
 /// then the result is also null. If any right hand value is zero then the result of this
 /// operation will be `Err(ArrowError::DivideByZero)`.
 ///
-/// When `simd` feature is not enabled. This detects overflow and returns an `Err` for that.
-/// For an non-overflow-checking variant, use `divide` instead.
+/// When `simd` feature is not enabled. This detects overflow and returns an `Err`
+/// identifying the overflowing row. For an non-overflow-checking variant, use `divide`
+/// instead.
 pub fn divide_checked<T>(
     left: &PrimitiveArray<T>,
     right: &PrimitiveArray<T>,
@@ -1409,10 +2118,15 @@ pub fn divide_dyn(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
 /// then the result is also null. If any right hand value is zero then the result of this
 /// operation will be `Err(ArrowError::DivideByZero)`.
 ///
-/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
-/// use `divide_dyn` instead.
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `divide_dyn` instead.
+///
+/// Decimal arrays are supported with the rules of [`divide_decimal`], which detects overflow.
 pub fn divide_dyn_checked(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
     match left.data_type() {
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+            divide_decimal(left, right)
+        }
         DataType::Dictionary(_, _) => {
             typed_dict_math_op!(
                 left,
@@ -1556,8 +2270,8 @@ where
 /// `Err(ArrowError::DivideByZero)`. The given array must be a `PrimitiveArray` of the type
 /// same as the scalar, or a `DictionaryArray` of the value type same as the scalar.
 ///
-/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
-/// use `divide_scalar_dyn` instead.
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `divide_scalar_dyn` instead.
 pub fn divide_scalar_checked_dyn<T>(
     array: &dyn Array,
     divisor: T::Native,
@@ -1570,8 +2284,7 @@ where
         return Err(ArrowError::DivideByZero);
     }
 
-    try_unary_dyn::<_, T>(array, |value| value.div_checked(divisor))
-        .map(|a| Arc::new(a) as ArrayRef)
+    math_checked_unary_dyn::<T, _>(array, |value| value.div_checked(divisor))
 }
 
 /// Divide every value in an array by a scalar. If any value in the array is null then the
@@ -1713,6 +2426,273 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_date_interval_subtract() {
+        let a = Date32Array::from(vec![
+            Some(Date32Type::from_naive_date(NaiveDate::from_ymd(
+                2020, 3, 31,
+            ))),
+            None,
+        ]);
+        let b = IntervalYearMonthArray::from(vec![
+            Some(IntervalYearMonthType::make_value(1, 1)),
+            Some(IntervalYearMonthType::make_value(0, 1)),
+        ]);
+        let c = subtract_dyn(&a, &b).unwrap();
+        let c = c.as_any().downcast_ref::<Date32Array>().unwrap();
+        assert_eq!(c.value_as_date(0), Some(NaiveDate::from_ymd(2019, 2, 28)));
+        assert!(c.is_null(1));
+
+        let a = Date64Array::from(vec![Date64Type::from_naive_date(
+            NaiveDate::from_ymd(2000, 3, 1),
+        )]);
+        let b = IntervalDayTimeArray::from(vec![IntervalDayTimeType::make_value(1, 0)]);
+        let c = subtract_dyn_checked(&a, &b).unwrap();
+        let c = c.as_any().downcast_ref::<Date64Array>().unwrap();
+        assert_eq!(
+            c.value(0),
+            Date64Type::from_naive_date(NaiveDate::from_ymd(2000, 2, 29))
+        );
+
+        let b =
+            IntervalMonthDayNanoArray::from(vec![IntervalMonthDayNanoType::make_value(
+                1, 2, 0,
+            )]);
+        let c = subtract_dyn(&a, &b).unwrap();
+        let c = c.as_any().downcast_ref::<Date64Array>().unwrap();
+        assert_eq!(
+            c.value(0),
+            Date64Type::from_naive_date(NaiveDate::from_ymd(2000, 1, 30))
+        );
+    }
+
+    #[test]
+    fn test_timestamp_interval_add() {
+        let ms = |y, m, d, h| {
+            NaiveDate::from_ymd(y, m, d)
+                .and_hms(h, 0, 0)
+                .timestamp_millis()
+        };
+
+        let a = TimestampMillisecondArray::from(vec![
+            Some(ms(2020, 1, 31, 12)),
+            None,
+            Some(ms(2019, 12, 31, 23)),
+        ])
+        .with_timezone("+01:00".to_string());
+        let b = IntervalYearMonthArray::from(vec![
+            Some(IntervalYearMonthType::make_value(0, 1)),
+            Some(IntervalYearMonthType::make_value(0, 1)),
+            Some(IntervalYearMonthType::make_value(1, 2)),
+        ]);
+        let c = add_dyn(&a, &b).unwrap();
+        assert_eq!(c.data_type(), a.data_type());
+        let c = as_primitive_array::<TimestampMillisecondType>(&c);
+        assert_eq!(c.value(0), ms(2020, 2, 29, 12));
+        assert!(c.is_null(1));
+        assert_eq!(c.value(2), ms(2021, 2, 28, 23));
+
+        // 2021-02-28T23:00Z is 2021-03-01T00:00 in the timezone of the array
+        let d = subtract_dyn_checked(c, &b).unwrap();
+        let d = as_primitive_array::<TimestampMillisecondType>(&d);
+        assert_eq!(d.value(0), ms(2020, 1, 29, 12));
+        assert_eq!(d.value(2), ms(2019, 12, 31, 23));
+
+        let b = IntervalDayTimeArray::from(vec![
+            IntervalDayTimeType::make_value(1, 3_600_000),
+            IntervalDayTimeType::make_value(0, 0),
+            IntervalDayTimeType::make_value(-1, -1),
+        ]);
+        let c = add_dyn_checked(&a, &b).unwrap();
+        let c = as_primitive_array::<TimestampMillisecondType>(&c);
+        assert_eq!(c.value(0), ms(2020, 2, 1, 13));
+        assert_eq!(c.value(2), ms(2019, 12, 30, 23) - 1);
+
+        let a = TimestampNanosecondArray::from(vec![ms(2020, 2, 29, 0) * 1_000_000]);
+        let b =
+            IntervalMonthDayNanoArray::from(vec![IntervalMonthDayNanoType::make_value(
+                12, 1, 5,
+            )]);
+        let c = add_dyn(&a, &b).unwrap();
+        let c = as_primitive_array::<TimestampNanosecondType>(&c);
+        assert_eq!(c.value(0), ms(2021, 3, 1, 0) * 1_000_000 + 5);
+
+        let c = subtract_dyn(&a, &b).unwrap();
+        let c = as_primitive_array::<TimestampNanosecondType>(&c);
+        assert_eq!(c.value(0), ms(2019, 2, 27, 0) * 1_000_000 - 5);
+
+        // The result cannot be represented as a nanosecond timestamp
+        let a = TimestampNanosecondArray::from(vec![i64::MAX - 1]);
+        let b = IntervalDayTimeArray::from(vec![IntervalDayTimeType::make_value(1, 0)]);
+        let err = add_dyn(&a, &b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Timestamp 9223372036854775806 of Nanosecond out of range at row 0"
+        );
+
+        // The months cannot be represented as a date
+        let a = TimestampSecondArray::from(vec![0]);
+        let b = IntervalYearMonthArray::from(vec![i32::MAX]);
+        let err = add_dyn(&a, &b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Timestamp 0 of Second out of range at row 0"
+        );
+        let b = IntervalYearMonthArray::from(vec![12 * 300_000]);
+        assert!(add_dyn(&a, &b).is_err());
+        assert!(subtract_dyn(&a, &b).is_err());
+        let b =
+            IntervalMonthDayNanoArray::from(vec![IntervalMonthDayNanoType::make_value(
+                i32::MIN,
+                0,
+                0,
+            )]);
+        assert!(add_dyn(&a, &b).is_err());
+        assert!(subtract_dyn(&a, &b).is_err());
+    }
+
+    #[test]
+    fn test_timestamp_interval_timezone() {
+        let s = |y, m, d, h| NaiveDate::from_ymd(y, m, d).and_hms(h, 0, 0).timestamp();
+
+        let b = IntervalYearMonthArray::from(vec![
+            IntervalYearMonthType::make_value(0, 1),
+            IntervalYearMonthType::make_value(0, 1),
+        ]);
+        let a = TimestampSecondArray::from(vec![s(2020, 1, 30, 22), s(2020, 1, 31, 2)]);
+        let c = add_dyn(&a, &b).unwrap();
+        let expected =
+            TimestampSecondArray::from(vec![s(2020, 2, 29, 22), s(2020, 2, 29, 2)]);
+        assert_eq!(c.as_ref(), &expected);
+
+        // 2020-01-30T22:00Z is 2020-01-31T03:00 in +05:00
+        let a = a.with_timezone("+05:00".to_string());
+        let c = add_dyn(&a, &b).unwrap();
+        let expected =
+            TimestampSecondArray::from(vec![s(2020, 2, 28, 22), s(2020, 2, 29, 2)])
+                .with_timezone("+05:00".to_string());
+        assert_eq!(c.as_ref(), &expected);
+
+        // 2020-01-31T02:00Z is 2020-01-30T21:00 in -05:00
+        let a = a.with_timezone("-05:00".to_string());
+        let c = add_dyn(&a, &b).unwrap();
+        let expected =
+            TimestampSecondArray::from(vec![s(2020, 2, 29, 22), s(2020, 3, 1, 2)])
+                .with_timezone("-05:00".to_string());
+        assert_eq!(c.as_ref(), &expected);
+
+        // 2020-02-29T22:00Z is 2020-03-01T03:00 in +05:00
+        let b = b.slice(0, 1);
+        let b = as_primitive_array::<IntervalYearMonthType>(&b);
+        let a = TimestampSecondArray::from(vec![s(2020, 2, 29, 22)])
+            .with_timezone("+05:00".to_string());
+        let c = subtract_dyn(&a, &b).unwrap();
+        let expected = TimestampSecondArray::from(vec![s(2020, 1, 31, 22)])
+            .with_timezone("+05:00".to_string());
+        assert_eq!(c.as_ref(), &expected);
+
+        // Out of range once converted to local time
+        let a = TimestampSecondArray::from(vec![8210298412799])
+            .with_timezone("+05:00".to_string());
+        let b = IntervalDayTimeArray::from(vec![IntervalDayTimeType::make_value(0, 0)]);
+        let err = add_dyn(&a, &b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Timestamp 8210298412799 of Second out of range at row 0"
+        );
+    }
+
+    #[test]
+    fn test_subtract_timestamps() {
+        let a = TimestampMillisecondArray::from(vec![Some(5_500), None, Some(-1_500)])
+            .with_timezone("+01:00".to_string());
+        let b = TimestampMillisecondArray::from(vec![Some(1_000), Some(0), Some(0)]);
+
+        let c = subtract_timestamps_dyn(&a, &b, TimeUnit::Millisecond).unwrap();
+        let expected =
+            DurationMillisecondArray::from(vec![Some(4_500), None, Some(-1_500)]);
+        assert_eq!(c.as_ref(), &expected);
+
+        // Coarser units truncate towards zero
+        let c = subtract_timestamps_dyn(&a, &b, TimeUnit::Second).unwrap();
+        let expected = DurationSecondArray::from(vec![Some(4), None, Some(-1)]);
+        assert_eq!(c.as_ref(), &expected);
+
+        let c = subtract_timestamps_dyn_checked(&a, &b, TimeUnit::Nanosecond).unwrap();
+        let expected = DurationNanosecondArray::from(vec![
+            Some(4_500_000_000),
+            None,
+            Some(-1_500_000_000),
+        ]);
+        assert_eq!(c.as_ref(), &expected);
+
+        // Overflow wraps around unless checked
+        let a = TimestampSecondArray::from(vec![i64::MAX]);
+        let b = TimestampSecondArray::from(vec![-1]);
+        let c = subtract_timestamps_dyn(&a, &b, TimeUnit::Second).unwrap();
+        assert_eq!(c.as_ref(), &DurationSecondArray::from(vec![i64::MIN]));
+        let err = subtract_timestamps_dyn_checked(&a, &b, TimeUnit::Second).unwrap_err();
+        assert!(err.to_string().contains("Overflow happened"), "{}", err);
+
+        let b = TimestampSecondArray::from(vec![i64::MAX / 2]);
+        let c = subtract_timestamps_dyn(&a, &b, TimeUnit::Microsecond).unwrap();
+        assert_eq!(c.len(), 1);
+        let err =
+            subtract_timestamps_dyn_checked(&a, &b, TimeUnit::Microsecond).unwrap_err();
+        assert!(err.to_string().contains("Overflow happened"), "{}", err);
+
+        let b = TimestampMillisecondArray::from(vec![0]);
+        let err = subtract_timestamps_dyn(&a, &b, TimeUnit::Second).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot perform arithmetic operation between array of type Timestamp(Second, None) and array of type Timestamp(Millisecond, None)"
+        );
+    }
+
+    #[test]
+    fn test_interval_scalar() {
+        let a = TimestampSecondArray::from(vec![Some(0), None, Some(86_400 * 31)]);
+        let c = add_interval_scalar_dyn::<IntervalMonthDayNanoType>(
+            &a,
+            IntervalMonthDayNanoType::make_value(1, 0, 1_500_000_000),
+        )
+        .unwrap();
+        let c = as_primitive_array::<TimestampSecondType>(&c);
+        let expected = TimestampSecondArray::from(vec![
+            Some(86_400 * 31 + 1),
+            None,
+            Some(86_400 * (31 + 28) + 1),
+        ]);
+        assert_eq!(c, &expected);
+
+        let a = Date32Array::from(vec![Date32Type::from_naive_date(
+            NaiveDate::from_ymd(2020, 2, 29),
+        )]);
+        let c = subtract_interval_scalar_dyn::<IntervalYearMonthType>(
+            &a,
+            IntervalYearMonthType::make_value(1, 0),
+        )
+        .unwrap();
+        let c = c.as_any().downcast_ref::<Date32Array>().unwrap();
+        assert_eq!(c.value_as_date(0), Some(NaiveDate::from_ymd(2019, 2, 28)));
+
+        let err = add_interval_scalar_dyn::<Int32Type>(&a, 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Expected interval scalar, got Int32"
+        );
+
+        let err = add_interval_scalar_dyn::<IntervalYearMonthType>(
+            &Int32Array::from(vec![1]),
+            1,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot perform arithmetic operation between array of type Int32 and array of type Interval(YearMonth)"
+        );
+    }
+
     #[test]
     fn test_primitive_array_add_dyn() {
         let a = Int32Array::from(vec![Some(5), Some(6), Some(7), Some(8), Some(9)]);
@@ -1754,6 +2734,19 @@ mod tests {
         assert_eq!(19, c.value(4));
     }
 
+    #[test]
+    #[cfg(feature = "dyn_arith_dict")]
+    fn test_primitive_array_multiply_dyn_dict_shared() {
+        let values = Int32Array::from(vec![Some(3), None, Some(-2)]);
+        let keys = Int8Array::from(vec![Some(2), Some(0), None, Some(1), Some(2)]);
+        let a = DictionaryArray::try_new(&keys, &values).unwrap();
+        let b = DictionaryArray::try_new(&keys, &values).unwrap();
+
+        let c = multiply_dyn(&a, &b).unwrap();
+        let expected = Int32Array::from(vec![Some(4), Some(9), None, None, Some(4)]);
+        assert_eq!(c.as_ref(), &expected);
+    }
+
     #[test]
     fn test_primitive_array_add_scalar_dyn() {
         let a = Int32Array::from(vec![Some(5), Some(6), Some(7), None, Some(9)]);
@@ -2961,4 +3954,252 @@ mod tests {
         let c = add(&a, &b).unwrap();
         assert_eq!(c, expected);
     }
+
+    #[test]
+    fn test_checked_overflow_row() {
+        let a = Int8Array::from(vec![Some(1), None, Some(127)]);
+        let b = Int8Array::from(vec![1, 1, 1]);
+        let err = add_checked(&a, &b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Overflow happened on: 127 + 1 at row 2"
+        );
+        let err = add_dyn_checked(&a, &b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Overflow happened on: 127 + 1 at row 2"
+        );
+
+        // Rows are relative to the offset of a sliced array
+        let a = Int8Array::from(vec![-128, 0, -128]);
+        let a = a.slice(1, 2);
+        let b = Int8Array::from(vec![1, 1]);
+        let err = subtract_dyn_checked(&a, &b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Overflow happened on: -128 - 1 at row 1"
+        );
+
+        let a = Int32Array::from(vec![None, Some(1), Some(i32::MAX)]);
+        let err = multiply_scalar_checked(&a, 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Overflow happened on: 2147483647 * 2 at row 2"
+        );
+
+        let err =
+            divide_dyn_checked(&Int8Array::from(vec![-128]), &Int8Array::from(vec![-1]))
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Overflow happened on: -128 / -1 at row 0"
+        );
+
+        let keys = Int8Array::from(vec![Some(0), None, Some(0), Some(1)]);
+        let values = Int8Array::from(vec![1, 127]);
+        let dict = DictionaryArray::try_new(&keys, &values).unwrap();
+        let err = add_scalar_checked_dyn::<Int8Type>(&dict, 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Overflow happened on: 127 + 1 at row 3"
+        );
+
+        // Division by zero is not an overflow
+        let err = divide_checked(&Int8Array::from(vec![1]), &Int8Array::from(vec![0]))
+            .unwrap_err();
+        assert!(matches!(err, ArrowError::DivideByZero));
+    }
+
+    #[test]
+    fn test_checked_different_lengths() {
+        let a = Int32Array::from(vec![1, 2, 3]);
+        let b = Int32Array::from(vec![1]);
+        let expected =
+            "Compute error: Cannot perform binary operation on arrays of different length";
+        assert_eq!(add_checked(&a, &b).unwrap_err().to_string(), expected);
+        assert_eq!(add_checked(&b, &a).unwrap_err().to_string(), expected);
+        assert_eq!(divide_checked(&a, &b).unwrap_err().to_string(), expected);
+        assert_eq!(add_dyn_checked(&a, &b).unwrap_err().to_string(), expected);
+        assert_eq!(
+            divide_dyn_checked(&b, &a).unwrap_err().to_string(),
+            expected
+        );
+
+        #[cfg(feature = "dyn_arith_dict")]
+        {
+            let values = Int32Array::from(vec![1, 2]);
+            let keys = Int8Array::from(vec![0, 1, 0]);
+            let a = DictionaryArray::try_new(&keys, &values).unwrap();
+            let keys = Int8Array::from(vec![1]);
+            let b = DictionaryArray::try_new(&keys, &values).unwrap();
+            assert_eq!(add_dyn_checked(&a, &b).unwrap_err().to_string(), expected);
+            assert_eq!(
+                divide_dyn_checked(&a, &b).unwrap_err().to_string(),
+                "Compute error: Cannot perform operation on arrays of different length (3, 1)"
+            );
+        }
+    }
+
+    #[test]
+    fn test_decimal_dyn_checked() {
+        let a: Decimal128Array = vec![Some(150), None].into_iter().collect();
+        let a = a.with_precision_and_scale(3, 2).unwrap();
+        let b: Decimal128Array = vec![Some(25), Some(1)].into_iter().collect();
+        let b = b.with_precision_and_scale(2, 1).unwrap();
+
+        let c = add_dyn_checked(&a, &b).unwrap();
+        let c = c.as_any().downcast_ref::<Decimal128Array>().unwrap();
+        assert_eq!(c.data_type(), &DataType::Decimal128(4, 2));
+        assert_eq!(c.value_as_string(0), "4.00");
+        assert!(c.is_null(1));
+
+        let c = subtract_dyn_checked(&a, &b).unwrap();
+        let c = c.as_any().downcast_ref::<Decimal128Array>().unwrap();
+        assert_eq!(c.value_as_string(0), "-1.00");
+
+        let c = multiply_dyn_checked(&a, &b).unwrap();
+        let c = c.as_any().downcast_ref::<Decimal128Array>().unwrap();
+        assert_eq!(c.value_as_string(0), "3.750");
+
+        let c = divide_dyn_checked(&a, &b).unwrap();
+        let c = c.as_any().downcast_ref::<Decimal128Array>().unwrap();
+        assert_eq!(c.value_as_string(0), "0.600000");
+
+        let max: Decimal128Array = vec![Some(1), Some(10_i128.pow(38) - 1)]
+            .into_iter()
+            .collect();
+        let max = max.with_precision_and_scale(38, 0).unwrap();
+        let err = add_dyn_checked(&max, &max).unwrap_err();
+        assert!(err.to_string().contains("at row 1"), "{}", err);
+    }
+
+    #[test]
+    fn test_saturating_and_wrapping() {
+        let a = Int32Array::from(vec![Some(i32::MAX), Some(i32::MIN), None, Some(1)]);
+        let b = Int32Array::from(vec![Some(1), Some(1), Some(1), Some(2)]);
+
+        let c = add_saturating(&a, &b).unwrap();
+        assert_eq!(
+            c,
+            Int32Array::from(vec![Some(i32::MAX), Some(i32::MIN + 1), None, Some(3)])
+        );
+        let c = add_wrapping(&a, &b).unwrap();
+        assert_eq!(
+            c,
+            Int32Array::from(vec![Some(i32::MIN), Some(i32::MIN + 1), None, Some(3)])
+        );
+
+        let c = subtract_saturating(&a, &b).unwrap();
+        assert_eq!(
+            c,
+            Int32Array::from(vec![Some(i32::MAX - 1), Some(i32::MIN), None, Some(-1)])
+        );
+        let c = subtract_wrapping(&a, &b).unwrap();
+        assert_eq!(
+            c,
+            Int32Array::from(vec![Some(i32::MAX - 1), Some(i32::MAX), None, Some(-1)])
+        );
+
+        let c = multiply_saturating(&a, &b).unwrap();
+        assert_eq!(
+            c,
+            Int32Array::from(vec![Some(i32::MAX), Some(i32::MIN), None, Some(2)])
+        );
+        let c = multiply_wrapping(&a, &b).unwrap();
+        assert_eq!(
+            c,
+            Int32Array::from(vec![Some(i32::MAX), Some(i32::MIN), None, Some(2)])
+        );
+
+        let c = add_scalar_saturating(&a, 10).unwrap();
+        assert_eq!(
+            c,
+            Int32Array::from(vec![Some(i32::MAX), Some(i32::MIN + 10), None, Some(11)])
+        );
+        let c = subtract_scalar_saturating(&a, 10).unwrap();
+        assert_eq!(
+            c,
+            Int32Array::from(vec![Some(i32::MAX - 10), Some(i32::MIN), None, Some(-9)])
+        );
+        let c = multiply_scalar_saturating(&a, -1).unwrap();
+        assert_eq!(
+            c,
+            Int32Array::from(vec![Some(-i32::MAX), Some(i32::MAX), None, Some(-1)])
+        );
+
+        let c = add_dyn_saturating(&a, &b).unwrap();
+        let c = c.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(
+            c,
+            &Int32Array::from(vec![Some(i32::MAX), Some(i32::MIN + 1), None, Some(3)])
+        );
+        let c = subtract_dyn_wrapping(&a, &b).unwrap();
+        let c = c.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(
+            c,
+            &Int32Array::from(vec![Some(i32::MAX - 1), Some(i32::MAX), None, Some(-1)])
+        );
+        let c = multiply_dyn_saturating(&a, &Int32Array::from(vec![2, 2, 2, 2])).unwrap();
+        let c = c.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(
+            c,
+            &Int32Array::from(vec![Some(i32::MAX), Some(i32::MIN), None, Some(2)])
+        );
+
+        let c = add_scalar_saturating_dyn::<Int32Type>(&a, 10).unwrap();
+        let c = c.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(
+            c,
+            &Int32Array::from(vec![Some(i32::MAX), Some(i32::MIN + 10), None, Some(11)])
+        );
+
+        let f = Float64Array::from(vec![1.5, f64::MAX]);
+        let c = add_saturating(&f, &f).unwrap();
+        assert_eq!(c, Float64Array::from(vec![3.0, f64::INFINITY]));
+
+        let err = add_dyn_saturating(&a, &f).unwrap_err();
+        assert!(err.to_string().contains("Unsupported data type"), "{}", err);
+    }
+
+    #[test]
+    #[cfg(feature = "dyn_arith_dict")]
+    fn test_dictionary_shared_keys() {
+        let keys = Int8Array::from(vec![Some(1), None, Some(0), Some(1)]);
+        // The values at index 2 and 3 are not referenced by any key
+        let a = Int32Array::from(vec![Some(10), Some(7), Some(i32::MAX), Some(5)]);
+        let b = Int32Array::from(vec![Some(2), Some(3), Some(1), Some(0)]);
+        let a = DictionaryArray::try_new(&keys, &a).unwrap();
+        let b = DictionaryArray::try_new(&keys, &b).unwrap();
+
+        // The result has the same type whether or not the keys are shared
+        let expected = Int32Array::from(vec![Some(10), None, Some(12), Some(10)]);
+        let c = add_dyn(&a, &b).unwrap();
+        assert_eq!(c.as_ref(), &expected);
+
+        // The unreferenced values don't cause a spurious error
+        let c = add_dyn_checked(&a, &b).unwrap();
+        assert_eq!(c.as_ref(), &expected);
+
+        let c = divide_dyn_checked(&a, &b).unwrap();
+        let expected = Int32Array::from(vec![Some(2), None, Some(5), Some(2)]);
+        assert_eq!(c.as_ref(), &expected);
+
+        let c = divide_dyn_opt(&a, &b).unwrap();
+        assert_eq!(c.as_ref(), &expected);
+
+        // A referenced overflow is still reported
+        let keys = Int8Array::from(vec![0, 2]);
+        let a = DictionaryArray::try_new(&keys, a.values()).unwrap();
+        let err = multiply_dyn_checked(&a, &a).unwrap_err();
+        assert!(err.to_string().contains("at row 1"), "{}", err);
+
+        // Dictionaries with different keys are evaluated per row
+        let c = subtract_dyn(&a, &b.slice(0, 2)).unwrap();
+        let expected = Int32Array::from(vec![Some(7), None]);
+        assert_eq!(c.as_ref(), &expected);
+
+        // Operations with a scalar only evaluate the values
+        let c = add_scalar_dyn::<Int32Type>(&a, 1).unwrap();
+        assert_eq!(c.data_type(), a.data_type());
+    }
 }
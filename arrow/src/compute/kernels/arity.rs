@@ -205,6 +205,96 @@ where
     Ok(unsafe { build_primitive_array(len, buffer, null_count, null_buffer) })
 }
 
+/// Like [`binary`] but consumes `a`, writing the results into its values buffer when no
+/// other array or buffer references it, and otherwise falling back to allocating a new
+/// buffer. The data type of `a`, including any timezone or precision, is preserved.
+///
+/// This avoids an allocation per operation when chaining kernels over intermediate results,
+/// e.g. when evaluating an expression tree.
+///
+/// # Error
+///
+/// This function gives error if the arrays have different lengths
+pub fn binary_mut<T, F>(
+    a: PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    if a.len() != b.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform binary operation on arrays of different length".to_string(),
+        ));
+    }
+    let len = a.len();
+
+    let data = ArrayData::from(a);
+    let data_type = data.data_type().clone();
+    let offset = data.offset();
+    let mut buffer = data.buffers()[0].clone();
+    let (nulls, null_count) = match b.null_count() {
+        // the validity of `a` is unchanged and can be kept at its offset
+        0 => (data.null_buffer().cloned(), data.null_count()),
+        _ => {
+            let combined = combine_option_bitmap(&[&data, b.data()], len)?;
+            let null_count = combined
+                .as_ref()
+                .map(|x| len - x.count_set_bits())
+                .unwrap_or_default();
+            (combined, null_count)
+        }
+    };
+    // the combined validity starts at offset zero, so only lines up with an unsliced `a`
+    let in_place = offset == 0 || b.null_count() == 0;
+    // release the array's reference so that `buffer` may be uniquely owned
+    drop(data);
+
+    let (buffer, offset, nulls) =
+        match buffer.typed_data_mut::<T::Native>().filter(|_| in_place) {
+            Some(values) => {
+                values[offset..offset + len]
+                    .iter_mut()
+                    .zip(b.values())
+                    .for_each(|(l, r)| *l = op(*l, *r));
+                (buffer, offset, nulls)
+            }
+            None => {
+                let values = buffer.typed_data::<T::Native>()[offset..offset + len]
+                    .iter()
+                    .zip(b.values())
+                    .map(|(l, r)| op(*l, *r));
+                // JUSTIFICATION
+                //  Benefit
+                //      ~60% speedup
+                //  Soundness
+                //      `values` is an iterator with a known size from a PrimitiveArray
+                let buffer = unsafe { Buffer::from_trusted_len_iter(values) };
+                let nulls = match b.null_count() {
+                    0 => nulls.map(|x| x.bit_slice(offset, len)),
+                    _ => nulls,
+                };
+                (buffer, 0, nulls)
+            }
+        };
+
+    // SAFETY
+    // the values and validity have the length of `a`, and line up at `offset`
+    Ok(PrimitiveArray::from(unsafe {
+        ArrayData::new_unchecked(
+            data_type,
+            len,
+            Some(null_count),
+            nulls,
+            offset,
+            vec![buffer],
+            vec![],
+        )
+    }))
+}
+
 /// Applies the provided fallible binary operation across `a` and `b`, returning any error,
 /// and collecting the results into a [`PrimitiveArray`]. If any index is null in either `a`
 /// or `b`, the corresponding index in the result will also be null
@@ -356,7 +446,9 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::array::{as_primitive_array, Float64Array, PrimitiveDictionaryBuilder};
+    use crate::array::{
+        as_primitive_array, Float64Array, Int32Array, PrimitiveDictionaryBuilder,
+    };
     use crate::datatypes::{Float64Type, Int32Type, Int8Type};
 
     #[test]
@@ -417,4 +509,53 @@ mod tests {
             &expected
         );
     }
+
+    #[test]
+    fn test_binary_mut() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3), Some(4)]);
+        let b = Int32Array::from(vec![Some(10), Some(20), None, Some(40)]);
+        let ptr = a.data().buffers()[0].as_ptr();
+        let c = binary_mut(a, &b, |l, r| l + r).unwrap();
+        assert_eq!(c, Int32Array::from(vec![Some(11), None, None, Some(44)]));
+        // uniquely owned, so updated in place
+        assert_eq!(c.data().buffers()[0].as_ptr(), ptr);
+
+        // shared with another array, so must not be mutated
+        let shared = Int32Array::from(c.data().clone());
+        let d = binary_mut(c, &b, |l, r| l * r).unwrap();
+        assert_eq!(d, Int32Array::from(vec![Some(110), None, None, Some(1760)]));
+        assert_ne!(d.data().buffers()[0].as_ptr(), ptr);
+        assert_eq!(
+            shared,
+            Int32Array::from(vec![Some(11), None, None, Some(44)])
+        );
+
+        // uniquely owned slice keeps its offset and validity
+        let a = {
+            let a = Int32Array::from(vec![Some(1), None, Some(3), Some(4)]);
+            Int32Array::from(a.data().slice(1, 3))
+        };
+        let b = Int32Array::from(vec![1, 2, 3]);
+        let c = binary_mut(a, &b, |l, r| l - r).unwrap();
+        assert_eq!(c, Int32Array::from(vec![None, Some(1), Some(1)]));
+        assert_eq!(c.offset(), 1);
+
+        // shared slice with nulls in both inputs
+        let sliced = shared.slice(1, 3);
+        let a = Int32Array::from(sliced.data().clone());
+        let b = Int32Array::from(vec![Some(1), None, Some(4)]);
+        let c = binary_mut(a, &b, |l, r| l - r).unwrap();
+        assert_eq!(c, Int32Array::from(vec![None, None, Some(40)]));
+
+        // shared slice with nulls only in `a`
+        let sliced = shared.slice(2, 2);
+        let a = Int32Array::from(sliced.data().clone());
+        let b = Int32Array::from(vec![1, 2]);
+        let c = binary_mut(a, &b, |l, r| l - r).unwrap();
+        assert_eq!(c, Int32Array::from(vec![None, Some(42)]));
+
+        let a = Int32Array::from(vec![1, 2, 3]);
+        let b = Int32Array::from(vec![1, 2]);
+        binary_mut(a, &b, |l, r| l + r).unwrap_err();
+    }
 }
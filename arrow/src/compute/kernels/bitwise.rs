@@ -17,8 +17,9 @@
 
 use crate::array::PrimitiveArray;
 use crate::compute::{binary, unary};
-use crate::datatypes::ArrowNumericType;
+use crate::datatypes::{ArrowNativeType, ArrowNumericType};
 use crate::error::Result;
+use num::traits::{WrappingShl, WrappingShr};
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
 // The helper function for bitwise operation with two array
@@ -73,6 +74,34 @@ where
     bitwise_op(left, right, |a, b| a ^ b)
 }
 
+/// Perform `left << right` operation on two arrays. If either left or right value is null
+/// then the result is also null. The shift amount wraps around the bit width of the type,
+/// e.g. shifting an `i32` left by 33 is the same as shifting it by 1.
+pub fn bitwise_shift_left<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: WrappingShl,
+{
+    bitwise_op(left, right, |a, b| a.wrapping_shl(b.as_usize() as u32))
+}
+
+/// Perform `left >> right` operation on two arrays. If either left or right value is null
+/// then the result is also null. Signed values are shifted arithmetically, and the shift
+/// amount wraps around the bit width of the type.
+pub fn bitwise_shift_right<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: WrappingShr,
+{
+    bitwise_op(left, right, |a, b| a.wrapping_shr(b.as_usize() as u32))
+}
+
 /// Perform `!array` operation on array. If array value is null
 /// then the result is also null.
 pub fn bitwise_not<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
@@ -122,12 +151,43 @@ where
     Ok(unary(array, |value| value ^ scalar))
 }
 
+/// Perform bitwise `shift left` every value in an array by the scalar. If any value in
+/// the array is null then the result is also null. The shift amount wraps around the bit
+/// width of the type.
+pub fn bitwise_shift_left_scalar<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: WrappingShl,
+{
+    let shift = scalar.as_usize() as u32;
+    Ok(unary(array, |value| value.wrapping_shl(shift)))
+}
+
+/// Perform bitwise `shift right` every value in an array by the scalar. If any value in
+/// the array is null then the result is also null. Signed values are shifted arithmetically,
+/// and the shift amount wraps around the bit width of the type.
+pub fn bitwise_shift_right_scalar<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: WrappingShr,
+{
+    let shift = scalar.as_usize() as u32;
+    Ok(unary(array, |value| value.wrapping_shr(shift)))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::array::{Int32Array, UInt64Array};
     use crate::compute::kernels::bitwise::{
         bitwise_and, bitwise_and_scalar, bitwise_not, bitwise_or, bitwise_or_scalar,
-        bitwise_xor, bitwise_xor_scalar,
+        bitwise_shift_left, bitwise_shift_left_scalar, bitwise_shift_right,
+        bitwise_shift_right_scalar, bitwise_xor, bitwise_xor_scalar,
     };
     use crate::error::Result;
 
@@ -258,4 +318,79 @@ mod tests {
         assert_eq!(expected, result);
         Ok(())
     }
+
+    #[test]
+    fn test_bitwise_shift_left_array() -> Result<()> {
+        // unsigned value
+        let left = UInt64Array::from(vec![Some(1), Some(2), None, Some(4), Some(8)]);
+        let right =
+            UInt64Array::from(vec![Some(5), Some(10), Some(8), Some(12), Some(65)]);
+        let expected =
+            UInt64Array::from(vec![Some(32), Some(2048), None, Some(16384), Some(16)]);
+        let result = bitwise_shift_left(&left, &right)?;
+        assert_eq!(expected, result);
+
+        // signed value
+        let left = Int32Array::from(vec![Some(1), Some(-2), None, Some(4), Some(8)]);
+        let right = Int32Array::from(vec![Some(5), Some(3), Some(8), Some(31), Some(33)]);
+        let expected =
+            Int32Array::from(vec![Some(32), Some(-16), None, Some(0), Some(16)]);
+        let result = bitwise_shift_left(&left, &right)?;
+        assert_eq!(expected, result);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bitwise_shift_left_array_scalar() -> Result<()> {
+        // unsigned value
+        let left = UInt64Array::from(vec![Some(15), Some(2), None, Some(4)]);
+        let scalar = 3;
+        let expected = UInt64Array::from(vec![Some(120), Some(16), None, Some(32)]);
+        let result = bitwise_shift_left_scalar(&left, scalar)?;
+        assert_eq!(expected, result);
+
+        // signed value
+        let left = Int32Array::from(vec![Some(1), Some(-2), None, Some(4)]);
+        let scalar = 34;
+        let expected = Int32Array::from(vec![Some(4), Some(-8), None, Some(16)]);
+        let result = bitwise_shift_left_scalar(&left, scalar)?;
+        assert_eq!(expected, result);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bitwise_shift_right_array() -> Result<()> {
+        // unsigned value
+        let left = UInt64Array::from(vec![Some(32), Some(2048), None, Some(u64::MAX)]);
+        let right = UInt64Array::from(vec![Some(5), Some(10), Some(8), Some(63)]);
+        let expected = UInt64Array::from(vec![Some(1), Some(2), None, Some(1)]);
+        let result = bitwise_shift_right(&left, &right)?;
+        assert_eq!(expected, result);
+
+        // signed value
+        let left = Int32Array::from(vec![Some(32), Some(-16), None, Some(16)]);
+        let right = Int32Array::from(vec![Some(5), Some(3), Some(8), Some(35)]);
+        let expected = Int32Array::from(vec![Some(1), Some(-2), None, Some(2)]);
+        let result = bitwise_shift_right(&left, &right)?;
+        assert_eq!(expected, result);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bitwise_shift_right_array_scalar() -> Result<()> {
+        // unsigned value
+        let left = UInt64Array::from(vec![Some(15), Some(2), None, Some(64)]);
+        let scalar = 1;
+        let expected = UInt64Array::from(vec![Some(7), Some(1), None, Some(32)]);
+        let result = bitwise_shift_right_scalar(&left, scalar)?;
+        assert_eq!(expected, result);
+
+        // signed value
+        let left = Int32Array::from(vec![Some(-1), Some(-20), None, Some(20)]);
+        let scalar = 2;
+        let expected = Int32Array::from(vec![Some(-1), Some(-5), None, Some(5)]);
+        let result = bitwise_shift_right_scalar(&left, scalar)?;
+        assert_eq!(expected, result);
+        Ok(())
+    }
 }
@@ -47,31 +47,127 @@ use crate::compute::divide_scalar;
 use crate::compute::kernels::arithmetic::{divide, multiply};
 use crate::compute::kernels::arity::unary;
 use crate::compute::kernels::cast_utils::string_to_timestamp_nanos;
+use crate::compute::kernels::decimal::RoundingMode;
 use crate::compute::kernels::temporal::extract_component_from_array;
+use crate::compute::kernels::temporal::parse_timestamps;
 use crate::compute::kernels::temporal::return_compute_error_with;
+use crate::compute::kernels::temporal::strftime;
 use crate::compute::{try_unary, using_chrono_tz_and_utc_naive_date_time};
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::temporal_conversions::{
-    as_datetime, EPOCH_DAYS_FROM_CE, MICROSECONDS, MILLISECONDS, MILLISECONDS_IN_DAY,
-    NANOSECONDS, SECONDS_IN_DAY,
+    as_datetime, time_unit_multiple, EPOCH_DAYS_FROM_CE, MILLISECONDS,
+    MILLISECONDS_IN_DAY, NANOSECONDS, SECONDS_IN_DAY,
 };
 use crate::{array::*, compute::take};
 use crate::{
-    buffer::Buffer, util::display::array_value_to_string,
+    buffer::Buffer,
+    util::display::{array_value_to_string, ArrayFormatter, FormatOptions},
     util::serialization::lexical_to_string,
 };
 use num::cast::AsPrimitive;
 use num::{BigInt, NumCast, ToPrimitive};
 
 /// CastOptions provides a way to override the default cast behaviors
-#[derive(Debug)]
+///
+/// New options may be added in future releases, and so rather than a struct literal
+/// listing every field, construct them from [`CastOptions::default`] or
+/// [`CastOptions::new`] followed by the `with_*` methods:
+///
+/// ```
+/// # use arrow::compute::kernels::cast::CastOptions;
+/// let options = CastOptions::new(false)
+///     .with_datetime_formats(vec!["%d/%m/%Y %H:%M".to_string()])
+///     .with_timezone(Some("+05:00".to_string()));
+/// assert!(!options.safe);
+/// ```
+///
+/// Note that since 25.0.0 a literal of the form `CastOptions { safe: false }` no longer
+/// compiles, and should be replaced by `CastOptions::new(false)`
+#[derive(Debug, Clone)]
 pub struct CastOptions {
     /// how to handle cast failures, either return NULL (safe=true) or return ERR (safe=false)
     pub safe: bool,
+    /// [chrono format strings](chrono::format::strftime) tried in order when casting strings
+    /// to timestamps, e.g. `%d/%m/%Y %H:%M`. If empty, only RFC3339-like strings are accepted
+    pub datetime_formats: Vec<String>,
+    /// The timezone in which strings without an explicit offset are interpreted when cast to
+    /// timestamps, either a fixed offset such as `+05:00`, or if the `chrono-tz` feature is
+    /// enabled, a name such as `America/New_York`. If `None`, the timezone of the target
+    /// type is used, and otherwise UTC
+    pub timezone: Option<String>,
+    /// how to format values when casting to Utf8 or LargeUtf8
+    pub format_options: FormatOptions,
+    /// how to round fractional digits beyond the scale when casting floating point values
+    /// and strings to decimals, [`RoundingMode::Truncate`] by default
+    pub rounding: RoundingMode,
+}
+
+pub const DEFAULT_CAST_OPTIONS: CastOptions = CastOptions {
+    safe: true,
+    datetime_formats: Vec::new(),
+    timezone: None,
+    format_options: FormatOptions::new(),
+    rounding: RoundingMode::Truncate,
+};
+
+impl Default for CastOptions {
+    fn default() -> Self {
+        DEFAULT_CAST_OPTIONS
+    }
+}
+
+impl CastOptions {
+    /// Creates the default options, returning nulls for cast failures if `safe` is
+    /// true and errors otherwise
+    pub fn new(safe: bool) -> Self {
+        Self {
+            safe,
+            ..Default::default()
+        }
+    }
+
+    /// Sets how to handle cast failures, see [`CastOptions::safe`]
+    pub fn with_safe(mut self, safe: bool) -> Self {
+        self.safe = safe;
+        self
+    }
+
+    /// Sets the format strings used to parse timestamps, see
+    /// [`CastOptions::datetime_formats`]
+    pub fn with_datetime_formats(mut self, datetime_formats: Vec<String>) -> Self {
+        self.datetime_formats = datetime_formats;
+        self
+    }
+
+    /// Sets the timezone of strings parsed as timestamps, see [`CastOptions::timezone`]
+    pub fn with_timezone(mut self, timezone: Option<String>) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Sets how to format values cast to strings, see [`CastOptions::format_options`]
+    pub fn with_format_options(mut self, format_options: FormatOptions) -> Self {
+        self.format_options = format_options;
+        self
+    }
+
+    /// Sets how to round values cast to decimals, see [`CastOptions::rounding`]
+    pub fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
 }
 
-pub const DEFAULT_CAST_OPTIONS: CastOptions = CastOptions { safe: true };
+/// The formats tried when casting strings to timestamps without
+/// [`CastOptions::datetime_formats`], accepting the same strings as
+/// [`string_to_timestamp_nanos`]
+const DEFAULT_DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%.f%#z",
+    "%Y-%m-%d %H:%M:%S%.f%#z",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+];
 
 /// Return true if a value of type `from_type` can be cast into a
 /// value of `to_type`. Note that such as cast may be lossy.
@@ -91,9 +187,15 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Decimal128(_, _), Decimal256(_, _)) => true,
         (Decimal256(_, _), Decimal128(_, _)) => true,
         // signed numeric to decimal
-        (Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64, Decimal128(_, _)) |
+        (
+            Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64 | Utf8 | LargeUtf8,
+            Decimal128(_, _) | Decimal256(_, _),
+        ) |
         // decimal to signed numeric
-        (Decimal128(_, _), Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64)
+        (
+            Decimal128(_, _) | Decimal256(_, _),
+            Null | Int8 | Int16 | Int32 | Int64 | Float32 | Float64 | Utf8 | LargeUtf8,
+        )
         | (
             Null,
             Boolean
@@ -126,8 +228,8 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Map(_, _)
             | Dictionary(_, _)
         ) => true,
-        (Decimal128(_, _), _) => false,
-        (_, Decimal128(_, _)) => false,
+        (Decimal128(_, _) | Decimal256(_, _), _) => false,
+        (_, Decimal128(_, _) | Decimal256(_, _)) => false,
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
         (LargeList(list_from), LargeList(list_to)) => {
@@ -137,10 +239,20 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             can_cast_types(list_from.data_type(), list_to.data_type())
         }
         (List(list_from), LargeList(list_to)) => {
-            list_from.data_type() == list_to.data_type()
+            can_cast_types(list_from.data_type(), list_to.data_type())
         }
         (LargeList(list_from), List(list_to)) => {
-            list_from.data_type() == list_to.data_type()
+            can_cast_types(list_from.data_type(), list_to.data_type())
+        }
+        (FixedSizeList(list_from, _), List(list_to) | LargeList(list_to)) => {
+            can_cast_types(list_from.data_type(), list_to.data_type())
+        }
+        (List(list_from) | LargeList(list_from), FixedSizeList(list_to, _)) => {
+            can_cast_types(list_from.data_type(), list_to.data_type())
+        }
+        (FixedSizeList(list_from, size_from), FixedSizeList(list_to, size_to)) => {
+            size_from == size_to
+                && can_cast_types(list_from.data_type(), list_to.data_type())
         }
         (List(list_from) | LargeList(list_from), Utf8 | LargeUtf8) => can_cast_types(list_from.data_type(), to_type),
         (List(_), _) => false,
@@ -165,7 +277,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Time32(TimeUnit::Millisecond)
             | Time64(TimeUnit::Microsecond)
             | Time64(TimeUnit::Nanosecond)
-            | Timestamp(TimeUnit::Nanosecond, None)
+            | Timestamp(_, _)
         ) => true,
         (Utf8, _) => DataType::is_numeric(to_type),
         (LargeUtf8,
@@ -176,7 +288,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Time32(TimeUnit::Millisecond)
             | Time64(TimeUnit::Microsecond)
             | Time64(TimeUnit::Nanosecond)
-            | Timestamp(TimeUnit::Nanosecond, None)
+            | Timestamp(_, _)
         ) => true,
         (LargeUtf8, _) => DataType::is_numeric(to_type),
         (Timestamp(_, _), Utf8) | (Timestamp(_, _), LargeUtf8) => true,
@@ -280,6 +392,8 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
                 IntervalUnit::MonthDayNano => false,
             }
         }
+        (Interval(IntervalUnit::DayTime | IntervalUnit::MonthDayNano), Duration(_)) => true,
+        (Duration(_), Interval(IntervalUnit::DayTime | IntervalUnit::MonthDayNano)) => true,
         (_, _) => false,
     }
 }
@@ -294,17 +408,22 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
-/// * List to List: the underlying data type is cast
+/// * Float and Utf8 to decimal: digits beyond the scale are rounded with
+///   [`CastOptions::rounding`], values exceeding the precision return null or error
+/// * List, LargeList and FixedSizeList to each other: the underlying data type is cast,
+///   lists cast to a FixedSizeList must have its size, otherwise they return null or error
 /// * Primitive to List: a list array with 1 value per slot is created
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * DayTime and MonthDayNano interval to/from duration: a day is 24 hours, intervals
+///   with a month component or values that overflow return null
 ///
 /// Unsupported Casts
 /// * To or from `StructArray`
 /// * List to primitive
-/// * Interval and duration
+/// * YearMonth interval to duration
 pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
     cast_with_options(array, to_type, &DEFAULT_CAST_OPTIONS)
 }
@@ -343,26 +462,302 @@ where
     cast_primitive_to_decimal(array, |v| v.as_() * mul, precision, scale)
 }
 
+/// Cast a floating point array to a Decimal128 array, rounding the fractional digits
+/// beyond `scale` with `cast_options.rounding`. NaN and infinite values, and those
+/// exceeding `precision`, are cast to null, or return an error if `cast_options.safe`
+/// is false.
 fn cast_floating_point_to_decimal<T: ArrowNumericType>(
     array: &PrimitiveArray<T>,
     precision: u8,
     scale: u8,
+    cast_options: &CastOptions,
 ) -> Result<Arc<dyn Array>>
 where
     <T as ArrowPrimitiveType>::Native: AsPrimitive<f64>,
 {
     let mul = 10_f64.powi(scale as i32);
 
-    cast_primitive_to_decimal(
-        array,
-        |v| {
-            // with_precision_and_scale validates the
-            // value is within range for the output precision
-            (v.as_() * mul) as i128
-        },
-        precision,
-        scale,
-    )
+    let decimal_array = array
+        .iter()
+        .map(|v| match v {
+            None => Ok(None),
+            Some(v) => match cast_options
+                .rounding
+                .round_f64(v.as_() * mul)
+                .to_i128()
+                .filter(|v| validate_decimal_precision(*v, precision).is_ok())
+            {
+                Some(v) => Ok(Some(v)),
+                None if cast_options.safe => Ok(None),
+                None => Err(ArrowError::CastError(format!(
+                    "Cannot cast {} to {}",
+                    v.as_(),
+                    DataType::Decimal128(precision, scale)
+                ))),
+            },
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .collect::<Decimal128Array>()
+        .with_precision_and_scale(precision, scale)?;
+
+    Ok(Arc::new(decimal_array))
+}
+
+/// Cast an integer array to a Decimal256 array. Values exceeding `precision` once scaled
+/// are cast to null, or return an error if `cast_options.safe` is false.
+fn cast_integer_to_decimal256<T: ArrowNumericType>(
+    array: &PrimitiveArray<T>,
+    precision: u8,
+    scale: u8,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef>
+where
+    T::Native: Into<BigInt> + std::fmt::Display,
+{
+    let mul = BigInt::from(10).pow(scale as u32);
+    let max = BigInt::from(10).pow(precision as u32);
+
+    // the values are checked against the precision before building the array, as
+    // values exceeding 256 bits can't be represented by it
+    let decimal_array = array
+        .iter()
+        .map(|v| match v {
+            None => Ok(None),
+            Some(v) => match v.into() * &mul {
+                scaled if scaled.magnitude() < max.magnitude() => Ok(Some(scaled)),
+                _ if cast_options.safe => Ok(None),
+                _ => Err(ArrowError::CastError(format!(
+                    "Cannot cast {} to {}",
+                    v,
+                    DataType::Decimal256(precision, scale)
+                ))),
+            },
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .collect::<Decimal256Array>()
+        .with_precision_and_scale(precision, scale)?;
+
+    Ok(Arc::new(decimal_array))
+}
+
+/// Cast a floating point array to a Decimal256 array, rounding the fractional digits
+/// beyond `scale` with `cast_options.rounding`. NaN and infinite values, and those
+/// exceeding `precision`, are cast to null, or return an error if `cast_options.safe`
+/// is false.
+fn cast_floating_point_to_decimal256<T: ArrowNumericType>(
+    array: &PrimitiveArray<T>,
+    precision: u8,
+    scale: u8,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef>
+where
+    <T as ArrowPrimitiveType>::Native: AsPrimitive<f64>,
+{
+    let mul = 10_f64.powi(scale as i32);
+    let max = BigInt::from(10).pow(precision as u32);
+
+    let decimal_array = array
+        .iter()
+        .map(|v| match v {
+            None => Ok(None),
+            Some(v) => match num::FromPrimitive::from_f64(
+                cast_options.rounding.round_f64(v.as_() * mul),
+            )
+            .filter(|v: &BigInt| v.magnitude() < max.magnitude())
+            {
+                Some(v) => Ok(Some(v)),
+                None if cast_options.safe => Ok(None),
+                None => Err(ArrowError::CastError(format!(
+                    "Cannot cast {} to {}",
+                    v.as_(),
+                    DataType::Decimal256(precision, scale)
+                ))),
+            },
+        })
+        .collect::<Result<Vec<Option<BigInt>>>>()?
+        .into_iter()
+        .collect::<Decimal256Array>()
+        .with_precision_and_scale(precision, scale)?;
+
+    Ok(Arc::new(decimal_array))
+}
+
+/// Cast a Decimal256 array to an integer array, truncating its fractional digits.
+/// Values out of range of the integer type are cast to null, or return an error
+/// if `cast_options.safe` is false.
+fn cast_decimal256_to_integer<T>(
+    array: &ArrayRef,
+    scale: u8,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef>
+where
+    T: ArrowPrimitiveType,
+    T::Native: NumCast,
+{
+    let array = array.as_any().downcast_ref::<Decimal256Array>().unwrap();
+    let div = BigInt::from(10).pow(scale as u32);
+
+    let integer_array = array
+        .iter()
+        .map(|v| match v {
+            None => Ok(None),
+            Some(v) => {
+                let v = v.to_big_int() / &div;
+                match <T::Native as NumCast>::from(v.clone()) {
+                    Some(v) => Ok(Some(v)),
+                    None if cast_options.safe => Ok(None),
+                    None => Err(ArrowError::CastError(format!(
+                        "value of {} is out of range {}",
+                        v,
+                        T::DATA_TYPE
+                    ))),
+                }
+            }
+        })
+        .collect::<Result<PrimitiveArray<T>>>()?;
+
+    Ok(Arc::new(integer_array))
+}
+
+/// Cast a Decimal128 or Decimal256 array to Utf8/LargeUtf8, see
+/// [`FormatOptions::with_decimal_separator`]
+fn cast_decimal_to_string<OffsetSize: OffsetSizeTrait>(
+    array: &ArrayRef,
+    options: &FormatOptions,
+) -> Result<ArrayRef> {
+    let separator = options.decimal_separator().map(|c| c.to_string());
+    let format = |value: String| match &separator {
+        Some(separator) => value.replace('.', separator),
+        None => value,
+    };
+
+    let string_array: GenericStringArray<OffsetSize> = match array.data_type() {
+        DataType::Decimal128(_, _) => {
+            let array = array.as_any().downcast_ref::<Decimal128Array>().unwrap();
+            array
+                .iter()
+                .map(|v| v.map(|v| format(v.to_string())))
+                .collect()
+        }
+        DataType::Decimal256(_, _) => {
+            let array = array.as_any().downcast_ref::<Decimal256Array>().unwrap();
+            array
+                .iter()
+                .map(|v| v.map(|v| format(v.to_string())))
+                .collect()
+        }
+        // implementation error
+        _ => unreachable!(),
+    };
+    Ok(Arc::new(string_array))
+}
+
+/// Cast a Utf8/LargeUtf8 array to a Decimal128 or Decimal256 array, rounding the
+/// fractional digits beyond `scale` with `cast_options.rounding`. Strings that are not
+/// decimal numbers, or exceed `precision`, are cast to null, or return an error if
+/// `cast_options.safe` is false.
+fn cast_string_to_decimal<Offset: OffsetSizeTrait>(
+    array: &ArrayRef,
+    precision: u8,
+    scale: u8,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef> {
+    let string_array = array
+        .as_any()
+        .downcast_ref::<GenericStringArray<Offset>>()
+        .unwrap();
+    let max = BigInt::from(10).pow(precision as u32);
+
+    let values = string_array
+        .iter()
+        .map(|v| match v {
+            None => Ok(None),
+            Some(v) => match parse_decimal(v, scale, cast_options.rounding)
+                .filter(|v| v.magnitude() < max.magnitude())
+            {
+                Some(v) => Ok(Some(v)),
+                None if cast_options.safe => Ok(None),
+                None => Err(ArrowError::CastError(format!(
+                    "Cannot cast string '{}' to value of {:?} type",
+                    v, to_type
+                ))),
+            },
+        })
+        .collect::<Result<Vec<Option<BigInt>>>>()?;
+
+    Ok(match to_type {
+        DataType::Decimal128(_, _) => Arc::new(
+            values
+                .into_iter()
+                // values within the precision of a Decimal128 fit in an i128
+                .map(|v| v.map(|v| v.to_i128().unwrap()))
+                .collect::<Decimal128Array>()
+                .with_precision_and_scale(precision, scale)?,
+        ),
+        _ => Arc::new(
+            values
+                .into_iter()
+                .collect::<Decimal256Array>()
+                .with_precision_and_scale(precision, scale)?,
+        ),
+    })
+}
+
+/// Parses a decimal number such as `-12.345` to its value with `scale` fractional
+/// digits, rounding any further digits with `rounding`
+fn parse_decimal(s: &str, scale: u8, rounding: RoundingMode) -> Option<BigInt> {
+    let s = s.trim();
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (integer, fraction) = s.split_once('.').unwrap_or((s, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if integer.len() + fraction.len() == 0 || !is_digits(integer) || !is_digits(fraction)
+    {
+        return None;
+    }
+    // Values such as `.5` have no integer digits
+    let integer = if integer.is_empty() { "0" } else { integer };
+
+    let scale = scale as usize;
+    let (kept, discarded) = fraction.split_at(fraction.len().min(scale));
+    let mut digits = String::with_capacity(integer.len() + scale);
+    digits.push_str(integer);
+    digits.push_str(kept);
+    digits.extend(std::iter::repeat('0').take(scale - kept.len()));
+
+    let mut value = BigInt::parse_bytes(digits.as_bytes(), 10)?;
+    let odd = digits.bytes().last().map_or(false, |d| (d - b'0') % 2 == 1);
+    if rounding.round_away(negative, odd, discarded.as_bytes()) {
+        value += 1;
+    }
+    Some(if negative { -value } else { value })
+}
+
+/// Cast a Decimal256 array to a floating point array, which may lose precision
+fn cast_decimal256_to_float<T>(array: &ArrayRef, scale: u8) -> Result<ArrayRef>
+where
+    T: ArrowPrimitiveType,
+    T::Native: NumCast,
+{
+    let array = array.as_any().downcast_ref::<Decimal256Array>().unwrap();
+    let div = 10_f64.powi(scale as i32);
+
+    let float_array = array
+        .iter()
+        .map(|v| {
+            v.and_then(|v| {
+                let v = v.to_big_int().to_f64()? / div;
+                <T::Native as NumCast>::from(v)
+            })
+        })
+        .collect::<PrimitiveArray<T>>();
+
+    Ok(Arc::new(float_array))
 }
 
 // cast the decimal array to integer array
@@ -419,11 +814,16 @@ macro_rules! cast_decimal_to_float {
 macro_rules! cast_list_to_string {
     ($ARRAY:expr, $SIZE:ident) => {{
         let mut value_builder: GenericStringBuilder<$SIZE> = GenericStringBuilder::new();
+        let options = FormatOptions::new();
+        let formatter = ArrayFormatter::try_new($ARRAY.as_ref(), &options)?;
+        let mut value = String::new();
         for i in 0..$ARRAY.len() {
             if $ARRAY.is_null(i) {
                 value_builder.append_null();
             } else {
-                value_builder.append_value(array_value_to_string($ARRAY, i)?);
+                value.clear();
+                formatter.value(i).write(&mut value)?;
+                value_builder.append_value(&value);
             }
         }
         Ok(Arc::new(value_builder.finish()))
@@ -441,12 +841,19 @@ macro_rules! cast_list_to_string {
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
-/// * List to List: the underlying data type is cast
+/// * Float and Utf8 to decimal: digits beyond the scale are rounded with
+///   [`CastOptions::rounding`], values exceeding the precision return null or error
+/// * List, LargeList and FixedSizeList to each other: the underlying data type is cast,
+///   lists cast to a FixedSizeList must have its size, otherwise they return null or error
 /// * Primitive to List: a list array with 1 value per slot is created
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * To Utf8 and LargeUtf8: floating point and decimal values, timestamps and nulls are
+///   formatted according to [`CastOptions::format_options`]. Nulls are only replaced if
+///   [`FormatOptions::with_null`] is set, in which case they are replaced in casts from
+///   any type, including from the same type
 ///
 /// Unsupported Casts
 /// * To or from `StructArray`
@@ -455,6 +862,27 @@ pub fn cast_with_options(
     array: &ArrayRef,
     to_type: &DataType,
     cast_options: &CastOptions,
+) -> Result<ArrayRef> {
+    // Nulls are replaced in casts from any type, including the same string type
+    let null = cast_options.format_options.cast_null();
+    match (to_type, null) {
+        (DataType::Utf8, Some(null)) => Ok(fill_string_nulls::<i32>(
+            &cast_values(array, to_type, cast_options)?,
+            null,
+        )),
+        (DataType::LargeUtf8, Some(null)) => Ok(fill_string_nulls::<i64>(
+            &cast_values(array, to_type, cast_options)?,
+            null,
+        )),
+        _ => cast_values(array, to_type, cast_options),
+    }
+}
+
+/// Casts `array` to `to_type`, see [`cast_with_options`]
+fn cast_values(
+    array: &ArrayRef,
+    to_type: &DataType,
+    cast_options: &CastOptions,
 ) -> Result<ArrayRef> {
     use DataType::*;
     let from_type = array.data_type();
@@ -497,6 +925,42 @@ pub fn cast_with_options(
                 Float64 => {
                     cast_decimal_to_float!(array, scale, Float64Builder, f64)
                 }
+                Utf8 => {
+                    cast_decimal_to_string::<i32>(array, &cast_options.format_options)
+                }
+                LargeUtf8 => {
+                    cast_decimal_to_string::<i64>(array, &cast_options.format_options)
+                }
+                Null => Ok(new_null_array(to_type, array.len())),
+                _ => Err(ArrowError::CastError(format!(
+                    "Casting from {:?} to {:?} not supported",
+                    from_type, to_type
+                ))),
+            }
+        }
+        (Decimal256(_, scale), _) => {
+            // cast decimal to other type
+            match to_type {
+                Int8 => {
+                    cast_decimal256_to_integer::<Int8Type>(array, *scale, cast_options)
+                }
+                Int16 => {
+                    cast_decimal256_to_integer::<Int16Type>(array, *scale, cast_options)
+                }
+                Int32 => {
+                    cast_decimal256_to_integer::<Int32Type>(array, *scale, cast_options)
+                }
+                Int64 => {
+                    cast_decimal256_to_integer::<Int64Type>(array, *scale, cast_options)
+                }
+                Float32 => cast_decimal256_to_float::<Float32Type>(array, *scale),
+                Float64 => cast_decimal256_to_float::<Float64Type>(array, *scale),
+                Utf8 => {
+                    cast_decimal_to_string::<i32>(array, &cast_options.format_options)
+                }
+                LargeUtf8 => {
+                    cast_decimal_to_string::<i64>(array, &cast_options.format_options)
+                }
                 Null => Ok(new_null_array(to_type, array.len())),
                 _ => Err(ArrowError::CastError(format!(
                     "Casting from {:?} to {:?} not supported",
@@ -532,11 +996,87 @@ pub fn cast_with_options(
                     as_primitive_array::<Float32Type>(array),
                     *precision,
                     *scale,
+                    cast_options,
                 ),
                 Float64 => cast_floating_point_to_decimal(
                     as_primitive_array::<Float64Type>(array),
                     *precision,
                     *scale,
+                    cast_options,
+                ),
+                Utf8 => cast_string_to_decimal::<i32>(
+                    array,
+                    *precision,
+                    *scale,
+                    to_type,
+                    cast_options,
+                ),
+                LargeUtf8 => cast_string_to_decimal::<i64>(
+                    array,
+                    *precision,
+                    *scale,
+                    to_type,
+                    cast_options,
+                ),
+                Null => Ok(new_null_array(to_type, array.len())),
+                _ => Err(ArrowError::CastError(format!(
+                    "Casting from {:?} to {:?} not supported",
+                    from_type, to_type
+                ))),
+            }
+        }
+        (_, Decimal256(precision, scale)) => {
+            // cast data to decimal
+            match from_type {
+                Int8 => cast_integer_to_decimal256(
+                    as_primitive_array::<Int8Type>(array),
+                    *precision,
+                    *scale,
+                    cast_options,
+                ),
+                Int16 => cast_integer_to_decimal256(
+                    as_primitive_array::<Int16Type>(array),
+                    *precision,
+                    *scale,
+                    cast_options,
+                ),
+                Int32 => cast_integer_to_decimal256(
+                    as_primitive_array::<Int32Type>(array),
+                    *precision,
+                    *scale,
+                    cast_options,
+                ),
+                Int64 => cast_integer_to_decimal256(
+                    as_primitive_array::<Int64Type>(array),
+                    *precision,
+                    *scale,
+                    cast_options,
+                ),
+                Float32 => cast_floating_point_to_decimal256(
+                    as_primitive_array::<Float32Type>(array),
+                    *precision,
+                    *scale,
+                    cast_options,
+                ),
+                Float64 => cast_floating_point_to_decimal256(
+                    as_primitive_array::<Float64Type>(array),
+                    *precision,
+                    *scale,
+                    cast_options,
+                ),
+                Utf8 => cast_string_to_decimal::<i32>(
+                    array,
+                    *precision,
+                    *scale,
+                    to_type,
+                    cast_options,
+                ),
+                LargeUtf8 => cast_string_to_decimal::<i64>(
+                    array,
+                    *precision,
+                    *scale,
+                    to_type,
+                    cast_options,
                 ),
                 Null => Ok(new_null_array(to_type, array.len())),
                 _ => Err(ArrowError::CastError(format!(
@@ -589,23 +1129,34 @@ pub fn cast_with_options(
         (LargeList(_), LargeList(ref to)) => {
             cast_list_inner::<i64>(array, to, to_type, cast_options)
         }
-        (List(list_from), LargeList(list_to)) => {
-            if list_to.data_type() != list_from.data_type() {
-                Err(ArrowError::CastError(
-                    "cannot cast list to large-list with different child data".into(),
-                ))
-            } else {
-                cast_list_container::<i32, i64>(&**array, cast_options)
-            }
+        (List(_), LargeList(ref to)) => {
+            let array = cast_list_container::<i32, i64>(&**array, cast_options)?;
+            cast_list_inner::<i64>(&array, to, to_type, cast_options)
         }
-        (LargeList(list_from), List(list_to)) => {
-            if list_to.data_type() != list_from.data_type() {
-                Err(ArrowError::CastError(
-                    "cannot cast large-list to list with different child data".into(),
-                ))
-            } else {
-                cast_list_container::<i64, i32>(&**array, cast_options)
+        (LargeList(_), List(ref to)) => {
+            let array = cast_list_container::<i64, i32>(&**array, cast_options)?;
+            cast_list_inner::<i32>(&array, to, to_type, cast_options)
+        }
+        (FixedSizeList(_, size), List(ref to)) => {
+            cast_fixed_size_list_to_list::<i32>(array, *size, to, to_type, cast_options)
+        }
+        (FixedSizeList(_, size), LargeList(ref to)) => {
+            cast_fixed_size_list_to_list::<i64>(array, *size, to, to_type, cast_options)
+        }
+        (List(_), FixedSizeList(ref to, size)) => {
+            cast_list_to_fixed_size_list::<i32>(array, to, *size, to_type, cast_options)
+        }
+        (LargeList(_), FixedSizeList(ref to, size)) => {
+            cast_list_to_fixed_size_list::<i64>(array, to, *size, to_type, cast_options)
+        }
+        (FixedSizeList(_, size_from), FixedSizeList(ref to, size_to)) => {
+            if size_from != size_to {
+                return Err(ArrowError::CastError(format!(
+                    "Cannot cast {:?} to {:?} with a different size",
+                    from_type, to_type
+                )));
             }
+            cast_fixed_size_list_inner(array, to, to_type, cast_options)
         }
         (List(_) | LargeList(_), Utf8) => cast_list_to_string!(array, i32),
         (List(_) | LargeList(_), LargeUtf8) => cast_list_to_string!(array, i64),
@@ -737,8 +1288,8 @@ pub fn cast_with_options(
             Time64(TimeUnit::Nanosecond) => {
                 cast_string_to_time64nanosecond::<i32>(&**array, cast_options)
             }
-            Timestamp(TimeUnit::Nanosecond, None) => {
-                cast_string_to_timestamp_ns::<i32>(&**array, cast_options)
+            Timestamp(unit, tz) => {
+                cast_string_to_timestamp::<i32>(&**array, unit, tz, cast_options)
             }
             _ => Err(ArrowError::CastError(format!(
                 "Casting from {:?} to {:?} not supported",
@@ -755,8 +1306,22 @@ pub fn cast_with_options(
             Int16 => cast_numeric_to_string::<Int16Type, i32>(array),
             Int32 => cast_numeric_to_string::<Int32Type, i32>(array),
             Int64 => cast_numeric_to_string::<Int64Type, i32>(array),
-            Float32 => cast_numeric_to_string::<Float32Type, i32>(array),
-            Float64 => cast_numeric_to_string::<Float64Type, i32>(array),
+            Float32 => cast_float_to_string::<Float32Type, i32>(
+                array,
+                &cast_options.format_options,
+            ),
+            Float64 => cast_float_to_string::<Float64Type, i32>(
+                array,
+                &cast_options.format_options,
+            ),
+            Timestamp(_, _)
+                if cast_options.format_options.datetime_format().is_some() =>
+            {
+                cast_timestamp_to_formatted_string::<i32>(
+                    array,
+                    cast_options.format_options.datetime_format().unwrap(),
+                )
+            }
             Timestamp(unit, tz) => match unit {
                 TimeUnit::Nanosecond => {
                     cast_timestamp_to_string::<TimestampNanosecondType, i32>(array, tz)
@@ -811,8 +1376,22 @@ pub fn cast_with_options(
             Int16 => cast_numeric_to_string::<Int16Type, i64>(array),
             Int32 => cast_numeric_to_string::<Int32Type, i64>(array),
             Int64 => cast_numeric_to_string::<Int64Type, i64>(array),
-            Float32 => cast_numeric_to_string::<Float32Type, i64>(array),
-            Float64 => cast_numeric_to_string::<Float64Type, i64>(array),
+            Float32 => cast_float_to_string::<Float32Type, i64>(
+                array,
+                &cast_options.format_options,
+            ),
+            Float64 => cast_float_to_string::<Float64Type, i64>(
+                array,
+                &cast_options.format_options,
+            ),
+            Timestamp(_, _)
+                if cast_options.format_options.datetime_format().is_some() =>
+            {
+                cast_timestamp_to_formatted_string::<i64>(
+                    array,
+                    cast_options.format_options.datetime_format().unwrap(),
+                )
+            }
             Timestamp(unit, tz) => match unit {
                 TimeUnit::Nanosecond => {
                     cast_timestamp_to_string::<TimestampNanosecondType, i64>(array, tz)
@@ -884,8 +1463,8 @@ pub fn cast_with_options(
             Time64(TimeUnit::Nanosecond) => {
                 cast_string_to_time64nanosecond::<i64>(&**array, cast_options)
             }
-            Timestamp(TimeUnit::Nanosecond, None) => {
-                cast_string_to_timestamp_ns::<i64>(&**array, cast_options)
+            Timestamp(unit, tz) => {
+                cast_string_to_timestamp::<i64>(&**array, unit, tz, cast_options)
             }
             _ => Err(ArrowError::CastError(format!(
                 "Casting from {:?} to {:?} not supported",
@@ -1445,6 +2024,14 @@ pub fn cast_with_options(
                 from_type, to_type,
             ))),
         },
+        (
+            Interval(IntervalUnit::DayTime | IntervalUnit::MonthDayNano),
+            Duration(unit),
+        ) => cast_interval_to_duration(array, unit, to_type, cast_options),
+        (
+            Duration(unit),
+            Interval(IntervalUnit::DayTime | IntervalUnit::MonthDayNano),
+        ) => cast_duration_to_interval(array, unit, to_type, cast_options),
         (_, _) => Err(ArrowError::CastError(format!(
             "Casting from {:?} to {:?} not supported",
             from_type, to_type,
@@ -1487,16 +2074,135 @@ fn cast_string_to_binary(array: &ArrayRef) -> Result<ArrayRef> {
     }
 }
 
-/// Get the time unit as a multiple of a second
-const fn time_unit_multiple(unit: &TimeUnit) -> i64 {
-    match unit {
-        TimeUnit::Second => 1,
-        TimeUnit::Millisecond => MILLISECONDS,
-        TimeUnit::Microsecond => MICROSECONDS,
-        TimeUnit::Nanosecond => NANOSECONDS,
+/// Cast DayTime and MonthDayNano intervals to durations of `unit`, with a day as 24 hours.
+/// Intervals with a month component, or that overflow, are null if `cast_options.safe`,
+/// and otherwise return an error
+fn cast_interval_to_duration(
+    array: &ArrayRef,
+    unit: &TimeUnit,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef> {
+    let nanos_per_day = (SECONDS_IN_DAY * NANOSECONDS) as i128;
+    let nanos_per_unit = (NANOSECONDS / time_unit_multiple(unit)) as i128;
+    let to_unit = |nanos: i128| i64::try_from(nanos / nanos_per_unit).ok();
+
+    let values: Int64Array = match array.data_type() {
+        DataType::Interval(IntervalUnit::DayTime) => cast_checked(
+            array,
+            as_primitive_array::<IntervalDayTimeType>(array),
+            to_type,
+            cast_options,
+            |v| {
+                let (days, millis) = IntervalDayTimeType::to_parts(v);
+                to_unit(days as i128 * nanos_per_day + millis as i128 * 1_000_000)
+            },
+        )?,
+        DataType::Interval(IntervalUnit::MonthDayNano) => cast_checked(
+            array,
+            as_primitive_array::<IntervalMonthDayNanoType>(array),
+            to_type,
+            cast_options,
+            |v| match IntervalMonthDayNanoType::to_parts(v) {
+                (0, days, nanos) => to_unit(days as i128 * nanos_per_day + nanos as i128),
+                _ => None,
+            },
+        )?,
+        // implementation error
+        _ => unreachable!(),
+    };
+    // SAFETY
+    // durations of any unit are backed by i64
+    let data = unsafe {
+        ArrayData::from(values)
+            .into_builder()
+            .data_type(to_type.clone())
+            .build_unchecked()
+    };
+    Ok(make_array(data))
+}
+
+/// Cast durations of `unit` to DayTime or MonthDayNano intervals without a month
+/// component, with a day as 24 hours. Durations that overflow are null if
+/// `cast_options.safe`, and otherwise return an error
+fn cast_duration_to_interval(
+    array: &ArrayRef,
+    unit: &TimeUnit,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef> {
+    let millis_per_day = (SECONDS_IN_DAY * MILLISECONDS) as i128;
+    let nanos_per_unit = (NANOSECONDS / time_unit_multiple(unit)) as i128;
+    // durations of any unit are backed by i64
+    let values = Int64Array::from(array.data().clone());
+
+    match to_type {
+        DataType::Interval(IntervalUnit::DayTime) => {
+            let intervals = cast_checked::<_, IntervalDayTimeType, _>(
+                array,
+                &values,
+                to_type,
+                cast_options,
+                |v| {
+                    let millis = v as i128 * nanos_per_unit / 1_000_000;
+                    let days = i32::try_from(millis / millis_per_day).ok()?;
+                    let millis = (millis % millis_per_day) as i32;
+                    Some(IntervalDayTimeType::make_value(days, millis))
+                },
+            )?;
+            Ok(Arc::new(intervals))
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            let intervals = cast_checked::<_, IntervalMonthDayNanoType, _>(
+                array,
+                &values,
+                to_type,
+                cast_options,
+                |v| {
+                    let nanos = i64::try_from(v as i128 * nanos_per_unit).ok()?;
+                    Some(IntervalMonthDayNanoType::make_value(0, 0, nanos))
+                },
+            )?;
+            Ok(Arc::new(intervals))
+        }
+        // implementation error
+        _ => unreachable!(),
     }
 }
 
+/// Applies `op` to the valid `values` of `array`, values for which it returns `None`
+/// are null if `cast_options.safe`, and otherwise return an error
+fn cast_checked<I, O, F>(
+    array: &ArrayRef,
+    values: &PrimitiveArray<I>,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+    op: F,
+) -> Result<PrimitiveArray<O>>
+where
+    I: ArrowPrimitiveType,
+    O: ArrowPrimitiveType,
+    F: Fn(I::Native) -> Option<O::Native>,
+{
+    (0..values.len())
+        .map(|i| {
+            if values.is_null(i) {
+                return Ok(None);
+            }
+            match op(values.value(i)) {
+                Some(v) => Ok(Some(v)),
+                None if cast_options.safe => Ok(None),
+                None => Err(ArrowError::CastError(format!(
+                    "Cannot cast value '{}' of {:?} to {:?}",
+                    array_value_to_string(array, i)?,
+                    array.data_type(),
+                    to_type
+                ))),
+            }
+        })
+        .collect()
+}
+
 /// Cast one type of decimal array to another type of decimal array
 fn cast_decimal_to_decimal<const BYTE_WIDTH1: usize, const BYTE_WIDTH2: usize>(
     array: &ArrayRef,
@@ -1755,6 +2461,19 @@ where
     Ok(Arc::new(builder.finish()) as ArrayRef)
 }
 
+/// Cast timestamp types to Utf8/LargeUtf8 with a chrono format string, see
+/// [`FormatOptions::with_datetime_format`]
+fn cast_timestamp_to_formatted_string<OffsetSize: OffsetSizeTrait>(
+    array: &ArrayRef,
+    format: &str,
+) -> Result<ArrayRef> {
+    let formatted = strftime(array.as_ref(), format, None)?;
+    match OffsetSize::IS_LARGE {
+        true => cast_str_container::<i32, i64>(&formatted),
+        false => Ok(Arc::new(formatted)),
+    }
+}
+
 /// Cast date32 types to Utf8/LargeUtf8
 fn cast_date32_to_string<OffsetSize: OffsetSizeTrait>(
     array: &ArrayRef,
@@ -1808,6 +2527,70 @@ where
     )))
 }
 
+/// Cast floating point types to Utf8/LargeUtf8, see [`FormatOptions`]
+fn cast_float_to_string<FROM, OffsetSize>(
+    array: &ArrayRef,
+    options: &FormatOptions,
+) -> Result<ArrayRef>
+where
+    FROM: ArrowNumericType,
+    FROM::Native: lexical_core::ToLexical + num::Float + std::fmt::Display,
+    OffsetSize: OffsetSizeTrait,
+{
+    if options.decimal_separator().is_none() && !options.suppress_scientific() {
+        return cast_numeric_to_string::<FROM, OffsetSize>(array);
+    }
+    let separator = options.decimal_separator().map(|c| c.to_string());
+
+    let format = |value: FROM::Native| {
+        let formatted =
+            match options.suppress_scientific() && num::Float::is_finite(value) {
+                // `Display` never uses scientific notation, but omits the fraction of
+                // integral values unlike `lexical_to_string`
+                true => match value.to_string() {
+                    s if s.contains('.') => s,
+                    s => s + ".0",
+                },
+                false => lexical_to_string(value),
+            };
+        match &separator {
+            Some(separator) => formatted.replace('.', separator),
+            None => formatted,
+        }
+    };
+
+    let array = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<FROM>>()
+        .unwrap();
+    Ok(Arc::new(
+        array
+            .iter()
+            .map(|v| v.map(format))
+            .collect::<GenericStringArray<OffsetSize>>(),
+    ))
+}
+
+/// Replaces the nulls of a Utf8/LargeUtf8 array with `null`, see [`FormatOptions::with_null`]
+fn fill_string_nulls<OffsetSize: OffsetSizeTrait>(
+    array: &ArrayRef,
+    null: &str,
+) -> ArrayRef {
+    if array.null_count() == 0 {
+        return array.clone();
+    }
+    let array = array
+        .as_any()
+        .downcast_ref::<GenericStringArray<OffsetSize>>()
+        .unwrap();
+    Arc::new(
+        array
+            .iter()
+            .map(|v| Some(v.unwrap_or(null)))
+            .collect::<GenericStringArray<OffsetSize>>(),
+    )
+}
+
 fn numeric_to_string_cast<T, OffsetSize>(
     from: &PrimitiveArray<T>,
 ) -> GenericStringArray<OffsetSize>
@@ -2243,6 +3026,86 @@ fn cast_string_to_time64nanosecond<Offset: OffsetSizeTrait>(
 }
 
 /// Casts generic string arrays to TimeStampNanosecondArray
+/// Casts generic string arrays to a timestamp of `unit` with the timezone `tz`, see
+/// [`CastOptions::datetime_formats`] and [`CastOptions::timezone`]
+fn cast_string_to_timestamp<Offset: OffsetSizeTrait>(
+    array: &dyn Array,
+    unit: &TimeUnit,
+    tz: &Option<String>,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef> {
+    let local_tz = cast_options.timezone.as_deref().or(tz.as_deref());
+    if cast_options.datetime_formats.is_empty()
+        && local_tz.is_none()
+        && unit == &TimeUnit::Nanosecond
+    {
+        return cast_string_to_timestamp_ns::<Offset>(array, cast_options);
+    }
+
+    let string_array = array
+        .as_any()
+        .downcast_ref::<GenericStringArray<Offset>>()
+        .unwrap();
+    let formats: Vec<&str> = match cast_options.datetime_formats.is_empty() {
+        true => DEFAULT_DATETIME_FORMATS.to_vec(),
+        false => cast_options
+            .datetime_formats
+            .iter()
+            .map(|f| f.as_str())
+            .collect(),
+    };
+
+    match unit {
+        TimeUnit::Second => parse_string_timestamps::<TimestampSecondType, Offset>(
+            string_array,
+            &formats,
+            local_tz,
+            tz,
+            cast_options,
+        ),
+        TimeUnit::Millisecond => parse_string_timestamps::<
+            TimestampMillisecondType,
+            Offset,
+        >(
+            string_array, &formats, local_tz, tz, cast_options
+        ),
+        TimeUnit::Microsecond => parse_string_timestamps::<
+            TimestampMicrosecondType,
+            Offset,
+        >(
+            string_array, &formats, local_tz, tz, cast_options
+        ),
+        TimeUnit::Nanosecond => {
+            parse_string_timestamps::<TimestampNanosecondType, Offset>(
+                string_array,
+                &formats,
+                local_tz,
+                tz,
+                cast_options,
+            )
+        }
+    }
+}
+
+/// Parses `array` with `formats` in `local_tz`, returning UTC timestamps with the timezone `tz`
+fn parse_string_timestamps<T, Offset>(
+    array: &GenericStringArray<Offset>,
+    formats: &[&str],
+    local_tz: Option<&str>,
+    tz: &Option<String>,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef>
+where
+    T: ArrowTimestampType<Native = i64>,
+    Offset: OffsetSizeTrait,
+{
+    let array = parse_timestamps::<T, Offset>(array, formats, local_tz, cast_options)?;
+    Ok(match tz {
+        Some(tz) => Arc::new(array.with_timezone(tz.clone())),
+        None => Arc::new(array),
+    })
+}
+
 fn cast_string_to_timestamp_ns<Offset: OffsetSizeTrait>(
     array: &dyn Array,
     cast_options: &CastOptions,
@@ -2685,6 +3548,136 @@ fn cast_list_inner<OffsetSize: OffsetSizeTrait>(
     Ok(Arc::new(list) as ArrayRef)
 }
 
+/// Helper function that casts a FixedSizeList of `size` to a List or LargeList, casting
+/// the inner datatype
+fn cast_fixed_size_list_to_list<OffsetSize: OffsetSizeTrait>(
+    array: &ArrayRef,
+    size: i32,
+    to: &Field,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef> {
+    let data = array.data_ref();
+    let size = size as usize;
+    // the values of a FixedSizeList are not sliced, so the offsets start at its offset
+    let end = (data.offset() + data.len()) * size;
+    if OffsetSize::from_usize(end).is_none() {
+        return Err(ArrowError::ComputeError(format!(
+            "FixedSizeList too large to cast to {:?}",
+            to_type
+        )));
+    }
+    let offsets: Buffer = (data.offset()..=data.offset() + data.len())
+        .map(|i| OffsetSize::from_usize(i * size).unwrap())
+        .collect();
+
+    let underlying_array = make_array(data.child_data()[0].clone());
+    let cast_array = cast_with_options(&underlying_array, to.data_type(), cast_options)?;
+    let array_data = unsafe {
+        ArrayData::new_unchecked(
+            to_type.clone(),
+            data.len(),
+            Some(data.null_count()),
+            data.null_buffer()
+                .map(|b| b.bit_slice(data.offset(), data.len())),
+            0,
+            vec![offsets],
+            vec![cast_array.into_data()],
+        )
+    };
+    Ok(make_array(array_data))
+}
+
+/// Helper function that casts a List or LargeList to a FixedSizeList of `size`, casting
+/// the inner datatype. Lists of a different length are null if `cast_options.safe`,
+/// and otherwise return an error
+fn cast_list_to_fixed_size_list<OffsetSize: OffsetSizeTrait>(
+    array: &ArrayRef,
+    to: &Field,
+    size: i32,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef> {
+    let list = array
+        .as_any()
+        .downcast_ref::<GenericListArray<OffsetSize>>()
+        .unwrap();
+    let size = size as usize;
+    let offsets = list.value_offsets();
+    let uniform = offsets.windows(2).all(|w| (w[1] - w[0]).as_usize() == size);
+
+    let (values, null_buffer) = if uniform {
+        // the values of each list are contiguous, and so can be sliced
+        let values = list
+            .values()
+            .slice(offsets[0].as_usize(), list.len() * size);
+        let null_buffer = list
+            .data_ref()
+            .null_buffer()
+            .map(|b| b.bit_slice(list.offset(), list.len()));
+        (values, null_buffer)
+    } else {
+        let values_data = list.values().data().clone();
+        let mut mutable =
+            MutableArrayData::new(vec![&values_data], true, list.len() * size);
+        let mut null_builder = BooleanBufferBuilder::new(list.len());
+        for (i, w) in offsets.windows(2).enumerate() {
+            let (start, end) = (w[0].as_usize(), w[1].as_usize());
+            match list.is_valid(i) && end - start == size {
+                true => mutable.extend(0, start, end),
+                false if list.is_valid(i) && !cast_options.safe => {
+                    return Err(ArrowError::CastError(format!(
+                        "Cannot cast list of length {} to {:?}",
+                        end - start,
+                        to_type
+                    )))
+                }
+                false => mutable.extend_nulls(size),
+            }
+            null_builder.append(list.is_valid(i) && end - start == size);
+        }
+        (make_array(mutable.freeze()), Some(null_builder.finish()))
+    };
+
+    let cast_array = cast_with_options(&values, to.data_type(), cast_options)?;
+    let array_data = unsafe {
+        ArrayData::new_unchecked(
+            to_type.clone(),
+            list.len(),
+            None,
+            null_buffer,
+            0,
+            vec![],
+            vec![cast_array.into_data()],
+        )
+    };
+    Ok(make_array(array_data))
+}
+
+/// Helper function that takes a FixedSizeList and casts the inner datatype.
+fn cast_fixed_size_list_inner(
+    array: &ArrayRef,
+    to: &Field,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef> {
+    let data = array.data_ref();
+    let underlying_array = make_array(data.child_data()[0].clone());
+    let cast_array = cast_with_options(&underlying_array, to.data_type(), cast_options)?;
+    let array_data = unsafe {
+        ArrayData::new_unchecked(
+            to_type.clone(),
+            array.len(),
+            Some(data.null_count()),
+            data.null_buffer().cloned(),
+            array.offset(),
+            vec![],
+            vec![cast_array.into_data()],
+        )
+    };
+    Ok(make_array(array_data))
+}
+
 /// Helper function to cast from `Utf8` to `LargeUtf8` and vice versa. If the `LargeUtf8` is too large for
 /// a `Utf8` array it will return an Error.
 fn cast_str_container<OffsetSizeFrom, OffsetSizeTo>(array: &dyn Array) -> Result<ArrayRef>
@@ -3088,18 +4081,156 @@ mod tests {
         let array = Arc::new(decimal_array) as ArrayRef;
         generate_cast_test_case!(
             &array,
-            Float64Array,
-            &DataType::Float64,
+            Float64Array,
+            &DataType::Float64,
+            vec![
+                Some(1.25_f64),
+                Some(2.25_f64),
+                Some(3.25_f64),
+                None,
+                Some(5.25_f64),
+                Some(1_123_456_789_012_345.6_f64),
+                Some(1_123_456_789_012_345.6_f64),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cast_decimal256_to_numeric() {
+        let decimal_type = DataType::Decimal256(76, 2);
+        assert!(!can_cast_types(&decimal_type, &DataType::UInt8));
+        let value_array = vec![Some(125), Some(-225), Some(325), None, Some(525)];
+        let value_array = value_array.into_iter().map(|v| v.map(BigInt::from));
+        let decimal_array = create_decimal256_array(value_array.collect(), 76, 2);
+        let array = Arc::new(decimal_array.unwrap()) as ArrayRef;
+        generate_cast_test_case!(
+            &array,
+            Int8Array,
+            &DataType::Int8,
+            vec![Some(1_i8), Some(-2_i8), Some(3_i8), None, Some(5_i8)]
+        );
+        generate_cast_test_case!(
+            &array,
+            Int64Array,
+            &DataType::Int64,
+            vec![Some(1_i64), Some(-2_i64), Some(3_i64), None, Some(5_i64)]
+        );
+        generate_cast_test_case!(
+            &array,
+            Float32Array,
+            &DataType::Float32,
+            vec![
+                Some(1.25_f32),
+                Some(-2.25_f32),
+                Some(3.25_f32),
+                None,
+                Some(5.25_f32)
+            ]
+        );
+        generate_cast_test_case!(
+            &array,
+            Float64Array,
+            &DataType::Float64,
+            vec![
+                Some(1.25_f64),
+                Some(-2.25_f64),
+                Some(3.25_f64),
+                None,
+                Some(5.25_f64)
+            ]
+        );
+
+        // overflow: out of range of i64
+        let value_array =
+            vec![Some(BigInt::from(i64::MAX) * 1000), Some(BigInt::from(1))];
+        let decimal_array = create_decimal256_array(value_array, 76, 2).unwrap();
+        let array = Arc::new(decimal_array) as ArrayRef;
+        generate_cast_test_case!(
+            &array,
+            Int64Array,
+            &DataType::Int64,
+            vec![None, Some(0_i64)]
+        );
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &DataType::Int64, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: value of 92233720368547758070 is out of range Int64"
+        );
+    }
+
+    #[test]
+    fn test_cast_numeric_to_decimal256() {
+        let decimal_type = DataType::Decimal256(76, 6);
+        assert!(!can_cast_types(&DataType::UInt64, &decimal_type));
+
+        let array =
+            Arc::new(Int64Array::from(vec![Some(i64::MAX), None, Some(-3)])) as ArrayRef;
+        generate_cast_test_case!(
+            &array,
+            Decimal256Array,
+            &decimal_type,
             vec![
-                Some(1.25_f64),
-                Some(2.25_f64),
-                Some(3.25_f64),
+                Some(
+                    Decimal256::from_big_int(
+                        &(BigInt::from(i64::MAX) * 1_000_000),
+                        76,
+                        6
+                    )
+                    .unwrap()
+                ),
                 None,
-                Some(5.25_f64),
-                Some(1_123_456_789_012_345.6_f64),
-                Some(1_123_456_789_012_345.6_f64),
+                Some(Decimal256::from_big_int(&BigInt::from(-3_000_000), 76, 6).unwrap()),
+            ]
+        );
+
+        let array = Arc::new(Float64Array::from(vec![
+            Some(1.5),
+            Some(f64::NAN),
+            Some(-0.25),
+        ])) as ArrayRef;
+        generate_cast_test_case!(
+            &array,
+            Decimal256Array,
+            &decimal_type,
+            vec![
+                Some(Decimal256::from_big_int(&BigInt::from(1_500_000), 76, 6).unwrap()),
+                None,
+                Some(Decimal256::from_big_int(&BigInt::from(-250_000), 76, 6).unwrap()),
             ]
         );
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &decimal_type, &options).unwrap_err();
+        assert!(err.to_string().contains("Cannot cast NaN"), "{}", err);
+
+        // exceeds the precision of the output type
+        let array = Arc::new(Int8Array::from(vec![100])) as ArrayRef;
+        let b = cast(&array, &DataType::Decimal256(3, 2)).unwrap();
+        assert!(b.is_null(0));
+        let err =
+            cast_with_options(&array, &DataType::Decimal256(3, 2), &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast 100 to Decimal256(3, 2)"
+        );
+
+        // exceeds 256 bits when scaled
+        let array = Arc::new(Int64Array::from(vec![i64::MAX, 1])) as ArrayRef;
+        let to_type = DataType::Decimal256(76, 70);
+        let b = cast(&array, &to_type).unwrap();
+        assert!(b.is_null(0));
+        assert!(b.is_valid(1));
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast 9223372036854775807 to Decimal256(76, 70)"
+        );
     }
 
     #[test]
@@ -3246,7 +4377,10 @@ mod tests {
         let a = Int32Array::from(vec![-5, 6, -7, 8, 100000000]);
         let array = Arc::new(a) as ArrayRef;
         // overflow with the error
-        let cast_option = CastOptions { safe: false };
+        let cast_option = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
         let result = cast_with_options(&array, &DataType::UInt8, &cast_option);
         assert!(result.is_err());
         result.unwrap();
@@ -3380,8 +4514,14 @@ mod tests {
     fn test_cast_with_options_utf8_to_i32() {
         let a = StringArray::from(vec!["5", "6", "seven", "8", "9.1"]);
         let array = Arc::new(a) as ArrayRef;
-        let result =
-            cast_with_options(&array, &DataType::Int32, &CastOptions { safe: false });
+        let result = cast_with_options(
+            &array,
+            &DataType::Int32,
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
+        );
         match result {
             Ok(_) => panic!("expected error"),
             Err(e) => {
@@ -3412,8 +4552,14 @@ mod tests {
         let strings = Arc::new(StringArray::from(vec![
             "true", "false", "invalid", " Y ", "",
         ])) as ArrayRef;
-        let casted =
-            cast_with_options(&strings, &DataType::Boolean, &CastOptions { safe: false });
+        let casted = cast_with_options(
+            &strings,
+            &DataType::Boolean,
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
+        );
         match casted {
             Ok(_) => panic!("expected error"),
             Err(e) => {
@@ -3595,7 +4741,10 @@ mod tests {
             assert!(c.is_null(1));
             assert!(c.is_null(2));
 
-            let options = CastOptions { safe: false };
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
                 err.to_string(),
@@ -3604,6 +4753,114 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cast_string_to_timestamp_with_formats() {
+        let a1 = Arc::new(StringArray::from(vec![
+            Some("17/11/2022 14:25"),
+            Some("2022-11-17 14:25:30.5"),
+            Some("Not a valid date"),
+            None,
+        ])) as ArrayRef;
+        let a2 = Arc::new(LargeStringArray::from(vec![
+            Some("17/11/2022 14:25"),
+            Some("2022-11-17 14:25:30.5"),
+            Some("Not a valid date"),
+            None,
+        ])) as ArrayRef;
+        for array in &[a1, a2] {
+            let mut options = CastOptions {
+                datetime_formats: vec![
+                    "%d/%m/%Y %H:%M".to_string(),
+                    "%Y-%m-%d %H:%M:%S%.f".to_string(),
+                ],
+                timezone: Some("+01:00".to_string()),
+                ..Default::default()
+            };
+            let to_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+            let b = cast_with_options(array, &to_type, &options).unwrap();
+            let c = as_primitive_array::<TimestampMillisecondType>(&b);
+            assert_eq!(
+                c,
+                &TimestampMillisecondArray::from(vec![
+                    Some(1668691500000),
+                    Some(1668691530500),
+                    None,
+                    None
+                ])
+            );
+
+            // the timezone of the options takes precedence over that of the type
+            let to_type = DataType::Timestamp(TimeUnit::Second, Some("+05:00".into()));
+            let b = cast_with_options(array, &to_type, &options).unwrap();
+            assert_eq!(b.data_type(), &to_type);
+            let c = as_primitive_array::<TimestampSecondType>(&b);
+            assert_eq!(c.values(), &[1668691500, 1668691530, 0, 0]);
+
+            options.timezone = None;
+            let b = cast_with_options(array, &to_type, &options).unwrap();
+            let c = as_primitive_array::<TimestampSecondType>(&b);
+            assert_eq!(c.values(), &[1668677100, 1668677130, 0, 0]);
+            assert!(c.is_null(2));
+
+            options.safe = false;
+            let err = cast_with_options(array, &to_type, &options).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Cast error: Error parsing 'Not a valid date' as Timestamp(Second, None) \
+                 with format '%d/%m/%Y %H:%M' or '%Y-%m-%d %H:%M:%S%.f'"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cast_string_to_timestamp_units() {
+        let array = Arc::new(StringArray::from(vec![
+            "2020-09-08T12:00:00+00:00",
+            "2020-09-08T14:00:00.250+02:00",
+            "2020-09-08 12:00:00Z",
+            "2020-09-08 12:00:00",
+        ])) as ArrayRef;
+
+        let to_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let b = cast(&array, &to_type).unwrap();
+        let c = as_primitive_array::<TimestampMillisecondType>(&b);
+        assert_eq!(
+            c.values(),
+            &[1599566400000, 1599566400250, 1599566400000, 1599566400000]
+        );
+
+        // strings without an offset are local to the timezone of the type
+        let to_type = DataType::Timestamp(TimeUnit::Second, Some("-01:00".into()));
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let c = as_primitive_array::<TimestampSecondType>(&b);
+        assert_eq!(
+            c.values(),
+            &[1599566400, 1599566400, 1599566400, 1599570000]
+        );
+
+        // strings that are out of range once converted to UTC
+        let array =
+            Arc::new(StringArray::from(vec!["+262143-12-31 23:00:00"])) as ArrayRef;
+        let to_type = DataType::Timestamp(TimeUnit::Second, Some("-12:00".into()));
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        assert!(b.is_null(0));
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("Cast error: Error parsing '+262143-12-31 23:00:00'"),
+            "{}",
+            err
+        );
+    }
+
     #[test]
     fn test_cast_string_to_date32() {
         let a1 = Arc::new(StringArray::from(vec![
@@ -3624,7 +4881,10 @@ mod tests {
             assert!(c.is_null(1));
             assert!(c.is_null(2));
 
-            let options = CastOptions { safe: false };
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid date' to value of Date32 type");
         }
@@ -3656,7 +4916,10 @@ mod tests {
             assert!(c.is_null(3));
             assert!(c.is_null(4));
 
-            let options = CastOptions { safe: false };
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Second) type");
         }
@@ -3688,7 +4951,10 @@ mod tests {
             assert!(c.is_null(3));
             assert!(c.is_null(4));
 
-            let options = CastOptions { safe: false };
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Millisecond) type");
         }
@@ -3714,7 +4980,10 @@ mod tests {
             assert!(c.is_null(1));
             assert!(c.is_null(2));
 
-            let options = CastOptions { safe: false };
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Microsecond) type");
         }
@@ -3740,7 +5009,10 @@ mod tests {
             assert!(c.is_null(1));
             assert!(c.is_null(2));
 
-            let options = CastOptions { safe: false };
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Nanosecond) type");
         }
@@ -3766,7 +5038,10 @@ mod tests {
             assert!(c.is_null(1));
             assert!(c.is_null(2));
 
-            let options = CastOptions { safe: false };
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid date' to value of Date64 type");
         }
@@ -3880,6 +5155,84 @@ mod tests {
         assert!(c.is_null(2));
     }
 
+    #[test]
+    fn test_cast_to_string_with_format_options() {
+        let array = Arc::new(Float64Array::from(vec![
+            Some(1.5),
+            Some(2.0),
+            Some(1e-7),
+            Some(1e20),
+            Some(f64::NAN),
+            None,
+        ])) as ArrayRef;
+        let to_string = |options: &CastOptions| {
+            let b = cast_with_options(&array, &DataType::Utf8, options).unwrap();
+            let c = as_string_array(&b);
+            c.iter().map(|v| v.map(str::to_string)).collect::<Vec<_>>()
+        };
+
+        let mut options = CastOptions::default();
+        assert_eq!(
+            to_string(&options),
+            vec![
+                Some("1.5".to_string()),
+                Some("2.0".to_string()),
+                Some("1.0e-7".to_string()),
+                Some("1.0e20".to_string()),
+                Some("NaN".to_string()),
+                None
+            ]
+        );
+
+        options.format_options = FormatOptions::new()
+            .with_decimal_separator(Some(','))
+            .with_suppress_scientific(true)
+            .with_null("NULL");
+        assert_eq!(
+            to_string(&options),
+            vec![
+                Some("1,5".to_string()),
+                Some("2,0".to_string()),
+                Some("0,0000001".to_string()),
+                Some("100000000000000000000,0".to_string()),
+                Some("NaN".to_string()),
+                Some("NULL".to_string())
+            ]
+        );
+
+        let array = Arc::new(TimestampSecondArray::from_opt_vec(
+            vec![Some(1668695100), None],
+            Some("+01:00".to_string()),
+        )) as ArrayRef;
+        options.format_options = options
+            .format_options
+            .with_datetime_format(Some("%d/%m/%Y %H:%M %:z".to_string()));
+        let b = cast_with_options(&array, &DataType::LargeUtf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        assert_eq!(
+            c,
+            &LargeStringArray::from(vec!["17/11/2022 15:25 +01:00", "NULL"])
+        );
+
+        // nulls of non-numeric types are also replaced
+        let array = Arc::new(BooleanArray::from(vec![Some(true), None])) as ArrayRef;
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        assert_eq!(as_string_array(&b), &StringArray::from(vec!["1", "NULL"]));
+
+        // as are those of strings, whether or not they change type
+        let expected = StringArray::from(vec!["a", "NULL"]);
+        let array = Arc::new(StringArray::from(vec![Some("a"), None])) as ArrayRef;
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        assert_eq!(as_string_array(&b), &expected);
+        let array = Arc::new(LargeStringArray::from(vec![Some("a"), None])) as ArrayRef;
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        assert_eq!(as_string_array(&b), &expected);
+
+        // and are left alone if no null string is set
+        let b = cast_with_options(&array, &DataType::Utf8, &CastOptions::default());
+        assert!(b.unwrap().is_null(1));
+    }
+
     #[test]
     fn test_cast_date32_to_string() {
         let a = Date32Array::from(vec![10000, 17890]);
@@ -3954,6 +5307,328 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cast_float_to_decimal_rounding() {
+        let array = Arc::new(Float64Array::from(vec![
+            Some(2.5),
+            Some(3.5),
+            Some(-2.5),
+            Some(2.9),
+            Some(-2.1),
+            None,
+        ])) as ArrayRef;
+        let round = |to_type: &DataType, rounding: RoundingMode| {
+            let options = CastOptions {
+                rounding,
+                ..Default::default()
+            };
+            let b = cast_with_options(&array, to_type, &options).unwrap();
+            let c = cast(&b, &DataType::Utf8).unwrap();
+            let c = c.as_any().downcast_ref::<StringArray>().unwrap();
+            c.iter().map(|v| v.map(str::to_string)).collect::<Vec<_>>()
+        };
+        let expected = |values: [&str; 5]| {
+            let mut expected: Vec<_> =
+                values.iter().map(|v| Some(v.to_string())).collect();
+            expected.push(None);
+            expected
+        };
+
+        for to_type in [DataType::Decimal128(10, 0), DataType::Decimal256(10, 0)] {
+            assert_eq!(
+                round(&to_type, RoundingMode::Truncate),
+                expected(["2", "3", "-2", "2", "-2"])
+            );
+            assert_eq!(
+                round(&to_type, RoundingMode::HalfUp),
+                expected(["3", "4", "-3", "3", "-2"])
+            );
+            assert_eq!(
+                round(&to_type, RoundingMode::HalfEven),
+                expected(["2", "4", "-2", "3", "-2"])
+            );
+            assert_eq!(
+                round(&to_type, RoundingMode::Floor),
+                expected(["2", "3", "-3", "2", "-3"])
+            );
+            assert_eq!(
+                round(&to_type, RoundingMode::Ceil),
+                expected(["3", "4", "-2", "3", "-2"])
+            );
+        }
+    }
+
+    #[test]
+    fn test_cast_float_to_decimal_precision_exceeded() {
+        let array = Arc::new(Float64Array::from(vec![
+            Some(123.45),
+            Some(1e10),
+            Some(f64::NAN),
+        ])) as ArrayRef;
+        for to_type in [DataType::Decimal128(5, 2), DataType::Decimal256(5, 2)] {
+            let b = cast(&array, &to_type).unwrap();
+            let c = cast(&b, &DataType::Utf8).unwrap();
+            let c = c.as_any().downcast_ref::<StringArray>().unwrap();
+            assert_eq!(
+                c.iter().collect::<Vec<_>>(),
+                vec![Some("123.45"), None, None]
+            );
+
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
+            let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("Cast error: Cannot cast 10000000000 to {}", to_type)
+            );
+        }
+    }
+
+    #[test]
+    fn test_cast_string_to_decimal() {
+        let array = Arc::new(StringArray::from(vec![
+            Some("123.455"),
+            Some("-1.5"),
+            Some(" 7 "),
+            Some(".5"),
+            Some("123456789.1"),
+            Some("abc"),
+            Some("1e3"),
+            Some("-"),
+            None,
+        ])) as ArrayRef;
+        let options = CastOptions {
+            rounding: RoundingMode::HalfEven,
+            ..Default::default()
+        };
+        for to_type in [DataType::Decimal128(10, 2), DataType::Decimal256(10, 2)] {
+            assert!(can_cast_types(array.data_type(), &to_type));
+            let b = cast_with_options(&array, &to_type, &options).unwrap();
+            assert_eq!(b.data_type(), &to_type);
+            let c = cast(&b, &DataType::Utf8).unwrap();
+            let c = c.as_any().downcast_ref::<StringArray>().unwrap();
+            assert_eq!(
+                c.iter().collect::<Vec<_>>(),
+                vec![
+                    Some("123.46"),
+                    Some("-1.50"),
+                    Some("7.00"),
+                    Some("0.50"),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None
+                ]
+            );
+        }
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let to_type = DataType::Decimal128(10, 2);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string '123456789.1' to value of Decimal128(10, 2) type"
+        );
+
+        // Values without integer digits at scale 0
+        let array =
+            Arc::new(StringArray::from(vec![".5", ".7", "-.5", "-.", "."])) as ArrayRef;
+        let options = CastOptions {
+            rounding: RoundingMode::HalfEven,
+            ..Default::default()
+        };
+        for to_type in [DataType::Decimal128(10, 0), DataType::Decimal256(10, 0)] {
+            let b = cast_with_options(&array, &to_type, &options).unwrap();
+            let c = cast(&b, &DataType::Utf8).unwrap();
+            let c = c.as_any().downcast_ref::<StringArray>().unwrap();
+            assert_eq!(
+                c.iter().collect::<Vec<_>>(),
+                vec![Some("0"), Some("1"), Some("0"), None, None]
+            );
+
+            let b = cast(&array.slice(0, 3), &to_type).unwrap();
+            let c = cast(&b, &DataType::Utf8).unwrap();
+            let c = c.as_any().downcast_ref::<StringArray>().unwrap();
+            assert_eq!(
+                c.iter().collect::<Vec<_>>(),
+                vec![Some("0"), Some("0"), Some("0")]
+            );
+        }
+    }
+
+    #[test]
+    fn test_cast_large_string_to_decimal256() {
+        let value = "-123456789012345678901234567890123456789012.35";
+        let array = Arc::new(LargeStringArray::from(vec![Some(value)])) as ArrayRef;
+        let to_type = DataType::Decimal256(50, 1);
+        assert!(can_cast_types(array.data_type(), &to_type));
+
+        let options = CastOptions {
+            rounding: RoundingMode::HalfUp,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let c = cast(&b, &DataType::LargeUtf8).unwrap();
+        let c = c.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        assert_eq!(c.value(0), "-123456789012345678901234567890123456789012.4");
+    }
+
+    #[test]
+    fn test_cast_decimal_to_string() {
+        let array = Arc::new(
+            [Some(12345), Some(-5), None]
+                .into_iter()
+                .collect::<Decimal128Array>()
+                .with_precision_and_scale(10, 2)
+                .unwrap(),
+        ) as ArrayRef;
+        let to_type = DataType::Utf8;
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let b = cast(&array, &to_type).unwrap();
+        let c = b.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(
+            c.iter().collect::<Vec<_>>(),
+            vec![Some("123.45"), Some("-0.05"), None]
+        );
+
+        let options = CastOptions::default()
+            .with_format_options(FormatOptions::new().with_decimal_separator(Some(',')));
+        let array = cast(&array, &DataType::Decimal256(10, 2)).unwrap();
+        let b = cast_with_options(&array, &DataType::LargeUtf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        assert_eq!(
+            c.iter().collect::<Vec<_>>(),
+            vec![Some("123,45"), Some("-0,05"), None]
+        );
+    }
+
+    #[test]
+    fn test_cast_interval_to_duration() {
+        let array = Arc::new(IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNanoType::make_value(0, 1, 500)),
+            Some(IntervalMonthDayNanoType::make_value(1, 0, 0)),
+            Some(IntervalMonthDayNanoType::make_value(0, -2, 0)),
+            Some(IntervalMonthDayNanoType::make_value(0, i32::MAX, 0)),
+            None,
+        ])) as ArrayRef;
+        let to_type = DataType::Duration(TimeUnit::Millisecond);
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let b = cast(&array, &to_type).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<DurationMillisecondArray>()
+            .unwrap();
+        assert_eq!(
+            c,
+            &DurationMillisecondArray::from(vec![
+                Some(86_400_000),
+                None,
+                Some(-172_800_000),
+                Some(i32::MAX as i64 * 86_400_000),
+                None
+            ])
+        );
+
+        // a month has no fixed duration
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert!(err.to_string().starts_with("Cast error: Cannot cast value"));
+
+        // overflow
+        let b = cast(&array, &DataType::Duration(TimeUnit::Nanosecond)).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<DurationNanosecondArray>()
+            .unwrap();
+        assert_eq!(c.null_count(), 3);
+        assert_eq!(c.value(0), 86_400_000_000_500);
+
+        let array = Arc::new(IntervalDayTimeArray::from(vec![
+            IntervalDayTimeType::make_value(1, 1500),
+            IntervalDayTimeType::make_value(-1, 0),
+        ])) as ArrayRef;
+        let b = cast(&array, &DataType::Duration(TimeUnit::Second)).unwrap();
+        let c = b.as_any().downcast_ref::<DurationSecondArray>().unwrap();
+        assert_eq!(c.values(), &[86_401, -86_400]);
+
+        let to_type = DataType::Duration(TimeUnit::Second);
+        assert!(!can_cast_types(
+            &DataType::Interval(IntervalUnit::YearMonth),
+            &to_type
+        ));
+    }
+
+    #[test]
+    fn test_cast_duration_to_interval() {
+        let array = Arc::new(DurationSecondArray::from(vec![
+            Some(1),
+            Some(90_000),
+            Some(i64::MAX),
+            None,
+        ])) as ArrayRef;
+        let to_type = DataType::Interval(IntervalUnit::MonthDayNano);
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let b = cast(&array, &to_type).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<IntervalMonthDayNanoArray>()
+            .unwrap();
+        assert_eq!(
+            c,
+            &IntervalMonthDayNanoArray::from(vec![
+                Some(IntervalMonthDayNanoType::make_value(0, 0, 1_000_000_000)),
+                Some(IntervalMonthDayNanoType::make_value(
+                    0,
+                    0,
+                    90_000_000_000_000
+                )),
+                None,
+                None
+            ])
+        );
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Cast error: Cannot cast value '{}' of Duration(Second) to {:?}",
+                array_value_to_string(&array, 2).unwrap(),
+                to_type
+            )
+        );
+
+        let to_type = DataType::Interval(IntervalUnit::DayTime);
+        let b = cast(&array, &to_type).unwrap();
+        let c = b.as_any().downcast_ref::<IntervalDayTimeArray>().unwrap();
+        assert_eq!(
+            c,
+            &IntervalDayTimeArray::from(vec![
+                Some(IntervalDayTimeType::make_value(0, 1_000)),
+                Some(IntervalDayTimeType::make_value(1, 3_600_000)),
+                None,
+                None
+            ])
+        );
+
+        let array = Arc::new(DurationMicrosecondArray::from(vec![-1_500])) as ArrayRef;
+        let b = cast(&array, &to_type).unwrap();
+        let c = b.as_any().downcast_ref::<IntervalDayTimeArray>().unwrap();
+        assert_eq!(c.value(0), IntervalDayTimeType::make_value(0, -1));
+    }
+
     #[test]
     fn test_cast_to_strings() {
         let a = Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef;
@@ -5415,6 +7090,102 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cast_list_flavours() {
+        let field = |data_type| Box::new(Field::new("item", data_type, true));
+
+        // list to large list of another child type
+        let list = Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3)]),
+            Some(vec![Some(4), Some(5)]),
+        ])) as ArrayRef;
+        let to_type = DataType::LargeList(field(DataType::Int64));
+        let b = cast(&list, &to_type).unwrap();
+        let expected = LargeListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3)]),
+            Some(vec![Some(4), Some(5)]),
+        ]);
+        assert_eq!(
+            b.as_any().downcast_ref::<LargeListArray>().unwrap(),
+            &expected
+        );
+
+        // lists of other lengths are null, or an error if not safe
+        let to_type = DataType::FixedSizeList(field(DataType::Int64), 2);
+        let b = cast(&list, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let fixed = b.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+        assert_eq!(fixed.len(), 4);
+        assert_eq!(fixed.null_count(), 2);
+        assert!(fixed.is_null(1) && fixed.is_null(2));
+        assert_eq!(
+            as_primitive_array::<Int64Type>(&fixed.value(0)).values(),
+            &[1, 2]
+        );
+        assert_eq!(
+            as_primitive_array::<Int64Type>(&fixed.value(3)).values(),
+            &[4, 5]
+        );
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&list, &to_type, &options).unwrap_err();
+        assert!(err.to_string().contains("Cannot cast list of length 1"));
+
+        // uniform lists are sliced, and the offset of the result accounted for
+        let b = cast(&list.slice(3, 1), &to_type).unwrap();
+        let fixed = b.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+        assert_eq!(fixed.len(), 1);
+        assert_eq!(
+            as_primitive_array::<Int64Type>(&fixed.value(0)).values(),
+            &[4, 5]
+        );
+
+        // fixed size list to lists, including a sliced one
+        let fixed = cast(&list, &to_type).unwrap();
+        let b = cast(&fixed, &DataType::List(field(DataType::Int32))).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            None,
+            Some(vec![Some(4), Some(5)]),
+        ]);
+        assert_eq!(b.as_any().downcast_ref::<ListArray>().unwrap(), &expected);
+
+        let b = cast(
+            &fixed.slice(2, 2),
+            &DataType::LargeList(field(DataType::Int64)),
+        )
+        .unwrap();
+        let expected = LargeListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            None,
+            Some(vec![Some(4), Some(5)]),
+        ]);
+        assert_eq!(
+            b.as_any().downcast_ref::<LargeListArray>().unwrap(),
+            &expected
+        );
+
+        // fixed size lists of the same size
+        let to_type = DataType::FixedSizeList(field(DataType::Float64), 2);
+        let b = cast(&fixed.slice(3, 1), &to_type).unwrap();
+        let b = b.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+        assert_eq!(
+            as_primitive_array::<Float64Type>(&b.value(0)).values(),
+            &[4., 5.]
+        );
+
+        let to_type = DataType::FixedSizeList(field(DataType::Int64), 3);
+        assert!(!can_cast_types(fixed.data_type(), &to_type));
+        cast(&fixed, &to_type).unwrap_err();
+    }
+
     #[test]
     fn test_cast_list_containers() {
         // large-list to list
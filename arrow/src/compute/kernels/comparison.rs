@@ -17,10 +17,11 @@
 
 //! Comparison kernels for `Array`s.
 //!
-//! These kernels can leverage SIMD if available on your system.  Currently no runtime
-//! detection is provided, you should enable the specific SIMD intrinsics using
-//! `RUSTFLAGS="-C target-feature=+avx2"` for example.  See the documentation
-//! [here](https://doc.rust-lang.org/stable/core/arch/) for more information.
+//! These kernels can leverage SIMD if available on your system. Comparisons of primitive
+//! arrays detect the available SIMD instructions at runtime, other kernels require enabling
+//! the specific SIMD intrinsics using `RUSTFLAGS="-C target-feature=+avx2"` for example.
+//! See the documentation [here](https://doc.rust-lang.org/stable/core/arch/) for more
+//! information.
 //!
 
 use crate::array::*;
@@ -39,7 +40,9 @@ use crate::datatypes::{
 use crate::downcast_dictionary_array;
 use crate::error::{ArrowError, Result};
 use crate::util::bit_util;
+use multiversion::multiversion;
 use regex::Regex;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
 /// Helper function to perform boolean lambda function on values from two array accessors, this
@@ -111,6 +114,100 @@ where
     Ok(BooleanArray::from(data))
 }
 
+/// Helper function to perform boolean lambda function on values from two primitive arrays.
+///
+/// Unlike [`compare_op`], this operates on the underlying value slices, and dispatches at
+/// runtime to a version of the comparison loop compiled for the SIMD instructions of the CPU.
+#[cfg(not(feature = "simd"))]
+fn compare_op_primitive<T, F>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    op: F,
+) -> Result<BooleanArray>
+where
+    T: ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> bool,
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform comparison operation on arrays of different length"
+                .to_string(),
+        ));
+    }
+
+    let null_bit_buffer =
+        combine_option_bitmap(&[left.data_ref(), right.data_ref()], left.len())?;
+    let buffer = collect_bool_binary(left.values(), right.values(), op);
+    Ok(boolean_array(left.len(), buffer, null_bit_buffer))
+}
+
+/// Helper function to perform boolean lambda function on values from a primitive array.
+///
+/// Unlike [`compare_op_scalar`], this operates on the underlying value slice, and dispatches
+/// at runtime to a version of the comparison loop compiled for the SIMD instructions of the
+/// CPU.
+fn compare_op_scalar_primitive<T, F>(
+    left: &PrimitiveArray<T>,
+    op: F,
+) -> Result<BooleanArray>
+where
+    T: ArrowNumericType,
+    F: Fn(T::Native) -> bool,
+{
+    let null_bit_buffer = left
+        .data()
+        .null_buffer()
+        .map(|b| b.bit_slice(left.offset(), left.len()));
+    let buffer = collect_bool_unary(left.values(), op);
+    Ok(boolean_array(left.len(), buffer, null_bit_buffer))
+}
+
+#[cfg(not(feature = "simd"))]
+#[multiversion]
+#[clone(target = "[x86|x86_64]+avx2")]
+#[clone(target = "[x86|x86_64]+sse4.1")]
+#[clone(target = "aarch64+neon")]
+fn collect_bool_binary<T, F>(left: &[T], right: &[T], op: F) -> MutableBuffer
+where
+    T: Copy,
+    F: Fn(T, T) -> bool,
+{
+    let right = &right[..left.len()];
+    MutableBuffer::collect_bool(left.len(), |i| op(left[i], right[i]))
+}
+
+#[multiversion]
+#[clone(target = "[x86|x86_64]+avx2")]
+#[clone(target = "[x86|x86_64]+sse4.1")]
+#[clone(target = "aarch64+neon")]
+fn collect_bool_unary<T, F>(values: &[T], op: F) -> MutableBuffer
+where
+    T: Copy,
+    F: Fn(T) -> bool,
+{
+    MutableBuffer::collect_bool(values.len(), |i| op(values[i]))
+}
+
+/// Creates a [`BooleanArray`] of length `len` from the bits of `buffer`
+fn boolean_array(
+    len: usize,
+    buffer: MutableBuffer,
+    null_bit_buffer: Option<Buffer>,
+) -> BooleanArray {
+    let data = unsafe {
+        ArrayData::new_unchecked(
+            DataType::Boolean,
+            len,
+            None,
+            null_bit_buffer,
+            0,
+            vec![Buffer::from(buffer)],
+            vec![],
+        )
+    };
+    BooleanArray::from(data)
+}
+
 /// Evaluate `op(left, right)` for [`PrimitiveArray`]s using a specified
 /// comparison function.
 pub fn no_simd_compare_op<T, F>(
@@ -2723,6 +2820,172 @@ where
     )
 }
 
+/// Applies `op` to the [`Ordering`] of each pair of values of the dictionary
+/// arrays `left` and `right` without unpacking them, by ranking their values
+/// once and comparing the ranks of the keys.
+///
+/// If both arrays have equal values these are ranked on their own, otherwise
+/// the values of both are ranked together, provided there are fewer of them
+/// than rows. Returns `None` if neither applies, or if their values are floats,
+/// which are compared using their total order.
+#[cfg(feature = "dyn_cmp_dict")]
+fn cmp_dict_ranks<F>(
+    left: &dyn Array,
+    right: &dyn Array,
+    op: F,
+) -> Result<Option<BooleanArray>>
+where
+    F: Fn(Ordering) -> bool,
+{
+    if left.data_type() != right.data_type() {
+        return Ok(None);
+    }
+    downcast_dictionary_array! {
+        left => cmp_dict_keys_ranks(left, as_dictionary_array(right), op),
+        _ => Ok(None)
+    }
+}
+
+#[cfg(feature = "dyn_cmp_dict")]
+fn cmp_dict_keys_ranks<K, F>(
+    left: &DictionaryArray<K>,
+    right: &DictionaryArray<K>,
+    op: F,
+) -> Result<Option<BooleanArray>>
+where
+    K: crate::datatypes::ArrowDictionaryKeyType,
+    F: Fn(Ordering) -> bool,
+{
+    let (left_values, right_values) = (left.values(), right.values());
+    let floating = matches!(
+        left_values.data_type(),
+        DataType::Float16 | DataType::Float32 | DataType::Float64
+    );
+    if floating {
+        return Ok(None);
+    }
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform comparison operation on arrays of different length"
+                .to_string(),
+        ));
+    }
+
+    // the ranks of the right values start at `offset` unless they are shared
+    let equal = left_values.data().ptr_eq(right_values.data())
+        || left_values.data() == right_values.data();
+    let (values, offset) = if equal {
+        (left_values.clone(), 0)
+    } else if left_values.len() + right_values.len() < left.len() {
+        match crate::compute::concat(&[left_values.as_ref(), right_values.as_ref()]) {
+            Ok(values) => (values, left_values.len()),
+            Err(_) => return Ok(None),
+        }
+    } else {
+        return Ok(None);
+    };
+    let cmp = match build_compare(values.as_ref(), values.as_ref()) {
+        Ok(cmp) => cmp,
+        Err(_) => return Ok(None),
+    };
+
+    // equal values have the same rank
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_unstable_by(|a, b| cmp(*a, *b));
+    let mut ranks = vec![0; values.len()];
+    for w in order.windows(2) {
+        ranks[w[1]] = ranks[w[0]] + usize::from(cmp(w[0], w[1]).is_ne());
+    }
+
+    let result = left
+        .keys()
+        .iter()
+        .zip(right.keys().iter())
+        .map(|(l, r)| {
+            let (l, r) = (l?.as_usize(), r?.as_usize());
+            (left_values.is_valid(l) && right_values.is_valid(r))
+                .then(|| op(ranks[l].cmp(&ranks[offset + r])))
+        })
+        .collect();
+    Ok(Some(result))
+}
+
+/// Returns true for the nested data types supported by [`compare_nested`]
+fn is_nested(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Struct(_)
+            | DataType::List(_)
+            | DataType::LargeList(_)
+            | DataType::FixedSizeList(_, _)
+            | DataType::Map(_, _)
+    )
+}
+
+/// Applies `op` to the [`Ordering`] of each pair of values of `left` and
+/// `right` as determined by [`build_compare`], with null values resulting in
+/// null
+fn compare_nested<F>(left: &dyn Array, right: &dyn Array, op: F) -> Result<BooleanArray>
+where
+    F: Fn(Ordering) -> bool,
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform comparison operation on arrays of different length"
+                .to_string(),
+        ));
+    }
+    let cmp = build_compare(left, right)?;
+    let null_bit_buffer =
+        combine_option_bitmap(&[left.data_ref(), right.data_ref()], left.len())?;
+    let buffer = MutableBuffer::collect_bool(left.len(), |i| op(cmp(i, i)));
+    Ok(boolean_array(left.len(), buffer, null_bit_buffer))
+}
+
+/// Returns true where the values of `left` and `right` differ, treating two
+/// null values as equal and a null value as distinct from any valid value.
+///
+/// Unlike [`neq_dyn`], the result never contains nulls. Supports every data
+/// type supported by [`build_compare`], including struct, list and map arrays.
+///
+/// ```
+/// # use arrow::array::{BooleanArray, Int32Array};
+/// # use arrow::compute::is_distinct_from;
+/// let a = Int32Array::from(vec![Some(1), None, None, Some(4)]);
+/// let b = Int32Array::from(vec![Some(1), None, Some(3), Some(5)]);
+/// let result = is_distinct_from(&a, &b).unwrap();
+/// assert_eq!(result, BooleanArray::from(vec![false, false, true, true]));
+/// ```
+pub fn is_distinct_from(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
+    distinct(left, right, true)
+}
+
+/// Returns true where the values of `left` and `right` are equal, treating
+/// two null values as equal and a null value as distinct from any valid value.
+///
+/// This is the negation of [`is_distinct_from`].
+pub fn is_not_distinct_from(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
+    distinct(left, right, false)
+}
+
+fn distinct(left: &dyn Array, right: &dyn Array, distinct: bool) -> Result<BooleanArray> {
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform comparison operation on arrays of different length"
+                .to_string(),
+        ));
+    }
+    let cmp = build_compare(left, right)?;
+    let buffer = MutableBuffer::collect_bool(left.len(), |i| {
+        let equal = match (left.is_valid(i), right.is_valid(i)) {
+            (true, true) => cmp(i, i).is_eq(),
+            (valid_left, valid_right) => valid_left == valid_right,
+        };
+        equal != distinct
+    });
+    Ok(boolean_array(left.len(), buffer, None))
+}
+
 /// Perform `left == right` operation on two (dynamic) [`Array`]s.
 ///
 /// Only when two arrays are of the same type the comparison will happen otherwise it will err
@@ -2732,6 +2995,8 @@ where
 /// the totalOrder predicate as defined in the IEEE 754 (2008 revision) floating point standard.
 /// Please refer to `f32::total_cmp` and `f64::total_cmp`.
 ///
+/// Struct, list and map arrays are compared lexicographically, see [`build_compare`].
+///
 /// # Example
 /// ```
 /// use arrow::array::{StringArray, BooleanArray};
@@ -2743,9 +3008,14 @@ where
 /// ```
 pub fn eq_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
     match left.data_type() {
+        t if is_nested(t) => compare_nested(left, right, Ordering::is_eq),
         DataType::Dictionary(_, _)
             if matches!(right.data_type(), DataType::Dictionary(_, _)) =>
         {
+            #[cfg(feature = "dyn_cmp_dict")]
+            if let Some(result) = cmp_dict_ranks(left, right, Ordering::is_eq)? {
+                return Ok(result);
+            }
             typed_dict_compares!(
                 left,
                 right,
@@ -2783,6 +3053,8 @@ pub fn eq_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
 /// the totalOrder predicate as defined in the IEEE 754 (2008 revision) floating point standard.
 /// Please refer to `f32::total_cmp` and `f64::total_cmp`.
 ///
+/// Struct, list and map arrays are compared lexicographically, see [`build_compare`].
+///
 /// # Example
 /// ```
 /// use arrow::array::{BinaryArray, BooleanArray};
@@ -2796,9 +3068,14 @@ pub fn eq_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
 /// ```
 pub fn neq_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
     match left.data_type() {
+        t if is_nested(t) => compare_nested(left, right, Ordering::is_ne),
         DataType::Dictionary(_, _)
             if matches!(right.data_type(), DataType::Dictionary(_, _)) =>
         {
+            #[cfg(feature = "dyn_cmp_dict")]
+            if let Some(result) = cmp_dict_ranks(left, right, Ordering::is_ne)? {
+                return Ok(result);
+            }
             typed_dict_compares!(
                 left,
                 right,
@@ -2836,6 +3113,8 @@ pub fn neq_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
 /// the totalOrder predicate as defined in the IEEE 754 (2008 revision) floating point standard.
 /// Please refer to `f32::total_cmp` and `f64::total_cmp`.
 ///
+/// Struct, list and map arrays are compared lexicographically, see [`build_compare`].
+///
 /// # Example
 /// ```
 /// use arrow::array::{PrimitiveArray, BooleanArray};
@@ -2849,9 +3128,14 @@ pub fn neq_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
 #[allow(clippy::bool_comparison)]
 pub fn lt_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
     match left.data_type() {
+        t if is_nested(t) => compare_nested(left, right, Ordering::is_lt),
         DataType::Dictionary(_, _)
             if matches!(right.data_type(), DataType::Dictionary(_, _)) =>
         {
+            #[cfg(feature = "dyn_cmp_dict")]
+            if let Some(result) = cmp_dict_ranks(left, right, Ordering::is_lt)? {
+                return Ok(result);
+            }
             typed_dict_compares!(
                 left,
                 right,
@@ -2889,6 +3173,8 @@ pub fn lt_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
 /// the totalOrder predicate as defined in the IEEE 754 (2008 revision) floating point standard.
 /// Please refer to `f32::total_cmp` and `f64::total_cmp`.
 ///
+/// Struct, list and map arrays are compared lexicographically, see [`build_compare`].
+///
 /// # Example
 /// ```
 /// use arrow::array::{PrimitiveArray, BooleanArray};
@@ -2901,9 +3187,14 @@ pub fn lt_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
 /// ```
 pub fn lt_eq_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
     match left.data_type() {
+        t if is_nested(t) => compare_nested(left, right, Ordering::is_le),
         DataType::Dictionary(_, _)
             if matches!(right.data_type(), DataType::Dictionary(_, _)) =>
         {
+            #[cfg(feature = "dyn_cmp_dict")]
+            if let Some(result) = cmp_dict_ranks(left, right, Ordering::is_le)? {
+                return Ok(result);
+            }
             typed_dict_compares!(
                 left,
                 right,
@@ -2941,6 +3232,8 @@ pub fn lt_eq_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
 /// the totalOrder predicate as defined in the IEEE 754 (2008 revision) floating point standard.
 /// Please refer to `f32::total_cmp` and `f64::total_cmp`.
 ///
+/// Struct, list and map arrays are compared lexicographically, see [`build_compare`].
+///
 /// # Example
 /// ```
 /// use arrow::array::BooleanArray;
@@ -2953,9 +3246,14 @@ pub fn lt_eq_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
 #[allow(clippy::bool_comparison)]
 pub fn gt_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
     match left.data_type() {
+        t if is_nested(t) => compare_nested(left, right, Ordering::is_gt),
         DataType::Dictionary(_, _)
             if matches!(right.data_type(), DataType::Dictionary(_, _)) =>
         {
+            #[cfg(feature = "dyn_cmp_dict")]
+            if let Some(result) = cmp_dict_ranks(left, right, Ordering::is_gt)? {
+                return Ok(result);
+            }
             typed_dict_compares!(
                 left,
                 right,
@@ -2993,6 +3291,8 @@ pub fn gt_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
 /// the totalOrder predicate as defined in the IEEE 754 (2008 revision) floating point standard.
 /// Please refer to `f32::total_cmp` and `f64::total_cmp`.
 ///
+/// Struct, list and map arrays are compared lexicographically, see [`build_compare`].
+///
 /// # Example
 /// ```
 /// use arrow::array::{BooleanArray, StringArray};
@@ -3004,9 +3304,14 @@ pub fn gt_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
 /// ```
 pub fn gt_eq_dyn(left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
     match left.data_type() {
+        t if is_nested(t) => compare_nested(left, right, Ordering::is_ge),
         DataType::Dictionary(_, _)
             if matches!(right.data_type(), DataType::Dictionary(_, _)) =>
         {
+            #[cfg(feature = "dyn_cmp_dict")]
+            if let Some(result) = cmp_dict_ranks(left, right, Ordering::is_ge)? {
+                return Ok(result);
+            }
             typed_dict_compares!(
                 left,
                 right,
@@ -3043,7 +3348,7 @@ where
     #[cfg(feature = "simd")]
     return simd_compare_op(left, right, T::eq, |a, b| a == b);
     #[cfg(not(feature = "simd"))]
-    return compare_op(left, right, |a, b| a == b);
+    return compare_op_primitive(left, right, |a, b| a == b);
 }
 
 /// Perform `left == right` operation on a [`PrimitiveArray`] and a scalar value.
@@ -3060,7 +3365,7 @@ where
     #[cfg(feature = "simd")]
     return simd_compare_op_scalar(left, right, T::eq, |a, b| a == b);
     #[cfg(not(feature = "simd"))]
-    return compare_op_scalar(left, |a| a.is_eq(right));
+    return compare_op_scalar_primitive(left, |a| a.is_eq(right));
 }
 
 /// Applies an unary and infallible comparison function to a primitive array.
@@ -3069,7 +3374,7 @@ where
     T: ArrowNumericType,
     F: Fn(T::Native) -> bool,
 {
-    compare_op_scalar(left, op)
+    compare_op_scalar_primitive(left, op)
 }
 
 /// Perform `left != right` operation on two [`PrimitiveArray`]s.
@@ -3080,7 +3385,7 @@ where
     #[cfg(feature = "simd")]
     return simd_compare_op(left, right, T::ne, |a, b| a != b);
     #[cfg(not(feature = "simd"))]
-    return compare_op(left, right, |a, b| a != b);
+    return compare_op_primitive(left, right, |a, b| a != b);
 }
 
 /// Perform `left != right` operation on a [`PrimitiveArray`] and a scalar value.
@@ -3097,7 +3402,7 @@ where
     #[cfg(feature = "simd")]
     return simd_compare_op_scalar(left, right, T::ne, |a, b| a != b);
     #[cfg(not(feature = "simd"))]
-    return compare_op_scalar(left, |a| a.is_ne(right));
+    return compare_op_scalar_primitive(left, |a| a.is_ne(right));
 }
 
 /// Perform `left < right` operation on two [`PrimitiveArray`]s. Null values are less than non-null
@@ -3109,7 +3414,7 @@ where
     #[cfg(feature = "simd")]
     return simd_compare_op(left, right, T::lt, |a, b| a < b);
     #[cfg(not(feature = "simd"))]
-    return compare_op(left, right, |a, b| a < b);
+    return compare_op_primitive(left, right, |a, b| a < b);
 }
 
 /// Perform `left < right` operation on a [`PrimitiveArray`] and a scalar value.
@@ -3127,7 +3432,7 @@ where
     #[cfg(feature = "simd")]
     return simd_compare_op_scalar(left, right, T::lt, |a, b| a < b);
     #[cfg(not(feature = "simd"))]
-    return compare_op_scalar(left, |a| a.is_lt(right));
+    return compare_op_scalar_primitive(left, |a| a.is_lt(right));
 }
 
 /// Perform `left <= right` operation on two [`PrimitiveArray`]s. Null values are less than non-null
@@ -3142,7 +3447,7 @@ where
     #[cfg(feature = "simd")]
     return simd_compare_op(left, right, T::le, |a, b| a <= b);
     #[cfg(not(feature = "simd"))]
-    return compare_op(left, right, |a, b| a <= b);
+    return compare_op_primitive(left, right, |a, b| a <= b);
 }
 
 /// Perform `left <= right` operation on a [`PrimitiveArray`] and a scalar value.
@@ -3160,7 +3465,7 @@ where
     #[cfg(feature = "simd")]
     return simd_compare_op_scalar(left, right, T::le, |a, b| a <= b);
     #[cfg(not(feature = "simd"))]
-    return compare_op_scalar(left, |a| a.is_le(right));
+    return compare_op_scalar_primitive(left, |a| a.is_le(right));
 }
 
 /// Perform `left > right` operation on two [`PrimitiveArray`]s. Non-null values are greater than null
@@ -3172,7 +3477,7 @@ where
     #[cfg(feature = "simd")]
     return simd_compare_op(left, right, T::gt, |a, b| a > b);
     #[cfg(not(feature = "simd"))]
-    return compare_op(left, right, |a, b| a > b);
+    return compare_op_primitive(left, right, |a, b| a > b);
 }
 
 /// Perform `left > right` operation on a [`PrimitiveArray`] and a scalar value.
@@ -3190,7 +3495,7 @@ where
     #[cfg(feature = "simd")]
     return simd_compare_op_scalar(left, right, T::gt, |a, b| a > b);
     #[cfg(not(feature = "simd"))]
-    return compare_op_scalar(left, |a| a.is_gt(right));
+    return compare_op_scalar_primitive(left, |a| a.is_gt(right));
 }
 
 /// Perform `left >= right` operation on two [`PrimitiveArray`]s. Non-null values are greater than null
@@ -3205,7 +3510,7 @@ where
     #[cfg(feature = "simd")]
     return simd_compare_op(left, right, T::ge, |a, b| a >= b);
     #[cfg(not(feature = "simd"))]
-    return compare_op(left, right, |a, b| a >= b);
+    return compare_op_primitive(left, right, |a, b| a >= b);
 }
 
 /// Perform `left >= right` operation on a [`PrimitiveArray`] and a scalar value.
@@ -3223,7 +3528,7 @@ where
     #[cfg(feature = "simd")]
     return simd_compare_op_scalar(left, right, T::ge, |a, b| a >= b);
     #[cfg(not(feature = "simd"))]
-    return compare_op_scalar(left, |a| a.is_ge(right));
+    return compare_op_scalar_primitive(left, |a| a.is_ge(right));
 }
 
 /// Checks if a [`GenericListArray`] contains a value in the [`PrimitiveArray`]
@@ -3511,6 +3816,106 @@ mod tests {
         assert!(d.value(4));
     }
 
+    #[test]
+    fn test_nested_cmp_dyn() {
+        let a = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3)]),
+            Some(vec![None]),
+        ]);
+        let b = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![]),
+            Some(vec![Some(2), Some(4)]),
+            Some(vec![Some(0)]),
+        ]);
+
+        let expected =
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(false)]);
+        assert_eq!(eq_dyn(&a, &b).unwrap(), expected);
+        let expected =
+            BooleanArray::from(vec![Some(false), None, Some(true), Some(true)]);
+        assert_eq!(neq_dyn(&a, &b).unwrap(), expected);
+        let expected =
+            BooleanArray::from(vec![Some(false), None, Some(false), Some(true)]);
+        assert_eq!(lt_dyn(&a, &b).unwrap(), expected);
+        let expected =
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        assert_eq!(lt_eq_dyn(&a, &b).unwrap(), expected);
+        let expected =
+            BooleanArray::from(vec![Some(false), None, Some(true), Some(false)]);
+        assert_eq!(gt_dyn(&a, &b).unwrap(), expected);
+        let expected =
+            BooleanArray::from(vec![Some(true), None, Some(true), Some(false)]);
+        assert_eq!(gt_eq_dyn(&a, &b).unwrap(), expected);
+
+        let c = Int32Array::from(vec![1, 2, 3, 4]);
+        assert!(eq_dyn(&a, &c).is_err());
+    }
+
+    #[test]
+    fn test_struct_eq_dyn() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "z"]));
+        let left = StructArray::try_from(vec![("a", a), ("b", b)]).unwrap();
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, None]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "z"]));
+        let right = StructArray::try_from(vec![("a", a), ("b", b)]).unwrap();
+
+        // null fields compare equal to each other
+        let expected = BooleanArray::from(vec![true, true, false]);
+        assert_eq!(eq_dyn(&left, &right).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_is_distinct_from() {
+        let a = Int32Array::from(vec![Some(1), None, None, Some(4)]);
+        let b = Int32Array::from(vec![Some(1), None, Some(3), Some(5)]);
+        let expected = BooleanArray::from(vec![false, false, true, true]);
+        assert_eq!(is_distinct_from(&a, &b).unwrap(), expected);
+        let expected = BooleanArray::from(vec![true, true, false, false]);
+        assert_eq!(is_not_distinct_from(&a, &b).unwrap(), expected);
+
+        let a = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+            None,
+        ]);
+        let b = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+            Some(vec![]),
+        ]);
+        let expected = BooleanArray::from(vec![false, false, true]);
+        assert_eq!(is_distinct_from(&a, &b).unwrap(), expected);
+
+        let err = is_distinct_from(&a, &b.slice(0, 2)).unwrap_err();
+        assert!(err.to_string().contains("different length"), "{}", err);
+    }
+
+    #[test]
+    fn test_primitive_array_cmp_multiple_chunks() {
+        // spans several 64 bit chunks of the result, with a remainder
+        let a: Int64Array = (0..200).map(Some).collect();
+        let b: Int64Array = (0..200).map(|x| (x % 3 != 0).then_some(100)).collect();
+        let a = a.slice(3, 150);
+        let a = as_primitive_array::<Int64Type>(a.as_ref());
+        let b = b.slice(7, 150);
+        let b = as_primitive_array::<Int64Type>(b.as_ref());
+
+        let result = lt(a, b).unwrap();
+        let expected: BooleanArray = (3..153)
+            .zip(7..157)
+            .map(|(a, b)| (b % 3 != 0).then_some(a < 100))
+            .collect();
+        assert_eq!(result, expected);
+
+        let result = gt_eq_scalar(a, 100).unwrap();
+        let expected: BooleanArray = (3..153).map(|a| Some(a >= 100)).collect();
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_primitive_array_eq_scalar_with_slice() {
         let a = Int32Array::from(vec![Some(1), None, Some(2), Some(3)]);
@@ -5479,6 +5884,80 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "dyn_cmp_dict")]
+    fn test_cmp_dyn_dictionary_shared_values() {
+        // duplicate and null values in the shared dictionary
+        let values = StringArray::from(vec![Some("b"), Some("a"), None, Some("b")]);
+        let keys1 = Int8Array::from(vec![Some(0), Some(1), Some(3), None, Some(2)]);
+        let keys2 = Int8Array::from(vec![Some(3), Some(0), Some(1), Some(0), Some(0)]);
+        let a = DictionaryArray::try_new(&keys1, &values).unwrap();
+        let b = DictionaryArray::try_new(&keys2, &values).unwrap();
+        assert!(a.values().data().ptr_eq(b.values().data()));
+
+        let expected = |v: [bool; 3]| {
+            BooleanArray::from(vec![Some(v[0]), Some(v[1]), Some(v[2]), None, None])
+        };
+        assert_eq!(eq_dyn(&a, &b).unwrap(), expected([true, false, false]));
+        assert_eq!(neq_dyn(&a, &b).unwrap(), expected([false, true, true]));
+        assert_eq!(lt_dyn(&a, &b).unwrap(), expected([false, true, false]));
+        assert_eq!(lt_eq_dyn(&a, &b).unwrap(), expected([true, true, false]));
+        assert_eq!(gt_dyn(&a, &b).unwrap(), expected([false, false, true]));
+        assert_eq!(gt_eq_dyn(&a, &b).unwrap(), expected([true, false, true]));
+
+        let keys3 = Int8Array::from(vec![0, 1]);
+        let short = DictionaryArray::try_new(&keys3, &values).unwrap();
+        assert!(eq_dyn(&a, &short).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "dyn_cmp_dict")]
+    fn test_cmp_dyn_dictionary_different_values() {
+        // equal but not shared values
+        let values1 = StringArray::from(vec![Some("b"), Some("a"), None]);
+        let values2 = StringArray::from(vec![Some("b"), Some("a"), None]);
+        let keys1 = Int8Array::from(vec![Some(0), Some(1), Some(2), None]);
+        let keys2 = Int8Array::from(vec![Some(1), Some(1), Some(0), Some(0)]);
+        let a = DictionaryArray::try_new(&keys1, &values1).unwrap();
+        let b = DictionaryArray::try_new(&keys2, &values2).unwrap();
+        assert!(!a.values().data().ptr_eq(b.values().data()));
+        assert_eq!(
+            lt_dyn(&a, &b).unwrap(),
+            BooleanArray::from(vec![Some(false), Some(false), None, None])
+        );
+        assert_eq!(
+            eq_dyn(&a, &b).unwrap(),
+            BooleanArray::from(vec![Some(false), Some(true), None, None])
+        );
+
+        // different values, fewer than rows, with values in common
+        let values1 = Int32Array::from(vec![Some(3), Some(1), None, Some(5)]);
+        let values2 = Int32Array::from(vec![Some(5), Some(2), Some(3)]);
+        let keys1 = Int16Array::from_iter_values((0..20).map(|i| i % 4));
+        let keys2 = Int16Array::from_iter_values((0..20).map(|i| i % 3));
+        let a = DictionaryArray::try_new(&keys1, &values1).unwrap();
+        let b = DictionaryArray::try_new(&keys2, &values2).unwrap();
+
+        let expected = |op: fn(&i32, &i32) -> bool| -> BooleanArray {
+            let value = |values: &Int32Array, key: i16| {
+                let key = key as usize;
+                values.is_valid(key).then(|| values.value(key))
+            };
+            keys1
+                .values()
+                .iter()
+                .zip(keys2.values())
+                .map(|(l, r)| Some(op(&value(&values1, *l)?, &value(&values2, *r)?)))
+                .collect()
+        };
+        assert_eq!(eq_dyn(&a, &b).unwrap(), expected(i32::eq));
+        assert_eq!(neq_dyn(&a, &b).unwrap(), expected(i32::ne));
+        assert_eq!(lt_dyn(&a, &b).unwrap(), expected(i32::lt));
+        assert_eq!(lt_eq_dyn(&a, &b).unwrap(), expected(i32::le));
+        assert_eq!(gt_dyn(&a, &b).unwrap(), expected(i32::gt));
+        assert_eq!(gt_eq_dyn(&a, &b).unwrap(), expected(i32::ge));
+    }
+
     #[test]
     #[cfg(feature = "dyn_cmp_dict")]
     fn test_eq_dyn_neq_dyn_dictionary_u64_array() {
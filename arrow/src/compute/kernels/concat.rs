@@ -31,26 +31,21 @@
 //! ```
 
 use crate::array::*;
-use crate::datatypes::{DataType, SchemaRef};
+use crate::compute::{cast, take};
+use crate::datatypes::{ArrowNativeType, DataType, Field, Schema, SchemaRef};
+use crate::downcast_dictionary_array;
 use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
-
-fn compute_str_values_length<Offset: OffsetSizeTrait>(arrays: &[&ArrayData]) -> usize {
-    arrays
-        .iter()
-        .map(|&data| {
-            // get the length of the value buffer
-            let buf_len = data.buffers()[1].len();
-            // find the offset of the buffer
-            // this returns a slice of offsets, starting from the offset of the array
-            // so we can take the first value
-            let offset = data.buffer::<Offset>(0)[0];
-            buf_len - offset.to_usize().unwrap()
-        })
-        .sum()
-}
+use crate::row::{RowConverter, SortField};
+use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Concatenate multiple [Array] of the same type into a single [ArrayRef].
+///
+/// Dictionary arrays sharing the same dictionary reuse it. Otherwise their
+/// dictionaries are appended, unless that would overflow the key type or
+/// produce more dictionary values than rows, in which case they are merged
+/// as by [`concat_dictionaries`].
 pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef> {
     if arrays.is_empty() {
         return Err(ArrowError::ComputeError(
@@ -72,29 +67,27 @@ pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef> {
     }
 
     let lengths = arrays.iter().map(|array| array.len()).collect::<Vec<_>>();
-    let capacity = lengths.iter().sum();
+
+    if let DataType::Dictionary(key_type, _) = arrays[0].data_type() {
+        let dictionaries: Vec<_> =
+            arrays.iter().map(|a| &a.data().child_data()[0]).collect();
+        if !dictionaries.windows(2).all(|d| d[0].ptr_eq(d[1])) {
+            let values_len: usize = dictionaries.iter().map(|d| d.len()).sum();
+            let overflow = !key_type_fits(key_type, values_len);
+            if overflow || values_len > lengths.iter().sum() {
+                match concat_dictionaries(arrays, DictionaryOverflow::Error) {
+                    // Values not supported by the row format can still be appended
+                    Err(ArrowError::NotYetImplemented(_)) if !overflow => {}
+                    result => return result,
+                }
+            }
+        }
+    }
 
     let arrays = arrays.iter().map(|a| a.data()).collect::<Vec<_>>();
 
-    let mut mutable = match arrays[0].data_type() {
-        DataType::Utf8 => {
-            let str_values_size = compute_str_values_length::<i32>(&arrays);
-            MutableArrayData::with_capacities(
-                arrays,
-                false,
-                Capacities::Binary(capacity, Some(str_values_size)),
-            )
-        }
-        DataType::LargeUtf8 => {
-            let str_values_size = compute_str_values_length::<i64>(&arrays);
-            MutableArrayData::with_capacities(
-                arrays,
-                false,
-                Capacities::Binary(capacity, Some(str_values_size)),
-            )
-        }
-        _ => MutableArrayData::new(arrays, false, capacity),
-    };
+    let capacities = Capacities::for_arrays(&arrays);
+    let mut mutable = MutableArrayData::with_capacities(arrays, false, capacities);
 
     for (i, len) in lengths.iter().enumerate() {
         mutable.extend(i, 0, *len)
@@ -103,6 +96,181 @@ pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef> {
     Ok(make_array(mutable.freeze()))
 }
 
+/// Defines how [`concat_dictionaries`] handles a merged dictionary with more
+/// values than its key type can index
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DictionaryOverflow {
+    /// Return an error
+    #[default]
+    Error,
+    /// Use the narrowest wider key type of the same signedness that can
+    /// index the merged dictionary
+    WidenKeys,
+    /// Return the values as a plain array instead of a dictionary
+    Unpack,
+}
+
+/// Concatenates multiple dictionary arrays of the same type into a single
+/// dictionary array whose dictionary contains each distinct value of the
+/// input dictionaries once.
+///
+/// Unlike [`concat`], which appends the dictionaries of its inputs, this
+/// deduplicates the values and remaps the keys accordingly. If the merged
+/// dictionary cannot be indexed by the key type, `overflow` defines what is
+/// returned instead.
+///
+/// Supports dictionaries whose values can be converted by
+/// [`RowConverter`](crate::row::RowConverter).
+///
+/// ```
+/// # use arrow::array::{Array, DictionaryArray, StringArray};
+/// # use arrow::compute::{concat_dictionaries, DictionaryOverflow};
+/// # use arrow::datatypes::Int8Type;
+/// let a: DictionaryArray<Int8Type> = vec!["a", "b", "a"].into_iter().collect();
+/// let b: DictionaryArray<Int8Type> = vec!["c", "b"].into_iter().collect();
+///
+/// let merged = concat_dictionaries(&[&a, &b], DictionaryOverflow::Error).unwrap();
+/// let merged = merged
+///     .as_any()
+///     .downcast_ref::<DictionaryArray<Int8Type>>()
+///     .unwrap();
+/// assert_eq!(merged.values().as_ref(), &StringArray::from(vec!["a", "b", "c"]));
+/// assert_eq!(merged.keys().values(), &[0, 1, 0, 2, 1]);
+/// ```
+pub fn concat_dictionaries(
+    arrays: &[&dyn Array],
+    overflow: DictionaryOverflow,
+) -> Result<ArrayRef> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+    let data_type = arrays[0].data_type();
+    let (key_type, value_type) = match data_type {
+        DataType::Dictionary(k, v) => (k.as_ref(), v.as_ref()),
+        t => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "concat_dictionaries requires dictionary arrays, got {}",
+                t
+            )))
+        }
+    };
+    if arrays.iter().any(|array| array.data_type() != data_type) {
+        return Err(ArrowError::InvalidArgumentError(
+            "It is not possible to concatenate arrays of different data types."
+                .to_string(),
+        ));
+    }
+
+    let values = arrays
+        .iter()
+        .map(|&array| {
+            downcast_dictionary_array! {
+                array => array.values().clone(),
+                _ => unreachable!("checked above")
+            }
+        })
+        .collect::<Vec<_>>();
+    let all_values = concat(&values.iter().map(|v| v.as_ref()).collect::<Vec<_>>())?;
+
+    // intern each value by its row encoding, keeping the first occurrence
+    let mut converter = RowConverter::new(vec![SortField::new(value_type.clone())]);
+    let rows = converter.convert_columns(std::slice::from_ref(&all_values))?;
+    let mut interned = HashMap::with_capacity(rows.num_rows());
+    let mut distinct = Vec::new();
+    let mapping = (0..rows.num_rows())
+        .map(|i| {
+            *interned.entry(rows.row(i)).or_insert_with(|| {
+                distinct.push(i as u64);
+                distinct.len() as u64 - 1
+            })
+        })
+        .collect::<Vec<_>>();
+    let merged = take(all_values.as_ref(), &UInt64Array::from(distinct), None)?;
+
+    let mut keys = Vec::with_capacity(arrays.iter().map(|a| a.len()).sum());
+    let mut value_offset = 0;
+    for (&array, values) in arrays.iter().zip(&values) {
+        downcast_dictionary_array! {
+            array => keys.extend(array.keys().iter().map(|key| {
+                key.map(|key| mapping[value_offset + key.as_usize()])
+            })),
+            _ => unreachable!("checked above")
+        }
+        value_offset += values.len();
+    }
+    let keys = UInt64Array::from(keys);
+
+    let key_type = if key_type_fits(key_type, merged.len()) {
+        key_type.clone()
+    } else {
+        match overflow {
+            DictionaryOverflow::Error => {
+                return Err(ArrowError::DictionaryKeyOverflowError);
+            }
+            DictionaryOverflow::WidenKeys => wider_key_type(key_type, merged.len())
+                .ok_or(ArrowError::DictionaryKeyOverflowError)?,
+            DictionaryOverflow::Unpack => {
+                return take(merged.as_ref(), &keys, None);
+            }
+        }
+    };
+
+    let keys = cast(&(Arc::new(keys) as ArrayRef), &key_type)?;
+    let keys = keys.data();
+    let dictionary_type =
+        DataType::Dictionary(Box::new(key_type), Box::new(value_type.clone()));
+    let data = ArrayData::builder(dictionary_type)
+        .len(keys.len())
+        .null_bit_buffer(keys.null_buffer().cloned())
+        .add_buffer(keys.buffers()[0].clone())
+        .add_child_data(merged.into_data())
+        .build()?;
+    Ok(make_array(data))
+}
+
+/// Returns true if a dictionary of `len` values can be indexed by `key_type`
+fn key_type_fits(key_type: &DataType, len: usize) -> bool {
+    let max_key = match key_type {
+        DataType::Int8 => i8::MAX as u64,
+        DataType::Int16 => i16::MAX as u64,
+        DataType::Int32 => i32::MAX as u64,
+        DataType::Int64 => i64::MAX as u64,
+        DataType::UInt8 => u8::MAX as u64,
+        DataType::UInt16 => u16::MAX as u64,
+        DataType::UInt32 => u32::MAX as u64,
+        DataType::UInt64 => u64::MAX,
+        t => unreachable!("unsupported dictionary key type: {}", t),
+    };
+    len == 0 || (len - 1) as u64 <= max_key
+}
+
+/// Returns the narrowest key type with the signedness of `key_type` that can
+/// index a dictionary of `len` values
+fn wider_key_type(key_type: &DataType, len: usize) -> Option<DataType> {
+    let signed = matches!(
+        key_type,
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64
+    );
+    let candidates = if signed {
+        [
+            DataType::Int8,
+            DataType::Int16,
+            DataType::Int32,
+            DataType::Int64,
+        ]
+    } else {
+        [
+            DataType::UInt8,
+            DataType::UInt16,
+            DataType::UInt32,
+            DataType::UInt64,
+        ]
+    };
+    candidates.into_iter().find(|t| key_type_fits(t, len))
+}
+
 /// Concatenates `batches` together into a single record batch.
 pub fn concat_batches(
     schema: &SchemaRef,
@@ -135,10 +303,292 @@ pub fn concat_batches(
     RecordBatch::try_new(schema.clone(), arrays)
 }
 
+/// Options that control how [`concat_batches_with_options`] checks that
+/// the schemas of the input batches are compatible
+#[derive(Debug, Clone, Default)]
+pub struct ConcatBatchesOptions {
+    ignore_metadata: bool,
+    ignore_nullability: bool,
+}
+
+impl ConcatBatchesOptions {
+    /// Create a new [`ConcatBatchesOptions`] requiring field metadata and
+    /// nullability to match exactly
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// If true, differences in field-level metadata between the batches and
+    /// the argument schema are ignored, including those of the fields nested in
+    /// struct, list, map and union types, and the argument schema's field
+    /// metadata is used for the output
+    pub fn with_ignore_metadata(mut self, ignore_metadata: bool) -> Self {
+        self.ignore_metadata = ignore_metadata;
+        self
+    }
+
+    /// If true, differences in field nullability are ignored, including those of
+    /// the fields nested in struct, list, map and union types, and an output
+    /// field is nullable if it is nullable in the argument schema or in any batch
+    pub fn with_ignore_nullability(mut self, ignore_nullability: bool) -> Self {
+        self.ignore_nullability = ignore_nullability;
+        self
+    }
+
+    /// Returns true if field-level metadata differences are ignored
+    pub fn ignore_metadata(&self) -> bool {
+        self.ignore_metadata
+    }
+
+    /// Returns true if field nullability differences are ignored
+    pub fn ignore_nullability(&self) -> bool {
+        self.ignore_nullability
+    }
+}
+
+/// Concatenates `batches` together into a single record batch, checking that
+/// each batch's schema is compatible with `schema` according to `options`.
+///
+/// Unlike [`concat_batches`], the batches' schemas need not be identical:
+/// field names and data types must match, while field metadata and nullability,
+/// at any level of nesting, may optionally differ. The schema-level metadata of `schema` and of every
+/// batch is merged into the output schema, returning an error if the same key
+/// has conflicting values.
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use std::sync::Arc;
+/// # use arrow::array::Int32Array;
+/// # use arrow::compute::{concat_batches_with_options, ConcatBatchesOptions};
+/// # use arrow::datatypes::{DataType, Field, Schema};
+/// # use arrow::record_batch::RecordBatch;
+/// let nullable = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+/// let required = Arc::new(
+///     Schema::new(vec![Field::new("a", DataType::Int32, false)])
+///         .with_metadata(HashMap::from([("k".to_string(), "v".to_string())])),
+/// );
+///
+/// let a = RecordBatch::try_new(nullable.clone(), vec![Arc::new(Int32Array::from(vec![None, Some(1)]))]).unwrap();
+/// let b = RecordBatch::try_new(required, vec![Arc::new(Int32Array::from(vec![2]))]).unwrap();
+///
+/// let options = ConcatBatchesOptions::new().with_ignore_nullability(true);
+/// let batch = concat_batches_with_options(&nullable, &[a, b], &options).unwrap();
+/// assert_eq!(batch.num_rows(), 3);
+/// assert_eq!(batch.schema().metadata()["k"], "v");
+/// ```
+pub fn concat_batches_with_options(
+    schema: &SchemaRef,
+    batches: &[RecordBatch],
+    options: &ConcatBatchesOptions,
+) -> Result<RecordBatch> {
+    let mut fields = schema.fields().clone();
+    let mut metadata = schema.metadata().clone();
+
+    for (i, batch) in batches.iter().enumerate() {
+        let batch_schema = batch.schema();
+        if batch_schema.fields().len() != fields.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "batches[{}] has {} columns but argument schema has {}",
+                i,
+                batch_schema.fields().len(),
+                fields.len()
+            )));
+        }
+
+        for (field, batch_field) in fields.iter_mut().zip(batch_schema.fields()) {
+            *field = merge_field(i, field, batch_field, options)?;
+        }
+
+        for (key, value) in batch_schema.metadata() {
+            match metadata.get(key) {
+                Some(existing) if existing != value => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "batches[{}] has conflicting metadata. Key '{}' has different values '{}' and '{}'",
+                        i, key, existing, value
+                    )));
+                }
+                Some(_) => {}
+                None => {
+                    metadata.insert(key.clone(), value.clone());
+                }
+            }
+        }
+    }
+
+    let schema = Arc::new(Schema::new_with_metadata(fields, metadata));
+    if batches.is_empty() {
+        return Ok(RecordBatch::new_empty(schema));
+    }
+
+    let arrays = schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            // the nested fields of the batches may differ from those of the output
+            let columns = batches
+                .iter()
+                .map(
+                    |batch| match batch.column(i).data_type() == field.data_type() {
+                        true => Ok(batch.column(i).clone()),
+                        false => {
+                            let data = with_data_type(
+                                batch.column(i).data(),
+                                field.data_type(),
+                            )?;
+                            Ok(make_array(data))
+                        }
+                    },
+                )
+                .collect::<Result<Vec<_>>>()?;
+            concat(&columns.iter().map(|c| c.as_ref()).collect::<Vec<_>>())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    RecordBatch::try_new(schema, arrays)
+}
+
+/// Returns `field` merged with `batch_field`, the field of the same column of
+/// `batches[batch_idx]`, or an error if they aren't compatible according to
+/// `options`. The fields of nested data types are compared recursively
+fn merge_field(
+    batch_idx: usize,
+    field: &Field,
+    batch_field: &Field,
+    options: &ConcatBatchesOptions,
+) -> Result<Field> {
+    let mismatch = |what: &str| {
+        ArrowError::InvalidArgumentError(format!(
+            "batches[{}] field '{}' has different {} than argument schema field '{}'",
+            batch_idx,
+            batch_field.name(),
+            what,
+            field.name()
+        ))
+    };
+
+    if field.name() != batch_field.name() {
+        return Err(mismatch("name"));
+    }
+    let data_type = merge_data_type(
+        batch_idx,
+        field.data_type(),
+        batch_field.data_type(),
+        options,
+    )?
+    .ok_or_else(|| mismatch("data type"))?;
+    if !options.ignore_nullability && field.is_nullable() != batch_field.is_nullable() {
+        return Err(mismatch("nullability"));
+    }
+    if !options.ignore_metadata && field.metadata() != batch_field.metadata() {
+        return Err(mismatch("metadata"));
+    }
+
+    let nullable = field.is_nullable() || batch_field.is_nullable();
+    Ok(field
+        .clone()
+        .with_data_type(data_type)
+        .with_nullable(nullable))
+}
+
+/// Returns `data_type` merged with `batch_type` as by [`merge_field`], or `None`
+/// if they are different data types
+fn merge_data_type(
+    batch_idx: usize,
+    data_type: &DataType,
+    batch_type: &DataType,
+    options: &ConcatBatchesOptions,
+) -> Result<Option<DataType>> {
+    let merge = |field: &Field, batch_field: &Field| {
+        merge_field(batch_idx, field, batch_field, options).map(Box::new)
+    };
+    Ok(Some(match (data_type, batch_type) {
+        (DataType::List(field), DataType::List(batch_field)) => {
+            DataType::List(merge(field, batch_field)?)
+        }
+        (DataType::LargeList(field), DataType::LargeList(batch_field)) => {
+            DataType::LargeList(merge(field, batch_field)?)
+        }
+        (
+            DataType::FixedSizeList(field, size),
+            DataType::FixedSizeList(batch_field, s),
+        ) if size == s => DataType::FixedSizeList(merge(field, batch_field)?, *size),
+        (DataType::Map(field, sorted), DataType::Map(batch_field, s)) if sorted == s => {
+            DataType::Map(merge(field, batch_field)?, *sorted)
+        }
+        (DataType::Struct(fields), DataType::Struct(batch_fields))
+            if fields.len() == batch_fields.len() =>
+        {
+            let fields = fields
+                .iter()
+                .zip(batch_fields)
+                .map(|(field, batch_field)| {
+                    merge_field(batch_idx, field, batch_field, options)
+                })
+                .collect::<Result<_>>()?;
+            DataType::Struct(fields)
+        }
+        (
+            DataType::Union(fields, type_ids, mode),
+            DataType::Union(batch_fields, batch_type_ids, batch_mode),
+        ) if fields.len() == batch_fields.len()
+            && type_ids == batch_type_ids
+            && mode == batch_mode =>
+        {
+            let fields = fields
+                .iter()
+                .zip(batch_fields)
+                .map(|(field, batch_field)| {
+                    merge_field(batch_idx, field, batch_field, options)
+                })
+                .collect::<Result<_>>()?;
+            DataType::Union(fields, type_ids.clone(), mode.clone())
+        }
+        (
+            DataType::Dictionary(key, value),
+            DataType::Dictionary(batch_key, batch_value),
+        ) if key == batch_key => {
+            match merge_data_type(batch_idx, value, batch_value, options)? {
+                Some(value) => DataType::Dictionary(key.clone(), Box::new(value)),
+                None => return Ok(None),
+            }
+        }
+        (data_type, batch_type) if data_type == batch_type => data_type.clone(),
+        _ => return Ok(None),
+    }))
+}
+
+/// Returns `data` with the data type `data_type`, which must only differ from
+/// its own in the nullability and metadata of nested fields
+fn with_data_type(data: &ArrayData, data_type: &DataType) -> Result<ArrayData> {
+    let child_types: Vec<&DataType> = match data_type {
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => vec![field.data_type()],
+        DataType::Struct(fields) | DataType::Union(fields, _, _) => {
+            fields.iter().map(|field| field.data_type()).collect()
+        }
+        DataType::Dictionary(_, value) => vec![value.as_ref()],
+        _ => vec![],
+    };
+    let child_data = data
+        .child_data()
+        .iter()
+        .zip(child_types)
+        .map(|(child, child_type)| with_data_type(child, child_type))
+        .collect::<Result<Vec<_>>>()?;
+    data.clone()
+        .into_builder()
+        .data_type(data_type.clone())
+        .child_data(child_data)
+        .build()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::datatypes::*;
+    use std::collections::{BTreeMap, HashMap};
     use std::sync::Arc;
 
     #[test]
@@ -603,6 +1053,130 @@ mod tests {
         assert!(!new.data().child_data()[0].ptr_eq(&combined.data().child_data()[0]));
     }
 
+    #[test]
+    fn test_concat_dictionaries() {
+        let a: DictionaryArray<Int8Type> = vec![Some("a"), None, Some("b"), Some("a")]
+            .into_iter()
+            .collect();
+        let b: DictionaryArray<Int8Type> =
+            vec![Some("c"), Some("b"), None].into_iter().collect();
+        let b = b.slice(1, 2);
+
+        let merged =
+            concat_dictionaries(&[&a, b.as_ref()], DictionaryOverflow::Error).unwrap();
+        let merged = as_dictionary_array::<Int8Type>(merged.as_ref());
+        assert_eq!(
+            merged.values().as_ref(),
+            &StringArray::from(vec!["a", "b", "c"])
+        );
+        assert_eq!(
+            merged.keys(),
+            &Int8Array::from(vec![Some(0), None, Some(1), Some(0), Some(1), None])
+        );
+    }
+
+    #[test]
+    fn test_concat_dictionaries_null_values() {
+        let values = Int32Array::from(vec![Some(1), None, Some(2)]);
+        let keys = UInt8Array::from(vec![0, 1, 2, 1]);
+        let a = DictionaryArray::<UInt8Type>::try_new(&keys, &values).unwrap();
+        let values = Int32Array::from(vec![None, Some(2)]);
+        let keys = UInt8Array::from(vec![1, 0]);
+        let b = DictionaryArray::<UInt8Type>::try_new(&keys, &values).unwrap();
+
+        let merged = concat_dictionaries(&[&a, &b], DictionaryOverflow::Error).unwrap();
+        let merged = as_dictionary_array::<UInt8Type>(merged.as_ref());
+        assert_eq!(
+            merged.values().as_ref(),
+            &Int32Array::from(vec![Some(1), None, Some(2)])
+        );
+        assert_eq!(merged.keys(), &UInt8Array::from(vec![0, 1, 2, 1, 2, 1]));
+    }
+
+    /// Returns a dictionary of the distinct strings of `range`
+    fn range_dictionary(range: std::ops::Range<i32>) -> DictionaryArray<Int8Type> {
+        let values: StringArray = range.clone().map(|i| Some(i.to_string())).collect();
+        let keys: Int8Array = (0..range.len()).map(|i| Some(i as i8)).collect();
+        DictionaryArray::try_new(&keys, &values).unwrap()
+    }
+
+    #[test]
+    fn test_concat_dictionaries_overflow() {
+        let a = range_dictionary(0..100);
+        let b = range_dictionary(50..150);
+        let inputs = [&a as &dyn Array, &b as &dyn Array];
+
+        let err = concat_dictionaries(&inputs, DictionaryOverflow::Error).unwrap_err();
+        assert!(matches!(err, ArrowError::DictionaryKeyOverflowError));
+
+        let widened =
+            concat_dictionaries(&inputs, DictionaryOverflow::WidenKeys).unwrap();
+        let widened = as_dictionary_array::<Int16Type>(widened.as_ref());
+        assert_eq!(widened.values().len(), 150);
+        assert_eq!(widened.keys().value(100), 50);
+        assert_eq!(widened.keys().value(199), 149);
+
+        let unpacked = concat_dictionaries(&inputs, DictionaryOverflow::Unpack).unwrap();
+        let expected: StringArray = (0..100)
+            .chain(50..150)
+            .map(|i| Some(i.to_string()))
+            .collect();
+        assert_eq!(unpacked.as_ref(), &expected);
+
+        // an exactly full dictionary is not an overflow
+        let a = range_dictionary(0..128);
+        let merged = concat_dictionaries(&[&a, &a], DictionaryOverflow::Error).unwrap();
+        assert_eq!(merged.len(), 256);
+    }
+
+    #[test]
+    fn test_concat_merges_dictionaries() {
+        // appending the dictionaries would need 170 values, merging them 120
+        let a = range_dictionary(0..100);
+        let b = range_dictionary(50..120);
+        let combined = concat(&[&a, &b]).unwrap();
+        let combined = as_dictionary_array::<Int8Type>(combined.as_ref());
+        assert_eq!(combined.values().len(), 120);
+        let expected: StringArray = (0..100)
+            .chain(50..120)
+            .map(|i| Some(i.to_string()))
+            .collect();
+        let values = take(combined.values().as_ref(), combined.keys(), None).unwrap();
+        assert_eq!(values.as_ref(), &expected);
+
+        let b = range_dictionary(50..150);
+        let err = concat(&[&a, &b]).unwrap_err();
+        assert!(matches!(err, ArrowError::DictionaryKeyOverflowError));
+
+        // dictionaries with more values than rows are merged
+        let a = range_dictionary(0..20).slice(0, 2);
+        let b = range_dictionary(0..20).slice(5, 1);
+        let combined = concat(&[a.as_ref(), b.as_ref()]).unwrap();
+        let combined = as_dictionary_array::<Int8Type>(combined.as_ref());
+        assert_eq!(combined.values().len(), 20);
+        assert_eq!(combined.keys(), &Int8Array::from(vec![0, 1, 5]));
+
+        // small dictionaries are still appended
+        let a: DictionaryArray<Int8Type> = vec!["a", "b", "a"].into_iter().collect();
+        let b: DictionaryArray<Int8Type> = vec!["a", "c"].into_iter().collect();
+        let combined = concat(&[&a, &b]).unwrap();
+        let combined = as_dictionary_array::<Int8Type>(combined.as_ref());
+        assert_eq!(combined.values().len(), 4);
+        assert_eq!(combined.keys(), &Int8Array::from(vec![0, 1, 0, 2, 3]));
+    }
+
+    #[test]
+    fn test_concat_dictionaries_errors() {
+        let a: DictionaryArray<Int8Type> = vec!["a"].into_iter().collect();
+        let b: DictionaryArray<Int16Type> = vec!["a"].into_iter().collect();
+        let err = concat_dictionaries(&[&a, &b], DictionaryOverflow::Error).unwrap_err();
+        assert!(err.to_string().contains("different data types"), "{}", err);
+
+        let c = Int32Array::from(vec![1]);
+        let err = concat_dictionaries(&[&c], DictionaryOverflow::Error).unwrap_err();
+        assert!(err.to_string().contains("requires dictionary"), "{}", err);
+    }
+
     #[test]
     fn concat_record_batches() {
         let schema = Arc::new(Schema::new(vec![
@@ -674,4 +1248,184 @@ mod tests {
             "Invalid argument error: batches[1] schema is different with argument schema.",
         );
     }
+
+    #[test]
+    fn concat_record_batches_with_options() {
+        let field_metadata = BTreeMap::from([("field".to_string(), "meta".to_string())]);
+        let schema = Arc::new(
+            Schema::new(vec![Field::new("a", DataType::Int32, false)])
+                .with_metadata(HashMap::from([("a".to_string(), "1".to_string())])),
+        );
+        let other = Arc::new(
+            Schema::new(vec![Field::new("a", DataType::Int32, true)
+                .with_metadata(Some(field_metadata))])
+            .with_metadata(HashMap::from([("b".to_string(), "2".to_string())])),
+        );
+        let batch1 = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![1, 2]))],
+        )
+        .unwrap();
+        let batch2 = RecordBatch::try_new(
+            other,
+            vec![Arc::new(Int32Array::from(vec![Some(3), None]))],
+        )
+        .unwrap();
+        let batches = [batch1, batch2];
+
+        let strict = ConcatBatchesOptions::new();
+        let error = concat_batches_with_options(&schema, &batches, &strict).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: batches[1] field 'a' has different nullability than argument schema field 'a'"
+        );
+
+        let options = strict.with_ignore_nullability(true);
+        let error = concat_batches_with_options(&schema, &batches, &options).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: batches[1] field 'a' has different metadata than argument schema field 'a'"
+        );
+
+        let options = options.with_ignore_metadata(true);
+        let batch = concat_batches_with_options(&schema, &batches, &options).unwrap();
+        let expected = Int32Array::from(vec![Some(1), Some(2), Some(3), None]);
+        assert_eq!(batch.column(0).as_ref(), &expected as &dyn Array);
+
+        let out_schema = batch.schema();
+        let field = out_schema.field(0);
+        assert!(field.is_nullable());
+        assert!(field.metadata().is_none());
+        assert_eq!(
+            out_schema.metadata(),
+            &HashMap::from([
+                ("a".to_string(), "1".to_string()),
+                ("b".to_string(), "2".to_string())
+            ])
+        );
+
+        // Empty input keeps the argument schema
+        let batch = concat_batches_with_options(&schema, &[], &options).unwrap();
+        assert_eq!(batch.schema(), schema);
+        assert_eq!(batch.num_rows(), 0);
+    }
+
+    #[test]
+    fn concat_record_batches_with_options_nested() {
+        // a batch of a column `s: {l: [item]}` with a single list of `values`
+        let nested_batch = |item: Field, values: Vec<Option<i32>>| {
+            let offsets =
+                crate::buffer::Buffer::from_slice_ref(&[0, values.len() as i32]);
+            let values = Int32Array::from(values);
+            let list = ArrayData::builder(DataType::List(Box::new(item)))
+                .len(1)
+                .add_buffer(offsets)
+                .add_child_data(values.data().clone())
+                .build()
+                .unwrap();
+            let list = make_array(list);
+            let field = Field::new("l", list.data_type().clone(), true);
+            let s = Arc::new(StructArray::from(vec![(field, list)])) as ArrayRef;
+            RecordBatch::try_from_iter([("s", s)]).unwrap()
+        };
+        let field_metadata = BTreeMap::from([("field".to_string(), "meta".to_string())]);
+        let batch1 =
+            nested_batch(Field::new("item", DataType::Int32, false), vec![Some(1)]);
+        let item =
+            Field::new("item", DataType::Int32, true).with_metadata(Some(field_metadata));
+        let batch2 = nested_batch(item, vec![Some(2), None]);
+        let schema = batch1.schema();
+        let batches = [batch1, batch2];
+
+        let options = ConcatBatchesOptions::new();
+        let error = concat_batches_with_options(&schema, &batches, &options).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: batches[1] field 'item' has different nullability than argument schema field 'item'"
+        );
+
+        let options = options.with_ignore_nullability(true);
+        let error = concat_batches_with_options(&schema, &batches, &options).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: batches[1] field 'item' has different metadata than argument schema field 'item'"
+        );
+
+        let options = options.with_ignore_metadata(true);
+        let batch = concat_batches_with_options(&schema, &batches, &options).unwrap();
+        let item = Field::new("item", DataType::Int32, true);
+        let expected = nested_batch(item, vec![Some(1), Some(2), None]);
+        assert_eq!(batch.schema(), expected.schema());
+        let s = as_struct_array(batch.column(0));
+        let l = as_list_array(s.column(0));
+        assert_eq!(l.len(), 2);
+        assert_eq!(
+            l.values().as_ref(),
+            &Int32Array::from(vec![Some(1), Some(2), None]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn concat_record_batches_with_options_incompatible() {
+        let schema = Arc::new(
+            Schema::new(vec![Field::new("a", DataType::Int32, false)])
+                .with_metadata(HashMap::from([("k".to_string(), "1".to_string())])),
+        );
+        let options = ConcatBatchesOptions::new()
+            .with_ignore_metadata(true)
+            .with_ignore_nullability(true);
+
+        let conflicting = Arc::new(
+            Schema::new(vec![Field::new("a", DataType::Int32, false)])
+                .with_metadata(HashMap::from([("k".to_string(), "2".to_string())])),
+        );
+        let batch =
+            RecordBatch::try_new(conflicting, vec![Arc::new(Int32Array::from(vec![1]))])
+                .unwrap();
+        let error = concat_batches_with_options(&schema, &[batch], &options).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: batches[0] has conflicting metadata. Key 'k' has different values '1' and '2'"
+        );
+
+        let renamed =
+            Arc::new(Schema::new(vec![Field::new("b", DataType::Int32, false)]));
+        let batch =
+            RecordBatch::try_new(renamed, vec![Arc::new(Int32Array::from(vec![1]))])
+                .unwrap();
+        let error = concat_batches_with_options(&schema, &[batch], &options).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: batches[0] field 'b' has different name than argument schema field 'a'"
+        );
+
+        let retyped =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let batch =
+            RecordBatch::try_new(retyped, vec![Arc::new(Int64Array::from(vec![1]))])
+                .unwrap();
+        let error = concat_batches_with_options(&schema, &[batch], &options).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: batches[0] field 'a' has different data type than argument schema field 'a'"
+        );
+
+        let wider = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            wider,
+            vec![
+                Arc::new(Int32Array::from(vec![1])),
+                Arc::new(Int32Array::from(vec![2])),
+            ],
+        )
+        .unwrap();
+        let error = concat_batches_with_options(&schema, &[batch], &options).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid argument error: batches[0] has 2 columns but argument schema has 1"
+        );
+    }
 }
@@ -0,0 +1,572 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines [`Datum`], either an array or a scalar, and the comparison and
+//! arithmetic kernels that accept it
+//!
+//! These allow expressions mixing columns and literals to be evaluated
+//! without expanding the literals into arrays of the length of the columns.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow::array::{ArrayRef, BooleanArray, Int32Array};
+//! # use arrow::compute::kernels::datum::{add_datum, gt_datum, Datum};
+//! let a = Datum::Array(Arc::new(Int32Array::from(vec![1, 2, 3])));
+//! let one = Datum::try_new_scalar(Arc::new(Int32Array::from(vec![1]))).unwrap();
+//!
+//! // a + 1 > 3
+//! let sum = add_datum(&a, &one).unwrap();
+//! let three = Datum::try_new_scalar(Arc::new(Int32Array::from(vec![3]))).unwrap();
+//! let result = gt_datum(&sum, &three).unwrap().into_array(3).unwrap();
+//! assert_eq!(result.as_ref(), &BooleanArray::from(vec![false, false, true]));
+//! ```
+
+use std::sync::Arc;
+
+use num::Zero;
+
+use crate::array::*;
+use crate::compute::kernels::arithmetic::{
+    add_dyn, add_scalar_dyn, divide_dyn, divide_scalar_dyn, multiply_dyn,
+    multiply_scalar_dyn, subtract_dyn, subtract_scalar_dyn,
+};
+use crate::compute::kernels::arity::{try_unary_dyn, unary_dyn};
+use crate::compute::kernels::comparison::*;
+use crate::compute::kernels::take::take;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+
+/// Either an array with one value per row, or a scalar shared by all rows
+#[derive(Debug, Clone)]
+pub enum Datum {
+    /// An array with one value per row
+    Array(ArrayRef),
+    /// A single value shared by all rows, stored as an array of length one
+    Scalar(ArrayRef),
+}
+
+impl Datum {
+    /// Creates a [`Datum::Scalar`] of the single value of `scalar`
+    ///
+    /// Returns an error if `scalar` does not have a length of one
+    pub fn try_new_scalar(scalar: ArrayRef) -> Result<Self> {
+        match scalar.len() {
+            1 => Ok(Self::Scalar(scalar)),
+            len => Err(ArrowError::InvalidArgumentError(format!(
+                "A scalar must contain exactly one value, got {}",
+                len
+            ))),
+        }
+    }
+
+    /// Returns the data type of this [`Datum`]
+    pub fn data_type(&self) -> &DataType {
+        match self {
+            Self::Array(array) | Self::Scalar(array) => array.data_type(),
+        }
+    }
+
+    /// Returns `true` if this is a [`Datum::Scalar`]
+    pub fn is_scalar(&self) -> bool {
+        matches!(self, Self::Scalar(_))
+    }
+
+    /// Returns this [`Datum`] as an array, repeating a scalar `len` times
+    pub fn into_array(self, len: usize) -> Result<ArrayRef> {
+        match self {
+            Self::Array(array) => Ok(array),
+            Self::Scalar(scalar) => {
+                let indices = UInt32Array::from(vec![0; len]);
+                take(scalar.as_ref(), &indices, None)
+            }
+        }
+    }
+}
+
+impl From<ArrayRef> for Datum {
+    fn from(array: ArrayRef) -> Self {
+        Self::Array(array)
+    }
+}
+
+/// Returns the type of the values of `array`, i.e. the value type of a dictionary
+fn value_type(array: &dyn Array) -> &DataType {
+    match array.data_type() {
+        DataType::Dictionary(_, values) => values.as_ref(),
+        t => t,
+    }
+}
+
+/// Calls `$f::<T>($($arg,)* value)` with the primitive type `T` and `value` of a
+/// numeric `$scalar`, or evaluates `$fallback` for other types
+macro_rules! dispatch_numeric {
+    ($scalar:expr, $f:ident($($arg:expr),*), $fallback:expr) => {{
+        let scalar = $scalar;
+        match scalar.data_type() {
+            DataType::Int8 => {
+                let value = as_primitive_array::<Int8Type>(scalar).value(0);
+                $f::<Int8Type>($($arg,)* value)
+            }
+            DataType::Int16 => {
+                let value = as_primitive_array::<Int16Type>(scalar).value(0);
+                $f::<Int16Type>($($arg,)* value)
+            }
+            DataType::Int32 => {
+                let value = as_primitive_array::<Int32Type>(scalar).value(0);
+                $f::<Int32Type>($($arg,)* value)
+            }
+            DataType::Int64 => {
+                let value = as_primitive_array::<Int64Type>(scalar).value(0);
+                $f::<Int64Type>($($arg,)* value)
+            }
+            DataType::UInt8 => {
+                let value = as_primitive_array::<UInt8Type>(scalar).value(0);
+                $f::<UInt8Type>($($arg,)* value)
+            }
+            DataType::UInt16 => {
+                let value = as_primitive_array::<UInt16Type>(scalar).value(0);
+                $f::<UInt16Type>($($arg,)* value)
+            }
+            DataType::UInt32 => {
+                let value = as_primitive_array::<UInt32Type>(scalar).value(0);
+                $f::<UInt32Type>($($arg,)* value)
+            }
+            DataType::UInt64 => {
+                let value = as_primitive_array::<UInt64Type>(scalar).value(0);
+                $f::<UInt64Type>($($arg,)* value)
+            }
+            DataType::Float32 => {
+                let value = as_primitive_array::<Float32Type>(scalar).value(0);
+                $f::<Float32Type>($($arg,)* value)
+            }
+            DataType::Float64 => {
+                let value = as_primitive_array::<Float64Type>(scalar).value(0);
+                $f::<Float64Type>($($arg,)* value)
+            }
+            _ => $fallback,
+        }
+    }};
+}
+
+/// A comparison evaluated by [`compare_datum`]
+#[derive(Debug, Clone, Copy)]
+enum Comparison {
+    Eq,
+    Neq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+impl Comparison {
+    /// Returns the comparison with its operands swapped
+    fn flip(self) -> Self {
+        match self {
+            Self::Eq => Self::Eq,
+            Self::Neq => Self::Neq,
+            Self::Lt => Self::Gt,
+            Self::LtEq => Self::GtEq,
+            Self::Gt => Self::Lt,
+            Self::GtEq => Self::LtEq,
+        }
+    }
+
+    fn arrays(self, left: &dyn Array, right: &dyn Array) -> Result<BooleanArray> {
+        match self {
+            Self::Eq => eq_dyn(left, right),
+            Self::Neq => neq_dyn(left, right),
+            Self::Lt => lt_dyn(left, right),
+            Self::LtEq => lt_eq_dyn(left, right),
+            Self::Gt => gt_dyn(left, right),
+            Self::GtEq => gt_eq_dyn(left, right),
+        }
+    }
+
+    fn utf8(self, left: &dyn Array, right: &str) -> Result<BooleanArray> {
+        match self {
+            Self::Eq => eq_dyn_utf8_scalar(left, right),
+            Self::Neq => neq_dyn_utf8_scalar(left, right),
+            Self::Lt => lt_dyn_utf8_scalar(left, right),
+            Self::LtEq => lt_eq_dyn_utf8_scalar(left, right),
+            Self::Gt => gt_dyn_utf8_scalar(left, right),
+            Self::GtEq => gt_eq_dyn_utf8_scalar(left, right),
+        }
+    }
+
+    fn binary(self, left: &dyn Array, right: &[u8]) -> Result<BooleanArray> {
+        match self {
+            Self::Eq => eq_dyn_binary_scalar(left, right),
+            Self::Neq => neq_dyn_binary_scalar(left, right),
+            Self::Lt => lt_dyn_binary_scalar(left, right),
+            Self::LtEq => lt_eq_dyn_binary_scalar(left, right),
+            Self::Gt => gt_dyn_binary_scalar(left, right),
+            Self::GtEq => gt_eq_dyn_binary_scalar(left, right),
+        }
+    }
+
+    fn boolean(self, left: &dyn Array, right: bool) -> Result<BooleanArray> {
+        match self {
+            Self::Eq => eq_dyn_bool_scalar(left, right),
+            Self::Neq => neq_dyn_bool_scalar(left, right),
+            Self::Lt => lt_dyn_bool_scalar(left, right),
+            Self::LtEq => lt_eq_dyn_bool_scalar(left, right),
+            Self::Gt => gt_dyn_bool_scalar(left, right),
+            Self::GtEq => gt_eq_dyn_bool_scalar(left, right),
+        }
+    }
+}
+
+fn compare_numeric<T>(
+    op: Comparison,
+    left: &dyn Array,
+    right: T::Native,
+) -> Result<BooleanArray>
+where
+    T: ArrowPrimitiveType,
+    T::Native: num::ToPrimitive + std::fmt::Debug,
+{
+    match op {
+        Comparison::Eq => eq_dyn_scalar(left, right),
+        Comparison::Neq => neq_dyn_scalar(left, right),
+        Comparison::Lt => lt_dyn_scalar(left, right),
+        Comparison::LtEq => lt_eq_dyn_scalar(left, right),
+        Comparison::Gt => gt_dyn_scalar(left, right),
+        Comparison::GtEq => gt_eq_dyn_scalar(left, right),
+    }
+}
+
+/// Compares each value of `array` with the single value of `scalar`
+fn compare_scalar(
+    op: Comparison,
+    array: &dyn Array,
+    scalar: &dyn Array,
+) -> Result<BooleanArray> {
+    let fallback = || {
+        let scalar = Datum::Scalar(make_array(scalar.data().clone()));
+        op.arrays(array, scalar.into_array(array.len())?.as_ref())
+    };
+    if value_type(array) != scalar.data_type() {
+        return fallback();
+    }
+    if scalar.is_null(0) {
+        return Ok(BooleanArray::from(vec![None; array.len()]));
+    }
+
+    match scalar.data_type() {
+        DataType::Utf8 => op.utf8(array, as_string_array(scalar).value(0)),
+        DataType::LargeUtf8 => op.utf8(array, as_largestring_array(scalar).value(0)),
+        DataType::Binary => {
+            op.binary(array, as_generic_binary_array::<i32>(scalar).value(0))
+        }
+        DataType::LargeBinary => {
+            op.binary(array, as_generic_binary_array::<i64>(scalar).value(0))
+        }
+        DataType::Boolean if array.data_type() == &DataType::Boolean => {
+            op.boolean(array, as_boolean_array(scalar).value(0))
+        }
+        _ => dispatch_numeric!(scalar, compare_numeric(op, array), fallback()),
+    }
+}
+
+fn compare_datum(left: &Datum, op: Comparison, right: &Datum) -> Result<Datum> {
+    Ok(match (left, right) {
+        (Datum::Array(l), Datum::Array(r)) => {
+            Datum::Array(Arc::new(op.arrays(l.as_ref(), r.as_ref())?))
+        }
+        (Datum::Scalar(l), Datum::Scalar(r)) => {
+            Datum::Scalar(Arc::new(op.arrays(l.as_ref(), r.as_ref())?))
+        }
+        (Datum::Array(l), Datum::Scalar(r)) => {
+            Datum::Array(Arc::new(compare_scalar(op, l.as_ref(), r.as_ref())?))
+        }
+        (Datum::Scalar(l), Datum::Array(r)) => {
+            Datum::Array(Arc::new(compare_scalar(op.flip(), r.as_ref(), l.as_ref())?))
+        }
+    })
+}
+
+/// Perform `left == right` on two [`Datum`], see [`eq_dyn`]
+///
+/// Comparing an array with a scalar uses the scalar kernels, such as
+/// [`eq_dyn_scalar`], where the scalar is numeric, a string, binary or boolean,
+/// and its type is that of the values of the array.
+pub fn eq_datum(left: &Datum, right: &Datum) -> Result<Datum> {
+    compare_datum(left, Comparison::Eq, right)
+}
+
+/// Perform `left != right` on two [`Datum`], see [`eq_datum`]
+pub fn neq_datum(left: &Datum, right: &Datum) -> Result<Datum> {
+    compare_datum(left, Comparison::Neq, right)
+}
+
+/// Perform `left < right` on two [`Datum`], see [`eq_datum`]
+pub fn lt_datum(left: &Datum, right: &Datum) -> Result<Datum> {
+    compare_datum(left, Comparison::Lt, right)
+}
+
+/// Perform `left <= right` on two [`Datum`], see [`eq_datum`]
+pub fn lt_eq_datum(left: &Datum, right: &Datum) -> Result<Datum> {
+    compare_datum(left, Comparison::LtEq, right)
+}
+
+/// Perform `left > right` on two [`Datum`], see [`eq_datum`]
+pub fn gt_datum(left: &Datum, right: &Datum) -> Result<Datum> {
+    compare_datum(left, Comparison::Gt, right)
+}
+
+/// Perform `left >= right` on two [`Datum`], see [`eq_datum`]
+pub fn gt_eq_datum(left: &Datum, right: &Datum) -> Result<Datum> {
+    compare_datum(left, Comparison::GtEq, right)
+}
+
+/// An arithmetic operation evaluated by [`arithmetic_datum`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Arithmetic {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Arithmetic {
+    fn arrays(self, left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+        match self {
+            Self::Add => add_dyn(left, right),
+            Self::Subtract => subtract_dyn(left, right),
+            Self::Multiply => multiply_dyn(left, right),
+            Self::Divide => divide_dyn(left, right),
+        }
+    }
+}
+
+/// Applies `op` to each value of `array` and `scalar`, with `scalar` as the
+/// left operand if `scalar_left`
+fn arithmetic_numeric<T>(
+    op: Arithmetic,
+    array: &dyn Array,
+    scalar_left: bool,
+    scalar: T::Native,
+) -> Result<ArrayRef>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp + Zero,
+{
+    match (op, scalar_left) {
+        (Arithmetic::Add, _) => add_scalar_dyn::<T>(array, scalar),
+        (Arithmetic::Multiply, _) => multiply_scalar_dyn::<T>(array, scalar),
+        (Arithmetic::Subtract, false) => subtract_scalar_dyn::<T>(array, scalar),
+        (Arithmetic::Subtract, true) => {
+            unary_dyn::<_, T>(array, |value| scalar.sub_wrapping(value))
+        }
+        (Arithmetic::Divide, false) => divide_scalar_dyn::<T>(array, scalar),
+        (Arithmetic::Divide, true) => try_unary_dyn::<_, T>(array, |value| {
+            if value.is_zero() {
+                Err(ArrowError::DivideByZero)
+            } else {
+                Ok(scalar.div_wrapping(value))
+            }
+        }),
+    }
+}
+
+/// Applies `op` to each value of `array` and the single value of `scalar`
+fn arithmetic_scalar(
+    op: Arithmetic,
+    array: &dyn Array,
+    scalar_left: bool,
+    scalar: &dyn Array,
+) -> Result<ArrayRef> {
+    let fallback = || {
+        let scalar = Datum::Scalar(make_array(scalar.data().clone()));
+        let scalar = scalar.into_array(array.len())?;
+        match scalar_left {
+            true => op.arrays(scalar.as_ref(), array),
+            false => op.arrays(array, scalar.as_ref()),
+        }
+    };
+    if value_type(array) != scalar.data_type() {
+        return fallback();
+    }
+    if scalar.is_null(0) {
+        return Ok(new_null_array(array.data_type(), array.len()));
+    }
+    dispatch_numeric!(
+        scalar,
+        arithmetic_numeric(op, array, scalar_left),
+        fallback()
+    )
+}
+
+fn arithmetic_datum(left: &Datum, op: Arithmetic, right: &Datum) -> Result<Datum> {
+    Ok(match (left, right) {
+        (Datum::Array(l), Datum::Array(r)) => {
+            Datum::Array(op.arrays(l.as_ref(), r.as_ref())?)
+        }
+        (Datum::Scalar(l), Datum::Scalar(r)) => {
+            Datum::Scalar(op.arrays(l.as_ref(), r.as_ref())?)
+        }
+        (Datum::Array(l), Datum::Scalar(r)) => {
+            Datum::Array(arithmetic_scalar(op, l.as_ref(), false, r.as_ref())?)
+        }
+        (Datum::Scalar(l), Datum::Array(r)) => {
+            Datum::Array(arithmetic_scalar(op, r.as_ref(), true, l.as_ref())?)
+        }
+    })
+}
+
+/// Perform `left + right` on two [`Datum`], see [`add_dyn`]
+///
+/// Adding a numeric scalar to an array of the same type, or a dictionary
+/// array with values of that type, uses [`add_scalar_dyn`].
+pub fn add_datum(left: &Datum, right: &Datum) -> Result<Datum> {
+    arithmetic_datum(left, Arithmetic::Add, right)
+}
+
+/// Perform `left - right` on two [`Datum`], see [`subtract_dyn`] and [`add_datum`]
+pub fn subtract_datum(left: &Datum, right: &Datum) -> Result<Datum> {
+    arithmetic_datum(left, Arithmetic::Subtract, right)
+}
+
+/// Perform `left * right` on two [`Datum`], see [`multiply_dyn`] and [`add_datum`]
+pub fn multiply_datum(left: &Datum, right: &Datum) -> Result<Datum> {
+    arithmetic_datum(left, Arithmetic::Multiply, right)
+}
+
+/// Perform `left / right` on two [`Datum`], see [`divide_dyn`] and [`add_datum`]
+///
+/// Returns [`ArrowError::DivideByZero`] if any valid divisor is zero.
+pub fn divide_datum(left: &Datum, right: &Datum) -> Result<Datum> {
+    arithmetic_datum(left, Arithmetic::Divide, right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn array(values: Vec<Option<i32>>) -> Datum {
+        Datum::Array(Arc::new(Int32Array::from(values)))
+    }
+
+    fn scalar(value: Option<i32>) -> Datum {
+        Datum::try_new_scalar(Arc::new(Int32Array::from(vec![value]))).unwrap()
+    }
+
+    fn materialized(datum: &Datum, len: usize) -> Datum {
+        Datum::Array(datum.clone().into_array(len).unwrap())
+    }
+
+    #[test]
+    fn test_datum() {
+        let err = Datum::try_new_scalar(Arc::new(Int32Array::from(vec![1, 2])));
+        assert!(err.unwrap_err().to_string().contains("exactly one value"));
+
+        let datum = scalar(Some(3));
+        assert!(datum.is_scalar());
+        assert_eq!(datum.data_type(), &DataType::Int32);
+        let array = datum.into_array(2).unwrap();
+        assert_eq!(array.as_ref(), &Int32Array::from(vec![3, 3]));
+    }
+
+    #[test]
+    fn test_datum_matches_arrays() {
+        let comparisons = [
+            eq_datum,
+            neq_datum,
+            lt_datum,
+            lt_eq_datum,
+            gt_datum,
+            gt_eq_datum,
+        ];
+        let arithmetic = [add_datum, subtract_datum, multiply_datum, divide_datum];
+
+        let values = array(vec![Some(1), None, Some(3), Some(-4), Some(2)]);
+        let keys = Int8Array::from(vec![Some(0), None, Some(1), Some(2), Some(3)]);
+        let dictionary_values = Int32Array::from(vec![1, 3, -4, 2]);
+        let dictionary =
+            DictionaryArray::<Int8Type>::try_new(&keys, &dictionary_values).unwrap();
+        let dictionary = Datum::Array(Arc::new(dictionary));
+
+        for s in [scalar(Some(2)), scalar(None)] {
+            let expanded = materialized(&s, 5);
+            for f in comparisons {
+                let expected = f(&values, &expanded).unwrap().into_array(5).unwrap();
+                let actual = f(&values, &s).unwrap().into_array(5).unwrap();
+                assert_eq!(actual.as_ref(), expected.as_ref());
+                let expected = f(&expanded, &values).unwrap().into_array(5).unwrap();
+                let actual = f(&s, &values).unwrap().into_array(5).unwrap();
+                assert_eq!(actual.as_ref(), expected.as_ref());
+
+                let actual = f(&dictionary, &s).unwrap().into_array(5).unwrap();
+                let expected = f(&values, &s).unwrap().into_array(5).unwrap();
+                assert_eq!(actual.as_ref(), expected.as_ref());
+            }
+            for f in arithmetic {
+                let expected = f(&values, &expanded).unwrap().into_array(5).unwrap();
+                let actual = f(&values, &s).unwrap().into_array(5).unwrap();
+                assert_eq!(actual.as_ref(), expected.as_ref());
+            }
+            for f in [add_datum, subtract_datum, multiply_datum] {
+                let expected = f(&expanded, &values).unwrap().into_array(5).unwrap();
+                let actual = f(&s, &values).unwrap().into_array(5).unwrap();
+                assert_eq!(actual.as_ref(), expected.as_ref());
+            }
+        }
+
+        // scalar / array divides by each valid value
+        let result = divide_datum(&scalar(Some(12)), &values).unwrap();
+        let expected = Int32Array::from(vec![Some(12), None, Some(4), Some(-3), Some(6)]);
+        assert_eq!(result.into_array(5).unwrap().as_ref(), &expected);
+        let zero = array(vec![Some(1), Some(0)]);
+        let err = divide_datum(&scalar(Some(12)), &zero).unwrap_err();
+        assert!(matches!(err, ArrowError::DivideByZero));
+        let err = divide_datum(&zero, &scalar(Some(0))).unwrap_err();
+        assert!(matches!(err, ArrowError::DivideByZero));
+    }
+
+    #[test]
+    fn test_datum_scalars() {
+        let result = add_datum(&scalar(Some(1)), &scalar(Some(2))).unwrap();
+        assert!(result.is_scalar());
+        assert_eq!(
+            result.into_array(1).unwrap().as_ref(),
+            &Int32Array::from(vec![3])
+        );
+
+        let strings = Datum::Array(Arc::new(StringArray::from(vec![
+            Some("a"),
+            None,
+            Some("c"),
+        ])));
+        let b = Datum::try_new_scalar(Arc::new(StringArray::from(vec!["b"]))).unwrap();
+        let result = lt_datum(&b, &strings).unwrap().into_array(3).unwrap();
+        let expected = BooleanArray::from(vec![Some(false), None, Some(true)]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let bools = Datum::Array(Arc::new(BooleanArray::from(vec![true, false])));
+        let t = Datum::try_new_scalar(Arc::new(BooleanArray::from(vec![true]))).unwrap();
+        let result = eq_datum(&bools, &t).unwrap().into_array(2).unwrap();
+        assert_eq!(result.as_ref(), &BooleanArray::from(vec![true, false]));
+
+        // other types, and mismatched types, are handled by the array kernels
+        let dates = Datum::Array(Arc::new(Date32Array::from(vec![1, 5])));
+        let date = Datum::try_new_scalar(Arc::new(Date32Array::from(vec![3]))).unwrap();
+        let result = gt_datum(&dates, &date).unwrap().into_array(2).unwrap();
+        assert_eq!(result.as_ref(), &BooleanArray::from(vec![false, true]));
+        assert!(add_datum(&dates, &scalar(Some(1))).is_err());
+    }
+}
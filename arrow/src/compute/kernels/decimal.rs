@@ -0,0 +1,794 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines arithmetic and rounding kernels for [`Decimal128Array`] and [`Decimal256Array`]
+//!
+//! Unlike the kernels in [`arithmetic`](crate::compute::kernels::arithmetic), the
+//! operands may have different precisions and scales, and the precision and scale of
+//! the result are derived from those of the operands following the rules commonly
+//! used by SQL engines, see [`decimal_op_type`].
+
+use std::cmp::{max, min, Ordering};
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use arrow_buffer::i256;
+
+use crate::array::*;
+use crate::compute::kernels::window::{cumulative, CumulativeOptions};
+use crate::datatypes::{
+    DataType, DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE, DECIMAL256_MAX_PRECISION,
+    DECIMAL256_MAX_SCALE,
+};
+use crate::error::{ArrowError, Result};
+use crate::util::decimal::Decimal256;
+
+/// The minimum scale of the result of a [`DecimalOp`] when its precision is reduced
+/// to fit within the maximum precision of the result type
+const MIN_ADJUSTED_SCALE: i32 = 6;
+
+/// An arithmetic operation on decimals, see [`decimal_op_type`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimalOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl std::fmt::Display for DecimalOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let op = match self {
+            Self::Add => "+",
+            Self::Subtract => "-",
+            Self::Multiply => "*",
+            Self::Divide => "/",
+        };
+        f.write_str(op)
+    }
+}
+
+/// Returns the [`DataType`] of the result of `op` on decimals of types `left` and `right`
+///
+/// The result is a [`DataType::Decimal256`] if either operand is, otherwise a
+/// [`DataType::Decimal128`]. With operands of precision `p1`, `p2` and scale `s1`, `s2`
+/// the precision and scale of the result are
+///
+/// | Operation | Precision                               | Scale                  |
+/// |-----------|-----------------------------------------|------------------------|
+/// | `+`, `-`  | `max(p1 - s1, p2 - s2) + max(s1, s2) + 1` | `max(s1, s2)`          |
+/// | `*`       | `p1 + p2 + 1`                           | `s1 + s2`              |
+/// | `/`       | `p1 - s1 + s2 + max(6, s1 + p2 + 1)`    | `max(6, s1 + p2 + 1)`  |
+///
+/// If the precision exceeds the maximum precision of the result type, it is reduced
+/// to the maximum, and the scale reduced by as much, to preserve the integral digits,
+/// but to no less than `min(scale, 6)`.
+///
+/// ```
+/// # use arrow::compute::kernels::decimal::{decimal_op_type, DecimalOp};
+/// # use arrow::datatypes::DataType;
+/// let left = DataType::Decimal128(10, 2);
+/// let right = DataType::Decimal128(5, 3);
+///
+/// let add = decimal_op_type(DecimalOp::Add, &left, &right).unwrap();
+/// assert_eq!(add, DataType::Decimal128(12, 3));
+///
+/// let divide = decimal_op_type(DecimalOp::Divide, &left, &right).unwrap();
+/// assert_eq!(divide, DataType::Decimal128(19, 8));
+/// ```
+pub fn decimal_op_type(
+    op: DecimalOp,
+    left: &DataType,
+    right: &DataType,
+) -> Result<DataType> {
+    let ((p1, s1), (p2, s2)) = match (left, right) {
+        (
+            DataType::Decimal128(p1, s1) | DataType::Decimal256(p1, s1),
+            DataType::Decimal128(p2, s2) | DataType::Decimal256(p2, s2),
+        ) => ((*p1 as i32, *s1 as i32), (*p2 as i32, *s2 as i32)),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot perform decimal operation {} between {} and {}",
+                op, left, right
+            )))
+        }
+    };
+
+    let (precision, scale) = match op {
+        DecimalOp::Add | DecimalOp::Subtract => {
+            let scale = max(s1, s2);
+            (max(p1 - s1, p2 - s2) + scale + 1, scale)
+        }
+        DecimalOp::Multiply => (p1 + p2 + 1, s1 + s2),
+        DecimalOp::Divide => {
+            let scale = max(MIN_ADJUSTED_SCALE, s1 + p2 + 1);
+            (p1 - s1 + s2 + scale, scale)
+        }
+    };
+
+    let decimal256 = matches!(left, DataType::Decimal256(_, _))
+        || matches!(right, DataType::Decimal256(_, _));
+    let max_precision = match decimal256 {
+        true => DECIMAL256_MAX_PRECISION as i32,
+        false => DECIMAL128_MAX_PRECISION as i32,
+    };
+
+    let (precision, scale) = match precision > max_precision {
+        true => {
+            let integral = precision - scale;
+            let min_scale = min(scale, MIN_ADJUSTED_SCALE);
+            (max_precision, max(max_precision - integral, min_scale))
+        }
+        false => (precision, scale),
+    };
+
+    let (precision, scale) = (precision as u8, scale as u8);
+    Ok(match decimal256 {
+        true => DataType::Decimal256(precision, scale),
+        false => DataType::Decimal128(precision, scale),
+    })
+}
+
+/// Perform `left + right` operation on two decimal arrays. If either left or right value
+/// is null then the result is also null.
+///
+/// The operands may have different precisions and scales, and be of different decimal
+/// types. The result has the type returned by [`decimal_op_type`], with the exact result
+/// rounded half away from zero to its scale if necessary.
+///
+/// This returns an `Err` if the result of a value does not fit within the precision of
+/// the result type.
+///
+/// ```
+/// # use arrow::array::{Array, Decimal128Array};
+/// # use arrow::compute::kernels::decimal::add_decimal;
+/// // [1.25, null]
+/// let left: Decimal128Array = vec![Some(125), None].into_iter().collect();
+/// let left = left.with_precision_and_scale(5, 2).unwrap();
+/// // [0.001, 1.0]
+/// let right: Decimal128Array = vec![Some(1), Some(1000)].into_iter().collect();
+/// let right = right.with_precision_and_scale(4, 3).unwrap();
+///
+/// let result = add_decimal(&left, &right).unwrap();
+/// let result = result.as_any().downcast_ref::<Decimal128Array>().unwrap();
+/// assert_eq!((result.precision(), result.scale()), (7, 3));
+/// assert_eq!(result.value_as_string(0), "1.251");
+/// assert!(result.is_null(1));
+/// ```
+pub fn add_decimal(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    decimal_op(DecimalOp::Add, left, right)
+}
+
+/// Perform `left - right` operation on two decimal arrays. If either left or right value
+/// is null then the result is also null.
+///
+/// See [`add_decimal`] for the precision and scale of the result.
+pub fn subtract_decimal(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    decimal_op(DecimalOp::Subtract, left, right)
+}
+
+/// Perform `left * right` operation on two decimal arrays. If either left or right value
+/// is null then the result is also null.
+///
+/// See [`add_decimal`] for the precision and scale of the result.
+pub fn multiply_decimal(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    decimal_op(DecimalOp::Multiply, left, right)
+}
+
+/// Perform `left / right` operation on two decimal arrays. If either left or right value
+/// is null then the result is also null.
+///
+/// See [`add_decimal`] for the precision and scale of the result. This returns an
+/// [`ArrowError::DivideByZero`] if a valid right value is zero.
+pub fn divide_decimal(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    decimal_op(DecimalOp::Divide, left, right)
+}
+
+/// How to round a decimal to a lower scale, see [`round_decimal`], or values with more
+/// fractional digits than the scale of a decimal when cast to it, see
+/// [`CastOptions::rounding`](crate::compute::kernels::cast::CastOptions::rounding)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to the nearest value, and halfway values away from zero
+    HalfUp,
+    /// Round to the nearest value, and halfway values to the even neighbour
+    HalfEven,
+    /// Round towards positive infinity
+    Ceil,
+    /// Round towards negative infinity
+    Floor,
+    /// Round towards zero
+    Truncate,
+}
+
+impl RoundingMode {
+    /// Rounds `value` to an integer
+    pub(crate) fn round_f64(self, value: f64) -> f64 {
+        match self {
+            Self::Truncate => value.trunc(),
+            Self::HalfUp => value.round(),
+            Self::HalfEven if (value - value.trunc()).abs() == 0.5 => {
+                2.0 * (value / 2.0).round()
+            }
+            Self::HalfEven => value.round(),
+            Self::Floor => value.floor(),
+            Self::Ceil => value.ceil(),
+        }
+    }
+
+    /// Returns whether to increment the magnitude of a decimal truncated towards zero,
+    /// given its sign, whether its last kept digit is odd, and its `discarded` digits
+    pub(crate) fn round_away(self, negative: bool, odd: bool, discarded: &[u8]) -> bool {
+        let nonzero = |digits: &[u8]| digits.iter().any(|d| *d != b'0');
+        match self {
+            Self::Truncate => false,
+            Self::HalfUp => discarded.first().map_or(false, |d| *d >= b'5'),
+            Self::HalfEven => match discarded.split_first() {
+                Some((b'5', rest)) => odd || nonzero(rest),
+                Some((d, _)) => *d > b'5',
+                None => false,
+            },
+            Self::Floor => negative && nonzero(discarded),
+            Self::Ceil => !negative && nonzero(discarded),
+        }
+    }
+}
+
+/// Rounds the values of a decimal array to `scale` with `mode`. Null values remain null.
+///
+/// The result is of the same decimal type as `array`, with the scale `scale`. Its
+/// precision preserves the integral digits of `array`, and an additional digit if
+/// `scale` is less than the scale of `array`, as rounding may carry into it, up to the
+/// maximum precision of the type.
+///
+/// This returns an `Err` if `scale` is greater than the maximum scale of the type, or
+/// a value does not fit within the precision of the result.
+///
+/// ```
+/// # use arrow::array::{Array, Decimal128Array};
+/// # use arrow::compute::kernels::decimal::{round_decimal, RoundingMode};
+/// // [2.25, -2.35, 9.99]
+/// let array: Decimal128Array = vec![Some(225), Some(-235), Some(999)].into_iter().collect();
+/// let array = array.with_precision_and_scale(3, 2).unwrap();
+///
+/// let result = round_decimal(&array, 1, RoundingMode::HalfEven).unwrap();
+/// let result = result.as_any().downcast_ref::<Decimal128Array>().unwrap();
+/// assert_eq!((result.precision(), result.scale()), (3, 1));
+/// assert_eq!(result.value_as_string(0), "2.2");
+/// assert_eq!(result.value_as_string(1), "-2.4");
+/// assert_eq!(result.value_as_string(2), "10.0");
+/// ```
+pub fn round_decimal(
+    array: &dyn Array,
+    scale: u8,
+    mode: RoundingMode,
+) -> Result<ArrayRef> {
+    let (values, from_scale) = DecimalValues::try_new(array)?;
+    let (precision, max_precision, max_scale) = match *array.data_type() {
+        DataType::Decimal128(p, _) => (p, DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE),
+        DataType::Decimal256(p, _) => (p, DECIMAL256_MAX_PRECISION, DECIMAL256_MAX_SCALE),
+        _ => unreachable!(),
+    };
+    if scale > max_scale {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot round {} to scale {}, greater than max {}",
+            array.data_type(),
+            scale,
+            max_scale
+        )));
+    }
+
+    let to_scale = scale as i32;
+    let precision = match to_scale >= from_scale {
+        true => precision as i32 + to_scale - from_scale,
+        false => precision as i32 - from_scale + to_scale + 1,
+    };
+    let precision = precision.clamp(max(scale, 1) as i32, max_precision as i32) as u8;
+    let data_type = match array.data_type() {
+        DataType::Decimal128(_, _) => DataType::Decimal128(precision, scale),
+        _ => DataType::Decimal256(precision, scale),
+    };
+
+    let (min_value, max_value) = precision_bounds(precision);
+    let rounded = (0..array.len()).map(|idx| {
+        if array.is_null(idx) {
+            return Ok(None);
+        }
+        match rescale(values.value(idx), from_scale, to_scale, mode) {
+            Some(v) if v >= min_value && v <= max_value => Ok(Some(v)),
+            _ => Err(ArrowError::ComputeError(format!(
+                "Overflow happened on: rounding {} to scale {} at row {}, the result does not fit in {}",
+                values.value_as_string(idx),
+                scale,
+                idx,
+                data_type
+            ))),
+        }
+    });
+    build_decimal(rounded, &data_type)
+}
+
+/// The values of a [`Decimal128Array`] or [`Decimal256Array`] as [`i256`]
+enum DecimalValues<'a> {
+    Decimal128(&'a Decimal128Array),
+    Decimal256(&'a Decimal256Array),
+}
+
+impl<'a> DecimalValues<'a> {
+    fn try_new(array: &'a dyn Array) -> Result<(Self, i32)> {
+        match array.data_type() {
+            DataType::Decimal128(_, scale) => Ok((
+                Self::Decimal128(array.as_any().downcast_ref().unwrap()),
+                *scale as i32,
+            )),
+            DataType::Decimal256(_, scale) => Ok((
+                Self::Decimal256(array.as_any().downcast_ref().unwrap()),
+                *scale as i32,
+            )),
+            d => Err(ArrowError::InvalidArgumentError(format!(
+                "Expected decimal array, got {}",
+                d
+            ))),
+        }
+    }
+
+    fn value(&self, idx: usize) -> i256 {
+        match self {
+            Self::Decimal128(array) => i128_to_i256(array.value(idx).as_i128()),
+            Self::Decimal256(array) => i256::from_le_bytes(*array.value(idx).raw_value()),
+        }
+    }
+
+    fn value_as_string(&self, idx: usize) -> String {
+        match self {
+            Self::Decimal128(array) => array.value_as_string(idx),
+            Self::Decimal256(array) => array.value_as_string(idx),
+        }
+    }
+}
+
+fn i128_to_i256(v: i128) -> i256 {
+    i256::from_parts(v as u128, if v < 0 { -1 } else { 0 })
+}
+
+/// Returns `10^exp`, or `None` on overflow
+fn pow10(exp: i32) -> Option<i256> {
+    let ten = i128_to_i256(10);
+    (0..exp).try_fold(i256::ONE, |acc, _| acc.checked_mul(ten))
+}
+
+/// Returns `value / divisor` rounded with `mode`, or `None` on overflow
+///
+/// `divisor` must not be zero
+fn div_round(value: i256, divisor: i256, mode: RoundingMode) -> Option<i256> {
+    let quotient = value.checked_div(divisor)?;
+    let remainder = value.checked_rem(divisor)?;
+    if remainder == i256::ZERO {
+        return Some(quotient);
+    }
+
+    let negative = (value < i256::ZERO) != (divisor < i256::ZERO);
+    // Whether to round away from zero, rather than truncate towards zero
+    let away = match mode {
+        RoundingMode::Truncate => false,
+        RoundingMode::Ceil => !negative,
+        RoundingMode::Floor => negative,
+        RoundingMode::HalfUp | RoundingMode::HalfEven => {
+            let remainder = remainder.checked_abs()?;
+            // Compare `2 * |remainder|` to `|divisor|` without overflowing
+            let rest = divisor.checked_abs()?.checked_sub(remainder)?;
+            match remainder.cmp(&rest) {
+                Ordering::Greater => true,
+                Ordering::Less => false,
+                Ordering::Equal => match mode {
+                    RoundingMode::HalfEven => {
+                        quotient.checked_rem(i128_to_i256(2))? != i256::ZERO
+                    }
+                    _ => true,
+                },
+            }
+        }
+    };
+
+    match (away, negative) {
+        (false, _) => Some(quotient),
+        (true, false) => quotient.checked_add(i256::ONE),
+        (true, true) => quotient.checked_sub(i256::ONE),
+    }
+}
+
+/// Returns `value * 10^(to - from)` rounded with `mode`, or `None` on overflow
+fn rescale(value: i256, from: i32, to: i32, mode: RoundingMode) -> Option<i256> {
+    match to >= from {
+        true => value.checked_mul(pow10(to - from)?),
+        false => div_round(value, pow10(from - to)?, mode),
+    }
+}
+
+/// Returns the minimum and maximum values of a decimal with `precision`
+fn precision_bounds(precision: u8) -> (i256, i256) {
+    match pow10(precision as i32) {
+        Some(max) => (i256::ONE.wrapping_sub(max), max.wrapping_sub(i256::ONE)),
+        None => (i256::MIN, i256::MAX),
+    }
+}
+
+/// Collects `values`, which must be within the precision of `data_type`, into a decimal
+/// array of `data_type`
+fn build_decimal<I>(values: I, data_type: &DataType) -> Result<ArrayRef>
+where
+    I: Iterator<Item = Result<Option<i256>>>,
+{
+    match *data_type {
+        DataType::Decimal128(precision, scale) => {
+            // The values fit within the precision, and therefore an i128
+            let array: Decimal128Array = values
+                .map(|v| v.map(|v| v.and_then(|v| v.to_i128())))
+                .collect::<Result<_>>()?;
+            Ok(Arc::new(array.with_precision_and_scale(precision, scale)?))
+        }
+        DataType::Decimal256(precision, scale) => {
+            let array: Decimal256Array = values
+                .map(|v| {
+                    v.map(|v| {
+                        v.map(|v| Decimal256::new(precision, scale, &v.to_le_bytes()))
+                    })
+                })
+                .collect::<Result<_>>()?;
+            Ok(Arc::new(array.with_precision_and_scale(precision, scale)?))
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn decimal_op(op: DecimalOp, left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform a binary operation on arrays of different length".to_string(),
+        ));
+    }
+    let data_type = decimal_op_type(op, left.data_type(), right.data_type())?;
+    let (precision, scale) = match data_type {
+        DataType::Decimal128(p, s) | DataType::Decimal256(p, s) => (p, s as i32),
+        _ => unreachable!(),
+    };
+    let (l, s1) = DecimalValues::try_new(left)?;
+    let (r, s2) = DecimalValues::try_new(right)?;
+
+    let (min_value, max_value) = precision_bounds(precision);
+    let mode = RoundingMode::HalfUp;
+
+    let compute = |idx: usize| -> Result<i256> {
+        let (a, b) = (l.value(idx), r.value(idx));
+        let result = match op {
+            DecimalOp::Add | DecimalOp::Subtract => {
+                let common = max(s1, s2);
+                rescale(a, s1, common, mode)
+                    .zip(rescale(b, s2, common, mode))
+                    .and_then(|(a, b)| match op {
+                        DecimalOp::Add => a.checked_add(b),
+                        _ => a.checked_sub(b),
+                    })
+                    .and_then(|v| rescale(v, common, scale, mode))
+            }
+            DecimalOp::Multiply => a
+                .checked_mul(b)
+                .and_then(|v| rescale(v, s1 + s2, scale, mode)),
+            DecimalOp::Divide => {
+                if b == i256::ZERO {
+                    return Err(ArrowError::DivideByZero);
+                }
+                // a / b has scale s1 - s2, and so is rescaled by scale - (s1 - s2)
+                let shift = scale + s2 - s1;
+                match shift >= 0 {
+                    true => pow10(shift)
+                        .and_then(|p| a.checked_mul(p))
+                        .and_then(|a| div_round(a, b, mode)),
+                    false => pow10(-shift)
+                        .and_then(|p| b.checked_mul(p))
+                        .and_then(|b| div_round(a, b, mode)),
+                }
+            }
+        };
+
+        match result {
+            Some(v) if v >= min_value && v <= max_value => Ok(v),
+            _ => Err(ArrowError::ComputeError(format!(
+                "Overflow happened on: {} {} {} at row {}, the result does not fit in {}",
+                l.value_as_string(idx),
+                op,
+                r.value_as_string(idx),
+                idx,
+                data_type
+            ))),
+        }
+    };
+
+    let values =
+        (0..left.len()).map(|idx| match left.is_valid(idx) && right.is_valid(idx) {
+            true => compute(idx).map(Some),
+            false => Ok(None),
+        });
+
+    build_decimal(values, &data_type)
+}
+
+/// Returns the running values of applying `op` to the accumulated value and each value
+/// of a decimal array, see [`cumsum_dyn`](crate::compute::cumsum_dyn).
+///
+/// Returns an `Err` if `op` returns `None`, or a value exceeding the precision of the array
+pub(crate) fn cumulative_decimal<F>(
+    array: &dyn Array,
+    options: CumulativeOptions,
+    op: F,
+) -> Result<ArrayRef>
+where
+    F: Fn(i256, i256) -> Option<i256>,
+{
+    let (values, _) = DecimalValues::try_new(array)?;
+    let data_type = array.data_type();
+    let (min_value, max_value) = match data_type {
+        DataType::Decimal128(p, _) | DataType::Decimal256(p, _) => precision_bounds(*p),
+        _ => unreachable!(),
+    };
+
+    let iter = (0..array.len()).map(|idx| array.is_valid(idx).then(|| values.value(idx)));
+    let result = cumulative(iter, options, |a, b| match op(a, b) {
+        Some(v) if v >= min_value && v <= max_value => Ok(v),
+        _ => Err(ArrowError::ComputeError(format!(
+            "Overflow happened on cumulative operation, the result does not fit in {}",
+            data_type
+        ))),
+    });
+    build_decimal(result, data_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal128(
+        values: Vec<Option<i128>>,
+        precision: u8,
+        scale: u8,
+    ) -> Decimal128Array {
+        values
+            .into_iter()
+            .collect::<Decimal128Array>()
+            .with_precision_and_scale(precision, scale)
+            .unwrap()
+    }
+
+    fn strings(array: &ArrayRef) -> Vec<Option<String>> {
+        match array.data_type() {
+            DataType::Decimal128(_, _) => {
+                let array = array.as_any().downcast_ref::<Decimal128Array>().unwrap();
+                (0..array.len())
+                    .map(|i| array.is_valid(i).then(|| array.value_as_string(i)))
+                    .collect()
+            }
+            _ => {
+                let array = array.as_any().downcast_ref::<Decimal256Array>().unwrap();
+                (0..array.len())
+                    .map(|i| array.is_valid(i).then(|| array.value_as_string(i)))
+                    .collect()
+            }
+        }
+    }
+
+    fn s(v: &str) -> Option<String> {
+        Some(v.to_string())
+    }
+
+    #[test]
+    fn test_decimal_op_type() {
+        let d128 = DataType::Decimal128;
+        let d256 = DataType::Decimal256;
+        let cases = [
+            (DecimalOp::Add, d128(10, 2), d128(5, 3), d128(12, 3)),
+            (DecimalOp::Subtract, d128(38, 10), d128(38, 10), d128(38, 9)),
+            (DecimalOp::Multiply, d128(10, 2), d128(5, 3), d128(16, 5)),
+            (DecimalOp::Multiply, d128(38, 10), d128(38, 10), d128(38, 6)),
+            (DecimalOp::Multiply, d128(38, 2), d128(38, 3), d128(38, 5)),
+            (DecimalOp::Divide, d128(10, 2), d128(5, 3), d128(19, 8)),
+            (DecimalOp::Divide, d128(38, 10), d128(38, 10), d128(38, 6)),
+            (
+                DecimalOp::Multiply,
+                d128(38, 10),
+                d256(38, 10),
+                d256(76, 19),
+            ),
+            (DecimalOp::Add, d256(76, 0), d128(1, 1), d256(76, 1)),
+        ];
+        for (op, left, right, expected) in cases {
+            let actual = decimal_op_type(op, &left, &right).unwrap();
+            assert_eq!(actual, expected, "{} {} {}", left, op, right);
+        }
+
+        let err =
+            decimal_op_type(DecimalOp::Add, &d128(5, 2), &DataType::Int32).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot perform decimal operation + between Decimal128(5, 2) and Int32"
+        );
+    }
+
+    #[test]
+    fn test_decimal_arithmetic() {
+        // [1.25, -3.50, null, 99.99]
+        let left = decimal128(vec![Some(125), Some(-350), None, Some(9999)], 4, 2);
+        // [0.001, 2.000, 1.000, -0.005]
+        let right = decimal128(vec![Some(1), Some(2000), Some(1000), Some(-5)], 4, 3);
+
+        let result = add_decimal(&left, &right).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal128(6, 3));
+        let expected = vec![s("1.251"), s("-1.500"), None, s("99.985")];
+        assert_eq!(strings(&result), expected);
+
+        let result = subtract_decimal(&left, &right).unwrap();
+        let expected = vec![s("1.249"), s("-5.500"), None, s("99.995")];
+        assert_eq!(strings(&result), expected);
+
+        let result = multiply_decimal(&left, &right).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal128(9, 5));
+        let expected = vec![s("0.00125"), s("-7.00000"), None, s("-0.49995")];
+        assert_eq!(strings(&result), expected);
+
+        let result = divide_decimal(&left, &right).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal128(12, 7));
+        let expected = vec![
+            s("1250.0000000"),
+            s("-1.7500000"),
+            None,
+            s("-19998.0000000"),
+        ];
+        assert_eq!(strings(&result), expected);
+    }
+
+    #[test]
+    fn test_decimal_divide_rounding() {
+        let left = decimal128(vec![Some(2), Some(-2), Some(1)], 1, 0);
+        let right = decimal128(vec![Some(3), Some(3), Some(0)], 1, 0);
+        let err = divide_decimal(&left, &right).unwrap_err();
+        assert!(matches!(err, ArrowError::DivideByZero), "{}", err);
+
+        let result = divide_decimal(&left.slice(0, 2), &right.slice(0, 2)).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal128(7, 6));
+        assert_eq!(strings(&result), vec![s("0.666667"), s("-0.666667")]);
+
+        // Division by zero is not evaluated for null values
+        let left = decimal128(vec![None], 1, 0);
+        let right = decimal128(vec![Some(0)], 1, 0);
+        let result = divide_decimal(&left, &right).unwrap();
+        assert_eq!(strings(&result), vec![None]);
+    }
+
+    #[test]
+    fn test_decimal_reduced_scale() {
+        // The scale of the product is reduced from 20 to 6, and rounded
+        let left = decimal128(vec![Some(15_000_000_000), Some(-15_000_000_000)], 38, 10);
+        let right = decimal128(vec![Some(10_000), Some(10_000)], 38, 10);
+        let result = multiply_decimal(&left, &right).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal128(38, 6));
+        assert_eq!(strings(&result), vec![s("0.000002"), s("-0.000002")]);
+    }
+
+    #[test]
+    fn test_decimal_overflow() {
+        let max = 10_i128.pow(38) - 1;
+        let left = decimal128(vec![Some(max)], 38, 0);
+        let right = decimal128(vec![Some(1)], 38, 0);
+        let err = add_decimal(&left, &right).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Overflow happened on: 99999999999999999999999999999999999999 + 1 at row 0, the result does not fit in Decimal128(38, 0)"
+        );
+
+        // A Decimal256 result has a greater precision
+        let right: Decimal256Array =
+            vec![Some(Decimal256::new(38, 0, &i256::ONE.to_le_bytes()))]
+                .into_iter()
+                .collect();
+        let right = right.with_precision_and_scale(38, 0).unwrap();
+        let result = add_decimal(&left, &right).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal256(39, 0));
+        assert_eq!(
+            strings(&result),
+            vec![s("100000000000000000000000000000000000000")]
+        );
+
+        let result = multiply_decimal(&left, &left);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decimal_invalid() {
+        let left = decimal128(vec![Some(1)], 1, 0);
+        let right = Int32Array::from(vec![1]);
+        assert!(add_decimal(&left, &right).is_err());
+
+        let right = decimal128(vec![Some(1), Some(2)], 1, 0);
+        let err = add_decimal(&left, &right).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot perform a binary operation on arrays of different length"
+        );
+    }
+
+    #[test]
+    fn test_round_decimal() {
+        // [2.25, -2.35, 9.99, null, -0.04, 1.00]
+        let values = vec![Some(225), Some(-235), Some(999), None, Some(-4), Some(100)];
+        let array = decimal128(values, 3, 2);
+
+        let cases = [
+            (RoundingMode::HalfUp, ["2.3", "-2.4", "10.0", "0.0", "1.0"]),
+            (
+                RoundingMode::HalfEven,
+                ["2.2", "-2.4", "10.0", "0.0", "1.0"],
+            ),
+            (RoundingMode::Ceil, ["2.3", "-2.3", "10.0", "0.0", "1.0"]),
+            (RoundingMode::Floor, ["2.2", "-2.4", "9.9", "-0.1", "1.0"]),
+            (RoundingMode::Truncate, ["2.2", "-2.3", "9.9", "0.0", "1.0"]),
+        ];
+        for (mode, expected) in cases {
+            let result = round_decimal(&array, 1, mode).unwrap();
+            assert_eq!(result.data_type(), &DataType::Decimal128(3, 1));
+            let expected: Vec<_> = expected.iter().map(|v| s(v)).collect();
+            let expected = [&expected[..3], &[None], &expected[3..]].concat();
+            assert_eq!(strings(&result), expected, "{:?}", mode);
+        }
+
+        let result = round_decimal(&array, 0, RoundingMode::HalfUp).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal128(2, 0));
+        let expected = vec![s("2"), s("-2"), s("10"), None, s("0"), s("1")];
+        assert_eq!(strings(&result), expected);
+
+        // Increasing the scale is exact
+        let result = round_decimal(&array, 4, RoundingMode::HalfUp).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal128(5, 4));
+        assert_eq!(strings(&result)[0], s("2.2500"));
+
+        let err = round_decimal(&array, 39, RoundingMode::HalfUp).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot round Decimal128(3, 2) to scale 39, greater than max 38"
+        );
+
+        // The precision of the result is limited to the maximum
+        let array = decimal128(vec![Some(10_i128.pow(38) - 1)], 38, 0);
+        let err = round_decimal(&array, 1, RoundingMode::HalfUp).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Overflow happened on: rounding 99999999999999999999999999999999999999 to scale 1 at row 0, the result does not fit in Decimal128(38, 1)"
+        );
+    }
+
+    #[test]
+    fn test_round_decimal256() {
+        let values = [Some(i128_to_i256(-12_345)), None, Some(i128_to_i256(15))];
+        let array: Decimal256Array = values
+            .iter()
+            .map(|v| v.map(|v| Decimal256::new(5, 2, &v.to_le_bytes())))
+            .collect();
+        let array = array.with_precision_and_scale(5, 2).unwrap();
+
+        let result = round_decimal(&array, 1, RoundingMode::HalfEven).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal256(5, 1));
+        assert_eq!(strings(&result), vec![s("-123.4"), None, s("0.2")]);
+    }
+}
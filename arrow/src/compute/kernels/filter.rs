@@ -20,13 +20,16 @@
 use std::ops::AddAssign;
 use std::sync::Arc;
 
+use multiversion::multiversion;
 use num::Zero;
 
 use crate::array::*;
 use crate::buffer::{buffer_bin_and, Buffer, MutableBuffer};
+use crate::compute::kernels::concat::concat;
+use crate::compute::kernels::selection::{Selection, SelectionKind};
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
-use crate::record_batch::RecordBatch;
+use crate::record_batch::{RecordBatch, RecordBatchOptions};
 use crate::util::bit_iterator::{BitIndexIterator, BitSliceIterator};
 use crate::util::bit_util;
 use crate::{downcast_dictionary_array, downcast_primitive_array};
@@ -211,6 +214,138 @@ pub fn filter_record_batch(
     RecordBatch::try_new(record_batch.schema(), filtered_arrays)
 }
 
+/// Filters each `(batch, predicate)` pair and concatenates the selected rows into
+/// batches of `target_batch_size` rows, the last of which may be shorter.
+///
+/// This is equivalent to calling [`filter_record_batch`] on each batch and then
+/// [`concat_batches`](crate::compute::concat_batches) on the results, but copies
+/// each selected row only once. Null predicate slots are treated as `false`.
+///
+/// As with [`concat`], the dictionaries of dictionary columns are merged if appending
+/// them would overflow their keys, and an error is returned if the merged dictionary
+/// still does.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{BooleanArray, Int32Array};
+/// # use arrow::compute::filter_concat_batches;
+/// # use arrow::datatypes::{DataType, Field, Schema};
+/// # use arrow::record_batch::RecordBatch;
+/// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+/// let a = RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(vec![1, 2, 3]))]).unwrap();
+/// let b = RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(vec![4, 5]))]).unwrap();
+/// let keep_a = BooleanArray::from(vec![true, false, true]);
+/// let keep_b = BooleanArray::from(vec![Some(true), None]);
+///
+/// let batches = filter_concat_batches(&schema, &[(&a, &keep_a), (&b, &keep_b)], 2).unwrap();
+/// assert_eq!(batches.len(), 2);
+/// assert_eq!(batches[0].column(0).as_ref(), &Int32Array::from(vec![1, 3]));
+/// assert_eq!(batches[1].column(0).as_ref(), &Int32Array::from(vec![4]));
+/// ```
+pub fn filter_concat_batches(
+    schema: &SchemaRef,
+    batches: &[(&RecordBatch, &BooleanArray)],
+    target_batch_size: usize,
+) -> Result<Vec<RecordBatch>> {
+    if target_batch_size == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "target_batch_size must be greater than 0".to_string(),
+        ));
+    }
+    for (i, (batch, predicate)) in batches.iter().enumerate() {
+        if batch.schema() != *schema {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "batches[{}] schema is different with argument schema.",
+                i
+            )));
+        }
+        if predicate.len() != batch.num_rows() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "batches[{}] has {} rows but its predicate has length {}",
+                i,
+                batch.num_rows(),
+                predicate.len()
+            )));
+        }
+    }
+
+    let predicates: Vec<_> = batches
+        .iter()
+        .map(|(_, predicate)| match predicate.null_count() {
+            0 => BooleanArray::from(predicate.data().clone()),
+            _ => prep_null_mask_filter(predicate),
+        })
+        .collect();
+
+    // The runs of selected rows, as (batch, start, end), of the next output batch
+    let mut runs = Vec::new();
+    let mut row_count = 0;
+    let mut output = Vec::new();
+    for (i, predicate) in predicates.iter().enumerate() {
+        for (mut start, end) in SlicesIterator::new(predicate) {
+            while start < end {
+                let run_end = end.min(start + target_batch_size - row_count);
+                runs.push((i, start, run_end));
+                row_count += run_end - start;
+                start = run_end;
+
+                if row_count == target_batch_size {
+                    output.push(concat_runs(schema, batches, &runs, row_count)?);
+                    runs.clear();
+                    row_count = 0;
+                }
+            }
+        }
+    }
+    if row_count != 0 {
+        output.push(concat_runs(schema, batches, &runs, row_count)?);
+    }
+    Ok(output)
+}
+
+/// Copies `runs` of rows, as (batch, start, end), from `batches` into a single batch
+fn concat_runs(
+    schema: &SchemaRef,
+    batches: &[(&RecordBatch, &BooleanArray)],
+    runs: &[(usize, usize, usize)],
+    row_count: usize,
+) -> Result<RecordBatch> {
+    // Runs are in batch order, so only the batches between the first and last are read
+    let first = runs.first().map(|run| run.0).unwrap_or_default();
+    let last = runs.last().map(|run| run.0).unwrap_or_default();
+    let sources = &batches[first..=last];
+
+    let columns = (0..schema.fields().len())
+        .map(|column| match schema.field(column).data_type() {
+            // Appending the dictionaries of every batch may overflow the keys, and so
+            // dictionaries are merged as by concat
+            DataType::Dictionary(_, _) => {
+                let slices: Vec<_> = runs
+                    .iter()
+                    .map(|(i, start, end)| {
+                        batches[*i].0.column(column).slice(*start, end - start)
+                    })
+                    .collect();
+                concat(&slices.iter().map(|s| s.as_ref()).collect::<Vec<_>>())
+            }
+            _ => {
+                let arrays = sources
+                    .iter()
+                    .map(|(batch, _)| batch.column(column).data())
+                    .collect();
+                let mut mutable = MutableArrayData::new(arrays, false, row_count);
+                for (i, start, end) in runs {
+                    mutable.extend(i - first, *start, *end);
+                }
+                Ok(make_array(mutable.freeze()))
+            }
+        })
+        .collect::<Result<_>>()?;
+
+    let options = RecordBatchOptions::new().with_row_count(Some(row_count));
+    RecordBatch::try_new_with_options(schema.clone(), columns, &options)
+}
+
 /// A builder to construct [`FilterPredicate`]
 #[derive(Debug)]
 pub struct FilterBuilder {
@@ -237,6 +372,29 @@ impl FilterBuilder {
         }
     }
 
+    /// Create a new [`FilterBuilder`] from a [`Selection`], reusing its indices or
+    /// ranges of selected rows if it has them
+    pub fn from_selection(selection: &Selection) -> Self {
+        let filter = selection.to_filter();
+        let count = selection.selected_count();
+        let partial = count != 0 && count != filter.len();
+        let strategy = match selection.kind() {
+            SelectionKind::Indices(indices) if partial => {
+                IterationStrategy::Indices(indices.clone())
+            }
+            SelectionKind::Ranges(ranges) if partial => IterationStrategy::Slices(
+                ranges.iter().map(|r| (r.start, r.end)).collect(),
+            ),
+            _ => IterationStrategy::default_strategy(filter.len(), count),
+        };
+
+        Self {
+            filter,
+            count,
+            strategy,
+        }
+    }
+
     /// Compute an optimised representation of the provided `filter` mask that can be
     /// applied to an array more quickly.
     ///
@@ -506,17 +664,14 @@ where
             buffer
         }
         IterationStrategy::IndexIterator => {
-            let iter =
-                IndexIterator::new(&predicate.filter, predicate.count).map(|x| values[x]);
+            let iter = IndexIterator::new(&predicate.filter, predicate.count);
 
             // SAFETY: IndexIterator is trusted length
-            unsafe { MutableBuffer::from_trusted_len_iter(iter) }
+            unsafe { gather(values, iter) }
         }
         IterationStrategy::Indices(indices) => {
-            let iter = indices.iter().map(|x| values[*x]);
-
             // SAFETY: `Vec::iter` is trusted length
-            unsafe { MutableBuffer::from_trusted_len_iter(iter) }
+            unsafe { gather(values, indices.iter().copied()) }
         }
         IterationStrategy::All | IterationStrategy::None => unreachable!(),
     };
@@ -533,6 +688,24 @@ where
     PrimitiveArray::from(data)
 }
 
+/// Copies the value at each index of `indices` from `values`, dispatching at
+/// runtime to a version compiled for the SIMD instructions of the CPU
+///
+/// # Safety
+///
+/// `indices` must have a trusted length
+#[multiversion]
+#[clone(target = "[x86|x86_64]+avx2")]
+#[clone(target = "[x86|x86_64]+sse4.1")]
+#[clone(target = "aarch64+neon")]
+unsafe fn gather<T, I>(values: &[T], indices: I) -> MutableBuffer
+where
+    T: ArrowNativeType,
+    I: Iterator<Item = usize>,
+{
+    MutableBuffer::from_trusted_len_iter(indices.map(|x| values[x]))
+}
+
 /// [`FilterString`] is created from a source [`GenericStringArray`] and can be
 /// used to build a new [`GenericStringArray`] by copying values from the source
 ///
@@ -1600,4 +1773,120 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_filter_concat_batches() {
+        let mut rng = thread_rng();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new(
+                "c",
+                DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+                true,
+            ),
+        ]));
+
+        let mut batches = Vec::new();
+        let mut predicates = Vec::new();
+        for len in [0, 1, 5, 17, 64, 3] {
+            let a: Int32Array = (0..len)
+                .map(|_| rng.gen_bool(0.8).then(|| rng.gen()))
+                .collect();
+            let b: StringArray = (0..len)
+                .map(|_| rng.gen_bool(0.8).then(|| rng.gen_range(0..100).to_string()))
+                .collect();
+            let c: DictionaryArray<Int8Type> = (0..len)
+                .map(|_| {
+                    rng.gen_bool(0.8)
+                        .then(|| ["x", "y", "z"][rng.gen_range(0..3)])
+                })
+                .collect();
+            let columns: Vec<ArrayRef> = vec![Arc::new(a), Arc::new(b), Arc::new(c)];
+            batches.push(RecordBatch::try_new(schema.clone(), columns).unwrap());
+
+            let predicate: BooleanArray = (0..len)
+                .map(|_| rng.gen_bool(0.9).then(|| rng.gen_bool(0.6)))
+                .collect();
+            predicates.push(predicate);
+        }
+        let pairs: Vec<_> = batches.iter().zip(&predicates).collect();
+
+        let filtered = pairs
+            .iter()
+            .map(|(batch, predicate)| filter_record_batch(batch, predicate))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        let expected = crate::compute::concat_batches(&schema, &filtered).unwrap();
+
+        for target_batch_size in [1, 3, 7, 1000] {
+            let actual =
+                filter_concat_batches(&schema, &pairs, target_batch_size).unwrap();
+            let expected_batches =
+                (expected.num_rows() + target_batch_size - 1) / target_batch_size;
+            assert_eq!(actual.len(), expected_batches);
+
+            let mut offset = 0;
+            for batch in &actual {
+                let len = target_batch_size.min(expected.num_rows() - offset);
+                assert_eq!(batch, &expected.slice(offset, len));
+                offset += len;
+            }
+            assert_eq!(offset, expected.num_rows());
+        }
+
+        let none = BooleanArray::from(vec![false; 5]);
+        let actual = filter_concat_batches(&schema, &[(&batches[2], &none)], 4).unwrap();
+        assert!(actual.is_empty());
+
+        let short = BooleanArray::from(vec![true; 4]);
+        let err =
+            filter_concat_batches(&schema, &[(&batches[2], &short)], 4).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("has 5 rows but its predicate has length 4"));
+        assert!(filter_concat_batches(&schema, &[], 0).is_err());
+    }
+
+    #[test]
+    fn test_filter_concat_batches_dictionary_overflow() {
+        let data_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let schema = Arc::new(Schema::new(vec![Field::new("a", data_type, false)]));
+        let batch = |values: std::ops::Range<i32>| {
+            let values: Vec<_> = values.map(|v| v.to_string()).collect();
+            let array: DictionaryArray<Int8Type> =
+                values.iter().map(|v| v.as_str()).collect();
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap()
+        };
+        let first_row = BooleanArray::from((0..100).map(|i| i == 0).collect::<Vec<_>>());
+
+        // Appending both dictionaries would overflow the keys, whereas merging them
+        // doesn't
+        let a = batch(0..100);
+        let b = batch(0..100);
+        let batches =
+            filter_concat_batches(&schema, &[(&a, &first_row), (&b, &first_row)], 10)
+                .unwrap();
+        assert_eq!(batches.len(), 1);
+        let array = as_dictionary_array::<Int8Type>(batches[0].column(0));
+        let values = as_string_array(array.values());
+        let actual: Vec<_> = array
+            .keys()
+            .iter()
+            .map(|k| values.value(k.unwrap() as usize))
+            .collect();
+        assert_eq!(actual, vec!["0", "0"]);
+
+        // The merged dictionary overflows the keys
+        let b = batch(100..200);
+        let err =
+            filter_concat_batches(&schema, &[(&a, &first_row), (&b, &first_row)], 10)
+                .unwrap_err();
+        assert!(
+            matches!(err, ArrowError::DictionaryKeyOverflowError),
+            "{}",
+            err
+        );
+    }
 }
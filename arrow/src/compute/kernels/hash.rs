@@ -0,0 +1,272 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels computing a 64-bit hash of every row of arrays, e.g. to partition
+//! rows or to build hash tables
+
+use ahash::RandomState;
+
+use crate::array::*;
+use crate::datatypes::{ArrowNativeType, DataType, ToByteSlice};
+use crate::error::{ArrowError, Result};
+use crate::{downcast_dictionary_array, downcast_primitive_array};
+
+/// The hash of null values, regardless of their data type
+const NULL_HASH: u64 = 0;
+
+/// Computes a 64-bit hash of every row of `array`, seeded with `seed`.
+///
+/// Equal values have equal hashes for the same seed, regardless of whether they are
+/// dictionary encoded, or stored with 32 or 64-bit offsets. Null values hash to `0`.
+///
+/// The hashes are not guaranteed to be stable across platforms or releases of this
+/// crate, and so shouldn't be persisted.
+///
+/// ```
+/// use arrow::array::StringArray;
+/// use arrow::compute::hash;
+///
+/// let array = StringArray::from(vec![Some("foo"), Some("bar"), Some("foo"), None]);
+/// let hashes = hash(&array, 42).unwrap();
+/// assert_eq!(hashes.value(0), hashes.value(2));
+/// assert_ne!(hashes.value(0), hashes.value(1));
+/// assert_eq!(hashes.value(3), 0);
+/// ```
+pub fn hash(array: &dyn Array, seed: u64) -> Result<UInt64Array> {
+    hash_columns(&[array], seed)
+}
+
+/// Computes a 64-bit hash of every row of `columns`, seeded with `seed`, by combining
+/// the hashes of the values of each column in order. See [`hash`] for details.
+///
+/// Returns an error if there are no columns, or if they have different lengths.
+pub fn hash_columns(columns: &[&dyn Array], seed: u64) -> Result<UInt64Array> {
+    let (first, rest) = columns.split_first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(
+            "hash_columns requires at least one column".to_string(),
+        )
+    })?;
+
+    let state = RandomState::with_seeds(
+        seed,
+        0x243f_6a88_85a3_08d3,
+        0x1319_8a2e_0370_7344,
+        0xa409_3822_299f_31d0,
+    );
+    let mut hashes = value_hashes(*first, &state)?;
+    for column in rest {
+        if column.len() != hashes.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "All columns to hash must have the same length, got {} and {}",
+                hashes.len(),
+                column.len()
+            )));
+        }
+        let column_hashes = value_hashes(*column, &state)?;
+        hashes
+            .iter_mut()
+            .zip(column_hashes)
+            .for_each(|(hash, value)| *hash = combine_hashes(*hash, value));
+    }
+    Ok(UInt64Array::from(hashes))
+}
+
+/// Combines the hash of the previous columns of a row with that of its next value
+#[inline]
+fn combine_hashes(hash: u64, value: u64) -> u64 {
+    (hash ^ value)
+        .wrapping_mul(0x9e37_79b9_7f4a_7c15)
+        .rotate_left(31)
+        .wrapping_add(value)
+}
+
+/// Hashes the values of `iter` with `f`, hashing nulls to [`NULL_HASH`]
+fn hash_iter<T>(iter: impl Iterator<Item = Option<T>>, f: impl Fn(T) -> u64) -> Vec<u64> {
+    iter.map(|v| v.map_or(NULL_HASH, &f)).collect()
+}
+
+/// Returns the hash of every value of `array`
+fn value_hashes(array: &dyn Array, state: &RandomState) -> Result<Vec<u64>> {
+    let hash_bytes = |v: &[u8]| state.hash_one(v);
+    Ok(match array.data_type() {
+        DataType::Null => vec![NULL_HASH; array.len()],
+        DataType::Boolean => {
+            hash_iter(as_boolean_array(array).iter(), |v| state.hash_one(v))
+        }
+        DataType::Utf8 => {
+            hash_iter(as_string_array(array).iter(), |v| hash_bytes(v.as_bytes()))
+        }
+        DataType::LargeUtf8 => hash_iter(as_largestring_array(array).iter(), |v| {
+            hash_bytes(v.as_bytes())
+        }),
+        DataType::Binary => {
+            hash_iter(as_generic_binary_array::<i32>(array).iter(), hash_bytes)
+        }
+        DataType::LargeBinary => {
+            hash_iter(as_generic_binary_array::<i64>(array).iter(), hash_bytes)
+        }
+        DataType::FixedSizeBinary(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            hash_iter(array.iter(), hash_bytes)
+        }
+        DataType::Decimal128(_, _) => hash_iter(as_decimal_array(array).iter(), |v| {
+            hash_bytes(v.raw_value())
+        }),
+        DataType::Decimal256(_, _) => {
+            let array = array.as_any().downcast_ref::<Decimal256Array>().unwrap();
+            hash_iter(array.iter(), |v| hash_bytes(v.raw_value()))
+        }
+        DataType::Dictionary(_, _) => downcast_dictionary_array!(
+            array => {
+                // hash every value once, rather than once per key referencing it
+                let values = value_hashes(array.values().as_ref(), state)?;
+                hash_iter(array.keys().iter(), |key| values[key.as_usize()])
+            }
+            t => unreachable!("Unexpected dictionary type {}", t)
+        ),
+        _ => downcast_primitive_array!(
+            array => hash_iter(array.iter(), |v| hash_bytes(v.to_byte_slice())),
+            t => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Hashing arrays of type {} is not supported",
+                    t
+                )))
+            }
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::cast;
+    use crate::datatypes::Int32Type;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_hash_primitive() {
+        let array = Int64Array::from(vec![Some(1), Some(2), None, Some(1), Some(-1)]);
+        let hashes = hash(&array, 0).unwrap();
+        assert_eq!(hashes.len(), 5);
+        assert_eq!(hashes.null_count(), 0);
+        assert_eq!(hashes.value(0), hashes.value(3));
+        assert_ne!(hashes.value(0), hashes.value(1));
+        assert_ne!(hashes.value(0), hashes.value(4));
+        assert_eq!(hashes.value(2), NULL_HASH);
+
+        // hashes are deterministic for a seed, and differ between seeds
+        assert_eq!(hash(&array, 0).unwrap(), hashes);
+        assert_ne!(hash(&array, 1).unwrap().value(0), hashes.value(0));
+
+        // hashes follow slices
+        let sliced = array.slice(3, 2);
+        let sliced_hashes = hash(sliced.as_ref(), 0).unwrap();
+        assert_eq!(sliced_hashes.values(), &hashes.values()[3..]);
+
+        let array = Float64Array::from(vec![Some(1.5), Some(2.5), Some(1.5)]);
+        let hashes = hash(&array, 0).unwrap();
+        assert_eq!(hashes.value(0), hashes.value(2));
+        assert_ne!(hashes.value(0), hashes.value(1));
+    }
+
+    #[test]
+    fn test_hash_strings() {
+        let array = StringArray::from(vec![Some("foo"), Some("bar"), None, Some("foo")]);
+        let hashes = hash(&array, 0).unwrap();
+        assert_eq!(hashes.value(0), hashes.value(3));
+        assert_ne!(hashes.value(0), hashes.value(1));
+        assert_eq!(hashes.value(2), NULL_HASH);
+
+        let large =
+            LargeStringArray::from(vec![Some("foo"), Some("bar"), None, Some("foo")]);
+        assert_eq!(hash(&large, 0).unwrap(), hashes);
+
+        let binary = BinaryArray::from(vec![
+            Some(b"foo".as_ref()),
+            Some(b"bar".as_ref()),
+            None,
+            Some(b"foo".as_ref()),
+        ]);
+        assert_eq!(hash(&binary, 0).unwrap(), hashes);
+    }
+
+    #[test]
+    fn test_hash_decimal() {
+        let array = [Some(12345_i128), Some(-5), None, Some(12345)]
+            .into_iter()
+            .collect::<Decimal128Array>()
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let hashes = hash(&array, 0).unwrap();
+        assert_eq!(hashes.value(0), hashes.value(3));
+        assert_ne!(hashes.value(0), hashes.value(1));
+        assert_eq!(hashes.value(2), NULL_HASH);
+
+        let array = Arc::new(array) as ArrayRef;
+        let array = cast(&array, &DataType::Decimal256(10, 2)).unwrap();
+        let hashes = hash(array.as_ref(), 0).unwrap();
+        assert_eq!(hashes.value(0), hashes.value(3));
+        assert_ne!(hashes.value(0), hashes.value(1));
+        assert_eq!(hashes.value(2), NULL_HASH);
+    }
+
+    #[test]
+    fn test_hash_dictionary() {
+        let values = StringArray::from(vec![Some("foo"), None, Some("bar")]);
+        let keys = Int32Array::from(vec![Some(0), Some(2), None, Some(1), Some(0)]);
+        let array = DictionaryArray::<Int32Type>::try_new(&keys, &values).unwrap();
+        let hashes = hash(&array, 7).unwrap();
+
+        let plain =
+            StringArray::from(vec![Some("foo"), Some("bar"), None, None, Some("foo")]);
+        assert_eq!(hashes, hash(&plain, 7).unwrap());
+    }
+
+    #[test]
+    fn test_hash_columns() {
+        let a = Int32Array::from(vec![Some(1), Some(1), Some(2), Some(1)]);
+        let b = StringArray::from(vec![Some("x"), Some("y"), Some("x"), Some("x")]);
+        let hashes = hash_columns(&[&a, &b], 0).unwrap();
+        assert_eq!(hashes.value(0), hashes.value(3));
+        assert_ne!(hashes.value(0), hashes.value(1));
+        assert_ne!(hashes.value(0), hashes.value(2));
+        assert_ne!(hashes.value(0), hash(&a, 0).unwrap().value(0));
+
+        // the order of the columns matters
+        let swapped = hash_columns(&[&b, &a], 0).unwrap();
+        assert_ne!(swapped.value(0), hashes.value(0));
+
+        let c = Int32Array::from(vec![1, 2]);
+        let err = hash_columns(&[&a, &c], 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: All columns to hash must have the same length, got 4 and 2"
+        );
+        assert!(hash_columns(&[], 0).is_err());
+    }
+
+    #[test]
+    fn test_hash_unsupported() {
+        let array =
+            ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)])]);
+        let err = hash(&array, 0).unwrap_err();
+        assert!(err.to_string().contains("not supported"), "{}", err);
+    }
+}
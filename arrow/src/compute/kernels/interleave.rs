@@ -0,0 +1,584 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the interleave kernel, which combines the values of several arrays
+//! in an arbitrary order
+
+use crate::array::*;
+use crate::buffer::Buffer;
+use crate::datatypes::{
+    ArrowDictionaryKeyType, ArrowNativeType, ArrowPrimitiveType, DataType,
+};
+use crate::error::{ArrowError, Result};
+use crate::row::{RowConverter, SortField};
+use crate::{downcast_dictionary_array, downcast_primitive_array};
+use std::collections::HashMap;
+
+/// Takes elements by index from a list of [`Array`], creating a new [`Array`]
+/// from those values.
+///
+/// Each element of `indices` is a pair of `usize`, the first identifying the
+/// index of an [`Array`] in `values`, and the second the index of the value
+/// within that [`Array`].
+///
+/// Dictionary arrays that share the same values are interleaved by their keys
+/// only, so that the result reuses those values without copying them. Otherwise
+/// the values referenced by `indices` are merged into a new dictionary, returning
+/// [`ArrowError::DictionaryKeyOverflowError`] if it cannot be indexed by the key type.
+///
+/// Struct, list and map arrays are interleaved by interleaving their children,
+/// so that these are handled in the same way.
+///
+/// ```
+/// # use arrow::array::Int32Array;
+/// # use arrow::compute::kernels::interleave::interleave;
+/// let a = Int32Array::from(vec![1, 2, 3]);
+/// let b = Int32Array::from(vec![4, 5, 6]);
+///
+/// let result = interleave(&[&a, &b], &[(0, 2), (1, 0), (0, 0)]).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![3, 4, 1]));
+/// ```
+pub fn interleave(values: &[&dyn Array], indices: &[(usize, usize)]) -> Result<ArrayRef> {
+    if values.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "interleave requires input of at least one array".to_string(),
+        ));
+    }
+    let data_type = values[0].data_type();
+    if values.iter().any(|array| array.data_type() != data_type) {
+        return Err(ArrowError::InvalidArgumentError(
+            "It is not possible to interleave arrays of different data types."
+                .to_string(),
+        ));
+    }
+    for (array, index) in indices {
+        match values.get(*array) {
+            Some(values) if *index < values.len() => {}
+            _ => {
+                return Err(ArrowError::ComputeError(format!(
+                    "Array index out of bounds, cannot get item ({}, {})",
+                    array, index
+                )))
+            }
+        }
+    }
+
+    let first = values[0];
+    downcast_primitive_array! {
+        first => Ok(make_array(interleave_primitive(first, values, indices)?)),
+        DataType::Dictionary(_, _) => downcast_dictionary_array! {
+            first => match interleave_dictionaries(first, values, indices)? {
+                Some(data) => Ok(make_array(data)),
+                None => Ok(interleave_fallback(values, indices)),
+            },
+            _ => unreachable!()
+        },
+        DataType::Struct(_) => Ok(make_array(interleave_struct(values, indices)?)),
+        DataType::List(_) => {
+            let arrays: Vec<_> = values.iter().map(|a| ListArray::from(a.data().clone())).collect();
+            let children: Vec<_> = arrays.iter().map(|a| a.values()).collect();
+            let offsets: Vec<_> = arrays.iter().map(|a| a.value_offsets()).collect();
+            Ok(make_array(interleave_list(values, &children, &offsets, indices)?))
+        }
+        DataType::LargeList(_) => {
+            let arrays: Vec<_> = values.iter().map(|a| LargeListArray::from(a.data().clone())).collect();
+            let children: Vec<_> = arrays.iter().map(|a| a.values()).collect();
+            let offsets: Vec<_> = arrays.iter().map(|a| a.value_offsets()).collect();
+            Ok(make_array(interleave_list(values, &children, &offsets, indices)?))
+        }
+        DataType::Map(_, _) => {
+            let arrays: Vec<_> = values.iter().map(|a| MapArray::from(a.data().clone())).collect();
+            let children: Vec<_> = arrays.iter().map(|a| make_array(a.data().child_data()[0].clone())).collect();
+            let offsets: Vec<_> = arrays.iter().map(|a| a.value_offsets()).collect();
+            Ok(make_array(interleave_list(values, &children, &offsets, indices)?))
+        }
+        DataType::FixedSizeList(_, _) => {
+            Ok(make_array(interleave_fixed_size_list(values, indices)?))
+        }
+        _ => Ok(interleave_fallback(values, indices))
+    }
+}
+
+/// Returns the validity of the interleaved `values`, or `None` if all are valid
+fn interleave_nulls(values: &[&dyn Array], indices: &[(usize, usize)]) -> Option<Buffer> {
+    values.iter().any(|array| array.null_count() > 0).then(|| {
+        let mut nulls = BooleanBufferBuilder::new(indices.len());
+        for (array, index) in indices {
+            nulls.append(values[*array].is_valid(*index));
+        }
+        nulls.finish()
+    })
+}
+
+/// Interleaves the primitive arrays `values`, whose first array is `first`
+fn interleave_primitive<T: ArrowPrimitiveType>(
+    first: &PrimitiveArray<T>,
+    values: &[&dyn Array],
+    indices: &[(usize, usize)],
+) -> Result<ArrayData> {
+    let arrays = values
+        .iter()
+        .map(|array| array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap())
+        .collect::<Vec<_>>();
+
+    let nulls = interleave_nulls(values, indices);
+    let buffer: Buffer = indices
+        .iter()
+        .map(|(array, index)| arrays[*array].value(*index))
+        .collect();
+
+    ArrayData::builder(first.data_type().clone())
+        .len(indices.len())
+        .add_buffer(buffer)
+        .null_bit_buffer(nulls)
+        .build()
+}
+
+/// Interleaves the keys of the dictionary arrays `values`, whose first array
+/// is `first`
+///
+/// If they all share the values of `first`, these are reused. Otherwise the
+/// values referenced by `indices` are merged into a new dictionary, returning
+/// `None` if their type is not supported by the [row format](crate::row)
+fn interleave_dictionaries<K: ArrowDictionaryKeyType>(
+    first: &DictionaryArray<K>,
+    values: &[&dyn Array],
+    indices: &[(usize, usize)],
+) -> Result<Option<ArrayData>> {
+    let dictionary = first.values().data();
+    let arrays = values
+        .iter()
+        .map(|array| array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap())
+        .collect::<Vec<_>>();
+    if !arrays.iter().all(|a| a.values().data().ptr_eq(dictionary)) {
+        return merge_dictionaries(first, &arrays, indices);
+    }
+
+    let keys = arrays
+        .iter()
+        .map(|a| a.keys() as &dyn Array)
+        .collect::<Vec<_>>();
+    let keys = interleave_primitive(first.keys(), &keys, indices)?;
+    let data = keys
+        .into_builder()
+        .data_type(first.data_type().clone())
+        .child_data(vec![dictionary.clone()])
+        .build()?;
+    Ok(Some(data))
+}
+
+/// Interleaves the dictionary arrays `arrays`, whose first array is `first`,
+/// into a dictionary of the distinct values referenced by `indices`
+fn merge_dictionaries<K: ArrowDictionaryKeyType>(
+    first: &DictionaryArray<K>,
+    arrays: &[&DictionaryArray<K>],
+    indices: &[(usize, usize)],
+) -> Result<Option<ArrayData>> {
+    let value_type = first.values().data_type();
+    let mut converter = RowConverter::new(vec![SortField::new(value_type.clone())]);
+    let rows = match arrays
+        .iter()
+        .map(|a| converter.convert_columns(std::slice::from_ref(a.values())))
+        .collect::<Result<Vec<_>>>()
+    {
+        Ok(rows) => rows,
+        Err(ArrowError::NotYetImplemented(_)) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    // The key of each referenced value in the merged dictionary, and the
+    // (array, value) location of each merged value
+    let mut mappings: Vec<Vec<Option<usize>>> = arrays
+        .iter()
+        .map(|a| vec![None; a.values().len()])
+        .collect();
+    let mut interned = HashMap::new();
+    let mut merged = Vec::new();
+    for (array, index) in indices.iter().copied() {
+        let keys = arrays[array].keys();
+        if keys.is_null(index) {
+            continue;
+        }
+        let value = keys.value(index).as_usize();
+        let mapping = &mut mappings[array][value];
+        if mapping.is_none() {
+            let key = *interned.entry(rows[array].row(value)).or_insert_with(|| {
+                merged.push((array, value));
+                merged.len() - 1
+            });
+            *mapping = Some(key);
+        }
+    }
+    if merged.len() > 1 && K::Native::from_usize(merged.len() - 1).is_none() {
+        return Err(ArrowError::DictionaryKeyOverflowError);
+    }
+
+    let dictionaries: Vec<_> = arrays.iter().map(|a| a.values().as_ref()).collect();
+    let dictionary = match merged.is_empty() {
+        true => new_empty_array(value_type),
+        false => interleave(&dictionaries, &merged)?,
+    };
+    let keys: PrimitiveArray<K> = indices
+        .iter()
+        .map(|(array, index)| {
+            let keys = arrays[*array].keys();
+            keys.is_valid(*index).then(|| {
+                let key = mappings[*array][keys.value(*index).as_usize()].unwrap();
+                K::Native::from_usize(key).unwrap()
+            })
+        })
+        .collect();
+    let data = keys
+        .into_data()
+        .into_builder()
+        .data_type(first.data_type().clone())
+        .child_data(vec![dictionary.into_data()])
+        .build()?;
+    Ok(Some(data))
+}
+
+/// Interleaves the struct arrays `values` by interleaving each of their fields
+fn interleave_struct(
+    values: &[&dyn Array],
+    indices: &[(usize, usize)],
+) -> Result<ArrayData> {
+    let arrays: Vec<_> = values
+        .iter()
+        .map(|a| StructArray::from(a.data().clone()))
+        .collect();
+    let children = (0..arrays[0].num_columns())
+        .map(|i| {
+            let columns: Vec<_> = arrays.iter().map(|a| a.column(i).as_ref()).collect();
+            Ok(interleave(&columns, indices)?.into_data())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    ArrayData::builder(values[0].data_type().clone())
+        .len(indices.len())
+        .null_bit_buffer(interleave_nulls(values, indices))
+        .child_data(children)
+        .build()
+}
+
+/// Interleaves the list-like arrays `values`, whose element `i` is the range
+/// `offsets[i]..offsets[i + 1]` of their `children`, by interleaving the
+/// elements of their children
+fn interleave_list<O: OffsetSizeTrait>(
+    values: &[&dyn Array],
+    children: &[ArrayRef],
+    offsets: &[&[O]],
+    indices: &[(usize, usize)],
+) -> Result<ArrayData> {
+    let mut new_offsets = Vec::with_capacity(indices.len() + 1);
+    let mut child_indices = Vec::new();
+    new_offsets.push(O::zero());
+    for (array, index) in indices.iter().copied() {
+        let start = offsets[array][index].as_usize();
+        let end = offsets[array][index + 1].as_usize();
+        child_indices.extend((start..end).map(|i| (array, i)));
+        new_offsets
+            .push(O::from_usize(child_indices.len()).ok_or_else(|| {
+                ArrowError::ComputeError("offset overflow".to_string())
+            })?);
+    }
+
+    let children: Vec<_> = children.iter().map(|c| c.as_ref()).collect();
+    let child = interleave(&children, &child_indices)?;
+
+    ArrayData::builder(values[0].data_type().clone())
+        .len(indices.len())
+        .null_bit_buffer(interleave_nulls(values, indices))
+        .add_buffer(Buffer::from_slice_ref(&new_offsets))
+        .add_child_data(child.into_data())
+        .build()
+}
+
+/// Interleaves the fixed size list arrays `values` by interleaving the
+/// elements of their children
+fn interleave_fixed_size_list(
+    values: &[&dyn Array],
+    indices: &[(usize, usize)],
+) -> Result<ArrayData> {
+    let arrays: Vec<_> = values
+        .iter()
+        .map(|a| FixedSizeListArray::from(a.data().clone()))
+        .collect();
+    let size = arrays[0].value_length() as usize;
+    let child_indices: Vec<_> = indices
+        .iter()
+        .flat_map(|(array, index)| {
+            let start = arrays[*array].value_offset(*index) as usize;
+            (start..start + size).map(move |i| (*array, i))
+        })
+        .collect();
+
+    let children: Vec<_> = arrays.iter().map(|a| a.values()).collect();
+    let children: Vec<_> = children.iter().map(|c| c.as_ref()).collect();
+    let child = interleave(&children, &child_indices)?;
+
+    ArrayData::builder(values[0].data_type().clone())
+        .len(indices.len())
+        .null_bit_buffer(interleave_nulls(values, indices))
+        .add_child_data(child.into_data())
+        .build()
+}
+
+/// Fallback implementation of interleave using [`MutableArrayData`]
+fn interleave_fallback(values: &[&dyn Array], indices: &[(usize, usize)]) -> ArrayRef {
+    let arrays = values.iter().map(|a| a.data()).collect::<Vec<_>>();
+    let mut mutable = MutableArrayData::new(arrays, false, indices.len());
+
+    // extend with runs of consecutive values from the same array
+    let mut run: Option<(usize, usize, usize)> = None;
+    for (array, index) in indices.iter().copied() {
+        run = match run {
+            Some((a, start, end)) if a == array && end == index => {
+                Some((a, start, end + 1))
+            }
+            Some((a, start, end)) => {
+                mutable.extend(a, start, end);
+                Some((array, index, index + 1))
+            }
+            None => Some((array, index, index + 1)),
+        };
+    }
+    if let Some((a, start, end)) = run {
+        mutable.extend(a, start, end);
+    }
+
+    make_array(mutable.freeze())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::{Field, Int16Type, Int8Type};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_interleave_primitive() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let b = Int32Array::from(vec![4, 5, 6]);
+        let indices = [(1, 2), (0, 1), (0, 0), (1, 0), (1, 1)];
+        let result = interleave(&[&a, &b], &indices).unwrap();
+        let expected = Int32Array::from(vec![Some(6), None, Some(1), Some(4), Some(5)]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let a = TimestampSecondArray::from(vec![1, 2]).with_timezone("+01:00".into());
+        let result = interleave(&[&a, &a], &[(1, 1), (0, 0)]).unwrap();
+        assert_eq!(result.data_type(), a.data_type());
+    }
+
+    #[test]
+    fn test_interleave_strings() {
+        let a = StringArray::from(vec![Some("a"), None, Some("c")]);
+        let b = StringArray::from(vec!["d", "e"]);
+        let indices = [(0, 2), (1, 0), (1, 1), (0, 1), (0, 0)];
+        let result = interleave(&[&a, &b], &indices).unwrap();
+        let expected =
+            StringArray::from(vec![Some("c"), Some("d"), Some("e"), None, Some("a")]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let result = interleave(&[&a, &b], &[]).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_interleave_dictionary_shared_values() {
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "z"]));
+        let a_keys = Int16Array::from(vec![Some(0), None, Some(2)]);
+        let b_keys = Int16Array::from(vec![1, 1, 0]);
+        let a = DictionaryArray::<Int16Type>::try_new(&a_keys, &values).unwrap();
+        let b = DictionaryArray::<Int16Type>::try_new(&b_keys, &values).unwrap();
+
+        let result = interleave(&[&a, &b], &[(0, 2), (1, 0), (0, 1), (0, 0)]).unwrap();
+        let result = as_dictionary_array::<Int16Type>(result.as_ref());
+        assert_eq!(
+            result.keys(),
+            &Int16Array::from(vec![Some(2), Some(1), None, Some(0)])
+        );
+        // the values are reused rather than copied
+        assert!(result.values().data().ptr_eq(values.data()));
+    }
+
+    #[test]
+    fn test_interleave_dictionary_different_values() {
+        let a: DictionaryArray<Int16Type> = vec!["a", "b"].into_iter().collect();
+        let b: DictionaryArray<Int16Type> = vec!["c", "a"].into_iter().collect();
+
+        let result = interleave(&[&a, &b], &[(1, 0), (0, 1), (1, 1)]).unwrap();
+        let result = as_dictionary_array::<Int16Type>(result.as_ref());
+        let result: Vec<_> = result
+            .keys()
+            .iter()
+            .map(|key| {
+                let values = as_string_array(result.values());
+                values.value(key.unwrap() as usize).to_string()
+            })
+            .collect();
+        assert_eq!(result, vec!["c", "b", "a"]);
+    }
+
+    #[test]
+    fn test_interleave_dictionary_merged() {
+        let a = DictionaryArray::<Int8Type>::from_iter(vec![
+            Some("a"),
+            Some("b"),
+            None,
+            Some("c"),
+        ]);
+        let b = DictionaryArray::<Int8Type>::from_iter(vec!["c", "a", "d"]);
+
+        let indices = [(1, 0), (0, 1), (0, 2), (0, 3), (1, 1)];
+        let result = interleave(&[&a, &b], &indices).unwrap();
+        let result = as_dictionary_array::<Int8Type>(result.as_ref());
+        // only the referenced values are kept, each once
+        assert_eq!(
+            result.values().as_ref(),
+            &StringArray::from(vec!["c", "b", "a"])
+        );
+        assert_eq!(
+            result.keys(),
+            &Int8Array::from(vec![Some(0), Some(1), None, Some(0), Some(2)])
+        );
+
+        let values: StringArray = (0..100).map(|i| Some(i.to_string())).collect();
+        let keys: Int8Array = (0..100).map(Some).collect();
+        let a = DictionaryArray::<Int8Type>::try_new(&keys, &values).unwrap();
+        let values: StringArray = (100..200).map(|i| Some(i.to_string())).collect();
+        let b = DictionaryArray::<Int8Type>::try_new(&keys, &values).unwrap();
+        let indices: Vec<_> = (0..100).flat_map(|i| [(0, i), (1, i)]).collect();
+        let err = interleave(&[&a, &b], &indices).unwrap_err();
+        assert!(matches!(err, ArrowError::DictionaryKeyOverflowError));
+        // few enough referenced values still fit
+        assert!(interleave(&[&a, &b], &indices[..100]).is_ok());
+    }
+
+    #[test]
+    fn test_interleave_nested() {
+        let mut builder = ListBuilder::new(Int32Builder::new());
+        builder.values().append_slice(&[1, 2]);
+        builder.append(true);
+        builder.append(false);
+        builder.values().append_null();
+        builder.append(true);
+        builder.append(true);
+        let a = builder.finish();
+        builder.values().append_slice(&[3, 4, 5]);
+        builder.append(true);
+        builder.values().append_value(6);
+        builder.append(true);
+        let b = builder.finish();
+        let b = b.slice(1, 1);
+        let indices = [(0, 2), (1, 0), (0, 0), (0, 1), (0, 3), (0, 0)];
+        let result = interleave(&[&a, b.as_ref()], &indices).unwrap();
+        assert_eq!(
+            result.data(),
+            interleave_fallback(&[&a, b.as_ref()], &indices).data()
+        );
+        let list = as_list_array(result.as_ref());
+        assert_eq!(list.value_offsets(), &[0, 1, 2, 4, 4, 4, 6]);
+
+        let mut builder = LargeListBuilder::new(StringBuilder::new());
+        builder.values().append_value("x");
+        builder.append(true);
+        builder.append(false);
+        let a = builder.finish();
+        let indices = [(0, 1), (0, 0), (0, 0)];
+        let result = interleave(&[&a], &indices).unwrap();
+        assert_eq!(result.data(), interleave_fallback(&[&a], &indices).data());
+
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(), 2);
+        builder.values().append_slice(&[1, 2]);
+        builder.append(true);
+        builder.values().append_nulls(2);
+        builder.append(false);
+        builder.values().append_slice(&[5, 6]);
+        builder.append(true);
+        let a = builder.finish();
+        let indices = [(0, 2), (0, 1), (0, 0)];
+        let result = interleave(&[&a], &indices).unwrap();
+        assert_eq!(result.data(), interleave_fallback(&[&a], &indices).data());
+
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("k");
+        builder.values().append_value(1);
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.keys().append_value("l");
+        builder.values().append_null();
+        builder.keys().append_value("m");
+        builder.values().append_value(3);
+        builder.append(true).unwrap();
+        let a = builder.finish();
+        let indices = [(0, 2), (0, 1), (0, 0), (0, 2)];
+        let result = interleave(&[&a], &indices).unwrap();
+        assert_eq!(result.data(), interleave_fallback(&[&a], &indices).data());
+    }
+
+    #[test]
+    fn test_interleave_struct() {
+        let strings: ArrayRef =
+            Arc::new(StringArray::from(vec![Some("a"), None, Some("c")]));
+        let dict: ArrayRef =
+            Arc::new(DictionaryArray::<Int8Type>::from_iter(vec!["x", "y", "x"]));
+        let a = StructArray::from((
+            vec![
+                (Field::new("s", DataType::Utf8, true), strings),
+                (Field::new("d", dict.data_type().clone(), true), dict),
+            ],
+            Buffer::from([0b101]),
+        ));
+        let strings: ArrayRef = Arc::new(StringArray::from(vec!["d", "e"]));
+        let dict: ArrayRef =
+            Arc::new(DictionaryArray::<Int8Type>::from_iter(vec!["z", "x"]));
+        let b = StructArray::from(vec![
+            (Field::new("s", DataType::Utf8, true), strings),
+            (Field::new("d", dict.data_type().clone(), true), dict),
+        ]);
+        let b = b.slice(1, 1);
+
+        let indices = [(1, 0), (0, 0), (0, 1), (0, 2)];
+        let result = interleave(&[&a, b.as_ref()], &indices).unwrap();
+        let result = as_struct_array(result.as_ref());
+        assert_eq!(result.len(), 4);
+        assert!(result.is_null(2));
+        assert_eq!(
+            result.column(0).as_ref(),
+            &StringArray::from(vec![Some("e"), Some("a"), None, Some("c")])
+        );
+        // the dictionaries of the field are merged
+        let dict = as_dictionary_array::<Int8Type>(result.column(1));
+        assert_eq!(dict.values().as_ref(), &StringArray::from(vec!["x", "y"]));
+        assert_eq!(dict.keys(), &Int8Array::from(vec![0, 0, 1, 0]));
+    }
+
+    #[test]
+    fn test_interleave_errors() {
+        let a = Int32Array::from(vec![1]);
+        let b = Int64Array::from(vec![1]);
+        let err = interleave(&[&a, &b], &[(0, 0)]).unwrap_err();
+        assert!(err.to_string().contains("different data types"), "{}", err);
+
+        let err = interleave(&[&a], &[(0, 1)]).unwrap_err();
+        assert!(err.to_string().contains("out of bounds"), "{}", err);
+
+        let err = interleave(&[&a], &[(1, 0)]).unwrap_err();
+        assert!(err.to_string().contains("out of bounds"), "{}", err);
+    }
+}
@@ -0,0 +1,406 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels for accessing the elements of list arrays
+
+use crate::array::*;
+use crate::compute::kernels::comparison::eq_dyn;
+use crate::compute::take;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// Returns the element at `index` of each list in `array`.
+///
+/// Indices are zero based, and negative indices count from the end of the
+/// list, so that `-1` is the last element. The result is null where the list
+/// is null or `index` is out of range.
+///
+/// Supports `List`, `LargeList` and `FixedSizeList` arrays.
+///
+/// ```
+/// # use arrow::array::{Int32Array, ListArray};
+/// # use arrow::compute::list_extract;
+/// # use arrow::datatypes::Int32Type;
+/// let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(1), Some(2), Some(3)]),
+///     None,
+///     Some(vec![Some(4)]),
+/// ]);
+///
+/// let first = list_extract(&list, 0).unwrap();
+/// assert_eq!(first.as_ref(), &Int32Array::from(vec![Some(1), None, Some(4)]));
+///
+/// let second_to_last = list_extract(&list, -2).unwrap();
+/// assert_eq!(second_to_last.as_ref(), &Int32Array::from(vec![Some(2), None, None]));
+/// ```
+pub fn list_extract(array: &dyn Array, index: i64) -> Result<ArrayRef> {
+    extract(array, |_| Some(index))
+}
+
+/// Returns the element at `indices[i]` of the `i`-th list in `array`.
+///
+/// Behaves like [`list_extract`], with the result also being null where the
+/// index is null.
+pub fn list_extract_indices(array: &dyn Array, indices: &Int64Array) -> Result<ArrayRef> {
+    if array.len() != indices.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "list_extract requires one index per list, got {} lists and {} indices",
+            array.len(),
+            indices.len()
+        )));
+    }
+    extract(array, |i| indices.is_valid(i).then(|| indices.value(i)))
+}
+
+/// Returns whether `values[i]` is contained in the `i`-th list of `lists`.
+///
+/// Follows SQL semantics for nulls: the result is null where the value or the
+/// list is null, or where the value is not found but the list contains a
+/// null element. Empty lists never contain the value.
+///
+/// Supports `List`, `LargeList` and `FixedSizeList` arrays whose elements
+/// have the same data type as `values`.
+///
+/// ```
+/// # use arrow::array::{BooleanArray, Int32Array, ListArray};
+/// # use arrow::compute::in_list;
+/// # use arrow::datatypes::Int32Type;
+/// let lists = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(1), Some(2)]),
+///     Some(vec![Some(3), None]),
+///     Some(vec![Some(4)]),
+///     None,
+/// ]);
+/// let values = Int32Array::from(vec![Some(2), Some(4), Some(5), Some(1)]);
+///
+/// let result = in_list(&values, &lists).unwrap();
+/// assert_eq!(result, BooleanArray::from(vec![Some(true), None, Some(false), None]));
+/// ```
+pub fn in_list(values: &dyn Array, lists: &dyn Array) -> Result<BooleanArray> {
+    if values.len() != lists.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "in_list requires one value per list, got {} values and {} lists",
+            values.len(),
+            lists.len()
+        )));
+    }
+    contains(values, lists, |i| i as u32)
+}
+
+/// Returns whether `value` is contained in each list of `lists`.
+///
+/// `value` must be an array containing a single value. Behaves like
+/// [`in_list`] with `value` repeated for every list.
+pub fn in_list_scalar(value: &dyn Array, lists: &dyn Array) -> Result<BooleanArray> {
+    if value.len() != 1 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "in_list_scalar requires a single value, got {}",
+            value.len()
+        )));
+    }
+    contains(value, lists, |_| 0)
+}
+
+/// Checks the lists of `lists` for the value at `value_index(i)` of `values`
+fn contains<F>(
+    values: &dyn Array,
+    lists: &dyn Array,
+    value_index: F,
+) -> Result<BooleanArray>
+where
+    F: Fn(usize) -> u32,
+{
+    let (elements, ranges) = list_ranges(lists, "in_list")?;
+    if values.data_type() != elements.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "in_list value of type {} does not match list element type {}",
+            values.data_type(),
+            elements.data_type()
+        )));
+    }
+
+    // compare each list element against the value of its row
+    let mut element_indices = Vec::new();
+    let mut row_indices = Vec::new();
+    for (i, range) in ranges.iter().enumerate() {
+        if let Some((start, end)) = range {
+            element_indices.extend(*start as u64..*end as u64);
+            row_indices.extend(std::iter::repeat(value_index(i)).take(end - start));
+        }
+    }
+    let elements = take(elements.as_ref(), &UInt64Array::from(element_indices), None)?;
+    let needles = take(values, &UInt32Array::from(row_indices), None)?;
+    let matches = eq_dyn(elements.as_ref(), needles.as_ref())?;
+
+    let mut position = 0;
+    Ok(ranges
+        .into_iter()
+        .enumerate()
+        .map(|(i, range)| {
+            let (start, end) = range?;
+            let row = position..position + (end - start);
+            position = row.end;
+            if values.is_null(value_index(i) as usize) {
+                return None;
+            }
+
+            let mut has_null = false;
+            for j in row {
+                if matches.is_null(j) {
+                    has_null = true;
+                } else if matches.value(j) {
+                    return Some(true);
+                }
+            }
+            (!has_null).then_some(false)
+        })
+        .collect())
+}
+
+fn extract<F>(array: &dyn Array, index: F) -> Result<ArrayRef>
+where
+    F: Fn(usize) -> Option<i64>,
+{
+    let (values, ranges) = list_ranges(array, "list_extract")?;
+    take_elements(values.as_ref(), ranges.into_iter(), index)
+}
+
+/// The range of values of a list, or `None` for a null list
+type ListRange = Option<(usize, usize)>;
+
+/// Returns the values of the list array `array`, together with the range of
+/// values of each list
+fn list_ranges(array: &dyn Array, kernel: &str) -> Result<(ArrayRef, Vec<ListRange>)> {
+    match array.data_type() {
+        DataType::List(_) => Ok(generic_list_ranges(as_list_array(array))),
+        DataType::LargeList(_) => Ok(generic_list_ranges(as_large_list_array(array))),
+        DataType::FixedSizeList(_, size) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let size = *size as usize;
+            let ranges = (0..array.len())
+                .map(|i| {
+                    let start = array.value_offset(i) as usize;
+                    array.is_valid(i).then_some((start, start + size))
+                })
+                .collect();
+            Ok((array.values(), ranges))
+        }
+        t => Err(ArrowError::InvalidArgumentError(format!(
+            "{} requires a list array, got {}",
+            kernel, t
+        ))),
+    }
+}
+
+fn generic_list_ranges<O: OffsetSizeTrait>(
+    array: &GenericListArray<O>,
+) -> (ArrayRef, Vec<ListRange>) {
+    let offsets = array.value_offsets();
+    let ranges = (0..array.len())
+        .map(|i| {
+            let range = (offsets[i].as_usize(), offsets[i + 1].as_usize());
+            array.is_valid(i).then_some(range)
+        })
+        .collect();
+    (array.values(), ranges)
+}
+
+/// Takes the element at `index(i)` of the `i`-th range of `values`, where a
+/// `None` range denotes a null list
+fn take_elements<I, F>(values: &dyn Array, ranges: I, index: F) -> Result<ArrayRef>
+where
+    I: Iterator<Item = Option<(usize, usize)>>,
+    F: Fn(usize) -> Option<i64>,
+{
+    let indices: UInt64Array = ranges
+        .enumerate()
+        .map(|(i, range)| {
+            let (start, end) = range?;
+            let len = (end - start) as i64;
+            let index = index(i)?;
+            let position = if index < 0 { len + index } else { index };
+            (0..len)
+                .contains(&position)
+                .then(|| (start as i64 + position) as u64)
+        })
+        .collect();
+    take(values, &indices, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::Buffer;
+    use crate::datatypes::{Field, Int32Type};
+
+    fn list() -> ListArray {
+        ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None, Some(3)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4), Some(5)]),
+        ])
+    }
+
+    #[test]
+    fn test_list_extract() {
+        let list = list();
+        let cases = [
+            (0, vec![Some(1), None, None, Some(4)]),
+            (1, vec![None, None, None, Some(5)]),
+            (2, vec![Some(3), None, None, None]),
+            (3, vec![None, None, None, None]),
+            (-1, vec![Some(3), None, None, Some(5)]),
+            (-3, vec![Some(1), None, None, None]),
+            (-4, vec![None, None, None, None]),
+        ];
+        for (index, expected) in cases {
+            let result = list_extract(&list, index).unwrap();
+            assert_eq!(result.as_ref(), &Int32Array::from(expected), "{}", index);
+        }
+
+        let sliced = list.slice(3, 1);
+        let result = list_extract(sliced.as_ref(), -1).unwrap();
+        assert_eq!(result.as_ref(), &Int32Array::from(vec![5]));
+    }
+
+    #[test]
+    fn test_list_extract_large_list() {
+        let list = LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3)]),
+        ]);
+        let result = list_extract(&list, 1).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &Int32Array::from(vec![Some(2), None, None])
+        );
+    }
+
+    #[test]
+    fn test_list_extract_fixed_size_list() {
+        let values = Int32Array::from(vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
+        let data = ArrayData::builder(DataType::FixedSizeList(
+            Box::new(Field::new("item", DataType::Int32, true)),
+            3,
+        ))
+        .len(3)
+        .add_child_data(values.into_data())
+        .null_bit_buffer(Some(Buffer::from([0b101])))
+        .build()
+        .unwrap();
+        let list = FixedSizeListArray::from(data);
+
+        let result = list_extract(&list, -1).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &Int32Array::from(vec![Some(2), None, Some(8)])
+        );
+
+        let sliced = list.slice(2, 1);
+        let result = list_extract(sliced.as_ref(), 0).unwrap();
+        assert_eq!(result.as_ref(), &Int32Array::from(vec![6]));
+    }
+
+    #[test]
+    fn test_list_extract_indices() {
+        let list = list();
+        let indices = Int64Array::from(vec![Some(-2), Some(0), Some(0), None]);
+        let result = list_extract_indices(&list, &indices).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &Int32Array::from(vec![None, None, None, None])
+        );
+
+        let indices = Int64Array::from(vec![2, 0, 0, -2]);
+        let result = list_extract_indices(&list, &indices).unwrap();
+        let expected = Int32Array::from(vec![Some(3), None, None, Some(4)]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let indices = Int64Array::from(vec![0]);
+        let err = list_extract_indices(&list, &indices).unwrap_err();
+        assert!(err.to_string().contains("one index per list"), "{}", err);
+    }
+
+    #[test]
+    fn test_in_list() {
+        let list = list();
+        let values = Int32Array::from(vec![Some(3), Some(1), Some(1), Some(5)]);
+        let result = in_list(&values, &list).unwrap();
+        let expected =
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        assert_eq!(result, expected);
+
+        // not found in a list containing a null is unknown
+        let values = Int32Array::from(vec![Some(2), Some(1), None, Some(6)]);
+        let result = in_list(&values, &list).unwrap();
+        let expected = BooleanArray::from(vec![None, None, None, Some(false)]);
+        assert_eq!(result, expected);
+
+        let sliced = list.slice(2, 2);
+        let values = Int32Array::from(vec![4, 4]);
+        let result = in_list(&values, sliced.as_ref()).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![false, true]));
+
+        let err = in_list(&Int32Array::from(vec![1]), &list).unwrap_err();
+        assert!(err.to_string().contains("one value per list"), "{}", err);
+
+        let values = Int64Array::from(vec![1, 2, 3, 4]);
+        let err = in_list(&values, &list).unwrap_err();
+        assert!(err.to_string().contains("does not match"), "{}", err);
+    }
+
+    #[test]
+    fn test_in_list_scalar() {
+        let list = list();
+        let result = in_list_scalar(&Int32Array::from(vec![4]), &list).unwrap();
+        let expected = BooleanArray::from(vec![None, None, Some(false), Some(true)]);
+        assert_eq!(result, expected);
+
+        let result = in_list_scalar(&Int32Array::from(vec![None]), &list).unwrap();
+        assert_eq!(result.null_count(), 4);
+
+        let err = in_list_scalar(&Int32Array::from(vec![1, 2]), &list).unwrap_err();
+        assert!(err.to_string().contains("single value"), "{}", err);
+    }
+
+    #[test]
+    fn test_in_list_utf8() {
+        let values = StringArray::from(vec!["a", "b", "c", "d", "e", "f"]);
+        let data = ArrayData::builder(DataType::FixedSizeList(
+            Box::new(Field::new("item", DataType::Utf8, true)),
+            2,
+        ))
+        .len(3)
+        .add_child_data(values.into_data())
+        .build()
+        .unwrap();
+        let list = FixedSizeListArray::from(data);
+
+        let values = StringArray::from(vec!["b", "b", "f"]);
+        let result = in_list(&values, &list).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![true, false, true]));
+    }
+
+    #[test]
+    fn test_list_extract_not_list() {
+        let array = Int32Array::from(vec![1]);
+        let err = list_extract(&array, 0).unwrap_err();
+        assert!(err.to_string().contains("requires a list"), "{}", err);
+    }
+}
@@ -0,0 +1,199 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels for accessing the keys and values of map arrays
+
+use crate::array::*;
+use crate::compute::kernels::comparison::eq_dyn;
+use crate::compute::take;
+use crate::datatypes::{DataType, Field};
+use crate::error::{ArrowError, Result};
+
+/// Returns the keys of each map in `map` as a [`ListArray`].
+///
+/// This does not copy any data, and null maps become null lists.
+pub fn map_keys(map: &MapArray) -> Result<ListArray> {
+    to_list(map, map.keys(), entry_field(map, 0).is_nullable())
+}
+
+/// Returns the values of each map in `map` as a [`ListArray`].
+///
+/// This does not copy any data, and null maps become null lists.
+pub fn map_values(map: &MapArray) -> Result<ListArray> {
+    to_list(map, map.values(), entry_field(map, 1).is_nullable())
+}
+
+/// Returns the key (`0`) or value (`1`) field of the entries of `map`
+fn entry_field(map: &MapArray, i: usize) -> &Field {
+    match map.data_type() {
+        DataType::Map(entries, _) => match entries.data_type() {
+            DataType::Struct(fields) => &fields[i],
+            _ => unreachable!("map entries must be a struct"),
+        },
+        _ => unreachable!("MapArray must have a map data type"),
+    }
+}
+
+/// Returns a list array sharing the offsets and validity of `map`
+fn to_list(map: &MapArray, values: ArrayRef, nullable: bool) -> Result<ListArray> {
+    let data = map.data();
+    let field = Field::new("item", values.data_type().clone(), nullable);
+    let list = ArrayData::builder(DataType::List(Box::new(field)))
+        .len(data.len())
+        .offset(data.offset())
+        .null_bit_buffer(data.null_buffer().cloned())
+        .add_buffer(data.buffers()[0].clone())
+        .add_child_data(values.into_data())
+        .build()?;
+    Ok(ListArray::from(list))
+}
+
+/// Returns the value associated with `key` in each map of `map`.
+///
+/// `key` must be an array containing a single value of the map's key type.
+/// The result is null where the map is null or does not contain `key`. If a
+/// map contains `key` more than once, the first value is returned.
+///
+/// ```
+/// # use arrow::array::{Int32Array, Int32Builder, MapBuilder, StringArray, StringBuilder};
+/// # use arrow::compute::map_lookup;
+/// let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+/// builder.keys().append_value("a");
+/// builder.values().append_value(1);
+/// builder.keys().append_value("b");
+/// builder.values().append_value(2);
+/// builder.append(true).unwrap();
+/// builder.keys().append_value("a");
+/// builder.values().append_value(3);
+/// builder.append(true).unwrap();
+/// let map = builder.finish();
+///
+/// let values = map_lookup(&map, &StringArray::from(vec!["b"])).unwrap();
+/// assert_eq!(values.as_ref(), &Int32Array::from(vec![Some(2), None]));
+/// ```
+pub fn map_lookup(map: &MapArray, key: &dyn Array) -> Result<ArrayRef> {
+    if key.len() != 1 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "map_lookup requires a single key, got {}",
+            key.len()
+        )));
+    }
+    if key.data_type() != &map.key_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "map_lookup key of type {} does not match map key type {}",
+            key.data_type(),
+            map.key_type()
+        )));
+    }
+
+    let keys = map.keys();
+    let key = take(key, &UInt32Array::from(vec![0; keys.len()]), None)?;
+    let matches = eq_dyn(keys.as_ref(), key.as_ref())?;
+
+    let offsets = map.value_offsets();
+    let indices: UInt32Array = (0..map.len())
+        .map(|i| {
+            if map.is_null(i) {
+                return None;
+            }
+            let (start, end) = (offsets[i] as usize, offsets[i + 1] as usize);
+            (start..end)
+                .find(|&j| matches.is_valid(j) && matches.value(j))
+                .map(|j| j as u32)
+        })
+        .collect();
+
+    take(map.values().as_ref(), &indices, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::Int32Type;
+
+    fn map() -> MapArray {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("b");
+        builder.values().append_null();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.append(true).unwrap();
+        builder.keys().append_value("b");
+        builder.values().append_value(4);
+        builder.keys().append_value("b");
+        builder.values().append_value(5);
+        builder.append(true).unwrap();
+        builder.finish()
+    }
+
+    #[test]
+    fn test_map_keys_values() {
+        let map = map();
+
+        let keys = map_keys(&map).unwrap();
+        assert_eq!(keys.len(), 4);
+        assert_eq!(keys.value(0).as_ref(), &StringArray::from(vec!["a", "b"]));
+        assert!(keys.is_null(1));
+        assert_eq!(keys.value_length(2), 0);
+        assert_eq!(keys.value(3).as_ref(), &StringArray::from(vec!["b", "b"]));
+
+        let values = map_values(&map).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4), Some(5)]),
+        ]);
+        assert_eq!(values, expected);
+
+        let sliced = map.slice(3, 1);
+        let sliced = sliced.as_any().downcast_ref::<MapArray>().unwrap();
+        let values = map_values(sliced).unwrap();
+        assert_eq!(values.len(), 1);
+        assert_eq!(values.value(0).as_ref(), &Int32Array::from(vec![4, 5]));
+    }
+
+    #[test]
+    fn test_map_lookup() {
+        let map = map();
+
+        let result = map_lookup(&map, &StringArray::from(vec!["a"])).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &Int32Array::from(vec![Some(1), None, None, None])
+        );
+
+        // duplicate keys return the first value
+        let result = map_lookup(&map, &StringArray::from(vec!["b"])).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &Int32Array::from(vec![None, None, None, Some(4)])
+        );
+
+        let result = map_lookup(&map, &StringArray::from(vec!["c"])).unwrap();
+        assert_eq!(result.null_count(), 4);
+
+        let err = map_lookup(&map, &StringArray::from(vec!["a", "b"])).unwrap_err();
+        assert!(err.to_string().contains("single key"), "{}", err);
+
+        let err = map_lookup(&map, &Int32Array::from(vec![1])).unwrap_err();
+        assert!(err.to_string().contains("does not match"), "{}", err);
+    }
+}
@@ -27,14 +27,24 @@ pub mod cast_utils;
 pub mod comparison;
 pub mod concat;
 pub mod concat_elements;
+pub mod datum;
+pub mod decimal;
 pub mod filter;
+pub mod hash;
+pub mod interleave;
 pub mod length;
 pub mod limit;
+pub mod list;
+pub mod map;
 pub mod partition;
 pub mod regexp;
+pub mod selection;
 pub mod sort;
+pub mod struct_field;
 pub mod substring;
 pub mod take;
 pub mod temporal;
+pub mod union;
+pub mod unnest;
 pub mod window;
 pub mod zip;
@@ -15,11 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines partition kernel for `ArrayRef`
+//! Defines partition kernels for `ArrayRef` and `RecordBatch`
 
+use crate::array::{ArrayRef, UInt32Array};
+use crate::compute::kernels::hash::hash_columns;
 use crate::compute::kernels::sort::LexicographicalComparator;
-use crate::compute::SortColumn;
+use crate::compute::{take, SortColumn};
 use crate::error::{ArrowError, Result};
+use crate::record_batch::{RecordBatch, RecordBatchOptions};
 use std::cmp::Ordering;
 use std::iter::Iterator;
 use std::ops::Range;
@@ -39,6 +42,94 @@ pub fn lexicographical_partition_ranges(
     LexicographicalPartitionIterator::try_new(columns)
 }
 
+/// Partitions the rows of `batch` into `num_partitions` batches by the hash
+/// of the columns at `key_columns`.
+///
+/// Rows with equal keys are always assigned the same partition, and rows keep
+/// their relative order within each partition. Rows are hashed with
+/// [`hash_columns`], so dictionaries are assigned the same partitions as their
+/// values, and the assignment is deterministic but not guaranteed to be stable
+/// across platforms or releases of this crate. Use [`hash_partition_indices`] to
+/// obtain the row indices of each partition without materializing the batches.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{ArrayRef, Int32Array};
+/// # use arrow::compute::partition_by_hash;
+/// # use arrow::record_batch::RecordBatch;
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 1, 3, 2]));
+/// let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+///
+/// let partitions = partition_by_hash(&batch, &[0], 4).unwrap();
+/// assert_eq!(partitions.len(), 4);
+/// assert_eq!(partitions.iter().map(|p| p.num_rows()).sum::<usize>(), 5);
+/// ```
+pub fn partition_by_hash(
+    batch: &RecordBatch,
+    key_columns: &[usize],
+    num_partitions: usize,
+) -> Result<Vec<RecordBatch>> {
+    let keys = key_columns
+        .iter()
+        .map(|idx| {
+            batch.columns().get(*idx).cloned().ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "key column index {} out of bounds, max field {}",
+                    idx,
+                    batch.num_columns()
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    hash_partition_indices(&keys, num_partitions)?
+        .iter()
+        .map(|indices| {
+            let columns = batch
+                .columns()
+                .iter()
+                .map(|c| take(c.as_ref(), indices, None))
+                .collect::<Result<Vec<_>>>()?;
+            let options = RecordBatchOptions::new().with_row_count(Some(indices.len()));
+            RecordBatch::try_new_with_options(batch.schema(), columns, &options)
+        })
+        .collect()
+}
+
+/// Returns, for each of `num_partitions` partitions, the ascending indices of
+/// the rows of `columns` assigned to it by the hash of their values.
+///
+/// See [`partition_by_hash`] for details. Returns an error if the columns have
+/// different lengths, or types that [`hash_columns`] doesn't support.
+pub fn hash_partition_indices(
+    columns: &[ArrayRef],
+    num_partitions: usize,
+) -> Result<Vec<UInt32Array>> {
+    if num_partitions == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "hash partitioning requires at least one partition".to_string(),
+        ));
+    }
+    if columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "hash partitioning requires at least one key column".to_string(),
+        ));
+    }
+
+    let columns: Vec<_> = columns.iter().map(|c| c.as_ref()).collect();
+    let hashes = hash_columns(&columns, PARTITION_SEED)?;
+
+    let mut partitions = vec![Vec::new(); num_partitions];
+    for (idx, hash) in hashes.values().iter().enumerate() {
+        let partition = hash % num_partitions as u64;
+        partitions[partition as usize].push(idx as u32);
+    }
+    Ok(partitions.into_iter().map(UInt32Array::from).collect())
+}
+
+/// The seed of the hashes used to assign rows to partitions
+const PARTITION_SEED: u64 = 0;
+
 struct LexicographicalPartitionIterator<'a> {
     comparator: LexicographicalComparator<'a>,
     num_rows: usize,
@@ -164,7 +255,7 @@ mod tests {
     use super::*;
     use crate::array::*;
     use crate::compute::SortOptions;
-    use crate::datatypes::DataType;
+    use crate::datatypes::{DataType, Int32Type, Int8Type};
     use std::sync::Arc;
 
     #[test]
@@ -405,4 +496,77 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_partition_by_hash() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(1),
+            Some(2),
+            None,
+            Some(1),
+            Some(3),
+            None,
+            Some(2),
+        ]));
+        let b: ArrayRef =
+            Arc::new(StringArray::from(vec!["x", "y", "x", "x", "z", "x", "y"]));
+        let batch = RecordBatch::try_from_iter([("a", a.clone()), ("b", b)]).unwrap();
+
+        let indices = hash_partition_indices(std::slice::from_ref(&a), 3).unwrap();
+        assert_eq!(indices.len(), 3);
+        let mut all: Vec<_> = indices.iter().flat_map(|p| p.values().to_vec()).collect();
+        all.sort_unstable();
+        assert_eq!(all, (0..7).collect::<Vec<_>>());
+
+        // Equal keys are in the same partition, in order
+        for partition in &indices {
+            let values = partition.values();
+            assert!(values.windows(2).all(|w| w[0] < w[1]));
+            for (x, y) in [(0, 3), (1, 6), (2, 5)] {
+                assert_eq!(values.contains(&x), values.contains(&y));
+            }
+        }
+
+        // The assignment is deterministic
+        assert_eq!(
+            indices,
+            hash_partition_indices(std::slice::from_ref(&a), 3).unwrap()
+        );
+
+        let partitions = partition_by_hash(&batch, &[0, 1], 3).unwrap();
+        assert_eq!(partitions.len(), 3);
+        assert_eq!(partitions.iter().map(|p| p.num_rows()).sum::<usize>(), 7);
+        for partition in &partitions {
+            assert_eq!(partition.schema(), batch.schema());
+        }
+
+        // Dictionaries hash the same as their values
+        let dict: DictionaryArray<Int8Type> = vec!["x", "y", "x", "x", "z", "x", "y"]
+            .into_iter()
+            .collect();
+        let strings: ArrayRef =
+            Arc::new(StringArray::from(vec!["x", "y", "x", "x", "z", "x", "y"]));
+        let expected = hash_partition_indices(&[strings], 4).unwrap();
+        assert_eq!(
+            hash_partition_indices(&[Arc::new(dict)], 4).unwrap(),
+            expected
+        );
+
+        // as do dictionaries of dictionaries
+        let values: DictionaryArray<Int8Type> = vec!["z", "x", "y"].into_iter().collect();
+        let keys = Int32Array::from(vec![1, 2, 1, 1, 0, 1, 2]);
+        let dict = DictionaryArray::<Int32Type>::try_new(&keys, &values).unwrap();
+        assert_eq!(
+            hash_partition_indices(&[Arc::new(dict)], 4).unwrap(),
+            expected
+        );
+
+        let err = partition_by_hash(&batch, &[2], 3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: key column index 2 out of bounds, max field 2"
+        );
+        assert!(partition_by_hash(&batch, &[0], 0).is_err());
+        assert!(partition_by_hash(&batch, &[], 2).is_err());
+    }
 }
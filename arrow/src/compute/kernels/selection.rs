@@ -0,0 +1,532 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines [`Selection`], a set of selected rows that can be shared by the
+//! filter, take and row format kernels
+
+use std::ops::Range;
+
+use crate::array::*;
+use crate::buffer::{buffer_bin_and, Buffer};
+use crate::compute::kernels::filter::{prep_null_mask_filter, FilterBuilder};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+use crate::util::bit_iterator::{BitIndexIterator, BitSliceIterator};
+
+/// The representation of the rows selected by a [`Selection`]
+#[derive(Debug, Clone)]
+pub(crate) enum SelectionKind {
+    /// A bitmask starting at offset zero, with a set bit for each selected row
+    Mask(Buffer),
+    /// The strictly increasing indices of the selected rows
+    Indices(Vec<usize>),
+    /// Sorted, non-overlapping and non-empty ranges of selected rows
+    Ranges(Vec<Range<usize>>),
+}
+
+/// A selection of rows out of `len` rows, stored either as a bitmask, as a
+/// sorted list of indices or as a sorted list of ranges.
+///
+/// This allows a pipeline to compute a selection once, e.g. from a predicate
+/// or a page index, and then apply it with [`select`], [`take`] or
+/// [`RowConverter::convert_selected_rows`] without converting it between
+/// representations at each step.
+///
+/// Selections from the same rows can be intersected with [`Selection::and`] and
+/// narrowed with [`Selection::retain`], so a chain of predicates only needs to
+/// filter the values once, at the end.
+///
+/// ```
+/// # use arrow::array::{BooleanArray, Int32Array};
+/// # use arrow::compute::kernels::selection::{select, Selection};
+/// let array = Int32Array::from(vec![1, 2, 3, 4, 5]);
+///
+/// let selection = Selection::try_from_ranges(vec![0..2, 4..5], 5).unwrap();
+/// assert_eq!(selection.selected_count(), 3);
+/// let selected = select(&array, &selection).unwrap();
+/// assert_eq!(selected.as_ref(), &Int32Array::from(vec![1, 2, 5]));
+///
+/// let filter = BooleanArray::from(vec![true, true, false, false, true]);
+/// assert_eq!(selection.to_filter(), filter);
+/// ```
+///
+/// [`take`]: crate::compute::kernels::take::take
+/// [`RowConverter::convert_selected_rows`]: crate::row::RowConverter::convert_selected_rows
+#[derive(Debug, Clone)]
+pub struct Selection {
+    len: usize,
+    count: usize,
+    kind: SelectionKind,
+}
+
+impl Selection {
+    /// Creates a [`Selection`] of the rows of `filter` that are true, treating
+    /// nulls as false
+    pub fn from_filter(filter: &BooleanArray) -> Self {
+        let filter = match filter.null_count() {
+            0 => BooleanArray::from(filter.data().clone()),
+            _ => prep_null_mask_filter(filter),
+        };
+        let mask = filter.values().bit_slice(filter.offset(), filter.len());
+        Self {
+            len: filter.len(),
+            count: mask.count_set_bits_offset(0, filter.len()),
+            kind: SelectionKind::Mask(mask),
+        }
+    }
+
+    /// Creates a [`Selection`] of the rows at `indices` out of `len` rows
+    ///
+    /// Returns an error if `indices` are not strictly increasing or are out of
+    /// bounds
+    pub fn try_from_indices(indices: Vec<usize>, len: usize) -> Result<Self> {
+        if let Some(w) = indices.windows(2).find(|w| w[0] >= w[1]) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "selection indices must be strictly increasing, got {} followed by {}",
+                w[0], w[1]
+            )));
+        }
+        if let Some(last) = indices.last().filter(|last| **last >= len) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "selection index {} is out of bounds for {} rows",
+                last, len
+            )));
+        }
+        Ok(Self {
+            len,
+            count: indices.len(),
+            kind: SelectionKind::Indices(indices),
+        })
+    }
+
+    /// Creates a [`Selection`] of the rows in `ranges` out of `len` rows
+    ///
+    /// Empty ranges are ignored and adjacent ranges are merged. Returns an error
+    /// if `ranges` are not sorted, overlap or are out of bounds
+    pub fn try_from_ranges(ranges: Vec<Range<usize>>, len: usize) -> Result<Self> {
+        let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            if range.start > range.end || range.end > len {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "selection range {:?} is invalid for {} rows",
+                    range, len
+                )));
+            }
+            if range.is_empty() {
+                continue;
+            }
+            match merged.last_mut() {
+                Some(last) if last.end > range.start => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "selection ranges must be sorted and non-overlapping, got {:?} followed by {:?}",
+                        last, range
+                    )))
+                }
+                Some(last) if last.end == range.start => last.end = range.end,
+                _ => merged.push(range),
+            }
+        }
+        Ok(Self {
+            len,
+            count: merged.iter().map(|r| r.len()).sum(),
+            kind: SelectionKind::Ranges(merged),
+        })
+    }
+
+    /// Creates a [`Selection`] of all `len` rows
+    pub fn all(len: usize) -> Self {
+        let ranges = (len != 0).then_some(0..len).into_iter().collect();
+        Self {
+            len,
+            count: len,
+            kind: SelectionKind::Ranges(ranges),
+        }
+    }
+
+    /// Creates a [`Selection`] of none of `len` rows
+    pub fn none(len: usize) -> Self {
+        Self {
+            len,
+            count: 0,
+            kind: SelectionKind::Ranges(vec![]),
+        }
+    }
+
+    /// Returns the total number of rows this [`Selection`] selects from
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if this [`Selection`] selects from zero rows
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of selected rows
+    pub fn selected_count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if this [`Selection`] selects any rows
+    pub fn selects_any(&self) -> bool {
+        self.count != 0
+    }
+
+    /// Returns the underlying representation of this [`Selection`]
+    pub(crate) fn kind(&self) -> &SelectionKind {
+        &self.kind
+    }
+
+    /// Returns an iterator of the indices of the selected rows, in increasing order
+    pub fn iter_indices(&self) -> Box<dyn Iterator<Item = usize> + '_> {
+        match &self.kind {
+            SelectionKind::Mask(mask) => {
+                Box::new(BitIndexIterator::new(mask.as_slice(), 0, self.len))
+            }
+            SelectionKind::Indices(indices) => Box::new(indices.iter().copied()),
+            SelectionKind::Ranges(ranges) => Box::new(ranges.iter().cloned().flatten()),
+        }
+    }
+
+    /// Returns an iterator of the maximal non-empty ranges of selected rows,
+    /// in increasing order
+    pub fn iter_ranges(&self) -> Box<dyn Iterator<Item = Range<usize>> + '_> {
+        match &self.kind {
+            SelectionKind::Mask(mask) => Box::new(
+                BitSliceIterator::new(mask.as_slice(), 0, self.len)
+                    .map(|(start, end)| start..end),
+            ),
+            SelectionKind::Indices(indices) => {
+                let mut indices = indices.iter().copied().peekable();
+                Box::new(std::iter::from_fn(move || {
+                    let start = indices.next()?;
+                    let mut end = start + 1;
+                    while indices.next_if_eq(&end).is_some() {
+                        end += 1;
+                    }
+                    Some(start..end)
+                }))
+            }
+            SelectionKind::Ranges(ranges) => Box::new(ranges.iter().cloned()),
+        }
+    }
+
+    /// Returns a [`BooleanArray`] without nulls that is true for each selected row
+    pub fn to_filter(&self) -> BooleanArray {
+        let mask = match &self.kind {
+            SelectionKind::Mask(mask) => mask.clone(),
+            _ => {
+                let mut mask = BooleanBufferBuilder::new(self.len);
+                for range in self.iter_ranges() {
+                    mask.append_n(range.start - mask.len(), false);
+                    mask.append_n(range.len(), true);
+                }
+                mask.append_n(self.len - mask.len(), false);
+                mask.finish()
+            }
+        };
+        let data = ArrayData::builder(DataType::Boolean)
+            .len(self.len)
+            .add_buffer(mask);
+        // SAFETY: the mask has at least `len` bits
+        BooleanArray::from(unsafe { data.build_unchecked() })
+    }
+
+    /// Returns the indices of the selected rows, e.g. to pass to [`take`]
+    ///
+    /// [`take`]: crate::compute::kernels::take::take
+    pub fn to_indices(&self) -> UInt64Array {
+        let values: Buffer = self.iter_indices().map(|i| i as u64).collect();
+        let data = ArrayData::builder(DataType::UInt64)
+            .len(self.count)
+            .add_buffer(values);
+        // SAFETY: the buffer contains one value per selected row
+        UInt64Array::from(unsafe { data.build_unchecked() })
+    }
+
+    /// Given a [`Selection`] of the rows selected by `self`, returns the
+    /// [`Selection`] of the original rows selected by both
+    ///
+    /// Returns an error if the length of `other` is not the number of rows
+    /// selected by `self`
+    pub fn and_then(&self, other: &Selection) -> Result<Selection> {
+        if other.len != self.count {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "cannot apply selection of {} rows to selection of {} rows",
+                other.len, self.count
+            )));
+        }
+        let selected: Vec<_> = self.iter_indices().collect();
+        let indices = other.iter_indices().map(|i| selected[i]).collect();
+        Selection::try_from_indices(indices, self.len)
+    }
+
+    /// Returns the [`Selection`] of the rows selected by both `self` and `other`,
+    /// which must select from the same rows
+    ///
+    /// Unlike [`Selection::and_then`], both selections refer to the original rows,
+    /// so predicates can be evaluated independently and combined before any
+    /// values are filtered
+    pub fn and(&self, other: &Selection) -> Result<Selection> {
+        if other.len != self.len {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "cannot intersect selection of {} rows with selection of {} rows",
+                self.len, other.len
+            )));
+        }
+        if let (SelectionKind::Mask(l), SelectionKind::Mask(r)) =
+            (&self.kind, &other.kind)
+        {
+            let mask = buffer_bin_and(l, 0, r, 0, self.len);
+            return Ok(Self {
+                len: self.len,
+                count: mask.count_set_bits_offset(0, self.len),
+                kind: SelectionKind::Mask(mask),
+            });
+        }
+
+        let mut ranges = Vec::new();
+        let mut left = self.iter_ranges().peekable();
+        let mut right = other.iter_ranges().peekable();
+        while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+            let start = l.start.max(r.start);
+            let end = l.end.min(r.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            // Advance whichever range ends first, as it cannot overlap any later range
+            if l.end <= r.end {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+        Selection::try_from_ranges(ranges, self.len)
+    }
+
+    /// Returns the [`Selection`] of the selected rows for which `predicate`,
+    /// called with the index of each selected row, returns `true`
+    ///
+    /// This allows a predicate to only be evaluated on the rows that remain
+    /// selected, e.g. when the earlier predicates of a chain are very selective
+    ///
+    /// ```
+    /// # use arrow::array::Int32Array;
+    /// # use arrow::compute::kernels::selection::Selection;
+    /// let array = Int32Array::from(vec![1, 2, 3, 4, 5]);
+    /// let selection = Selection::try_from_indices(vec![0, 1, 3], 5).unwrap();
+    /// let even = selection.retain(|i| array.value(i) % 2 == 0);
+    /// assert_eq!(even.iter_indices().collect::<Vec<_>>(), vec![1, 3]);
+    /// ```
+    pub fn retain<F: FnMut(usize) -> bool>(&self, mut predicate: F) -> Selection {
+        let indices: Vec<_> = self.iter_indices().filter(|i| predicate(*i)).collect();
+        Self {
+            len: self.len,
+            count: indices.len(),
+            kind: SelectionKind::Indices(indices),
+        }
+    }
+}
+
+impl From<&BooleanArray> for Selection {
+    fn from(filter: &BooleanArray) -> Self {
+        Self::from_filter(filter)
+    }
+}
+
+/// Returns the rows of `values` selected by `selection`
+///
+/// Returns an error if the length of `selection` is not the length of `values`
+pub fn select(values: &dyn Array, selection: &Selection) -> Result<ArrayRef> {
+    check_len(values.len(), selection)?;
+    FilterBuilder::from_selection(selection)
+        .build()
+        .filter(values)
+}
+
+/// Returns a new [`RecordBatch`] with the rows of `record_batch` selected by
+/// `selection`
+pub fn select_record_batch(
+    record_batch: &RecordBatch,
+    selection: &Selection,
+) -> Result<RecordBatch> {
+    check_len(record_batch.num_rows(), selection)?;
+    let predicate = FilterBuilder::from_selection(selection).build();
+    let columns = record_batch
+        .columns()
+        .iter()
+        .map(|column| predicate.filter(column))
+        .collect::<Result<Vec<_>>>()?;
+    RecordBatch::try_new(record_batch.schema(), columns)
+}
+
+fn check_len(len: usize, selection: &Selection) -> Result<()> {
+    if selection.len() != len {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "selection of {} rows cannot be applied to {} rows",
+            selection.len(),
+            len
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute::kernels::boolean::and;
+    use crate::compute::kernels::comparison::{eq_utf8_scalar, gt_scalar};
+    use crate::compute::kernels::filter::filter_record_batch;
+    use crate::compute::kernels::take::take;
+    use crate::datatypes::Int32Type;
+    use std::sync::Arc;
+
+    fn representations() -> Vec<Selection> {
+        let filter = BooleanArray::from(vec![
+            Some(false),
+            Some(true),
+            Some(true),
+            None,
+            Some(true),
+        ]);
+        let padded = BooleanArray::from(vec![true, false, true, true, false, true]);
+        let sliced = padded.slice(1, 5);
+        vec![
+            Selection::from_filter(&filter),
+            Selection::from_filter(as_boolean_array(sliced.as_ref())),
+            Selection::try_from_indices(vec![1, 2, 4], 5).unwrap(),
+            Selection::try_from_ranges(vec![1..2, 2..3, 3..3, 4..5], 5).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_conversions() {
+        for selection in representations() {
+            assert_eq!(selection.len(), 5);
+            assert_eq!(selection.selected_count(), 3);
+            assert!(selection.selects_any());
+            assert_eq!(selection.iter_indices().collect::<Vec<_>>(), vec![1, 2, 4]);
+            assert_eq!(
+                selection.iter_ranges().collect::<Vec<_>>(),
+                vec![1..3, 4..5]
+            );
+            assert_eq!(
+                selection.to_filter(),
+                BooleanArray::from(vec![false, true, true, false, true])
+            );
+            assert_eq!(selection.to_indices(), UInt64Array::from(vec![1, 2, 4]));
+        }
+
+        let all = Selection::all(3);
+        assert_eq!(all.iter_indices().collect::<Vec<_>>(), vec![0, 1, 2]);
+        let none = Selection::none(3);
+        assert!(!none.selects_any());
+        assert_eq!(none.to_filter(), BooleanArray::from(vec![false; 3]));
+        assert!(Selection::all(0).is_empty());
+    }
+
+    #[test]
+    fn test_select() {
+        let array = StringArray::from(vec![Some("a"), Some("b"), None, Some("d"), None]);
+        let expected = StringArray::from(vec![Some("b"), None, None]);
+        for selection in representations() {
+            let selected = select(&array, &selection).unwrap();
+            assert_eq!(selected.as_ref(), &expected);
+
+            let taken = take(&array, &selection.to_indices(), None).unwrap();
+            assert_eq!(taken.as_ref(), &expected);
+        }
+
+        let err = select(&array, &Selection::all(4)).unwrap_err();
+        assert!(err.to_string().contains("cannot be applied"), "{}", err);
+    }
+
+    #[test]
+    fn test_and_then() {
+        for selection in representations() {
+            let other = Selection::try_from_indices(vec![0, 2], 3).unwrap();
+            let combined = selection.and_then(&other).unwrap();
+            assert_eq!(combined.len(), 5);
+            assert_eq!(combined.iter_indices().collect::<Vec<_>>(), vec![1, 4]);
+
+            assert!(selection.and_then(&Selection::all(5)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_and() {
+        let others = [
+            Selection::from_filter(&BooleanArray::from(vec![
+                true, false, true, true, true,
+            ])),
+            Selection::try_from_indices(vec![0, 2, 3, 4], 5).unwrap(),
+            Selection::try_from_ranges(vec![0..1, 2..5], 5).unwrap(),
+        ];
+        for selection in representations() {
+            for other in &others {
+                let combined = selection.and(other).unwrap();
+                assert_eq!(combined.len(), 5);
+                assert_eq!(combined.selected_count(), 2);
+                assert_eq!(combined.iter_indices().collect::<Vec<_>>(), vec![2, 4]);
+                assert_eq!(
+                    combined.to_filter(),
+                    other.and(&selection).unwrap().to_filter()
+                );
+            }
+            assert!(!selection.and(&Selection::none(5)).unwrap().selects_any());
+            assert!(selection.and(&Selection::all(4)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_lazy_filter_chain() {
+        let a = Int32Array::from(vec![Some(1), Some(5), None, Some(7), Some(2), Some(9)]);
+        let b = StringArray::from(vec!["x", "y", "x", "x", "y", "x"]);
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
+        let a = as_primitive_array::<Int32Type>(batch.column(0));
+        let b = as_string_array(batch.column(1));
+
+        // a > 1 AND b = 'x' AND a is odd, only materializing the final batch
+        let gt = Selection::from_filter(&gt_scalar(a, 1).unwrap());
+        let eq = Selection::from_filter(&eq_utf8_scalar(b, "x").unwrap());
+        let selection = gt.and(&eq).unwrap().retain(|i| a.value(i) % 2 == 1);
+        assert_eq!(selection.iter_indices().collect::<Vec<_>>(), vec![3, 5]);
+
+        let actual = select_record_batch(&batch, &selection).unwrap();
+        let filter =
+            and(&gt_scalar(a, 1).unwrap(), &eq_utf8_scalar(b, "x").unwrap()).unwrap();
+        let expected = filter_record_batch(&batch, &filter).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let err = Selection::try_from_indices(vec![1, 1], 5).unwrap_err();
+        assert!(err.to_string().contains("strictly increasing"), "{}", err);
+        let err = Selection::try_from_indices(vec![5], 5).unwrap_err();
+        assert!(err.to_string().contains("out of bounds"), "{}", err);
+        let err = Selection::try_from_ranges(vec![2..4, 3..5], 5).unwrap_err();
+        assert!(err.to_string().contains("non-overlapping"), "{}", err);
+        let err = Selection::try_from_ranges(vec![0..1, 2..6], 5).unwrap_err();
+        assert!(err.to_string().contains("invalid"), "{}", err);
+    }
+}
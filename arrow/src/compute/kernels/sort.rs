@@ -23,8 +23,10 @@ use crate::compute::take;
 use crate::datatypes::*;
 use crate::downcast_dictionary_array;
 use crate::error::{ArrowError, Result};
+use crate::record_batch::{RecordBatch, RecordBatchOptions};
+use crate::row::{RowConverter, SortField};
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{BinaryHeap, HashMap};
 use TimeUnit::*;
 
 /// Sort the `ArrayRef` using `SortOptions`.
@@ -136,6 +138,12 @@ fn partition_validity(array: &ArrayRef) -> (Vec<u32>, Vec<u32>) {
 /// Sort elements from `ArrayRef` into an unsigned integer (`UInt32Array`) of indices.
 /// For floating point arrays any NaN values are considered to be greater than any other non-null value
 /// limit is an option for partial_sort
+///
+/// Struct, list and map arrays are sorted lexicographically by their fields or
+/// elements, as defined by [`build_compare`].
+///
+/// This sort is not stable: equal values may be returned in any order. Use
+/// [`sort_to_indices_stable`] to preserve their relative order.
 pub fn sort_to_indices(
     values: &ArrayRef,
     options: Option<SortOptions>,
@@ -278,12 +286,7 @@ pub fn sort_to_indices(
             DataType::Float64 => {
                 sort_list::<i32, Float64Type>(values, v, n, &options, limit)
             }
-            t => {
-                return Err(ArrowError::ComputeError(format!(
-                    "Sort not supported for list type {:?}",
-                    t
-                )));
-            }
+            _ => sort_nested(values, v, n, &options, limit)?,
         },
         DataType::LargeList(field) => match field.data_type() {
             DataType::Int8 => sort_list::<i64, Int8Type>(values, v, n, &options, limit),
@@ -306,13 +309,11 @@ pub fn sort_to_indices(
             DataType::Float64 => {
                 sort_list::<i64, Float64Type>(values, v, n, &options, limit)
             }
-            t => {
-                return Err(ArrowError::ComputeError(format!(
-                    "Sort not supported for list type {:?}",
-                    t
-                )));
-            }
+            _ => sort_nested(values, v, n, &options, limit)?,
         },
+        DataType::Struct(_) | DataType::Map(_, _) => {
+            sort_nested(values, v, n, &options, limit)?
+        }
         DataType::Dictionary(_, _) => {
             let value_null_first = if options.descending {
                 // When sorting dictionary in descending order, we take inverse of of null ordering
@@ -366,6 +367,58 @@ pub fn sort_to_indices(
     })
 }
 
+/// Sort elements from `ArrayRef` into an unsigned integer (`UInt32Array`) of indices,
+/// preserving the relative order of equal values, including nulls.
+///
+/// Values are compared as by [`lexsort_to_indices_stable`], which is slower than the
+/// type-specialized comparisons of [`sort_to_indices`], but supports every type
+/// supported by [`build_compare`]. Returns only the first `limit` indices if provided.
+pub fn sort_to_indices_stable(
+    values: &ArrayRef,
+    options: Option<SortOptions>,
+    limit: Option<usize>,
+) -> Result<UInt32Array> {
+    let column = SortColumn {
+        values: values.clone(),
+        options,
+    };
+    lexsort_to_indices_stable(&[column], limit)
+}
+
+/// Sort elements from `ArrayRef` into an unsigned integer (`UInt32Array`) of indices
+/// using multiple threads, preserving the relative order of equal values, including nulls.
+///
+/// The indices are sorted by a parallel merge sort, which splits them into chunks that
+/// are sorted concurrently on the [rayon] thread pool, and then merged. Values are
+/// compared as by [`sort_to_indices_stable`], and so the result is identical to it.
+///
+/// Small arrays, and sorts with a `limit`, are sorted on the current thread by
+/// [`sort_to_indices_stable`].
+#[cfg(feature = "rayon")]
+pub fn sort_to_indices_parallel(
+    values: &ArrayRef,
+    options: Option<SortOptions>,
+    limit: Option<usize>,
+) -> Result<UInt32Array> {
+    use rayon::slice::ParallelSliceMut;
+
+    /// The minimum number of values sorted using multiple threads
+    const MIN_PARALLEL_LEN: usize = 1 << 14;
+
+    if limit.is_some() || values.len() < MIN_PARALLEL_LEN {
+        return sort_to_indices_stable(values, options, limit);
+    }
+
+    let columns = [SortColumn {
+        values: values.clone(),
+        options,
+    }];
+    let comparator = LexicographicalComparator::try_new(&columns)?;
+    let mut indices: Vec<u32> = (0..values.len() as u32).collect();
+    indices.par_sort_by(|a, b| comparator.compare(&(*a as usize), &(*b as usize)));
+    Ok(UInt32Array::from(indices))
+}
+
 /// Options that define how sort kernels should behave
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct SortOptions {
@@ -857,6 +910,44 @@ where
     }
 }
 
+/// Sort nested arrays, such as structs and lists of non-primitive values, using
+/// the ordering defined by [`build_compare`]
+fn sort_nested(
+    values: &ArrayRef,
+    value_indices: Vec<u32>,
+    mut null_indices: Vec<u32>,
+    options: &SortOptions,
+    limit: Option<usize>,
+) -> Result<UInt32Array> {
+    let cmp = build_compare(values.as_ref(), values.as_ref())?;
+    let mut valids: Vec<(u32, u32)> = value_indices
+        .into_iter()
+        .map(|index| (index, index))
+        .collect();
+
+    let mut len = values.len();
+    let descending = options.descending;
+
+    if let Some(limit) = limit {
+        len = limit.min(len);
+    }
+
+    sort_valids(descending, &mut valids, &mut null_indices, len, |a, b| {
+        cmp(a as usize, b as usize)
+    });
+
+    let mut valid_indices: Vec<u32> = valids.iter().map(|tuple| tuple.0).collect();
+    if options.nulls_first {
+        null_indices.append(&mut valid_indices);
+        null_indices.truncate(len);
+        Ok(UInt32Array::from(null_indices))
+    } else {
+        valid_indices.append(&mut null_indices);
+        valid_indices.truncate(len);
+        Ok(UInt32Array::from(valid_indices))
+    }
+}
+
 /// Compare two `Array`s based on the ordering defined in [build_compare]
 fn cmp_array(a: &dyn Array, b: &dyn Array) -> Ordering {
     let cmp_op = build_compare(a, b).unwrap();
@@ -933,16 +1024,108 @@ pub fn lexsort(columns: &[SortColumn], limit: Option<usize>) -> Result<Vec<Array
 
 /// Sort elements lexicographically from a list of `ArrayRef` into an unsigned integer
 /// (`UInt32Array`) of indices.
+///
+/// This sort is not stable: rows that compare equal may be returned in any order.
+/// Use [`lexsort_to_indices_stable`] to preserve their relative order.
+///
+/// Sorts on multiple columns of integer, string, binary and similar types are
+/// performed by comparing the columns converted to the [row format](crate::row).
 pub fn lexsort_to_indices(
     columns: &[SortColumn],
     limit: Option<usize>,
 ) -> Result<UInt32Array> {
+    lexsort_to_indices_impl(columns, limit, false)
+}
+
+/// Sort elements lexicographically from a list of `ArrayRef` into an unsigned integer
+/// (`UInt32Array`) of indices, preserving the relative order of rows that compare equal.
+///
+/// Nulls compare equal to each other, so null rows also keep their relative order.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{ArrayRef, Int32Array, UInt32Array};
+/// # use arrow::compute::{lexsort_to_indices_stable, SortColumn, SortOptions};
+/// let values: ArrayRef = Arc::new(Int32Array::from(vec![2, 1, 2, 1]));
+/// let columns = [SortColumn {
+///     values,
+///     options: Some(SortOptions { descending: true, nulls_first: true }),
+/// }];
+/// let indices = lexsort_to_indices_stable(&columns, None).unwrap();
+/// assert_eq!(indices, UInt32Array::from(vec![0, 2, 1, 3]));
+/// ```
+pub fn lexsort_to_indices_stable(
+    columns: &[SortColumn],
+    limit: Option<usize>,
+) -> Result<UInt32Array> {
+    lexsort_to_indices_impl(columns, limit, true)
+}
+
+/// Returns the indices of the first `k` rows of `columns` in lexicographical order,
+/// i.e. the `k` smallest rows, or the `k` largest for columns sorted descending,
+/// without sorting the remaining rows.
+///
+/// This is the selection needed by `ORDER BY ... LIMIT k`, and returns the same
+/// indices as [`lexsort_to_indices_stable`] with a limit of `k`. Where all columns
+/// are supported by the [row format](crate::row), this is computed in `O(n log k)`
+/// by retaining only the best `k` rows seen so far, including for a single column.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{ArrayRef, Int32Array, StringArray, UInt32Array};
+/// # use arrow::compute::{top_k_to_indices, SortColumn, SortOptions};
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![3, 1, 4, 1, 5, 9, 2, 6]));
+/// let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "d", "c", "b", "e", "f", "g", "h"]));
+/// let descending = Some(SortOptions { descending: true, nulls_first: false });
+///
+/// // The three largest values of `a`
+/// let columns = [SortColumn { values: a.clone(), options: descending }];
+/// let indices = top_k_to_indices(&columns, 3).unwrap();
+/// assert_eq!(indices, UInt32Array::from(vec![5, 7, 4]));
+///
+/// // The two smallest values of `a`, breaking ties on `b`
+/// let columns = [
+///     SortColumn { values: a, options: None },
+///     SortColumn { values: b, options: None },
+/// ];
+/// let indices = top_k_to_indices(&columns, 2).unwrap();
+/// assert_eq!(indices, UInt32Array::from(vec![3, 1]));
+/// ```
+pub fn top_k_to_indices(columns: &[SortColumn], k: usize) -> Result<UInt32Array> {
     if columns.is_empty() {
         return Err(ArrowError::InvalidArgumentError(
             "Sort requires at least one column".to_string(),
         ));
     }
-    if columns.len() == 1 {
+
+    let row_count = columns[0].values.len();
+    if columns.iter().any(|item| item.values.len() != row_count) {
+        return Err(ArrowError::ComputeError(
+            "lexical sort columns have different row counts".to_string(),
+        ));
+    };
+
+    if k < row_count
+        && columns
+            .iter()
+            .all(|c| row_format_supported(c.values.data_type()))
+    {
+        return row_top_k_to_indices(columns, k);
+    }
+    lexsort_to_indices_stable(columns, Some(k))
+}
+
+fn lexsort_to_indices_impl(
+    columns: &[SortColumn],
+    limit: Option<usize>,
+    stable: bool,
+) -> Result<UInt32Array> {
+    if columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Sort requires at least one column".to_string(),
+        ));
+    }
+    if columns.len() == 1 && !stable {
         // fallback to non-lexical sort
         let column = &columns[0];
         return sort_to_indices(&column.values, column.options, limit);
@@ -955,6 +1138,72 @@ pub fn lexsort_to_indices(
         ));
     };
 
+    if use_row_format(columns) {
+        // The row format sort is stable, and so also satisfies an unstable sort
+        return row_lexsort_to_indices(columns, limit);
+    }
+    comparator_lexsort_to_indices(columns, limit, stable)
+}
+
+/// Returns true if `columns` should be sorted by converting them to the
+/// [row format](crate::row), instead of using a [`LexicographicalComparator`]
+///
+/// Comparing rows avoids the dynamic dispatch and null checks of the comparator for
+/// each column that compares equal, at the cost of converting every row up front,
+/// which pays off once there are several columns to compare.
+///
+/// Only types the row format orders identically to the comparator are eligible,
+/// in particular floats are not, as the row format distinguishes `-0.0` from `0.0`
+fn use_row_format(columns: &[SortColumn]) -> bool {
+    /// The minimum number of columns for which rows are used
+    const MIN_COLUMNS: usize = 2;
+
+    columns.len() >= MIN_COLUMNS
+        && columns
+            .iter()
+            .all(|c| row_format_supported(c.values.data_type()))
+}
+
+/// Returns true if the [row format](crate::row) orders `data_type` identically
+/// to a [`LexicographicalComparator`]
+fn row_format_supported(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Dictionary(_, values) => {
+            !matches!(values.as_ref(), DataType::Dictionary(_, _))
+                && row_format_supported(values)
+        }
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Decimal128(_, _)
+        | DataType::Date32
+        | DataType::Date64
+        | DataType::Time32(_)
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_)
+        | DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Binary
+        | DataType::LargeBinary => true,
+        _ => false,
+    }
+}
+
+/// Sort elements lexicographically from a list of `ArrayRef` into an unsigned integer
+/// (`UInt32Array`) of indices, using a [`LexicographicalComparator`]
+fn comparator_lexsort_to_indices(
+    columns: &[SortColumn],
+    limit: Option<usize>,
+    stable: bool,
+) -> Result<UInt32Array> {
+    let row_count = columns[0].values.len();
     let mut value_indices = (0..row_count).collect::<Vec<usize>>();
     let mut len = value_indices.len();
 
@@ -963,9 +1212,13 @@ pub fn lexsort_to_indices(
     }
 
     let lexicographical_comparator = LexicographicalComparator::try_new(columns)?;
-    // uint32 can be sorted unstably
+    // uint32 can be sorted unstably, breaking ties by index if the sort must be stable
     sort_unstable_by(&mut value_indices, len, |a, b| {
-        lexicographical_comparator.compare(a, b)
+        let ordering = lexicographical_comparator.compare(a, b);
+        match stable {
+            true => ordering.then_with(|| a.cmp(b)),
+            false => ordering,
+        }
     });
 
     Ok(UInt32Array::from(
@@ -976,6 +1229,130 @@ pub fn lexsort_to_indices(
     ))
 }
 
+/// Sort a [`RecordBatch`] by `columns`, returning a new batch with all of its
+/// columns reordered accordingly.
+///
+/// `columns` would typically contain columns of `batch`, but may contain any arrays
+/// with the same number of rows, such as computed sort keys. The [row format](crate::row)
+/// is used to compare rows, falling back to [`lexsort_to_indices_stable`] for types it
+/// does not support, or orders differently, such as floats.
+///
+/// Performs a stable sort, returning only the first `limit` rows if provided.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{ArrayRef, Int32Array, StringArray};
+/// # use arrow::compute::{sort_record_batch, SortColumn, SortOptions};
+/// # use arrow::record_batch::RecordBatch;
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![2, 1, 2, 1]));
+/// let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c", "d"]));
+/// let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+///
+/// let columns = [
+///     SortColumn { values: batch.column(0).clone(), options: None },
+///     SortColumn {
+///         values: batch.column(1).clone(),
+///         options: Some(SortOptions { descending: true, nulls_first: false }),
+///     },
+/// ];
+/// let sorted = sort_record_batch(&batch, &columns, None).unwrap();
+/// assert_eq!(
+///     sorted.column(1).as_ref(),
+///     &StringArray::from(vec!["d", "b", "c", "a"]),
+/// );
+/// ```
+pub fn sort_record_batch(
+    batch: &RecordBatch,
+    columns: &[SortColumn],
+    limit: Option<usize>,
+) -> Result<RecordBatch> {
+    if columns.iter().any(|c| c.values.len() != batch.num_rows()) {
+        return Err(ArrowError::ComputeError(
+            "sort columns must have the same number of rows as the record batch"
+                .to_string(),
+        ));
+    }
+
+    let use_rows = !columns.is_empty()
+        && columns
+            .iter()
+            .all(|c| row_format_supported(c.values.data_type()));
+    let indices = match use_rows {
+        true => row_lexsort_to_indices(columns, limit)?,
+        false => lexsort_to_indices_stable(columns, limit)?,
+    };
+
+    let sorted = batch
+        .columns()
+        .iter()
+        .map(|c| take(c.as_ref(), &indices, None))
+        .collect::<Result<Vec<_>>>()?;
+    let options = RecordBatchOptions::new().with_row_count(Some(indices.len()));
+    RecordBatch::try_new_with_options(batch.schema(), sorted, &options)
+}
+
+/// Sort elements lexicographically from a list of `ArrayRef` into an unsigned integer
+/// (`UInt32Array`) of indices, using the [row format](crate::row).
+///
+/// Unlike [`lexsort_to_indices`] this performs a stable sort.
+fn row_lexsort_to_indices(
+    columns: &[SortColumn],
+    limit: Option<usize>,
+) -> Result<UInt32Array> {
+    let fields = columns
+        .iter()
+        .map(|c| {
+            let options = c.options.unwrap_or_default();
+            SortField::new_with_options(c.values.data_type().clone(), options)
+        })
+        .collect();
+    let arrays: Vec<_> = columns.iter().map(|c| c.values.clone()).collect();
+    let rows = RowConverter::new(fields).convert_columns(&arrays)?;
+
+    // Comparing indices after rows ensures equal rows retain their order
+    let mut sort: Vec<_> = (&rows).into_iter().zip(0_u32..).collect();
+    let len = limit.unwrap_or(sort.len()).min(sort.len());
+    sort_unstable_by(&mut sort, len, |a, b| a.cmp(b));
+    Ok(UInt32Array::from_iter_values(
+        sort[..len].iter().map(|(_, idx)| *idx),
+    ))
+}
+
+/// Selects the first `k` rows of `columns` using a bounded max-heap of rows,
+/// see [`top_k_to_indices`]
+fn row_top_k_to_indices(columns: &[SortColumn], k: usize) -> Result<UInt32Array> {
+    if k == 0 {
+        return Ok(UInt32Array::from_iter_values(std::iter::empty()));
+    }
+
+    let fields = columns
+        .iter()
+        .map(|c| {
+            let options = c.options.unwrap_or_default();
+            SortField::new_with_options(c.values.data_type().clone(), options)
+        })
+        .collect();
+    let arrays: Vec<_> = columns.iter().map(|c| c.values.clone()).collect();
+    let rows = RowConverter::new(fields).convert_columns(&arrays)?;
+
+    // The heap's maximum is the worst of the best `k` rows seen so far. As indices
+    // increase, a later row equal to it compares greater, keeping the sort stable
+    let mut heap = BinaryHeap::with_capacity(k);
+    for candidate in (&rows).into_iter().zip(0_u32..) {
+        if heap.len() < k {
+            heap.push(candidate);
+        } else if let Some(mut worst) = heap.peek_mut() {
+            if candidate < *worst {
+                *worst = candidate;
+            }
+        }
+    }
+
+    Ok(UInt32Array::from_iter_values(
+        heap.into_sorted_vec().into_iter().map(|(_, idx)| idx),
+    ))
+}
+
 /// It's unstable_sort, may not preserve the order of equal elements
 pub fn partial_sort<T, F>(v: &mut [T], limit: usize, mut is_less: F)
 where
@@ -1403,6 +1780,15 @@ mod tests {
         for (result, expected) in sorted.iter().zip(expected_output.iter()) {
             assert_eq!(result, expected);
         }
+
+        // The row format and comparator based sorts must agree
+        if input.len() > 1 {
+            let indices = comparator_lexsort_to_indices(&input, limit, true).unwrap();
+            for (column, expected) in input.iter().zip(expected_output.iter()) {
+                let result = take(column.values.as_ref(), &indices, None).unwrap();
+                assert_eq!(&result, expected);
+            }
+        }
     }
 
     fn test_sort_binary_arrays(
@@ -3603,4 +3989,419 @@ mod tests {
             vec![None, None, None, Some(5.1), Some(5.1), Some(3.0), Some(1.2)],
         );
     }
+
+    #[test]
+    fn test_sort_record_batch() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(2),
+            None,
+            Some(1),
+            Some(2),
+            Some(1),
+        ]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "x", "x", "z"]));
+        let c: ArrayRef = Arc::new(Int64Array::from(vec![0, 1, 2, 3, 4]));
+        let batch =
+            RecordBatch::try_from_iter([("a", a.clone()), ("b", b.clone()), ("c", c)])
+                .unwrap();
+
+        let columns = [
+            SortColumn {
+                values: a.clone(),
+                options: Some(SortOptions {
+                    descending: false,
+                    nulls_first: false,
+                }),
+            },
+            SortColumn {
+                values: b,
+                options: Some(SortOptions {
+                    descending: true,
+                    nulls_first: false,
+                }),
+            },
+        ];
+        let sorted = sort_record_batch(&batch, &columns, None).unwrap();
+        assert_eq!(sorted.schema(), batch.schema());
+        // Rows 0 and 3 are equal, and retain their order
+        assert_eq!(
+            sorted.column(2).as_ref(),
+            &Int64Array::from(vec![4, 2, 0, 3, 1]) as &dyn Array
+        );
+
+        let sorted = sort_record_batch(&batch, &columns, Some(3)).unwrap();
+        assert_eq!(sorted.num_rows(), 3);
+        assert_eq!(
+            sorted.column(2).as_ref(),
+            &Int64Array::from(vec![4, 2, 0]) as &dyn Array
+        );
+
+        let sorted = sort_record_batch(&batch, &columns, Some(10)).unwrap();
+        assert_eq!(sorted.num_rows(), 5);
+
+        let columns = [SortColumn {
+            values: a,
+            options: None,
+        }];
+        let sorted = sort_record_batch(&batch, &columns, Some(1)).unwrap();
+        assert_eq!(sorted.num_rows(), 1);
+        assert!(sorted.column(0).is_null(0));
+
+        let columns = [SortColumn {
+            values: Arc::new(Int32Array::from(vec![1, 2])),
+            options: None,
+        }];
+        let err = sort_record_batch(&batch, &columns, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: sort columns must have the same number of rows as the record batch"
+        );
+        assert!(sort_record_batch(&batch, &[], None).is_err());
+    }
+
+    #[test]
+    fn test_sort_to_indices_stable() {
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(2),
+            None,
+            Some(1),
+            Some(2),
+            None,
+            Some(1),
+            Some(2),
+        ]));
+
+        let indices = sort_to_indices_stable(&values, None, None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![1, 4, 2, 5, 0, 3, 6]));
+
+        // ties keep their relative order when sorting in descending order
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let indices = sort_to_indices_stable(&values, Some(options), None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![0, 3, 6, 2, 5, 1, 4]));
+
+        let indices = sort_to_indices_stable(&values, Some(options), Some(2)).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![0, 3]));
+
+        let values: ArrayRef =
+            Arc::new(StringArray::from(vec!["b", "a", "b", "a", "c", "b"]));
+        let indices = sort_to_indices_stable(&values, None, Some(4)).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![1, 3, 0, 2]));
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_sort_to_indices_parallel() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let len = 50_000;
+        let ints: ArrayRef = Arc::new(
+            (0..len)
+                .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..100)))
+                .collect::<Int32Array>(),
+        );
+        let strings: ArrayRef = Arc::new(
+            (0..len)
+                .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..100).to_string()))
+                .collect::<StringArray>(),
+        );
+
+        for values in [&ints, &strings] {
+            for (descending, nulls_first) in
+                [(false, true), (false, false), (true, true), (true, false)]
+            {
+                let options = Some(SortOptions {
+                    descending,
+                    nulls_first,
+                });
+                let expected = sort_to_indices_stable(values, options, None).unwrap();
+                let actual = sort_to_indices_parallel(values, options, None).unwrap();
+                assert_eq!(actual, expected);
+            }
+        }
+
+        let indices = sort_to_indices_parallel(&ints, None, Some(10)).unwrap();
+        let expected = sort_to_indices_stable(&ints, None, Some(10)).unwrap();
+        assert_eq!(indices, expected);
+
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![2, 1, 2, 1]));
+        let indices = sort_to_indices_parallel(&values, None, None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![1, 3, 0, 2]));
+    }
+
+    #[test]
+    fn test_lexsort_row_format() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let len = 500;
+        let ints: Int32Array = (0..len)
+            .map(|_| rng.gen_bool(0.8).then(|| rng.gen_range(0..5)))
+            .collect();
+        let strings: StringArray = (0..len)
+            .map(|_| {
+                rng.gen_bool(0.8)
+                    .then(|| ["a", "b", "ab", ""][rng.gen_range(0..4)])
+            })
+            .collect();
+        let dict: DictionaryArray<Int8Type> = (0..len)
+            .map(|_| {
+                rng.gen_bool(0.8)
+                    .then(|| ["x", "y", "z"][rng.gen_range(0..3)])
+            })
+            .collect();
+        let values: Vec<ArrayRef> =
+            vec![Arc::new(ints), Arc::new(strings), Arc::new(dict)];
+
+        for descending in [false, true] {
+            for nulls_first in [false, true] {
+                let options = Some(SortOptions {
+                    descending,
+                    nulls_first,
+                });
+                let columns: Vec<_> = values
+                    .iter()
+                    .map(|values| SortColumn {
+                        values: values.clone(),
+                        options,
+                    })
+                    .collect();
+                assert!(use_row_format(&columns));
+
+                for limit in [None, Some(10)] {
+                    let expected =
+                        comparator_lexsort_to_indices(&columns, limit, true).unwrap();
+                    let actual = lexsort_to_indices_stable(&columns, limit).unwrap();
+                    assert_eq!(actual, expected);
+                }
+            }
+        }
+
+        let column = |values: ArrayRef| SortColumn {
+            values,
+            options: None,
+        };
+        let ints = Arc::new(Int32Array::from(vec![1])) as ArrayRef;
+        let floats = Arc::new(Float64Array::from(vec![1.])) as ArrayRef;
+        assert!(!use_row_format(&[column(ints.clone())]));
+        assert!(use_row_format(&[
+            column(ints.clone()),
+            column(ints.clone())
+        ]));
+        assert!(!use_row_format(&[column(ints), column(floats)]));
+    }
+
+    #[test]
+    fn test_top_k_to_indices() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let len = 500;
+        let ints: ArrayRef = Arc::new(
+            (0..len)
+                .map(|_| rng.gen_bool(0.8).then(|| rng.gen_range(0..5)))
+                .collect::<Int32Array>(),
+        );
+        let strings: ArrayRef = Arc::new(
+            (0..len)
+                .map(|_| {
+                    rng.gen_bool(0.8)
+                        .then(|| ["a", "b", "ab", ""][rng.gen_range(0..4)])
+                })
+                .collect::<StringArray>(),
+        );
+        let floats: ArrayRef = Arc::new(
+            (0..len)
+                .map(|_| rng.gen_bool(0.8).then(|| rng.gen_range(0..5) as f64))
+                .collect::<Float64Array>(),
+        );
+
+        let cases: [&[&ArrayRef]; 4] =
+            [&[&ints], &[&strings], &[&ints, &strings], &[&floats, &ints]];
+        for arrays in cases {
+            for (descending, nulls_first) in
+                [(false, true), (false, false), (true, true), (true, false)]
+            {
+                let options = Some(SortOptions {
+                    descending,
+                    nulls_first,
+                });
+                let columns: Vec<_> = arrays
+                    .iter()
+                    .map(|values| SortColumn {
+                        values: (*values).clone(),
+                        options,
+                    })
+                    .collect();
+
+                for k in [0, 1, 10, 100, len, len + 1] {
+                    let expected = lexsort_to_indices_stable(&columns, Some(k)).unwrap();
+                    let actual = top_k_to_indices(&columns, k).unwrap();
+                    assert_eq!(actual, expected);
+                }
+            }
+        }
+
+        let columns = [
+            SortColumn {
+                values: ints.clone(),
+                options: None,
+            },
+            SortColumn {
+                values: Arc::new(Int32Array::from(vec![1])) as ArrayRef,
+                options: None,
+            },
+        ];
+        assert!(top_k_to_indices(&columns, 1).is_err());
+        assert!(top_k_to_indices(&[], 1).is_err());
+    }
+
+    #[test]
+    fn test_sort_nested() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(2),
+            Some(1),
+            Some(2),
+            None,
+            Some(1),
+        ]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("x"),
+            Some("y"),
+            Some("a"),
+            Some("z"),
+            None,
+        ]));
+        let structs: ArrayRef = Arc::new(StructArray::from((
+            vec![
+                (Field::new("a", DataType::Int32, true), a),
+                (Field::new("b", DataType::Utf8, true), b),
+            ],
+            crate::buffer::Buffer::from([0b01111]),
+        )));
+
+        let options = Some(SortOptions {
+            descending: false,
+            nulls_first: false,
+        });
+        let indices = sort_to_indices(&structs, options, None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![3, 1, 2, 0, 4]));
+
+        let options = Some(SortOptions {
+            descending: true,
+            nulls_first: true,
+        });
+        let indices = sort_to_indices(&structs, options, None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![4, 0, 2, 1, 3]));
+
+        let mut builder = ListBuilder::new(StringBuilder::new());
+        builder.values().append_value("a");
+        builder.values().append_value("b");
+        builder.append(true);
+        builder.values().append_value("a");
+        builder.append(true);
+        builder.append(false);
+        builder.values().append_value("b");
+        builder.append(true);
+        builder.append(true);
+        builder.values().append_value("a");
+        builder.values().append_null();
+        builder.append(true);
+        let lists: ArrayRef = Arc::new(builder.finish());
+
+        let indices = sort_to_indices(&lists, None, None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![2, 4, 1, 5, 0, 3]));
+        let indices = sort_to_indices(&lists, None, Some(3)).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![2, 4, 1]));
+
+        let columns = [
+            SortColumn {
+                values: Arc::new(Int32Array::from(vec![1, 1, 0, 0, 1, 0])),
+                options: None,
+            },
+            SortColumn {
+                values: lists,
+                options: None,
+            },
+        ];
+        let expected = UInt32Array::from(vec![2, 5, 3, 4, 1, 0]);
+        assert_eq!(lexsort_to_indices(&columns, None).unwrap(), expected);
+        assert_eq!(lexsort_to_indices_stable(&columns, None).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_lexsort_to_indices_stable() {
+        let columns = [
+            SortColumn {
+                values: Arc::new(Int32Array::from(vec![1, 2, 1, 2, 1])),
+                options: None,
+            },
+            SortColumn {
+                values: Arc::new(StringArray::from(vec![
+                    Some("a"),
+                    None,
+                    Some("a"),
+                    None,
+                    Some("b"),
+                ])),
+                options: Some(SortOptions {
+                    descending: true,
+                    nulls_first: false,
+                }),
+            },
+        ];
+        let indices = lexsort_to_indices_stable(&columns, None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![4, 0, 2, 1, 3]));
+
+        let indices = lexsort_to_indices_stable(&columns, Some(3)).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![4, 0, 2]));
+
+        assert!(lexsort_to_indices_stable(&[], None).is_err());
+    }
+
+    #[test]
+    fn test_sort_record_batch_single_column_stable() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![3, 1, 3, 1, 3]));
+        let b: ArrayRef = Arc::new(Int64Array::from(vec![0, 1, 2, 3, 4]));
+        let batch = RecordBatch::try_from_iter([("a", a.clone()), ("b", b)]).unwrap();
+
+        let columns = [SortColumn {
+            values: a,
+            options: Some(SortOptions {
+                descending: true,
+                nulls_first: true,
+            }),
+        }];
+        let sorted = sort_record_batch(&batch, &columns, None).unwrap();
+        assert_eq!(
+            sorted.column(1).as_ref(),
+            &Int64Array::from(vec![0, 2, 4, 1, 3]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn test_sort_record_batch_signed_zero() {
+        // -0.0 and 0.0 compare equal, and so retain their order
+        let a: ArrayRef = Arc::new(Float64Array::from(vec![0.0, -0.0, 1.0, 0.0, -0.0]));
+        let b: ArrayRef = Arc::new(Int32Array::from(vec![1, 1, 0, 1, 1]));
+        let c: ArrayRef = Arc::new(Int64Array::from(vec![0, 1, 2, 3, 4]));
+        let batch =
+            RecordBatch::try_from_iter([("a", a.clone()), ("b", b.clone()), ("c", c)])
+                .unwrap();
+
+        let columns = [
+            SortColumn {
+                values: a,
+                options: None,
+            },
+            SortColumn {
+                values: b,
+                options: None,
+            },
+        ];
+        let sorted = sort_record_batch(&batch, &columns, None).unwrap();
+        let expected = lexsort_to_indices_stable(&columns, None).unwrap();
+        assert_eq!(expected, UInt32Array::from(vec![0, 1, 3, 4, 2]));
+        assert_eq!(
+            sorted.column(2).as_ref(),
+            &Int64Array::from(vec![0, 1, 3, 4, 2]) as &dyn Array
+        );
+    }
 }
@@ -0,0 +1,212 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels for extracting the fields of struct arrays
+
+use crate::array::*;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// A path of field names into nested struct arrays, such as `a.b.c`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldPath(Vec<String>);
+
+impl FieldPath {
+    /// Creates a new [`FieldPath`] from the field names at each level of nesting
+    pub fn new<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self(names.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the field names of this path
+    pub fn names(&self) -> &[String] {
+        &self.0
+    }
+}
+
+/// Returns the child array of the struct array `array` for the field `name`.
+///
+/// Unlike [`StructArray::column_by_name`], the returned array is null
+/// wherever the struct itself is null, as well as where the child is null.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{Array, ArrayRef, Int32Array, StructArray};
+/// # use arrow::buffer::Buffer;
+/// # use arrow::compute::get_field;
+/// # use arrow::datatypes::{DataType, Field};
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+/// let array = StructArray::from((
+///     vec![(Field::new("a", DataType::Int32, true), a)],
+///     Buffer::from([0b011]),
+/// ));
+///
+/// let a = get_field(&array, "a").unwrap();
+/// assert_eq!(a.as_ref(), &Int32Array::from(vec![Some(1), None, None]));
+/// ```
+pub fn get_field(array: &dyn Array, name: &str) -> Result<ArrayRef> {
+    let fields = match array.data_type() {
+        DataType::Struct(fields) => fields,
+        t => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "get_field requires a struct array, got {}",
+                t
+            )))
+        }
+    };
+    let index = fields
+        .iter()
+        .position(|f| f.name() == name)
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "struct has no field named \"{}\"",
+                name
+            ))
+        })?;
+
+    let data = array.data();
+    let child = struct_child_with_parent_nulls(data, &data.child_data()[index])?;
+    Ok(make_array(child))
+}
+
+/// Returns the array at `path` in the nested struct array `array`.
+///
+/// This is equivalent to calling [`get_field`] for each name of the path, so
+/// the result is null wherever any of its ancestors is null. An empty path
+/// returns `array` itself.
+pub fn get_field_path(array: &dyn Array, path: &FieldPath) -> Result<ArrayRef> {
+    path.names()
+        .iter()
+        .try_fold(make_array(array.data().clone()), |array, name| {
+            get_field(array.as_ref(), name)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::Buffer;
+    use crate::datatypes::Field;
+    use std::sync::Arc;
+
+    /// Returns `{inner: {a: Int32, b: Utf8}}` with nulls at every level
+    fn nested() -> StructArray {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(1),
+            Some(2),
+            None,
+            Some(4),
+            Some(5),
+        ]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c", "d", "e"]));
+        let inner = StructArray::from((
+            vec![
+                (Field::new("a", DataType::Int32, true), a),
+                (Field::new("b", DataType::Utf8, false), b),
+            ],
+            Buffer::from([0b11101]),
+        ));
+        StructArray::from((
+            vec![(
+                Field::new("inner", inner.data_type().clone(), true),
+                Arc::new(inner) as ArrayRef,
+            )],
+            Buffer::from([0b10111]),
+        ))
+    }
+
+    #[test]
+    fn test_get_field() {
+        let array = nested();
+        let inner = get_field(&array, "inner").unwrap();
+        let expected = [true, false, true, false, true];
+        for (i, valid) in expected.iter().enumerate() {
+            assert_eq!(inner.is_valid(i), *valid, "{}", i);
+        }
+
+        let b = get_field(inner.as_ref(), "b").unwrap();
+        let expected =
+            StringArray::from(vec![Some("a"), None, Some("c"), None, Some("e")]);
+        assert_eq!(b.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_get_field_path() {
+        let array = nested();
+        let path = FieldPath::new(["inner", "a"]);
+        let a = get_field_path(&array, &path).unwrap();
+        let expected = Int32Array::from(vec![Some(1), None, None, None, Some(5)]);
+        assert_eq!(a.as_ref(), &expected);
+
+        let sliced = array.slice(1, 4);
+        let a = get_field_path(sliced.as_ref(), &path).unwrap();
+        let expected = Int32Array::from(vec![None, None, None, Some(5)]);
+        assert_eq!(a.as_ref(), &expected);
+
+        let empty =
+            get_field_path(&array, &FieldPath::new(Vec::<String>::new())).unwrap();
+        assert_eq!(empty.data(), array.data());
+    }
+
+    #[test]
+    fn test_get_field_offset_child() {
+        // the null bitmap of a child with a non-zero offset must be shifted
+        let values = Int32Array::from(vec![Some(0), Some(1), None, Some(3), Some(4)]);
+        let child = values.data().slice(1, 4);
+        let field = Field::new("a", DataType::Int32, true);
+        let data = ArrayData::builder(DataType::Struct(vec![field]))
+            .len(4)
+            .null_bit_buffer(Some(Buffer::from([0b1110])))
+            .add_child_data(child)
+            .build()
+            .unwrap();
+        let array = StructArray::from(data);
+
+        let a = get_field(&array, "a").unwrap();
+        let expected = Int32Array::from(vec![None, None, Some(3), Some(4)]);
+        assert_eq!(a.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_get_field_null_child() {
+        // null arrays have no null buffer to apply the nulls of their parent to
+        let array = StructArray::from((
+            vec![(
+                Field::new("n", DataType::Null, true),
+                Arc::new(NullArray::new(3)) as ArrayRef,
+            )],
+            Buffer::from([0b101]),
+        ));
+        let n = get_field(&array, "n").unwrap();
+        assert_eq!(n.data_type(), &DataType::Null);
+        assert_eq!(n.len(), 3);
+    }
+
+    #[test]
+    fn test_get_field_errors() {
+        let array = nested();
+        let err = get_field(&array, "missing").unwrap_err();
+        assert!(err.to_string().contains("no field named"), "{}", err);
+
+        let path = FieldPath::new(["inner", "a", "x"]);
+        let err = get_field_path(&array, &path).unwrap_err();
+        assert!(err.to_string().contains("requires a struct"), "{}", err);
+    }
+}
@@ -1997,6 +1997,8 @@ mod tests {
         let expected_values = StringArray::from(vec!["foo", "bar", ""]);
         assert_eq!(&expected_values, dict_values);
         assert_eq!(&expected_values, &result_values);
+        // and should not have been copied
+        assert!(result.values().data().ptr_eq(array.values().data()));
 
         let expected_keys = Int16Array::from(vec![
             Some(0),
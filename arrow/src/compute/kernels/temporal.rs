@@ -20,13 +20,16 @@
 use chrono::{Datelike, Timelike};
 
 use crate::array::*;
+use crate::compute::kernels::cast::CastOptions;
 use crate::datatypes::*;
+use crate::downcast_dictionary_array;
 use crate::error::{ArrowError, Result};
-use arrow_array::temporal_conversions::{as_datetime, as_time};
+use arrow_array::temporal_conversions::{as_datetime, as_time, time_unit_multiple};
 
 use chrono::format::strftime::StrftimeItems;
-use chrono::format::{parse, Parsed};
-use chrono::FixedOffset;
+use chrono::format::{parse, Item, Parsed};
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+use std::sync::Arc;
 
 macro_rules! extract_component_from_array {
     ($iter:ident, $builder:ident, $extract_fn:ident, $using:expr, $convert:expr) => {
@@ -923,6 +926,838 @@ where
     Ok(b.finish())
 }
 
+/// A timezone of a [`DataType::Timestamp`], parsed once per kernel invocation
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Tz {
+    /// A fixed offset from UTC, e.g. `+08:00`
+    Fixed(FixedOffset),
+    /// A named timezone, e.g. `America/New_York`
+    #[cfg(feature = "chrono-tz")]
+    Named(chrono_tz::Tz),
+}
+
+impl Tz {
+    /// Parses a timezone, either a fixed offset of the form `[+-]XX:XX`, or if the
+    /// `chrono-tz` feature is enabled, a name from the IANA timezone database
+    fn parse(tz: &str) -> Result<Self> {
+        if (tz.starts_with('+') || tz.starts_with('-')) && !tz.contains(':') {
+            return_compute_error_with!(
+                "Invalid timezone",
+                "Expected format [+-]XX:XX".to_string()
+            )
+        }
+        let mut parsed = Parsed::new();
+        if parse(&mut parsed, tz, StrftimeItems::new("%z")).is_ok() {
+            return match parsed.to_fixed_offset() {
+                Ok(offset) => Ok(Self::Fixed(offset)),
+                err => return_compute_error_with!("Invalid timezone", err),
+            };
+        }
+        Self::parse_named(tz)
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    fn parse_named(tz: &str) -> Result<Self> {
+        match tz.parse::<chrono_tz::Tz>() {
+            Ok(tz) => Ok(Self::Named(tz)),
+            Err(err) => return_compute_error_with!("Unable to parse timezone", err),
+        }
+    }
+
+    #[cfg(not(feature = "chrono-tz"))]
+    fn parse_named(tz: &str) -> Result<Self> {
+        return_compute_error_with!("Unable to parse timezone", tz)
+    }
+
+    /// Returns the offset from UTC at `utc`
+    #[cfg_attr(not(feature = "chrono-tz"), allow(unused_variables))]
+    fn offset_from_utc(&self, utc: &NaiveDateTime) -> FixedOffset {
+        match self {
+            Self::Fixed(offset) => *offset,
+            #[cfg(feature = "chrono-tz")]
+            Self::Named(tz) => {
+                use chrono::{Offset, TimeZone};
+                tz.offset_from_utc_datetime(utc).fix()
+            }
+        }
+    }
+
+    /// Returns the offset from UTC of the local datetime `local`, choosing the earliest
+    /// if ambiguous, or the offset prior to the transition if `local` does not exist
+    #[cfg_attr(not(feature = "chrono-tz"), allow(unused_variables))]
+    fn offset_from_local(&self, local: &NaiveDateTime) -> FixedOffset {
+        match self {
+            Self::Fixed(offset) => *offset,
+            #[cfg(feature = "chrono-tz")]
+            Self::Named(tz) => {
+                use chrono::{Offset, TimeZone};
+                match tz.offset_from_local_datetime(local).earliest() {
+                    Some(offset) => offset.fix(),
+                    // `local` is within a gap, and so is mapped to the same UTC
+                    // instant as the local datetime with the offset of the gap start
+                    None => self.offset_from_utc(local),
+                }
+            }
+        }
+    }
+
+    /// Returns the local datetime of `utc`, or `None` if it is out of range
+    fn to_local(self, utc: NaiveDateTime) -> Option<NaiveDateTime> {
+        let offset = self.offset_from_utc(&utc).local_minus_utc();
+        utc.checked_add_signed(chrono::Duration::seconds(offset as i64))
+    }
+
+    /// Returns the UTC datetime of the local datetime `local`, or `None` if it is out
+    /// of range
+    fn to_utc(self, local: NaiveDateTime) -> Option<NaiveDateTime> {
+        let offset = self.offset_from_local(&local).local_minus_utc();
+        local.checked_sub_signed(chrono::Duration::seconds(offset as i64))
+    }
+
+    /// Returns the local timestamp of the UTC timestamp `v` of type `T`, or `None` if it
+    /// is out of range
+    pub(crate) fn timestamp_to_local<T: ArrowTimestampType>(self, v: i64) -> Option<i64> {
+        let local = self.to_local(as_datetime::<T>(v)?)?;
+        datetime_to_timestamp(local, &T::get_time_unit())
+    }
+
+    /// Returns the UTC timestamp of the local timestamp `v` of type `T`, or `None` if it
+    /// is out of range
+    pub(crate) fn timestamp_to_utc<T: ArrowTimestampType>(self, v: i64) -> Option<i64> {
+        let utc = self.to_utc(as_datetime::<T>(v)?)?;
+        datetime_to_timestamp(utc, &T::get_time_unit())
+    }
+}
+
+/// Returns the parsed timezone of `data_type`, if any
+pub(crate) fn timestamp_tz(data_type: &DataType) -> Result<Option<Tz>> {
+    match data_type {
+        DataType::Timestamp(_, Some(tz)) => Tz::parse(tz).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Converts a [`NaiveDateTime`] to the number of `unit` since the UNIX epoch,
+/// returning `None` on overflow
+fn datetime_to_timestamp(datetime: NaiveDateTime, unit: &TimeUnit) -> Option<i64> {
+    let nanos = datetime.timestamp_subsec_nanos() as i64;
+    let sub_second = nanos / (1_000_000_000 / time_unit_multiple(unit));
+    datetime
+        .timestamp()
+        .checked_mul(time_unit_multiple(unit))?
+        .checked_add(sub_second)
+}
+
+/// A part of a temporal value to extract, see [`date_part`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatePart {
+    /// The year
+    Year,
+    /// The quarter of the year, in the range `1..=4`
+    Quarter,
+    /// The month of the year, in the range `1..=12`
+    Month,
+    /// The ISO 8601 week of the year, in the range `1..=53`
+    Week,
+    /// The day of the month, in the range `1..=31`
+    Day,
+    /// The day of the week, with Sunday as `0` and Saturday as `6`
+    DayOfWeekSunday0,
+    /// The day of the week, with Monday as `0` and Sunday as `6`
+    DayOfWeekMonday0,
+    /// The day of the year, in the range `1..=366`
+    DayOfYear,
+    /// The hour of the day, in the range `0..=23`
+    Hour,
+    /// The minute of the hour, in the range `0..=59`
+    Minute,
+    /// The second of the minute, in the range `0..=59`
+    Second,
+    /// The milliseconds within the second, in the range `0..=999`
+    Millisecond,
+    /// The microseconds within the second, in the range `0..=999_999`
+    Microsecond,
+    /// The nanoseconds within the second, in the range `0..=999_999_999`
+    Nanosecond,
+    /// The number of seconds since the UNIX epoch including any fractional seconds,
+    /// or for times, since midnight
+    Epoch,
+}
+
+impl DatePart {
+    /// Returns this part of the time of day `time`, if it is not a part of a date
+    fn of_time(&self, time: &NaiveTime) -> Option<i32> {
+        Some(match self {
+            Self::Hour => time.hour() as i32,
+            Self::Minute => time.minute() as i32,
+            Self::Second => time.second() as i32,
+            Self::Millisecond => (time.nanosecond() / 1_000_000) as i32,
+            Self::Microsecond => (time.nanosecond() / 1_000) as i32,
+            Self::Nanosecond => time.nanosecond() as i32,
+            _ => return None,
+        })
+    }
+
+    /// Returns this part of `datetime`, or `None` for [`DatePart::Epoch`]
+    fn of_datetime(&self, datetime: &NaiveDateTime) -> Option<i32> {
+        Some(match self {
+            Self::Year => datetime.year(),
+            Self::Quarter => datetime.quarter() as i32,
+            Self::Month => datetime.month() as i32,
+            Self::Week => datetime.iso_week().week() as i32,
+            Self::Day => datetime.day() as i32,
+            Self::DayOfWeekSunday0 => datetime.num_days_from_sunday(),
+            Self::DayOfWeekMonday0 => datetime.num_days_from_monday(),
+            Self::DayOfYear => datetime.ordinal() as i32,
+            Self::Epoch => return None,
+            _ => return self.of_time(&datetime.time()),
+        })
+    }
+}
+
+/// Extracts `part` of the values of a date, time or timestamp array, or a dictionary
+/// of them, in the timezone of the array if any
+///
+/// Returns a [`Float64Array`] for [`DatePart::Epoch`], otherwise an [`Int32Array`].
+/// Returns an error if `part` is a part of a date, and `array` is a time array.
+///
+/// ```
+/// # use arrow::array::{Float64Array, Int32Array, TimestampMillisecondArray};
+/// # use arrow::compute::kernels::temporal::{date_part, DatePart};
+/// // 2022-11-17T14:25:36.123Z
+/// let array = TimestampMillisecondArray::from(vec![Some(1668695136123), None]);
+///
+/// let week = date_part(&array, DatePart::Week).unwrap();
+/// assert_eq!(week.as_ref(), &Int32Array::from(vec![Some(46), None]));
+///
+/// let millis = date_part(&array, DatePart::Millisecond).unwrap();
+/// assert_eq!(millis.as_ref(), &Int32Array::from(vec![Some(123), None]));
+///
+/// let epoch = date_part(&array, DatePart::Epoch).unwrap();
+/// assert_eq!(epoch.as_ref(), &Float64Array::from(vec![Some(1668695136.123), None]));
+/// ```
+pub fn date_part(array: &dyn Array, part: DatePart) -> Result<ArrayRef> {
+    downcast_dictionary_array! {
+        array => {
+            let values = date_part(array.values().as_ref(), part)?;
+            crate::compute::take(values.as_ref(), array.keys(), None)
+        }
+        DataType::Date32 => date_part_primitive(as_primitive_array::<Date32Type>(array), part),
+        DataType::Date64 => date_part_primitive(as_primitive_array::<Date64Type>(array), part),
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            date_part_primitive(as_primitive_array::<TimestampSecondType>(array), part)
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            date_part_primitive(as_primitive_array::<TimestampMillisecondType>(array), part)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            date_part_primitive(as_primitive_array::<TimestampMicrosecondType>(array), part)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            date_part_primitive(as_primitive_array::<TimestampNanosecondType>(array), part)
+        }
+        DataType::Time32(TimeUnit::Second) => {
+            date_part_primitive(as_primitive_array::<Time32SecondType>(array), part)
+        }
+        DataType::Time32(TimeUnit::Millisecond) => {
+            date_part_primitive(as_primitive_array::<Time32MillisecondType>(array), part)
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            date_part_primitive(as_primitive_array::<Time64MicrosecondType>(array), part)
+        }
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            date_part_primitive(as_primitive_array::<Time64NanosecondType>(array), part)
+        }
+        t => return_compute_error_with!("date_part does not support", t),
+    }
+}
+
+/// Extracts `part` of the values of a date, time or timestamp array
+fn date_part_primitive<T>(array: &PrimitiveArray<T>, part: DatePart) -> Result<ArrayRef>
+where
+    T: ArrowTemporalType,
+    i64: From<T::Native>,
+{
+    let invalid = |v: i64| {
+        ArrowError::ComputeError(format!(
+            "Unable to read value {} of {} as datetime",
+            v,
+            array.data_type()
+        ))
+    };
+
+    if matches!(array.data_type(), DataType::Time32(_) | DataType::Time64(_)) {
+        let time = |v: T::Native| {
+            let v = i64::from(v);
+            as_time::<T>(v).ok_or_else(|| invalid(v))
+        };
+        return match part {
+            DatePart::Epoch => {
+                let seconds = |t: NaiveTime| {
+                    t.num_seconds_from_midnight() as f64 + t.nanosecond() as f64 / 1e9
+                };
+                let result: Float64Array = array.try_unary(|v| time(v).map(seconds))?;
+                Ok(Arc::new(result))
+            }
+            _ if part.of_time(&NaiveTime::from_hms(0, 0, 0)).is_none() => {
+                return_compute_error_with!(
+                    format!("date_part {:?} does not support", part),
+                    array.data_type()
+                )
+            }
+            _ => {
+                let result: Int32Array =
+                    array.try_unary(|v| time(v).map(|t| part.of_time(&t).unwrap()))?;
+                Ok(Arc::new(result))
+            }
+        };
+    }
+
+    let datetime = |v: T::Native| {
+        let v = i64::from(v);
+        as_datetime::<T>(v).ok_or_else(|| invalid(v))
+    };
+    match (part, timestamp_tz(array.data_type())?) {
+        (DatePart::Epoch, _) => {
+            let result: Float64Array = array.try_unary(|v| {
+                let utc = datetime(v)?;
+                let nanos = utc.timestamp_subsec_nanos() as f64;
+                Ok::<_, ArrowError>(utc.timestamp() as f64 + nanos / 1e9)
+            })?;
+            Ok(Arc::new(result))
+        }
+        (part, tz) => {
+            let result: Int32Array = array.try_unary(|v| {
+                let utc = datetime(v)?;
+                let local = match tz {
+                    Some(tz) => tz.to_local(utc).ok_or_else(|| invalid(i64::from(v)))?,
+                    None => utc,
+                };
+                Ok::<_, ArrowError>(part.of_datetime(&local).unwrap())
+            })?;
+            Ok(Arc::new(result))
+        }
+    }
+}
+
+/// The granularity to truncate temporal values to, see [`date_trunc`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTruncGranularity {
+    /// The first day of the year
+    Year,
+    /// The first day of the quarter
+    Quarter,
+    /// The first day of the month
+    Month,
+    /// The Monday of the ISO 8601 week
+    Week,
+    /// The start of the day
+    Day,
+    /// The start of the hour
+    Hour,
+    /// The start of the minute
+    Minute,
+    /// The start of the second
+    Second,
+}
+
+impl DateTruncGranularity {
+    /// Returns the duration in seconds of granularities with a fixed duration
+    fn fixed_seconds(&self) -> Option<i64> {
+        match self {
+            Self::Day => Some(86_400),
+            Self::Hour => Some(3_600),
+            Self::Minute => Some(60),
+            Self::Second => Some(1),
+            Self::Year | Self::Quarter | Self::Month | Self::Week => None,
+        }
+    }
+
+    /// Truncates `datetime` to this granularity, returning `None` if the result is out
+    /// of range
+    fn truncate(&self, datetime: NaiveDateTime) -> Option<NaiveDateTime> {
+        let date = datetime.date();
+        let date = match self {
+            Self::Year => NaiveDate::from_ymd(date.year(), 1, 1),
+            Self::Quarter => NaiveDate::from_ymd(date.year(), date.quarter0() * 3 + 1, 1),
+            Self::Month => NaiveDate::from_ymd(date.year(), date.month(), 1),
+            Self::Week => date.checked_sub_signed(chrono::Duration::days(
+                date.num_days_from_monday() as i64,
+            ))?,
+            _ => date,
+        };
+        Some(match self {
+            Self::Hour => date.and_hms(datetime.hour(), 0, 0),
+            Self::Minute => date.and_hms(datetime.hour(), datetime.minute(), 0),
+            Self::Second => {
+                date.and_hms(datetime.hour(), datetime.minute(), datetime.second())
+            }
+            _ => date.and_hms(0, 0, 0),
+        })
+    }
+}
+
+/// Truncates the values of a timestamp array to `granularity`, e.g. to the start of
+/// the day, in the timezone of the array, if any
+///
+/// Values that would overflow once truncated return an error.
+///
+/// ```
+/// # use arrow::array::{Array, TimestampSecondArray};
+/// # use arrow::compute::kernels::temporal::{date_trunc, DateTruncGranularity};
+/// // 2022-11-17T14:25:36Z
+/// let array = TimestampSecondArray::from(vec![Some(1668695136), None])
+///     .with_timezone("+02:00".to_string());
+///
+/// // 2022-11-17T00:00:00+02:00
+/// let day = date_trunc(&array, DateTruncGranularity::Day).unwrap();
+/// assert_eq!(day.value(0), 1668636000);
+/// assert!(day.is_null(1));
+///
+/// // 2022-11-01T00:00:00+02:00
+/// let month = date_trunc(&array, DateTruncGranularity::Month).unwrap();
+/// assert_eq!(month.value(0), 1667253600);
+/// ```
+pub fn date_trunc<T>(
+    array: &PrimitiveArray<T>,
+    granularity: DateTruncGranularity,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowTimestampType<Native = i64>,
+{
+    let unit = T::get_time_unit();
+    let tz = timestamp_tz(array.data_type())?;
+    let truncated = trunc_timestamps(array, &unit, tz, granularity)?;
+    Ok(match array.data_type() {
+        DataType::Timestamp(_, Some(tz)) => truncated.with_timezone(tz.clone()),
+        _ => truncated,
+    })
+}
+
+/// Truncates the values of a date or timestamp array to `granularity`, returning an
+/// array of the same type, see [`date_trunc`]
+///
+/// Truncating a date to a granularity of a day or finer returns it unchanged.
+pub fn date_trunc_dyn(
+    array: &dyn Array,
+    granularity: DateTruncGranularity,
+) -> Result<ArrayRef> {
+    Ok(match array.data_type() {
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            let array = as_primitive_array::<TimestampSecondType>(array);
+            Arc::new(date_trunc(array, granularity)?)
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            let array = as_primitive_array::<TimestampMillisecondType>(array);
+            Arc::new(date_trunc(array, granularity)?)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            let array = as_primitive_array::<TimestampMicrosecondType>(array);
+            Arc::new(date_trunc(array, granularity)?)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            let array = as_primitive_array::<TimestampNanosecondType>(array);
+            Arc::new(date_trunc(array, granularity)?)
+        }
+        DataType::Date32 => {
+            let epoch = NaiveDate::from_ymd(1970, 1, 1);
+            let array = as_primitive_array::<Date32Type>(array);
+            Arc::new(array.try_unary::<_, Date32Type, _>(|days| {
+                epoch
+                    .checked_add_signed(chrono::Duration::days(days as i64))
+                    .and_then(|date| granularity.truncate(date.and_hms(0, 0, 0)))
+                    .map(|truncated| (truncated.date() - epoch).num_days() as i32)
+                    .ok_or_else(|| {
+                        ArrowError::ComputeError(format!(
+                            "Overflow truncating {} to {:?}",
+                            days, granularity
+                        ))
+                    })
+            })?)
+        }
+        DataType::Date64 => {
+            let array = as_primitive_array::<Date64Type>(array);
+            let unit = TimeUnit::Millisecond;
+            Arc::new(trunc_timestamps(array, &unit, None, granularity)?)
+        }
+        t => return_compute_error_with!("date_trunc does not support", t),
+    })
+}
+
+/// Bins the values of a timestamp array into intervals of `stride` aligned to `origin`,
+/// returning the start of the interval containing each value, like `date_bin` in
+/// PostgreSQL
+///
+/// `stride` and `origin` are in the [`TimeUnit`] of the array, with `origin` the
+/// number of units since the UNIX epoch. As this operates on the absolute instants
+/// of the values, the timezone of the array, which is preserved, does not affect it.
+///
+/// Returns an error if `stride` is not positive, or a value would overflow once binned.
+///
+/// ```
+/// # use arrow::array::TimestampSecondArray;
+/// # use arrow::compute::kernels::temporal::date_bin;
+/// // 2022-11-17T14:25:36Z
+/// let array = TimestampSecondArray::from(vec![1668695136]);
+///
+/// // 15 minute intervals starting at 2001-01-01T00:00:00Z
+/// let binned = date_bin(&array, 15 * 60, 978307200).unwrap();
+///
+/// // 2022-11-17T14:15:00Z
+/// assert_eq!(binned.value(0), 1668694500);
+/// ```
+pub fn date_bin<T>(
+    array: &PrimitiveArray<T>,
+    stride: i64,
+    origin: i64,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowTimestampType<Native = i64>,
+{
+    if stride <= 0 {
+        return_compute_error_with!("date_bin stride must be positive, got", stride)
+    }
+    let binned = array.try_unary(|v| {
+        v.checked_sub(origin)
+            .and_then(|delta| delta.div_euclid(stride).checked_mul(stride))
+            .and_then(|delta| delta.checked_add(origin))
+            .ok_or_else(|| {
+                ArrowError::ComputeError(format!(
+                    "Overflow binning {} with stride {} and origin {}",
+                    v, stride, origin
+                ))
+            })
+    })?;
+    Ok(match array.data_type() {
+        DataType::Timestamp(_, Some(tz)) => binned.with_timezone(tz.clone()),
+        _ => binned,
+    })
+}
+
+/// Formats the values of a date, time or timestamp array as strings with a
+/// [chrono format string](chrono::format::strftime)
+///
+/// Timestamps are formatted in the timezone `tz` if specified, otherwise in the
+/// timezone of the array if any. Timestamps with a timezone can be formatted with
+/// specifiers of the offset, such as `%z`, while dates are formatted with a time of
+/// midnight, and times cannot be formatted with specifiers of a date.
+///
+/// Returns an error if `format` is invalid, or contains a specifier not applicable
+/// to the values.
+///
+/// ```
+/// # use arrow::array::{StringArray, TimestampSecondArray};
+/// # use arrow::compute::kernels::temporal::strftime;
+/// // 2022-11-17T14:25:36Z
+/// let array = TimestampSecondArray::from(vec![Some(1668695136), None]);
+///
+/// let formatted = strftime(&array, "%d/%m/%Y %H:%M", None).unwrap();
+/// assert_eq!(formatted, StringArray::from(vec![Some("17/11/2022 14:25"), None]));
+///
+/// let formatted = strftime(&array, "%H:%M %:z", Some("-05:00")).unwrap();
+/// assert_eq!(formatted, StringArray::from(vec![Some("09:25 -05:00"), None]));
+/// ```
+pub fn strftime(
+    array: &dyn Array,
+    format: &str,
+    tz: Option<&str>,
+) -> Result<StringArray> {
+    let items: Vec<_> = StrftimeItems::new(format).collect();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return_compute_error_with!("Invalid format string", format)
+    }
+    let tz = match tz {
+        Some(tz) => Some(Tz::parse(tz)?),
+        None => timestamp_tz(array.data_type())?,
+    };
+
+    match array.data_type() {
+        DataType::Date32 => {
+            strftime_primitive(as_primitive_array::<Date32Type>(array), &items, tz)
+        }
+        DataType::Date64 => {
+            strftime_primitive(as_primitive_array::<Date64Type>(array), &items, tz)
+        }
+        DataType::Timestamp(TimeUnit::Second, _) => strftime_primitive(
+            as_primitive_array::<TimestampSecondType>(array),
+            &items,
+            tz,
+        ),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => strftime_primitive(
+            as_primitive_array::<TimestampMillisecondType>(array),
+            &items,
+            tz,
+        ),
+        DataType::Timestamp(TimeUnit::Microsecond, _) => strftime_primitive(
+            as_primitive_array::<TimestampMicrosecondType>(array),
+            &items,
+            tz,
+        ),
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => strftime_primitive(
+            as_primitive_array::<TimestampNanosecondType>(array),
+            &items,
+            tz,
+        ),
+        DataType::Time32(TimeUnit::Second) => {
+            strftime_primitive(as_primitive_array::<Time32SecondType>(array), &items, tz)
+        }
+        DataType::Time32(TimeUnit::Millisecond) => strftime_primitive(
+            as_primitive_array::<Time32MillisecondType>(array),
+            &items,
+            tz,
+        ),
+        DataType::Time64(TimeUnit::Microsecond) => strftime_primitive(
+            as_primitive_array::<Time64MicrosecondType>(array),
+            &items,
+            tz,
+        ),
+        DataType::Time64(TimeUnit::Nanosecond) => strftime_primitive(
+            as_primitive_array::<Time64NanosecondType>(array),
+            &items,
+            tz,
+        ),
+        t => return_compute_error_with!("strftime does not support", t),
+    }
+}
+
+/// Formats the values of a date, time or timestamp array with `items`, see [`strftime`]
+fn strftime_primitive<T>(
+    array: &PrimitiveArray<T>,
+    items: &[Item<'_>],
+    tz: Option<Tz>,
+) -> Result<StringArray>
+where
+    T: ArrowTemporalType,
+    i64: From<T::Native>,
+{
+    use std::fmt::Write;
+
+    let is_time = matches!(array.data_type(), DataType::Time32(_) | DataType::Time64(_));
+    let is_timestamp = matches!(array.data_type(), DataType::Timestamp(_, _));
+
+    let mut builder = StringBuilder::with_capacity(array.len(), array.len() * 16);
+    let mut buffer = String::new();
+    for value in array.iter() {
+        let value = match value {
+            Some(value) => i64::from(value),
+            None => {
+                builder.append_null();
+                continue;
+            }
+        };
+        buffer.clear();
+        let written = match (is_time, is_timestamp.then_some(tz).flatten()) {
+            (true, _) => as_time::<T>(value)
+                .map(|time| write!(buffer, "{}", time.format_with_items(items.iter()))),
+            (false, Some(tz)) => as_datetime::<T>(value).map(|utc| {
+                let datetime = chrono::DateTime::<FixedOffset>::from_utc(
+                    utc,
+                    tz.offset_from_utc(&utc),
+                );
+                write!(buffer, "{}", datetime.format_with_items(items.iter()))
+            }),
+            (false, None) => as_datetime::<T>(value).map(|datetime| {
+                write!(buffer, "{}", datetime.format_with_items(items.iter()))
+            }),
+        };
+        match written {
+            Some(Ok(_)) => builder.append_value(&buffer),
+            Some(Err(_)) => {
+                return Err(ArrowError::ComputeError(format!(
+                    "Format string not applicable to value {} of {}",
+                    value,
+                    array.data_type()
+                )))
+            }
+            None => return_compute_error_with!("Unable to read value as datetime", value),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Parses the values of a string array as timestamps with a
+/// [chrono format string](chrono::format::strftime)
+///
+/// Unlike casting a string array to a timestamp, which only accepts RFC3339-like
+/// strings, this accepts any format. Values with an offset, parsed with specifiers
+/// such as `%z`, are converted to UTC with that offset. Values without an offset are
+/// interpreted as local to the timezone `tz` if specified, and otherwise as UTC. The
+/// time of values without one, parsed with a format containing only a date, is
+/// midnight.
+///
+/// The returned array has the timezone `tz`. Values that cannot be parsed are null if
+/// [`CastOptions::safe`] is true, otherwise an error is returned.
+///
+/// ```
+/// # use arrow::array::{StringArray, TimestampSecondArray};
+/// # use arrow::compute::kernels::cast::CastOptions;
+/// # use arrow::compute::kernels::temporal::strptime;
+/// # use arrow::datatypes::TimestampSecondType;
+/// let array = StringArray::from(vec![Some("17/11/2022 14:25"), Some("invalid"), None]);
+/// let options = CastOptions::new(true);
+///
+/// let parsed = strptime::<TimestampSecondType, _>(&array, "%d/%m/%Y %H:%M", None, &options);
+/// let expected = TimestampSecondArray::from(vec![Some(1668695100), None, None]);
+/// assert_eq!(parsed.unwrap(), expected);
+///
+/// let options = CastOptions::new(false);
+/// assert!(strptime::<TimestampSecondType, _>(&array, "%d/%m/%Y %H:%M", None, &options).is_err());
+/// ```
+pub fn strptime<T, O>(
+    array: &GenericStringArray<O>,
+    format: &str,
+    tz: Option<&str>,
+    cast_options: &CastOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowTimestampType<Native = i64>,
+    O: OffsetSizeTrait,
+{
+    let array = parse_timestamps::<T, O>(array, &[format], tz, cast_options)?;
+    Ok(match tz {
+        Some(tz) => array.with_timezone(tz.to_string()),
+        None => array,
+    })
+}
+
+/// Parses the values of `array` with the first of `formats` that matches, interpreting
+/// values without an offset as local to `tz`, see [`strptime`]
+///
+/// The returned array has no timezone
+pub(crate) fn parse_timestamps<T, O>(
+    array: &GenericStringArray<O>,
+    formats: &[&str],
+    tz: Option<&str>,
+    cast_options: &CastOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowTimestampType<Native = i64>,
+    O: OffsetSizeTrait,
+{
+    let items = formats
+        .iter()
+        .map(|format| {
+            let items: Vec<_> = StrftimeItems::new(format).collect();
+            if items.iter().any(|item| matches!(item, Item::Error)) {
+                return_compute_error_with!("Invalid format string", format)
+            }
+            Ok(items)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let parsed_tz = tz.map(Tz::parse).transpose()?;
+    let unit = T::get_time_unit();
+
+    let parse_value = |value: &str| {
+        items
+            .iter()
+            .find_map(|items| parse_datetime(value, items, parsed_tz))
+            .and_then(|datetime| datetime_to_timestamp(datetime, &unit))
+    };
+
+    if cast_options.safe {
+        return Ok(array.iter().map(|v| v.and_then(parse_value)).collect());
+    }
+    array
+        .iter()
+        .map(|v| {
+            v.map(|v| {
+                parse_value(v).ok_or_else(|| {
+                    ArrowError::CastError(format!(
+                        "Error parsing '{}' as {:?} with format '{}'",
+                        v,
+                        DataType::Timestamp(unit.clone(), None),
+                        formats.join("' or '")
+                    ))
+                })
+            })
+            .transpose()
+        })
+        .collect()
+}
+
+/// Parses `value` with `items` returning the UTC datetime, see [`strptime`]
+fn parse_datetime(
+    value: &str,
+    items: &[Item<'_>],
+    tz: Option<Tz>,
+) -> Option<NaiveDateTime> {
+    let mut parsed = Parsed::new();
+    parse(&mut parsed, value, items.iter()).ok()?;
+
+    if parsed.offset.is_some() {
+        return parsed
+            .to_datetime()
+            .ok()
+            .map(|datetime| datetime.naive_utc());
+    }
+    if parsed.timestamp.is_some() {
+        return parsed.to_naive_datetime_with_offset(0).ok();
+    }
+
+    let local = match parsed.to_naive_datetime_with_offset(0) {
+        Ok(local) => local,
+        Err(_) if parsed.hour_div_12.is_none() && parsed.hour_mod_12.is_none() => {
+            parsed.to_naive_date().ok()?.and_hms(0, 0, 0)
+        }
+        Err(_) => return None,
+    };
+    match tz {
+        Some(tz) => tz.to_utc(local),
+        None => Some(local),
+    }
+}
+
+/// Truncates the values of `array`, the number of `unit` since the UNIX epoch in `tz`
+fn trunc_timestamps<T>(
+    array: &PrimitiveArray<T>,
+    unit: &TimeUnit,
+    tz: Option<Tz>,
+    granularity: DateTruncGranularity,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowTemporalType<Native = i64>,
+{
+    let overflow = |v: i64| {
+        ArrowError::ComputeError(format!(
+            "Overflow truncating {} to {:?}",
+            v, granularity
+        ))
+    };
+
+    let fixed_offset = match tz {
+        None => Some(0),
+        Some(Tz::Fixed(offset)) => Some(offset.local_minus_utc() as i64),
+        #[cfg(feature = "chrono-tz")]
+        Some(Tz::Named(_)) => None,
+    };
+
+    // Granularities with a fixed duration in timezones with a fixed offset
+    // only require arithmetic
+    if let (Some(seconds), Some(offset)) = (granularity.fixed_seconds(), fixed_offset) {
+        let width = seconds * time_unit_multiple(unit);
+        let offset = offset * time_unit_multiple(unit);
+        return array.try_unary(|v| {
+            let local = v.checked_add(offset).ok_or_else(|| overflow(v))?;
+            v.checked_sub(local.rem_euclid(width))
+                .ok_or_else(|| overflow(v))
+        });
+    }
+
+    array.try_unary(|v| {
+        let utc = as_datetime::<T>(v).ok_or_else(|| overflow(v))?;
+        let truncated = match tz {
+            Some(tz) => tz
+                .to_local(utc)
+                .and_then(|local| granularity.truncate(local))
+                .and_then(|truncated| tz.to_utc(truncated)),
+            None => granularity.truncate(utc),
+        };
+        truncated
+            .and_then(|truncated| datetime_to_timestamp(truncated, unit))
+            .ok_or_else(|| overflow(v))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1523,4 +2358,508 @@ mod tests {
         let expected = Int32Array::from(vec![Some(1), Some(8), Some(8), Some(1), None]);
         assert_eq!(expected, b);
     }
+
+    /// Returns the nanoseconds since the UNIX epoch of a UTC datetime
+    fn ns(y: i32, m: u32, d: u32, h: u32, min: u32, sec: u32, nano: u32) -> i64 {
+        NaiveDate::from_ymd(y, m, d)
+            .and_hms_nano(h, min, sec, nano)
+            .timestamp_nanos()
+    }
+
+    #[test]
+    fn test_date_trunc() {
+        // A Thursday
+        let array = TimestampNanosecondArray::from(vec![
+            Some(ns(2022, 11, 17, 14, 25, 36, 123_456_789)),
+            None,
+            Some(ns(1969, 12, 31, 12, 30, 0, 5)),
+        ]);
+
+        let cases = [
+            (
+                DateTruncGranularity::Year,
+                ns(2022, 1, 1, 0, 0, 0, 0),
+                ns(1969, 1, 1, 0, 0, 0, 0),
+            ),
+            (
+                DateTruncGranularity::Quarter,
+                ns(2022, 10, 1, 0, 0, 0, 0),
+                ns(1969, 10, 1, 0, 0, 0, 0),
+            ),
+            (
+                DateTruncGranularity::Month,
+                ns(2022, 11, 1, 0, 0, 0, 0),
+                ns(1969, 12, 1, 0, 0, 0, 0),
+            ),
+            (
+                DateTruncGranularity::Week,
+                ns(2022, 11, 14, 0, 0, 0, 0),
+                ns(1969, 12, 29, 0, 0, 0, 0),
+            ),
+            (
+                DateTruncGranularity::Day,
+                ns(2022, 11, 17, 0, 0, 0, 0),
+                ns(1969, 12, 31, 0, 0, 0, 0),
+            ),
+            (
+                DateTruncGranularity::Hour,
+                ns(2022, 11, 17, 14, 0, 0, 0),
+                ns(1969, 12, 31, 12, 0, 0, 0),
+            ),
+            (
+                DateTruncGranularity::Minute,
+                ns(2022, 11, 17, 14, 25, 0, 0),
+                ns(1969, 12, 31, 12, 30, 0, 0),
+            ),
+            (
+                DateTruncGranularity::Second,
+                ns(2022, 11, 17, 14, 25, 36, 0),
+                ns(1969, 12, 31, 12, 30, 0, 0),
+            ),
+        ];
+        for (granularity, a, b) in cases {
+            let expected = TimestampNanosecondArray::from(vec![Some(a), None, Some(b)]);
+            let actual = date_trunc(&array, granularity).unwrap();
+            assert_eq!(actual, expected, "{:?}", granularity);
+
+            let seconds: TimestampSecondArray =
+                array.unary(|x| x.div_euclid(1_000_000_000));
+            let expected: TimestampSecondArray =
+                expected.unary(|x| x.div_euclid(1_000_000_000));
+            let actual = date_trunc_dyn(&seconds, granularity).unwrap();
+            assert_eq!(actual.as_ref(), &expected, "{:?}", granularity);
+        }
+    }
+
+    #[test]
+    fn test_date_trunc_fixed_offset() {
+        // 2022-11-30T21:30:00-05:30
+        let array = TimestampMillisecondArray::from(vec![
+            ns(2022, 12, 1, 3, 0, 0, 0) / 1_000_000,
+        ])
+        .with_timezone("-05:30".to_string());
+
+        let month = date_trunc(&array, DateTruncGranularity::Month).unwrap();
+        assert_eq!(month.data_type(), array.data_type());
+        assert_eq!(month.value(0), ns(2022, 11, 1, 5, 30, 0, 0) / 1_000_000);
+
+        let day = date_trunc(&array, DateTruncGranularity::Day).unwrap();
+        assert_eq!(day.value(0), ns(2022, 11, 30, 5, 30, 0, 0) / 1_000_000);
+
+        let hour = date_trunc(&array, DateTruncGranularity::Hour).unwrap();
+        assert_eq!(hour.value(0), ns(2022, 12, 1, 2, 30, 0, 0) / 1_000_000);
+
+        let array =
+            TimestampSecondArray::from(vec![0]).with_timezone("+0530".to_string());
+        let err = date_trunc(&array, DateTruncGranularity::Day).unwrap_err();
+        assert!(err.to_string().contains("Invalid timezone"), "{}", err);
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_date_trunc_named_timezone() {
+        // 2022-11-06T07:00:00-05:00, after the end of daylight saving time
+        let array = TimestampSecondArray::from(vec![
+            ns(2022, 11, 6, 12, 0, 0, 0) / 1_000_000_000,
+        ])
+        .with_timezone("America/New_York".to_string());
+
+        // 2022-11-06T00:00:00-04:00
+        let day = date_trunc(&array, DateTruncGranularity::Day).unwrap();
+        assert_eq!(day.value(0), ns(2022, 11, 6, 4, 0, 0, 0) / 1_000_000_000);
+
+        // 2022-11-06T07:00:00-05:00
+        let hour = date_trunc(&array, DateTruncGranularity::Hour).unwrap();
+        assert_eq!(hour.value(0), array.value(0));
+    }
+
+    #[test]
+    fn test_date_trunc_dates() {
+        // 2022-11-17, a Thursday
+        let days = (ns(2022, 11, 17, 0, 0, 0, 0) / 86_400_000_000_000) as i32;
+        let array = Date32Array::from(vec![Some(days), None]);
+
+        let week = date_trunc_dyn(&array, DateTruncGranularity::Week).unwrap();
+        assert_eq!(
+            week.as_ref(),
+            &Date32Array::from(vec![Some(days - 3), None])
+        );
+
+        let day = date_trunc_dyn(&array, DateTruncGranularity::Day).unwrap();
+        assert_eq!(day.as_ref(), &array);
+
+        let millis = ns(2022, 11, 17, 14, 25, 36, 0) / 1_000_000;
+        let array = Date64Array::from(vec![millis]);
+        let quarter = date_trunc_dyn(&array, DateTruncGranularity::Quarter).unwrap();
+        let expected = Date64Array::from(vec![ns(2022, 10, 1, 0, 0, 0, 0) / 1_000_000]);
+        assert_eq!(quarter.as_ref(), &expected);
+
+        let array = Int64Array::from(vec![1]);
+        let err = date_trunc_dyn(&array, DateTruncGranularity::Day).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: date_trunc does not support: Int64"
+        );
+    }
+
+    #[test]
+    fn test_date_trunc_overflow() {
+        let array = TimestampNanosecondArray::from(vec![i64::MIN + 1]);
+        for granularity in [DateTruncGranularity::Year, DateTruncGranularity::Day] {
+            let err = date_trunc(&array, granularity).unwrap_err();
+            assert!(err.to_string().contains("Overflow"), "{}", err);
+        }
+
+        // +262143-12-31T23:59:59Z, the last representable datetime, which is out of
+        // range in a timezone ahead of UTC
+        let array = TimestampSecondArray::from(vec![8210298412799])
+            .with_timezone("+05:00".to_string());
+        for granularity in [DateTruncGranularity::Month, DateTruncGranularity::Week] {
+            let err = date_trunc(&array, granularity).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "Compute error: Overflow truncating 8210298412799 to {:?}",
+                    granularity
+                )
+            );
+        }
+
+        let array = Date32Array::from(vec![i32::MAX]);
+        let err = date_trunc_dyn(&array, DateTruncGranularity::Month).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Overflow truncating 2147483647 to Month"
+        );
+    }
+
+    #[test]
+    fn test_date_bin() {
+        let origin = ns(2001, 1, 1, 0, 0, 0, 0);
+        let array = TimestampNanosecondArray::from(vec![
+            Some(ns(2022, 11, 17, 14, 25, 36, 123)),
+            None,
+            Some(ns(2001, 1, 1, 0, 0, 0, 0)),
+            Some(ns(2000, 12, 31, 23, 59, 59, 999_999_999)),
+            Some(ns(1969, 7, 20, 20, 17, 40, 0)),
+        ])
+        .with_timezone("+05:00".to_string());
+
+        let binned = date_bin(&array, 15 * 60 * 1_000_000_000, origin).unwrap();
+        let expected = TimestampNanosecondArray::from(vec![
+            Some(ns(2022, 11, 17, 14, 15, 0, 0)),
+            None,
+            Some(ns(2001, 1, 1, 0, 0, 0, 0)),
+            Some(ns(2000, 12, 31, 23, 45, 0, 0)),
+            Some(ns(1969, 7, 20, 20, 15, 0, 0)),
+        ])
+        .with_timezone("+05:00".to_string());
+        assert_eq!(binned, expected);
+
+        // Stride not evenly dividing a day, with an origin offset
+        let array = TimestampSecondArray::from(vec![0, 6, 7, -1, -8]);
+        let binned = date_bin(&array, 7, 3).unwrap();
+        assert_eq!(binned.values(), &[-4, 3, 3, -4, -11]);
+
+        let err = date_bin(&array, 0, 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: date_bin stride must be positive, got: 0"
+        );
+
+        let array = TimestampMillisecondArray::from(vec![i64::MIN]);
+        let err = date_bin(&array, 1000, 1).unwrap_err();
+        assert!(err.to_string().contains("Overflow"), "{}", err);
+    }
+
+    #[test]
+    fn test_date_part() {
+        let value = ns(2022, 11, 17, 14, 25, 36, 123_456_789);
+        let timestamps = TimestampNanosecondArray::from(vec![Some(value), None])
+            .with_timezone("+02:00".to_string());
+        let dates =
+            Date32Array::from(vec![Some((value / 86_400_000_000_000) as i32), None]);
+        let times =
+            Time64NanosecondArray::from(vec![Some(value % 86_400_000_000_000), None]);
+
+        let cases = [
+            (DatePart::Year, Some(2022), Some(2022), None),
+            (DatePart::Quarter, Some(4), Some(4), None),
+            (DatePart::Month, Some(11), Some(11), None),
+            (DatePart::Week, Some(46), Some(46), None),
+            (DatePart::Day, Some(17), Some(17), None),
+            (DatePart::DayOfWeekSunday0, Some(4), Some(4), None),
+            (DatePart::DayOfWeekMonday0, Some(3), Some(3), None),
+            (DatePart::DayOfYear, Some(321), Some(321), None),
+            (DatePart::Hour, Some(16), Some(0), Some(14)),
+            (DatePart::Minute, Some(25), Some(0), Some(25)),
+            (DatePart::Second, Some(36), Some(0), Some(36)),
+            (DatePart::Millisecond, Some(123), Some(0), Some(123)),
+            (DatePart::Microsecond, Some(123_456), Some(0), Some(123_456)),
+            (
+                DatePart::Nanosecond,
+                Some(123_456_789),
+                Some(0),
+                Some(123_456_789),
+            ),
+        ];
+
+        for (part, timestamp, date, time) in cases {
+            let actual = date_part(&timestamps, part).unwrap();
+            let expected = Int32Array::from(vec![timestamp, None]);
+            assert_eq!(actual.as_ref(), &expected, "{:?}", part);
+
+            let actual = date_part(&dates, part).unwrap();
+            let expected = Int32Array::from(vec![date, None]);
+            assert_eq!(actual.as_ref(), &expected, "{:?}", part);
+
+            match time {
+                Some(time) => {
+                    let actual = date_part(&times, part).unwrap();
+                    let expected = Int32Array::from(vec![Some(time), None]);
+                    assert_eq!(actual.as_ref(), &expected, "{:?}", part);
+                }
+                None => {
+                    let err = date_part(&times, part).unwrap_err();
+                    assert_eq!(
+                        err.to_string(),
+                        format!(
+                            "Compute error: date_part {:?} does not support: Time64(Nanosecond)",
+                            part
+                        )
+                    );
+                }
+            }
+        }
+
+        let epoch = date_part(&timestamps, DatePart::Epoch).unwrap();
+        let epoch = as_primitive_array::<Float64Type>(&epoch);
+        assert!((epoch.value(0) - 1668695136.123457).abs() < 1e-6);
+        assert!(epoch.is_null(1));
+
+        let epoch = date_part(&dates, DatePart::Epoch).unwrap();
+        let expected = Float64Array::from(vec![Some(1668643200.), None]);
+        assert_eq!(epoch.as_ref(), &expected);
+
+        let epoch = date_part(&times, DatePart::Epoch).unwrap();
+        let expected = Float64Array::from(vec![Some(51936.123456789), None]);
+        assert_eq!(epoch.as_ref(), &expected);
+
+        let err = date_part(&Int32Array::from(vec![1]), DatePart::Year).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: date_part does not support: Int32"
+        );
+    }
+
+    #[test]
+    fn test_date_part_out_of_range() {
+        // +262143-12-31T23:59:59Z, the last representable datetime, which is out of
+        // range in a timezone ahead of UTC
+        let array = TimestampSecondArray::from(vec![8210298412799])
+            .with_timezone("+05:00".to_string());
+        let err = date_part(&array, DatePart::Hour).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Unable to read value 8210298412799 of Timestamp(Second, Some(\"+05:00\")) as datetime"
+        );
+
+        // the epoch doesn't depend on the timezone
+        let epoch = date_part(&array, DatePart::Epoch).unwrap();
+        let expected = Float64Array::from(vec![8210298412799.]);
+        assert_eq!(epoch.as_ref(), &expected);
+
+        let array = TimestampSecondArray::from(vec![8210298412799])
+            .with_timezone("-05:00".to_string());
+        let hour = date_part(&array, DatePart::Hour).unwrap();
+        assert_eq!(hour.as_ref(), &Int32Array::from(vec![18]));
+    }
+
+    #[test]
+    fn test_date_part_dictionary() {
+        let values = Time32SecondArray::from(vec![3_600, 7_261]);
+        let keys = Int8Array::from(vec![Some(1), None, Some(0), Some(1)]);
+        let dict = DictionaryArray::try_new(&keys, &values).unwrap();
+
+        let actual = date_part(&dict, DatePart::Minute).unwrap();
+        let expected = Int32Array::from(vec![Some(1), None, Some(0), Some(1)]);
+        assert_eq!(actual.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_strftime() {
+        let timestamps =
+            TimestampMillisecondArray::from(vec![Some(1668695136123), None, Some(-1000)]);
+        let actual = strftime(&timestamps, "%Y-%m-%dT%H:%M:%S%.3f", None).unwrap();
+        let expected = StringArray::from(vec![
+            Some("2022-11-17T14:25:36.123"),
+            None,
+            Some("1969-12-31T23:59:59.000"),
+        ]);
+        assert_eq!(actual, expected);
+
+        // Formatted in the timezone of the array, or the specified timezone
+        let timestamps = timestamps.with_timezone("+01:00".to_string());
+        let actual = strftime(&timestamps, "%H:%M %z", None).unwrap();
+        let expected =
+            StringArray::from(vec![Some("15:25 +0100"), None, Some("00:59 +0100")]);
+        assert_eq!(actual, expected);
+
+        let actual = strftime(&timestamps, "%H:%M %z", Some("-02:30")).unwrap();
+        let expected =
+            StringArray::from(vec![Some("11:55 -0230"), None, Some("21:29 -0230")]);
+        assert_eq!(actual, expected);
+
+        let dates = Date32Array::from(vec![Some(19313), None]);
+        let actual = strftime(&dates, "%A %-d %B %Y", None).unwrap();
+        let expected = StringArray::from(vec![Some("Thursday 17 November 2022"), None]);
+        assert_eq!(actual, expected);
+
+        let times = Time64NanosecondArray::from(vec![Some(51_936_123_456_789), None]);
+        let actual = strftime(&times, "%I:%M:%S%.f %p", None).unwrap();
+        let expected = StringArray::from(vec![Some("02:25:36.123456789 PM"), None]);
+        assert_eq!(actual, expected);
+
+        let err = strftime(&times, "%Y", None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Format string not applicable to value 51936123456789 of Time64(Nanosecond)"
+        );
+
+        let err = strftime(&dates, "%z", None).unwrap_err();
+        assert!(err.to_string().contains("not applicable"), "{}", err);
+
+        let err = strftime(&dates, "%Q", None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Invalid format string: \"%Q\""
+        );
+
+        let err = strftime(&Int32Array::from(vec![1]), "%Y", None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: strftime does not support: Int32"
+        );
+    }
+
+    #[test]
+    fn test_strptime() {
+        let safe = CastOptions {
+            safe: true,
+            ..Default::default()
+        };
+        let strict = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+
+        let array = StringArray::from(vec![
+            Some("2022-11-17 14:25:36.123"),
+            None,
+            Some("1969-12-31 23:59:59.5"),
+            Some("2022-11-17"),
+        ]);
+        let actual = strptime::<TimestampMillisecondType, _>(
+            &array,
+            "%Y-%m-%d %H:%M:%S%.f",
+            None,
+            &safe,
+        )
+        .unwrap();
+        let expected = TimestampMillisecondArray::from(vec![
+            Some(1668695136123),
+            None,
+            Some(-500),
+            None,
+        ]);
+        assert_eq!(actual, expected);
+
+        let err = strptime::<TimestampMillisecondType, _>(
+            &array,
+            "%Y-%m-%d %H:%M:%S%.f",
+            None,
+            &strict,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Error parsing '2022-11-17' as Timestamp(Millisecond, None) with format '%Y-%m-%d %H:%M:%S%.f'"
+        );
+
+        // Dates without a time are parsed as midnight
+        let array = LargeStringArray::from(vec!["17/11/2022", "01/01/1970"]);
+        let actual =
+            strptime::<TimestampSecondType, _>(&array, "%d/%m/%Y", None, &strict)
+                .unwrap();
+        let expected = TimestampSecondArray::from(vec![1668643200, 0]);
+        assert_eq!(actual, expected);
+
+        // Values with an offset are converted to UTC, others are local to the timezone
+        let array = StringArray::from(vec![
+            "2022-11-17 14:25:36 +0100",
+            "2022-11-17 14:25:36 -0230",
+        ]);
+        let actual = strptime::<TimestampSecondType, _>(
+            &array,
+            "%Y-%m-%d %H:%M:%S %z",
+            Some("+05:00"),
+            &strict,
+        )
+        .unwrap();
+        let expected = TimestampSecondArray::from(vec![1668691536, 1668704136])
+            .with_timezone("+05:00".to_string());
+        assert_eq!(actual, expected);
+
+        let array = StringArray::from(vec!["2022-11-17 14:25:36"]);
+        let actual = strptime::<TimestampNanosecondType, _>(
+            &array,
+            "%Y-%m-%d %H:%M:%S",
+            Some("+05:00"),
+            &strict,
+        )
+        .unwrap();
+        assert_eq!(actual.value(0), 1_668_677_136_000_000_000);
+        assert_eq!(
+            actual.data_type(),
+            &DataType::Timestamp(TimeUnit::Nanosecond, Some("+05:00".to_string()))
+        );
+
+        // Values that are out of range once converted to UTC
+        let array = StringArray::from(vec!["+262143-12-31 23:00", "2022-11-17 14:25"]);
+        let actual = strptime::<TimestampSecondType, _>(
+            &array,
+            "%Y-%m-%d %H:%M",
+            Some("-12:00"),
+            &safe,
+        )
+        .unwrap();
+        let expected = TimestampSecondArray::from(vec![None, Some(1668738300)])
+            .with_timezone("-12:00".to_string());
+        assert_eq!(actual, expected);
+
+        let err = strptime::<TimestampSecondType, _>(
+            &array,
+            "%Y-%m-%d %H:%M",
+            Some("-12:00"),
+            &strict,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Error parsing '+262143-12-31 23:00' as Timestamp(Second, None) with format '%Y-%m-%d %H:%M'"
+        );
+
+        let array = StringArray::from(vec!["+262143-12-31 23:00 -1200"]);
+        let actual =
+            strptime::<TimestampSecondType, _>(&array, "%Y-%m-%d %H:%M %z", None, &safe)
+                .unwrap();
+        assert!(actual.is_null(0));
+
+        let err =
+            strptime::<TimestampSecondType, _>(&array, "%Y-%Q", None, &safe).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Invalid format string: \"%Y-%Q\""
+        );
+    }
 }
@@ -0,0 +1,241 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels for inspecting and converting [`UnionArray`]s
+
+use crate::array::*;
+use crate::buffer::Buffer;
+use crate::compute::take;
+use crate::datatypes::{DataType, Field};
+use crate::error::{ArrowError, Result};
+
+/// Returns the fields and type ids of the union `array`
+fn union_type(array: &UnionArray) -> (&[Field], &[i8]) {
+    match array.data_type() {
+        DataType::Union(fields, type_ids, _) => (fields, type_ids),
+        _ => unreachable!("UnionArray must have a union data type"),
+    }
+}
+
+/// Returns the position of the child of `array` with type id `type_id`
+fn child_index(array: &UnionArray, type_id: i8) -> Result<usize> {
+    let (_, type_ids) = union_type(array);
+    type_ids.iter().position(|t| *t == type_id).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "union has no child with type id {}, expected one of {:?}",
+            type_id, type_ids
+        ))
+    })
+}
+
+/// Returns the type id of each slot of `array`
+pub fn type_ids(array: &UnionArray) -> Int8Array {
+    (0..array.len())
+        .map(|i| array.type_id(i))
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// Returns a mask that is true for each slot of `array` holding a value of
+/// the child with type id `type_id`
+pub fn type_id_mask(array: &UnionArray, type_id: i8) -> Result<BooleanArray> {
+    child_index(array, type_id)?;
+    Ok((0..array.len())
+        .map(|i| Some(array.type_id(i) == type_id))
+        .collect())
+}
+
+/// Returns the values of the child with type id `type_id` of `array`, with
+/// one value per slot of `array` that is null where the slot holds a value
+/// of a different child.
+///
+/// ```
+/// # use arrow::array::{Int32Array, UnionBuilder};
+/// # use arrow::compute::kernels::union::extract_child;
+/// # use arrow::datatypes::{Float64Type, Int32Type};
+/// let mut builder = UnionBuilder::new_dense();
+/// builder.append::<Int32Type>("a", 1).unwrap();
+/// builder.append::<Float64Type>("b", 3.2).unwrap();
+/// builder.append::<Int32Type>("a", 34).unwrap();
+/// let union = builder.build().unwrap();
+///
+/// let a = extract_child(&union, 0).unwrap();
+/// assert_eq!(a.as_ref(), &Int32Array::from(vec![Some(1), None, Some(34)]));
+/// ```
+pub fn extract_child(array: &UnionArray, type_id: i8) -> Result<ArrayRef> {
+    let child = &array.data().child_data()[child_index(array, type_id)?];
+    let indices: UInt32Array = (0..array.len())
+        .map(|i| {
+            let offset = array.value_offset(i) as u32;
+            (array.type_id(i) == type_id).then_some(offset)
+        })
+        .collect();
+    take(make_array(child.clone()).as_ref(), &indices, None)
+}
+
+/// Converts `array` to a dense union, keeping only the values of each child
+/// that are referenced by a slot of `array`
+pub fn densify(array: &UnionArray) -> Result<UnionArray> {
+    let (fields, field_type_ids) = union_type(array);
+    let mut child_lengths = vec![0_i32; fields.len()];
+    let mut child_indices = vec![vec![]; fields.len()];
+    let mut offsets = Vec::with_capacity(array.len());
+    for i in 0..array.len() {
+        let child = child_index(array, array.type_id(i))?;
+        offsets.push(child_lengths[child]);
+        child_lengths[child] += 1;
+        child_indices[child].push(array.value_offset(i) as u32);
+    }
+
+    let children = fields
+        .iter()
+        .zip(array.data().child_data())
+        .zip(child_indices)
+        .map(|((field, child), indices)| {
+            let indices = UInt32Array::from(indices);
+            let child = take(make_array(child.clone()).as_ref(), &indices, None)?;
+            Ok((field.clone(), child))
+        })
+        .collect::<Result<_>>()?;
+
+    UnionArray::try_new(
+        field_type_ids,
+        type_ids_buffer(array),
+        Some(Buffer::from_slice_ref(&offsets)),
+        children,
+    )
+}
+
+/// Converts `array` to a sparse union, where each child has one value per
+/// slot of `array` that is null where the slot holds a value of a different
+/// child
+pub fn sparsify(array: &UnionArray) -> Result<UnionArray> {
+    let (fields, field_type_ids) = union_type(array);
+    let children = fields
+        .iter()
+        .zip(field_type_ids)
+        .map(|(field, type_id)| Ok((field.clone(), extract_child(array, *type_id)?)))
+        .collect::<Result<_>>()?;
+
+    UnionArray::try_new(field_type_ids, type_ids_buffer(array), None, children)
+}
+
+/// Returns the type ids of `array` as a buffer starting at offset zero
+fn type_ids_buffer(array: &UnionArray) -> Buffer {
+    let offset = array.offset();
+    let type_ids = array.data().buffers()[0].as_slice();
+    Buffer::from(&type_ids[offset..offset + array.len()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::{Float64Type, Int32Type, UnionMode};
+
+    fn sparse() -> UnionArray {
+        let mut builder = UnionBuilder::new_sparse();
+        builder.append::<Int32Type>("a", 1).unwrap();
+        builder.append::<Float64Type>("b", 2.5).unwrap();
+        builder.append_null::<Int32Type>("a").unwrap();
+        builder.append::<Int32Type>("a", 4).unwrap();
+        builder.append::<Float64Type>("b", 5.5).unwrap();
+        builder.build().unwrap()
+    }
+
+    fn dense() -> UnionArray {
+        let mut builder = UnionBuilder::new_dense();
+        builder.append::<Int32Type>("a", 1).unwrap();
+        builder.append::<Float64Type>("b", 2.5).unwrap();
+        builder.append_null::<Int32Type>("a").unwrap();
+        builder.append::<Int32Type>("a", 4).unwrap();
+        builder.append::<Float64Type>("b", 5.5).unwrap();
+        builder.build().unwrap()
+    }
+
+    fn assert_children(array: &UnionArray) {
+        let a = extract_child(array, 0).unwrap();
+        let expected = Int32Array::from(vec![Some(1), None, None, Some(4), None]);
+        assert_eq!(a.as_ref(), &expected);
+
+        let b = extract_child(array, 1).unwrap();
+        let expected = Float64Array::from(vec![None, Some(2.5), None, None, Some(5.5)]);
+        assert_eq!(b.as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_type_ids() {
+        for array in [sparse(), dense()] {
+            assert_eq!(type_ids(&array), Int8Array::from(vec![0, 1, 0, 0, 1]));
+
+            let mask = type_id_mask(&array, 1).unwrap();
+            let expected = BooleanArray::from(vec![false, true, false, false, true]);
+            assert_eq!(mask, expected);
+
+            let err = type_id_mask(&array, 2).unwrap_err();
+            assert!(
+                err.to_string().contains("no child with type id 2"),
+                "{}",
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_child() {
+        assert_children(&sparse());
+        assert_children(&dense());
+
+        let sliced = dense().slice(3, 2);
+        let sliced = sliced.as_any().downcast_ref::<UnionArray>().unwrap();
+        let b = extract_child(sliced, 1).unwrap();
+        assert_eq!(b.as_ref(), &Float64Array::from(vec![None, Some(5.5)]));
+    }
+
+    #[test]
+    fn test_densify() {
+        let dense = densify(&sparse()).unwrap();
+        assert!(matches!(
+            dense.data_type(),
+            DataType::Union(_, _, UnionMode::Dense)
+        ));
+        assert_eq!(dense.child(0).len(), 3);
+        assert_eq!(dense.child(1).len(), 2);
+        assert_children(&dense);
+
+        let sliced = sparse().slice(1, 3);
+        let sliced = sliced.as_any().downcast_ref::<UnionArray>().unwrap();
+        let dense = densify(sliced).unwrap();
+        assert_eq!(dense.len(), 3);
+        assert_eq!(dense.child(0).len(), 2);
+        assert_eq!(type_ids(&dense), Int8Array::from(vec![1, 0, 0]));
+    }
+
+    #[test]
+    fn test_sparsify() {
+        let sparse = sparsify(&dense()).unwrap();
+        assert!(matches!(
+            sparse.data_type(),
+            DataType::Union(_, _, UnionMode::Sparse)
+        ));
+        assert_eq!(sparse.child(0).len(), 5);
+        assert_eq!(sparse.child(1).len(), 5);
+        assert_children(&sparse);
+
+        // round trip
+        assert_children(&densify(&sparse).unwrap());
+    }
+}
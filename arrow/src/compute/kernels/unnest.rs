@@ -0,0 +1,290 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the unnest kernel, which expands list arrays into their elements
+
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::compute::take;
+use crate::datatypes::{DataType, Field, Schema};
+use crate::error::{ArrowError, Result};
+use crate::record_batch::{RecordBatch, RecordBatchOptions};
+
+/// Options that define how [`unnest_list`] handles null and empty lists
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UnnestOptions {
+    /// If true, null and empty lists produce a single null element, so that
+    /// every input row appears at least once in the output. Otherwise they
+    /// produce no output. Defaults to false.
+    pub preserve_nulls: bool,
+}
+
+/// Expands a `List` or `LargeList` array into the concatenation of its lists.
+///
+/// Returns the flattened values, together with the index of the list each
+/// value came from. These indices can be passed to [`take`] to replicate the
+/// other columns of a batch, see [`unnest`].
+///
+/// ```
+/// # use arrow::array::{Array, Int32Array, ListArray, UInt32Array};
+/// # use arrow::compute::{unnest_list, UnnestOptions};
+/// # use arrow::datatypes::Int32Type;
+/// let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(1), Some(2)]),
+///     None,
+///     Some(vec![]),
+///     Some(vec![Some(3)]),
+/// ]);
+///
+/// let (values, indices) = unnest_list(&list, &UnnestOptions::default()).unwrap();
+/// assert_eq!(values.as_ref(), &Int32Array::from(vec![1, 2, 3]));
+/// assert_eq!(indices, UInt32Array::from(vec![0, 0, 3]));
+///
+/// let options = UnnestOptions { preserve_nulls: true };
+/// let (values, indices) = unnest_list(&list, &options).unwrap();
+/// assert_eq!(
+///     values.as_ref(),
+///     &Int32Array::from(vec![Some(1), Some(2), None, None, Some(3)])
+/// );
+/// assert_eq!(indices, UInt32Array::from(vec![0, 0, 1, 2, 3]));
+/// ```
+pub fn unnest_list(
+    array: &dyn Array,
+    options: &UnnestOptions,
+) -> Result<(ArrayRef, UInt32Array)> {
+    match array.data_type() {
+        DataType::List(_) => unnest_generic(as_list_array(array), options),
+        DataType::LargeList(_) => unnest_generic(as_large_list_array(array), options),
+        t => Err(ArrowError::InvalidArgumentError(format!(
+            "unnest requires a list array, got {}",
+            t
+        ))),
+    }
+}
+
+fn unnest_generic<O: OffsetSizeTrait>(
+    array: &GenericListArray<O>,
+    options: &UnnestOptions,
+) -> Result<(ArrayRef, UInt32Array)> {
+    if array.len() > u32::MAX as usize {
+        return Err(ArrowError::ComputeError(format!(
+            "unnest supports at most {} lists, got {}",
+            u32::MAX,
+            array.len()
+        )));
+    }
+
+    let offsets = array.value_offsets();
+    let lists = (0..array.len()).map(|i| {
+        let start = offsets[i].as_usize();
+        let end = offsets[i + 1].as_usize();
+        (array.is_valid(i), start, end)
+    });
+
+    let has_empty = || {
+        lists
+            .clone()
+            .any(|(valid, start, end)| !valid || start == end)
+    };
+    if array.null_count() == 0 && !(options.preserve_nulls && has_empty()) {
+        // every list is emitted as is, so the values are a contiguous slice
+        let start = offsets[0].as_usize();
+        let end = offsets[array.len()].as_usize();
+        let mut indices = Vec::with_capacity(end - start);
+        for (i, (_, start, end)) in lists.enumerate() {
+            indices.extend(std::iter::repeat(i as u32).take(end - start));
+        }
+        let values = array.values().slice(start, end - start);
+        return Ok((values, UInt32Array::from(indices)));
+    }
+
+    let mut indices = Vec::new();
+    let mut value_indices: Vec<Option<u64>> = Vec::new();
+    for (i, (valid, start, end)) in lists.enumerate() {
+        if valid && start < end {
+            indices.extend(std::iter::repeat(i as u32).take(end - start));
+            value_indices.extend((start..end).map(|v| Some(v as u64)));
+        } else if options.preserve_nulls {
+            indices.push(i as u32);
+            value_indices.push(None);
+        }
+    }
+
+    let value_indices = UInt64Array::from(value_indices);
+    let values = take(array.values().as_ref(), &value_indices, None)?;
+    Ok((values, UInt32Array::from(indices)))
+}
+
+/// Expands the list column `column` of `batch`, replicating the values of
+/// the other columns for each element of its lists.
+///
+/// The unnested column keeps its name, and takes the data type of the list
+/// elements. See [`unnest_list`] for how null and empty lists are handled.
+pub fn unnest(
+    batch: &RecordBatch,
+    column: &str,
+    options: &UnnestOptions,
+) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let index = schema.index_of(column)?;
+    let (values, indices) = unnest_list(batch.column(index).as_ref(), options)?;
+
+    let mut fields = Vec::with_capacity(batch.num_columns());
+    let mut columns = Vec::with_capacity(batch.num_columns());
+    for (i, (field, array)) in schema.fields().iter().zip(batch.columns()).enumerate() {
+        if i == index {
+            let nullable = match field.data_type() {
+                DataType::List(f) | DataType::LargeList(f) => f.is_nullable(),
+                _ => unreachable!("checked by unnest_list"),
+            };
+            let field = Field::new(
+                field.name(),
+                values.data_type().clone(),
+                nullable || options.preserve_nulls,
+            )
+            .with_metadata(field.metadata().cloned());
+            fields.push(field);
+            columns.push(values.clone());
+        } else {
+            fields.push(field.clone());
+            columns.push(take(array.as_ref(), &indices, None)?);
+        }
+    }
+
+    let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+    let options = RecordBatchOptions::new().with_row_count(Some(indices.len()));
+    RecordBatch::try_new_with_options(Arc::new(schema), columns, &options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::Int32Type;
+
+    fn list() -> ListArray {
+        ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(3), Some(4), Some(5)]),
+        ])
+    }
+
+    #[test]
+    fn test_unnest_list() {
+        let list = list();
+        let (values, indices) = unnest_list(&list, &UnnestOptions::default()).unwrap();
+        let expected = Int32Array::from(vec![Some(1), None, Some(3), Some(4), Some(5)]);
+        assert_eq!(values.as_ref(), &expected);
+        assert_eq!(indices, UInt32Array::from(vec![0, 0, 3, 3, 3]));
+
+        let options = UnnestOptions {
+            preserve_nulls: true,
+        };
+        let (values, indices) = unnest_list(&list, &options).unwrap();
+        let expected =
+            Int32Array::from(vec![Some(1), None, None, None, Some(3), Some(4), Some(5)]);
+        assert_eq!(values.as_ref(), &expected);
+        assert_eq!(indices, UInt32Array::from(vec![0, 0, 1, 2, 3, 3, 3]));
+    }
+
+    #[test]
+    fn test_unnest_sliced() {
+        let list = list();
+        let sliced = list.slice(3, 1);
+        let (values, indices) =
+            unnest_list(sliced.as_ref(), &UnnestOptions::default()).unwrap();
+        assert_eq!(values.as_ref(), &Int32Array::from(vec![3, 4, 5]));
+        assert_eq!(indices, UInt32Array::from(vec![0, 0, 0]));
+
+        let sliced = list.slice(1, 2);
+        let (values, indices) =
+            unnest_list(sliced.as_ref(), &UnnestOptions::default()).unwrap();
+        assert!(values.is_empty());
+        assert!(indices.is_empty());
+    }
+
+    #[test]
+    fn test_unnest_large_list() {
+        let list = LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            Some(vec![]),
+            Some(vec![Some(2), Some(3)]),
+        ]);
+        let (values, indices) = unnest_list(&list, &UnnestOptions::default()).unwrap();
+        assert_eq!(values.as_ref(), &Int32Array::from(vec![1, 2, 3]));
+        assert_eq!(indices, UInt32Array::from(vec![0, 2, 2]));
+
+        let options = UnnestOptions {
+            preserve_nulls: true,
+        };
+        let (values, indices) = unnest_list(&list, &options).unwrap();
+        let expected = Int32Array::from(vec![Some(1), None, Some(2), Some(3)]);
+        assert_eq!(values.as_ref(), &expected);
+        assert_eq!(indices, UInt32Array::from(vec![0, 1, 2, 2]));
+    }
+
+    #[test]
+    fn test_unnest_not_list() {
+        let array = Int32Array::from(vec![1]);
+        let err = unnest_list(&array, &UnnestOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("requires a list"), "{}", err);
+    }
+
+    #[test]
+    fn test_unnest_batch() {
+        let ids: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c", "d"]));
+        let batch = RecordBatch::try_from_iter(vec![
+            ("id", ids),
+            ("list", Arc::new(list()) as _),
+        ])
+        .unwrap();
+
+        let unnested = unnest(&batch, "list", &UnnestOptions::default()).unwrap();
+        assert_eq!(unnested.num_rows(), 5);
+        assert_eq!(unnested.schema().field(1).data_type(), &DataType::Int32);
+        assert_eq!(
+            unnested.column(0).as_ref(),
+            &StringArray::from(vec!["a", "a", "d", "d", "d"])
+        );
+
+        let options = UnnestOptions {
+            preserve_nulls: true,
+        };
+        let unnested = unnest(&batch, "list", &options).unwrap();
+        assert_eq!(
+            unnested.column(0).as_ref(),
+            &StringArray::from(vec!["a", "a", "b", "c", "d", "d", "d"])
+        );
+
+        let err = unnest(&batch, "id", &options).unwrap_err();
+        assert!(err.to_string().contains("requires a list"), "{}", err);
+    }
+
+    #[test]
+    fn test_unnest_only_column() {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            None::<Vec<Option<i32>>>,
+        ]);
+        let batch =
+            RecordBatch::try_from_iter(vec![("list", Arc::new(list) as ArrayRef)])
+                .unwrap();
+        let unnested = unnest(&batch, "list", &UnnestOptions::default()).unwrap();
+        assert_eq!(unnested.num_rows(), 0);
+    }
+}
@@ -15,10 +15,15 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines windowing functions, like `shift`ing
+//! Defines windowing functions, like `shift`ing and cumulative aggregates
 
-use crate::array::{Array, ArrayRef};
-use crate::error::Result;
+use std::sync::Arc;
+
+use crate::array::{Array, ArrayRef, PrimitiveArray};
+use crate::compute::kernels::decimal::cumulative_decimal;
+use crate::datatypes::{ArrowNativeTypeOp, ArrowNumericType, DataType};
+use crate::downcast_primitive_array;
+use crate::error::{ArrowError, Result};
 use crate::{
     array::{make_array, new_null_array},
     compute::concat,
@@ -83,10 +88,228 @@ pub fn shift(array: &dyn Array, offset: i64) -> Result<ArrayRef> {
     }
 }
 
+/// Options that define how cumulative kernels, such as [`cumsum`], handle nulls
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CumulativeOptions {
+    /// If true, null values are skipped, resulting in null at their position without
+    /// affecting the following values. If false, a null value results in null for every
+    /// following value
+    pub skip_nulls: bool,
+}
+
+impl Default for CumulativeOptions {
+    fn default() -> Self {
+        Self { skip_nulls: true }
+    }
+}
+
+/// Returns the running values of applying `op` to the accumulated value and each
+/// value of `values`, with nulls handled according to `options`.
+///
+/// Errors returned by `op` are annotated with the row of the value, if a
+/// [`ArrowError::ComputeError`], such as an overflow.
+pub(crate) fn cumulative<T, I, F>(
+    values: I,
+    options: CumulativeOptions,
+    op: F,
+) -> impl Iterator<Item = Result<Option<T>>>
+where
+    T: Copy,
+    I: Iterator<Item = Option<T>>,
+    F: Fn(T, T) -> Result<T>,
+{
+    let mut accumulator: Option<T> = None;
+    let mut null_seen = false;
+    values.enumerate().map(move |(idx, value)| match value {
+        Some(value) if !null_seen => {
+            let value = match accumulator {
+                Some(accumulator) => op(accumulator, value).map_err(|e| match e {
+                    ArrowError::ComputeError(msg) => {
+                        ArrowError::ComputeError(format!("{} at row {}", msg, idx))
+                    }
+                    e => e,
+                })?,
+                None => value,
+            };
+            accumulator = Some(value);
+            Ok(Some(value))
+        }
+        _ => {
+            null_seen |= !options.skip_nulls;
+            Ok(None)
+        }
+    })
+}
+
+/// Returns the cumulative sum of the values in the primitive array, with nulls handled
+/// according to `options`.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around.
+/// For an overflow-checking variant, use `cumsum_checked` instead.
+///
+/// # Example
+/// ```
+/// use arrow::array::Int32Array;
+/// use arrow::compute::{cumsum, CumulativeOptions};
+///
+/// let a = Int32Array::from(vec![Some(1), None, Some(4)]);
+/// let result = cumsum(&a, CumulativeOptions::default()).unwrap();
+/// assert_eq!(result, Int32Array::from(vec![Some(1), None, Some(5)]));
+///
+/// let options = CumulativeOptions { skip_nulls: false };
+/// let result = cumsum(&a, options).unwrap();
+/// assert_eq!(result, Int32Array::from(vec![Some(1), None, None]));
+/// ```
+pub fn cumsum<T>(
+    array: &PrimitiveArray<T>,
+    options: CumulativeOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    cumulative(array.iter(), options, |a, b| Ok(a.add_wrapping(b))).collect()
+}
+
+/// Returns the cumulative sum of the values in the primitive array, with nulls handled
+/// according to `options`.
+///
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `cumsum` instead.
+pub fn cumsum_checked<T>(
+    array: &PrimitiveArray<T>,
+    options: CumulativeOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    cumulative(array.iter(), options, |a, b| a.add_checked(b)).collect()
+}
+
+/// Returns the cumulative minimum of the values in the primitive array, with nulls
+/// handled according to `options`.
+///
+/// For floating point arrays NaN values are considered to be greater than any other
+/// value, as for [`min`](crate::compute::min).
+pub fn cummin<T>(
+    array: &PrimitiveArray<T>,
+    options: CumulativeOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    cumulative(array.iter(), options, |a, b| {
+        Ok(if b.is_lt(a) { b } else { a })
+    })
+    .collect()
+}
+
+/// Returns the cumulative maximum of the values in the primitive array, with nulls
+/// handled according to `options`.
+///
+/// For floating point arrays NaN values are considered to be greater than any other
+/// value, as for [`max`](crate::compute::max).
+pub fn cummax<T>(
+    array: &PrimitiveArray<T>,
+    options: CumulativeOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    cumulative(array.iter(), options, |a, b| {
+        Ok(if b.is_gt(a) { b } else { a })
+    })
+    .collect()
+}
+
+/// Returns the cumulative sum of the values in the primitive or decimal array, with
+/// nulls handled according to `options`.
+///
+/// This doesn't detect overflow for primitive arrays, for which the result will wrap
+/// around. For an overflow-checking variant, use `cumsum_dyn_checked` instead. For
+/// decimal arrays an `Err` is returned if a sum exceeds the precision of the array.
+pub fn cumsum_dyn(array: &dyn Array, options: CumulativeOptions) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+            cumulative_decimal(array, options, |a, b| a.checked_add(b))
+        }
+        _ => downcast_primitive_array!(
+            array => cumsum(array, options).map(|a| Arc::new(a) as ArrayRef),
+            t => Err(ArrowError::CastError(format!(
+                "Unsupported data type {} for cumulative sum",
+                t
+            )))
+        ),
+    }
+}
+
+/// Returns the cumulative sum of the values in the primitive or decimal array, with
+/// nulls handled according to `options`.
+///
+/// This detects overflow and returns an `Err` identifying the overflowing row. For an
+/// non-overflow-checking variant, use `cumsum_dyn` instead.
+pub fn cumsum_dyn_checked(
+    array: &dyn Array,
+    options: CumulativeOptions,
+) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+            cumulative_decimal(array, options, |a, b| a.checked_add(b))
+        }
+        _ => downcast_primitive_array!(
+            array => cumsum_checked(array, options).map(|a| Arc::new(a) as ArrayRef),
+            t => Err(ArrowError::CastError(format!(
+                "Unsupported data type {} for cumulative sum",
+                t
+            )))
+        ),
+    }
+}
+
+/// Returns the cumulative minimum of the values in the primitive or decimal array,
+/// with nulls handled according to `options`.
+pub fn cummin_dyn(array: &dyn Array, options: CumulativeOptions) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+            cumulative_decimal(array, options, |a, b| Some(a.min(b)))
+        }
+        _ => downcast_primitive_array!(
+            array => cummin(array, options).map(|a| Arc::new(a) as ArrayRef),
+            t => Err(ArrowError::CastError(format!(
+                "Unsupported data type {} for cumulative minimum",
+                t
+            )))
+        ),
+    }
+}
+
+/// Returns the cumulative maximum of the values in the primitive or decimal array,
+/// with nulls handled according to `options`.
+pub fn cummax_dyn(array: &dyn Array, options: CumulativeOptions) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+            cumulative_decimal(array, options, |a, b| Some(a.max(b)))
+        }
+        _ => downcast_primitive_array!(
+            array => cummax(array, options).map(|a| Arc::new(a) as ArrayRef),
+            t => Err(ArrowError::CastError(format!(
+                "Unsupported data type {} for cumulative maximum",
+                t
+            )))
+        ),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::array::{Float64Array, Int32Array, Int32DictionaryArray};
+    use crate::array::{
+        Decimal128Array, Decimal256Array, Float64Array, Int32Array, Int32DictionaryArray,
+        Int8Array, StringArray,
+    };
 
     #[test]
     fn test_shift_neg() {
@@ -195,4 +418,99 @@ mod tests {
         let expected: Int32Array = vec![None, None, None].into();
         assert_eq!(res.as_ref(), &expected);
     }
+
+    #[test]
+    fn test_cumulative() {
+        let a = Int32Array::from(vec![Some(3), None, Some(1), Some(4), None, Some(2)]);
+        let skip = CumulativeOptions::default();
+        let propagate = CumulativeOptions { skip_nulls: false };
+
+        let expected = vec![Some(3), None, Some(4), Some(8), None, Some(10)];
+        assert_eq!(cumsum(&a, skip).unwrap(), Int32Array::from(expected));
+        let expected = vec![Some(3), None, None, None, None, None];
+        assert_eq!(cumsum(&a, propagate).unwrap(), Int32Array::from(expected));
+
+        let expected = vec![Some(3), None, Some(1), Some(1), None, Some(1)];
+        assert_eq!(cummin(&a, skip).unwrap(), Int32Array::from(expected));
+        let expected = vec![Some(3), None, Some(3), Some(4), None, Some(4)];
+        assert_eq!(cummax(&a, skip).unwrap(), Int32Array::from(expected));
+
+        let sliced = a.slice(2, 3);
+        let result = cummax_dyn(sliced.as_ref(), propagate).unwrap();
+        let expected = Int32Array::from(vec![Some(1), Some(4), None]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let a = Float64Array::from(vec![2.0, f64::NAN, 1.0]);
+        let expected = Float64Array::from(vec![2.0, 2.0, 1.0]);
+        assert_eq!(cummin(&a, skip).unwrap(), expected);
+        let result = cummax(&a, skip).unwrap();
+        assert!(result.value(1).is_nan() && result.value(2).is_nan());
+
+        let a = Int8Array::from(vec![Some(100), None, Some(27), Some(1)]);
+        let expected = Int8Array::from(vec![Some(100), None, Some(127), Some(-128)]);
+        assert_eq!(cumsum(&a, skip).unwrap(), expected);
+        let err = cumsum_checked(&a, skip).unwrap_err();
+        assert!(err.to_string().contains("at row 3"), "{}", err);
+        let err = cumsum_dyn_checked(&a, skip).unwrap_err();
+        assert!(err.to_string().contains("at row 3"), "{}", err);
+
+        let a = StringArray::from(vec!["a"]);
+        let err = cumsum_dyn(&a, skip).unwrap_err();
+        assert!(
+            err.to_string().contains("Unsupported data type Utf8"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_cumulative_decimal() {
+        let skip = CumulativeOptions::default();
+        let a = vec![Some(550), None, Some(-100), Some(425)]
+            .into_iter()
+            .collect::<Decimal128Array>()
+            .with_precision_and_scale(4, 2)
+            .unwrap();
+
+        let result = cumsum_dyn(&a, skip).unwrap();
+        assert_eq!(result.data_type(), a.data_type());
+        let result = result.as_any().downcast_ref::<Decimal128Array>().unwrap();
+        let strings: Vec<_> = (0..result.len())
+            .map(|i| result.is_valid(i).then(|| result.value_as_string(i)))
+            .collect();
+        let expected = vec![Some("5.50"), None, Some("4.50"), Some("8.75")];
+        assert_eq!(
+            strings,
+            expected
+                .into_iter()
+                .map(|s| s.map(String::from))
+                .collect::<Vec<_>>()
+        );
+
+        let result = cummin_dyn(&a, skip).unwrap();
+        let result = result.as_any().downcast_ref::<Decimal128Array>().unwrap();
+        assert_eq!(result.value_as_string(3), "-1.00");
+
+        let a = vec![Some(550), Some(500)]
+            .into_iter()
+            .collect::<Decimal128Array>()
+            .with_precision_and_scale(3, 2)
+            .unwrap();
+        let err = cumsum_dyn(&a, skip).unwrap_err();
+        assert!(err.to_string().contains("at row 1"), "{}", err);
+
+        let a = vec![Some(5), Some(-7)]
+            .into_iter()
+            .collect::<Decimal128Array>()
+            .with_precision_and_scale(3, 0)
+            .unwrap();
+        let a = crate::compute::cast(
+            &(Arc::new(a) as ArrayRef),
+            &DataType::Decimal256(40, 0),
+        )
+        .unwrap();
+        let result = cummax_dyn(a.as_ref(), skip).unwrap();
+        let result = result.as_any().downcast_ref::<Decimal256Array>().unwrap();
+        assert_eq!(result.value_as_string(1), "5");
+    }
 }
@@ -16,7 +16,8 @@
 // under the License.
 
 use crate::array::*;
-use crate::compute::SlicesIterator;
+use crate::compute::kernels::datum::Datum;
+use crate::compute::{prep_null_mask_filter, SlicesIterator};
 use crate::error::{ArrowError, Result};
 
 /// Zip two arrays by some boolean mask. Where the mask evaluates `true` values of `truthy`
@@ -70,9 +71,144 @@ pub fn zip(
     Ok(make_array(data))
 }
 
+/// Returns the values of `truthy` where `mask` is true, and of `falsy` where it
+/// is false or null, like `CASE WHEN mask THEN truthy ELSE falsy END`.
+///
+/// Unlike [`zip`], either of `truthy` and `falsy` may be a [`Datum::Scalar`],
+/// which is repeated without first being expanded to an array.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{BooleanArray, Int32Array};
+/// # use arrow::compute::kernels::datum::Datum;
+/// # use arrow::compute::kernels::zip::if_else;
+/// let mask = BooleanArray::from(vec![Some(true), Some(false), None]);
+/// let truthy = Datum::Array(Arc::new(Int32Array::from(vec![1, 2, 3])));
+/// let falsy = Datum::try_new_scalar(Arc::new(Int32Array::from(vec![0]))).unwrap();
+///
+/// let result = if_else(&mask, &truthy, &falsy).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![1, 0, 0]));
+/// ```
+pub fn if_else(mask: &BooleanArray, truthy: &Datum, falsy: &Datum) -> Result<ArrayRef> {
+    if truthy.data_type() != falsy.data_type() {
+        return Err(ArrowError::InvalidArgumentError(
+            "arguments need to have the same data type".into(),
+        ));
+    }
+    let (truthy, truthy_scalar) = datum_data(truthy);
+    let (falsy, falsy_scalar) = datum_data(falsy);
+    if (!truthy_scalar && truthy.len() != mask.len())
+        || (!falsy_scalar && falsy.len() != mask.len())
+    {
+        return Err(ArrowError::InvalidArgumentError(
+            "all arrays should have the same length".into(),
+        ));
+    }
+    let mask = match mask.null_count() {
+        0 => BooleanArray::from(mask.data().clone()),
+        _ => prep_null_mask_filter(mask),
+    };
+
+    let mut mutable = MutableArrayData::new(vec![truthy, falsy], false, mask.len());
+    let scalar = [truthy_scalar, falsy_scalar];
+    let mut extend = |index: usize, start: usize, end: usize| match scalar[index] {
+        true => (start..end).for_each(|_| mutable.extend(index, 0, 1)),
+        false => mutable.extend(index, start, end),
+    };
+
+    let mut filled = 0;
+    SlicesIterator::new(&mask).for_each(|(start, end)| {
+        if start > filled {
+            extend(1, filled, start);
+        }
+        extend(0, start, end);
+        filled = end;
+    });
+    if filled < mask.len() {
+        extend(1, filled, mask.len());
+    }
+
+    Ok(make_array(mutable.freeze()))
+}
+
+/// Returns the first non-null value of `arrays` for each row, like SQL's
+/// `COALESCE(arrays[0], arrays[1], ...)`, or null if all are null.
+///
+/// Arrays after the one filling the last null row are not inspected.
+///
+/// ```
+/// # use arrow::array::Int32Array;
+/// # use arrow::compute::kernels::zip::coalesce;
+/// let a = Int32Array::from(vec![Some(1), None, None, None]);
+/// let b = Int32Array::from(vec![Some(10), Some(20), None, None]);
+/// let c = Int32Array::from(vec![Some(100), Some(200), Some(300), None]);
+///
+/// let result = coalesce(&[&a, &b, &c]).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![Some(1), Some(20), Some(300), None]));
+/// ```
+pub fn coalesce(arrays: &[&dyn Array]) -> Result<ArrayRef> {
+    let first = match arrays.first() {
+        Some(first) => *first,
+        None => {
+            return Err(ArrowError::InvalidArgumentError(
+                "coalesce requires at least one array".into(),
+            ))
+        }
+    };
+    if arrays.iter().any(|a| a.data_type() != first.data_type()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "arguments need to have the same data type".into(),
+        ));
+    }
+    if arrays.iter().any(|a| a.len() != first.len()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "all arrays should have the same length".into(),
+        ));
+    }
+    if first.null_count() == 0 {
+        return Ok(make_array(first.data().clone()));
+    }
+
+    // The array to take each row from, rows null in all arrays are taken
+    // from the last array inspected
+    let mut source = vec![0; first.len()];
+    let mut unfilled: Vec<usize> = (0..first.len()).collect();
+    let mut last = 0;
+    for (i, array) in arrays.iter().enumerate() {
+        last = i;
+        unfilled.retain(|row| {
+            source[*row] = i;
+            array.is_null(*row)
+        });
+        if unfilled.is_empty() {
+            break;
+        }
+    }
+
+    let sources = arrays[..=last].iter().map(|a| a.data()).collect();
+    let mut mutable = MutableArrayData::new(sources, false, first.len());
+    let mut start = 0;
+    for end in 1..=source.len() {
+        if end == source.len() || source[end] != source[start] {
+            mutable.extend(source[start], start, end);
+            start = end;
+        }
+    }
+    Ok(make_array(mutable.freeze()))
+}
+
+/// Returns the data of `datum`, and whether it is a scalar
+fn datum_data(datum: &Datum) -> (&ArrayData, bool) {
+    match datum {
+        Datum::Array(array) => (array.data(), false),
+        Datum::Scalar(scalar) => (scalar.data(), true),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::sync::Arc;
 
     #[test]
     fn test_zip_kernel() {
@@ -84,4 +220,67 @@ mod test {
         let expected = Int32Array::from(vec![Some(5), None, Some(6), Some(7), Some(1)]);
         assert_eq!(actual, &expected);
     }
+
+    #[test]
+    fn test_if_else() {
+        let mask = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        let a = Datum::Array(Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            None,
+            Some("d"),
+        ])));
+        let b = Datum::try_new_scalar(Arc::new(StringArray::from(vec!["x"]))).unwrap();
+
+        let result = if_else(&mask, &a, &b).unwrap();
+        let expected = StringArray::from(vec!["a", "x", "x", "d"]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let result = if_else(&mask, &b, &a).unwrap();
+        let expected = StringArray::from(vec![Some("x"), Some("b"), None, Some("x")]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let null = Datum::try_new_scalar(Arc::new(StringArray::from(vec![None::<&str>])));
+        let result = if_else(&mask, &b, &null.unwrap()).unwrap();
+        let expected = StringArray::from(vec![Some("x"), None, None, Some("x")]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let short = Datum::Array(Arc::new(StringArray::from(vec!["a"])));
+        assert!(if_else(&mask, &short, &b).is_err());
+        let ints = Datum::Array(Arc::new(Int32Array::from(vec![1, 2, 3, 4])));
+        assert!(if_else(&mask, &ints, &b).is_err());
+    }
+
+    #[test]
+    fn test_coalesce() {
+        let a = StringArray::from(vec![Some("a"), None, None, None, Some("e")]);
+        let b = StringArray::from(vec![None, Some("b"), None, None, Some("x")]);
+        let c = StringArray::from(vec![Some("x"), Some("x"), Some("c"), None, None]);
+
+        let result = coalesce(&[&a, &b, &c]).unwrap();
+        let expected =
+            StringArray::from(vec![Some("a"), Some("b"), Some("c"), None, Some("e")]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let result = coalesce(&[&a, &b]).unwrap();
+        let expected =
+            StringArray::from(vec![Some("a"), Some("b"), None, None, Some("e")]);
+        assert_eq!(result.as_ref(), &expected);
+
+        let result = coalesce(&[&a]).unwrap();
+        assert_eq!(result.as_ref(), &a);
+
+        // arrays after all rows are filled are not needed
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let b = Int32Array::from(vec![4, 5, 6]);
+        let c = Int32Array::from(vec![None, None, None]);
+        let result = coalesce(&[&a, &b, &c]).unwrap();
+        assert_eq!(result.as_ref(), &Int32Array::from(vec![1, 5, 3]));
+        let result = coalesce(&[&b, &a]).unwrap();
+        assert_eq!(result.as_ref(), &b);
+
+        assert!(coalesce(&[]).is_err());
+        assert!(coalesce(&[&a, &Int32Array::from(vec![1])]).is_err());
+        assert!(coalesce(&[&a, &StringArray::from(vec!["a", "b", "c"])]).is_err());
+    }
 }
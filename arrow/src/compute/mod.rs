@@ -17,6 +17,7 @@
 
 //! Computation kernels on Arrow Arrays
 
+pub mod eval;
 pub mod kernels;
 
 mod util;
@@ -28,11 +29,19 @@ pub use self::kernels::boolean::*;
 pub use self::kernels::cast::*;
 pub use self::kernels::comparison::*;
 pub use self::kernels::concat::*;
+pub use self::kernels::datum::*;
 pub use self::kernels::filter::*;
+pub use self::kernels::hash::*;
+pub use self::kernels::interleave::*;
 pub use self::kernels::limit::*;
+pub use self::kernels::list::*;
+pub use self::kernels::map::*;
 pub use self::kernels::partition::*;
 pub use self::kernels::regexp::*;
+pub use self::kernels::selection::*;
 pub use self::kernels::sort::*;
+pub use self::kernels::struct_field::*;
 pub use self::kernels::take::*;
 pub use self::kernels::temporal::*;
+pub use self::kernels::unnest::*;
 pub use self::kernels::window::*;
@@ -74,7 +74,7 @@ use crate::csv::map_csv_error;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
-use crate::util::display::make_string_from_decimal;
+use crate::util::display::{ArrayFormatter, BinaryFormat, FormatOptions};
 use crate::{array::*, util::serialization::lexical_to_string};
 
 const DEFAULT_DATE_FORMAT: &str = "%F";
@@ -82,6 +82,19 @@ const DEFAULT_TIME_FORMAT: &str = "%T";
 const DEFAULT_TIMESTAMP_FORMAT: &str = "%FT%H:%M:%S.%9f";
 const DEFAULT_TIMESTAMP_TZ_FORMAT: &str = "%FT%H:%M:%S.%9f%:z";
 
+/// Returns the options used to format values without a CSV specific format
+fn format_options(
+    date_format: String,
+    time_format: String,
+    binary_format: BinaryFormat,
+) -> FormatOptions {
+    FormatOptions::new()
+        .with_null("")
+        .with_date_format(Some(date_format))
+        .with_time_format(Some(time_format))
+        .with_binary_format(binary_format)
+}
+
 fn write_primitive_value<T>(array: &ArrayRef, i: usize) -> String
 where
     T: ArrowNumericType,
@@ -98,8 +111,9 @@ pub struct Writer<W: Write> {
     writer: csv_crate::Writer<W>,
     /// Whether file should be written with headers. Defaults to `true`
     has_headers: bool,
-    /// The date format for date arrays
-    date_format: String,
+    /// The options used to format values of types without a format of their own,
+    /// including the date, time and binary formats
+    format_options: FormatOptions,
     /// The datetime format for datetime arrays
     datetime_format: String,
     /// The timestamp format for timestamp arrays
@@ -107,8 +121,6 @@ pub struct Writer<W: Write> {
     /// The timestamp format for timestamp (with timezone) arrays
     #[allow(dead_code)]
     timestamp_tz_format: String,
-    /// The time format for time arrays
-    time_format: String,
     /// Is the beginning-of-writer
     beginning: bool,
 }
@@ -122,9 +134,12 @@ impl<W: Write> Writer<W> {
         Writer {
             writer,
             has_headers: true,
-            date_format: DEFAULT_DATE_FORMAT.to_string(),
+            format_options: format_options(
+                DEFAULT_DATE_FORMAT.to_string(),
+                DEFAULT_TIME_FORMAT.to_string(),
+                BinaryFormat::default(),
+            ),
             datetime_format: DEFAULT_TIMESTAMP_FORMAT.to_string(),
-            time_format: DEFAULT_TIME_FORMAT.to_string(),
             timestamp_format: DEFAULT_TIMESTAMP_FORMAT.to_string(),
             timestamp_tz_format: DEFAULT_TIMESTAMP_TZ_FORMAT.to_string(),
             beginning: true,
@@ -135,18 +150,19 @@ impl<W: Write> Writer<W> {
     fn convert(
         &self,
         batch: &[ArrayRef],
+        formatters: &[ArrayFormatter],
         row_index: usize,
         buffer: &mut [String],
     ) -> Result<()> {
         // TODO: it'd be more efficient if we could create `record: Vec<&[u8]>
-        for (col_index, item) in buffer.iter_mut().enumerate() {
-            let col = &batch[col_index];
+        let cells = batch.iter().zip(formatters).zip(buffer.iter_mut());
+        for ((col, formatter), item) in cells {
             if col.is_null(row_index) {
                 // write an empty value
-                *item = "".to_string();
+                item.clear();
                 continue;
             }
-            let string = match col.data_type() {
+            *item = match col.data_type() {
                 DataType::Float64 => write_primitive_value::<Float64Type>(col, row_index),
                 DataType::Float32 => write_primitive_value::<Float32Type>(col, row_index),
                 DataType::Int8 => write_primitive_value::<Int8Type>(col, row_index),
@@ -157,25 +173,6 @@ impl<W: Write> Writer<W> {
                 DataType::UInt16 => write_primitive_value::<UInt16Type>(col, row_index),
                 DataType::UInt32 => write_primitive_value::<UInt32Type>(col, row_index),
                 DataType::UInt64 => write_primitive_value::<UInt64Type>(col, row_index),
-                DataType::Boolean => {
-                    let c = col.as_any().downcast_ref::<BooleanArray>().unwrap();
-                    c.value(row_index).to_string()
-                }
-                DataType::Utf8 => {
-                    let c = col.as_any().downcast_ref::<StringArray>().unwrap();
-                    c.value(row_index).to_owned()
-                }
-                DataType::LargeUtf8 => {
-                    let c = col.as_any().downcast_ref::<LargeStringArray>().unwrap();
-                    c.value(row_index).to_owned()
-                }
-                DataType::Date32 => {
-                    let c = col.as_any().downcast_ref::<Date32Array>().unwrap();
-                    c.value_as_date(row_index)
-                        .unwrap()
-                        .format(&self.date_format)
-                        .to_string()
-                }
                 DataType::Date64 => {
                     let c = col.as_any().downcast_ref::<Date64Array>().unwrap();
                     c.value_as_datetime(row_index)
@@ -183,57 +180,16 @@ impl<W: Write> Writer<W> {
                         .format(&self.datetime_format)
                         .to_string()
                 }
-                DataType::Time32(TimeUnit::Second) => {
-                    let c = col.as_any().downcast_ref::<Time32SecondArray>().unwrap();
-                    c.value_as_time(row_index)
-                        .unwrap()
-                        .format(&self.time_format)
-                        .to_string()
-                }
-                DataType::Time32(TimeUnit::Millisecond) => {
-                    let c = col
-                        .as_any()
-                        .downcast_ref::<Time32MillisecondArray>()
-                        .unwrap();
-                    c.value_as_time(row_index)
-                        .unwrap()
-                        .format(&self.time_format)
-                        .to_string()
-                }
-                DataType::Time64(TimeUnit::Microsecond) => {
-                    let c = col
-                        .as_any()
-                        .downcast_ref::<Time64MicrosecondArray>()
-                        .unwrap();
-                    c.value_as_time(row_index)
-                        .unwrap()
-                        .format(&self.time_format)
-                        .to_string()
-                }
-                DataType::Time64(TimeUnit::Nanosecond) => {
-                    let c = col
-                        .as_any()
-                        .downcast_ref::<Time64NanosecondArray>()
-                        .unwrap();
-                    c.value_as_time(row_index)
-                        .unwrap()
-                        .format(&self.time_format)
-                        .to_string()
-                }
                 DataType::Timestamp(time_unit, time_zone) => {
                     self.handle_timestamp(time_unit, time_zone.as_ref(), row_index, col)?
                 }
-                DataType::Decimal128(..) => make_string_from_decimal(col, row_index)?,
-                t => {
-                    // List and Struct arrays not supported by the writer, any
-                    // other type needs to be implemented
-                    return Err(ArrowError::CsvError(format!(
-                        "CSV Writer does not support {:?} data type",
-                        t
-                    )));
+                _ => {
+                    // reuse the allocation of the previous value of this column
+                    item.clear();
+                    formatter.value(row_index).write(item)?;
+                    continue;
                 }
             };
-            *item = string;
         }
         Ok(())
     }
@@ -364,10 +320,25 @@ impl<W: Write> Writer<W> {
             })
             .collect();
 
+        let formatters = columns
+            .iter()
+            .map(|array| match array.data_type() {
+                // List and Struct arrays not supported by the writer
+                DataType::List(_)
+                | DataType::LargeList(_)
+                | DataType::FixedSizeList(_, _)
+                | DataType::Struct(_)
+                | DataType::Map(_, _)
+                | DataType::Union(_, _, _) => Err(unsupported(array.data_type())),
+                t => ArrayFormatter::try_new(array.as_ref(), &self.format_options)
+                    .map_err(|_| unsupported(t)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
         let mut buffer = vec!["".to_string(); batch.num_columns()];
 
         for row_index in 0..batch.num_rows() {
-            self.convert(columns.as_slice(), row_index, &mut buffer)?;
+            self.convert(&columns, &formatters, row_index, &mut buffer)?;
             self.writer.write_record(&buffer).map_err(map_csv_error)?;
         }
         self.writer.flush()?;
@@ -376,6 +347,14 @@ impl<W: Write> Writer<W> {
     }
 }
 
+/// Returns the error for columns of `data_type`, which can't be written to CSV
+fn unsupported(data_type: &DataType) -> ArrowError {
+    ArrowError::CsvError(format!(
+        "CSV Writer does not support {:?} data type",
+        data_type
+    ))
+}
+
 /// A CSV writer builder
 #[derive(Debug)]
 pub struct WriterBuilder {
@@ -393,6 +372,8 @@ pub struct WriterBuilder {
     timestamp_tz_format: Option<String>,
     /// Optional time format for time arrays
     time_format: Option<String>,
+    /// Format for binary arrays. Defaults to [`BinaryFormat::Hex`]
+    binary_format: BinaryFormat,
 }
 
 impl Default for WriterBuilder {
@@ -405,6 +386,7 @@ impl Default for WriterBuilder {
             time_format: Some(DEFAULT_TIME_FORMAT.to_string()),
             timestamp_format: Some(DEFAULT_TIMESTAMP_FORMAT.to_string()),
             timestamp_tz_format: Some(DEFAULT_TIMESTAMP_TZ_FORMAT.to_string()),
+            binary_format: BinaryFormat::default(),
         }
     }
 }
@@ -472,6 +454,12 @@ impl WriterBuilder {
         self
     }
 
+    /// Set the format used to write binary arrays
+    pub fn with_binary_format(mut self, format: BinaryFormat) -> Self {
+        self.binary_format = format;
+        self
+    }
+
     /// Create a new `Writer`
     pub fn build<W: Write>(self, writer: W) -> Writer<W> {
         let delimiter = self.delimiter.unwrap_or(b',');
@@ -480,15 +468,16 @@ impl WriterBuilder {
         Writer {
             writer,
             has_headers: self.has_headers,
-            date_format: self
-                .date_format
-                .unwrap_or_else(|| DEFAULT_DATE_FORMAT.to_string()),
+            format_options: format_options(
+                self.date_format
+                    .unwrap_or_else(|| DEFAULT_DATE_FORMAT.to_string()),
+                self.time_format
+                    .unwrap_or_else(|| DEFAULT_TIME_FORMAT.to_string()),
+                self.binary_format,
+            ),
             datetime_format: self
                 .datetime_format
                 .unwrap_or_else(|| DEFAULT_TIMESTAMP_FORMAT.to_string()),
-            time_format: self
-                .time_format
-                .unwrap_or_else(|| DEFAULT_TIME_FORMAT.to_string()),
             timestamp_format: self
                 .timestamp_format
                 .unwrap_or_else(|| DEFAULT_TIMESTAMP_FORMAT.to_string()),
@@ -657,6 +646,96 @@ sed do eiusmod tempor,-556132.25,1,,2019-04-18T02:45:55.555000000,23:46:03,foo
         );
     }
 
+    #[test]
+    fn test_write_csv_durations_and_intervals() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Duration(TimeUnit::Millisecond), true),
+            Field::new("c2", DataType::Interval(IntervalUnit::YearMonth), true),
+            Field::new("c3", DataType::Interval(IntervalUnit::MonthDayNano), true),
+        ]);
+
+        let c1 = DurationMillisecondArray::from(vec![Some(183_845_123), None]);
+        let c2 = IntervalYearMonthArray::from(vec![Some(14), Some(-1)]);
+        let c3 = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNanoType::make_value(1, 2, 3_000_000_000)),
+            None,
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(c1), Arc::new(c2), Arc::new(c3)],
+        )
+        .unwrap();
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = Writer::new(&mut buf);
+            writer.write(&batch).unwrap();
+        }
+
+        let expected = "c1,c2,c3\n2 days 03:04:05.123,1 year 2 mons,1 mon 2 days 00:00:03\n,-1 mons,\n";
+        assert_eq!(expected, String::from_utf8(buf).unwrap());
+    }
+
+    #[test]
+    fn test_write_csv_binary() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Binary, true),
+            Field::new("c2", DataType::FixedSizeBinary(2), true),
+        ]);
+
+        let c1 = BinaryArray::from_opt_vec(vec![Some(b"foo"), None]);
+        let c2 =
+            FixedSizeBinaryArray::try_from_iter(vec![[0x0a, 0xff], [0, 1]].into_iter())
+                .unwrap();
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(c1), Arc::new(c2)])
+                .unwrap();
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = Writer::new(&mut buf);
+            writer.write(&batch).unwrap();
+        }
+        assert_eq!(
+            "c1,c2\n666f6f,0aff\n,0001\n",
+            String::from_utf8(buf).unwrap()
+        );
+
+        for (format, expected) in [
+            (BinaryFormat::HexPrefixed, "0x666f6f,0x0aff\n,0x0001\n"),
+            (BinaryFormat::Base64, "Zm9v,Cv8=\n,AAE=\n"),
+        ] {
+            let mut buf = Vec::new();
+            {
+                let mut writer = WriterBuilder::new()
+                    .has_headers(false)
+                    .with_binary_format(format)
+                    .build(&mut buf);
+                writer.write(&batch).unwrap();
+            }
+            assert_eq!(expected, String::from_utf8(buf).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_write_csv_unsupported() {
+        let list =
+            ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)])]);
+        let decimal = new_null_array(&DataType::Decimal256(10, 2), 1);
+        for column in [Arc::new(list) as ArrayRef, decimal] {
+            let batch = RecordBatch::try_from_iter([("c1", column.clone())]).unwrap();
+            let mut writer = Writer::new(Vec::new());
+            let err = writer.write(&batch).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "Csv error: CSV Writer does not support {:?} data type",
+                    column.data_type()
+                )
+            );
+        }
+    }
+
     #[cfg(feature = "chrono-tz")]
     #[test]
     fn test_export_csv_timestamps() {
@@ -74,6 +74,18 @@ impl TryFrom<&FFI_ArrowSchema> for DataType {
                 let map_keys_sorted = c_schema.map_keys_sorted();
                 DataType::Map(Box::new(Field::try_from(c_child)?), map_keys_sorted)
             }
+            // Layouts of the C data interface without a counterpart in this implementation
+            "+r" | "vu" | "vz" => {
+                return Err(ArrowError::CDataInterface(format!(
+                    "The {} layout (format \"{}\") is not yet supported in Rust implementation",
+                    match c_schema.format() {
+                        "+r" => "run-end encoded",
+                        "vu" => "string view",
+                        _ => "binary view",
+                    },
+                    c_schema.format()
+                )))
+            }
             // Parametrized types, requiring string parse
             other => {
                 match other.splitn(2, ':').collect::<Vec<&str>>().as_slice() {
@@ -176,7 +188,9 @@ impl TryFrom<&FFI_ArrowSchema> for Field {
 
     fn try_from(c_schema: &FFI_ArrowSchema) -> Result<Self> {
         let dtype = DataType::try_from(c_schema)?;
-        let field = Field::new(c_schema.name(), dtype, c_schema.nullable());
+        let metadata = c_schema.metadata()?;
+        let field = Field::new(c_schema.name(), dtype, c_schema.nullable())
+            .with_metadata(Some(metadata.into_iter().collect()));
         Ok(field)
     }
 }
@@ -188,7 +202,8 @@ impl TryFrom<&FFI_ArrowSchema> for Schema {
         // interpret it as a struct type then extract its fields
         let dtype = DataType::try_from(c_schema)?;
         if let DataType::Struct(fields) = dtype {
-            Ok(Schema::new(fields))
+            let metadata = c_schema.metadata()?.into_iter().collect();
+            Ok(Schema::new_with_metadata(fields, metadata))
         } else {
             Err(ArrowError::CDataInterface(
                 "Unable to interpret C data struct as a Schema".to_string(),
@@ -302,7 +317,8 @@ impl TryFrom<&Field> for FFI_ArrowSchema {
 
         FFI_ArrowSchema::try_from(field.data_type())?
             .with_name(field.name())?
-            .with_flags(flags)
+            .with_flags(flags)?
+            .with_metadata(field.metadata().into_iter().flatten())
     }
 }
 
@@ -311,7 +327,8 @@ impl TryFrom<&Schema> for FFI_ArrowSchema {
 
     fn try_from(schema: &Schema) -> Result<Self> {
         let dtype = DataType::Struct(schema.fields().clone());
-        let c_schema = FFI_ArrowSchema::try_from(&dtype)?;
+        let c_schema =
+            FFI_ArrowSchema::try_from(&dtype)?.with_metadata(&schema.metadata)?;
         Ok(c_schema)
     }
 }
@@ -345,6 +362,7 @@ mod tests {
     use super::*;
     use crate::datatypes::{DataType, Field, TimeUnit};
     use crate::error::Result;
+    use std::collections::{BTreeMap, HashMap};
     use std::convert::TryFrom;
 
     fn round_trip_type(dtype: DataType) -> Result<()> {
@@ -426,6 +444,58 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_metadata() -> Result<()> {
+        let extension_metadata = BTreeMap::from([
+            ("ARROW:extension:name".to_string(), "arrow.uuid".to_string()),
+            ("ARROW:extension:metadata".to_string(), "".to_string()),
+        ]);
+        let nested = Field::new("nested", DataType::FixedSizeBinary(16), true)
+            .with_metadata(Some(extension_metadata.clone()));
+        let field = Field::new("a", DataType::Struct(vec![nested]), false).with_metadata(
+            Some(BTreeMap::from([("key".to_string(), "välue".to_string())])),
+        );
+        round_trip_field(field.clone())?;
+
+        let c_schema = FFI_ArrowSchema::try_from(&field)?;
+        assert_eq!(
+            c_schema.child(0).metadata()?,
+            extension_metadata.into_iter().collect::<Vec<_>>()
+        );
+
+        let schema = Schema::new_with_metadata(
+            vec![field, Field::new("b", DataType::Int32, true)],
+            HashMap::from([("schema_key".to_string(), "value".to_string())]),
+        );
+        round_trip_schema(schema)?;
+
+        // no metadata is exported as a null pointer
+        let c_schema = FFI_ArrowSchema::try_from(&Field::new("c", DataType::Int8, true))?;
+        assert!(c_schema.metadata.is_null());
+        assert!(c_schema.metadata()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_layouts() -> Result<()> {
+        for (format, layout) in [
+            ("+r", "run-end encoded"),
+            ("vu", "string view"),
+            ("vz", "binary view"),
+        ] {
+            let c_schema = FFI_ArrowSchema::try_new(format, vec![], None)?;
+            let err = DataType::try_from(&c_schema).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "C Data interface error: The {} layout (format \"{}\") is not yet supported in Rust implementation",
+                    layout, format
+                )
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_map_keys_sorted() -> Result<()> {
         let keys = Field::new("keys", DataType::Int32, false);
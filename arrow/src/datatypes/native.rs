@@ -36,6 +36,9 @@ mod private {
 ///
 /// The APIs with `_checked` suffix are the variant of overflow-checking which return `None`
 /// if overflow occurred.
+///
+/// The APIs with `_saturating` suffix are the variant of non-overflow-checking which
+/// saturate at the boundary of the type if overflow occurred.
 pub trait ArrowNativeTypeOp:
     ArrowNativeType
     + Add<Output = Self>
@@ -54,6 +57,10 @@ pub trait ArrowNativeTypeOp:
         self + rhs
     }
 
+    fn add_saturating(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
     fn sub_checked(self, rhs: Self) -> Result<Self> {
         Ok(self - rhs)
     }
@@ -62,6 +69,10 @@ pub trait ArrowNativeTypeOp:
         self - rhs
     }
 
+    fn sub_saturating(self, rhs: Self) -> Self {
+        self - rhs
+    }
+
     fn mul_checked(self, rhs: Self) -> Result<Self> {
         Ok(self * rhs)
     }
@@ -70,6 +81,10 @@ pub trait ArrowNativeTypeOp:
         self * rhs
     }
 
+    fn mul_saturating(self, rhs: Self) -> Self {
+        self * rhs
+    }
+
     fn div_checked(self, rhs: Self) -> Result<Self> {
         if rhs.is_zero() {
             Err(ArrowError::DivideByZero)
@@ -136,6 +151,10 @@ macro_rules! native_type_op {
                 self.wrapping_add(rhs)
             }
 
+            fn add_saturating(self, rhs: Self) -> Self {
+                self.saturating_add(rhs)
+            }
+
             fn sub_checked(self, rhs: Self) -> Result<Self> {
                 self.checked_sub(rhs).ok_or_else(|| {
                     ArrowError::ComputeError(format!(
@@ -149,6 +168,10 @@ macro_rules! native_type_op {
                 self.wrapping_sub(rhs)
             }
 
+            fn sub_saturating(self, rhs: Self) -> Self {
+                self.saturating_sub(rhs)
+            }
+
             fn mul_checked(self, rhs: Self) -> Result<Self> {
                 self.checked_mul(rhs).ok_or_else(|| {
                     ArrowError::ComputeError(format!(
@@ -162,6 +185,10 @@ macro_rules! native_type_op {
                 self.wrapping_mul(rhs)
             }
 
+            fn mul_saturating(self, rhs: Self) -> Self {
+                self.saturating_mul(rhs)
+            }
+
             fn div_checked(self, rhs: Self) -> Result<Self> {
                 if rhs.is_zero() {
                     Err(ArrowError::DivideByZero)
@@ -110,12 +110,14 @@ To export an array, create an `ArrowArray` using [ArrowArray::try_new].
 */
 
 use std::{
+    any::Any,
     convert::TryFrom,
     ffi::CStr,
     ffi::CString,
     iter,
     mem::size_of,
     os::raw::{c_char, c_void},
+    panic::{self, AssertUnwindSafe},
     ptr::{self, NonNull},
     sync::Arc,
 };
@@ -156,6 +158,7 @@ pub struct FFI_ArrowSchema {
 struct SchemaPrivateData {
     children: Box<[*mut FFI_ArrowSchema]>,
     dictionary: *mut FFI_ArrowSchema,
+    metadata: Option<Vec<u8>>,
 }
 
 // callback used to drop [FFI_ArrowSchema] when it is exported.
@@ -212,6 +215,7 @@ impl FFI_ArrowSchema {
         let mut private_data = Box::new(SchemaPrivateData {
             children: children_ptr,
             dictionary: dictionary_ptr,
+            metadata: None,
         });
 
         // intentionally set from private_data (see https://github.com/apache/arrow-rs/issues/580)
@@ -234,6 +238,50 @@ impl FFI_ArrowSchema {
         Ok(self)
     }
 
+    /// Sets the key-value metadata of this schema, encoded as described in
+    /// <https://arrow.apache.org/docs/format/CDataInterface.html#c.ArrowSchema.metadata>.
+    /// An empty `metadata` leaves the metadata pointer null.
+    pub fn with_metadata<I, K, V>(mut self, metadata: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        if self.private_data.is_null() {
+            return Err(ArrowError::CDataInterface(
+                "Metadata can only be set on schemas created by FFI_ArrowSchema::try_new"
+                    .to_string(),
+            ));
+        }
+
+        let mut count = 0_i32;
+        // reserve space for the number of entries, written once they are counted
+        let mut encoded = count.to_ne_bytes().to_vec();
+        for (key, value) in metadata {
+            for item in [key.as_ref(), value.as_ref()] {
+                let len = i32::try_from(item.len()).map_err(|_| {
+                    ArrowError::CDataInterface(format!(
+                        "Metadata entry of length {} is too long",
+                        item.len()
+                    ))
+                })?;
+                encoded.extend_from_slice(&len.to_ne_bytes());
+                encoded.extend_from_slice(item.as_bytes());
+            }
+            count += 1;
+        }
+        encoded[..size_of::<i32>()].copy_from_slice(&count.to_ne_bytes());
+
+        // safe because `private_data` is only set by `FFI_ArrowSchema::try_new`
+        let private_data = unsafe { &mut *(self.private_data as *mut SchemaPrivateData) };
+        private_data.metadata = if count > 0 { Some(encoded) } else { None };
+        self.metadata = match &private_data.metadata {
+            Some(metadata) => metadata.as_ptr() as *const c_char,
+            None => std::ptr::null(),
+        };
+        Ok(self)
+    }
+
     pub fn empty() -> Self {
         Self {
             format: std::ptr::null_mut(),
@@ -270,6 +318,45 @@ impl FFI_ArrowSchema {
         Flags::from_bits(self.flags)
     }
 
+    /// returns the key-value metadata of this schema, in the order it was encoded.
+    /// Returns an empty list if the schema has no metadata.
+    pub fn metadata(&self) -> Result<Vec<(String, String)>> {
+        if self.metadata.is_null() {
+            return Ok(vec![]);
+        }
+
+        // the encoding is: an int32 number of entries, followed by, for each entry,
+        // an int32 key length, the key bytes, an int32 value length and the value bytes.
+        // Lengths are native-endian and not necessarily aligned.
+        let mut ptr = self.metadata as *const u8;
+        let read_len = |ptr: &mut *const u8| -> Result<usize> {
+            // safe because the C data interface guarantees the metadata is well formed
+            let len = unsafe { std::ptr::read_unaligned(*ptr as *const i32) };
+            *ptr = unsafe { ptr.add(size_of::<i32>()) };
+            usize::try_from(len).map_err(|_| {
+                ArrowError::CDataInterface(format!(
+                    "Invalid negative length {} in schema metadata",
+                    len
+                ))
+            })
+        };
+        let read_str = |ptr: &mut *const u8| -> Result<String> {
+            let len = read_len(ptr)?;
+            let bytes = unsafe { std::slice::from_raw_parts(*ptr, len) };
+            *ptr = unsafe { ptr.add(len) };
+            String::from_utf8(bytes.to_vec()).map_err(|_| {
+                ArrowError::CDataInterface(
+                    "The external API has a non-utf8 metadata entry".to_string(),
+                )
+            })
+        };
+
+        let count = read_len(&mut ptr)?;
+        (0..count)
+            .map(|_| Ok((read_str(&mut ptr)?, read_str(&mut ptr)?)))
+            .collect()
+    }
+
     pub fn child(&self, index: usize) -> &Self {
         assert!(index < self.n_children as usize);
         unsafe { self.children.add(index).as_ref().unwrap().as_ref().unwrap() }
@@ -436,6 +523,15 @@ unsafe extern "C" fn release_array(array: *mut FFI_ArrowArray) {
     }
     let array = &mut *array;
 
+    // user data and release callbacks may panic, which must not unwind into the
+    // consumer, so discard the panic and mark the array as released regardless
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| release_private_data(array)));
+
+    array.release = None;
+}
+
+/// Drops the [`ArrayPrivateData`] of `array`, then invokes its release callback
+unsafe fn release_private_data(array: &mut FFI_ArrowArray) {
     // take ownership of `private_data`, therefore dropping it`
     let private = Box::from_raw(array.private_data as *mut ArrayPrivateData);
     for child in private.children.iter() {
@@ -445,15 +541,32 @@ unsafe extern "C" fn release_array(array: *mut FFI_ArrowArray) {
         let _ = Box::from_raw(private.dictionary);
     }
 
-    array.release = None;
+    // drop the buffers and the user data before notifying the callback, so that
+    // the callback observes all memory owned by this array as released
+    let ArrayPrivateData {
+        buffers,
+        user_data,
+        release_callback,
+        ..
+    } = *private;
+    drop(buffers);
+    drop(user_data);
+    if let Some(callback) = release_callback {
+        callback();
+    }
 }
 
+/// A callback invoked once an exported [FFI_ArrowArray] has been released by its consumer
+pub type ReleaseCallback = Box<dyn FnOnce() + Send>;
+
 struct ArrayPrivateData {
     #[allow(dead_code)]
     buffers: Vec<Option<Buffer>>,
     buffers_ptr: Box<[*const c_void]>,
     children: Box<[*mut FFI_ArrowArray]>,
     dictionary: *mut FFI_ArrowArray,
+    user_data: Option<Box<dyn Any + Send + Sync>>,
+    release_callback: Option<ReleaseCallback>,
 }
 
 impl FFI_ArrowArray {
@@ -516,6 +629,8 @@ impl FFI_ArrowArray {
             buffers_ptr,
             children,
             dictionary,
+            user_data: None,
+            release_callback: None,
         });
 
         Self {
@@ -567,6 +682,72 @@ impl FFI_ArrowArray {
     pub fn null_count(&self) -> usize {
         self.null_count as usize
     }
+
+    /// whether this array has been released, i.e. its `release` callback was called
+    /// or it was never populated
+    pub fn is_released(&self) -> bool {
+        self.release.is_none()
+    }
+
+    /// Attaches `user_data` to this exported array. The data is kept alive until the
+    /// consumer releases the array, which makes it possible to tie the lifetime of
+    /// external resources (e.g. a memory map) to the exported buffers.
+    ///
+    /// # Error
+    /// Errors if this array was not exported by [FFI_ArrowArray::new] or has been released
+    pub fn with_user_data<T: Any + Send + Sync>(mut self, user_data: T) -> Result<Self> {
+        self.private_data_mut()?.user_data = Some(Box::new(user_data));
+        Ok(self)
+    }
+
+    /// Returns the user data attached with [FFI_ArrowArray::with_user_data], if any
+    /// and if it is of type `T`
+    pub fn user_data<T: Any>(&self) -> Option<&T> {
+        if !self.is_exported_by_rust() {
+            return None;
+        }
+        // safe because `private_data` was created by `FFI_ArrowArray::new`
+        let private = unsafe { &*(self.private_data as *const ArrayPrivateData) };
+        private.user_data.as_ref()?.downcast_ref()
+    }
+
+    /// Registers `callback` to be invoked once the consumer releases this array, after
+    /// the buffers and user data owned by it have been dropped. Registering a new
+    /// callback replaces any previously registered one.
+    ///
+    /// Note that the callback is only invoked for the top-level array, children and
+    /// dictionaries are released as part of it.
+    ///
+    /// Panics of the callback, or of dropping the user data, must not unwind into the
+    /// consumer, so they are caught and discarded. The array is released regardless.
+    ///
+    /// # Error
+    /// Errors if this array was not exported by [FFI_ArrowArray::new] or has been released
+    pub fn with_release_callback<F>(mut self, callback: F) -> Result<Self>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.private_data_mut()?.release_callback = Some(Box::new(callback));
+        Ok(self)
+    }
+
+    // whether `private_data` is an `ArrayPrivateData` created by `FFI_ArrowArray::new`
+    fn is_exported_by_rust(&self) -> bool {
+        let exported: unsafe extern "C" fn(*mut FFI_ArrowArray) = release_array;
+        let release = self.release.map(|f| f as usize);
+        release == Some(exported as usize) && !self.private_data.is_null()
+    }
+
+    fn private_data_mut(&mut self) -> Result<&mut ArrayPrivateData> {
+        if !self.is_exported_by_rust() {
+            return Err(ArrowError::CDataInterface(
+                "Only arrays exported by FFI_ArrowArray::new can be customized"
+                    .to_string(),
+            ));
+        }
+        // safe because `private_data` was created by `FFI_ArrowArray::new`
+        Ok(unsafe { &mut *(self.private_data as *mut ArrayPrivateData) })
+    }
 }
 
 /// returns a new buffer corresponding to the index `i` of the FFI array. It may not exist (null pointer).
@@ -1481,4 +1662,86 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_release_callback() -> Result<()> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static RELEASED: AtomicUsize = AtomicUsize::new(0);
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        struct Tracker(Arc<Vec<i32>>);
+
+        impl Drop for Tracker {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        // simulate an externally owned allocation shared with the exported array
+        let external = Arc::new(vec![1_i32, 2, 3]);
+        let buffer = unsafe {
+            Buffer::from_custom_allocation(
+                NonNull::new(external.as_ptr() as *mut u8).unwrap(),
+                external.len() * size_of::<i32>(),
+                external.clone(),
+            )
+        };
+        let data = ArrayData::builder(DataType::Int32)
+            .len(3)
+            .add_buffer(buffer)
+            .build()?;
+        let array = Int32Array::from(data);
+
+        let ffi_array = FFI_ArrowArray::new(array.data())
+            .with_user_data(Tracker(external.clone()))?
+            .with_release_callback(|| {
+                assert_eq!(DROPPED.load(Ordering::SeqCst), 1);
+                RELEASED.fetch_add(1, Ordering::SeqCst);
+            })?;
+        drop(array);
+
+        assert!(!ffi_array.is_released());
+        assert_eq!(ffi_array.user_data::<Tracker>().unwrap().0.len(), 3);
+        assert!(ffi_array.user_data::<String>().is_none());
+        // owned by `external`, the buffer and the user data
+        assert_eq!(Arc::strong_count(&external), 3);
+        assert_eq!(RELEASED.load(Ordering::SeqCst), 0);
+
+        drop(ffi_array);
+
+        assert_eq!(Arc::strong_count(&external), 1);
+        assert_eq!(DROPPED.load(Ordering::SeqCst), 1);
+        assert_eq!(RELEASED.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_release_callback_panic() -> Result<()> {
+        let array = Int32Array::from(vec![1, 2, 3]);
+        let mut ffi_array = FFI_ArrowArray::new(array.data())
+            .with_release_callback(|| panic!("release callback failed"))?;
+
+        // the panic doesn't unwind out of the release callback
+        let release = ffi_array.release.unwrap();
+        unsafe { release(&mut ffi_array) };
+        assert!(ffi_array.is_released());
+        Ok(())
+    }
+
+    #[test]
+    fn test_release_callback_imported() -> Result<()> {
+        let err = FFI_ArrowArray::empty()
+            .with_release_callback(|| ())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "C Data interface error: Only arrays exported by FFI_ArrowArray::new can be customized"
+        );
+
+        let array = FFI_ArrowArray::empty();
+        assert!(array.is_released());
+        assert!(array.user_data::<i32>().is_none());
+        Ok(())
+    }
 }
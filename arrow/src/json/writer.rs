@@ -113,6 +113,7 @@ use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::json::JsonSerializable;
 use crate::record_batch::RecordBatch;
+use crate::util::display::{ArrayFormatter, FormatOptions};
 
 fn primitive_array_to_json<T>(array: &ArrayRef) -> Result<Vec<Value>>
 where
@@ -264,6 +265,25 @@ fn set_column_by_primitive_type<T>(
         });
 }
 
+/// Sets the values of `array` as strings, formatted by an [`ArrayFormatter`]
+fn set_column_by_formatter(
+    rows: &mut [JsonMap<String, Value>],
+    row_count: usize,
+    array: &ArrayRef,
+    col_name: &str,
+) -> Result<()> {
+    let options = FormatOptions::new();
+    let formatter = ArrayFormatter::try_new(array.as_ref(), &options)?;
+    for (i, row) in rows.iter_mut().take(row_count).enumerate() {
+        // when value is null, we simply skip setting the key
+        if array.is_valid(i) {
+            let value = formatter.value(i).try_to_string()?;
+            row.insert(col_name.to_string(), value.into());
+        }
+    }
+    Ok(())
+}
+
 fn set_column_for_json_rows(
     rows: &mut [JsonMap<String, Value>],
     row_count: usize,
@@ -319,105 +339,13 @@ fn set_column_for_json_rows(
                 row_count
             );
         }
-        DataType::Date32 => {
-            set_temporal_column_by_array_type!(
-                Date32Array,
-                col_name,
-                rows,
-                array,
-                row_count,
-                value_as_date
-            );
-        }
-        DataType::Date64 => {
-            set_temporal_column_by_array_type!(
-                Date64Array,
-                col_name,
-                rows,
-                array,
-                row_count,
-                value_as_date
-            );
-        }
-        DataType::Timestamp(TimeUnit::Second, _) => {
-            set_temporal_column_by_array_type!(
-                TimestampSecondArray,
-                col_name,
-                rows,
-                array,
-                row_count,
-                value_as_datetime
-            );
-        }
-        DataType::Timestamp(TimeUnit::Millisecond, _) => {
-            set_temporal_column_by_array_type!(
-                TimestampMillisecondArray,
-                col_name,
-                rows,
-                array,
-                row_count,
-                value_as_datetime
-            );
-        }
-        DataType::Timestamp(TimeUnit::Microsecond, _) => {
-            set_temporal_column_by_array_type!(
-                TimestampMicrosecondArray,
-                col_name,
-                rows,
-                array,
-                row_count,
-                value_as_datetime
-            );
-        }
-        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
-            set_temporal_column_by_array_type!(
-                TimestampNanosecondArray,
-                col_name,
-                rows,
-                array,
-                row_count,
-                value_as_datetime
-            );
-        }
-        DataType::Time32(TimeUnit::Second) => {
-            set_temporal_column_by_array_type!(
-                Time32SecondArray,
-                col_name,
-                rows,
-                array,
-                row_count,
-                value_as_time
-            );
-        }
-        DataType::Time32(TimeUnit::Millisecond) => {
-            set_temporal_column_by_array_type!(
-                Time32MillisecondArray,
-                col_name,
-                rows,
-                array,
-                row_count,
-                value_as_time
-            );
-        }
-        DataType::Time64(TimeUnit::Microsecond) => {
-            set_temporal_column_by_array_type!(
-                Time64MicrosecondArray,
-                col_name,
-                rows,
-                array,
-                row_count,
-                value_as_time
-            );
-        }
-        DataType::Time64(TimeUnit::Nanosecond) => {
-            set_temporal_column_by_array_type!(
-                Time64NanosecondArray,
-                col_name,
-                rows,
-                array,
-                row_count,
-                value_as_time
-            );
+        DataType::Date32
+        | DataType::Date64
+        | DataType::Timestamp(_, _)
+        | DataType::Time32(_)
+        | DataType::Time64(_)
+        | DataType::Interval(_) => {
+            set_column_by_formatter(rows, row_count, array, col_name)?;
         }
         DataType::Duration(TimeUnit::Second) => {
             set_temporal_column_by_array_type!(
@@ -1026,6 +954,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_intervals() {
+        let arr_year_month = IntervalYearMonthArray::from(vec![Some(25), None]);
+        let arr_day_time = IntervalDayTimeArray::from(vec![
+            Some(IntervalDayTimeType::make_value(1, 1500)),
+            Some(IntervalDayTimeType::make_value(0, -60_000)),
+        ]);
+
+        let schema = Schema::new(vec![
+            Field::new("year_month", arr_year_month.data_type().clone(), true),
+            Field::new("day_time", arr_day_time.data_type().clone(), true),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(arr_year_month), Arc::new(arr_day_time)],
+        )
+        .unwrap();
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = LineDelimitedWriter::new(&mut buf);
+            writer.write_batches(&[batch]).unwrap();
+        }
+
+        assert_json_eq(
+            &buf,
+            r#"{"year_month":"2 years 1 mon","day_time":"1 day 00:00:01.5"}
+{"day_time":"-00:01:00"}
+"#,
+        );
+    }
+
     #[test]
     fn write_durations() {
         let arr_durationsec = DurationSecondArray::from(vec![Some(120), None]);
@@ -24,7 +24,7 @@ use std::sync::Arc;
 use pyo3::ffi::Py_uintptr_t;
 use pyo3::import_exception;
 use pyo3::prelude::*;
-use pyo3::types::{PyList, PyTuple};
+use pyo3::types::{PyDict, PyList, PyTuple};
 
 use crate::array::{make_array, Array, ArrayData};
 use crate::datatypes::{DataType, Field, Schema};
@@ -183,21 +183,21 @@ impl PyArrowConvert for RecordBatch {
     }
 
     fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
-        let mut py_arrays = vec![];
-        let mut py_names = vec![];
-
-        let schema = self.schema();
-        let fields = schema.fields().iter();
-        let columns = self.columns().iter();
+        let py_arrays = self
+            .columns()
+            .iter()
+            .map(|array| array.data().to_pyarrow(py))
+            .collect::<PyResult<Vec<_>>>()?;
 
-        for (array, field) in columns.zip(fields) {
-            py_arrays.push(array.data().to_pyarrow(py)?);
-            py_names.push(field.name());
-        }
+        // pass the schema rather than the column names, so that field and schema
+        // metadata (including extension types) are preserved
+        let py_schema = self.schema().to_pyarrow(py)?;
 
         let module = py.import("pyarrow")?;
         let class = module.getattr("RecordBatch")?;
-        let record = class.call_method1("from_arrays", (py_arrays, py_names))?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("schema", py_schema)?;
+        let record = class.call_method("from_arrays", (py_arrays,), Some(kwargs))?;
 
         Ok(PyObject::from(record))
     }
@@ -0,0 +1,479 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A two-dimensional batch of column-oriented data with a defined
+//! [schema](crate::datatypes::Schema), and [`RecordBatchReader`] for
+//! streams of them.
+
+pub use arrow_array::{RecordBatch, RecordBatchOptions, RecordBatchRowIter, RowAccessor};
+use arrow_schema::{ArrowError, SchemaRef};
+
+/// Trait for types that can read `RecordBatch`'s.
+pub trait RecordBatchReader: Iterator<Item = Result<RecordBatch, ArrowError>> {
+    /// Returns the schema of this `RecordBatchReader`.
+    ///
+    /// Implementation of this trait should guarantee that all `RecordBatch`'s returned by this
+    /// reader should have the same schema as returned from this method.
+    fn schema(&self) -> SchemaRef;
+
+    /// Reads the next `RecordBatch`.
+    #[deprecated(
+        since = "2.0.0",
+        note = "This method is deprecated in favour of `next` from the trait Iterator."
+    )]
+    fn next_batch(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        self.next().transpose()
+    }
+}
+
+/// A [`RecordBatchReader`] over an iterator of [`RecordBatch`] with a known schema
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{ArrayRef, Int32Array};
+/// # use arrow::record_batch::{RecordBatch, RecordBatchIterator, RecordBatchReader};
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+/// let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+/// let schema = batch.schema();
+///
+/// let reader = RecordBatchIterator::new(vec![Ok(batch)], schema.clone());
+/// assert_eq!(reader.schema(), schema);
+/// assert_eq!(reader.count(), 1);
+/// ```
+#[derive(Debug)]
+pub struct RecordBatchIterator<I> {
+    inner: I,
+    schema: SchemaRef,
+}
+
+impl<I> RecordBatchIterator<I>
+where
+    I: Iterator<Item = Result<RecordBatch, ArrowError>>,
+{
+    /// Create a new [`RecordBatchIterator`] yielding the batches of `iter`,
+    /// which must all have the provided `schema`
+    pub fn new(
+        iter: impl IntoIterator<IntoIter = I, Item = I::Item>,
+        schema: SchemaRef,
+    ) -> Self {
+        Self {
+            inner: iter.into_iter(),
+            schema,
+        }
+    }
+}
+
+impl<I> Iterator for RecordBatchIterator<I>
+where
+    I: Iterator<Item = Result<RecordBatch, ArrowError>>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I> RecordBatchReader for RecordBatchIterator<I>
+where
+    I: Iterator<Item = Result<RecordBatch, ArrowError>>,
+{
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+#[cfg(feature = "avro")]
+impl<R: std::io::Read> RecordBatchReader for arrow_avro::Reader<R> {
+    fn schema(&self) -> SchemaRef {
+        arrow_avro::Reader::schema(self)
+    }
+}
+
+#[cfg(feature = "orc")]
+impl<R: std::io::Read + std::io::Seek> RecordBatchReader for arrow_orc::Reader<R> {
+    fn schema(&self) -> SchemaRef {
+        arrow_orc::Reader::schema(self)
+    }
+}
+
+/// Extension trait providing combinators over any [`RecordBatchReader`]
+///
+/// Each adapter is itself a [`RecordBatchReader`], allowing pipelines to be
+/// composed without hand-written iterator structs. Errors returned by the
+/// underlying reader are passed through unchanged.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{ArrayRef, Int32Array};
+/// # use arrow::compute::cast;
+/// # use arrow::datatypes::{DataType, Field, Schema};
+/// # use arrow::record_batch::{RecordBatch, RecordBatchIterator, RecordBatchReaderExt};
+/// let batches = (0..4).map(|i| {
+///     let a: ArrayRef = Arc::new(Int32Array::from(vec![i; i as usize]));
+///     RecordBatch::try_from_iter([("a", a)])
+/// });
+/// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+/// let reader = RecordBatchIterator::new(batches, schema);
+///
+/// let output = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+/// let reader = reader
+///     .filter_batches(|batch| Ok(batch.num_rows() != 0))
+///     .take_batches(2)
+///     .map_batches(output.clone(), move |batch| {
+///         let a = cast(batch.column(0), &DataType::Int64)?;
+///         RecordBatch::try_new(output.clone(), vec![a])
+///     });
+///
+/// let rows: Vec<_> = reader.map(|b| b.unwrap().num_rows()).collect();
+/// assert_eq!(rows, vec![1, 2]);
+/// ```
+pub trait RecordBatchReaderExt: RecordBatchReader + Sized {
+    /// Applies `f` to each batch, returning a reader with the provided `schema`
+    ///
+    /// `f` must return batches matching `schema`
+    fn map_batches<F>(self, schema: SchemaRef, f: F) -> MapBatches<Self, F>
+    where
+        F: FnMut(RecordBatch) -> Result<RecordBatch, ArrowError>,
+    {
+        MapBatches {
+            reader: self,
+            schema,
+            f,
+        }
+    }
+
+    /// Only yields the batches for which `predicate` returns true
+    ///
+    /// To filter the rows within each batch, use [`Self::map_batches`] with
+    /// [`filter_record_batch`](crate::compute::filter_record_batch)
+    fn filter_batches<F>(self, predicate: F) -> FilterBatches<Self, F>
+    where
+        F: FnMut(&RecordBatch) -> Result<bool, ArrowError>,
+    {
+        FilterBatches {
+            reader: self,
+            predicate,
+        }
+    }
+
+    /// Yields at most the first `n` batches
+    fn take_batches(self, n: usize) -> TakeBatches<Self> {
+        TakeBatches {
+            reader: self,
+            remaining: n,
+        }
+    }
+
+    /// Returns a reader that allows peeking at the next batch without consuming it
+    fn peekable_batches(self) -> PeekableBatches<Self> {
+        PeekableBatches {
+            reader: self,
+            peeked: None,
+        }
+    }
+
+    /// Yields the first `n` batches and then `error`, after which no further
+    /// batches are returned
+    ///
+    /// This is useful for testing the error handling of consumers
+    fn fail_after(self, n: usize, error: ArrowError) -> FailAfter<Self> {
+        FailAfter {
+            reader: self,
+            remaining: n,
+            error: Some(error),
+        }
+    }
+}
+
+impl<R: RecordBatchReader> RecordBatchReaderExt for R {}
+
+/// A [`RecordBatchReader`] returned by [`RecordBatchReaderExt::map_batches`]
+#[derive(Debug)]
+pub struct MapBatches<R, F> {
+    reader: R,
+    schema: SchemaRef,
+    f: F,
+}
+
+impl<R, F> Iterator for MapBatches<R, F>
+where
+    R: RecordBatchReader,
+    F: FnMut(RecordBatch) -> Result<RecordBatch, ArrowError>,
+{
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.next().map(|batch| batch.and_then(&mut self.f))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.reader.size_hint()
+    }
+}
+
+impl<R, F> RecordBatchReader for MapBatches<R, F>
+where
+    R: RecordBatchReader,
+    F: FnMut(RecordBatch) -> Result<RecordBatch, ArrowError>,
+{
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+/// A [`RecordBatchReader`] returned by [`RecordBatchReaderExt::filter_batches`]
+#[derive(Debug)]
+pub struct FilterBatches<R, F> {
+    reader: R,
+    predicate: F,
+}
+
+impl<R, F> Iterator for FilterBatches<R, F>
+where
+    R: RecordBatchReader,
+    F: FnMut(&RecordBatch) -> Result<bool, ArrowError>,
+{
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let batch = match self.reader.next()? {
+                Ok(batch) => batch,
+                Err(e) => return Some(Err(e)),
+            };
+            match (self.predicate)(&batch) {
+                Ok(true) => return Some(Ok(batch)),
+                Ok(false) => continue,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.reader.size_hint().1)
+    }
+}
+
+impl<R, F> RecordBatchReader for FilterBatches<R, F>
+where
+    R: RecordBatchReader,
+    F: FnMut(&RecordBatch) -> Result<bool, ArrowError>,
+{
+    fn schema(&self) -> SchemaRef {
+        self.reader.schema()
+    }
+}
+
+/// A [`RecordBatchReader`] returned by [`RecordBatchReaderExt::take_batches`]
+#[derive(Debug)]
+pub struct TakeBatches<R> {
+    reader: R,
+    remaining: usize,
+}
+
+impl<R: RecordBatchReader> Iterator for TakeBatches<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.reader.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.reader.size_hint();
+        let upper = upper.map_or(self.remaining, |u| u.min(self.remaining));
+        (lower.min(self.remaining), Some(upper))
+    }
+}
+
+impl<R: RecordBatchReader> RecordBatchReader for TakeBatches<R> {
+    fn schema(&self) -> SchemaRef {
+        self.reader.schema()
+    }
+}
+
+/// A [`RecordBatchReader`] returned by [`RecordBatchReaderExt::peekable_batches`]
+#[derive(Debug)]
+pub struct PeekableBatches<R: RecordBatchReader> {
+    reader: R,
+    peeked: Option<Option<Result<RecordBatch, ArrowError>>>,
+}
+
+impl<R: RecordBatchReader> PeekableBatches<R> {
+    /// Returns a reference to the next batch without advancing the reader
+    pub fn peek(&mut self) -> Option<&Result<RecordBatch, ArrowError>> {
+        let reader = &mut self.reader;
+        self.peeked.get_or_insert_with(|| reader.next()).as_ref()
+    }
+}
+
+impl<R: RecordBatchReader> Iterator for PeekableBatches<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => self.reader.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = match &self.peeked {
+            Some(None) => return (0, Some(0)),
+            Some(Some(_)) => 1,
+            None => 0,
+        };
+        let (lower, upper) = self.reader.size_hint();
+        (
+            lower.saturating_add(peeked),
+            upper.and_then(|u| u.checked_add(peeked)),
+        )
+    }
+}
+
+impl<R: RecordBatchReader> RecordBatchReader for PeekableBatches<R> {
+    fn schema(&self) -> SchemaRef {
+        self.reader.schema()
+    }
+}
+
+/// A [`RecordBatchReader`] returned by [`RecordBatchReaderExt::fail_after`]
+#[derive(Debug)]
+pub struct FailAfter<R> {
+    reader: R,
+    remaining: usize,
+    error: Option<ArrowError>,
+}
+
+impl<R: RecordBatchReader> Iterator for FailAfter<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return self.error.take().map(Err);
+        }
+        self.remaining -= 1;
+        self.reader.next()
+    }
+}
+
+impl<R: RecordBatchReader> RecordBatchReader for FailAfter<R> {
+    fn schema(&self) -> SchemaRef {
+        self.reader.schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{ArrayRef, Int32Array};
+    use crate::datatypes::{DataType, Field, Schema};
+    use std::sync::Arc;
+
+    fn reader(
+        lengths: &[i32],
+    ) -> RecordBatchIterator<impl Iterator<Item = Result<RecordBatch, ArrowError>>> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batches = lengths
+            .iter()
+            .map(|len| {
+                let a: ArrayRef = Arc::new(Int32Array::from_iter_values(0..*len));
+                RecordBatch::try_from_iter([("a", a)])
+            })
+            .collect::<Vec<_>>();
+        RecordBatchIterator::new(batches, schema)
+    }
+
+    fn rows(reader: impl RecordBatchReader) -> Vec<usize> {
+        reader.map(|batch| batch.unwrap().num_rows()).collect()
+    }
+
+    #[test]
+    fn test_map_batches() {
+        let schema = Arc::new(Schema::new(vec![Field::new("b", DataType::Int32, false)]));
+        let mapped_schema = schema.clone();
+        let mapped = reader(&[1, 2, 3]).map_batches(schema.clone(), move |batch| {
+            let b = batch.column(0).slice(0, batch.num_rows() - 1);
+            RecordBatch::try_new(mapped_schema.clone(), vec![b])
+        });
+        assert_eq!(mapped.schema(), schema);
+        let batches = mapped.collect::<Result<Vec<_>, _>>().unwrap();
+        assert!(batches.iter().all(|batch| batch.schema() == schema));
+        let rows: Vec<_> = batches.iter().map(|batch| batch.num_rows()).collect();
+        assert_eq!(rows, vec![0, 1, 2]);
+
+        let reader = reader(&[1, 2]);
+        let schema = reader.schema();
+        let mut failing = reader.map_batches(schema, |batch| match batch.num_rows() {
+            1 => Ok(batch),
+            _ => Err(ArrowError::ComputeError("fail".to_string())),
+        });
+        assert!(failing.next().unwrap().is_ok());
+        assert!(failing.next().unwrap().is_err());
+        assert!(failing.next().is_none());
+    }
+
+    #[test]
+    fn test_filter_take_batches() {
+        let filtered = reader(&[0, 1, 0, 2, 3]).filter_batches(|b| Ok(b.num_rows() > 0));
+        assert_eq!(filtered.schema().field(0).name(), "a");
+        assert_eq!(rows(filtered), vec![1, 2, 3]);
+
+        let taken = reader(&[1, 2, 3]).take_batches(2);
+        assert_eq!(taken.size_hint(), (2, Some(2)));
+        assert_eq!(rows(taken), vec![1, 2]);
+
+        let taken = reader(&[1]).take_batches(2);
+        assert_eq!(taken.size_hint(), (1, Some(1)));
+        assert_eq!(rows(taken), vec![1]);
+
+        let mut failing = reader(&[1, 2])
+            .filter_batches(|_| Err(ArrowError::ComputeError("fail".to_string())));
+        assert!(failing.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_peekable_batches() {
+        let mut peekable = reader(&[1, 2]).peekable_batches();
+        assert_eq!(peekable.peek().unwrap().as_ref().unwrap().num_rows(), 1);
+        assert_eq!(peekable.peek().unwrap().as_ref().unwrap().num_rows(), 1);
+        assert_eq!(peekable.size_hint(), (2, Some(2)));
+        assert_eq!(peekable.next().unwrap().unwrap().num_rows(), 1);
+        assert_eq!(peekable.next().unwrap().unwrap().num_rows(), 2);
+        assert!(peekable.peek().is_none());
+        assert_eq!(peekable.size_hint(), (0, Some(0)));
+        assert!(peekable.next().is_none());
+    }
+
+    #[test]
+    fn test_fail_after() {
+        let error = ArrowError::IoError("injected".to_string());
+        let mut failing = reader(&[1, 2, 3]).fail_after(1, error);
+        assert_eq!(failing.next().unwrap().unwrap().num_rows(), 1);
+        let error = failing.next().unwrap().unwrap_err();
+        assert_eq!(error.to_string(), "Io error: injected");
+        assert!(failing.next().is_none());
+    }
+}
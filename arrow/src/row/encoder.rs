@@ -0,0 +1,177 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::array::ArrayRef;
+use crate::error::Result;
+use crate::row::{RowConverter, Rows};
+
+/// A push-based encoder that converts a stream of column chunks into [`Rows`] of
+/// `batch_size` rows, without requiring all input up front
+///
+/// Chunks of any length can be pushed with [`RowEncoder::push`], and the completed
+/// batches of exactly `batch_size` rows retrieved with [`RowEncoder::next_batch`]
+/// as soon as they are available. At the end of the input, [`RowEncoder::finish`]
+/// completes a final batch of any remaining rows. This allows an operator, such as
+/// a streaming sort, to pipeline the conversion of its input with its processing.
+///
+/// All batches are produced by the same [`RowConverter`], and can therefore be
+/// compared with each other and converted back with [`RowEncoder::converter`].
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{ArrayRef, Int32Array};
+/// # use arrow::datatypes::DataType;
+/// # use arrow::row::{RowConverter, RowEncoder, SortField};
+/// let converter = RowConverter::new(vec![SortField::new(DataType::Int32)]);
+/// let mut encoder = RowEncoder::new(converter, 4);
+///
+/// let chunk: ArrayRef = Arc::new(Int32Array::from_iter_values(0..6));
+/// encoder.push(&[chunk]).unwrap();
+///
+/// // A batch is complete, the remaining 2 rows are buffered
+/// let batch = encoder.next_batch().unwrap();
+/// assert_eq!(batch.num_rows(), 4);
+/// assert!(encoder.next_batch().is_none());
+/// assert_eq!(encoder.buffered_rows(), 2);
+///
+/// let chunk: ArrayRef = Arc::new(Int32Array::from_iter_values(6..9));
+/// encoder.push(&[chunk]).unwrap();
+/// assert_eq!(encoder.next_batch().unwrap().num_rows(), 4);
+///
+/// // Complete the remaining rows at the end of the input
+/// encoder.finish();
+/// let last = encoder.next_batch().unwrap();
+/// assert_eq!(last.num_rows(), 1);
+/// assert!(batch.row(3) < last.row(0));
+///
+/// let converted = encoder.converter().convert_rows(&last).unwrap();
+/// assert_eq!(converted[0].as_ref(), &Int32Array::from(vec![8]));
+/// ```
+#[derive(Debug)]
+pub struct RowEncoder {
+    converter: RowConverter,
+    batch_size: usize,
+    /// The batch currently being filled, containing fewer than `batch_size` rows
+    in_progress: Rows,
+    /// The completed batches not yet returned by [`RowEncoder::next_batch`]
+    completed: VecDeque<Rows>,
+}
+
+impl RowEncoder {
+    /// Create a new [`RowEncoder`] producing batches of `batch_size` rows with
+    /// `converter`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero
+    pub fn new(converter: RowConverter, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "batch_size must be greater than 0");
+        let in_progress = converter.empty_rows(batch_size, 0);
+        Self {
+            converter,
+            batch_size,
+            in_progress,
+            completed: VecDeque::new(),
+        }
+    }
+
+    /// Encodes a chunk of `columns`, completing any batches that become full
+    ///
+    /// Returns an error if `columns` do not match the schema of the [`RowConverter`],
+    /// in which case none of its rows are buffered
+    pub fn push(&mut self, columns: &[ArrayRef]) -> Result<()> {
+        self.converter.append(&mut self.in_progress, columns)?;
+
+        let num_rows = self.in_progress.num_rows();
+        if num_rows < self.batch_size {
+            return Ok(());
+        }
+
+        // Copy the rows after the first batch, which is then truncated in place
+        let mut batches = Vec::with_capacity(num_rows / self.batch_size);
+        let mut start = self.batch_size;
+        while start < num_rows {
+            let end = num_rows.min(start + self.batch_size);
+            batches.push(copy_rows(&self.in_progress, start..end));
+            start = end;
+        }
+        let first_end = self.in_progress.offsets[self.batch_size];
+        self.in_progress.offsets.truncate(self.batch_size + 1);
+        self.in_progress.buffer.truncate(first_end);
+        if let Some(hashes) = &mut self.in_progress.hashes {
+            hashes.truncate(self.batch_size);
+        }
+
+        let next = match batches.last() {
+            Some(last) if last.num_rows() < self.batch_size => batches.pop().unwrap(),
+            _ => self.converter.empty_rows(self.batch_size, first_end),
+        };
+        self.completed
+            .push_back(std::mem::replace(&mut self.in_progress, next));
+        self.completed.extend(batches);
+        Ok(())
+    }
+
+    /// Marks the end of the input, completing the batch of any remaining rows, which
+    /// may contain fewer than `batch_size` rows
+    pub fn finish(&mut self) {
+        if self.in_progress.num_rows() != 0 {
+            let next = self.converter.empty_rows(self.batch_size, 0);
+            let batch = std::mem::replace(&mut self.in_progress, next);
+            self.completed.push_back(batch);
+        }
+    }
+
+    /// Returns the next completed batch, if any
+    pub fn next_batch(&mut self) -> Option<Rows> {
+        self.completed.pop_front()
+    }
+
+    /// Returns the number of rows pushed but not yet part of a completed batch
+    pub fn buffered_rows(&self) -> usize {
+        self.in_progress.num_rows()
+    }
+
+    /// Returns the [`RowConverter`] used to encode the rows
+    pub fn converter(&self) -> &RowConverter {
+        &self.converter
+    }
+
+    /// Consumes this [`RowEncoder`] returning the [`RowConverter`]
+    pub fn into_inner(self) -> RowConverter {
+        self.converter
+    }
+}
+
+/// Returns a copy of the rows of `rows` within `range`
+fn copy_rows(rows: &Rows, range: Range<usize>) -> Rows {
+    let start = rows.offsets[range.start];
+    let end = rows.offsets[range.end];
+    Rows {
+        buffer: rows.buffer[start..end].to_vec(),
+        offsets: rows.offsets[range.start..=range.end]
+            .iter()
+            .map(|offset| offset - start)
+            .collect(),
+        hashes: rows.hashes.as_ref().map(|h| h[range].to_vec()),
+        fields: Arc::clone(&rows.fields),
+    }
+}
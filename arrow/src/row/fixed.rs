@@ -18,9 +18,9 @@
 use crate::array::PrimitiveArray;
 use crate::compute::SortOptions;
 use crate::datatypes::ArrowPrimitiveType;
-use crate::row::{null_sentinel, Rows};
+use crate::row::null_sentinel;
 use arrow_array::types::DecimalType;
-use arrow_array::{BooleanArray, DecimalArray};
+use arrow_array::{Array, BooleanArray, DecimalArray};
 use arrow_buffer::{bit_util, MutableBuffer, ToByteSlice};
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::DataType;
@@ -164,6 +164,39 @@ impl FixedLengthEncoding for f64 {
     }
 }
 
+/// A float type whose NaN values can be ordered before or after all other values
+pub trait FloatEncoding: FixedLengthEncoding {
+    /// Returns true if this is NaN
+    fn is_nan(self) -> bool;
+
+    /// Returns the NaN ordered after all other values if `largest`, otherwise the
+    /// NaN ordered before all other values, by [`FixedLengthEncoding::encode`]
+    fn canonical_nan(largest: bool) -> Self;
+}
+
+macro_rules! float_encoding {
+    ($t:ty, $bits:ty) => {
+        impl FloatEncoding for $t {
+            fn is_nan(self) -> bool {
+                self.is_nan()
+            }
+
+            fn canonical_nan(largest: bool) -> Self {
+                match largest {
+                    // Positive NaN with all payload bits set
+                    true => Self::from_bits(<$bits>::MAX >> 1),
+                    // Negative NaN with all payload bits set
+                    false => Self::from_bits(<$bits>::MAX),
+                }
+            }
+        }
+    };
+}
+
+float_encoding!(f16, u16);
+float_encoding!(f32, u32);
+float_encoding!(f64, u64);
+
 pub type RawDecimal128 = RawDecimal<16>;
 pub type RawDecimal256 = RawDecimal<32>;
 
@@ -207,31 +240,120 @@ where
 
 /// Fixed width types are encoded as
 ///
-/// - 1 byte `0` if null or `1` if valid
+/// - 1 byte `0` if null or `1` if valid, omitted if not `nullable`
 /// - bytes of [`FixedLengthEncoding`]
+///
+/// If not `nullable`, `i` must not contain any nulls
 pub fn encode<T: FixedLengthEncoding, I: IntoIterator<Item = Option<T>>>(
-    out: &mut Rows,
+    data: &mut [u8],
+    offsets: &mut [usize],
     i: I,
     opts: SortOptions,
+    nullable: bool,
 ) {
-    for (offset, maybe_val) in out.offsets.iter_mut().skip(1).zip(i) {
-        let end_offset = *offset + T::ENCODED_LEN;
+    let null_len = nullable as usize;
+    for (offset, maybe_val) in offsets.iter_mut().skip(1).zip(i) {
+        let end_offset = *offset + T::ENCODED_LEN - 1 + null_len;
         if let Some(val) = maybe_val {
-            let to_write = &mut out.buffer[*offset..end_offset];
-            to_write[0] = 1;
+            let to_write = &mut data[*offset..end_offset];
+            if nullable {
+                to_write[0] = 1;
+            }
             let mut encoded = val.encode();
             if opts.descending {
                 // Flip bits to reverse order
                 encoded.as_mut().iter_mut().for_each(|v| *v = !*v)
             }
-            to_write[1..].copy_from_slice(encoded.as_ref())
+            to_write[null_len..].copy_from_slice(encoded.as_ref())
         } else {
-            out.buffer[*offset] = null_sentinel(opts);
+            debug_assert!(nullable, "null value in non-nullable column");
+            data[*offset] = null_sentinel(opts);
         }
         *offset = end_offset;
     }
 }
 
+/// Encodes the values of `array` as [`encode`]
+///
+/// Rather than encoding value by value, all values are first encoded in a branch-free
+/// loop regardless of validity, with the rows of any nulls then overwritten
+pub fn encode_primitive<T>(
+    data: &mut [u8],
+    offsets: &mut [usize],
+    array: &PrimitiveArray<T>,
+    opts: SortOptions,
+    nullable: bool,
+) where
+    T: ArrowPrimitiveType,
+    T::Native: FixedLengthEncoding,
+{
+    let null_len = nullable as usize;
+    let len = T::Native::ENCODED_LEN - 1 + null_len;
+    // XOR-ing with this mask flips the bits if descending
+    let mask = match opts.descending {
+        true => u8::MAX,
+        false => 0,
+    };
+
+    for (offset, value) in offsets.iter_mut().skip(1).zip(array.values()) {
+        let to_write = &mut data[*offset..*offset + len];
+        let mut encoded = value.encode();
+        encoded.as_mut().iter_mut().for_each(|v| *v ^= mask);
+        if nullable {
+            to_write[0] = 1;
+        }
+        to_write[null_len..].copy_from_slice(encoded.as_ref());
+        *offset += len;
+    }
+
+    let nulls = match array.data().null_buffer() {
+        Some(nulls) if array.null_count() != 0 => nulls,
+        _ => return,
+    };
+    debug_assert!(nullable, "null value in non-nullable column");
+
+    let sentinel = null_sentinel(opts);
+    let mut write_null = |idx: usize| {
+        let end = offsets[idx + 1];
+        let row = &mut data[end - len..end];
+        row[0] = sentinel;
+        row[1..].fill(0);
+    };
+
+    let chunks = nulls.bit_chunks(array.offset(), array.len());
+    let remainder = std::iter::once(chunks.remainder_bits());
+    let valid_chunks = chunks.iter().chain(remainder);
+    for (chunk_idx, valid) in valid_chunks.enumerate() {
+        // Ignore the unset bits of the remainder beyond the end of `array`
+        let mut null_bits = match chunk_idx == chunks.chunk_len() {
+            true => !valid & ((1 << chunks.remainder_len()) - 1),
+            false => !valid,
+        };
+        while null_bits != 0 {
+            write_null(chunk_idx * 64 + null_bits.trailing_zeros() as usize);
+            null_bits &= null_bits - 1;
+        }
+    }
+}
+
+/// Encodes floats as [`encode`], replacing any NaN with [`FloatEncoding::canonical_nan`]
+/// so that all NaN are ordered after all other values if `nan_largest`, otherwise
+/// before them
+pub fn encode_float<T: FloatEncoding, I: IntoIterator<Item = Option<T>>>(
+    data: &mut [u8],
+    offsets: &mut [usize],
+    i: I,
+    opts: SortOptions,
+    nullable: bool,
+    nan_largest: bool,
+) {
+    let nan = T::canonical_nan(nan_largest);
+    let iter = i
+        .into_iter()
+        .map(|v| v.map(|v| if v.is_nan() { nan } else { v }));
+    encode(data, offsets, iter, opts, nullable)
+}
+
 /// Splits `len` bytes from `src`
 #[inline]
 fn split_off<'a>(src: &mut &'a [u8], len: usize) -> &'a [u8] {
@@ -240,12 +362,17 @@ fn split_off<'a>(src: &mut &'a [u8], len: usize) -> &'a [u8] {
     v
 }
 
-/// Decodes a `BooleanArray` from rows
-pub fn decode_bool(rows: &mut [&[u8]], options: SortOptions) -> BooleanArray {
+/// Decodes a `BooleanArray` from rows, that have no null byte if not `nullable`
+pub fn decode_bool(
+    rows: &mut [&[u8]],
+    options: SortOptions,
+    nullable: bool,
+) -> BooleanArray {
     let true_val = match options.descending {
         true => !1,
         false => 1,
     };
+    let null_len = nullable as usize;
 
     let len = rows.len();
 
@@ -260,8 +387,8 @@ pub fn decode_bool(rows: &mut [&[u8]], options: SortOptions) -> BooleanArray {
         let mut values_packed = 0;
 
         for bit_idx in 0..64 {
-            let i = split_off(&mut rows[bit_idx + chunk * 64], 2);
-            let (null, value) = (i[0] == 1, i[1] == true_val);
+            let i = split_off(&mut rows[bit_idx + chunk * 64], 1 + null_len);
+            let (null, value) = (!nullable || i[0] == 1, i[null_len] == true_val);
             null_count += !null as usize;
             null_packed |= (null as u64) << bit_idx;
             values_packed |= (value as u64) << bit_idx;
@@ -276,8 +403,8 @@ pub fn decode_bool(rows: &mut [&[u8]], options: SortOptions) -> BooleanArray {
         let mut values_packed = 0;
 
         for bit_idx in 0..remainder {
-            let i = split_off(&mut rows[bit_idx + chunks * 64], 2);
-            let (null, value) = (i[0] == 1, i[1] == true_val);
+            let i = split_off(&mut rows[bit_idx + chunks * 64], 1 + null_len);
+            let (null, value) = (!nullable || i[0] == 1, i[null_len] == true_val);
             null_count += !null as usize;
             null_packed |= (null as u64) << bit_idx;
             values_packed |= (value as u64) << bit_idx;
@@ -291,20 +418,24 @@ pub fn decode_bool(rows: &mut [&[u8]], options: SortOptions) -> BooleanArray {
         .len(rows.len())
         .null_count(null_count)
         .add_buffer(values.into())
-        .null_bit_buffer(Some(nulls.into()));
+        .null_bit_buffer(nullable.then(|| nulls.into()));
 
     // SAFETY:
     // Buffers are the correct length
     unsafe { BooleanArray::from(builder.build_unchecked()) }
 }
 
-/// Decodes a `ArrayData` from rows based on the provided `FixedLengthEncoding` `T`
+/// Decodes a `ArrayData` from rows based on the provided `FixedLengthEncoding` `T`,
+/// that have no null byte if not `nullable`
 fn decode_fixed<T: FixedLengthEncoding + ToByteSlice>(
     rows: &mut [&[u8]],
     data_type: DataType,
     options: SortOptions,
+    nullable: bool,
 ) -> ArrayData {
     let len = rows.len();
+    let null_len = nullable as usize;
+    let encoded_len = T::ENCODED_LEN - 1 + null_len;
 
     let mut null_count = 0;
     let mut nulls = MutableBuffer::new(bit_util::ceil(len, 64) * 8);
@@ -316,12 +447,12 @@ fn decode_fixed<T: FixedLengthEncoding + ToByteSlice>(
         let mut null_packed = 0;
 
         for bit_idx in 0..64 {
-            let i = split_off(&mut rows[bit_idx + chunk * 64], T::ENCODED_LEN);
-            let null = i[0] == 1;
+            let i = split_off(&mut rows[bit_idx + chunk * 64], encoded_len);
+            let null = !nullable || i[0] == 1;
             null_count += !null as usize;
             null_packed |= (null as u64) << bit_idx;
 
-            let value = T::Encoded::from_slice(&i[1..], options.descending);
+            let value = T::Encoded::from_slice(&i[null_len..], options.descending);
             values.push(T::decode(value));
         }
 
@@ -332,12 +463,12 @@ fn decode_fixed<T: FixedLengthEncoding + ToByteSlice>(
         let mut null_packed = 0;
 
         for bit_idx in 0..remainder {
-            let i = split_off(&mut rows[bit_idx + chunks * 64], T::ENCODED_LEN);
-            let null = i[0] == 1;
+            let i = split_off(&mut rows[bit_idx + chunks * 64], encoded_len);
+            let null = !nullable || i[0] == 1;
             null_count += !null as usize;
             null_packed |= (null as u64) << bit_idx;
 
-            let value = T::Encoded::from_slice(&i[1..], options.descending);
+            let value = T::Encoded::from_slice(&i[null_len..], options.descending);
             values.push(T::decode(value));
         }
 
@@ -348,30 +479,32 @@ fn decode_fixed<T: FixedLengthEncoding + ToByteSlice>(
         .len(rows.len())
         .null_count(null_count)
         .add_buffer(values.into())
-        .null_bit_buffer(Some(nulls.into()));
+        .null_bit_buffer(nullable.then(|| nulls.into()));
 
     // SAFETY: Buffers correct length
     unsafe { builder.build_unchecked() }
 }
 
-/// Decodes a `DecimalArray` from rows
+/// Decodes a `DecimalArray` from rows, that have no null byte if not `nullable`
 pub fn decode_decimal<const N: usize, T: DecimalType>(
     rows: &mut [&[u8]],
     options: SortOptions,
+    nullable: bool,
     precision: u8,
     scale: u8,
 ) -> DecimalArray<T> {
-    decode_fixed::<RawDecimal<N>>(rows, T::TYPE_CONSTRUCTOR(precision, scale), options)
-        .into()
+    let data_type = T::TYPE_CONSTRUCTOR(precision, scale);
+    decode_fixed::<RawDecimal<N>>(rows, data_type, options, nullable).into()
 }
 
-/// Decodes a `PrimitiveArray` from rows
+/// Decodes a `PrimitiveArray` from rows, that have no null byte if not `nullable`
 pub fn decode_primitive<T: ArrowPrimitiveType>(
     rows: &mut [&[u8]],
     options: SortOptions,
+    nullable: bool,
 ) -> PrimitiveArray<T>
 where
     T::Native: FixedLengthEncoding + ToByteSlice,
 {
-    decode_fixed::<T::Native>(rows, T::DATA_TYPE, options).into()
+    decode_fixed::<T::Native>(rows, T::DATA_TYPE, options, nullable).into()
 }
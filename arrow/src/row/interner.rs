@@ -161,6 +161,17 @@ impl OrderPreservingInterner {
     pub fn value(&self, key: Interned) -> &[u8] {
         self.values.index(key)
     }
+
+    /// Returns the size of this instance in bytes including self
+    pub fn size(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + self.keys.size()
+            + self.values.size()
+            + self.bucket.size()
+            // Approximates the allocation of the hash table as its capacity, as the
+            // number of buckets is not exposed, plus a control byte per entry
+            + self.lookup.capacity() * (std::mem::size_of::<Interned>() + 1)
+    }
 }
 
 /// A buffer of `[u8]` indexed by `[Interned]`
@@ -196,6 +207,11 @@ impl InternBuffer {
         self.offsets.push(self.values.len());
         key
     }
+
+    /// Returns the byte size of the associated buffers
+    fn size(&self) -> usize {
+        self.values.capacity() + self.offsets.capacity() * std::mem::size_of::<usize>()
+    }
 }
 
 impl Index<Interned> for InternBuffer {
@@ -406,6 +422,18 @@ impl Bucket {
             }
         }
     }
+
+    /// Returns the size of this instance in bytes, including any child buckets
+    fn size(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + self.slots.len() * std::mem::size_of::<Slot>()
+            + self
+                .slots
+                .iter()
+                .filter_map(|slot| slot.child.as_ref())
+                .map(|child| child.size())
+                .sum::<usize>()
+    }
 }
 
 #[cfg(test)]
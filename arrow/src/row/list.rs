@@ -0,0 +1,294 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::compute::{interleave, SortOptions};
+use crate::error::Result;
+use crate::row::{null_sentinel, variable, Row, RowConverter, Rows, SortField};
+use arrow_array::builder::BufferBuilder;
+use arrow_array::{new_empty_array, Array, FixedSizeListArray, OffsetSizeTrait};
+use arrow_buffer::MutableBuffer;
+use arrow_data::{ArrayData, ArrayDataBuilder};
+use arrow_schema::DataType;
+use std::ops::Range;
+
+/// Indicates a non-null list
+pub const LIST_SENTINEL: u8 = 1;
+
+/// Indicates the end of the elements of a list
+pub const LIST_TERMINATOR: u8 = 0;
+
+/// Returns the [`SortField`] of the elements of a list column sorted with `options`,
+/// the elements of a map being its entries
+///
+/// The elements are inverted along with the rest of the list if `descending`, and so
+/// are encoded in ascending order with the null ordering flipped accordingly
+pub fn element_field(field: &SortField) -> Option<SortField> {
+    let options = field.options;
+    let options = SortOptions {
+        descending: false,
+        nulls_first: options.nulls_first != options.descending,
+    };
+    match &field.data_type {
+        DataType::List(f) | DataType::LargeList(f) | DataType::Map(f, _) => {
+            let element = SortField::new_with_options(f.data_type().clone(), options);
+            Some(element.with_nan_ordering(field.nan_ordering))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the range of the elements of slot `idx` of `array` with `offsets`, if it
+/// is not null
+fn element_range<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    offsets: &[O],
+    idx: usize,
+) -> Option<Range<usize>> {
+    array
+        .is_valid(idx)
+        .then(|| offsets[idx].as_usize()..offsets[idx + 1].as_usize())
+}
+
+/// Adds the length of the encoded lists of `array` with `offsets` to `lengths`,
+/// given the encoded `rows` of its elements
+pub fn compute_lengths<O: OffsetSizeTrait>(
+    lengths: &mut [usize],
+    rows: &Rows,
+    array: &dyn Array,
+    offsets: &[O],
+) {
+    for (idx, length) in lengths.iter_mut().enumerate() {
+        *length += match element_range(array, offsets, idx) {
+            Some(range) => {
+                let elements: usize = range
+                    .map(|i| variable::encoded_len(Some(rows.row(i).data)))
+                    .sum();
+                2 + elements
+            }
+            None => 1,
+        }
+    }
+}
+
+/// Lists are encoded as
+///
+/// - single `0_u8` if null
+/// - `1_u8` if not null, followed by each of its elements encoded as a byte array
+///   containing their row, see [`variable::encode_one`], and a `0_u8` terminator
+///
+/// The encoded elements and the terminator are inverted if descending
+pub fn encode<O: OffsetSizeTrait>(
+    data: &mut [u8],
+    offsets: &mut [usize],
+    rows: &Rows,
+    opts: SortOptions,
+    array: &dyn Array,
+    value_offsets: &[O],
+) {
+    let terminator = match opts.descending {
+        true => !LIST_TERMINATOR,
+        false => LIST_TERMINATOR,
+    };
+
+    for (idx, offset) in offsets.iter_mut().skip(1).enumerate() {
+        match element_range(array, value_offsets, idx) {
+            Some(range) => {
+                data[*offset] = LIST_SENTINEL;
+                *offset += 1;
+                for i in range {
+                    let to_write = &mut data[*offset..];
+                    *offset +=
+                        variable::encode_one(to_write, Some(rows.row(i).data), opts);
+                }
+                data[*offset] = terminator;
+                *offset += 1;
+            }
+            None => {
+                data[*offset] = null_sentinel(opts);
+                *offset += 1;
+            }
+        }
+    }
+}
+
+/// Decodes a list or map array from `rows` with the provided `field`, using
+/// `converter` to decode its elements
+///
+/// # Safety
+///
+/// `rows` must contain valid data for the provided `field` and `converter`
+pub unsafe fn decode<O: OffsetSizeTrait>(
+    converter: &RowConverter,
+    rows: &mut [&[u8]],
+    field: &SortField,
+) -> Result<ArrayData> {
+    let options = field.options;
+    let terminator = match options.descending {
+        true => !LIST_TERMINATOR,
+        false => LIST_TERMINATOR,
+    };
+
+    let len = rows.len();
+    let mut null_count = 0;
+    let nulls = MutableBuffer::collect_bool(len, |x| {
+        let valid = rows[x][0] != null_sentinel(options);
+        null_count += !valid as usize;
+        valid
+    });
+
+    let mut offsets = BufferBuilder::<O>::new(len + 1);
+    offsets.append(O::zero());
+    let mut values = MutableBuffer::new(0);
+    let mut element_offsets = vec![0];
+
+    for row in rows.iter_mut() {
+        let mut offset = 1;
+        if row[0] == LIST_SENTINEL {
+            while row[offset] != terminator {
+                offset += variable::decode_one(&row[offset..], options, &mut values);
+                element_offsets.push(values.len());
+            }
+            offset += 1;
+        }
+        *row = &row[offset..];
+        offsets
+            .append(O::from_usize(element_offsets.len() - 1).expect("offset overflow"));
+    }
+
+    if options.descending {
+        values.as_slice_mut().iter_mut().for_each(|o| *o = !*o)
+    }
+
+    let mut element_rows: Vec<_> = element_offsets
+        .windows(2)
+        .map(|w| &values[w[0]..w[1]])
+        .collect();
+    let mut child = converter.convert_raw(&mut element_rows)?;
+
+    let builder = ArrayDataBuilder::new(field.data_type.clone())
+        .len(len)
+        .null_count(null_count)
+        .null_bit_buffer(Some(nulls.into()))
+        .add_buffer(offsets.finish())
+        .add_child_data(child.remove(0).data().clone());
+
+    // SAFETY:
+    // Valid by construction above
+    Ok(builder.build_unchecked())
+}
+
+/// Adds the length of the encoded lists of the fixed size list `array` to `lengths`,
+/// given the encoded `rows` of its elements and the row of a `null` element
+pub fn compute_fixed_size_lengths(
+    lengths: &mut [usize],
+    rows: &Rows,
+    null: Row<'_>,
+    array: &FixedSizeListArray,
+) {
+    let size = array.value_length() as usize;
+    for (idx, length) in lengths.iter_mut().enumerate() {
+        *length += match array.is_valid(idx) {
+            true => {
+                let start = array.value_offset(idx) as usize;
+                let elements: usize =
+                    (start..start + size).map(|i| rows.row(i).data.len()).sum();
+                1 + elements
+            }
+            false => 1 + size * null.data.len(),
+        }
+    }
+}
+
+/// Fixed size lists are encoded as
+///
+/// - single `0_u8` if null, followed by the row of a `null` element for each element
+/// - `1_u8` if not null, followed by the rows of each of its elements
+pub fn encode_fixed_size(
+    data: &mut [u8],
+    offsets: &mut [usize],
+    rows: &Rows,
+    null: Row<'_>,
+    opts: SortOptions,
+    array: &FixedSizeListArray,
+) {
+    let size = array.value_length() as usize;
+    for (idx, offset) in offsets.iter_mut().skip(1).enumerate() {
+        let start = array.value_offset(idx) as usize;
+        let (sentinel, elements) = match array.is_valid(idx) {
+            true => (LIST_SENTINEL, start..start + size),
+            false => (null_sentinel(opts), 0..0),
+        };
+        data[*offset] = sentinel;
+        *offset += 1;
+
+        let elements = elements.map(|i| rows.row(i));
+        let nulls = std::iter::repeat(null).take(size - elements.len());
+        for row in elements.chain(nulls) {
+            let end_offset = *offset + row.data.len();
+            data[*offset..end_offset].copy_from_slice(row.data);
+            *offset = end_offset;
+        }
+    }
+}
+
+/// Decodes a fixed size list array of `size` elements from `rows` with the provided
+/// `field`, using `converter` to decode its elements
+///
+/// # Safety
+///
+/// `rows` must contain valid data for the provided `field` and `converter`
+pub unsafe fn decode_fixed_size(
+    converter: &RowConverter,
+    rows: &mut [&[u8]],
+    field: &SortField,
+    size: usize,
+) -> Result<ArrayData> {
+    let len = rows.len();
+    let mut null_count = 0;
+    let nulls = MutableBuffer::collect_bool(len, |x| {
+        let valid = rows[x][0] != null_sentinel(field.options);
+        null_count += !valid as usize;
+        valid
+    });
+    rows.iter_mut().for_each(|row| *row = &row[1..]);
+
+    // The end of an element is only known once it is decoded, so the elements
+    // are decoded one position at a time for all the lists, and then interleaved
+    let positions = (0..size)
+        .map(|_| Ok(converter.convert_raw(rows)?.remove(0)))
+        .collect::<Result<Vec<_>>>()?;
+    let child = match positions.is_empty() {
+        true => new_empty_array(&converter.fields[0].data_type),
+        false => {
+            let positions: Vec<_> = positions.iter().map(|p| p.as_ref()).collect();
+            let indices: Vec<_> = (0..len)
+                .flat_map(|i| (0..size).map(move |position| (position, i)))
+                .collect();
+            interleave(&positions, &indices)?
+        }
+    };
+
+    let builder = ArrayDataBuilder::new(field.data_type.clone())
+        .len(len)
+        .null_count(null_count)
+        .null_bit_buffer(Some(nulls.into()))
+        .add_child_data(child.data().clone());
+
+    // SAFETY:
+    // Valid by construction above
+    Ok(builder.build_unchecked())
+}
@@ -23,8 +23,11 @@ use std::sync::Arc;
 
 use arrow_array::cast::*;
 use arrow_array::*;
+use arrow_buffer::MutableBuffer;
+use arrow_data::ArrayDataBuilder;
 
-use crate::compute::SortOptions;
+use crate::buffer::Buffer;
+use crate::compute::{interleave, Selection, SortOptions};
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::row::dictionary::{
@@ -38,9 +41,14 @@ use crate::row::interner::OrderPreservingInterner;
 use crate::row::variable::{decode_binary, decode_string};
 use crate::{downcast_dictionary_array, downcast_primitive_array};
 
+pub use encoder::RowEncoder;
+
 mod dictionary;
+mod encoder;
 mod fixed;
 mod interner;
+mod list;
+mod validate;
 mod variable;
 
 /// Converts [`ArrayRef`] columns into a row-oriented format that are [normalized for sorting].
@@ -77,6 +85,12 @@ mod variable;
 ///
 /// They are then encoded in the same manner as a signed integer
 ///
+/// ## Non-Nullable Fixed Width Encoding
+///
+/// The leading null byte of the above fixed width encodings, and of booleans and
+/// decimals, is omitted for a [`SortField`] declared not nullable with
+/// [`SortField::with_nullable`]
+///
 /// ## Variable Length Bytes Encoding
 ///
 /// A null is encoded as a `0_u8`
@@ -86,10 +100,41 @@ mod variable;
 /// A non-null, non-empty byte array is encoded as `2_u8` followed by the byte array
 /// encoded using a block based scheme described below.
 ///
-/// The byte array is broken up into 32-byte blocks, each block is written in turn
-/// to the output, followed by `0xFF_u8`. The final block is padded to 32-bytes
+/// The byte array is broken up into fixed-width blocks, each block is written in turn
+/// to the output, followed by `0xFF_u8`. The final block is padded to the block size
 /// with `0_u8` and written to the output, followed by the un-padded length in bytes
-/// of this final block as a `u8`
+/// of this final block as a `u8`. The first 4 blocks have a length of 8, with
+/// subsequent blocks using a length of 32, this is to reduce space amplification for
+/// small strings.
+///
+/// Note the following example encodings use a block size of 4 bytes and no mini
+/// blocks for brevity:
+///
+/// ```text
+///                       ┌───┬───┬───┬───┬───┬───┐
+///  "MEEP"               │02 │'M'│'E'│'E'│'P'│04 │
+///                       └───┴───┴───┴───┴───┴───┘
+///
+///                       ┌───┐
+///  ""                   │01 |
+///                       └───┘
+///
+///  NULL                 ┌───┐
+///                       │00 │
+///                       └───┘
+///
+/// "Defenestration"      ┌───┬───┬───┬───┬───┬───┐
+///                       │02 │'D'│'e'│'f'│'e'│FF │
+///                       └───┼───┼───┼───┼───┼───┤
+///                           │'n'│'e'│'s'│'t'│FF │
+///                           ├───┼───┼───┼───┼───┤
+///                           │'r'│'a'│'t'│'r'│FF │
+///                           ├───┼───┼───┼───┼───┤
+///                           │'a'│'t'│'i'│'o'│FF │
+///                           ├───┼───┼───┼───┼───┤
+///                           │'n'│00 │00 │00 │01 │
+///                           └───┴───┴───┴───┴───┘
+/// ```
 ///
 /// This is loosely inspired by [COBS] encoding, and chosen over more traditional
 /// [byte stuffing] as it is more amenable to vectorisation, in particular AVX-256.
@@ -110,6 +155,50 @@ mod variable;
 /// A non-null dictionary value is encoded as `1_u8` followed by a null-terminated byte array
 /// key determined by the order-preserving dictionary encoding
 ///
+/// ## Struct Encoding
+///
+/// The children of a struct column are converted to rows by a [`RowConverter`] for its
+/// fields, sorted with the struct's [`SortOptions`].
+///
+/// A null struct is encoded as `0_u8`, followed by the row of a struct whose fields are
+/// all null, so that all null structs are equal regardless of their children.
+///
+/// A non-null struct is encoded as `1_u8` followed by the row of its children.
+///
+/// ## Fixed Size List Encoding
+///
+/// The elements of a fixed size list column are converted to rows by a [`RowConverter`]
+/// for its child type, sorted with the list's [`SortOptions`].
+///
+/// A null list is encoded as `0_u8`, followed by the row of a null element repeated
+/// for each element of the list.
+///
+/// A non-null list is encoded as `1_u8`, followed by the rows of its elements. As all
+/// lists have the same number of elements, these need no delimiter, and the encoding
+/// of a list of fixed width values, such as primitives, is itself fixed width.
+///
+/// ## Union Encoding
+///
+/// The children of a union column are each converted to rows by a [`RowConverter`] for
+/// their type, sorted with the union's [`SortOptions`].
+///
+/// A union value is encoded as its type id as a `u8`, followed by the row of its value in
+/// the child of that type id. Unions are therefore ordered by type id, and then by value,
+/// and as unions have no null bitmap of their own, a null value is ordered among the
+/// values of its type id.
+///
+/// ## List Encoding
+///
+/// The elements of all the lists of a column are first converted to rows by a
+/// [`RowConverter`] for the list's child type.
+///
+/// A null list is encoded as `0_u8`.
+///
+/// A non-null list is encoded as `1_u8`, followed by the row of each of its elements
+/// encoded as a byte array, as described in [Variable Length Bytes Encoding], and a
+/// terminating `0_u8`. As each element starts with either `1_u8` or `2_u8`, a list
+/// orders before any other list of which it is a prefix.
+///
 /// # Ordering
 ///
 /// ## Float Ordering
@@ -130,6 +219,10 @@ mod variable;
 ///
 /// The order of a given column can be reversed by negating the encoded bytes of non-null values
 ///
+/// The elements of a list are encoded in ascending order and negated along with the
+/// rest of the list, with their null ordering inverted so that null elements are
+/// ordered as requested once negated
+///
 /// ## Reconstruction
 ///
 /// Given a schema it would theoretically be possible to reconstruct the columnar data from
@@ -143,11 +236,201 @@ mod variable;
 /// [`memcmp`]:[https://www.man7.org/linux/man-pages/man3/memcmp.3.html]
 /// [COBS]:[https://en.wikipedia.org/wiki/Consistent_Overhead_Byte_Stuffing]
 /// [byte stuffing]:[https://en.wikipedia.org/wiki/High-Level_Data_Link_Control#Asynchronous_framing]
+/// [Variable Length Bytes Encoding]: #variable-length-bytes-encoding
 #[derive(Debug)]
 pub struct RowConverter {
     fields: Arc<[SortField]>,
-    /// interning state for column `i`, if column`i` is a dictionary
-    interners: Vec<Option<Box<OrderPreservingInterner>>>,
+    /// encoding state for column `i`
+    codecs: Vec<Codec>,
+    /// The maximum size in bytes of the interner of each dictionary column, if any
+    interner_limit: Option<usize>,
+    /// Whether to compute the hash of each row, see [`Rows::hashes`]
+    hashes: bool,
+}
+
+/// The hasher of [`Rows::hashes`], with fixed seeds so that equal rows have equal hashes
+const ROW_HASHER: ahash::RandomState = ahash::RandomState::with_seeds(
+    0x243F_6A88_85A3_08D3,
+    0x1319_8A2E_0370_7344,
+    0xA409_3822_299F_31D0,
+    0x082E_FA98_EC4E_6C89,
+);
+
+/// The state needed to encode and decode a column of a [`RowConverter`]
+#[derive(Debug)]
+enum Codec {
+    /// No state is needed
+    Stateless,
+    /// The interning state of a dictionary column
+    Dictionary(OrderPreservingInterner),
+    /// The converter of the children of a struct column, and the row of a
+    /// struct whose fields are all null, computed on first use
+    Struct(RowConverter, Option<Rows>),
+    /// The converter of the elements of a list column, or of the entries of a map column
+    List(RowConverter),
+    /// The converter of the elements of a fixed size list column, and the row of a
+    /// null element, computed on first use
+    FixedSizeList(RowConverter, Option<Rows>),
+    /// The converter of each child of a union column, by type id
+    Union(Vec<RowConverter>),
+}
+
+impl Codec {
+    fn new(field: &SortField) -> Self {
+        match &field.data_type {
+            DataType::Dictionary(_, _) => Self::Dictionary(Default::default()),
+            DataType::Struct(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|f| field.child(f.data_type().clone()))
+                    .collect();
+                Self::Struct(RowConverter::new(fields), None)
+            }
+            DataType::List(_) | DataType::LargeList(_) | DataType::Map(_, _) => {
+                let element = list::element_field(field).unwrap();
+                Self::List(RowConverter::new(vec![element]))
+            }
+            DataType::Union(fields, _, _) => {
+                let converters = fields
+                    .iter()
+                    .map(|f| RowConverter::new(vec![field.child(f.data_type().clone())]))
+                    .collect();
+                Self::Union(converters)
+            }
+            DataType::FixedSizeList(f, _) => {
+                let element = field.child(f.data_type().clone());
+                Self::FixedSizeList(RowConverter::new(vec![element]), None)
+            }
+            _ => Self::Stateless,
+        }
+    }
+
+    /// Returns the [`Encoder`] of `column`, updating the state as needed
+    ///
+    /// Returns an error if the size of a dictionary interner exceeds `interner_limit`
+    fn encoder(
+        &mut self,
+        column: &ArrayRef,
+        interner_limit: Option<usize>,
+    ) -> Result<Encoder<'_>> {
+        match self {
+            Codec::Stateless => Ok(Encoder::Stateless),
+            Codec::Dictionary(interner) => {
+                let values = downcast_dictionary_array! {
+                    column => column.values(),
+                    _ => unreachable!()
+                };
+
+                let mapping = compute_dictionary_mapping(interner, values)?;
+                if let Some(limit) = interner_limit {
+                    let size = interner.size();
+                    if size > limit {
+                        return Err(ArrowError::MemoryError(format!(
+                            "RowConverter dictionary interner size of {} bytes exceeds limit of {} bytes",
+                            size, limit
+                        )));
+                    }
+                }
+
+                let mapping = mapping
+                    .into_iter()
+                    .map(|maybe_interned| {
+                        maybe_interned.map(|interned| interner.normalized_key(interned))
+                    })
+                    .collect();
+
+                Ok(Encoder::Dictionary(mapping))
+            }
+            Codec::Struct(converter, null) => {
+                let null = match null {
+                    Some(null) => null,
+                    None => null.insert(converter.null_row()?),
+                };
+                let rows =
+                    converter.convert_columns(&as_struct_array(column).columns_ref())?;
+                Ok(Encoder::Struct(rows, null.row(0)))
+            }
+            Codec::FixedSizeList(converter, null) => {
+                let null = match null {
+                    Some(null) => null,
+                    None => null.insert(converter.null_row()?),
+                };
+                let values = as_fixed_size_list_array(column).values();
+                let rows = converter.convert_columns(&[values])?;
+                Ok(Encoder::FixedSizeList(rows, null.row(0)))
+            }
+            Codec::Union(converters) => {
+                let column = as_union_array(column);
+                let rows = converters
+                    .iter_mut()
+                    .enumerate()
+                    .map(|(type_id, c)| {
+                        c.convert_columns(&[column.child(type_id as i8).clone()])
+                    })
+                    .collect::<Result<_>>()?;
+                Ok(Encoder::Union(rows))
+            }
+            Codec::List(converter) => {
+                let values = match column.data_type() {
+                    DataType::List(_) => as_list_array(column).values(),
+                    DataType::LargeList(_) => as_large_list_array(column).values(),
+                    DataType::Map(_, _) => {
+                        make_array(column.data().child_data()[0].clone())
+                    }
+                    _ => unreachable!(),
+                };
+                let rows = converter.convert_columns(&[values])?;
+                Ok(Encoder::List(rows))
+            }
+        }
+    }
+
+    /// Sets the interner limit of any child [`RowConverter`]
+    fn set_interner_limit(&mut self, limit: Option<usize>) {
+        match self {
+            Codec::Stateless | Codec::Dictionary(_) => {}
+            Codec::Struct(converter, _)
+            | Codec::FixedSizeList(converter, _)
+            | Codec::List(converter) => converter.set_interner_limit(limit),
+            Codec::Union(converters) => converters
+                .iter_mut()
+                .for_each(|c| c.set_interner_limit(limit)),
+        }
+    }
+
+    /// Returns the size of the state of this [`Codec`] in bytes, excluding
+    /// `size_of::<Self>()`
+    fn size(&self) -> usize {
+        match self {
+            Codec::Stateless => 0,
+            Codec::Dictionary(interner) => interner.size(),
+            Codec::Struct(converter, null) | Codec::FixedSizeList(converter, null) => {
+                converter.size() + null.as_ref().map(|x| x.size()).unwrap_or_default()
+            }
+            Codec::List(converter) => converter.size(),
+            Codec::Union(converters) => {
+                converters.capacity() * std::mem::size_of::<RowConverter>()
+                    + converters.iter().map(|c| c.size()).sum::<usize>()
+            }
+        }
+    }
+}
+
+/// The per-batch data needed to encode a column, computed by [`Codec::encoder`]
+#[derive(Debug)]
+enum Encoder<'a> {
+    /// No data is needed
+    Stateless,
+    /// The normalized key of each dictionary value
+    Dictionary(Vec<Option<&'a [u8]>>),
+    /// The rows of the struct children, and the row of a struct whose fields are all null
+    Struct(Rows, Row<'a>),
+    /// The rows of the list elements or map entries
+    List(Rows),
+    /// The rows of the fixed size list elements, and the row of a null element
+    FixedSizeList(Rows, Row<'a>),
+    /// The rows of each child of a union, by type id
+    Union(Vec<Rows>),
 }
 
 /// Configure the data type and sort order for a given column
@@ -157,6 +440,10 @@ pub struct SortField {
     options: SortOptions,
     /// Data type
     data_type: DataType,
+    /// Whether the column may contain nulls
+    nullable: bool,
+    /// The ordering of NaN float values
+    nan_ordering: NanOrdering,
 }
 
 impl SortField {
@@ -167,20 +454,215 @@ impl SortField {
 
     /// Create a new column with the given data type and [`SortOptions`]
     pub fn new_with_options(data_type: DataType, options: SortOptions) -> Self {
-        Self { options, data_type }
+        Self {
+            options,
+            data_type,
+            nullable: true,
+            nan_ordering: NanOrdering::TotalOrder,
+        }
+    }
+
+    /// Sets whether the column may contain nulls, defaults to `true`
+    ///
+    /// The rows of a column of a fixed width type, such as a primitive, boolean or
+    /// decimal, that is not nullable omit the leading null byte of each value,
+    /// shrinking the rows of wide fixed width schemas. Other types are encoded the
+    /// same regardless. Converting a column containing nulls for a field that is
+    /// not nullable returns an error.
+    pub fn with_nullable(mut self, nullable: bool) -> Self {
+        self.nullable = nullable;
+        self
+    }
+
+    /// Sets the ordering of NaN values of a float column, defaults to
+    /// [`NanOrdering::TotalOrder`]
+    ///
+    /// Applies to the float children of struct, list and union columns, but not to the
+    /// values of dictionary columns, which are always ordered by their total order.
+    ///
+    /// When not [`NanOrdering::TotalOrder`], NaN values are converted back to arrays as
+    /// a NaN that may differ in sign and payload from that converted.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow::array::{ArrayRef, Float64Array};
+    /// # use arrow::compute::SortOptions;
+    /// # use arrow::datatypes::DataType;
+    /// # use arrow::row::{NanOrdering, RowConverter, SortField};
+    /// let options = SortOptions {
+    ///     descending: false,
+    ///     nulls_first: true,
+    /// };
+    /// let field = SortField::new_with_options(DataType::Float64, options)
+    ///     .with_nan_ordering(NanOrdering::Nulls);
+    /// let mut converter = RowConverter::new(vec![field]);
+    ///
+    /// let values = vec![Some(1.), None, Some(-f64::NAN), Some(f64::NEG_INFINITY)];
+    /// let values = Float64Array::from(values);
+    /// let rows = converter.convert_columns(&[Arc::new(values)]).unwrap();
+    ///
+    /// // NaN is ordered immediately after nulls
+    /// assert!(rows.row(1) < rows.row(2));
+    /// assert!(rows.row(2) < rows.row(3));
+    /// assert!(rows.row(3) < rows.row(0));
+    /// ```
+    pub fn with_nan_ordering(mut self, nan_ordering: NanOrdering) -> Self {
+        self.nan_ordering = nan_ordering;
+        self
     }
+
+    /// Returns the [`SortField`] of a child of this field with `data_type`
+    fn child(&self, data_type: DataType) -> SortField {
+        Self::new_with_options(data_type, self.options)
+            .with_nan_ordering(self.nan_ordering)
+    }
+
+    /// Returns whether NaN should be encoded as larger than all other values,
+    /// or `None` if ordered by [`NanOrdering::TotalOrder`]
+    fn nan_largest(&self) -> Option<bool> {
+        match self.nan_ordering {
+            NanOrdering::TotalOrder => None,
+            NanOrdering::Largest => Some(true),
+            NanOrdering::Smallest => Some(false),
+            // Nulls are ordered first if `nulls_first`, which is the smallest value
+            // if ascending or the largest if descending
+            NanOrdering::Nulls => {
+                Some(self.options.nulls_first == self.options.descending)
+            }
+        }
+    }
+}
+
+/// The ordering of NaN float values relative to other values of a [`SortField`]
+///
+/// Orderings are with respect to [`SortOptions::descending`], e.g. [`NanOrdering::Largest`]
+/// orders NaN last if ascending, and first if descending.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum NanOrdering {
+    /// Orders by the IEEE 754 total order, in which a positive NaN is larger, and a
+    /// negative NaN is smaller, than all other values
+    #[default]
+    TotalOrder,
+    /// Orders all NaN as larger than all other values
+    Largest,
+    /// Orders all NaN as smaller than all other values
+    Smallest,
+    /// Orders all NaN adjacent to nulls, i.e. immediately after nulls if
+    /// [`SortOptions::nulls_first`], otherwise immediately before them
+    Nulls,
 }
 
 impl RowConverter {
     /// Create a new [`RowConverter`] with the provided schema
     pub fn new(fields: Vec<SortField>) -> Self {
-        let interners = (0..fields.len()).map(|_| None).collect();
+        let codecs = fields.iter().map(Codec::new).collect();
         Self {
             fields: fields.into(),
-            interners,
+            codecs,
+            interner_limit: None,
+            hashes: false,
         }
     }
 
+    /// Sets whether to compute a 64-bit hash of each row when converting columns,
+    /// defaults to `false`
+    ///
+    /// The hashes are exposed by [`Rows::hashes`], allowing rows to be used as the keys
+    /// of a hash table, e.g. for a hash aggregation, without hashing them again. Equal
+    /// rows of this [`RowConverter`] have equal hashes, but hashes are not stable across
+    /// versions or platforms and should not be persisted.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow::array::{ArrayRef, StringArray};
+    /// # use arrow::datatypes::DataType;
+    /// # use arrow::row::{RowConverter, SortField};
+    /// let mut converter = RowConverter::new(vec![SortField::new(DataType::Utf8)])
+    ///     .with_hashes(true);
+    ///
+    /// let values: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "a"]));
+    /// let rows = converter.convert_columns(&[values]).unwrap();
+    ///
+    /// let hashes = rows.hashes().unwrap();
+    /// assert_eq!(hashes.len(), 3);
+    /// assert_eq!(hashes[0], hashes[2]);
+    /// ```
+    pub fn with_hashes(mut self, hashes: bool) -> Self {
+        self.hashes = hashes;
+        self
+    }
+
+    /// Limits the size in bytes of the state used to encode each dictionary column
+    ///
+    /// Dictionary values are interned to compute order preserving normalized keys, and
+    /// this state grows with the number of distinct values converted. Once the size of
+    /// the interner of a dictionary column, including within a nested column, exceeds
+    /// `limit`, converting further columns returns [`ArrowError::MemoryError`]. As the
+    /// values of a batch are interned before the limit is checked, the interner may
+    /// exceed `limit` by the size of the values of a single dictionary.
+    ///
+    /// The state can be discarded with [`RowConverter::reset`], or the dictionary
+    /// columns converted to their value type with [`cast`](crate::compute::cast)
+    /// before conversion, which requires no state.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow::array::{ArrayRef, DictionaryArray};
+    /// # use arrow::datatypes::{DataType, Int32Type};
+    /// # use arrow::row::{RowConverter, SortField};
+    /// let data_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+    /// let mut converter = RowConverter::new(vec![SortField::new(data_type)])
+    ///     .with_interner_limit(16 * 1024);
+    ///
+    /// // Returns a dictionary array of `n` distinct values
+    /// let dictionary = |n: usize| -> ArrayRef {
+    ///     let values: Vec<_> = (0..n).map(|x| x.to_string()).collect();
+    ///     let array: DictionaryArray<Int32Type> = values.iter().map(|x| x.as_str()).collect();
+    ///     Arc::new(array)
+    /// };
+    ///
+    /// converter.convert_columns(&[dictionary(10)]).unwrap();
+    /// assert!(converter.convert_columns(&[dictionary(1000)]).is_err());
+    ///
+    /// // Discarding the state allows converting more values
+    /// converter.reset();
+    /// converter.convert_columns(&[dictionary(100)]).unwrap();
+    /// ```
+    pub fn with_interner_limit(mut self, limit: usize) -> Self {
+        self.set_interner_limit(Some(limit));
+        self
+    }
+
+    fn set_interner_limit(&mut self, limit: Option<usize>) {
+        self.interner_limit = limit;
+        self.codecs
+            .iter_mut()
+            .for_each(|c| c.set_interner_limit(limit));
+    }
+
+    /// Discards the state used to encode dictionary columns, freeing its memory
+    ///
+    /// The [`Rows`] produced before this call are not comparable with those produced
+    /// after it, and can no longer be converted back to arrays, or appended to, by this
+    /// [`RowConverter`]. Attempting to do so will panic.
+    pub fn reset(&mut self) {
+        // Use a new allocation for the fields so rows produced before are rejected
+        self.fields = self.fields.iter().cloned().collect::<Vec<_>>().into();
+        self.codecs = self.fields.iter().map(Codec::new).collect();
+        self.set_interner_limit(self.interner_limit);
+    }
+
+    /// Returns the size of this instance in bytes, including the state used to
+    /// encode dictionary columns
+    ///
+    /// Includes the size of `Self`.
+    pub fn size(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + self.fields.len() * std::mem::size_of::<SortField>()
+            + self.codecs.capacity() * std::mem::size_of::<Codec>()
+            + self.codecs.iter().map(Codec::size).sum::<usize>()
+    }
+
     /// Convert [`ArrayRef`] columns into [`Rows`]
     ///
     /// See [`Row`] for information on when [`Row`] can be compared
@@ -189,6 +671,63 @@ impl RowConverter {
     ///
     /// Panics if the schema of `columns` does not match that provided to [`RowConverter::new`]
     pub fn convert_columns(&mut self, columns: &[ArrayRef]) -> Result<Rows> {
+        let num_rows = columns.first().map(|x| x.len()).unwrap_or(0);
+        let mut rows = self.empty_rows(num_rows, 0);
+        self.append(&mut rows, columns)?;
+        Ok(rows)
+    }
+
+    /// Returns an empty [`Rows`] with capacity for `row_capacity` rows with a
+    /// total length of `data_capacity`, that can be appended to with
+    /// [`RowConverter::append`]
+    ///
+    /// This allows reusing the same allocation across the conversion of multiple
+    /// batches, see [`Rows::clear`]
+    pub fn empty_rows(&self, row_capacity: usize, data_capacity: usize) -> Rows {
+        let mut offsets = Vec::with_capacity(row_capacity.saturating_add(1));
+        offsets.push(0);
+
+        Rows {
+            buffer: Vec::with_capacity(data_capacity),
+            offsets,
+            hashes: self.hashes.then(|| Vec::with_capacity(row_capacity)),
+            fields: Arc::clone(&self.fields),
+        }
+    }
+
+    /// Convert [`ArrayRef`] columns and append them to an existing [`Rows`]
+    ///
+    /// See [`Row`] for information on when [`Row`] can be compared
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow::row::{RowConverter, SortField};
+    /// # use arrow::array::{ArrayRef, StringArray};
+    /// # use arrow::datatypes::DataType;
+    /// let mut converter = RowConverter::new(vec![SortField::new(DataType::Utf8)]);
+    /// let mut rows = converter.empty_rows(4, 64);
+    ///
+    /// let a1: ArrayRef = Arc::new(StringArray::from(vec!["hello", "world"]));
+    /// converter.append(&mut rows, &[a1]).unwrap();
+    ///
+    /// let a2: ArrayRef = Arc::new(StringArray::from(vec!["a", "hello"]));
+    /// converter.append(&mut rows, &[a2]).unwrap();
+    ///
+    /// assert_eq!(rows.num_rows(), 4);
+    /// assert_eq!(rows.row(0), rows.row(3));
+    /// assert!(rows.row(2) < rows.row(1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schema of `columns` does not match that provided to
+    /// [`RowConverter::new`], or if `rows` were not produced by this [`RowConverter`]
+    pub fn append(&mut self, rows: &mut Rows, columns: &[ArrayRef]) -> Result<()> {
+        assert!(
+            Arc::ptr_eq(&rows.fields, &self.fields),
+            "rows were not produced by this RowConverter"
+        );
+
         if columns.len() != self.fields.len() {
             return Err(ArrowError::InvalidArgumentError(format!(
                 "Incorrect number of arrays provided to RowConverter, expected {} got {}",
@@ -197,11 +736,11 @@ impl RowConverter {
             )));
         }
 
-        let dictionaries = columns
+        let encoders = columns
             .iter()
-            .zip(&mut self.interners)
+            .zip(&mut self.codecs)
             .zip(self.fields.iter())
-            .map(|((column, interner), field)| {
+            .map(|((column, codec), field)| {
                 if !column.data_type().equals_datatype(&field.data_type) {
                     return Err(ArrowError::InvalidArgumentError(format!(
                         "RowConverter column schema mismatch, expected {} got {}",
@@ -209,32 +748,60 @@ impl RowConverter {
                         column.data_type()
                     )));
                 }
-
-                let values = downcast_dictionary_array! {
-                    column => column.values(),
-                    _ => return Ok(None)
-                };
-
-                let interner = interner.get_or_insert_with(Default::default);
-
-                let mapping: Vec<_> = compute_dictionary_mapping(interner, values)?
-                    .into_iter()
-                    .map(|maybe_interned| {
-                        maybe_interned.map(|interned| interner.normalized_key(interned))
-                    })
-                    .collect();
-
-                Ok(Some(mapping))
+                if !field.nullable && column.null_count() != 0 {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "RowConverter column of type {} is not nullable but contains {} nulls",
+                        field.data_type,
+                        column.null_count()
+                    )));
+                }
+                codec.encoder(column, self.interner_limit)
             })
             .collect::<Result<Vec<_>>>()?;
 
-        let mut rows = new_empty_rows(columns, &dictionaries, Arc::clone(&self.fields))?;
+        let write_offset = rows.num_rows();
+        let lengths = row_lengths(columns, &encoders, &self.fields)?;
+
+        // We initialize the offsets shifted down by one row index.
+        //
+        // As the rows are appended to the offsets will be incremented to match
+        //
+        // For example, consider the case of 3 rows of length 3, 4, and 6 respectively.
+        // The offsets would be initialized to `0, 0, 3, 7`
+        //
+        // Writing the first row entirely would yield `0, 3, 3, 7`
+        // The second, `0, 3, 7, 7`
+        // The third, `0, 3, 7, 13`
+        //
+        // This would be the final offsets for reading
+        //
+        // In this way offsets tracks the position during writing whilst eventually serving
+        // as identifying the offsets of the written rows
+        rows.offsets.reserve(lengths.len());
+        let mut cur_offset = rows.buffer.len();
+        for l in lengths {
+            rows.offsets.push(cur_offset);
+            cur_offset = cur_offset.checked_add(l).expect("overflow");
+        }
+        rows.buffer.resize(cur_offset, 0);
 
-        for ((column, field), dictionary) in
-            columns.iter().zip(self.fields.iter()).zip(dictionaries)
+        for ((column, field), encoder) in
+            columns.iter().zip(self.fields.iter()).zip(&encoders)
         {
             // We encode a column at a time to minimise dispatch overheads
-            encode_column(&mut rows, column, field.options, dictionary.as_deref())
+            encode_column(
+                &mut rows.buffer,
+                &mut rows.offsets[write_offset..],
+                column,
+                field,
+                encoder,
+            )
+        }
+
+        if let Some(hashes) = &mut rows.hashes {
+            let buffer = &rows.buffer;
+            let new_rows = rows.offsets[write_offset..].windows(2);
+            hashes.extend(new_rows.map(|w| ROW_HASHER.hash_one(&buffer[w[0]..w[1]])));
         }
 
         if cfg!(debug_assertions) {
@@ -244,7 +811,7 @@ impl RowConverter {
                 .for_each(|w| assert!(w[0] <= w[1], "offsets should be monotonic"));
         }
 
-        Ok(rows)
+        Ok(())
     }
 
     /// Convert [`Rows`] columns into [`ArrayRef`]
@@ -268,17 +835,229 @@ impl RowConverter {
             })
             .collect();
 
+        // SAFETY
+        // We have validated that the rows came from this [`RowConverter`]
+        // and therefore must be valid
+        unsafe { self.convert_raw(&mut rows) }
+    }
+
+    /// Convert the columns of [`Rows`] with indices in `projection` into [`ArrayRef`],
+    /// returned in the order of `projection`
+    ///
+    /// As the encoded columns are not of fixed length, the columns preceding the last
+    /// projected column must be decoded to locate it, but any later columns are not
+    /// decoded. Decoding only a prefix of the columns, such as a group key, therefore
+    /// avoids materializing the remaining columns.
+    ///
+    /// Returns an error if `projection` contains an index out of bounds
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows were not produced by this [`RowConverter`]
+    pub fn convert_rows_with_projection<'a, I>(
+        &self,
+        rows: I,
+        projection: &[usize],
+    ) -> Result<Vec<ArrayRef>>
+    where
+        I: IntoIterator<Item = Row<'a>>,
+    {
+        if let Some(idx) = projection.iter().find(|idx| **idx >= self.fields.len()) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "projection index {} out of bounds for RowConverter with {} fields",
+                idx,
+                self.fields.len()
+            )));
+        }
+
+        let mut rows: Vec<_> = rows
+            .into_iter()
+            .map(|row| {
+                assert!(
+                    Arc::ptr_eq(row.fields, &self.fields),
+                    "rows were not produced by this RowConverter"
+                );
+
+                row.data
+            })
+            .collect();
+
+        let num_columns = projection.iter().max().map(|x| x + 1).unwrap_or(0);
+
+        // SAFETY
+        // We have validated that the rows came from this [`RowConverter`]
+        // and therefore must be valid
+        let columns = unsafe { self.convert_raw_prefix(&mut rows, num_columns)? };
+        Ok(projection
+            .iter()
+            .map(|idx| Arc::clone(&columns[*idx]))
+            .collect())
+    }
+
+    /// Reconstructs [`Rows`] from bytes written by [`Rows::to_bytes`]
+    ///
+    /// Returns an error if `bytes` are not serialized rows, or were serialized from
+    /// rows with different fields from those of this [`RowConverter`].
+    ///
+    /// # Safety
+    ///
+    /// The rows must have been produced by this [`RowConverter`], as the data of the
+    /// rows themselves is not validated, and is later assumed to be valid by
+    /// [`RowConverter::convert_rows`]
+    pub unsafe fn rows_from_bytes(&self, mut bytes: &[u8]) -> Result<Rows> {
+        let mut expected = ROWS_MAGIC.to_vec();
+        write_fields(&mut expected, &self.fields);
+        match bytes.strip_prefix(expected.as_slice()) {
+            Some(rest) => bytes = rest,
+            None => {
+                return Err(ArrowError::InvalidArgumentError(
+                    "serialized rows do not match the fields of the RowConverter"
+                        .to_string(),
+                ))
+            }
+        }
+
+        let num_rows = read_u64(&mut bytes)? as usize;
+        let mut offsets = Vec::with_capacity(num_rows.min(bytes.len() / 8) + 1);
+        for _ in 0..=num_rows {
+            let offset = read_u64(&mut bytes)? as usize;
+            if offsets
+                .last()
+                .map(|last| offset < *last)
+                .unwrap_or(offset != 0)
+            {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "invalid offset {} in serialized rows",
+                    offset
+                )));
+            }
+            offsets.push(offset);
+        }
+
+        if offsets[num_rows] != bytes.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "serialized rows contain {} bytes of data, expected {}",
+                bytes.len(),
+                offsets[num_rows]
+            )));
+        }
+
+        let hashes = self.hashes.then(|| {
+            offsets
+                .windows(2)
+                .map(|w| ROW_HASHER.hash_one(&bytes[w[0]..w[1]]))
+                .collect()
+        });
+
+        Ok(Rows {
+            buffer: bytes.into(),
+            offsets,
+            hashes,
+            fields: Arc::clone(&self.fields),
+        })
+    }
+
+    /// Checked version of [`RowConverter::rows_from_bytes`], that validates the data of
+    /// each row, returning an error if any is not a valid row of this [`RowConverter`]
+    ///
+    /// This allows rows received from an untrusted source, e.g. over the network, to be
+    /// converted back to arrays with [`RowConverter::convert_rows`]
+    pub fn rows_from_bytes_checked(&self, bytes: &[u8]) -> Result<Rows> {
+        // SAFETY:
+        // The data of the rows is validated before they are returned
+        let rows = unsafe { self.rows_from_bytes(bytes)? };
+        for row in &rows {
+            self.parse_checked(row.data)?;
+        }
+        Ok(rows)
+    }
+
+    /// Parses `bytes` as a [`Row`] of this [`RowConverter`], returning an error if they
+    /// are not a valid row, e.g. as they were received from an untrusted source
+    ///
+    /// Unlike [`RowParser::parse`] this is safe, as the returned [`Row`] is validated
+    /// to be decodable by [`RowConverter::convert_rows`]. Note that a dictionary value
+    /// is only valid if it was previously converted by this [`RowConverter`].
+    pub fn parse_checked<'a>(&'a self, bytes: &'a [u8]) -> Result<Row<'a>> {
+        let len = validate::validate_row(self, bytes)?;
+        if len != bytes.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "malformed row: {} trailing bytes",
+                bytes.len() - len
+            )));
+        }
+        Ok(Row {
+            data: bytes,
+            fields: &self.fields,
+        })
+    }
+
+    /// Returns a [`RowParser`] that can be used to parse bytes of [`Row`] produced by
+    /// this [`RowConverter`], e.g. as obtained by [`Row::as_ref`]
+    pub fn parser(&self) -> RowParser {
+        RowParser {
+            fields: Arc::clone(&self.fields),
+        }
+    }
+
+    /// Returns the [`Rows`] containing a single row whose columns are all null
+    fn null_row(&mut self) -> Result<Rows> {
+        let nulls: Vec<_> = self
+            .fields
+            .iter()
+            .map(|f| new_null_array(&f.data_type, 1))
+            .collect();
+        self.convert_columns(&nulls)
+    }
+
+    /// Convert raw bytes into [`ArrayRef`]
+    ///
+    /// # Safety
+    ///
+    /// `rows` must contain valid data for this [`RowConverter`]
+    unsafe fn convert_raw(&self, rows: &mut [&[u8]]) -> Result<Vec<ArrayRef>> {
+        self.convert_raw_prefix(rows, self.fields.len())
+    }
+
+    /// Convert the first `num_columns` columns of raw bytes into [`ArrayRef`]
+    ///
+    /// # Safety
+    ///
+    /// `rows` must contain valid data for this [`RowConverter`]
+    unsafe fn convert_raw_prefix(
+        &self,
+        rows: &mut [&[u8]],
+        num_columns: usize,
+    ) -> Result<Vec<ArrayRef>> {
         self.fields
             .iter()
-            .zip(&self.interners)
-            .map(|(field, interner)| {
-                // SAFETY
-                // We have validated that the rows came from this [`RowConverter`]
-                // and therefore must be valid
-                unsafe { decode_column(field, &mut rows, interner.as_deref()) }
-            })
+            .zip(&self.codecs)
+            .take(num_columns)
+            .map(|(field, codec)| decode_column(field, rows, codec))
             .collect()
     }
+
+    /// Convert the rows of `rows` selected by `selection` into [`ArrayRef`]
+    ///
+    /// Returns an error if the length of `selection` is not the number of rows
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows were not produced by this [`RowConverter`]
+    pub fn convert_selected_rows(
+        &self,
+        rows: &Rows,
+        selection: &Selection,
+    ) -> Result<Vec<ArrayRef>> {
+        if selection.len() != rows.num_rows() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "selection of {} rows cannot be applied to {} rows",
+                selection.len(),
+                rows.num_rows()
+            )));
+        }
+        self.convert_rows(selection.iter_indices().map(|i| rows.row(i)))
+    }
 }
 
 /// A row-oriented representation of arrow data, that is normalized for comparison
@@ -287,9 +1066,11 @@ impl RowConverter {
 #[derive(Debug)]
 pub struct Rows {
     /// Underlying row bytes
-    buffer: Box<[u8]>,
+    buffer: Vec<u8>,
     /// Row `i` has data `&buffer[offsets[i]..offsets[i+1]]`
-    offsets: Box<[usize]>,
+    offsets: Vec<usize>,
+    /// The hash of each row, if enabled by [`RowConverter::with_hashes`]
+    hashes: Option<Vec<u64>>,
     /// The schema for these rows
     fields: Arc<[SortField]>,
 }
@@ -307,20 +1088,173 @@ impl Rows {
     pub fn num_rows(&self) -> usize {
         self.offsets.len() - 1
     }
-}
 
-impl<'a> IntoIterator for &'a Rows {
-    type Item = Row<'a>;
-    type IntoIter = RowsIter<'a>;
+    /// Returns the hash of each row, if enabled by [`RowConverter::with_hashes`]
+    pub fn hashes(&self) -> Option<&[u64]> {
+        self.hashes.as_deref()
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        RowsIter {
-            rows: self,
-            start: 0,
-            end: self.num_rows(),
-        }
+    /// Returns the index of the first row for which `pred` returns false, assuming
+    /// these rows are partitioned according to `pred`, see [`slice::partition_point`]
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(Row<'_>) -> bool,
+    {
+        self.partition_point_from(0, pred)
     }
-}
+
+    /// Returns the index of the first row from `start` for which `pred` returns false,
+    /// assuming the rows from `start` are partitioned according to `pred`
+    fn partition_point_from<P>(&self, start: usize, mut pred: P) -> usize
+    where
+        P: FnMut(Row<'_>) -> bool,
+    {
+        let (mut left, mut right) = (start, self.num_rows());
+        while left < right {
+            let mid = left + (right - left) / 2;
+            match pred(self.row(mid)) {
+                true => left = mid + 1,
+                false => right = mid,
+            }
+        }
+        left
+    }
+
+    /// Binary searches these sorted rows for `row`, see [`slice::binary_search`]
+    ///
+    /// Returns `Ok` with the index of a matching row, or `Err` with the index at
+    /// which `row` could be inserted whilst maintaining the sort order
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` was not produced by the same [`RowConverter`] as these rows
+    pub fn binary_search(&self, row: Row<'_>) -> std::result::Result<usize, usize> {
+        assert!(
+            Arc::ptr_eq(row.fields, &self.fields),
+            "rows were not produced by the same RowConverter"
+        );
+        let idx = self.partition_point(|r| r < row);
+        match idx < self.num_rows() && self.row(idx) == row {
+            true => Ok(idx),
+            false => Err(idx),
+        }
+    }
+
+    /// Returns the range of these sorted rows that start with the bytes of `prefix`
+    ///
+    /// As the columns of a row are encoded one after the other, `prefix` can be a
+    /// row produced by a [`RowConverter`] for the leading [`SortField`] of these rows,
+    /// finding the rows whose leading columns have the values of `prefix`, e.g. to
+    /// find the rows of a merge join key or to prune a range. Dictionary columns are
+    /// not supported, as their encoding depends on the state of each [`RowConverter`].
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow::row::{RowConverter, SortField};
+    /// # use arrow::array::{ArrayRef, Int32Array, StringArray};
+    /// # use arrow::datatypes::DataType;
+    /// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 2, 2, 3]));
+    /// let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "a", "b", "c", "a"]));
+    /// let fields = vec![SortField::new(DataType::Int32), SortField::new(DataType::Utf8)];
+    /// let mut converter = RowConverter::new(fields);
+    /// let rows = converter.convert_columns(&[a, b]).unwrap();
+    ///
+    /// let mut key_converter = RowConverter::new(vec![SortField::new(DataType::Int32)]);
+    /// let key: ArrayRef = Arc::new(Int32Array::from(vec![2]));
+    /// let keys = key_converter.convert_columns(&[key]).unwrap();
+    /// assert_eq!(rows.prefix_range(keys.row(0).as_ref()), 1..4);
+    /// ```
+    pub fn prefix_range(&self, prefix: &[u8]) -> std::ops::Range<usize> {
+        let start = self.partition_point(|r| r.data < prefix);
+        let end = self.partition_point_from(start, |r| r.data.starts_with(prefix));
+        start..end
+    }
+
+    /// Returns the size of this instance in bytes, including the allocated capacity
+    ///
+    /// Includes the size of `Self`.
+    pub fn size(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + self.buffer.capacity()
+            + self.offsets.capacity() * std::mem::size_of::<usize>()
+            + self
+                .hashes
+                .as_ref()
+                .map(|h| h.capacity() * 8)
+                .unwrap_or_default()
+    }
+
+    /// Removes all rows, retaining the allocated capacity for use by
+    /// [`RowConverter::append`]
+    pub fn clear(&mut self) {
+        self.offsets.truncate(1);
+        self.buffer.clear();
+        if let Some(hashes) = &mut self.hashes {
+            hashes.clear();
+        }
+    }
+
+    /// Serializes these [`Rows`] to a contiguous byte region, that can be converted
+    /// back to [`Rows`] by [`RowConverter::rows_from_bytes`], e.g. to spill them to disk
+    ///
+    /// The serialized form starts with a description of the fields of the rows, followed
+    /// by the row offsets and the row data, all integers being little endian.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(ROWS_MAGIC);
+        write_fields(&mut out, &self.fields);
+        out.extend_from_slice(&(self.num_rows() as u64).to_le_bytes());
+        for offset in self.offsets.iter() {
+            out.extend_from_slice(&(*offset as u64).to_le_bytes());
+        }
+        out.extend_from_slice(&self.buffer);
+        out
+    }
+}
+
+/// The magic bytes at the start of serialized [`Rows`]
+const ROWS_MAGIC: &[u8; 4] = b"AROW";
+
+/// Writes a description of `fields` to `out`, identifying their type and options
+fn write_fields(out: &mut Vec<u8>, fields: &[SortField]) {
+    out.extend_from_slice(&(fields.len() as u32).to_le_bytes());
+    for field in fields {
+        let options = field.options.descending as u8
+            | (field.options.nulls_first as u8) << 1
+            | (!field.nullable as u8) << 2
+            | (field.nan_ordering as u8) << 3;
+        out.push(options);
+        let data_type = format!("{:?}", field.data_type);
+        out.extend_from_slice(&(data_type.len() as u32).to_le_bytes());
+        out.extend_from_slice(data_type.as_bytes());
+    }
+}
+
+/// Reads a little endian `u64` from the start of `bytes`, advancing it
+fn read_u64(bytes: &mut &[u8]) -> Result<u64> {
+    match bytes.get(..8) {
+        Some(v) => {
+            *bytes = &bytes[8..];
+            Ok(u64::from_le_bytes(v.try_into().unwrap()))
+        }
+        None => Err(ArrowError::InvalidArgumentError(
+            "unexpected end of serialized rows".to_string(),
+        )),
+    }
+}
+
+impl<'a> IntoIterator for &'a Rows {
+    type Item = Row<'a>;
+    type IntoIter = RowsIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RowsIter {
+            rows: self,
+            start: 0,
+            end: self.num_rows(),
+        }
+    }
+}
 
 /// An iterator over [`Rows`]
 #[derive(Debug)]
@@ -416,6 +1350,151 @@ impl<'a> AsRef<[u8]> for Row<'a> {
     }
 }
 
+impl<'a> Row<'a> {
+    /// Create owned version of the row to detach it from the shared [`Rows`]
+    pub fn owned(&self) -> OwnedRow {
+        OwnedRow {
+            data: self.data.into(),
+            fields: Arc::clone(self.fields),
+        }
+    }
+
+    /// Returns the first `len` bytes of this row as a [`RowPrefix`], e.g. to be
+    /// stored as a fixed size sort key by an external sort
+    pub fn prefix(&self, len: usize) -> RowPrefix<'a> {
+        RowPrefix {
+            data: &self.data[..len.min(self.data.len())],
+            truncated: self.data.len() > len,
+        }
+    }
+}
+
+/// A prefix of at most a fixed number of bytes of a [`Row`], see [`Row::prefix`]
+///
+/// Two [`RowPrefix`] of the same length taken from [`Row`] that can be compared
+/// compare the same as their rows, unless they compare equal and are both
+/// truncated, in which case the full rows must be compared to order them
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::row::{RowConverter, SortField};
+/// # use arrow::array::{ArrayRef, StringArray};
+/// # use arrow::datatypes::DataType;
+/// let mut converter = RowConverter::new(vec![SortField::new(DataType::Utf8)]);
+/// let array = StringArray::from(vec!["hello world", "hello there", "a"]);
+/// let rows = converter.convert_columns(&[Arc::new(array) as ArrayRef]).unwrap();
+///
+/// let prefixes: Vec<_> = rows.into_iter().map(|row| row.prefix(6)).collect();
+/// // The prefixes of "a" and "hello world" are decisive
+/// assert!(prefixes[2] < prefixes[0]);
+/// // The encoded rows of "hello world" and "hello there" share their first 6 bytes
+/// assert_eq!(prefixes[0], prefixes[1]);
+/// assert!(prefixes[0].is_truncated() && prefixes[1].is_truncated());
+/// assert!(rows.row(1) < rows.row(0));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RowPrefix<'a> {
+    // `data` is compared before `truncated`, so that a row that is itself a prefix
+    // of a truncated row with the same `data` compares less than it
+    data: &'a [u8],
+    truncated: bool,
+}
+
+impl<'a> RowPrefix<'a> {
+    /// Returns the bytes of this prefix
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Returns true if the [`Row`] is longer than this prefix
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+/// Owned version of a [`Row`], that can be moved and stored independently of the
+/// [`Rows`] it was created from, e.g. as the key of a hash table
+///
+/// Two [`OwnedRow`] can be compared under the same conditions as two [`Row`]
+#[derive(Debug, Clone)]
+pub struct OwnedRow {
+    data: Box<[u8]>,
+    fields: Arc<[SortField]>,
+}
+
+impl OwnedRow {
+    /// Get the row as a [`Row`], e.g. to compare it to another [`Row`] or convert it
+    /// back to arrays with [`RowConverter::convert_rows`]
+    pub fn row(&self) -> Row<'_> {
+        Row {
+            data: &self.data,
+            fields: &self.fields,
+        }
+    }
+}
+
+// Manually derive these as don't wish to include `fields`
+
+impl PartialEq for OwnedRow {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.row().eq(&other.row())
+    }
+}
+
+impl Eq for OwnedRow {}
+
+impl PartialOrd for OwnedRow {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OwnedRow {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.row().cmp(&other.row())
+    }
+}
+
+impl Hash for OwnedRow {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.row().hash(state)
+    }
+}
+
+impl AsRef<[u8]> for OwnedRow {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// A [`RowParser`] can be created from a [`RowConverter`] and used to parse bytes
+/// stored outside of [`Rows`] back into [`Row`], see [`RowConverter::parser`]
+#[derive(Debug)]
+pub struct RowParser {
+    fields: Arc<[SortField]>,
+}
+
+impl RowParser {
+    /// Creates a [`Row`] from the provided `bytes`
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be the bytes of a [`Row`] produced by the [`RowConverter`] that
+    /// created this [`RowParser`], as they are assumed to be valid when converted back
+    /// to arrays by [`RowConverter::convert_rows`]
+    pub unsafe fn parse<'a>(&'a self, bytes: &'a [u8]) -> Row<'a> {
+        Row {
+            data: bytes,
+            fields: &self.fields,
+        }
+    }
+}
+
 /// Returns the null sentinel, negated if `invert` is true
 #[inline]
 fn null_sentinel(options: SortOptions) -> u8 {
@@ -425,24 +1504,32 @@ fn null_sentinel(options: SortOptions) -> u8 {
     }
 }
 
-/// Computes the length of each encoded [`Rows`] and returns an empty [`Rows`]
-fn new_empty_rows(
+/// Computes the length of the encoded row of each of the rows of `cols`
+fn row_lengths(
     cols: &[ArrayRef],
-    dictionaries: &[Option<Vec<Option<&[u8]>>>],
-    fields: Arc<[SortField]>,
-) -> Result<Rows> {
+    encoders: &[Encoder],
+    fields: &[SortField],
+) -> Result<Vec<usize>> {
     use fixed::FixedLengthEncoding;
 
     let num_rows = cols.first().map(|x| x.len()).unwrap_or(0);
     let mut lengths = vec![0; num_rows];
+    // The total length of the fixed width columns, added to all rows at the end
+    let mut fixed_length = 0;
 
-    for (array, dict) in cols.iter().zip(dictionaries) {
+    for ((array, encoder), field) in cols.iter().zip(encoders).zip(fields) {
+        // Fixed width types omit the null byte if not nullable
+        let fixed_len = |len: usize| len - !field.nullable as usize;
         downcast_primitive_array! {
-            array => lengths.iter_mut().for_each(|x| *x += fixed::encoded_len(array)),
+            array => fixed_length += fixed_len(fixed::encoded_len(array)),
             DataType::Null => {},
-            DataType::Boolean => lengths.iter_mut().for_each(|x| *x += bool::ENCODED_LEN),
-            DataType::Decimal128(_, _) => lengths.iter_mut().for_each(|x| *x += RawDecimal128::ENCODED_LEN),
-            DataType::Decimal256(_, _) => lengths.iter_mut().for_each(|x| *x += RawDecimal256::ENCODED_LEN),
+            DataType::Boolean => fixed_length += fixed_len(bool::ENCODED_LEN),
+            DataType::Decimal128(_, _) => {
+                fixed_length += fixed_len(RawDecimal128::ENCODED_LEN)
+            },
+            DataType::Decimal256(_, _) => {
+                fixed_length += fixed_len(RawDecimal256::ENCODED_LEN)
+            },
             DataType::Binary => as_generic_binary_array::<i32>(array)
                 .iter()
                 .zip(lengths.iter_mut())
@@ -465,7 +1552,10 @@ fn new_empty_rows(
                 }),
             DataType::Dictionary(_, _) => downcast_dictionary_array! {
                 array => {
-                    let dict = dict.as_ref().unwrap();
+                    let dict = match encoder {
+                        Encoder::Dictionary(dict) => dict,
+                        _ => unreachable!(),
+                    };
                     for (v, length) in array.keys().iter().zip(lengths.iter_mut()) {
                         match v.and_then(|v| dict[v as usize]) {
                             Some(k) => *length += k.len() + 1,
@@ -475,54 +1565,106 @@ fn new_empty_rows(
                 }
                 _ => unreachable!(),
             }
+            DataType::Struct(_) => match encoder {
+                Encoder::Struct(rows, null) => {
+                    let array = as_struct_array(array);
+                    lengths.iter_mut().enumerate().for_each(|(idx, length)| {
+                        match array.is_valid(idx) {
+                            true => *length += 1 + rows.row(idx).data.len(),
+                            false => *length += 1 + null.data.len(),
+                        }
+                    });
+                }
+                _ => unreachable!(),
+            },
+            DataType::FixedSizeList(_, _) => match encoder {
+                Encoder::FixedSizeList(rows, null) => {
+                    let array = as_fixed_size_list_array(array);
+                    list::compute_fixed_size_lengths(&mut lengths, rows, *null, array)
+                }
+                _ => unreachable!(),
+            },
+            DataType::Union(_, _, _) => match encoder {
+                Encoder::Union(rows) => {
+                    let array = as_union_array(array);
+                    lengths.iter_mut().enumerate().for_each(|(idx, length)| {
+                        let rows = &rows[array.type_id(idx) as usize];
+                        *length += 1 + rows.row(array.value_offset(idx) as usize).data.len()
+                    });
+                }
+                _ => unreachable!(),
+            },
+            DataType::List(_) => match encoder {
+                Encoder::List(rows) => {
+                    let offsets = as_list_array(array).value_offsets();
+                    list::compute_lengths(&mut lengths, rows, array, offsets)
+                }
+                _ => unreachable!(),
+            },
+            DataType::LargeList(_) => match encoder {
+                Encoder::List(rows) => {
+                    let offsets = as_large_list_array(array).value_offsets();
+                    list::compute_lengths(&mut lengths, rows, array, offsets)
+                }
+                _ => unreachable!(),
+            },
+            DataType::Map(_, _) => match encoder {
+                Encoder::List(rows) => {
+                    let offsets = as_map_array(array).value_offsets();
+                    list::compute_lengths(&mut lengths, rows, array, offsets)
+                }
+                _ => unreachable!(),
+            },
             t => return Err(ArrowError::NotYetImplemented(format!("not yet implemented: {}", t)))
         }
     }
 
-    let mut offsets = Vec::with_capacity(num_rows + 1);
-    offsets.push(0);
-
-    // We initialize the offsets shifted down by one row index.
-    //
-    // As the rows are appended to the offsets will be incremented to match
-    //
-    // For example, consider the case of 3 rows of length 3, 4, and 6 respectively.
-    // The offsets would be initialized to `0, 0, 3, 7`
-    //
-    // Writing the first row entirely would yield `0, 3, 3, 7`
-    // The second, `0, 3, 7, 7`
-    // The third, `0, 3, 7, 13`
-    //
-    // This would be the final offsets for reading
-    //
-    // In this way offsets tracks the position during writing whilst eventually serving
-    // as identifying the offsets of the written rows
-    let mut cur_offset = 0_usize;
-    for l in lengths {
-        offsets.push(cur_offset);
-        cur_offset = cur_offset.checked_add(l).expect("overflow");
-    }
-
-    let buffer = vec![0_u8; cur_offset];
-
-    Ok(Rows {
-        buffer: buffer.into(),
-        offsets: offsets.into(),
-        fields,
-    })
+    if fixed_length != 0 {
+        lengths.iter_mut().for_each(|x| *x += fixed_length);
+    }
+    Ok(lengths)
 }
 
-/// Encodes a column to the provided [`Rows`] incrementing the offsets as it progresses
+/// Encodes a column to the provided `data` and `offsets` of [`Rows`], incrementing
+/// the offsets as it progresses
 fn encode_column(
-    out: &mut Rows,
+    data: &mut [u8],
+    offsets: &mut [usize],
     column: &ArrayRef,
-    opts: SortOptions,
-    dictionary: Option<&[Option<&[u8]>]>,
+    field: &SortField,
+    encoder: &Encoder,
 ) {
+    let opts = field.options;
+    if let Some(largest) = field.nan_largest() {
+        let nullable = field.nullable;
+        match column.data_type() {
+            DataType::Float16 => {
+                let column = as_primitive_array::<Float16Type>(column);
+                return fixed::encode_float(
+                    data, offsets, column, opts, nullable, largest,
+                );
+            }
+            DataType::Float32 => {
+                let column = as_primitive_array::<Float32Type>(column);
+                return fixed::encode_float(
+                    data, offsets, column, opts, nullable, largest,
+                );
+            }
+            DataType::Float64 => {
+                let column = as_primitive_array::<Float64Type>(column);
+                return fixed::encode_float(
+                    data, offsets, column, opts, nullable, largest,
+                );
+            }
+            _ => {}
+        }
+    }
     downcast_primitive_array! {
-        column => fixed::encode(out, column, opts),
+        column => fixed::encode_primitive(data, offsets, column, opts, field.nullable),
         DataType::Null => {}
-        DataType::Boolean => fixed::encode(out, as_boolean_array(column), opts),
+        DataType::Boolean => {
+            fixed::encode(data, offsets, as_boolean_array(column), opts, field.nullable)
+        }
         DataType::Decimal128(_, _) => {
             let iter = column
                 .as_any()
@@ -531,7 +1673,7 @@ fn encode_column(
                 .into_iter()
                 .map(|x| x.map(|x| RawDecimal(*x.raw_value())));
 
-            fixed::encode(out, iter, opts)
+            fixed::encode(data, offsets, iter, opts, field.nullable)
         },
         DataType::Decimal256(_, _) => {
             let iter = column
@@ -541,30 +1683,85 @@ fn encode_column(
                 .into_iter()
                 .map(|x| x.map(|x| RawDecimal(*x.raw_value())));
 
-            fixed::encode(out, iter, opts)
+            fixed::encode(data, offsets, iter, opts, field.nullable)
         },
         DataType::Binary => {
-            variable::encode(out, as_generic_binary_array::<i32>(column).iter(), opts)
+            variable::encode(data, offsets, as_generic_binary_array::<i32>(column).iter(), opts)
         }
         DataType::LargeBinary => {
-            variable::encode(out, as_generic_binary_array::<i64>(column).iter(), opts)
+            variable::encode(data, offsets, as_generic_binary_array::<i64>(column).iter(), opts)
         }
         DataType::Utf8 => variable::encode(
-            out,
+            data,
+            offsets,
             as_string_array(column).iter().map(|x| x.map(|x| x.as_bytes())),
             opts,
         ),
         DataType::LargeUtf8 => variable::encode(
-            out,
+            data,
+            offsets,
             as_largestring_array(column)
                 .iter()
                 .map(|x| x.map(|x| x.as_bytes())),
             opts,
         ),
-        DataType::Dictionary(_, _) => downcast_dictionary_array! {
-            column => encode_dictionary(out, column, dictionary.unwrap(), opts),
+        DataType::Dictionary(_, _) => match encoder {
+            Encoder::Dictionary(dict) => downcast_dictionary_array! {
+                column => encode_dictionary(data, offsets, column, dict, opts),
+                _ => unreachable!()
+            },
             _ => unreachable!()
-        }
+        },
+        DataType::Struct(_) => match encoder {
+            Encoder::Struct(rows, null) => {
+                let array = as_struct_array(column);
+                for (idx, offset) in offsets.iter_mut().skip(1).enumerate() {
+                    let (sentinel, row) = match array.is_valid(idx) {
+                        true => (1, rows.row(idx)),
+                        false => (null_sentinel(opts), *null),
+                    };
+                    let end_offset = *offset + 1 + row.data.len();
+                    data[*offset] = sentinel;
+                    data[*offset + 1..end_offset].copy_from_slice(row.data);
+                    *offset = end_offset;
+                }
+            }
+            _ => unreachable!()
+        },
+        DataType::FixedSizeList(_, _) => match encoder {
+            Encoder::FixedSizeList(rows, null) => {
+                let array = as_fixed_size_list_array(column);
+                list::encode_fixed_size(data, offsets, rows, *null, opts, array)
+            }
+            _ => unreachable!()
+        },
+        DataType::Union(_, _, _) => match encoder {
+            Encoder::Union(rows) => {
+                encode_union(data, offsets, rows, opts, as_union_array(column))
+            }
+            _ => unreachable!()
+        },
+        DataType::List(_) => match encoder {
+            Encoder::List(rows) => {
+                let value_offsets = as_list_array(column).value_offsets();
+                list::encode(data, offsets, rows, opts, column, value_offsets)
+            }
+            _ => unreachable!()
+        },
+        DataType::LargeList(_) => match encoder {
+            Encoder::List(rows) => {
+                let value_offsets = as_large_list_array(column).value_offsets();
+                list::encode(data, offsets, rows, opts, column, value_offsets)
+            }
+            _ => unreachable!()
+        },
+        DataType::Map(_, _) => match encoder {
+            Encoder::List(rows) => {
+                let value_offsets = as_map_array(column).value_offsets();
+                list::encode(data, offsets, rows, opts, column, value_offsets)
+            }
+            _ => unreachable!()
+        },
         t => unimplemented!("not yet implemented: {}", t)
     }
 }
@@ -577,175 +1774,386 @@ fn encode_column(
 unsafe fn decode_column(
     field: &SortField,
     rows: &mut [&[u8]],
-    interner: Option<&OrderPreservingInterner>,
+    codec: &Codec,
 ) -> Result<ArrayRef> {
     let options = field.options;
-    let array: ArrayRef = match &field.data_type {
-        DataType::Null => Arc::new(NullArray::new(rows.len())),
-        DataType::Boolean => Arc::new(decode_bool(rows, options)),
-        DataType::Int8 => Arc::new(decode_primitive::<Int8Type>(rows, options)),
-        DataType::Int16 => Arc::new(decode_primitive::<Int16Type>(rows, options)),
-        DataType::Int32 => Arc::new(decode_primitive::<Int32Type>(rows, options)),
-        DataType::Int64 => Arc::new(decode_primitive::<Int64Type>(rows, options)),
-        DataType::UInt8 => Arc::new(decode_primitive::<UInt8Type>(rows, options)),
-        DataType::UInt16 => Arc::new(decode_primitive::<UInt16Type>(rows, options)),
-        DataType::UInt32 => Arc::new(decode_primitive::<UInt32Type>(rows, options)),
-        DataType::UInt64 => Arc::new(decode_primitive::<UInt64Type>(rows, options)),
-        DataType::Float16 => Arc::new(decode_primitive::<Float16Type>(rows, options)),
-        DataType::Float32 => Arc::new(decode_primitive::<Float32Type>(rows, options)),
-        DataType::Float64 => Arc::new(decode_primitive::<Float64Type>(rows, options)),
-        DataType::Timestamp(TimeUnit::Second, _) => {
-            Arc::new(decode_primitive::<TimestampSecondType>(rows, options))
-        }
-        DataType::Timestamp(TimeUnit::Millisecond, _) => {
-            Arc::new(decode_primitive::<TimestampMillisecondType>(rows, options))
-        }
-        DataType::Timestamp(TimeUnit::Microsecond, _) => {
-            Arc::new(decode_primitive::<TimestampMicrosecondType>(rows, options))
-        }
-        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
-            Arc::new(decode_primitive::<TimestampNanosecondType>(rows, options))
-        }
-        DataType::Date32 => Arc::new(decode_primitive::<Date32Type>(rows, options)),
-        DataType::Date64 => Arc::new(decode_primitive::<Date64Type>(rows, options)),
-        DataType::Time32(t) => match t {
-            TimeUnit::Second => {
-                Arc::new(decode_primitive::<Time32SecondType>(rows, options))
+    let nullable = field.nullable;
+    let interner = match codec {
+        Codec::Dictionary(interner) => Some(interner),
+        _ => None,
+    };
+    let array: ArrayRef =
+        match &field.data_type {
+            DataType::Null => Arc::new(NullArray::new(rows.len())),
+            DataType::Boolean => Arc::new(decode_bool(rows, options, nullable)),
+            DataType::Int8 => {
+                Arc::new(decode_primitive::<Int8Type>(rows, options, nullable))
             }
-            TimeUnit::Millisecond => {
-                Arc::new(decode_primitive::<Time32MillisecondType>(rows, options))
+            DataType::Int16 => {
+                Arc::new(decode_primitive::<Int16Type>(rows, options, nullable))
             }
-            _ => unreachable!(),
-        },
-        DataType::Time64(t) => match t {
-            TimeUnit::Microsecond => {
-                Arc::new(decode_primitive::<Time64MicrosecondType>(rows, options))
+            DataType::Int32 => {
+                Arc::new(decode_primitive::<Int32Type>(rows, options, nullable))
             }
-            TimeUnit::Nanosecond => {
-                Arc::new(decode_primitive::<Time64NanosecondType>(rows, options))
+            DataType::Int64 => {
+                Arc::new(decode_primitive::<Int64Type>(rows, options, nullable))
             }
-            _ => unreachable!(),
-        },
-        DataType::Duration(TimeUnit::Second) => {
-            Arc::new(decode_primitive::<DurationSecondType>(rows, options))
-        }
-        DataType::Duration(TimeUnit::Millisecond) => {
-            Arc::new(decode_primitive::<DurationMillisecondType>(rows, options))
-        }
-        DataType::Duration(TimeUnit::Microsecond) => {
-            Arc::new(decode_primitive::<DurationMicrosecondType>(rows, options))
-        }
-        DataType::Duration(TimeUnit::Nanosecond) => {
-            Arc::new(decode_primitive::<DurationNanosecondType>(rows, options))
-        }
-        DataType::Interval(IntervalUnit::DayTime) => {
-            Arc::new(decode_primitive::<IntervalDayTimeType>(rows, options))
-        }
-        DataType::Interval(IntervalUnit::MonthDayNano) => {
-            Arc::new(decode_primitive::<IntervalMonthDayNanoType>(rows, options))
-        }
-        DataType::Interval(IntervalUnit::YearMonth) => {
-            Arc::new(decode_primitive::<IntervalYearMonthType>(rows, options))
-        }
-        DataType::Binary => Arc::new(decode_binary::<i32>(rows, options)),
-        DataType::LargeBinary => Arc::new(decode_binary::<i64>(rows, options)),
-        DataType::Utf8 => Arc::new(decode_string::<i32>(rows, options)),
-        DataType::LargeUtf8 => Arc::new(decode_string::<i64>(rows, options)),
-        DataType::Decimal128(p, s) => {
-            Arc::new(decode_decimal::<16, Decimal128Type>(rows, options, *p, *s))
-        }
-        DataType::Decimal256(p, s) => {
-            Arc::new(decode_decimal::<32, Decimal256Type>(rows, options, *p, *s))
-        }
-        DataType::Dictionary(k, v) => match k.as_ref() {
-            DataType::Int8 => Arc::new(decode_dictionary::<Int8Type>(
-                interner.unwrap(),
-                v.as_ref(),
-                options,
-                rows,
-            )?),
-            DataType::Int16 => Arc::new(decode_dictionary::<Int16Type>(
-                interner.unwrap(),
-                v.as_ref(),
-                options,
-                rows,
-            )?),
-            DataType::Int32 => Arc::new(decode_dictionary::<Int32Type>(
-                interner.unwrap(),
-                v.as_ref(),
-                options,
-                rows,
-            )?),
-            DataType::Int64 => Arc::new(decode_dictionary::<Int64Type>(
-                interner.unwrap(),
-                v.as_ref(),
-                options,
-                rows,
-            )?),
-            DataType::UInt8 => Arc::new(decode_dictionary::<UInt8Type>(
-                interner.unwrap(),
-                v.as_ref(),
-                options,
-                rows,
-            )?),
-            DataType::UInt16 => Arc::new(decode_dictionary::<UInt16Type>(
-                interner.unwrap(),
-                v.as_ref(),
-                options,
-                rows,
-            )?),
-            DataType::UInt32 => Arc::new(decode_dictionary::<UInt32Type>(
-                interner.unwrap(),
-                v.as_ref(),
-                options,
-                rows,
-            )?),
-            DataType::UInt64 => Arc::new(decode_dictionary::<UInt64Type>(
-                interner.unwrap(),
-                v.as_ref(),
-                options,
-                rows,
-            )?),
-            _ => {
-                return Err(ArrowError::InvalidArgumentError(format!(
-                    "{} is not a valid dictionary key type",
+            DataType::UInt8 => {
+                Arc::new(decode_primitive::<UInt8Type>(rows, options, nullable))
+            }
+            DataType::UInt16 => {
+                Arc::new(decode_primitive::<UInt16Type>(rows, options, nullable))
+            }
+            DataType::UInt32 => {
+                Arc::new(decode_primitive::<UInt32Type>(rows, options, nullable))
+            }
+            DataType::UInt64 => {
+                Arc::new(decode_primitive::<UInt64Type>(rows, options, nullable))
+            }
+            DataType::Float16 => {
+                Arc::new(decode_primitive::<Float16Type>(rows, options, nullable))
+            }
+            DataType::Float32 => {
+                Arc::new(decode_primitive::<Float32Type>(rows, options, nullable))
+            }
+            DataType::Float64 => {
+                Arc::new(decode_primitive::<Float64Type>(rows, options, nullable))
+            }
+            DataType::Timestamp(TimeUnit::Second, _) => {
+                Arc::new(decode_primitive::<TimestampSecondType>(
+                    rows, options, nullable,
+                ))
+            }
+            DataType::Timestamp(TimeUnit::Millisecond, _) => Arc::new(
+                decode_primitive::<TimestampMillisecondType>(rows, options, nullable),
+            ),
+            DataType::Timestamp(TimeUnit::Microsecond, _) => Arc::new(
+                decode_primitive::<TimestampMicrosecondType>(rows, options, nullable),
+            ),
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+                Arc::new(decode_primitive::<TimestampNanosecondType>(
+                    rows, options, nullable,
+                ))
+            }
+            DataType::Date32 => {
+                Arc::new(decode_primitive::<Date32Type>(rows, options, nullable))
+            }
+            DataType::Date64 => {
+                Arc::new(decode_primitive::<Date64Type>(rows, options, nullable))
+            }
+            DataType::Time32(t) => match t {
+                TimeUnit::Second => Arc::new(decode_primitive::<Time32SecondType>(
+                    rows, options, nullable,
+                )),
+                TimeUnit::Millisecond => {
+                    Arc::new(decode_primitive::<Time32MillisecondType>(
+                        rows, options, nullable,
+                    ))
+                }
+                _ => unreachable!(),
+            },
+            DataType::Time64(t) => match t {
+                TimeUnit::Microsecond => {
+                    Arc::new(decode_primitive::<Time64MicrosecondType>(
+                        rows, options, nullable,
+                    ))
+                }
+                TimeUnit::Nanosecond => Arc::new(
+                    decode_primitive::<Time64NanosecondType>(rows, options, nullable),
+                ),
+                _ => unreachable!(),
+            },
+            DataType::Duration(TimeUnit::Second) => {
+                Arc::new(decode_primitive::<DurationSecondType>(
+                    rows, options, nullable,
+                ))
+            }
+            DataType::Duration(TimeUnit::Millisecond) => {
+                Arc::new(decode_primitive::<DurationMillisecondType>(
+                    rows, options, nullable,
+                ))
+            }
+            DataType::Duration(TimeUnit::Microsecond) => {
+                Arc::new(decode_primitive::<DurationMicrosecondType>(
+                    rows, options, nullable,
+                ))
+            }
+            DataType::Duration(TimeUnit::Nanosecond) => {
+                Arc::new(decode_primitive::<DurationNanosecondType>(
+                    rows, options, nullable,
+                ))
+            }
+            DataType::Interval(IntervalUnit::DayTime) => {
+                Arc::new(decode_primitive::<IntervalDayTimeType>(
+                    rows, options, nullable,
+                ))
+            }
+            DataType::Interval(IntervalUnit::MonthDayNano) => Arc::new(
+                decode_primitive::<IntervalMonthDayNanoType>(rows, options, nullable),
+            ),
+            DataType::Interval(IntervalUnit::YearMonth) => {
+                Arc::new(decode_primitive::<IntervalYearMonthType>(
+                    rows, options, nullable,
+                ))
+            }
+            DataType::Binary => Arc::new(decode_binary::<i32>(rows, options)),
+            DataType::LargeBinary => Arc::new(decode_binary::<i64>(rows, options)),
+            DataType::Utf8 => Arc::new(decode_string::<i32>(rows, options)),
+            DataType::LargeUtf8 => Arc::new(decode_string::<i64>(rows, options)),
+            DataType::Decimal128(p, s) => Arc::new(decode_decimal::<16, Decimal128Type>(
+                rows, options, nullable, *p, *s,
+            )),
+            DataType::Decimal256(p, s) => Arc::new(decode_decimal::<32, Decimal256Type>(
+                rows, options, nullable, *p, *s,
+            )),
+            DataType::Dictionary(k, v) => match k.as_ref() {
+                DataType::Int8 => Arc::new(decode_dictionary::<Int8Type>(
+                    interner.unwrap(),
+                    v.as_ref(),
+                    options,
+                    rows,
+                )?),
+                DataType::Int16 => Arc::new(decode_dictionary::<Int16Type>(
+                    interner.unwrap(),
+                    v.as_ref(),
+                    options,
+                    rows,
+                )?),
+                DataType::Int32 => Arc::new(decode_dictionary::<Int32Type>(
+                    interner.unwrap(),
+                    v.as_ref(),
+                    options,
+                    rows,
+                )?),
+                DataType::Int64 => Arc::new(decode_dictionary::<Int64Type>(
+                    interner.unwrap(),
+                    v.as_ref(),
+                    options,
+                    rows,
+                )?),
+                DataType::UInt8 => Arc::new(decode_dictionary::<UInt8Type>(
+                    interner.unwrap(),
+                    v.as_ref(),
+                    options,
+                    rows,
+                )?),
+                DataType::UInt16 => Arc::new(decode_dictionary::<UInt16Type>(
+                    interner.unwrap(),
+                    v.as_ref(),
+                    options,
+                    rows,
+                )?),
+                DataType::UInt32 => Arc::new(decode_dictionary::<UInt32Type>(
+                    interner.unwrap(),
+                    v.as_ref(),
+                    options,
+                    rows,
+                )?),
+                DataType::UInt64 => Arc::new(decode_dictionary::<UInt64Type>(
+                    interner.unwrap(),
+                    v.as_ref(),
+                    options,
+                    rows,
+                )?),
+                _ => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "{} is not a valid dictionary key type",
+                        field.data_type
+                    )));
+                }
+            },
+            DataType::Struct(_) => match codec {
+                Codec::Struct(converter, _) => {
+                    Arc::new(decode_struct(converter, rows, field)?)
+                }
+                _ => unreachable!(),
+            },
+            DataType::List(_) | DataType::Map(_, _) => match codec {
+                Codec::List(converter) => {
+                    make_array(list::decode::<i32>(converter, rows, field)?)
+                }
+                _ => unreachable!(),
+            },
+            DataType::LargeList(_) => match codec {
+                Codec::List(converter) => {
+                    make_array(list::decode::<i64>(converter, rows, field)?)
+                }
+                _ => unreachable!(),
+            },
+            DataType::FixedSizeList(_, size) => match codec {
+                Codec::FixedSizeList(converter, _) => make_array(
+                    list::decode_fixed_size(converter, rows, field, *size as usize)?,
+                ),
+                _ => unreachable!(),
+            },
+            DataType::Union(_, _, _) => match codec {
+                Codec::Union(converters) => decode_union(converters, rows, field)?,
+                _ => unreachable!(),
+            },
+            DataType::FixedSizeBinary(_) => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "converting {} row is not supported",
                     field.data_type
-                )));
+                )))
             }
-        },
-        DataType::FixedSizeBinary(_)
-        | DataType::List(_)
-        | DataType::FixedSizeList(_, _)
-        | DataType::LargeList(_)
-        | DataType::Struct(_)
-        | DataType::Union(_, _, _)
-        | DataType::Map(_, _) => {
-            return Err(ArrowError::NotYetImplemented(format!(
-                "converting {} row is not supported",
-                field.data_type
-            )))
-        }
-    };
+        };
     Ok(array)
 }
 
-#[cfg(test)]
-mod tests {
-    use std::sync::Arc;
+/// Decodes a struct array from `rows` with the provided `field`, using `converter`
+/// to decode its children
+///
+/// # Safety
+///
+/// `rows` must contain valid data for the provided `field` and `converter`
+unsafe fn decode_struct(
+    converter: &RowConverter,
+    rows: &mut [&[u8]],
+    field: &SortField,
+) -> Result<StructArray> {
+    let len = rows.len();
+    let mut null_count = 0;
+    let nulls = MutableBuffer::collect_bool(len, |x| {
+        let valid = rows[x][0] != null_sentinel(field.options);
+        null_count += !valid as usize;
+        valid
+    });
+
+    rows.iter_mut().for_each(|row| *row = &row[1..]);
+    let children = converter.convert_raw(rows)?;
+
+    let builder = ArrayDataBuilder::new(field.data_type.clone())
+        .len(len)
+        .null_count(null_count)
+        .null_bit_buffer(Some(nulls.into()))
+        .child_data(children.iter().map(|c| c.data().clone()).collect());
+
+    // SAFETY:
+    // Valid by construction above
+    Ok(StructArray::from(builder.build_unchecked()))
+}
 
-    use rand::distributions::uniform::SampleUniform;
-    use rand::distributions::{Distribution, Standard};
+/// Unions are encoded as their type id, negated if descending, followed by the
+/// row of their value in the child of that type id
+fn encode_union(
+    data: &mut [u8],
+    offsets: &mut [usize],
+    rows: &[Rows],
+    opts: SortOptions,
+    array: &UnionArray,
+) {
+    for (idx, offset) in offsets.iter_mut().skip(1).enumerate() {
+        let type_id = array.type_id(idx);
+        let row = rows[type_id as usize].row(array.value_offset(idx) as usize);
+        let end_offset = *offset + 1 + row.data.len();
+        data[*offset] = match opts.descending {
+            true => !(type_id as u8),
+            false => type_id as u8,
+        };
+        data[*offset + 1..end_offset].copy_from_slice(row.data);
+        *offset = end_offset;
+    }
+}
+
+/// Decodes a union array from `rows` with the provided `field`, using the
+/// `converters` of each of its children
+///
+/// # Safety
+///
+/// `rows` must contain valid data for the provided `field` and `converters`
+unsafe fn decode_union(
+    converters: &[RowConverter],
+    rows: &mut [&[u8]],
+    field: &SortField,
+) -> Result<ArrayRef> {
+    let len = rows.len();
+    let type_ids: Vec<i8> = rows
+        .iter_mut()
+        .map(|row| {
+            let type_id = match field.options.descending {
+                true => !row[0],
+                false => row[0],
+            };
+            *row = &row[1..];
+            type_id as i8
+        })
+        .collect();
+
+    let mut children = Vec::with_capacity(converters.len());
+    for (type_id, converter) in converters.iter().enumerate() {
+        let indices: Vec<_> = (0..len)
+            .filter(|i| type_ids[*i] as usize == type_id)
+            .collect();
+        let mut child_rows: Vec<_> = indices.iter().map(|i| rows[*i]).collect();
+        let child = converter.convert_raw(&mut child_rows)?.remove(0);
+        for (i, row) in indices.iter().zip(child_rows) {
+            rows[*i] = row;
+        }
+        children.push((indices, child));
+    }
+
+    let builder = ArrayDataBuilder::new(field.data_type.clone())
+        .len(len)
+        .add_buffer(Buffer::from_slice_ref(&type_ids));
+
+    let builder = match &field.data_type {
+        DataType::Union(_, _, UnionMode::Dense) => {
+            // the values of each child are in the order of the rows
+            let mut offsets = vec![0_i32; len];
+            for (indices, _) in &children {
+                for (offset, i) in indices.iter().enumerate() {
+                    offsets[*i] = offset as i32;
+                }
+            }
+            let children = children.into_iter().map(|(_, c)| c.data().clone());
+            builder
+                .add_buffer(Buffer::from_slice_ref(&offsets))
+                .child_data(children.collect())
+        }
+        _ => {
+            // the children of a sparse union have a slot for each row, which is null
+            // for the rows of another type id
+            let children = children
+                .into_iter()
+                .map(|(indices, child)| {
+                    let nulls = new_null_array(child.data_type(), 1);
+                    let mut positions = vec![(1, 0); len];
+                    for (position, i) in indices.iter().enumerate() {
+                        positions[*i] = (0, position);
+                    }
+                    let child =
+                        interleave(&[child.as_ref(), nulls.as_ref()], &positions)?;
+                    Ok(child.data().clone())
+                })
+                .collect::<Result<_>>()?;
+            builder.child_data(children)
+        }
+    };
+
+    // SAFETY:
+    // Valid by construction above
+    Ok(make_array(builder.build_unchecked()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rand::distributions::uniform::SampleUniform;
+    use rand::distributions::{Distribution, Standard};
     use rand::{thread_rng, Rng};
 
     use arrow_array::NullArray;
 
     use crate::array::{
-        BinaryArray, BooleanArray, DictionaryArray, Float32Array, GenericStringArray,
-        Int16Array, Int32Array, OffsetSizeTrait, PrimitiveArray,
-        PrimitiveDictionaryBuilder, StringArray,
+        BinaryArray, BooleanArray, DictionaryArray, FixedSizeListArray, Float32Array,
+        GenericStringArray, Int16Array, Int32Array, LargeListArray, ListArray, MapArray,
+        OffsetSizeTrait, PrimitiveArray, PrimitiveDictionaryBuilder, StringArray,
+        StructArray, UnionBuilder,
     };
     use crate::compute::{LexicographicalComparator, SortColumn};
     use crate::util::display::array_value_to_string;
+    use arrow_data::ArrayData;
 
     use super::*;
 
@@ -778,9 +2186,9 @@ mod tests {
         ]);
         let rows = converter.convert_columns(&cols).unwrap();
 
-        assert_eq!(rows.offsets.as_ref(), &[0, 8, 16, 24, 32, 40, 48, 56]);
+        assert_eq!(rows.offsets.as_slice(), &[0, 8, 16, 24, 32, 40, 48, 56]);
         assert_eq!(
-            rows.buffer.as_ref(),
+            rows.buffer.as_slice(),
             &[
                 1, 128, 1, //
                 1, 191, 166, 102, 102, //
@@ -869,6 +2277,11 @@ mod tests {
         assert!(rows.row(3) < rows.row(0));
         assert!(rows.row(3) < rows.row(1));
 
+        // Short values are encoded using a single mini block
+        assert_eq!(rows.row(0).as_ref().len(), 2 + variable::MINI_BLOCK_SIZE);
+        assert_eq!(rows.row(2).as_ref().len(), 1);
+        assert_eq!(rows.row(4).as_ref().len(), 1);
+
         let cols = converter.convert_rows(&rows).unwrap();
         assert_eq!(&cols[0], &col);
 
@@ -876,12 +2289,18 @@ mod tests {
             None,
             Some(vec![0_u8; 0]),
             Some(vec![0_u8; 6]),
+            Some(vec![0_u8; variable::MINI_BLOCK_SIZE]),
+            Some(vec![0_u8; variable::MINI_BLOCK_SIZE + 1]),
             Some(vec![0_u8; variable::BLOCK_SIZE]),
             Some(vec![0_u8; variable::BLOCK_SIZE + 1]),
             Some(vec![1_u8; 6]),
+            Some(vec![1_u8; variable::MINI_BLOCK_SIZE]),
+            Some(vec![1_u8; variable::MINI_BLOCK_SIZE + 1]),
             Some(vec![1_u8; variable::BLOCK_SIZE]),
             Some(vec![1_u8; variable::BLOCK_SIZE + 1]),
             Some(vec![0xFF_u8; 6]),
+            Some(vec![0xFF_u8; variable::MINI_BLOCK_SIZE]),
+            Some(vec![0xFF_u8; variable::MINI_BLOCK_SIZE + 1]),
             Some(vec![0xFF_u8; variable::BLOCK_SIZE]),
             Some(vec![0xFF_u8; variable::BLOCK_SIZE + 1]),
         ])) as ArrayRef;
@@ -931,6 +2350,34 @@ mod tests {
         assert_eq!(&cols[0], &col);
     }
 
+    #[test]
+    fn test_variable_width_block_boundaries() {
+        let values: Vec<_> = (0..100_u8).map(|len| vec![len; len as usize]).collect();
+        let col = Arc::new(BinaryArray::from_iter_values(&values)) as ArrayRef;
+
+        for descending in [false, true] {
+            let options = SortOptions {
+                descending,
+                nulls_first: false,
+            };
+            let mut converter = RowConverter::new(vec![SortField::new_with_options(
+                DataType::Binary,
+                options,
+            )]);
+            let rows = converter.convert_columns(&[Arc::clone(&col)]).unwrap();
+
+            for (row, value) in rows.into_iter().zip(&values) {
+                let expected = variable::encoded_len(Some(value));
+                assert_eq!(row.as_ref().len(), expected, "{}", value.len());
+            }
+            // 1 sentinel byte + 4 mini blocks + 1 block
+            assert_eq!(rows.row(33).as_ref().len(), 1 + 4 * 9 + 33);
+
+            let cols = converter.convert_rows(&rows).unwrap();
+            assert_eq!(&cols[0], &col);
+        }
+    }
+
     #[test]
     fn test_string_dictionary() {
         let a = Arc::new(DictionaryArray::<Int32Type>::from_iter([
@@ -1054,6 +2501,1107 @@ mod tests {
         let _ = converter.convert_rows(&rows);
     }
 
+    #[test]
+    fn test_convert_selected_rows() {
+        let values = Arc::new(StringArray::from(vec![
+            Some("a"),
+            None,
+            Some("c"),
+            Some("d"),
+        ])) as ArrayRef;
+        let mut converter = RowConverter::new(vec![SortField::new(DataType::Utf8)]);
+        let rows = converter.convert_columns(&[values]).unwrap();
+
+        let filter = BooleanArray::from(vec![false, true, false, true]);
+        let selection = Selection::from_filter(&filter);
+        let back = converter.convert_selected_rows(&rows, &selection).unwrap();
+        assert_eq!(back[0].as_ref(), &StringArray::from(vec![None, Some("d")]));
+
+        let err = converter
+            .convert_selected_rows(&rows, &Selection::all(3))
+            .unwrap_err();
+        assert!(err.to_string().contains("cannot be applied"), "{}", err);
+    }
+
+    #[test]
+    fn test_struct() {
+        let a = Arc::new(Int32Array::from(vec![
+            Some(1),
+            Some(1),
+            None,
+            Some(2),
+            Some(1),
+        ])) as ArrayRef;
+        let b = Arc::new(StringArray::from(vec!["b", "a", "c", "a", "x"])) as ArrayRef;
+        let data_type = DataType::Struct(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+        ]);
+        let data = ArrayData::builder(data_type.clone())
+            .len(5)
+            .child_data(vec![a.data().clone(), b.data().clone()])
+            .null_bit_buffer(Some(Buffer::from([0b00001111])))
+            .build()
+            .unwrap();
+        let column = Arc::new(StructArray::from(data)) as ArrayRef;
+
+        let mut converter = RowConverter::new(vec![SortField::new(data_type.clone())]);
+        let rows = converter.convert_columns(&[Arc::clone(&column)]).unwrap();
+
+        assert!(rows.row(1) < rows.row(0)); // {1, "a"} < {1, "b"}
+        assert!(rows.row(0) < rows.row(3)); // {1, "b"} < {2, "a"}
+        assert!(rows.row(2) < rows.row(1)); // {null, "c"} < {1, "a"}
+        assert!(rows.row(4) < rows.row(2)); // null < {null, "c"}
+
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back[0].data(), column.data());
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let field = SortField::new_with_options(data_type, options);
+        let mut converter = RowConverter::new(vec![field]);
+        let sliced = column.slice(1, 4);
+        let rows = converter.convert_columns(&[Arc::clone(&sliced)]).unwrap();
+
+        assert!(rows.row(0) > rows.row(2)); // {1, "a"} > {2, "a"}
+        assert!(rows.row(1) > rows.row(0)); // {null, "c"} > {1, "a"}
+        assert!(rows.row(3) > rows.row(1)); // null > {null, "c"}
+
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back[0].data(), sliced.data());
+    }
+
+    #[test]
+    fn test_list() {
+        let list = Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(32), Some(52), Some(32)]),
+            Some(vec![Some(32), Some(52), Some(12)]),
+            Some(vec![Some(32), Some(52)]),
+            Some(vec![Some(32), Some(52)]),
+            None,
+            Some(vec![Some(32), None]),
+            Some(vec![]),
+        ])) as ArrayRef;
+
+        let field = SortField::new(list.data_type().clone());
+        let mut converter = RowConverter::new(vec![field]);
+        let rows = converter.convert_columns(&[Arc::clone(&list)]).unwrap();
+
+        assert!(rows.row(0) > rows.row(1)); // [32, 52, 32] > [32, 52, 12]
+        assert!(rows.row(2) < rows.row(1)); // [32, 52] < [32, 52, 12]
+        assert_eq!(rows.row(2), rows.row(3)); // [32, 52] = [32, 52]
+        assert!(rows.row(4) < rows.row(6)); // null < []
+        assert!(rows.row(5) < rows.row(2)); // [32, null] < [32, 52]
+        assert!(rows.row(6) < rows.row(5)); // [] < [32, null]
+
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back[0].as_ref(), list.as_ref());
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let field = SortField::new_with_options(list.data_type().clone(), options);
+        let mut converter = RowConverter::new(vec![field]);
+        let rows = converter.convert_columns(&[Arc::clone(&list)]).unwrap();
+
+        assert!(rows.row(0) < rows.row(1)); // [32, 52, 32] < [32, 52, 12]
+        assert!(rows.row(2) > rows.row(1)); // [32, 52] > [32, 52, 12]
+        assert_eq!(rows.row(2), rows.row(3)); // [32, 52] = [32, 52]
+        assert!(rows.row(4) < rows.row(6)); // null < []
+        assert!(rows.row(5) < rows.row(2)); // [32, null] < [32, 52]
+        assert!(rows.row(6) > rows.row(5)); // [] > [32, null]
+
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back[0].as_ref(), list.as_ref());
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let field = SortField::new_with_options(list.data_type().clone(), options);
+        let mut converter = RowConverter::new(vec![field]);
+        let rows = converter.convert_columns(&[Arc::clone(&list)]).unwrap();
+
+        assert!(rows.row(0) < rows.row(1)); // [32, 52, 32] < [32, 52, 12]
+        assert!(rows.row(4) > rows.row(6)); // null > []
+        assert!(rows.row(5) > rows.row(2)); // [32, null] > [32, 52]
+        assert!(rows.row(6) > rows.row(5)); // [] > [32, null]
+
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back[0].as_ref(), list.as_ref());
+    }
+
+    #[test]
+    fn test_large_list_of_strings() {
+        let strings = StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            None,
+            Some("a very long string that spans more than one block"),
+            Some(""),
+            Some("b"),
+        ]);
+        let data_type =
+            DataType::LargeList(Box::new(Field::new("item", DataType::Utf8, true)));
+        let data = ArrayData::builder(data_type.clone())
+            .len(5)
+            .add_buffer(Buffer::from_slice_ref(&[0_i64, 2, 2, 4, 5, 6]))
+            .add_child_data(strings.into_data())
+            .null_bit_buffer(Some(Buffer::from([0b00011101])))
+            .build()
+            .unwrap();
+        let list = LargeListArray::from(data);
+        let list = list.slice(1, 4);
+
+        let mut converter = RowConverter::new(vec![SortField::new(data_type)]);
+        let rows = converter.convert_columns(&[Arc::clone(&list)]).unwrap();
+
+        assert!(rows.row(0) < rows.row(1)); // null < [null, "a very long..."]
+        assert!(rows.row(2) < rows.row(3)); // [""] < ["b"]
+        assert!(rows.row(1) < rows.row(2)); // [null, "a very long..."] < [""]
+
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back[0].as_ref(), list.as_ref());
+    }
+
+    #[test]
+    fn test_fixed_size_list() {
+        let values = Int32Array::from(vec![
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(1),
+            Some(2),
+            Some(4),
+            None,
+            None,
+            None,
+            Some(1),
+            None,
+            Some(3),
+            Some(0),
+            Some(9),
+            Some(9),
+        ]);
+        let data_type = DataType::FixedSizeList(
+            Box::new(Field::new("item", DataType::Int32, true)),
+            3,
+        );
+        let data = ArrayData::builder(data_type.clone())
+            .len(5)
+            .add_child_data(values.into_data())
+            .null_bit_buffer(Some(Buffer::from([0b00011011])))
+            .build()
+            .unwrap();
+        let list = Arc::new(FixedSizeListArray::from(data)) as ArrayRef;
+
+        let mut converter = RowConverter::new(vec![SortField::new(data_type.clone())]);
+        let rows = converter.convert_columns(&[Arc::clone(&list)]).unwrap();
+
+        // lists of primitives are encoded with a fixed width
+        assert!((&rows).into_iter().all(|row| row.data.len() == 1 + 3 * 5));
+        assert!(rows.row(0) < rows.row(1)); // [1, 2, 3] < [1, 2, 4]
+        assert!(rows.row(3) < rows.row(0)); // [1, null, 3] < [1, 2, 3]
+        assert!(rows.row(2) < rows.row(3)); // null < [1, null, 3]
+        assert!(rows.row(4) < rows.row(0)); // [0, 9, 9] < [1, 2, 3]
+
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back[0].data(), list.data());
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let field = SortField::new_with_options(data_type, options);
+        let mut converter = RowConverter::new(vec![field]);
+        let sliced = list.slice(1, 3);
+        let rows = converter.convert_columns(&[Arc::clone(&sliced)]).unwrap();
+
+        assert!(rows.row(0) < rows.row(2)); // [1, 2, 4] < [1, null, 3]
+        assert!(rows.row(2) < rows.row(1)); // [1, null, 3] < null
+
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back[0].data(), sliced.data());
+    }
+
+    #[test]
+    fn test_union() {
+        fn build(mut builder: UnionBuilder) -> ArrayRef {
+            builder.append::<Int32Type>("a", 1).unwrap();
+            builder.append::<Float64Type>("b", 3.0).unwrap();
+            builder.append::<Int32Type>("a", 4).unwrap();
+            builder.append_null::<Float64Type>("b").unwrap();
+            builder.append::<Int32Type>("a", -1).unwrap();
+            Arc::new(builder.build().unwrap())
+        }
+
+        for union in [
+            build(UnionBuilder::new_dense()),
+            build(UnionBuilder::new_sparse()),
+        ] {
+            let field = SortField::new(union.data_type().clone());
+            let mut converter = RowConverter::new(vec![field]);
+            let rows = converter.convert_columns(&[Arc::clone(&union)]).unwrap();
+
+            assert!(rows.row(4) < rows.row(0)); // a: -1 < a: 1
+            assert!(rows.row(0) < rows.row(2)); // a: 1 < a: 4
+            assert!(rows.row(2) < rows.row(3)); // a: 4 < b: null
+            assert!(rows.row(3) < rows.row(1)); // b: null < b: 3.0
+
+            let back = converter.convert_rows(&rows).unwrap();
+            assert_eq!(back[0].data(), union.data());
+
+            let options = SortOptions {
+                descending: true,
+                nulls_first: false,
+            };
+            let field = SortField::new_with_options(union.data_type().clone(), options);
+            let mut converter = RowConverter::new(vec![field]);
+            let rows = converter.convert_columns(&[Arc::clone(&union)]).unwrap();
+
+            assert!(rows.row(1) < rows.row(3)); // b: 3.0 < b: null
+            assert!(rows.row(3) < rows.row(2)); // b: null < a: 4
+            assert!(rows.row(2) < rows.row(0)); // a: 4 < a: 1
+
+            let back = converter.convert_rows(&rows).unwrap();
+            assert_eq!(back[0].data(), union.data());
+        }
+    }
+
+    #[test]
+    fn test_map() {
+        let keys = vec!["a", "b", "a", "a", "c", "a", "b", "a"];
+        let values = Int32Array::from(vec![
+            Some(1),
+            Some(2),
+            Some(1),
+            Some(1),
+            Some(0),
+            None,
+            Some(2),
+            Some(1),
+        ]);
+        // {a: 1, b: 2}, {a: 1}, {a: 1, c: 0}, {}, {a: null, b: 2}, {a: 1}
+        let offsets = [0, 2, 3, 5, 5, 7, 8];
+        let map =
+            MapArray::new_from_strings(keys.into_iter(), &values, &offsets).unwrap();
+        let map = Arc::new(map) as ArrayRef;
+
+        let mut converter =
+            RowConverter::new(vec![SortField::new(map.data_type().clone())]);
+        let rows = converter.convert_columns(&[Arc::clone(&map)]).unwrap();
+
+        assert_eq!(rows.row(1), rows.row(5)); // {a: 1} = {a: 1}
+        assert!(rows.row(1) < rows.row(0)); // {a: 1} < {a: 1, b: 2}
+        assert!(rows.row(0) < rows.row(2)); // {a: 1, b: 2} < {a: 1, c: 0}
+        assert!(rows.row(3) < rows.row(4)); // {} < {a: null, b: 2}
+        assert!(rows.row(4) < rows.row(1)); // {a: null, b: 2} < {a: 1}
+
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back[0].data(), map.data());
+
+        let sliced = map.slice(2, 3);
+        let rows = converter.convert_columns(&[Arc::clone(&sliced)]).unwrap();
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back[0].data(), sliced.data());
+    }
+
+    #[test]
+    fn test_rows_to_bytes() {
+        let strings = Arc::new(StringArray::from(vec![
+            Some("hello"),
+            None,
+            Some("a string longer than the block size of the encoding"),
+        ])) as ArrayRef;
+        let dictionary = Arc::new(
+            vec![Some("b"), Some("a"), None]
+                .into_iter()
+                .collect::<DictionaryArray<Int32Type>>(),
+        ) as ArrayRef;
+        let columns = vec![strings, dictionary];
+
+        let fields = vec![
+            SortField::new(DataType::Utf8),
+            SortField::new(columns[1].data_type().clone()),
+        ];
+        let mut converter = RowConverter::new(fields.clone());
+        let rows = converter.convert_columns(&columns).unwrap();
+
+        let bytes = rows.to_bytes();
+        let spilled = unsafe { converter.rows_from_bytes(&bytes) }.unwrap();
+        assert_eq!(spilled.num_rows(), 3);
+        for i in 0..3 {
+            assert_eq!(spilled.row(i), rows.row(i));
+        }
+        let back = converter.convert_rows(&spilled).unwrap();
+        assert_eq!(&back, &columns);
+
+        let err = unsafe { converter.rows_from_bytes(&bytes[..bytes.len() - 1]) };
+        assert!(err.unwrap_err().to_string().contains("expected"));
+
+        let mut fields = fields;
+        fields[0] = SortField::new_with_options(
+            DataType::Utf8,
+            SortOptions {
+                descending: true,
+                nulls_first: true,
+            },
+        );
+        let converter = RowConverter::new(fields);
+        let err = unsafe { converter.rows_from_bytes(&bytes) }.unwrap_err();
+        assert!(err.to_string().contains("do not match"), "{}", err);
+    }
+
+    #[test]
+    fn test_owned_row_and_parser() {
+        let values = Arc::new(StringArray::from(vec![
+            Some("b"),
+            Some("a"),
+            None,
+            Some("b"),
+        ])) as ArrayRef;
+        let mut converter = RowConverter::new(vec![SortField::new(DataType::Utf8)]);
+
+        let mut counts = std::collections::HashMap::new();
+        {
+            let rows = converter.convert_columns(&[Arc::clone(&values)]).unwrap();
+            for row in &rows {
+                *counts.entry(row.owned()).or_insert(0) += 1;
+            }
+        }
+        // the rows are no longer alive
+        let mut owned: Vec<_> = counts.into_iter().collect();
+        owned.sort_unstable();
+        let counts: Vec<_> = owned.iter().map(|(_, count)| *count).collect();
+        assert_eq!(counts, vec![1, 1, 2]);
+
+        let back = converter
+            .convert_rows(owned.iter().map(|(row, _)| row.row()))
+            .unwrap();
+        assert_eq!(
+            back[0].as_ref(),
+            &StringArray::from(vec![None, Some("a"), Some("b")])
+        );
+
+        let stored: Vec<Vec<u8>> =
+            owned.iter().map(|(row, _)| row.as_ref().to_vec()).collect();
+        let parser = converter.parser();
+        let parsed: Vec<_> = stored
+            .iter()
+            .map(|bytes| unsafe { parser.parse(bytes) })
+            .collect();
+        assert!(parsed[1] < parsed[2]);
+        assert_eq!(parsed[2], owned[2].0.row());
+        let back = converter.convert_rows(parsed).unwrap();
+        assert_eq!(back[0].len(), 3);
+    }
+
+    #[test]
+    fn test_convert_rows_with_projection() {
+        let mut converter = RowConverter::new(vec![
+            SortField::new(DataType::Utf8),
+            SortField::new_with_options(
+                DataType::Int32,
+                SortOptions {
+                    descending: true,
+                    nulls_first: false,
+                },
+            ),
+            SortField::new(DataType::Float64),
+        ]);
+
+        let a =
+            Arc::new(StringArray::from(vec![Some("a"), None, Some("bc")])) as ArrayRef;
+        let b = Arc::new(Int32Array::from(vec![None, Some(2), Some(-3)])) as ArrayRef;
+        let c = Arc::new(Float64Array::from(vec![1.5, 2.5, 3.5])) as ArrayRef;
+        let rows = converter
+            .convert_columns(&[a.clone(), b.clone(), c.clone()])
+            .unwrap();
+
+        let back = converter.convert_rows_with_projection(&rows, &[0]).unwrap();
+        assert_eq!(back.len(), 1);
+        assert_eq!(back[0].data(), a.data());
+
+        let back = converter
+            .convert_rows_with_projection(&rows, &[2, 0, 1, 2])
+            .unwrap();
+        assert_eq!(back.len(), 4);
+        assert_eq!(back[0].data(), c.data());
+        assert_eq!(back[1].data(), a.data());
+        assert_eq!(back[2].data(), b.data());
+        assert_eq!(back[3].data(), c.data());
+
+        let back = converter.convert_rows_with_projection(&rows, &[]).unwrap();
+        assert!(back.is_empty());
+
+        let err = converter
+            .convert_rows_with_projection(&rows, &[3])
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: projection index 3 out of bounds for RowConverter with 3 fields"
+        );
+    }
+
+    #[test]
+    fn test_append() {
+        let mut converter = RowConverter::new(vec![
+            SortField::new(DataType::Int32),
+            SortField::new(DataType::Utf8),
+        ]);
+
+        let a1 = Arc::new(Int32Array::from(vec![Some(3), None])) as ArrayRef;
+        let b1 = Arc::new(StringArray::from(vec![Some("foo"), Some("bar")])) as ArrayRef;
+        let a2 = Arc::new(Int32Array::from(vec![Some(1), Some(3), None])) as ArrayRef;
+        let b2 =
+            Arc::new(StringArray::from(vec![None, Some("foo"), Some("a")])) as ArrayRef;
+
+        let mut rows = converter.empty_rows(5, 64);
+        converter
+            .append(&mut rows, &[a1.clone(), b1.clone()])
+            .unwrap();
+        converter
+            .append(&mut rows, &[a2.clone(), b2.clone()])
+            .unwrap();
+        assert_eq!(rows.num_rows(), 5);
+
+        let r1 = converter.convert_columns(&[a1, b1]).unwrap();
+        let r2 = converter.convert_columns(&[a2, b2]).unwrap();
+        let expected: Vec<_> = r1.into_iter().chain(&r2).collect();
+        let actual: Vec<_> = (&rows).into_iter().collect();
+        assert_eq!(actual, expected);
+        assert_eq!(rows.row(0), rows.row(3));
+
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(
+            back[0].as_ref(),
+            &Int32Array::from(vec![Some(3), None, Some(1), Some(3), None])
+        );
+        assert_eq!(
+            back[1].as_ref(),
+            &StringArray::from(vec![
+                Some("foo"),
+                Some("bar"),
+                None,
+                Some("foo"),
+                Some("a")
+            ])
+        );
+
+        rows.clear();
+        assert_eq!(rows.num_rows(), 0);
+        let a3 = Arc::new(Int32Array::from(vec![Some(2)])) as ArrayRef;
+        let b3 = Arc::new(StringArray::from(vec![Some("baz")])) as ArrayRef;
+        converter
+            .append(&mut rows, &[a3.clone(), b3.clone()])
+            .unwrap();
+        let r3 = converter.convert_columns(&[a3, b3]).unwrap();
+        assert_eq!(rows.num_rows(), 1);
+        assert_eq!(rows.row(0), r3.row(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "rows were not produced by this RowConverter")]
+    fn test_append_different_converter() {
+        let fields = vec![SortField::new(DataType::Int32)];
+        let c1 = RowConverter::new(fields.clone());
+        let mut c2 = RowConverter::new(fields);
+        let a = Arc::new(Int32Array::from(vec![1])) as ArrayRef;
+        let mut rows = c1.empty_rows(1, 8);
+        c2.append(&mut rows, &[a]).unwrap();
+    }
+
+    #[test]
+    fn test_row_prefix() {
+        let mut converter = RowConverter::new(vec![
+            SortField::new(DataType::Utf8),
+            SortField::new(DataType::Int32),
+        ]);
+        let a = Arc::new(StringArray::from(vec![
+            Some("foo"),
+            Some("foobar"),
+            Some("foobaz"),
+            None,
+            Some(""),
+            Some("foo"),
+        ])) as ArrayRef;
+        let b = Arc::new(Int32Array::from(vec![1, 1, 1, 1, 2, 0])) as ArrayRef;
+        let rows = converter.convert_columns(&[a, b]).unwrap();
+
+        for len in [0, 1, 3, 8, 16, 64] {
+            for l in &rows {
+                for r in &rows {
+                    let (lp, rp) = (l.prefix(len), r.prefix(len));
+                    assert_eq!(lp.data(), &l.as_ref()[..lp.data().len()]);
+                    assert!(lp.data().len() <= len);
+                    assert_eq!(lp.is_truncated(), l.as_ref().len() > len);
+
+                    match lp.cmp(&rp) {
+                        Ordering::Equal if lp.is_truncated() => {
+                            assert!(rp.is_truncated())
+                        }
+                        ord => assert_eq!(ord, l.cmp(&r), "{} {:?} {:?}", len, l, r),
+                    }
+                }
+            }
+        }
+
+        let full = rows.row(0).prefix(usize::MAX);
+        assert!(!full.is_truncated());
+        assert_eq!(full.data(), rows.row(0).as_ref());
+    }
+
+    #[test]
+    fn test_non_nullable() {
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let fields = vec![
+            SortField::new(DataType::Int32).with_nullable(false),
+            SortField::new_with_options(DataType::Boolean, options).with_nullable(false),
+            SortField::new_with_options(DataType::Decimal128(10, 2), options)
+                .with_nullable(false),
+            SortField::new(DataType::Utf8).with_nullable(false),
+            SortField::new(DataType::Float64),
+        ];
+        let mut converter = RowConverter::new(fields.clone());
+
+        let cols = vec![
+            Arc::new(Int32Array::from(vec![1, -2, 1, 1])) as ArrayRef,
+            Arc::new(BooleanArray::from(vec![true, false, false, true])) as ArrayRef,
+            Arc::new(
+                Decimal128Array::from_iter_values([5, 3, -1, 5])
+                    .with_precision_and_scale(10, 2)
+                    .unwrap(),
+            ) as ArrayRef,
+            Arc::new(StringArray::from(vec!["a", "b", "c", "a"])) as ArrayRef,
+            Arc::new(Float64Array::from(vec![Some(1.), None, None, Some(2.)]))
+                as ArrayRef,
+        ];
+        let rows = converter.convert_columns(&cols).unwrap();
+
+        // 4 + 1 + 16 bytes of values, a 10 byte string and a 9 byte float
+        assert_eq!(rows.row(0).as_ref().len(), 21 + 10 + 9);
+
+        let nullable_fields: Vec<_> =
+            fields.into_iter().map(|f| f.with_nullable(true)).collect();
+        let mut nullable = RowConverter::new(nullable_fields);
+        let nullable_rows = nullable.convert_columns(&cols).unwrap();
+        assert_eq!(
+            nullable_rows.row(0).as_ref().len(),
+            rows.row(0).as_ref().len() + 3
+        );
+        for i in 0..rows.num_rows() {
+            for j in 0..rows.num_rows() {
+                assert_eq!(
+                    rows.row(i).cmp(&rows.row(j)),
+                    nullable_rows.row(i).cmp(&nullable_rows.row(j)),
+                );
+            }
+        }
+
+        let back = converter.convert_rows(&rows).unwrap();
+        for (actual, expected) in back.iter().zip(&cols) {
+            assert_eq!(actual.data(), expected.data());
+            if expected.null_count() == 0 {
+                assert!(actual.data().null_buffer().is_none());
+            }
+        }
+
+        let serialized = rows.to_bytes();
+        assert!(unsafe { nullable.rows_from_bytes(&serialized) }.is_err());
+
+        let mut cols = cols;
+        cols[0] = Arc::new(Int32Array::from(vec![Some(1), None, Some(1), Some(1)]));
+        let err = converter.convert_columns(&cols).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: RowConverter column of type Int32 is not nullable but contains 1 nulls"
+        );
+    }
+
+    #[test]
+    fn test_binary_search() {
+        let mut rng = thread_rng();
+        let len = 200;
+        let a: Int32Array = (0..len)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..10)))
+            .collect();
+        let b: StringArray = (0..len)
+            .map(|_| {
+                rng.gen_bool(0.9)
+                    .then(|| ["a", "ab", "b"][rng.gen_range(0..3)])
+            })
+            .collect();
+        let (a, b) = (Arc::new(a) as ArrayRef, Arc::new(b) as ArrayRef);
+        let fields = vec![
+            SortField::new(DataType::Int32),
+            SortField::new_with_options(
+                DataType::Utf8,
+                SortOptions {
+                    descending: true,
+                    nulls_first: false,
+                },
+            ),
+        ];
+
+        let mut converter = RowConverter::new(fields.clone());
+        let unsorted = converter.convert_columns(&[a.clone(), b.clone()]).unwrap();
+        let mut sorted_rows: Vec<_> = (&unsorted).into_iter().collect();
+        sorted_rows.sort_unstable();
+        let sorted = converter.convert_rows(sorted_rows).unwrap();
+        let rows = converter.convert_columns(&sorted).unwrap();
+
+        for row in &unsorted {
+            let idx = rows.binary_search(row).unwrap();
+            assert_eq!(rows.row(idx), row);
+            let first = rows.partition_point(|r| r < row);
+            assert_eq!(first, (&rows).into_iter().filter(|r| *r < row).count());
+        }
+        let missing = converter
+            .convert_columns(&[
+                Arc::new(Int32Array::from(vec![20])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["z"])) as ArrayRef,
+            ])
+            .unwrap();
+        assert_eq!(rows.binary_search(missing.row(0)), Err(rows.num_rows()));
+
+        let mut key_converter = RowConverter::new(fields[..1].to_vec());
+        let keys = Arc::new(Int32Array::from(vec![None, Some(0), Some(5), Some(10)]));
+        let keys = key_converter.convert_columns(&[keys]).unwrap();
+        let sorted_a = as_primitive_array::<Int32Type>(&sorted[0]);
+        for (key, expected) in (&keys).into_iter().zip([None, Some(0), Some(5), Some(10)])
+        {
+            let range = rows.prefix_range(key.as_ref());
+            let matching: Vec<_> = (0..rows.num_rows())
+                .filter(|i| sorted_a.is_valid(*i).then(|| sorted_a.value(*i)) == expected)
+                .collect();
+            assert_eq!(
+                range.clone().collect::<Vec<_>>(),
+                matching,
+                "{:?}",
+                expected
+            );
+        }
+        assert_eq!(rows.prefix_range(&[]), 0..rows.num_rows());
+    }
+
+    #[test]
+    fn test_parse_checked() {
+        let descending = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let ints = Arc::new(Int32Array::from(vec![Some(1), None, Some(-5), Some(7)]))
+            as ArrayRef;
+        let strings = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("a very long string spanning multiple blocks"),
+            None,
+            Some(""),
+        ])) as ArrayRef;
+        let dict = Arc::new(DictionaryArray::<Int32Type>::from_iter([
+            Some("x"),
+            None,
+            Some("y"),
+            Some("x"),
+        ])) as ArrayRef;
+        let list = Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(3)]),
+        ])) as ArrayRef;
+        let strukt = Arc::new(StructArray::from(vec![
+            (Field::new("a", DataType::Int32, true), Arc::clone(&ints)),
+            (Field::new("b", DataType::Utf8, true), Arc::clone(&strings)),
+        ])) as ArrayRef;
+        let mut builder = UnionBuilder::new_sparse();
+        builder.append::<Int32Type>("a", 1).unwrap();
+        builder.append::<Float64Type>("b", 3.0).unwrap();
+        builder.append_null::<Float64Type>("b").unwrap();
+        builder.append::<Int32Type>("a", 4).unwrap();
+        let union = Arc::new(builder.build().unwrap()) as ArrayRef;
+
+        let cols = vec![ints, strings, dict, list, strukt, union];
+        let fields = cols
+            .iter()
+            .enumerate()
+            .map(|(idx, c)| match idx % 2 {
+                0 => SortField::new(c.data_type().clone()),
+                _ => SortField::new_with_options(c.data_type().clone(), descending),
+            })
+            .collect();
+        let mut converter = RowConverter::new(fields);
+        let rows = converter.convert_columns(&cols).unwrap();
+
+        let parsed: Vec<_> = (&rows)
+            .into_iter()
+            .map(|row| converter.parse_checked(row.data).unwrap())
+            .collect();
+        let back = converter.convert_rows(parsed).unwrap();
+        for (actual, expected) in back.iter().zip(&cols) {
+            assert_eq!(actual.data(), expected.data());
+        }
+
+        let checked = converter.rows_from_bytes_checked(&rows.to_bytes()).unwrap();
+        assert_eq!(checked.num_rows(), rows.num_rows());
+
+        // Any truncated or extended row is invalid
+        for row in &rows {
+            let bytes = row.as_ref();
+            for len in 0..bytes.len() {
+                assert!(converter.parse_checked(&bytes[..len]).is_err());
+            }
+            let mut extended = bytes.to_vec();
+            extended.push(0);
+            let err = converter.parse_checked(&extended).unwrap_err().to_string();
+            assert_eq!(
+                err,
+                "Invalid argument error: malformed row: 1 trailing bytes"
+            );
+        }
+
+        // Corrupted rows are either rejected or can be decoded
+        let mut rng = thread_rng();
+        for row in &rows {
+            for _ in 0..500 {
+                let mut bytes = row.as_ref().to_vec();
+                for _ in 0..rng.gen_range(1..4) {
+                    let idx = rng.gen_range(0..bytes.len());
+                    bytes[idx] = rng.gen();
+                }
+                if let Ok(row) = converter.parse_checked(&bytes) {
+                    converter.convert_rows([row]).unwrap();
+                }
+            }
+        }
+
+        // Invalid UTF-8
+        let mut converter = RowConverter::new(vec![SortField::new(DataType::Utf8)]);
+        let binary = Arc::new(BinaryArray::from(vec![&[0xFF_u8, 0xFE][..]])) as ArrayRef;
+        let mut binary_converter =
+            RowConverter::new(vec![SortField::new(DataType::Binary)]);
+        let binary_rows = binary_converter.convert_columns(&[binary]).unwrap();
+        let err = converter
+            .parse_checked(binary_rows.row(0).as_ref())
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "Invalid argument error: malformed row: invalid UTF-8");
+        let strings = Arc::new(StringArray::from(vec!["valid"])) as ArrayRef;
+        let rows = converter.convert_columns(&[strings]).unwrap();
+        converter.parse_checked(rows.row(0).as_ref()).unwrap();
+
+        // Dictionary values unknown to the converter
+        let dict = Arc::new(DictionaryArray::<Int32Type>::from_iter(["z"])) as ArrayRef;
+        let mut other = RowConverter::new(vec![SortField::new(dict.data_type().clone())]);
+        let other_rows = other.convert_columns(&[Arc::clone(&dict)]).unwrap();
+        let converter = RowConverter::new(vec![SortField::new(dict.data_type().clone())]);
+        let err = converter
+            .parse_checked(other_rows.row(0).as_ref())
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: malformed row: unknown dictionary key"
+        );
+    }
+
+    #[test]
+    fn test_interner_limit() {
+        fn dictionary(range: std::ops::Range<usize>) -> ArrayRef {
+            let values: Vec<_> = range.map(|x| format!("value{}", x)).collect();
+            let array: DictionaryArray<Int32Type> =
+                values.iter().map(|x| x.as_str()).collect();
+            Arc::new(array)
+        }
+
+        let dict = dictionary(0..10);
+        let list_type =
+            DataType::List(Box::new(Field::new("item", dict.data_type().clone(), true)));
+        let mut converter = RowConverter::new(vec![
+            SortField::new(dict.data_type().clone()),
+            SortField::new(list_type.clone()),
+        ])
+        .with_interner_limit(32 * 1024);
+
+        // Returns a list array of `len` rows, the first containing all of `values`
+        let list = |values: ArrayRef, len: usize| -> ArrayRef {
+            let mut offsets = vec![values.len() as i32; len + 1];
+            offsets[0] = 0;
+            let data = ArrayData::builder(list_type.clone())
+                .len(len)
+                .add_buffer(Buffer::from_slice_ref(&offsets))
+                .add_child_data(values.data().clone())
+                .build()
+                .unwrap();
+            Arc::new(ListArray::from(data))
+        };
+
+        let rows = converter
+            .convert_columns(&[dictionary(0..1), list(dictionary(0..10), 1)])
+            .unwrap();
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back[0].len(), 1);
+
+        // Exceeding the limit of the top-level dictionary
+        let err = converter
+            .convert_columns(&[dictionary(0..2_000), list(dictionary(0..10), 2_000)])
+            .unwrap_err();
+        assert!(matches!(err, ArrowError::MemoryError(_)), "{}", err);
+
+        converter.reset();
+        assert!(converter.size() < 32 * 1024);
+        converter
+            .convert_columns(&[dictionary(0..10), list(dictionary(0..10), 10)])
+            .unwrap();
+
+        // Exceeding the limit of the nested dictionary
+        let err = converter
+            .convert_columns(&[dictionary(0..10), list(dictionary(0..2_000), 10)])
+            .unwrap_err();
+        assert!(matches!(err, ArrowError::MemoryError(_)), "{}", err);
+
+        // The limit is preserved by reset
+        converter.reset();
+        converter
+            .convert_columns(&[dictionary(0..2_000), list(dictionary(0..10), 2_000)])
+            .unwrap_err();
+    }
+
+    #[test]
+    #[should_panic(expected = "rows were not produced by this RowConverter")]
+    fn test_reset_invalidates_rows() {
+        let dict =
+            Arc::new(DictionaryArray::<Int32Type>::from_iter(["a", "b"])) as ArrayRef;
+        let mut converter =
+            RowConverter::new(vec![SortField::new(dict.data_type().clone())]);
+        let rows = converter.convert_columns(&[dict]).unwrap();
+
+        converter.reset();
+        let _ = converter.convert_rows(&rows);
+    }
+
+    #[test]
+    fn test_row_encoder() {
+        let ints = Arc::new(generate_primitive_array::<Int32Type>(500, 0.8)) as ArrayRef;
+        let strings = Arc::new(generate_strings::<i32>(500, 0.8)) as ArrayRef;
+        let fields = vec![
+            SortField::new(DataType::Int32),
+            SortField::new(DataType::Utf8),
+        ];
+        let mut converter = RowConverter::new(fields.clone());
+        let expected = converter
+            .convert_columns(&[Arc::clone(&ints), Arc::clone(&strings)])
+            .unwrap();
+
+        let mut encoder = RowEncoder::new(RowConverter::new(fields), 7);
+        let mut batches = vec![];
+        let mut rng = thread_rng();
+        let mut offset = 0;
+        while offset < ints.len() {
+            let len = rng.gen_range(0..30).min(ints.len() - offset);
+            let chunk = [ints.slice(offset, len), strings.slice(offset, len)];
+            encoder.push(&chunk).unwrap();
+            offset += len;
+
+            batches.extend(std::iter::from_fn(|| encoder.next_batch()));
+            assert_eq!(encoder.buffered_rows(), offset % 7);
+        }
+
+        // Mismatched schema leaves the buffered rows unchanged
+        let buffered = encoder.buffered_rows();
+        assert!(encoder.push(&[Arc::clone(&ints)]).is_err());
+        assert_eq!(encoder.buffered_rows(), buffered);
+
+        encoder.finish();
+        batches.extend(std::iter::from_fn(|| encoder.next_batch()));
+        assert_eq!(encoder.buffered_rows(), 0);
+
+        let (last, full) = batches.split_last().unwrap();
+        assert!(full.iter().all(|b| b.num_rows() == 7));
+        assert_eq!(last.num_rows(), 500 % 7);
+
+        let actual: Vec<_> = batches.iter().flatten().collect();
+        assert_eq!(actual.len(), expected.num_rows());
+        for (a, e) in actual.iter().zip(&expected) {
+            assert_eq!(a.as_ref(), e.as_ref());
+        }
+
+        let back = encoder.converter().convert_rows(actual).unwrap();
+        assert_eq!(&back[0], &ints);
+        assert_eq!(&back[1], &strings);
+    }
+
+    #[test]
+    fn test_nan_ordering() {
+        let values = Float32Array::from(vec![
+            Some(f32::NAN),
+            Some(1.),
+            None,
+            Some(-f32::NAN),
+            Some(f32::INFINITY),
+            Some(f32::NEG_INFINITY),
+        ]);
+        let column = Arc::new(values) as ArrayRef;
+        let strukt = Arc::new(StructArray::from(vec![(
+            Field::new("a", DataType::Float32, true),
+            Arc::clone(&column),
+        )])) as ArrayRef;
+
+        let sort = |ordering: NanOrdering, options: SortOptions, col: &ArrayRef| {
+            let field = SortField::new_with_options(col.data_type().clone(), options)
+                .with_nan_ordering(ordering);
+            let mut converter = RowConverter::new(vec![field]);
+            let rows = converter.convert_columns(&[Arc::clone(col)]).unwrap();
+
+            let back = converter.convert_rows(&rows).unwrap();
+            assert_eq!(back[0].data_type(), col.data_type());
+            assert_eq!(back[0].null_count(), col.null_count());
+
+            let mut indices: Vec<_> = (0..rows.num_rows()).collect();
+            indices.sort_by(|a, b| rows.row(*a).cmp(&rows.row(*b)).then(a.cmp(b)));
+            indices
+        };
+
+        let cases = [
+            (
+                NanOrdering::TotalOrder,
+                false,
+                false,
+                vec![3, 5, 1, 4, 0, 2],
+            ),
+            (NanOrdering::TotalOrder, true, true, vec![2, 0, 4, 1, 5, 3]),
+            (NanOrdering::Largest, false, false, vec![5, 1, 4, 0, 3, 2]),
+            (NanOrdering::Largest, true, false, vec![0, 3, 4, 1, 5, 2]),
+            (NanOrdering::Smallest, false, true, vec![2, 0, 3, 5, 1, 4]),
+            (NanOrdering::Smallest, true, true, vec![2, 4, 1, 5, 0, 3]),
+            (NanOrdering::Nulls, false, false, vec![5, 1, 4, 0, 3, 2]),
+            (NanOrdering::Nulls, false, true, vec![2, 0, 3, 5, 1, 4]),
+            (NanOrdering::Nulls, true, false, vec![4, 1, 5, 0, 3, 2]),
+            (NanOrdering::Nulls, true, true, vec![2, 0, 3, 4, 1, 5]),
+        ];
+
+        for (ordering, descending, nulls_first, expected) in cases {
+            let options = SortOptions {
+                descending,
+                nulls_first,
+            };
+            for col in [&column, &strukt] {
+                let actual = sort(ordering, options, col);
+                assert_eq!(actual, expected, "{:?} {:?}", ordering, options);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hashes() {
+        let ints = Arc::new(Int32Array::from(vec![Some(1), None, Some(1), Some(2)]));
+        let strings = Arc::new(StringArray::from(vec!["a", "b", "a", "a"]));
+        let columns = [ints as ArrayRef, strings as ArrayRef];
+        let fields = vec![
+            SortField::new(DataType::Int32),
+            SortField::new(DataType::Utf8),
+        ];
+
+        let mut converter = RowConverter::new(fields.clone());
+        let rows = converter.convert_columns(&columns).unwrap();
+        assert!(rows.hashes().is_none());
+
+        let mut converter = RowConverter::new(fields).with_hashes(true);
+        let mut rows = converter.convert_columns(&columns).unwrap();
+        let hashes = rows.hashes().unwrap().to_vec();
+        assert_eq!(hashes.len(), 4);
+        assert_eq!(hashes[0], hashes[2]);
+        assert_ne!(hashes[0], hashes[1]);
+        assert_ne!(hashes[0], hashes[3]);
+
+        // Hashes are consistent across appended batches
+        let sliced: Vec<_> = columns.iter().map(|c| c.slice(1, 3)).collect();
+        converter.append(&mut rows, &sliced).unwrap();
+        assert_eq!(rows.hashes().unwrap()[4..], hashes[1..]);
+
+        let bytes = rows.to_bytes();
+        let back = unsafe { converter.rows_from_bytes(&bytes).unwrap() };
+        assert_eq!(back.hashes(), rows.hashes());
+
+        let mut encoder = RowEncoder::new(converter, 3);
+        encoder.push(&columns).unwrap();
+        encoder.push(&sliced).unwrap();
+        encoder.finish();
+        let batches: Vec<_> = std::iter::from_fn(|| encoder.next_batch()).collect();
+        let encoded: Vec<_> = batches
+            .iter()
+            .flat_map(|b| b.hashes().unwrap().iter().copied())
+            .collect();
+        assert_eq!(encoded, rows.hashes().unwrap());
+
+        rows.clear();
+        assert_eq!(rows.hashes().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_primitive_nulls_sliced() {
+        let array = generate_primitive_array::<Int64Type>(300, 0.6);
+        let options = [
+            SortOptions::default(),
+            SortOptions {
+                descending: true,
+                nulls_first: false,
+            },
+        ];
+        for options in options {
+            let field = SortField::new_with_options(DataType::Int64, options);
+            let mut converter = RowConverter::new(vec![field]);
+            for (offset, len) in
+                [(0, 300), (1, 64), (7, 130), (63, 65), (64, 200), (250, 50)]
+            {
+                let sliced = Arc::new(array.slice(offset, len)) as ArrayRef;
+                let copied: Int64Array =
+                    as_primitive_array::<Int64Type>(&sliced).iter().collect();
+                let rows = converter.convert_columns(&[sliced]).unwrap();
+                let expected = converter
+                    .convert_columns(&[Arc::new(copied) as ArrayRef])
+                    .unwrap();
+                assert_eq!(rows.buffer, expected.buffer);
+                assert_eq!(rows.offsets, expected.offsets);
+
+                let back = converter.convert_rows(&rows).unwrap();
+                assert_eq!(&back[0], &(Arc::new(array.slice(offset, len)) as ArrayRef));
+            }
+        }
+    }
+
+    #[test]
+    fn test_size() {
+        let data_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let mut converter = RowConverter::new(vec![SortField::new(data_type)]);
+        let empty_size = converter.size();
+        assert!(empty_size > std::mem::size_of::<RowConverter>());
+
+        let strings: Vec<_> = (0..1000).map(|i| format!("value{}", i)).collect();
+        let values: DictionaryArray<Int32Type> =
+            strings.iter().map(|x| x.as_str()).collect();
+        let rows = converter
+            .convert_columns(&[Arc::new(values) as ArrayRef])
+            .unwrap();
+
+        // The interner now holds every distinct value
+        assert!(converter.size() > empty_size + 1000 * "value".len());
+        assert!(rows.size() >= rows.buffer.len() + rows.offsets.len() * 8);
+
+        let mut rows = converter.empty_rows(100, 1024);
+        assert!(rows.size() >= 1024 + 101 * std::mem::size_of::<usize>());
+        rows.clear();
+        assert!(rows.size() >= 1024);
+    }
+
     fn generate_primitive_array<K>(len: usize, valid_percent: f64) -> PrimitiveArray<K>
     where
         K: ArrowPrimitiveType,
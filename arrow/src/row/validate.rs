@@ -0,0 +1,207 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Validation of rows from untrusted sources, see [`RowConverter::parse_checked`]
+
+use crate::compute::SortOptions;
+use crate::datatypes::{DataType, IntervalUnit};
+use crate::error::{ArrowError, Result};
+use crate::row::interner::OrderPreservingInterner;
+use crate::row::list::{LIST_SENTINEL, LIST_TERMINATOR};
+use crate::row::{null_sentinel, variable, Codec, RowConverter, SortField};
+
+fn malformed(reason: &str) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!("malformed row: {}", reason))
+}
+
+/// Returns the byte at `idx` of `row`
+fn byte_at(row: &[u8], idx: usize) -> Result<u8> {
+    row.get(idx)
+        .copied()
+        .ok_or_else(|| malformed("unexpected end of row"))
+}
+
+/// Checks that `row` starts with the null sentinel or `valid`, returning true if valid
+fn check_sentinel(row: &[u8], options: SortOptions, valid: u8) -> Result<bool> {
+    match byte_at(row, 0)? {
+        v if v == valid => Ok(true),
+        v if v == null_sentinel(options) => Ok(false),
+        v => Err(malformed(&format!("invalid sentinel {}", v))),
+    }
+}
+
+/// Validates that `row` starts with a valid encoding of the columns of `converter`,
+/// returning the number of bytes of `row` it occupies
+pub fn validate_row(converter: &RowConverter, row: &[u8]) -> Result<usize> {
+    let mut offset = 0;
+    for (field, codec) in converter.fields.iter().zip(&converter.codecs) {
+        offset += validate_column(field, codec, &row[offset..])?;
+    }
+    Ok(offset)
+}
+
+/// Validates that `row` starts with a valid encoding of a value of `field`,
+/// returning the number of bytes of `row` it occupies
+fn validate_column(field: &SortField, codec: &Codec, row: &[u8]) -> Result<usize> {
+    let options = field.options;
+    match (&field.data_type, codec) {
+        (DataType::Null, _) => Ok(0),
+        (DataType::Binary | DataType::LargeBinary, _) => {
+            variable::decode_blocks_checked(row, options, |_| {})
+        }
+        (DataType::Utf8 | DataType::LargeUtf8, _) => {
+            let mut value = Vec::new();
+            let len = variable::decode_blocks_checked(row, options, |block| {
+                value.extend_from_slice(block)
+            })?;
+            if options.descending {
+                value.iter_mut().for_each(|v| *v = !*v);
+            }
+            std::str::from_utf8(&value).map_err(|_| malformed("invalid UTF-8"))?;
+            Ok(len)
+        }
+        (DataType::Dictionary(_, _), Codec::Dictionary(interner)) => {
+            validate_dictionary(interner, row, options)
+        }
+        (DataType::Struct(_), Codec::Struct(converter, _)) => {
+            check_sentinel(row, options, 1)?;
+            Ok(1 + validate_row(converter, &row[1..])?)
+        }
+        (DataType::FixedSizeList(_, size), Codec::FixedSizeList(converter, _)) => {
+            check_sentinel(row, options, LIST_SENTINEL)?;
+            let mut offset = 1;
+            for _ in 0..*size {
+                offset += validate_row(converter, &row[offset..])?;
+            }
+            Ok(offset)
+        }
+        (DataType::Union(_, _, _), Codec::Union(converters)) => {
+            let type_id = match options.descending {
+                true => !byte_at(row, 0)?,
+                false => byte_at(row, 0)?,
+            };
+            let converter = converters
+                .get(type_id as usize)
+                .ok_or_else(|| malformed(&format!("invalid type id {}", type_id)))?;
+            Ok(1 + validate_row(converter, &row[1..])?)
+        }
+        (
+            DataType::List(_) | DataType::LargeList(_) | DataType::Map(_, _),
+            Codec::List(converter),
+        ) => validate_list(converter, row, options),
+        (t, _) => {
+            let width = fixed_width(t).ok_or_else(|| {
+                ArrowError::NotYetImplemented(format!("not yet implemented: {}", t))
+            })?;
+            let len = width + field.nullable as usize;
+            if field.nullable {
+                check_sentinel(row, options, 1)?;
+            }
+            match row.len() >= len {
+                true => Ok(len),
+                false => Err(malformed("unexpected end of row")),
+            }
+        }
+    }
+}
+
+/// Returns the width in bytes of the encoded values of a fixed width type,
+/// excluding the null byte
+fn fixed_width(data_type: &DataType) -> Option<usize> {
+    Some(match data_type {
+        DataType::Boolean | DataType::Int8 | DataType::UInt8 => 1,
+        DataType::Int16 | DataType::UInt16 | DataType::Float16 => 2,
+        DataType::Int32
+        | DataType::UInt32
+        | DataType::Float32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => 4,
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime) => 8,
+        DataType::Interval(IntervalUnit::MonthDayNano) | DataType::Decimal128(_, _) => 16,
+        DataType::Decimal256(_, _) => 32,
+        _ => return None,
+    })
+}
+
+/// Validates a dictionary value, whose normalized key must be known to `interner`
+fn validate_dictionary(
+    interner: &OrderPreservingInterner,
+    row: &[u8],
+    options: SortOptions,
+) -> Result<usize> {
+    let (valid, terminator) = match options.descending {
+        true => (!1, 0xFF),
+        false => (1, 0),
+    };
+    if !check_sentinel(row, options, valid)? {
+        return Ok(1);
+    }
+
+    let key_len = row[1..]
+        .iter()
+        .position(|x| *x == terminator)
+        .ok_or_else(|| malformed("unterminated dictionary key"))?
+        + 1;
+
+    let mut key = row[1..1 + key_len].to_vec();
+    if options.descending {
+        key.iter_mut().for_each(|v| *v = !*v);
+    }
+    match interner.lookup(&key) {
+        Some(_) => Ok(1 + key_len),
+        None => Err(malformed("unknown dictionary key")),
+    }
+}
+
+/// Validates a list, whose elements must be valid rows of `converter`
+fn validate_list(
+    converter: &RowConverter,
+    row: &[u8],
+    options: SortOptions,
+) -> Result<usize> {
+    if !check_sentinel(row, options, LIST_SENTINEL)? {
+        return Ok(1);
+    }
+    let terminator = match options.descending {
+        true => !LIST_TERMINATOR,
+        false => LIST_TERMINATOR,
+    };
+
+    let mut element = Vec::new();
+    let mut offset = 1;
+    while byte_at(row, offset)? != terminator {
+        element.clear();
+        offset += variable::decode_blocks_checked(&row[offset..], options, |block| {
+            element.extend_from_slice(block)
+        })?;
+        if options.descending {
+            element.iter_mut().for_each(|v| *v = !*v);
+        }
+        if validate_row(converter, &element)? != element.len() {
+            return Err(malformed("trailing bytes in list element"));
+        }
+    }
+    Ok(offset + 1)
+}
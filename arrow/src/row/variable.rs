@@ -16,7 +16,8 @@
 // under the License.
 
 use crate::compute::SortOptions;
-use crate::row::{null_sentinel, Rows};
+use crate::error::{ArrowError, Result};
+use crate::row::null_sentinel;
 use crate::util::bit_util::ceil;
 use arrow_array::builder::BufferBuilder;
 use arrow_array::{Array, GenericBinaryArray, GenericStringArray, OffsetSizeTrait};
@@ -27,6 +28,14 @@ use arrow_schema::DataType;
 /// The block size of the variable length encoding
 pub const BLOCK_SIZE: usize = 32;
 
+/// The first block is split into `MINI_BLOCK_COUNT` mini-blocks
+///
+/// This helps to reduce the space amplification for small strings
+pub const MINI_BLOCK_COUNT: usize = 4;
+
+/// The mini block size
+pub const MINI_BLOCK_SIZE: usize = BLOCK_SIZE / MINI_BLOCK_COUNT;
+
 /// The continuation token
 pub const BLOCK_CONTINUATION: u8 = 0xFF;
 
@@ -39,7 +48,11 @@ pub const NON_EMPTY_SENTINEL: u8 = 2;
 /// Returns the length of the encoded representation of a byte array, including the null byte
 pub fn encoded_len(a: Option<&[u8]>) -> usize {
     match a {
-        Some(a) => 1 + ceil(a.len(), BLOCK_SIZE) * (BLOCK_SIZE + 1),
+        Some(a) if a.len() <= BLOCK_SIZE => {
+            1 + ceil(a.len(), MINI_BLOCK_SIZE) * (MINI_BLOCK_SIZE + 1)
+        }
+        // Each of the mini blocks making up the first block adds a continuation byte
+        Some(a) => MINI_BLOCK_COUNT + ceil(a.len(), BLOCK_SIZE) * (BLOCK_SIZE + 1),
         None => 1,
     }
 }
@@ -55,71 +68,96 @@ pub fn encoded_len(a: Option<&[u8]>) -> usize {
 /// - [`BLOCK_SIZE`] bytes of string data, padded with 0s
 /// - `0xFF_u8` if this is not the last block for this string
 /// - otherwise the length of the block as a `u8`
+///
+/// except that the first [`BLOCK_SIZE`] bytes are split into [`MINI_BLOCK_COUNT`]
+/// blocks of [`MINI_BLOCK_SIZE`] bytes, to reduce the size of short values
 pub fn encode<'a, I: Iterator<Item = Option<&'a [u8]>>>(
-    out: &mut Rows,
+    data: &mut [u8],
+    offsets: &mut [usize],
     i: I,
     opts: SortOptions,
 ) {
-    for (offset, maybe_val) in out.offsets.iter_mut().skip(1).zip(i) {
-        match maybe_val {
-            Some(val) if val.is_empty() => {
-                out.buffer[*offset] = match opts.descending {
-                    true => !EMPTY_SENTINEL,
-                    false => EMPTY_SENTINEL,
-                };
-                *offset += 1;
-            }
-            Some(val) => {
-                let block_count = ceil(val.len(), BLOCK_SIZE);
-                let end_offset = *offset + 1 + block_count * (BLOCK_SIZE + 1);
-                let to_write = &mut out.buffer[*offset..end_offset];
-
-                // Write `2_u8` to demarcate as non-empty, non-null string
-                to_write[0] = NON_EMPTY_SENTINEL;
-
-                let chunks = val.chunks_exact(BLOCK_SIZE);
-                let remainder = chunks.remainder();
-                for (input, output) in chunks
-                    .clone()
-                    .zip(to_write[1..].chunks_exact_mut(BLOCK_SIZE + 1))
-                {
-                    let input: &[u8; BLOCK_SIZE] = input.try_into().unwrap();
-                    let out_block: &mut [u8; BLOCK_SIZE] =
-                        (&mut output[..BLOCK_SIZE]).try_into().unwrap();
-
-                    *out_block = *input;
-
-                    // Indicate that there are further blocks to follow
-                    output[BLOCK_SIZE] = BLOCK_CONTINUATION;
-                }
-
-                if !remainder.is_empty() {
-                    let start_offset = 1 + (block_count - 1) * (BLOCK_SIZE + 1);
-                    to_write[start_offset..start_offset + remainder.len()]
-                        .copy_from_slice(remainder);
-                    *to_write.last_mut().unwrap() = remainder.len() as u8;
-                } else {
-                    // We must overwrite the continuation marker written by the loop above
-                    *to_write.last_mut().unwrap() = BLOCK_SIZE as u8;
-                }
-
-                *offset = end_offset;
-
-                if opts.descending {
-                    // Invert bits
-                    to_write.iter_mut().for_each(|v| *v = !*v)
-                }
-            }
-            None => {
-                out.buffer[*offset] = null_sentinel(opts);
-                *offset += 1;
+    for (offset, maybe_val) in offsets.iter_mut().skip(1).zip(i) {
+        *offset += encode_one(&mut data[*offset..], maybe_val, opts);
+    }
+}
+
+/// Encodes a single value to the start of `out` as described in [`encode`],
+/// returning the number of bytes written
+pub fn encode_one(out: &mut [u8], val: Option<&[u8]>, opts: SortOptions) -> usize {
+    match val {
+        Some([]) => {
+            out[0] = match opts.descending {
+                true => !EMPTY_SENTINEL,
+                false => EMPTY_SENTINEL,
+            };
+            1
+        }
+        Some(val) => {
+            // Write `2_u8` to demarcate as non-empty, non-null string
+            out[0] = NON_EMPTY_SENTINEL;
+
+            let len = if val.len() <= BLOCK_SIZE {
+                1 + encode_blocks::<MINI_BLOCK_SIZE>(&mut out[1..], val)
+            } else {
+                let (initial, rem) = val.split_at(BLOCK_SIZE);
+                let offset = encode_blocks::<MINI_BLOCK_SIZE>(&mut out[1..], initial);
+                // The last mini block is followed by further blocks
+                out[offset] = BLOCK_CONTINUATION;
+                1 + offset + encode_blocks::<BLOCK_SIZE>(&mut out[1 + offset..], rem)
+            };
+
+            if opts.descending {
+                // Invert bits
+                out[..len].iter_mut().for_each(|v| *v = !*v)
             }
+            len
+        }
+        None => {
+            out[0] = null_sentinel(opts);
+            1
         }
     }
 }
 
-/// Returns the number of bytes of encoded data
-fn decoded_len(row: &[u8], options: SortOptions) -> usize {
+/// Writes `val` in blocks of `SIZE` bytes to the start of `out`, returning the
+/// number of bytes written
+///
+/// The bytes following the end of `val` in the final block must already be zeroed
+#[inline]
+fn encode_blocks<const SIZE: usize>(out: &mut [u8], val: &[u8]) -> usize {
+    let block_count = ceil(val.len(), SIZE);
+    let end_offset = block_count * (SIZE + 1);
+    let to_write = &mut out[..end_offset];
+
+    let chunks = val.chunks_exact(SIZE);
+    let remainder = chunks.remainder();
+    for (input, output) in chunks.clone().zip(to_write.chunks_exact_mut(SIZE + 1)) {
+        let input: &[u8; SIZE] = input.try_into().unwrap();
+        let out_block: &mut [u8; SIZE] = (&mut output[..SIZE]).try_into().unwrap();
+
+        *out_block = *input;
+
+        // Indicate that there are further blocks to follow
+        output[SIZE] = BLOCK_CONTINUATION;
+    }
+
+    if !remainder.is_empty() {
+        let start_offset = (block_count - 1) * (SIZE + 1);
+        to_write[start_offset..start_offset + remainder.len()].copy_from_slice(remainder);
+        *to_write.last_mut().unwrap() = remainder.len() as u8;
+    } else {
+        // We must overwrite the continuation marker written by the loop above
+        *to_write.last_mut().unwrap() = SIZE as u8;
+    }
+    end_offset
+}
+
+/// Calls `f` with each of the blocks of data of the value encoded at the start of
+/// `row`, without inverting them if descending, returning the number of bytes of
+/// `row` it occupies
+#[inline]
+fn decode_blocks(row: &[u8], options: SortOptions, mut f: impl FnMut(&[u8])) -> usize {
     let (non_empty_sentinel, continuation) = match options.descending {
         true => (!NON_EMPTY_SENTINEL, !BLOCK_CONTINUATION),
         false => (NON_EMPTY_SENTINEL, BLOCK_CONTINUATION),
@@ -127,26 +165,99 @@ fn decoded_len(row: &[u8], options: SortOptions) -> usize {
 
     if row[0] != non_empty_sentinel {
         // Empty or null string
-        return 0;
+        return 1;
     }
 
-    let mut str_len = 0;
+    let block_len = |sentinel: u8| match options.descending {
+        true => !sentinel as usize,
+        false => sentinel as usize,
+    };
+
     let mut idx = 1;
+    for _ in 0..MINI_BLOCK_COUNT {
+        let sentinel = row[idx + MINI_BLOCK_SIZE];
+        if sentinel != continuation {
+            f(&row[idx..idx + block_len(sentinel)]);
+            return idx + MINI_BLOCK_SIZE + 1;
+        }
+        f(&row[idx..idx + MINI_BLOCK_SIZE]);
+        idx += MINI_BLOCK_SIZE + 1;
+    }
+
     loop {
         let sentinel = row[idx + BLOCK_SIZE];
-        if sentinel == continuation {
-            idx += BLOCK_SIZE + 1;
-            str_len += BLOCK_SIZE;
-            continue;
+        if sentinel != continuation {
+            f(&row[idx..idx + block_len(sentinel)]);
+            return idx + BLOCK_SIZE + 1;
         }
-        let block_len = match options.descending {
-            true => !sentinel,
-            false => sentinel,
+        f(&row[idx..idx + BLOCK_SIZE]);
+        idx += BLOCK_SIZE + 1;
+    }
+}
+
+/// Checked version of [`decode_blocks`] for a `row` that may be malformed, returning
+/// an error instead of panicking if it is not a valid encoded value
+pub fn decode_blocks_checked(
+    row: &[u8],
+    options: SortOptions,
+    mut f: impl FnMut(&[u8]),
+) -> Result<usize> {
+    let malformed = || {
+        ArrowError::InvalidArgumentError(
+            "malformed row: invalid variable length value".to_string(),
+        )
+    };
+    let (empty_sentinel, non_empty_sentinel, continuation) = match options.descending {
+        true => (!EMPTY_SENTINEL, !NON_EMPTY_SENTINEL, !BLOCK_CONTINUATION),
+        false => (EMPTY_SENTINEL, NON_EMPTY_SENTINEL, BLOCK_CONTINUATION),
+    };
+
+    match row.first() {
+        Some(v) if *v == null_sentinel(options) || *v == empty_sentinel => return Ok(1),
+        Some(v) if *v == non_empty_sentinel => {}
+        _ => return Err(malformed()),
+    }
+
+    let mut idx = 1;
+    let mut block = 0;
+    loop {
+        let size = match block < MINI_BLOCK_COUNT {
+            true => MINI_BLOCK_SIZE,
+            false => BLOCK_SIZE,
         };
-        return str_len + block_len as usize;
+        let sentinel = *row.get(idx + size).ok_or_else(malformed)?;
+        if sentinel == continuation {
+            f(&row[idx..idx + size]);
+        } else {
+            let len = match options.descending {
+                true => !sentinel as usize,
+                false => sentinel as usize,
+            };
+            if len > size {
+                return Err(malformed());
+            }
+            f(&row[idx..idx + len]);
+            return Ok(idx + size + 1);
+        }
+        idx += size + 1;
+        block += 1;
     }
 }
 
+/// Returns the number of bytes of encoded data
+fn decoded_len(row: &[u8], options: SortOptions) -> usize {
+    let mut str_len = 0;
+    decode_blocks(row, options, |block| str_len += block.len());
+    str_len
+}
+
+/// Appends the bytes of the value encoded at the start of `row` to `values`, without
+/// inverting them if descending, returning the number of bytes of `row` it occupies
+#[inline]
+pub fn decode_one(row: &[u8], options: SortOptions, values: &mut MutableBuffer) -> usize {
+    decode_blocks(row, options, |block| values.extend_from_slice(block))
+}
+
 /// Decodes a binary array from `rows` with the provided `options`
 pub fn decode_binary<I: OffsetSizeTrait>(
     rows: &mut [&[u8]],
@@ -166,20 +277,7 @@ pub fn decode_binary<I: OffsetSizeTrait>(
     let mut values = MutableBuffer::new(values_capacity);
 
     for row in rows {
-        let str_length = decoded_len(row, options);
-        let mut to_read = str_length;
-        let mut offset = 1;
-        while to_read >= BLOCK_SIZE {
-            to_read -= BLOCK_SIZE;
-
-            values.extend_from_slice(&row[offset..offset + BLOCK_SIZE]);
-            offset += BLOCK_SIZE + 1;
-        }
-
-        if to_read != 0 {
-            values.extend_from_slice(&row[offset..offset + to_read]);
-            offset += BLOCK_SIZE + 1;
-        }
+        let offset = decode_one(row, options, &mut values);
         *row = &row[offset..];
 
         offsets.append(I::from_usize(values.len()).expect("offset overflow"))
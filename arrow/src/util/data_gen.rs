@@ -16,20 +16,129 @@
 // under the License.
 
 //! Utilities to generate random arrays and batches
+//!
+//! The generated data is random but deterministic, controlled by
+//! [`RandomArrayOptions::with_seed`], which makes it suitable for benchmarks and
+//! for reproducing failures found by fuzzing
 
-use std::{convert::TryFrom, sync::Arc};
+use std::ops::RangeInclusive;
+use std::sync::Arc;
 
-use rand::{distributions::uniform::SampleUniform, Rng};
+use half::f16;
+use num::BigInt;
+use rand::distributions::{uniform::SampleUniform, Alphanumeric, Distribution, Standard};
+use rand::prelude::StdRng;
+use rand::{Rng, SeedableRng};
 
 use crate::error::{ArrowError, Result};
 use crate::record_batch::{RecordBatch, RecordBatchOptions};
+use crate::util::decimal;
 use crate::{array::*, datatypes::SchemaRef};
 use crate::{
     buffer::{Buffer, MutableBuffer},
     datatypes::*,
 };
 
-use super::{bench_util::*, bit_util, test_util::seedable_rng};
+use super::bit_util;
+
+/// Options for generating random arrays and batches, see
+/// [`create_random_array_with_options`] and [`create_random_batch_with_options`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RandomArrayOptions {
+    null_density: f32,
+    true_density: f32,
+    value_len: RangeInclusive<usize>,
+    list_len: RangeInclusive<usize>,
+    dictionary_cardinality: usize,
+    seed: u64,
+}
+
+impl Default for RandomArrayOptions {
+    fn default() -> Self {
+        Self {
+            null_density: 0.0,
+            true_density: 0.5,
+            value_len: 0..=8,
+            list_len: 0..=4,
+            dictionary_cardinality: 16,
+            seed: 42,
+        }
+    }
+}
+
+impl RandomArrayOptions {
+    /// Creates a new set of options, equivalent to [`RandomArrayOptions::default`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the probability of a value being null, defaults to `0.0`. Non-nullable
+    /// fields never contain nulls
+    pub fn with_null_density(mut self, null_density: f32) -> Self {
+        self.null_density = null_density;
+        self
+    }
+
+    /// Sets the probability of a boolean value being `true`, defaults to `0.5`
+    pub fn with_true_density(mut self, true_density: f32) -> Self {
+        self.true_density = true_density;
+        self
+    }
+
+    /// Sets the range of lengths of string and binary values, defaults to `0..=8`
+    pub fn with_value_len(mut self, value_len: RangeInclusive<usize>) -> Self {
+        self.value_len = value_len;
+        self
+    }
+
+    /// Sets the range of lengths of list and map values, defaults to `0..=4`
+    pub fn with_list_len(mut self, list_len: RangeInclusive<usize>) -> Self {
+        self.list_len = list_len;
+        self
+    }
+
+    /// Sets the number of distinct values of dictionary arrays, defaults to `16`
+    pub fn with_dictionary_cardinality(mut self, cardinality: usize) -> Self {
+        self.dictionary_cardinality = cardinality;
+        self
+    }
+
+    /// Sets the seed of the random number generator, defaults to `42`
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Returns the probability of a value being null
+    pub fn null_density(&self) -> f32 {
+        self.null_density
+    }
+
+    /// Returns the probability of a boolean value being `true`
+    pub fn true_density(&self) -> f32 {
+        self.true_density
+    }
+
+    /// Returns the range of lengths of string and binary values
+    pub fn value_len(&self) -> &RangeInclusive<usize> {
+        &self.value_len
+    }
+
+    /// Returns the range of lengths of list and map values
+    pub fn list_len(&self) -> &RangeInclusive<usize> {
+        &self.list_len
+    }
+
+    /// Returns the number of distinct values of dictionary arrays
+    pub fn dictionary_cardinality(&self) -> usize {
+        self.dictionary_cardinality
+    }
+
+    /// Returns the seed of the random number generator
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
 
 /// Create a random [RecordBatch] from a schema
 pub fn create_random_batch(
@@ -38,10 +147,21 @@ pub fn create_random_batch(
     null_density: f32,
     true_density: f32,
 ) -> Result<RecordBatch> {
+    let options = legacy_options(null_density, true_density);
+    create_random_batch_with_options(schema, size, &options)
+}
+
+/// Create a random [RecordBatch] from a schema, according to `options`
+pub fn create_random_batch_with_options(
+    schema: SchemaRef,
+    size: usize,
+    options: &RandomArrayOptions,
+) -> Result<RecordBatch> {
+    let rng = &mut StdRng::seed_from_u64(options.seed);
     let columns = schema
         .fields()
         .iter()
-        .map(|field| create_random_array(field, size, null_density, true_density))
+        .map(|field| random_array(field, size, options, rng))
         .collect::<Result<Vec<ArrayRef>>>()?;
 
     RecordBatch::try_new_with_options(
@@ -59,130 +179,313 @@ pub fn create_random_array(
     null_density: f32,
     true_density: f32,
 ) -> Result<ArrayRef> {
-    // Override null density with 0.0 if the array is non-nullable
-    let null_density = match field.is_nullable() {
-        true => null_density,
-        false => 0.0,
+    let options = legacy_options(null_density, true_density);
+    create_random_array_with_options(field, size, &options)
+}
+
+/// Create a random [ArrayRef] of `size` values of the type of `field`, according
+/// to `options`
+pub fn create_random_array_with_options(
+    field: &Field,
+    size: usize,
+    options: &RandomArrayOptions,
+) -> Result<ArrayRef> {
+    let rng = &mut StdRng::seed_from_u64(options.seed);
+    random_array(field, size, options, rng)
+}
+
+/// The options used by [`create_random_batch`] and [`create_random_array`]
+fn legacy_options(null_density: f32, true_density: f32) -> RandomArrayOptions {
+    RandomArrayOptions::new()
+        .with_null_density(null_density)
+        .with_true_density(true_density)
+}
+
+fn random_array(
+    field: &Field,
+    size: usize,
+    options: &RandomArrayOptions,
+    rng: &mut StdRng,
+) -> Result<ArrayRef> {
+    // Override null density with 0.0 if the array is non-nullable, which also
+    // applies to its children
+    let non_nullable;
+    let options = match field.is_nullable() {
+        true => options,
+        false => {
+            non_nullable = options.clone().with_null_density(0.0);
+            &non_nullable
+        }
     };
+    let null_density = options.null_density;
     use DataType::*;
     Ok(match field.data_type() {
         Null => Arc::new(NullArray::new(size)) as ArrayRef,
-        Boolean => Arc::new(create_boolean_array(size, null_density, true_density)),
-        Int8 => Arc::new(create_primitive_array::<Int8Type>(size, null_density)),
-        Int16 => Arc::new(create_primitive_array::<Int16Type>(size, null_density)),
-        Int32 => Arc::new(create_primitive_array::<Int32Type>(size, null_density)),
-        Int64 => Arc::new(create_primitive_array::<Int64Type>(size, null_density)),
-        UInt8 => Arc::new(create_primitive_array::<UInt8Type>(size, null_density)),
-        UInt16 => Arc::new(create_primitive_array::<UInt16Type>(size, null_density)),
-        UInt32 => Arc::new(create_primitive_array::<UInt32Type>(size, null_density)),
-        UInt64 => Arc::new(create_primitive_array::<UInt64Type>(size, null_density)),
-        Float16 => {
-            return Err(ArrowError::NotYetImplemented(
-                "Float16 is not implememted".to_string(),
-            ))
+        Boolean => Arc::new(
+            (0..size)
+                .map(|_| {
+                    let valid = rng.gen::<f32>() >= null_density;
+                    valid.then(|| rng.gen::<f32>() < options.true_density)
+                })
+                .collect::<BooleanArray>(),
+        ),
+        Int8 => Arc::new(random_primitive::<Int8Type>(size, null_density, rng)),
+        Int16 => Arc::new(random_primitive::<Int16Type>(size, null_density, rng)),
+        Int32 => Arc::new(random_primitive::<Int32Type>(size, null_density, rng)),
+        Int64 => Arc::new(random_primitive::<Int64Type>(size, null_density, rng)),
+        UInt8 => Arc::new(random_primitive::<UInt8Type>(size, null_density, rng)),
+        UInt16 => Arc::new(random_primitive::<UInt16Type>(size, null_density, rng)),
+        UInt32 => Arc::new(random_primitive::<UInt32Type>(size, null_density, rng)),
+        UInt64 => Arc::new(random_primitive::<UInt64Type>(size, null_density, rng)),
+        Float16 => Arc::new(
+            (0..size)
+                .map(|_| {
+                    let valid = rng.gen::<f32>() >= null_density;
+                    valid.then(|| f16::from_f32(rng.gen()))
+                })
+                .collect::<Float16Array>(),
+        ),
+        Float32 => Arc::new(random_primitive::<Float32Type>(size, null_density, rng)),
+        Float64 => Arc::new(random_primitive::<Float64Type>(size, null_density, rng)),
+        // Temporal types are generated from their native representation
+        Date32 | Time32(_) | Interval(IntervalUnit::YearMonth) => {
+            let array = random_primitive::<Int32Type>(size, null_density, rng);
+            with_data_type(&array, field.data_type())?
         }
-        Float32 => Arc::new(create_primitive_array::<Float32Type>(size, null_density)),
-        Float64 => Arc::new(create_primitive_array::<Float64Type>(size, null_density)),
-        Timestamp(_, _) => {
-            let int64_array =
-                Arc::new(create_primitive_array::<Int64Type>(size, null_density))
-                    as ArrayRef;
-            return crate::compute::cast(&int64_array, field.data_type());
+        Date64
+        | Time64(_)
+        | Timestamp(_, _)
+        | Duration(_)
+        | Interval(IntervalUnit::DayTime) => {
+            let array = random_primitive::<Int64Type>(size, null_density, rng);
+            with_data_type(&array, field.data_type())?
         }
-        Date32 => Arc::new(create_primitive_array::<Date32Type>(size, null_density)),
-        Date64 => Arc::new(create_primitive_array::<Date64Type>(size, null_density)),
-        Time32(unit) => match unit {
-            TimeUnit::Second => Arc::new(create_primitive_array::<Time32SecondType>(
-                size,
-                null_density,
-            )) as ArrayRef,
-            TimeUnit::Millisecond => Arc::new(create_primitive_array::<
-                Time32MillisecondType,
-            >(size, null_density)),
-            _ => {
-                return Err(ArrowError::InvalidArgumentError(format!(
-                    "Unsupported unit {:?} for Time32",
-                    unit
-                )))
-            }
-        },
-        Time64(unit) => match unit {
-            TimeUnit::Microsecond => Arc::new(create_primitive_array::<
-                Time64MicrosecondType,
-            >(size, null_density)) as ArrayRef,
-            TimeUnit::Nanosecond => Arc::new(create_primitive_array::<
-                Time64NanosecondType,
-            >(size, null_density)),
-            _ => {
-                return Err(ArrowError::InvalidArgumentError(format!(
-                    "Unsupported unit {:?} for Time64",
-                    unit
-                )))
-            }
-        },
-        Utf8 => Arc::new(create_string_array::<i32>(size, null_density)),
-        LargeUtf8 => Arc::new(create_string_array::<i64>(size, null_density)),
-        Binary => Arc::new(create_binary_array::<i32>(size, null_density)),
-        LargeBinary => Arc::new(create_binary_array::<i64>(size, null_density)),
+        Interval(IntervalUnit::MonthDayNano) => {
+            let array = (0..size)
+                .map(|_| {
+                    let valid = rng.gen::<f32>() >= null_density;
+                    valid.then(|| rng.gen::<i128>())
+                })
+                .collect::<PrimitiveArray<IntervalMonthDayNanoType>>();
+            Arc::new(array)
+        }
+        Decimal128(precision, scale) => {
+            let array: Decimal128Array =
+                random_decimals(size, null_density, *precision, rng)?.collect();
+            Arc::new(array.with_precision_and_scale(*precision, *scale)?)
+        }
+        Decimal256(precision, scale) => {
+            let array: Decimal256Array =
+                random_decimals(size, null_density, *precision, rng)?
+                    .map(|v| v.map(|v| decimal::Decimal256::from(BigInt::from(v))))
+                    .collect();
+            Arc::new(array.with_precision_and_scale(*precision, *scale)?)
+        }
+        Utf8 => Arc::new(random_strings::<GenericStringArray<i32>>(
+            size,
+            null_density,
+            options,
+            rng,
+        )),
+        LargeUtf8 => Arc::new(random_strings::<GenericStringArray<i64>>(
+            size,
+            null_density,
+            options,
+            rng,
+        )),
+        Binary => Arc::new(random_bytes::<GenericBinaryArray<i32>>(
+            size,
+            null_density,
+            options.value_len.clone(),
+            rng,
+        )),
+        LargeBinary => Arc::new(random_bytes::<GenericBinaryArray<i64>>(
+            size,
+            null_density,
+            options.value_len.clone(),
+            rng,
+        )),
         FixedSizeBinary(len) => {
-            Arc::new(create_fsb_array(size, null_density, *len as usize))
+            let len = *len as usize;
+            let values = random_bytes::<Vec<_>>(size, null_density, len..=len, rng);
+            match size {
+                // try_from_sparse_iter cannot infer the value length of an empty array
+                0 => new_empty_array(field.data_type()),
+                _ => Arc::new(FixedSizeBinaryArray::try_from_sparse_iter(
+                    values.into_iter(),
+                )?),
+            }
+        }
+        List(_) | LargeList(_) | Map(_, _) => {
+            random_list_array(field, size, null_density, options, rng)?
         }
-        List(_) => create_random_list_array(field, size, null_density, true_density)?,
-        LargeList(_) => {
-            create_random_list_array(field, size, null_density, true_density)?
+        FixedSizeList(child, len) => {
+            let values = random_array(child, size * *len as usize, options, rng)?;
+            let data = ArrayData::builder(field.data_type().clone())
+                .len(size)
+                .null_bit_buffer(random_null_buffer(size, null_density, rng))
+                .add_child_data(values.data().clone())
+                .build()?;
+            make_array(data)
         }
-        Struct(fields) => Arc::new(StructArray::try_from(
-            fields
+        Struct(fields) => {
+            let children = fields
                 .iter()
-                .map(|struct_field| {
-                    create_random_array(struct_field, size, null_density, true_density)
-                        .map(|array_ref| (struct_field.name().as_str(), array_ref))
-                })
-                .collect::<Result<Vec<(&str, ArrayRef)>>>()?,
-        )?),
-        d @ Dictionary(_, value_type)
-            if crate::compute::can_cast_types(value_type, d) =>
-        {
-            let f = Field::new(
-                field.name(),
-                value_type.as_ref().clone(),
-                field.is_nullable(),
-            );
-            let v = create_random_array(&f, size, null_density, true_density)?;
-            crate::compute::cast(&v, d)?
+                .map(|child| Ok(random_array(child, size, options, rng)?.data().clone()))
+                .collect::<Result<Vec<_>>>()?;
+            let data = ArrayData::builder(field.data_type().clone())
+                .len(size)
+                .null_bit_buffer(random_null_buffer(size, null_density, rng))
+                .child_data(children)
+                .build()?;
+            make_array(data)
         }
-        other => {
-            return Err(ArrowError::NotYetImplemented(format!(
-                "Generating random arrays not yet implemented for {:?}",
-                other
-            )))
+        Union(fields, type_ids, mode) => {
+            random_union_array(fields, type_ids, mode, size, options, rng)?
+        }
+        Dictionary(key_type, value_type) => {
+            let value_field = Field::new("values", value_type.as_ref().clone(), false);
+            let cardinality = options.dictionary_cardinality;
+            let values = random_array(&value_field, cardinality, options, rng)?;
+            macro_rules! dictionary {
+                ($key_type:ty) => {{
+                    let keys: PrimitiveArray<$key_type> = (0..size)
+                        .map(|_| {
+                            let valid =
+                                cardinality > 0 && rng.gen::<f32>() >= null_density;
+                            valid
+                                .then(|| {
+                                    let key = rng.gen_range(0..cardinality);
+                                    <$key_type as ArrowPrimitiveType>::Native::from_usize(
+                                        key,
+                                    )
+                                    .ok_or(ArrowError::DictionaryKeyOverflowError)
+                                })
+                                .transpose()
+                        })
+                        .collect::<Result<_>>()?;
+                    Arc::new(DictionaryArray::try_new(&keys, &values)?) as ArrayRef
+                }};
+            }
+            match key_type.as_ref() {
+                Int8 => dictionary!(Int8Type),
+                Int16 => dictionary!(Int16Type),
+                Int32 => dictionary!(Int32Type),
+                Int64 => dictionary!(Int64Type),
+                UInt8 => dictionary!(UInt8Type),
+                UInt16 => dictionary!(UInt16Type),
+                UInt32 => dictionary!(UInt32Type),
+                UInt64 => dictionary!(UInt64Type),
+                other => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Invalid dictionary key type {:?}",
+                        other
+                    )))
+                }
+            }
         }
     })
 }
 
-#[inline]
-fn create_random_list_array(
+/// Reinterprets `array` as an array of `data_type`, which must have the same layout
+fn with_data_type(array: &dyn Array, data_type: &DataType) -> Result<ArrayRef> {
+    let data = array
+        .data()
+        .clone()
+        .into_builder()
+        .data_type(data_type.clone())
+        .build()?;
+    Ok(make_array(data))
+}
+
+fn random_primitive<T>(
+    size: usize,
+    null_density: f32,
+    rng: &mut StdRng,
+) -> PrimitiveArray<T>
+where
+    T: ArrowPrimitiveType,
+    Standard: Distribution<T::Native>,
+{
+    (0..size)
+        .map(|_| {
+            let valid = rng.gen::<f32>() >= null_density;
+            valid.then(|| rng.gen())
+        })
+        .collect()
+}
+
+/// Generates decimal values with at most `precision` digits, returning an error if
+/// `precision` is 0
+fn random_decimals<'a>(
+    size: usize,
+    null_density: f32,
+    precision: u8,
+    rng: &'a mut StdRng,
+) -> Result<impl Iterator<Item = Option<i128>> + 'a> {
+    if precision == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "Decimal precision must be at least 1, got 0".to_string(),
+        ));
+    }
+    let max = 10_i128.pow(precision.min(38) as u32 - 1) * 10 - 1;
+    Ok((0..size).map(move |_| {
+        let valid = rng.gen::<f32>() >= null_density;
+        valid.then(|| rng.gen_range(-max..=max))
+    }))
+}
+
+fn random_strings<A: FromIterator<Option<String>>>(
+    size: usize,
+    null_density: f32,
+    options: &RandomArrayOptions,
+    rng: &mut StdRng,
+) -> A {
+    (0..size)
+        .map(|_| {
+            let valid = rng.gen::<f32>() >= null_density;
+            valid.then(|| {
+                let len = rng.gen_range(options.value_len.clone());
+                let value = rng.sample_iter(&Alphanumeric).take(len).collect();
+                String::from_utf8(value).unwrap()
+            })
+        })
+        .collect()
+}
+
+fn random_bytes<A: FromIterator<Option<Vec<u8>>>>(
+    size: usize,
+    null_density: f32,
+    len: RangeInclusive<usize>,
+    rng: &mut StdRng,
+) -> A {
+    (0..size)
+        .map(|_| {
+            let valid = rng.gen::<f32>() >= null_density;
+            valid.then(|| {
+                let len = rng.gen_range(len.clone());
+                rng.sample_iter(Standard).take(len).collect()
+            })
+        })
+        .collect()
+}
+
+fn random_list_array(
     field: &Field,
     size: usize,
     null_density: f32,
-    true_density: f32,
+    options: &RandomArrayOptions,
+    rng: &mut StdRng,
 ) -> Result<ArrayRef> {
-    // Override null density with 0.0 if the array is non-nullable
-    let null_density = match field.is_nullable() {
-        true => null_density,
-        false => 0.0,
-    };
-    let list_field;
-    let (offsets, child_len) = match field.data_type() {
-        DataType::List(f) => {
-            let (offsets, child_len) = create_random_offsets::<i32>(size, 0, 5);
-            list_field = f;
-            (Buffer::from(offsets.to_byte_slice()), child_len as usize)
+    let list_len = options.list_len.clone();
+    let (offsets, child_len, child_field) = match field.data_type() {
+        DataType::List(f) | DataType::Map(f, _) => {
+            let (offsets, child_len) = random_offsets::<i32>(size, list_len, rng);
+            (offsets, child_len, f.as_ref().clone())
         }
         DataType::LargeList(f) => {
-            let (offsets, child_len) = create_random_offsets::<i64>(size, 0, 5);
-            list_field = f;
-            (Buffer::from(offsets.to_byte_slice()), child_len as usize)
+            let (offsets, child_len) = random_offsets::<i64>(size, list_len, rng);
+            (offsets, child_len, f.as_ref().clone())
         }
         _ => {
             return Err(ArrowError::InvalidArgumentError(format!(
@@ -192,52 +495,109 @@ fn create_random_list_array(
         }
     };
 
-    // Create list's child data
-    let child_array =
-        create_random_array(list_field, child_len as usize, null_density, true_density)?;
-    let child_data = child_array.data();
-    // Create list's null buffers, if it is nullable
-    let null_buffer = match field.is_nullable() {
-        true => Some(create_random_null_buffer(size, null_density)),
-        false => None,
-    };
-    let list_data = unsafe {
-        ArrayData::new_unchecked(
-            field.data_type().clone(),
-            size,
-            None,
-            null_buffer,
-            0,
-            vec![offsets],
-            vec![child_data.clone()],
-        )
+    // The entries and keys of a map must not be null
+    let child_field = match field.data_type() {
+        DataType::Map(_, _) => match child_field.data_type() {
+            DataType::Struct(entries) if entries.len() == 2 => {
+                let key = &entries[0];
+                let key = Field::new(key.name(), key.data_type().clone(), false);
+                let entries = DataType::Struct(vec![key, entries[1].clone()]);
+                Field::new(child_field.name(), entries, false)
+            }
+            _ => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Invalid map entries field {:?}",
+                    child_field
+                )))
+            }
+        },
+        _ => child_field,
     };
+
+    // Create list's child data
+    let child_array = random_array(&child_field, child_len, options, rng)?;
+    let list_data = ArrayData::builder(field.data_type().clone())
+        .len(size)
+        .null_bit_buffer(random_null_buffer(size, null_density, rng))
+        .add_buffer(offsets)
+        .add_child_data(child_array.data().clone())
+        .build()?;
     Ok(make_array(list_data))
 }
 
-/// Generate random offsets for list arrays
-fn create_random_offsets<T: OffsetSizeTrait + SampleUniform>(
+/// Generate random offsets for list arrays, returning the offsets and the total
+/// number of values
+fn random_offsets<T: OffsetSizeTrait + SampleUniform>(
     size: usize,
-    min: T,
-    max: T,
-) -> (Vec<T>, T) {
-    let rng = &mut seedable_rng();
-
-    let mut current_offset = T::zero();
-
+    list_len: RangeInclusive<usize>,
+    rng: &mut StdRng,
+) -> (Buffer, usize) {
+    let mut current_offset = 0;
     let mut offsets = Vec::with_capacity(size + 1);
-    offsets.push(current_offset);
+    offsets.push(T::zero());
 
     (0..size).for_each(|_| {
-        current_offset += rng.gen_range(min..max);
-        offsets.push(current_offset);
+        current_offset += rng.gen_range(list_len.clone());
+        offsets.push(T::from_usize(current_offset).unwrap());
     });
 
-    (offsets, current_offset)
+    (Buffer::from(offsets.to_byte_slice()), current_offset)
+}
+
+fn random_union_array(
+    fields: &[Field],
+    type_ids: &[i8],
+    mode: &UnionMode,
+    size: usize,
+    options: &RandomArrayOptions,
+    rng: &mut StdRng,
+) -> Result<ArrayRef> {
+    if fields.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Cannot create union array without fields".to_string(),
+        ));
+    }
+
+    let child_idx: Vec<usize> =
+        (0..size).map(|_| rng.gen_range(0..fields.len())).collect();
+    let types: Buffer = child_idx.iter().map(|idx| type_ids[*idx]).collect();
+
+    let (child_sizes, offsets) = match mode {
+        UnionMode::Sparse => (vec![size; fields.len()], None),
+        UnionMode::Dense => {
+            let mut child_sizes = vec![0; fields.len()];
+            let offsets: Buffer = child_idx
+                .iter()
+                .map(|idx| {
+                    child_sizes[*idx] += 1;
+                    child_sizes[*idx] as i32 - 1
+                })
+                .collect();
+            (child_sizes, Some(offsets))
+        }
+    };
+
+    let children = fields
+        .iter()
+        .zip(child_sizes)
+        .map(|(field, size)| {
+            Ok((field.clone(), random_array(field, size, options, rng)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Arc::new(UnionArray::try_new(
+        type_ids, types, offsets, children,
+    )?))
 }
 
-fn create_random_null_buffer(size: usize, null_density: f32) -> Buffer {
-    let mut rng = seedable_rng();
+fn random_null_buffer(
+    size: usize,
+    null_density: f32,
+    rng: &mut StdRng,
+) -> Option<Buffer> {
+    if null_density <= 0.0 {
+        return None;
+    }
     let mut mut_buf = MutableBuffer::new_null(size);
     {
         let mut_slice = mut_buf.as_slice_mut();
@@ -247,7 +607,7 @@ fn create_random_null_buffer(size: usize, null_density: f32) -> Buffer {
             }
         })
     };
-    mut_buf.into()
+    Some(mut_buf.into())
 }
 
 #[cfg(test)]
@@ -355,4 +715,107 @@ mod tests {
         assert_eq!(col_d_y.data_type(), &DataType::Float32);
         assert_eq!(col_d_y.null_count(), 0);
     }
+
+    #[test]
+    fn test_create_batch_with_options() {
+        let size = 100;
+        let entries = Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int32, true),
+            ]),
+            false,
+        );
+        let fields = vec![
+            Field::new("f16", DataType::Float16, true),
+            Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Second, Some("UTC".into())),
+                true,
+            ),
+            Field::new("dur", DataType::Duration(TimeUnit::Millisecond), true),
+            Field::new("ym", DataType::Interval(IntervalUnit::YearMonth), true),
+            Field::new("mdn", DataType::Interval(IntervalUnit::MonthDayNano), true),
+            Field::new("d128", DataType::Decimal128(10, 2), true),
+            Field::new("d256", DataType::Decimal256(50, 5), true),
+            Field::new("s", DataType::Utf8, true),
+            Field::new(
+                "fsl",
+                DataType::FixedSizeList(
+                    Box::new(Field::new("item", DataType::Int8, true)),
+                    3,
+                ),
+                true,
+            ),
+            Field::new("map", DataType::Map(Box::new(entries), false), true),
+            Field::new(
+                "dict",
+                DataType::Dictionary(Box::new(DataType::UInt8), Box::new(DataType::Utf8)),
+                true,
+            ),
+            Field::new(
+                "union",
+                DataType::Union(
+                    vec![
+                        Field::new("a", DataType::Int32, true),
+                        Field::new("b", DataType::Utf8, true),
+                    ],
+                    vec![2, 5],
+                    UnionMode::Dense,
+                ),
+                false,
+            ),
+        ];
+        let schema = Arc::new(Schema::new(fields));
+        let options = RandomArrayOptions::new()
+            .with_null_density(0.3)
+            .with_value_len(2..=6)
+            .with_dictionary_cardinality(5)
+            .with_seed(7);
+
+        let batch =
+            create_random_batch_with_options(schema.clone(), size, &options).unwrap();
+        assert_eq!(batch.schema(), schema);
+        for column in batch.columns() {
+            assert_eq!(column.len(), size);
+            column.data().validate_full().unwrap();
+        }
+        assert!(batch.column(0).null_count() > 0);
+
+        let strings = as_string_array(batch.column(7));
+        assert!(strings.iter().flatten().all(|s| (2..=6).contains(&s.len())));
+
+        let dict = batch
+            .column(10)
+            .as_any()
+            .downcast_ref::<DictionaryArray<UInt8Type>>()
+            .unwrap();
+        assert_eq!(dict.values().len(), 5);
+
+        // The same seed generates the same data
+        let other = create_random_batch_with_options(schema, size, &options).unwrap();
+        assert_eq!(batch, other);
+    }
+
+    #[test]
+    fn test_create_decimal_precision() {
+        let options = RandomArrayOptions::new().with_null_density(0.);
+        let field = Field::new("d", DataType::Decimal128(1, 0), false);
+        let array = create_random_array_with_options(&field, 100, &options).unwrap();
+        let array = as_decimal_array(&array);
+        assert!(array
+            .iter()
+            .flatten()
+            .all(|v| (-9..=9).contains(&v.as_i128())));
+
+        for data_type in [DataType::Decimal128(0, 0), DataType::Decimal256(0, 0)] {
+            let field = Field::new("d", data_type, true);
+            let err = create_random_array_with_options(&field, 10, &options).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Invalid argument error: Decimal precision must be at least 1, got 0"
+            );
+        }
+    }
 }
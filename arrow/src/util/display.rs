@@ -19,252 +19,238 @@
 //! purposes. See the `pretty` crate for additional functions for
 //! record batch pretty printing.
 
-use std::fmt::Write;
+use std::fmt::{Display, Formatter, Write};
 use std::sync::Arc;
 
 use crate::array::Array;
 use crate::datatypes::{
     ArrowNativeType, ArrowPrimitiveType, DataType, Field, Int16Type, Int32Type,
-    Int64Type, Int8Type, TimeUnit, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
-    UnionMode,
+    Int64Type, Int8Type, IntervalDayTimeType, IntervalMonthDayNanoType, TimeUnit,
+    UInt16Type, UInt32Type, UInt64Type, UInt8Type, UnionMode,
 };
 use crate::{array, datatypes::IntervalUnit};
 
 use array::DictionaryArray;
 
 use crate::error::{ArrowError, Result};
+use crate::temporal_conversions::time_unit_multiple;
 
-macro_rules! make_string {
-    ($array_type:ty, $column: ident, $row: ident) => {{
+macro_rules! write_value {
+    ($array_type:ty, $f: ident, $column: ident, $row: ident) => {{
         let array = $column.as_any().downcast_ref::<$array_type>().unwrap();
 
-        let s = if array.is_null($row) {
-            "".to_string()
-        } else {
-            array.value($row).to_string()
-        };
-
-        Ok(s)
+        write!($f, "{}", array.value($row))?;
+        Ok(())
     }};
 }
 
-macro_rules! make_string_interval_year_month {
-    ($column: ident, $row: ident) => {{
+macro_rules! write_interval_year_month {
+    ($f: ident, $column: ident, $row: ident) => {{
         let array = $column
             .as_any()
             .downcast_ref::<array::IntervalYearMonthArray>()
             .unwrap();
 
-        let s = if array.is_null($row) {
-            "NULL".to_string()
-        } else {
-            let interval = array.value($row) as f64;
-            let years = (interval / 12_f64).floor();
-            let month = interval - (years * 12_f64);
-
-            format!(
-                "{} years {} mons 0 days 0 hours 0 mins 0.00 secs",
-                years, month,
-            )
-        };
-
-        Ok(s)
+        write_interval($f, array.value($row), 0, 0)?;
+        Ok(())
     }};
 }
 
-macro_rules! make_string_interval_day_time {
-    ($column: ident, $row: ident) => {{
+macro_rules! write_interval_day_time {
+    ($f: ident, $column: ident, $row: ident) => {{
         let array = $column
             .as_any()
             .downcast_ref::<array::IntervalDayTimeArray>()
             .unwrap();
 
-        let s = if array.is_null($row) {
-            "NULL".to_string()
-        } else {
-            let value: u64 = array.value($row) as u64;
-
-            let days_parts: i32 = ((value & 0xFFFFFFFF00000000) >> 32) as i32;
-            let milliseconds_part: i32 = (value & 0xFFFFFFFF) as i32;
-
-            let secs = milliseconds_part / 1000;
-            let mins = secs / 60;
-            let hours = mins / 60;
-
-            let secs = secs - (mins * 60);
-            let mins = mins - (hours * 60);
-
-            format!(
-                "0 years 0 mons {} days {} hours {} mins {}.{:02} secs",
-                days_parts,
-                hours,
-                mins,
-                secs,
-                (milliseconds_part % 1000),
-            )
-        };
-
-        Ok(s)
+        let (days, millis) = IntervalDayTimeType::to_parts(array.value($row));
+        write_interval($f, 0, days as i64, millis as i64 * 1_000_000)?;
+        Ok(())
     }};
 }
 
-macro_rules! make_string_interval_month_day_nano {
-    ($column: ident, $row: ident) => {{
+macro_rules! write_interval_month_day_nano {
+    ($f: ident, $column: ident, $row: ident) => {{
         let array = $column
             .as_any()
             .downcast_ref::<array::IntervalMonthDayNanoArray>()
             .unwrap();
 
-        let s = if array.is_null($row) {
-            "NULL".to_string()
-        } else {
-            let value: u128 = array.value($row) as u128;
-
-            let months_part: i32 =
-                ((value & 0xFFFFFFFF000000000000000000000000) >> 96) as i32;
-            let days_part: i32 = ((value & 0xFFFFFFFF0000000000000000) >> 64) as i32;
-            let nanoseconds_part: i64 = (value & 0xFFFFFFFFFFFFFFFF) as i64;
-
-            let secs = nanoseconds_part / 1000000000;
-            let mins = secs / 60;
-            let hours = mins / 60;
-
-            let secs = secs - (mins * 60);
-            let mins = mins - (hours * 60);
-
-            format!(
-                "0 years {} mons {} days {} hours {} mins {}.{:02} secs",
-                months_part,
-                days_part,
-                hours,
-                mins,
-                secs,
-                (nanoseconds_part % 1000000000),
-            )
-        };
-
-        Ok(s)
+        let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(array.value($row));
+        write_interval($f, months, days as i64, nanos)?;
+        Ok(())
     }};
 }
 
-macro_rules! make_string_date {
-    ($array_type:ty, $column: ident, $row: ident) => {{
+macro_rules! write_duration {
+    ($array_type:ty, $f: ident, $column: ident, $row: ident, $unit: expr) => {{
         let array = $column.as_any().downcast_ref::<$array_type>().unwrap();
 
-        let s = if array.is_null($row) {
-            "".to_string()
-        } else {
-            array
-                .value_as_date($row)
-                .map(|d| d.to_string())
-                .unwrap_or_else(|| "ERROR CONVERTING DATE".to_string())
-        };
-
-        Ok(s)
+        write_duration($f, array.value($row), $unit)?;
+        Ok(())
     }};
 }
 
-macro_rules! make_string_time {
-    ($array_type:ty, $column: ident, $row: ident) => {{
+// Dates, times and timestamps are converted to their chrono equivalent by `$as_chrono`
+macro_rules! write_temporal {
+    ($array_type:ty, $f: ident, $column: ident, $row: ident, $as_chrono: ident, $format: expr) => {{
         let array = $column.as_any().downcast_ref::<$array_type>().unwrap();
 
-        let s = if array.is_null($row) {
-            "".to_string()
-        } else {
-            array
-                .value_as_time($row)
-                .map(|d| d.to_string())
-                .unwrap_or_else(|| "ERROR CONVERTING DATE".to_string())
-        };
-
-        Ok(s)
+        match array.$as_chrono($row) {
+            Some(value) => match $format {
+                Some(format) => write!($f, "{}", value.format(format))?,
+                None => write!($f, "{}", value)?,
+            },
+            None => $f.write_str("ERROR CONVERTING DATE")?,
+        }
+        Ok(())
     }};
 }
 
-macro_rules! make_string_datetime {
-    ($array_type:ty, $column: ident, $row: ident) => {{
+macro_rules! write_binary {
+    ($array_type:ty, $f: ident, $column: ident, $row: ident, $format: expr) => {{
         let array = $column.as_any().downcast_ref::<$array_type>().unwrap();
 
-        let s = if array.is_null($row) {
-            "".to_string()
-        } else {
-            array
-                .value_as_datetime($row)
-                .map(|d| d.to_string())
-                .unwrap_or_else(|| "ERROR CONVERTING DATE".to_string())
-        };
-
-        Ok(s)
+        write_binary($f, array.value($row), $format)?;
+        Ok(())
     }};
 }
 
-// It's not possible to do array.value($row).to_string() for &[u8], let's format it as hex
-macro_rules! make_string_hex {
-    ($array_type:ty, $column: ident, $row: ident) => {{
-        let array = $column.as_any().downcast_ref::<$array_type>().unwrap();
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
 
-        let s = if array.is_null($row) {
-            "".to_string()
-        } else {
-            let mut tmp = "".to_string();
+/// Formats `bytes` as text, see [`BinaryFormat`]
+pub fn format_binary(bytes: &[u8], format: BinaryFormat) -> String {
+    let mut s = String::new();
+    // writing to a String cannot fail
+    write_binary(&mut s, bytes, format).unwrap();
+    s
+}
 
-            for character in array.value($row) {
-                let _ = write!(tmp, "{:02x}", character);
+/// Writes `bytes` to `f`, see [`format_binary`]
+fn write_binary(
+    f: &mut dyn Write,
+    bytes: &[u8],
+    format: BinaryFormat,
+) -> std::fmt::Result {
+    match format {
+        BinaryFormat::Hex | BinaryFormat::HexPrefixed => {
+            if format == BinaryFormat::HexPrefixed {
+                f.write_str("0x")?;
+            }
+            for byte in bytes {
+                write!(f, "{:02x}", byte)?;
+            }
+        }
+        BinaryFormat::Base64 => {
+            for chunk in bytes.chunks(3) {
+                let bits = chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0_u32, |acc, (i, b)| acc | (*b as u32) << (16 - 8 * i));
+                // a chunk of n bytes is encoded as n + 1 characters, padded with `=`
+                for i in 0..4 {
+                    match i <= chunk.len() {
+                        true => {
+                            let idx = (bits >> (18 - 6 * i)) & 0x3f;
+                            f.write_char(BASE64_ALPHABET[idx as usize] as char)?
+                        }
+                        false => f.write_char('=')?,
+                    }
+                }
             }
+        }
+    }
+    Ok(())
+}
 
-            tmp
-        };
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+const SECONDS_PER_DAY: i64 = 86_400;
 
-        Ok(s)
-    }};
+/// Formats an interval of `months`, `days` and `nanos`, in the style of PostgreSQL,
+/// e.g. `1 year 2 mons 3 days 04:05:06.789`. Zero components are omitted and
+/// fractional seconds are only displayed when non-zero.
+pub fn format_interval(months: i32, days: i64, nanos: i64) -> String {
+    let mut s = String::new();
+    // writing to a String cannot fail
+    write_interval(&mut s, months, days, nanos).unwrap();
+    s
 }
 
-macro_rules! make_string_from_list {
-    ($column: ident, $row: ident) => {{
-        let list = $column
-            .as_any()
-            .downcast_ref::<array::ListArray>()
-            .ok_or(ArrowError::InvalidArgumentError(format!(
-                "Repl error: could not convert list column to list array."
-            )))?
-            .value($row);
-        let string_values = (0..list.len())
-            .map(|i| array_value_to_string(&list.clone(), i))
-            .collect::<Result<Vec<String>>>()?;
-        Ok(format!("[{}]", string_values.join(", ")))
-    }};
+/// Writes an interval to `f`, see [`format_interval`]
+fn write_interval(
+    f: &mut dyn Write,
+    months: i32,
+    days: i64,
+    nanos: i64,
+) -> std::fmt::Result {
+    let (years, months) = (months / 12, months % 12);
+    let units = [
+        (years as i64, "year", "years"),
+        (months as i64, "mon", "mons"),
+        (days, "day", "days"),
+    ];
+
+    let mut empty = true;
+    for (value, singular, plural) in units {
+        if value == 0 {
+            continue;
+        }
+        if !empty {
+            f.write_char(' ')?;
+        }
+        match value {
+            1 => write!(f, "1 {}", singular)?,
+            v => write!(f, "{} {}", v, plural)?,
+        }
+        empty = false;
+    }
+
+    if nanos != 0 || empty {
+        if !empty {
+            f.write_char(' ')?;
+        }
+        let sign = if nanos < 0 { "-" } else { "" };
+        let nanos = nanos.unsigned_abs();
+        let secs = nanos / NANOS_PER_SECOND as u64;
+        write!(
+            f,
+            "{}{:02}:{:02}:{:02}",
+            sign,
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        )?;
+        let mut fraction = nanos % NANOS_PER_SECOND as u64;
+        if fraction != 0 {
+            // omit trailing zeros
+            let mut digits = 9;
+            while fraction % 10 == 0 {
+                fraction /= 10;
+                digits -= 1;
+            }
+            write!(f, ".{:0digits$}", fraction, digits = digits)?;
+        }
+    }
+    Ok(())
 }
 
-macro_rules! make_string_from_large_list {
-    ($column: ident, $row: ident) => {{
-        let list = $column
-            .as_any()
-            .downcast_ref::<array::LargeListArray>()
-            .ok_or(ArrowError::InvalidArgumentError(format!(
-                "Repl error: could not convert large list column to list array."
-            )))?
-            .value($row);
-        let string_values = (0..list.len())
-            .map(|i| array_value_to_string(&list, i))
-            .collect::<Result<Vec<String>>>()?;
-        Ok(format!("[{}]", string_values.join(", ")))
-    }};
+/// Formats a duration of `value` in `unit`s as a number of days followed by the
+/// remaining time, e.g. `2 days 03:04:05.123`
+pub fn format_duration(value: i64, unit: &TimeUnit) -> String {
+    let mut s = String::new();
+    // writing to a String cannot fail
+    write_duration(&mut s, value, unit).unwrap();
+    s
 }
 
-macro_rules! make_string_from_fixed_size_list {
-    ($column: ident, $row: ident) => {{
-        let list = $column
-            .as_any()
-            .downcast_ref::<array::FixedSizeListArray>()
-            .ok_or(ArrowError::InvalidArgumentError(format!(
-                "Repl error: could not convert list column to list array."
-            )))?
-            .value($row);
-        let string_values = (0..list.len())
-            .map(|i| array_value_to_string(&list.clone(), i))
-            .collect::<Result<Vec<String>>>()?;
-        Ok(format!("[{}]", string_values.join(", ")))
-    }};
+/// Writes a duration to `f`, see [`format_duration`]
+fn write_duration(f: &mut dyn Write, value: i64, unit: &TimeUnit) -> std::fmt::Result {
+    let units_per_second = time_unit_multiple(unit);
+    let units_per_day = units_per_second * SECONDS_PER_DAY;
+    let days = value / units_per_day;
+    let nanos = (value % units_per_day) * (NANOS_PER_SECOND / units_per_second);
+    write_interval(f, 0, days, nanos)
 }
 
 #[inline(always)]
@@ -278,144 +264,740 @@ pub fn make_string_from_decimal(column: &Arc<dyn Array>, row: usize) -> Result<S
     Ok(formatted_decimal)
 }
 
-fn append_struct_field_string(
-    target: &mut String,
-    name: &str,
-    field_col: &Arc<dyn Array>,
+/// Writes the value at `row` of `column`, which is nested at `depth` within the
+/// value being displayed
+fn write_nested_value(
+    f: &mut dyn Write,
+    column: &dyn Array,
     row: usize,
+    options: &FormatOptions,
+    depth: usize,
 ) -> Result<()> {
-    target.push('"');
-    target.push_str(name);
-    target.push_str("\": ");
-
-    if field_col.is_null(row) {
-        target.push_str("null");
-    } else {
-        match field_col.data_type() {
-            DataType::Utf8 | DataType::LargeUtf8 => {
-                target.push('"');
-                target.push_str(array_value_to_string(field_col, row)?.as_str());
-                target.push('"');
-            }
-            _ => {
-                target.push_str(array_value_to_string(field_col, row)?.as_str());
-            }
+    if column.is_null(row) {
+        f.write_str("null")?;
+        return Ok(());
+    }
+    write_value(f, column, row, options, depth)
+}
+
+/// Whether nested values at `depth` should be elided rather than displayed
+fn exceeds_max_depth(options: &FormatOptions, depth: usize) -> bool {
+    matches!(options.max_depth(), Some(max_depth) if depth >= max_depth)
+}
+
+/// Writes the elements of a list as `[a, b, c]`
+fn write_list(
+    f: &mut dyn Write,
+    values: &dyn Array,
+    options: &FormatOptions,
+    depth: usize,
+) -> Result<()> {
+    if exceeds_max_depth(options, depth) {
+        f.write_str("[…]")?;
+        return Ok(());
+    }
+    f.write_char('[')?;
+    for i in 0..values.len() {
+        if i != 0 {
+            f.write_str(", ")?;
+        }
+        write_nested_value(f, values, i, options, depth + 1)?;
+    }
+    f.write_char(']')?;
+    Ok(())
+}
+
+/// Writes the fields of the struct at `row` as `{a: 1, b: [1, 2]}`
+fn write_struct(
+    f: &mut dyn Write,
+    column: &dyn Array,
+    row: usize,
+    options: &FormatOptions,
+    depth: usize,
+) -> Result<()> {
+    let st = column
+        .as_any()
+        .downcast_ref::<array::StructArray>()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(
+                "Repl error: could not convert struct column to struct array."
+                    .to_string(),
+            )
+        })?;
+    if exceeds_max_depth(options, depth) {
+        f.write_str("{…}")?;
+        return Ok(());
+    }
+
+    f.write_char('{')?;
+    for (i, (col, name)) in st.columns().into_iter().zip(st.column_names()).enumerate() {
+        if i != 0 {
+            f.write_str(", ")?;
         }
+        write!(f, "{}: ", name)?;
+        write_nested_value(f, col.as_ref(), row, options, depth + 1)?;
+    }
+    f.write_char('}')?;
+    Ok(())
+}
+
+/// Writes the entries of the map at `row` as `{key1: value1, key2: value2}`
+fn write_map(
+    f: &mut dyn Write,
+    column: &dyn Array,
+    row: usize,
+    options: &FormatOptions,
+    depth: usize,
+) -> Result<()> {
+    let map = column
+        .as_any()
+        .downcast_ref::<array::MapArray>()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(
+                "Repl error: could not convert map column to map array.".to_string(),
+            )
+        })?;
+    if exceeds_max_depth(options, depth) {
+        f.write_str("{…}")?;
+        return Ok(());
     }
 
+    let keys = map.keys();
+    let values = map.values();
+    let start = map.value_offsets()[row] as usize;
+    let end = map.value_offsets()[row + 1] as usize;
+    f.write_char('{')?;
+    for i in start..end {
+        if i != start {
+            f.write_str(", ")?;
+        }
+        write_nested_value(f, keys.as_ref(), i, options, depth + 1)?;
+        f.write_str(": ")?;
+        write_nested_value(f, values.as_ref(), i, options, depth + 1)?;
+    }
+    f.write_char('}')?;
     Ok(())
 }
 
-/// Get the value at the given row in an array as a String.
+/// Options for formatting array values as strings, see
+/// [`array_value_to_string_with_options`], the `pretty` module, and
+/// [`CastOptions::format_options`](crate::compute::kernels::cast::CastOptions::format_options)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FormatOptions {
+    null: Option<String>,
+    date_format: Option<String>,
+    datetime_format: Option<String>,
+    time_format: Option<String>,
+    decimal_separator: Option<char>,
+    suppress_scientific: bool,
+    max_width: Option<usize>,
+    max_rows: Option<usize>,
+    max_depth: Option<usize>,
+    binary_format: BinaryFormat,
+    row_numbers: bool,
+    summary: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FormatOptions {
+    /// Creates a new set of format options, equivalent to [`FormatOptions::default`]
+    pub const fn new() -> Self {
+        Self {
+            null: None,
+            date_format: None,
+            datetime_format: None,
+            time_format: None,
+            decimal_separator: None,
+            suppress_scientific: false,
+            max_width: None,
+            max_rows: None,
+            max_depth: None,
+            binary_format: BinaryFormat::Hex,
+            row_numbers: false,
+            summary: false,
+        }
+    }
+
+    /// Sets the string used to display null values, defaults to `""`
+    ///
+    /// When casting to strings, nulls are only replaced if this is set, and otherwise
+    /// remain null
+    pub fn with_null(mut self, null: impl Into<String>) -> Self {
+        self.null = Some(null.into());
+        self
+    }
+
+    /// Sets the [`chrono` format string] used to display dates, defaults to ISO 8601
+    ///
+    /// [`chrono` format string]: chrono::format::strftime
+    pub fn with_date_format(mut self, date_format: Option<String>) -> Self {
+        self.date_format = date_format;
+        self
+    }
+
+    /// Sets the [`chrono` format string] used to display timestamps, defaults to
+    /// ISO 8601 with a space separating the date and the time
+    ///
+    /// [`chrono` format string]: chrono::format::strftime
+    pub fn with_datetime_format(mut self, datetime_format: Option<String>) -> Self {
+        self.datetime_format = datetime_format;
+        self
+    }
+
+    /// Sets the [`chrono` format string] used to display times, defaults to ISO 8601
+    ///
+    /// [`chrono` format string]: chrono::format::strftime
+    pub fn with_time_format(mut self, time_format: Option<String>) -> Self {
+        self.time_format = time_format;
+        self
+    }
+
+    /// Sets the separator between the integer and fractional digits of floating point
+    /// and decimal values, defaults to `.`
+    pub fn with_decimal_separator(mut self, decimal_separator: Option<char>) -> Self {
+        self.decimal_separator = decimal_separator;
+        self
+    }
+
+    /// Sets whether floating point values cast to strings are formatted in positional
+    /// rather than scientific notation, e.g. `0.0000001` rather than `1e-7`, defaults
+    /// to `false`. Displayed values always use positional notation
+    pub fn with_suppress_scientific(mut self, suppress_scientific: bool) -> Self {
+        self.suppress_scientific = suppress_scientific;
+        self
+    }
+
+    /// Sets the maximum number of characters displayed per value when pretty
+    /// printing, longer values are truncated and end with `…`. A maximum of `0`
+    /// displays every value as an empty cell
+    pub fn with_max_width(mut self, max_width: Option<usize>) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum number of rows displayed when pretty printing, the number
+    /// of omitted rows is shown below the table
+    pub fn with_max_rows(mut self, max_rows: Option<usize>) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Sets the maximum nesting depth displayed for nested types such as lists,
+    /// structs and maps, deeper values are displayed as `[…]` or `{…}`
+    pub fn with_max_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets how binary values are displayed, defaults to [`BinaryFormat::Hex`]
+    pub fn with_binary_format(mut self, binary_format: BinaryFormat) -> Self {
+        self.binary_format = binary_format;
+        self
+    }
+
+    /// Sets whether pretty printing prefixes each row with its index, in a
+    /// column named `#`
+    pub fn with_row_numbers(mut self, row_numbers: bool) -> Self {
+        self.row_numbers = row_numbers;
+        self
+    }
+
+    /// Sets whether pretty printing displays the number of rows and columns,
+    /// and the type of each column, below the table
+    pub fn with_summary(mut self, summary: bool) -> Self {
+        self.summary = summary;
+        self
+    }
+
+    /// Returns the string used to display null values
+    pub fn null(&self) -> &str {
+        self.null.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the string nulls are replaced with when cast to strings, if set
+    pub(crate) fn cast_null(&self) -> Option<&str> {
+        self.null.as_deref()
+    }
+
+    /// Returns the format string used to display dates, if any
+    pub fn date_format(&self) -> Option<&str> {
+        self.date_format.as_deref()
+    }
+
+    /// Returns the format string used to display timestamps, if any
+    pub fn datetime_format(&self) -> Option<&str> {
+        self.datetime_format.as_deref()
+    }
+
+    /// Returns the format string used to display times, if any
+    pub fn time_format(&self) -> Option<&str> {
+        self.time_format.as_deref()
+    }
+
+    /// Returns the separator between integer and fractional digits, if not `.`
+    pub fn decimal_separator(&self) -> Option<char> {
+        self.decimal_separator
+    }
+
+    /// Returns whether floating point values cast to strings avoid scientific notation
+    pub fn suppress_scientific(&self) -> bool {
+        self.suppress_scientific
+    }
+
+    /// Returns the maximum number of characters displayed per value, if any
+    pub fn max_width(&self) -> Option<usize> {
+        self.max_width
+    }
+
+    /// Returns the maximum number of rows displayed, if any
+    pub fn max_rows(&self) -> Option<usize> {
+        self.max_rows
+    }
+
+    /// Returns the maximum nesting depth displayed, if any
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Returns how binary values are displayed
+    pub fn binary_format(&self) -> BinaryFormat {
+        self.binary_format
+    }
+
+    /// Returns whether rows are prefixed with their index
+    pub fn row_numbers(&self) -> bool {
+        self.row_numbers
+    }
+
+    /// Returns whether a summary is displayed below the table
+    pub fn summary(&self) -> bool {
+        self.summary
+    }
+}
+
+/// How binary values are displayed, see [`FormatOptions::with_binary_format`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BinaryFormat {
+    /// Lowercase hexadecimal digits, e.g. `0aff`
+    #[default]
+    Hex,
+    /// Lowercase hexadecimal digits prefixed with `0x`, e.g. `0x0aff`
+    HexPrefixed,
+    /// Standard base64 encoding with padding, e.g. `Cv8=`
+    Base64,
+}
+
+/// Formats the values of an array according to [`FormatOptions`], writing them
+/// to any [`std::fmt::Write`] without allocating an intermediate `String`
 ///
-/// Note this function is quite inefficient and is unlikely to be
-/// suitable for converting large arrays or record batches.
-pub fn array_value_to_string(column: &array::ArrayRef, row: usize) -> Result<String> {
-    if column.is_null(row) {
-        return Ok("".to_string());
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::Int32Array;
+/// # use arrow::util::display::{ArrayFormatter, FormatOptions};
+/// let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+/// let options = FormatOptions::new().with_null("NULL");
+/// let formatter = ArrayFormatter::try_new(&array, &options).unwrap();
+///
+/// let mut s = String::new();
+/// for i in 0..array.len() {
+///     formatter.value(i).write(&mut s).unwrap();
+///     s.push(';');
+/// }
+/// assert_eq!(s, "1;NULL;3;");
+/// assert_eq!(formatter.value(2).to_string(), "3");
+/// ```
+#[derive(Debug)]
+pub struct ArrayFormatter<'a> {
+    array: &'a dyn Array,
+    options: &'a FormatOptions,
+}
+
+impl<'a> ArrayFormatter<'a> {
+    /// Creates a formatter for `array`, returning an error if values of its
+    /// data type cannot be formatted.
+    ///
+    /// The data type is only checked here, so a formatter should be created once
+    /// per column, rather than once per value
+    pub fn try_new(array: &'a dyn Array, options: &'a FormatOptions) -> Result<Self> {
+        check_supported(array.data_type())?;
+        Ok(Self { array, options })
     }
-    match column.data_type() {
-        DataType::Utf8 => make_string!(array::StringArray, column, row),
-        DataType::LargeUtf8 => make_string!(array::LargeStringArray, column, row),
-        DataType::Binary => make_string_hex!(array::BinaryArray, column, row),
-        DataType::LargeBinary => make_string_hex!(array::LargeBinaryArray, column, row),
-        DataType::FixedSizeBinary(_) => {
-            make_string_hex!(array::FixedSizeBinaryArray, column, row)
+
+    /// Returns the options used by this formatter
+    pub fn options(&self) -> &FormatOptions {
+        self.options
+    }
+
+    /// Returns a [`ValueFormatter`] for the value at `idx`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds
+    pub fn value(&self, idx: usize) -> ValueFormatter<'_> {
+        assert!(
+            idx < self.array.len(),
+            "Trying to access an element at index {} from an array of length {}",
+            idx,
+            self.array.len()
+        );
+        ValueFormatter {
+            formatter: self,
+            idx,
         }
-        DataType::Boolean => make_string!(array::BooleanArray, column, row),
-        DataType::Int8 => make_string!(array::Int8Array, column, row),
-        DataType::Int16 => make_string!(array::Int16Array, column, row),
-        DataType::Int32 => make_string!(array::Int32Array, column, row),
-        DataType::Int64 => make_string!(array::Int64Array, column, row),
-        DataType::UInt8 => make_string!(array::UInt8Array, column, row),
-        DataType::UInt16 => make_string!(array::UInt16Array, column, row),
-        DataType::UInt32 => make_string!(array::UInt32Array, column, row),
-        DataType::UInt64 => make_string!(array::UInt64Array, column, row),
-        DataType::Float16 => make_string!(array::Float16Array, column, row),
-        DataType::Float32 => make_string!(array::Float32Array, column, row),
-        DataType::Float64 => make_string!(array::Float64Array, column, row),
-        DataType::Decimal128(..) => make_string_from_decimal(column, row),
-        DataType::Timestamp(unit, _) if *unit == TimeUnit::Second => {
-            make_string_datetime!(array::TimestampSecondArray, column, row)
+    }
+}
+
+/// Formats a single value of an array, see [`ArrayFormatter::value`]
+///
+/// Implements [`Display`], which panics if writing the value fails. Use
+/// [`ValueFormatter::write`] or [`ValueFormatter::try_to_string`] to handle
+/// such errors
+#[derive(Debug, Clone, Copy)]
+pub struct ValueFormatter<'a> {
+    formatter: &'a ArrayFormatter<'a>,
+    idx: usize,
+}
+
+impl<'a> ValueFormatter<'a> {
+    /// Writes this value to `s`
+    pub fn write(&self, s: &mut impl Write) -> Result<()> {
+        let ArrayFormatter { array, options } = self.formatter;
+        if array.is_null(self.idx) {
+            s.write_str(options.null())?;
+            return Ok(());
         }
-        DataType::Timestamp(unit, _) if *unit == TimeUnit::Millisecond => {
-            make_string_datetime!(array::TimestampMillisecondArray, column, row)
+        write_value(s, *array, self.idx, options, 0)
+    }
+
+    /// Returns this value as a `String`
+    pub fn try_to_string(&self) -> Result<String> {
+        let mut s = String::new();
+        self.write(&mut s)?;
+        Ok(s)
+    }
+}
+
+impl<'a> Display for ValueFormatter<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.write(f).map_err(|_| std::fmt::Error)
+    }
+}
+
+/// Returns an error if values of `data_type` cannot be formatted
+fn check_supported(data_type: &DataType) -> Result<()> {
+    match data_type {
+        DataType::Null
+        | DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Binary
+        | DataType::LargeBinary
+        | DataType::FixedSizeBinary(_)
+        | DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float16
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Decimal128(..)
+        | DataType::Timestamp(..)
+        | DataType::Date32
+        | DataType::Date64
+        | DataType::Time32(TimeUnit::Second | TimeUnit::Millisecond)
+        | DataType::Time64(TimeUnit::Microsecond | TimeUnit::Nanosecond)
+        | DataType::Duration(_)
+        | DataType::Interval(_) => Ok(()),
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => check_supported(field.data_type()),
+        DataType::Struct(fields) | DataType::Union(fields, _, _) => fields
+            .iter()
+            .try_for_each(|field| check_supported(field.data_type())),
+        DataType::Dictionary(key_type, value_type) => {
+            if !DataType::is_dictionary_key_type(key_type) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Pretty printing not supported for {:?} due to index type",
+                    data_type
+                )));
+            }
+            check_supported(value_type)
         }
-        DataType::Timestamp(unit, _) if *unit == TimeUnit::Microsecond => {
-            make_string_datetime!(array::TimestampMicrosecondArray, column, row)
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "Pretty printing not implemented for {:?} type",
+            data_type
+        ))),
+    }
+}
+
+/// Get the value at the given row in an array as a String.
+///
+/// Note this function is quite inefficient and is unlikely to be
+/// suitable for converting large arrays or record batches, see
+/// [`ArrayFormatter`] instead.
+pub fn array_value_to_string(column: &array::ArrayRef, row: usize) -> Result<String> {
+    array_value_to_string_with_options(column, row, &FormatOptions::default())
+}
+
+/// Get the value at the given row in an array as a String, formatted according
+/// to `options`.
+///
+/// Note this function is quite inefficient and is unlikely to be
+/// suitable for converting large arrays or record batches, see
+/// [`ArrayFormatter`] instead.
+pub fn array_value_to_string_with_options(
+    column: &array::ArrayRef,
+    row: usize,
+    options: &FormatOptions,
+) -> Result<String> {
+    ArrayFormatter::try_new(column.as_ref(), options)?
+        .value(row)
+        .try_to_string()
+}
+
+/// Writes a floating point or decimal `value` with the decimal separator of `options`
+fn write_fractional(
+    f: &mut dyn Write,
+    value: impl Display,
+    options: &FormatOptions,
+) -> Result<()> {
+    match options.decimal_separator() {
+        Some(separator) => {
+            f.write_str(&value.to_string().replace('.', &separator.to_string()))?
         }
-        DataType::Timestamp(unit, _) if *unit == TimeUnit::Nanosecond => {
-            make_string_datetime!(array::TimestampNanosecondArray, column, row)
+        None => write!(f, "{}", value)?,
+    }
+    Ok(())
+}
+
+/// Writes the non-null value at `row` of `column`, nested at `depth`
+fn write_value(
+    f: &mut dyn Write,
+    column: &dyn Array,
+    row: usize,
+    options: &FormatOptions,
+    depth: usize,
+) -> Result<()> {
+    match column.data_type() {
+        DataType::Utf8 => write_value!(array::StringArray, f, column, row),
+        DataType::LargeUtf8 => write_value!(array::LargeStringArray, f, column, row),
+        DataType::Binary => {
+            write_binary!(array::BinaryArray, f, column, row, options.binary_format())
         }
-        DataType::Date32 => make_string_date!(array::Date32Array, column, row),
-        DataType::Date64 => make_string_date!(array::Date64Array, column, row),
-        DataType::Time32(unit) if *unit == TimeUnit::Second => {
-            make_string_time!(array::Time32SecondArray, column, row)
+        DataType::LargeBinary => write_binary!(
+            array::LargeBinaryArray,
+            f,
+            column,
+            row,
+            options.binary_format()
+        ),
+        DataType::FixedSizeBinary(_) => write_binary!(
+            array::FixedSizeBinaryArray,
+            f,
+            column,
+            row,
+            options.binary_format()
+        ),
+        DataType::Boolean => write_value!(array::BooleanArray, f, column, row),
+        DataType::Int8 => write_value!(array::Int8Array, f, column, row),
+        DataType::Int16 => write_value!(array::Int16Array, f, column, row),
+        DataType::Int32 => write_value!(array::Int32Array, f, column, row),
+        DataType::Int64 => write_value!(array::Int64Array, f, column, row),
+        DataType::UInt8 => write_value!(array::UInt8Array, f, column, row),
+        DataType::UInt16 => write_value!(array::UInt16Array, f, column, row),
+        DataType::UInt32 => write_value!(array::UInt32Array, f, column, row),
+        DataType::UInt64 => write_value!(array::UInt64Array, f, column, row),
+        DataType::Float16 => {
+            let array = column
+                .as_any()
+                .downcast_ref::<array::Float16Array>()
+                .unwrap();
+            write_fractional(f, array.value(row), options)
         }
-        DataType::Time32(unit) if *unit == TimeUnit::Millisecond => {
-            make_string_time!(array::Time32MillisecondArray, column, row)
+        DataType::Float32 => {
+            let array = column
+                .as_any()
+                .downcast_ref::<array::Float32Array>()
+                .unwrap();
+            write_fractional(f, array.value(row), options)
         }
-        DataType::Time64(unit) if *unit == TimeUnit::Microsecond => {
-            make_string_time!(array::Time64MicrosecondArray, column, row)
+        DataType::Float64 => {
+            let array = column
+                .as_any()
+                .downcast_ref::<array::Float64Array>()
+                .unwrap();
+            write_fractional(f, array.value(row), options)
         }
-        DataType::Time64(unit) if *unit == TimeUnit::Nanosecond => {
-            make_string_time!(array::Time64NanosecondArray, column, row)
+        DataType::Decimal128(..) => {
+            let array = column
+                .as_any()
+                .downcast_ref::<array::Decimal128Array>()
+                .unwrap();
+            write_fractional(f, array.value_as_string(row), options)
         }
-        DataType::Interval(unit) => match unit {
-            IntervalUnit::DayTime => {
-                make_string_interval_day_time!(column, row)
+        DataType::Timestamp(unit, _) => match unit {
+            TimeUnit::Second => write_temporal!(
+                array::TimestampSecondArray,
+                f,
+                column,
+                row,
+                value_as_datetime,
+                options.datetime_format()
+            ),
+            TimeUnit::Millisecond => write_temporal!(
+                array::TimestampMillisecondArray,
+                f,
+                column,
+                row,
+                value_as_datetime,
+                options.datetime_format()
+            ),
+            TimeUnit::Microsecond => write_temporal!(
+                array::TimestampMicrosecondArray,
+                f,
+                column,
+                row,
+                value_as_datetime,
+                options.datetime_format()
+            ),
+            TimeUnit::Nanosecond => write_temporal!(
+                array::TimestampNanosecondArray,
+                f,
+                column,
+                row,
+                value_as_datetime,
+                options.datetime_format()
+            ),
+        },
+        DataType::Date32 => write_temporal!(
+            array::Date32Array,
+            f,
+            column,
+            row,
+            value_as_date,
+            options.date_format()
+        ),
+        DataType::Date64 => write_temporal!(
+            array::Date64Array,
+            f,
+            column,
+            row,
+            value_as_date,
+            options.date_format()
+        ),
+        DataType::Time32(TimeUnit::Second) => write_temporal!(
+            array::Time32SecondArray,
+            f,
+            column,
+            row,
+            value_as_time,
+            options.time_format()
+        ),
+        DataType::Time32(TimeUnit::Millisecond) => write_temporal!(
+            array::Time32MillisecondArray,
+            f,
+            column,
+            row,
+            value_as_time,
+            options.time_format()
+        ),
+        DataType::Time64(TimeUnit::Microsecond) => write_temporal!(
+            array::Time64MicrosecondArray,
+            f,
+            column,
+            row,
+            value_as_time,
+            options.time_format()
+        ),
+        DataType::Time64(TimeUnit::Nanosecond) => write_temporal!(
+            array::Time64NanosecondArray,
+            f,
+            column,
+            row,
+            value_as_time,
+            options.time_format()
+        ),
+        DataType::Duration(unit) => match unit {
+            TimeUnit::Second => {
+                write_duration!(array::DurationSecondArray, f, column, row, unit)
             }
-            IntervalUnit::YearMonth => {
-                make_string_interval_year_month!(column, row)
+            TimeUnit::Millisecond => {
+                write_duration!(array::DurationMillisecondArray, f, column, row, unit)
             }
-            IntervalUnit::MonthDayNano => {
-                make_string_interval_month_day_nano!(column, row)
+            TimeUnit::Microsecond => {
+                write_duration!(array::DurationMicrosecondArray, f, column, row, unit)
             }
+            TimeUnit::Nanosecond => {
+                write_duration!(array::DurationNanosecondArray, f, column, row, unit)
+            }
+        },
+        DataType::Interval(unit) => match unit {
+            IntervalUnit::DayTime => write_interval_day_time!(f, column, row),
+            IntervalUnit::YearMonth => write_interval_year_month!(f, column, row),
+            IntervalUnit::MonthDayNano => write_interval_month_day_nano!(f, column, row),
         },
-        DataType::List(_) => make_string_from_list!(column, row),
-        DataType::LargeList(_) => make_string_from_large_list!(column, row),
+        DataType::List(_) => {
+            let list = column.as_any().downcast_ref::<array::ListArray>().unwrap();
+            write_list(f, list.value(row).as_ref(), options, depth)
+        }
+        DataType::LargeList(_) => {
+            let list = column
+                .as_any()
+                .downcast_ref::<array::LargeListArray>()
+                .unwrap();
+            write_list(f, list.value(row).as_ref(), options, depth)
+        }
         DataType::Dictionary(index_type, _value_type) => match **index_type {
-            DataType::Int8 => dict_array_value_to_string::<Int8Type>(column, row),
-            DataType::Int16 => dict_array_value_to_string::<Int16Type>(column, row),
-            DataType::Int32 => dict_array_value_to_string::<Int32Type>(column, row),
-            DataType::Int64 => dict_array_value_to_string::<Int64Type>(column, row),
-            DataType::UInt8 => dict_array_value_to_string::<UInt8Type>(column, row),
-            DataType::UInt16 => dict_array_value_to_string::<UInt16Type>(column, row),
-            DataType::UInt32 => dict_array_value_to_string::<UInt32Type>(column, row),
-            DataType::UInt64 => dict_array_value_to_string::<UInt64Type>(column, row),
+            DataType::Int8 => {
+                write_dict_value::<Int8Type>(f, column, row, options, depth)
+            }
+            DataType::Int16 => {
+                write_dict_value::<Int16Type>(f, column, row, options, depth)
+            }
+            DataType::Int32 => {
+                write_dict_value::<Int32Type>(f, column, row, options, depth)
+            }
+            DataType::Int64 => {
+                write_dict_value::<Int64Type>(f, column, row, options, depth)
+            }
+            DataType::UInt8 => {
+                write_dict_value::<UInt8Type>(f, column, row, options, depth)
+            }
+            DataType::UInt16 => {
+                write_dict_value::<UInt16Type>(f, column, row, options, depth)
+            }
+            DataType::UInt32 => {
+                write_dict_value::<UInt32Type>(f, column, row, options, depth)
+            }
+            DataType::UInt64 => {
+                write_dict_value::<UInt64Type>(f, column, row, options, depth)
+            }
             _ => Err(ArrowError::InvalidArgumentError(format!(
                 "Pretty printing not supported for {:?} due to index type",
                 column.data_type()
             ))),
         },
-        DataType::FixedSizeList(_, _) => make_string_from_fixed_size_list!(column, row),
-        DataType::Struct(_) => {
-            let st = column
+        DataType::FixedSizeList(_, _) => {
+            let list = column
                 .as_any()
-                .downcast_ref::<array::StructArray>()
-                .ok_or_else(|| {
-                    ArrowError::InvalidArgumentError(
-                        "Repl error: could not convert struct column to struct array."
-                            .to_string(),
-                    )
-                })?;
-
-            let mut s = String::new();
-            s.push('{');
-            let mut kv_iter = st.columns().into_iter().zip(st.column_names().into_iter());
-            if let Some((col, name)) = kv_iter.next() {
-                append_struct_field_string(&mut s, name, col, row)?;
-            }
-            for (col, name) in kv_iter {
-                s.push_str(", ");
-                append_struct_field_string(&mut s, name, col, row)?;
-            }
-            s.push('}');
-
-            Ok(s)
+                .downcast_ref::<array::FixedSizeListArray>()
+                .unwrap();
+            write_list(f, list.value(row).as_ref(), options, depth)
         }
+        DataType::Struct(_) => write_struct(f, column, row, options, depth),
+        DataType::Map(_, _) => write_map(f, column, row, options, depth),
         DataType::Union(field_vec, type_ids, mode) => {
-            union_to_string(column, row, field_vec, type_ids, mode)
+            write_union(f, column, row, field_vec, type_ids, mode, options, depth)
         }
         _ => Err(ArrowError::InvalidArgumentError(format!(
             "Pretty printing not implemented for {:?} type",
@@ -424,14 +1006,18 @@ pub fn array_value_to_string(column: &array::ArrayRef, row: usize) -> Result<Str
     }
 }
 
-/// Converts the value of the union array at `row` to a String
-fn union_to_string(
-    column: &array::ArrayRef,
+/// Writes the value of the union array at `row` as `{name=value}`
+#[allow(clippy::too_many_arguments)]
+fn write_union(
+    f: &mut dyn Write,
+    column: &dyn Array,
     row: usize,
     fields: &[Field],
     type_ids: &[i8],
     mode: &UnionMode,
-) -> Result<String> {
+    options: &FormatOptions,
+    depth: usize,
+) -> Result<()> {
     let list = column
         .as_any()
         .downcast_ref::<array::UnionArray>()
@@ -449,27 +1035,35 @@ fn union_to_string(
     })?;
     let name = fields.get(field_idx).unwrap().name();
 
-    let value = array_value_to_string(
-        list.child(type_id),
-        match mode {
-            UnionMode::Dense => list.value_offset(row) as usize,
-            UnionMode::Sparse => row,
-        },
-    )?;
-
-    Ok(format!("{{{}={}}}", name, value))
+    let child = list.child(type_id);
+    let child_row = match mode {
+        UnionMode::Dense => list.value_offset(row) as usize,
+        UnionMode::Sparse => row,
+    };
+    write!(f, "{{{}=", name)?;
+    match child.is_null(child_row) {
+        true => f.write_str(options.null())?,
+        false => write_value(f, child.as_ref(), child_row, options, depth + 1)?,
+    }
+    f.write_char('}')?;
+    Ok(())
 }
-/// Converts the value of the dictionary array at `row` to a String
-fn dict_array_value_to_string<K: ArrowPrimitiveType>(
-    colum: &array::ArrayRef,
+
+/// Writes the value of the dictionary array at `row`
+fn write_dict_value<K: ArrowPrimitiveType>(
+    f: &mut dyn Write,
+    colum: &dyn Array,
     row: usize,
-) -> Result<String> {
+    options: &FormatOptions,
+    depth: usize,
+) -> Result<()> {
     let dict_array = colum.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
 
     let keys_array = dict_array.keys();
 
     if keys_array.is_null(row) {
-        return Ok(String::from(""));
+        f.write_str(options.null())?;
+        return Ok(());
     }
 
     let dict_index = keys_array.value(row).to_usize().ok_or_else(|| {
@@ -480,5 +1074,86 @@ fn dict_array_value_to_string<K: ArrowPrimitiveType>(
         ))
     })?;
 
-    array_value_to_string(dict_array.values(), dict_index)
+    let values = dict_array.values();
+    match values.is_null(dict_index) {
+        true => f.write_str(options.null())?,
+        false => write_value(f, values.as_ref(), dict_index, options, depth)?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Decimal256Array, ListArray, StringArray};
+    use crate::datatypes::Int32Type;
+    use crate::util::decimal::Decimal256;
+
+    #[test]
+    fn test_array_formatter() {
+        let array = StringArray::from(vec![Some("a"), None, Some("c")]);
+        let options = FormatOptions::new().with_null("<null>");
+        let formatter = ArrayFormatter::try_new(&array, &options).unwrap();
+
+        let mut s = String::new();
+        for i in 0..array.len() {
+            formatter.value(i).write(&mut s).unwrap();
+            s.push(',');
+        }
+        assert_eq!(s, "a,<null>,c,");
+        assert_eq!(formatter.value(1).to_string(), "<null>");
+        assert_eq!(formatter.value(2).try_to_string().unwrap(), "c");
+
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+        ]);
+        let formatter = ArrayFormatter::try_new(&list, &options).unwrap();
+        assert_eq!(formatter.value(0).to_string(), "[1, null]");
+        assert_eq!(formatter.value(1).to_string(), "<null>");
+    }
+
+    #[test]
+    fn test_array_formatter_unsupported() {
+        let options = FormatOptions::new();
+        let array: Decimal256Array = vec![None::<Decimal256>].into_iter().collect();
+        let err = ArrayFormatter::try_new(&array, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Pretty printing not implemented for Decimal256(76, 10) type"
+        );
+    }
+
+    #[test]
+    fn test_format_binary() {
+        let cases: [(&[u8], &str); 5] = [
+            (b"", ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob\xff", "Zm9vYv8="),
+        ];
+        for (bytes, expected) in cases {
+            assert_eq!(format_binary(bytes, BinaryFormat::Base64), expected);
+        }
+        assert_eq!(format_binary(&[0x0a, 0xff], BinaryFormat::Hex), "0aff");
+        assert_eq!(
+            format_binary(&[0x0a, 0xff], BinaryFormat::HexPrefixed),
+            "0x0aff"
+        );
+    }
+
+    #[test]
+    fn test_format_interval() {
+        assert_eq!(format_interval(0, 0, 0), "00:00:00");
+        assert_eq!(format_interval(14, 1, 0), "1 year 2 mons 1 day");
+        assert_eq!(
+            format_interval(0, -2, -3_600_500_000_000),
+            "-2 days -01:00:00.5"
+        );
+        assert_eq!(
+            format_duration(90_061_001, &TimeUnit::Millisecond),
+            "1 day 01:01:01.001"
+        );
+    }
 }
@@ -0,0 +1,223 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Utilities for estimating the memory used by arrays and record batches
+
+use crate::array::{make_array, Array, ArrayData, OffsetSizeTrait};
+use crate::datatypes::{DataType, UnionMode};
+use crate::record_batch::RecordBatch;
+use crate::util::bit_util;
+use arrow_data::{layout, BufferSpec};
+
+/// The memory used by an [`Array`] or a [`RecordBatch`], as returned by
+/// [`estimate_size`] and [`estimate_array_size`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemorySize {
+    /// The number of bytes of buffer data referenced by the slots of the
+    /// arrays, i.e. the size of a compacted copy of them. Dictionary values
+    /// are counted in full.
+    pub logical: usize,
+    /// The number of bytes of the distinct buffer allocations kept alive by
+    /// the arrays, each allocation being counted once however many arrays,
+    /// children or slices reference it. Externally owned allocations, e.g.
+    /// imported through FFI, are not counted.
+    pub allocated: usize,
+}
+
+/// Returns the memory used by the columns of `batch`
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{ArrayRef, Int32Array};
+/// # use arrow::record_batch::RecordBatch;
+/// # use arrow::util::memory::estimate_size;
+/// let a: ArrayRef = Arc::new(Int32Array::from_iter_values(0..1024));
+/// let batch = RecordBatch::try_from_iter([("a", a.clone()), ("b", a)]).unwrap();
+///
+/// let size = estimate_size(&batch);
+/// assert_eq!(size.logical, 2 * 1024 * 4);
+/// assert_eq!(size.allocated, 1024 * 4);
+///
+/// let size = estimate_size(&batch.slice(0, 10));
+/// assert_eq!(size.logical, 2 * 10 * 4);
+/// assert_eq!(size.allocated, 1024 * 4);
+/// ```
+pub fn estimate_size(batch: &RecordBatch) -> MemorySize {
+    MemorySize {
+        logical: batch
+            .columns()
+            .iter()
+            .map(|column| logical_size(column.data()))
+            .sum(),
+        allocated: batch.get_batch_memory_size(),
+    }
+}
+
+/// Returns the memory used by `array`
+pub fn estimate_array_size(array: &dyn Array) -> MemorySize {
+    let column = make_array(array.data().clone());
+    // a batch of a single column is always valid
+    let batch = RecordBatch::try_from_iter([("", column)]).unwrap();
+    estimate_size(&batch)
+}
+
+/// Returns the number of bytes of buffer data referenced by the slots of `data`
+fn logical_size(data: &ArrayData) -> usize {
+    let len = data.len();
+    let nulls = match data.null_buffer() {
+        Some(_) => bit_util::ceil(len, 8),
+        None => 0,
+    };
+
+    let values = match data.data_type() {
+        DataType::Null => 0,
+        DataType::Boolean => bit_util::ceil(len, 8),
+        DataType::Utf8 | DataType::Binary => variable_size::<i32>(data),
+        DataType::LargeUtf8 | DataType::LargeBinary => variable_size::<i64>(data),
+        DataType::List(_) | DataType::Map(_, _) => list_size::<i32>(data),
+        DataType::LargeList(_) => list_size::<i64>(data),
+        DataType::FixedSizeList(_, size) => {
+            let size = *size as usize;
+            let child = data.child_data()[0].slice(data.offset() * size, len * size);
+            logical_size(&child)
+        }
+        // struct children are sliced along with the struct
+        DataType::Struct(_) => data.child_data().iter().map(logical_size).sum(),
+        DataType::Dictionary(key_type, _) => {
+            fixed_width(key_type) * len + logical_size(&data.child_data()[0])
+        }
+        DataType::Union(_, _, mode) => {
+            let children: usize = match mode {
+                UnionMode::Sparse => data
+                    .child_data()
+                    .iter()
+                    .map(|child| logical_size(&child.slice(data.offset(), len)))
+                    .sum(),
+                UnionMode::Dense => data.child_data().iter().map(logical_size).sum(),
+            };
+            let offsets = match mode {
+                UnionMode::Sparse => 0,
+                UnionMode::Dense => std::mem::size_of::<i32>() * len,
+            };
+            len + offsets + children
+        }
+        data_type => fixed_width(data_type) * len,
+    };
+    nulls + values
+}
+
+/// Returns the width in bytes of the values of the fixed width `data_type`
+fn fixed_width(data_type: &DataType) -> usize {
+    match layout(data_type).buffers.as_slice() {
+        [BufferSpec::FixedWidth { byte_width }] => *byte_width,
+        _ => unreachable!("{} is not a fixed width type", data_type),
+    }
+}
+
+/// Returns the size of the offsets and values of the binary or string `data`
+fn variable_size<O: OffsetSizeTrait>(data: &ArrayData) -> usize {
+    let offsets = data.buffer::<O>(0);
+    let values = match data.len() {
+        0 => 0,
+        len => (offsets[len] - offsets[0]).as_usize(),
+    };
+    offsets_size::<O>(data.len()) + values
+}
+
+/// Returns the size of the offsets and referenced child values of the list `data`
+fn list_size<O: OffsetSizeTrait>(data: &ArrayData) -> usize {
+    let child = match data.len() {
+        0 => 0,
+        len => {
+            let offsets = data.buffer::<O>(0);
+            let start = offsets[0].as_usize();
+            let end = offsets[len].as_usize();
+            logical_size(&data.child_data()[0].slice(start, end - start))
+        }
+    };
+    offsets_size::<O>(data.len()) + child
+}
+
+/// Returns the size of the offsets of an array of `len` slots
+fn offsets_size<O: OffsetSizeTrait>(len: usize) -> usize {
+    match len {
+        0 => 0,
+        len => (len + 1) * std::mem::size_of::<O>(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::*;
+    use crate::datatypes::{Int32Type, Int8Type};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_estimate_array_size() {
+        let array = Int64Array::from(vec![Some(1), None, Some(3)]);
+        let size = estimate_array_size(&array);
+        assert_eq!(size.logical, 1 + 3 * 8);
+        assert_eq!(size.allocated, array.get_buffer_memory_size());
+
+        let array = StringArray::from(vec!["hello", "arrow", "world"]);
+        let size = estimate_array_size(&array);
+        assert_eq!(size.logical, 4 * 4 + 15);
+        let sliced = array.slice(1, 1);
+        let sliced_size = estimate_array_size(sliced.as_ref());
+        assert_eq!(sliced_size.logical, 2 * 4 + 5);
+        assert_eq!(sliced_size.allocated, size.allocated);
+
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3)]),
+        ]);
+        let size = estimate_array_size(list.slice(2, 1).as_ref());
+        // null bitmap, two offsets and one child value
+        assert_eq!(size.logical, 1 + 2 * 4 + 4);
+
+        let boolean = BooleanArray::from(vec![true; 20]);
+        assert_eq!(estimate_array_size(&boolean).logical, 3);
+    }
+
+    #[test]
+    fn test_estimate_size_shared_buffers() {
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let keys = Int8Array::from(vec![0, 1, 2, 1]);
+        let a = DictionaryArray::<Int8Type>::try_new(&keys, &values).unwrap();
+        let keys = Int8Array::from(vec![2, 2, 0, 0]);
+        let b = DictionaryArray::<Int8Type>::try_new(&keys, &values).unwrap();
+
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
+
+        let size = estimate_size(&batch);
+        let values_size = estimate_array_size(values.as_ref());
+        assert_eq!(size.logical, 4 + 4 + 2 * values_size.logical);
+        assert_eq!(size.allocated, batch.get_batch_memory_size());
+        let unshared: usize = batch
+            .columns()
+            .iter()
+            .map(|c| c.get_buffer_memory_size())
+            .sum();
+        assert_eq!(size.allocated, unshared - values_size.allocated);
+    }
+}
@@ -15,16 +15,16 @@
 // specific language governing permissions and limitations
 // under the License.
 
-pub use arrow_buffer::{bit_chunk_iterator, bit_util};
+pub use arrow_buffer::{bit_chunk_iterator, bit_mask, bit_util};
 
 pub use arrow_data::bit_iterator;
-pub use arrow_data::bit_mask;
 
 #[cfg(feature = "test_utils")]
 pub mod bench_util;
 #[cfg(feature = "test_utils")]
 pub mod data_gen;
 pub mod display;
+pub mod memory;
 #[cfg(feature = "prettyprint")]
 pub mod pretty;
 pub(crate) mod serialization;
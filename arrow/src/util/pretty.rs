@@ -20,15 +20,25 @@
 
 use crate::{array::ArrayRef, record_batch::RecordBatch};
 use comfy_table::{Cell, Table};
-use std::fmt::Display;
+use std::fmt::{Display, Formatter};
+
+use crate::datatypes::DataType;
 
 use crate::error::Result;
 
-use super::display::array_value_to_string;
+use super::display::{ArrayFormatter, FormatOptions};
 
 ///! Create a visual representation of record batches
 pub fn pretty_format_batches(results: &[RecordBatch]) -> Result<impl Display> {
-    create_table(results)
+    pretty_format_batches_with_options(results, &FormatOptions::default())
+}
+
+/// Create a visual representation of record batches, formatted according to `options`
+pub fn pretty_format_batches_with_options(
+    results: &[RecordBatch],
+    options: &FormatOptions,
+) -> Result<impl Display> {
+    Ok(create_table(batches_to_rows(results, options)?))
 }
 
 ///! Create a visual representation of columns
@@ -36,71 +46,319 @@ pub fn pretty_format_columns(
     col_name: &str,
     results: &[ArrayRef],
 ) -> Result<impl Display> {
-    create_column(col_name, results)
+    pretty_format_columns_with_options(col_name, results, &FormatOptions::default())
+}
+
+/// Create a visual representation of columns, formatted according to `options`
+pub fn pretty_format_columns_with_options(
+    col_name: &str,
+    results: &[ArrayRef],
+    options: &FormatOptions,
+) -> Result<impl Display> {
+    Ok(create_table(columns_to_rows(col_name, results, options)?))
+}
+
+/// Create a [GitHub Flavored Markdown] table of record batches, formatted according
+/// to `options`. The number of omitted rows and the summary, if any, are separated
+/// from the table by a blank line
+///
+/// [GitHub Flavored Markdown]: https://github.github.com/gfm/#tables-extension-
+pub fn markdown_format_batches(
+    results: &[RecordBatch],
+    options: &FormatOptions,
+) -> Result<impl Display> {
+    Ok(MarkdownTable(batches_to_rows(results, options)?))
+}
+
+/// Create a minimal HTML `<table>` of record batches, formatted according to `options`
+pub fn html_format_batches(
+    results: &[RecordBatch],
+    options: &FormatOptions,
+) -> Result<impl Display> {
+    Ok(HtmlTable(batches_to_rows(results, options)?))
 }
 
 ///! Prints a visual representation of record batches to stdout
 pub fn print_batches(results: &[RecordBatch]) -> Result<()> {
-    println!("{}", create_table(results)?);
+    println!("{}", pretty_format_batches(results)?);
     Ok(())
 }
 
 ///! Prints a visual representation of a list of column to stdout
 pub fn print_columns(col_name: &str, results: &[ArrayRef]) -> Result<()> {
-    println!("{}", create_column(col_name, results)?);
+    println!("{}", pretty_format_columns(col_name, results)?);
     Ok(())
 }
 
-///! Convert a series of record batches into a table
-fn create_table(results: &[RecordBatch]) -> Result<Table> {
-    let mut table = Table::new();
-    table.load_preset("||--+-++|    ++++++");
+/// The formatted cells of a table, independent of how the table is rendered
+struct Rows {
+    /// `None` if there is nothing to display
+    header: Option<Vec<String>>,
+    rows: Vec<Vec<String>>,
+    omitted_rows: usize,
+    /// Displayed below the table, see [`FormatOptions::with_summary`]
+    summary: Option<String>,
+}
 
-    if results.is_empty() {
-        return Ok(table);
+impl Rows {
+    fn empty() -> Self {
+        Self {
+            header: None,
+            rows: vec![],
+            omitted_rows: 0,
+            summary: None,
+        }
     }
 
-    let schema = results[0].schema();
+    /// Creates the rows of a table with the given columns, containing `total_rows` rows
+    fn new<'a>(
+        columns: impl IntoIterator<Item = (&'a str, &'a DataType)>,
+        total_rows: usize,
+        options: &FormatOptions,
+    ) -> Self {
+        let mut header = vec![];
+        let mut types = vec![];
+        if options.row_numbers() {
+            header.push("#".to_string());
+        }
+        for (name, data_type) in columns {
+            header.push(name.to_string());
+            types.push(format!("{}: {:?}", name, data_type));
+        }
 
-    let mut header = Vec::new();
-    for field in schema.fields() {
-        header.push(Cell::new(&field.name()));
+        let summary = options.summary().then(|| {
+            format!(
+                "{} {}, {} {} ({})",
+                total_rows,
+                if total_rows == 1 { "row" } else { "rows" },
+                types.len(),
+                if types.len() == 1 {
+                    "column"
+                } else {
+                    "columns"
+                },
+                types.join(", ")
+            )
+        });
+
+        Self {
+            header: Some(header),
+            rows: vec![],
+            omitted_rows: total_rows,
+            summary,
+        }
     }
-    table.set_header(header);
 
-    for batch in results {
-        for row in 0..batch.num_rows() {
-            let mut cells = Vec::new();
-            for col in 0..batch.num_columns() {
-                let column = batch.column(col);
-                cells.push(Cell::new(&array_value_to_string(column, row)?));
+    /// Appends a row unless `options.max_rows()` has been reached
+    fn push(
+        &mut self,
+        options: &FormatOptions,
+        cells: impl FnOnce() -> Result<Vec<String>>,
+    ) -> Result<()> {
+        if self.rows.len() < options.max_rows().unwrap_or(usize::MAX) {
+            let mut row = vec![];
+            if options.row_numbers() {
+                row.push(self.rows.len().to_string());
             }
-            table.add_row(cells);
+            row.extend(cells()?);
+            self.rows.push(row);
+            self.omitted_rows -= 1;
         }
+        Ok(())
+    }
+
+    /// Returns true if [`Self::write_footer`] writes anything
+    fn has_footer(&self) -> bool {
+        self.omitted_rows != 0 || self.summary.is_some()
     }
 
-    Ok(table)
+    /// Writes the number of omitted rows and the summary, if any
+    fn write_footer(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.omitted_rows {
+            0 => {}
+            1 => write!(f, "\n… 1 more row")?,
+            n => write!(f, "\n… {} more rows", n)?,
+        }
+        match &self.summary {
+            Some(summary) => write!(f, "\n{}", summary),
+            None => Ok(()),
+        }
+    }
 }
 
-fn create_column(field: &str, columns: &[ArrayRef]) -> Result<Table> {
-    let mut table = Table::new();
-    table.load_preset("||--+-++|    ++++++");
+/// Formats the value at `row` of a column, truncating it to `options.max_width()`
+fn create_cell(formatter: &ArrayFormatter, row: usize) -> Result<String> {
+    let mut value = formatter.value(row).try_to_string()?;
+    match formatter.options().max_width() {
+        // there is no room for the truncation marker
+        Some(0) => value.clear(),
+        Some(max_width) if value.chars().nth(max_width).is_some() => {
+            // keep `max_width` characters, including the truncation marker
+            let (end, _) = value.char_indices().nth(max_width - 1).unwrap();
+            value.truncate(end);
+            value.push('…');
+        }
+        _ => {}
+    }
+    Ok(value)
+}
 
+fn batches_to_rows(results: &[RecordBatch], options: &FormatOptions) -> Result<Rows> {
+    if results.is_empty() {
+        return Ok(Rows::empty());
+    }
+
+    let schema = results[0].schema();
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|f| (f.name().as_str(), f.data_type()));
+    let total_rows = results.iter().map(|batch| batch.num_rows()).sum();
+
+    let mut rows = Rows::new(columns, total_rows, options);
+    for batch in results {
+        let formatters = batch
+            .columns()
+            .iter()
+            .map(|column| ArrayFormatter::try_new(column.as_ref(), options))
+            .collect::<Result<Vec<_>>>()?;
+        for row in 0..batch.num_rows() {
+            rows.push(options, || {
+                formatters
+                    .iter()
+                    .map(|formatter| create_cell(formatter, row))
+                    .collect()
+            })?;
+        }
+    }
+    Ok(rows)
+}
+
+fn columns_to_rows(
+    field: &str,
+    columns: &[ArrayRef],
+    options: &FormatOptions,
+) -> Result<Rows> {
     if columns.is_empty() {
-        return Ok(table);
+        return Ok(Rows::empty());
     }
 
-    let header = vec![Cell::new(field)];
-    table.set_header(header);
+    let total_rows = columns.iter().map(|col| col.len()).sum();
 
+    let mut rows = Rows::new([(field, columns[0].data_type())], total_rows, options);
     for col in columns {
+        let formatter = ArrayFormatter::try_new(col.as_ref(), options)?;
         for row in 0..col.len() {
-            let cells = vec![Cell::new(&array_value_to_string(col, row)?)];
-            table.add_row(cells);
+            rows.push(options, || Ok(vec![create_cell(&formatter, row)?]))?;
+        }
+    }
+    Ok(rows)
+}
+
+/// A table, followed by the number of rows omitted from it
+struct TruncatedTable {
+    table: Table,
+    rows: Rows,
+}
+
+impl Display for TruncatedTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.table)?;
+        self.rows.write_footer(f)
+    }
+}
+
+/// Convert formatted rows into a table
+fn create_table(mut rows: Rows) -> TruncatedTable {
+    let mut table = Table::new();
+    table.load_preset("||--+-++|    ++++++");
+
+    if let Some(header) = &rows.header {
+        table.set_header(header.iter().map(Cell::new));
+        for row in std::mem::take(&mut rows.rows) {
+            table.add_row(row.iter().map(Cell::new));
+        }
+    }
+
+    TruncatedTable { table, rows }
+}
+
+struct MarkdownTable(Rows);
+
+impl MarkdownTable {
+    fn write_row(f: &mut Formatter<'_>, cells: &[String]) -> std::fmt::Result {
+        write!(f, "|")?;
+        for cell in cells {
+            let cell = cell.replace('|', "\\|").replace('\n', "<br>");
+            write!(f, " {} |", cell)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for MarkdownTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let header = match &self.0.header {
+            Some(header) => header,
+            None => return Ok(()),
+        };
+        Self::write_row(f, header)?;
+        write!(f, "\n|")?;
+        for _ in header {
+            write!(f, "---|")?;
         }
+        for row in &self.0.rows {
+            writeln!(f)?;
+            Self::write_row(f, row)?;
+        }
+        if self.0.has_footer() {
+            // without a blank line, the footer would be parsed as a row of the table
+            writeln!(f)?;
+        }
+        self.0.write_footer(f)
     }
+}
 
-    Ok(table)
+struct HtmlTable(Rows);
+
+impl HtmlTable {
+    fn write_row(f: &mut Formatter<'_>, tag: &str, cells: &[String]) -> std::fmt::Result {
+        write!(f, "<tr>")?;
+        for cell in cells {
+            write!(f, "<{}>", tag)?;
+            for c in cell.chars() {
+                match c {
+                    '&' => write!(f, "&amp;")?,
+                    '<' => write!(f, "&lt;")?,
+                    '>' => write!(f, "&gt;")?,
+                    '"' => write!(f, "&quot;")?,
+                    c => write!(f, "{}", c)?,
+                }
+            }
+            write!(f, "</{}>", tag)?;
+        }
+        writeln!(f, "</tr>")
+    }
+}
+
+impl Display for HtmlTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let header = match &self.0.header {
+            Some(header) => header,
+            None => return Ok(()),
+        };
+        writeln!(f, "<table>")?;
+        writeln!(f, "<thead>")?;
+        Self::write_row(f, "th", header)?;
+        writeln!(f, "</thead>")?;
+        writeln!(f, "<tbody>")?;
+        for row in &self.0.rows {
+            Self::write_row(f, "td", row)?;
+        }
+        writeln!(f, "</tbody>")?;
+        write!(f, "</table>")?;
+        self.0.write_footer(f)
+    }
 }
 
 #[cfg(test)]
@@ -108,18 +366,25 @@ mod tests {
     use crate::{
         array::{
             self, new_null_array, Array, Date32Array, Date64Array,
-            FixedSizeBinaryBuilder, Float16Array, Int32Array, StringArray,
-            StringDictionaryBuilder, StructArray, Time32MillisecondArray,
-            Time32SecondArray, Time64MicrosecondArray, Time64NanosecondArray,
-            TimestampMicrosecondArray, TimestampMillisecondArray,
+            DurationNanosecondArray, DurationSecondArray, FixedSizeBinaryBuilder,
+            Float16Array, Int32Array, IntervalDayTimeArray, IntervalMonthDayNanoArray,
+            IntervalYearMonthArray, StringArray, StringDictionaryBuilder, StructArray,
+            Time32MillisecondArray, Time32SecondArray, Time64MicrosecondArray,
+            Time64NanosecondArray, TimestampMicrosecondArray, TimestampMillisecondArray,
             TimestampNanosecondArray, TimestampSecondArray, UnionArray, UnionBuilder,
         },
         buffer::Buffer,
-        datatypes::{DataType, Field, Float64Type, Int32Type, Schema, UnionMode},
+        datatypes::{
+            DataType, Field, Float64Type, Int32Type, IntervalDayTimeType,
+            IntervalMonthDayNanoType, Schema, TimeUnit, UnionMode,
+        },
     };
 
     use super::*;
-    use crate::array::{Decimal128Array, FixedSizeListBuilder};
+    use crate::array::{
+        Decimal128Array, FixedSizeListBuilder, Int32Builder, ListBuilder, MapArray,
+    };
+    use crate::util::display::{array_value_to_string, BinaryFormat};
     use std::fmt::Write;
     use std::sync::Arc;
 
@@ -324,7 +589,7 @@ mod tests {
         let array = Arc::new(builder.finish());
 
         let batch = RecordBatch::try_new(schema, vec![array])?;
-        let table = pretty_format_batches(&[batch])?.to_string();
+        let table = pretty_format_batches(std::slice::from_ref(&batch))?.to_string();
         let expected = vec![
             "+--------+",
             "| d1     |",
@@ -339,6 +604,17 @@ mod tests {
 
         assert_eq!(expected, actual, "Actual result:\n{}", table);
 
+        let options = FormatOptions::new().with_binary_format(BinaryFormat::Base64);
+        let table = pretty_format_batches_with_options(&[batch], &options)?.to_string();
+        let expected = vec![
+            "+------+", "| d1   |", "+------+", "| AQID |", "|      |", "| BwgJ |",
+            "+------+",
+        ];
+
+        let actual: Vec<&str> = table.lines().collect();
+
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
         Ok(())
     }
 
@@ -630,13 +906,13 @@ mod tests {
 
         let table = pretty_format_batches(&[batch])?.to_string();
         let expected = vec![
-            r#"+-------------------------------------+----+"#,
-            r#"| c1                                  | c2 |"#,
-            r#"+-------------------------------------+----+"#,
-            r#"| {"c11": 1, "c12": {"c121": "e"}}    | a  |"#,
-            r#"| {"c11": null, "c12": {"c121": "f"}} | b  |"#,
-            r#"| {"c11": 5, "c12": {"c121": "g"}}    | c  |"#,
-            r#"+-------------------------------------+----+"#,
+            "+-----------------------------+----+",
+            "| c1                          | c2 |",
+            "+-----------------------------+----+",
+            "| {c11: 1, c12: {c121: e}}    | a  |",
+            "| {c11: null, c12: {c121: f}} | b  |",
+            "| {c11: 5, c12: {c121: g}}    | c  |",
+            "+-----------------------------+----+",
         ];
 
         let actual: Vec<&str> = table.lines().collect();
@@ -866,4 +1142,421 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_pretty_format_with_options() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Date32, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from(vec![
+                    Some("short"),
+                    None,
+                    Some("a rather long välue"),
+                ])),
+                Arc::new(Date32Array::from(vec![Some(0), Some(19000), None])),
+            ],
+        )?;
+
+        let options = FormatOptions::new()
+            .with_null("NULL")
+            .with_max_width(Some(10))
+            .with_date_format(Some("%d/%m/%Y".to_string()));
+        let table =
+            pretty_format_batches_with_options(std::slice::from_ref(&batch), &options)?
+                .to_string();
+
+        let expected = vec![
+            "+------------+------------+",
+            "| a          | b          |",
+            "+------------+------------+",
+            "| short      | 01/01/1970 |",
+            "| NULL       | 08/01/2022 |",
+            "| a rather … | NULL       |",
+            "+------------+------------+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        // the row limit applies across batches
+        let options = FormatOptions::new().with_max_rows(Some(4));
+        let table =
+            pretty_format_batches_with_options(&[batch.clone(), batch], &options)?
+                .to_string();
+
+        let expected = vec![
+            "+---------------------+------------+",
+            "| a                   | b          |",
+            "+---------------------+------------+",
+            "| short               | 1970-01-01 |",
+            "|                     | 2022-01-08 |",
+            "| a rather long välue |            |",
+            "| short               | 1970-01-01 |",
+            "+---------------------+------------+",
+            "… 2 more rows",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pretty_format_max_width() -> Result<()> {
+        let columns = vec![Arc::new(StringArray::from(vec!["a", "abc"])) as ArrayRef];
+
+        let options = FormatOptions::new().with_max_width(Some(1));
+        let table =
+            pretty_format_columns_with_options("a", &columns, &options)?.to_string();
+        let expected = vec!["+---+", "| a |", "+---+", "| a |", "| … |", "+---+"];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        // a width of 0 leaves every cell empty, rather than only showing `…`
+        let options = FormatOptions::new().with_max_width(Some(0));
+        let table =
+            pretty_format_columns_with_options("a", &columns, &options)?.to_string();
+        let expected = vec!["+---+", "| a |", "+---+", "|   |", "|   |", "+---+"];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pretty_format_columns_with_options() -> Result<()> {
+        let columns = vec![
+            Arc::new(StringArray::from(vec![Some("a"), None])) as ArrayRef,
+            Arc::new(StringArray::from(vec![Some("b")])),
+        ];
+        let options = FormatOptions::new().with_null("-").with_max_rows(Some(2));
+        let table =
+            pretty_format_columns_with_options("a", &columns, &options)?.to_string();
+
+        let expected = vec![
+            "+---+",
+            "| a |",
+            "+---+",
+            "| a |",
+            "| - |",
+            "+---+",
+            "… 1 more row",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pretty_format_time_with_options() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("ts", DataType::Timestamp(TimeUnit::Second, None), true),
+            Field::new("t", DataType::Time32(TimeUnit::Second), true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(TimestampSecondArray::from(vec![11111111])),
+                Arc::new(Time32SecondArray::from(vec![3661])),
+            ],
+        )?;
+
+        let options = FormatOptions::new()
+            .with_datetime_format(Some("%Y-%m-%dT%H:%M".to_string()))
+            .with_time_format(Some("%Hh%M".to_string()));
+        let table = pretty_format_batches_with_options(&[batch], &options)?.to_string();
+
+        let expected = vec![
+            "+------------------+-------+",
+            "| ts               | t     |",
+            "+------------------+-------+",
+            "| 1970-05-09T14:25 | 01h01 |",
+            "+------------------+-------+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pretty_format_duration() -> Result<()> {
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(DurationSecondArray::from(vec![
+                Some(0),
+                Some(86_400),
+                Some(-3_661),
+                None,
+            ])),
+            Arc::new(DurationNanosecondArray::from(vec![
+                Some(1),
+                Some(183_845_123_000_000),
+                Some(-90_061_500_000_000),
+                Some(i64::MAX),
+            ])),
+        ];
+        let table = pretty_format_columns("duration", &columns)?.to_string();
+
+        let expected = vec![
+            "+--------------------------------+",
+            "| duration                       |",
+            "+--------------------------------+",
+            "| 00:00:00                       |",
+            "| 1 day                          |",
+            "| -01:01:01                      |",
+            "|                                |",
+            "| 00:00:00.000000001             |",
+            "| 2 days 03:04:05.123            |",
+            "| -1 days -01:01:01.5            |",
+            "| 106751 days 23:47:16.854775807 |",
+            "+--------------------------------+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pretty_format_interval() -> Result<()> {
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(IntervalYearMonthArray::from(vec![
+                Some(14),
+                Some(-25),
+                Some(0),
+            ])),
+            Arc::new(IntervalDayTimeArray::from(vec![Some(
+                IntervalDayTimeType::make_value(3, 14_706_789),
+            )])),
+            Arc::new(IntervalMonthDayNanoArray::from(vec![
+                Some(IntervalMonthDayNanoType::make_value(12, 1, 1_000)),
+                Some(IntervalMonthDayNanoType::make_value(
+                    -1,
+                    -1,
+                    -3_600_000_000_000,
+                )),
+            ])),
+        ];
+        let table = pretty_format_columns("interval", &columns)?.to_string();
+
+        let expected = vec![
+            "+------------------------------+",
+            "| interval                     |",
+            "+------------------------------+",
+            "| 1 year 2 mons                |",
+            "| -2 years -1 mons             |",
+            "| 00:00:00                     |",
+            "| 3 days 04:05:06.789          |",
+            "| 1 year 1 day 00:00:00.000001 |",
+            "| -1 mons -1 days -01:00:00    |",
+            "+------------------------------+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pretty_format_nested() -> Result<()> {
+        let mut builder = ListBuilder::new(Int32Builder::new());
+        builder.values().append_value(1);
+        builder.values().append_null();
+        builder.append(true);
+        builder.append(false);
+        builder.values().append_value(2);
+        builder.append(true);
+        let list = Arc::new(builder.finish()) as ArrayRef;
+
+        let names = Arc::new(StringArray::from(vec![Some("a"), None, Some("c")]));
+        let structs = Arc::new(StructArray::from(vec![
+            (Field::new("name", DataType::Utf8, true), names as ArrayRef),
+            (Field::new("values", list.data_type().clone(), true), list),
+        ])) as ArrayRef;
+
+        let map = Arc::new(
+            MapArray::new_from_strings(
+                ["x", "y", "z"].into_iter(),
+                &Int32Array::from(vec![Some(1), None, Some(3)]),
+                &[0, 2, 2, 3],
+            )
+            .unwrap(),
+        ) as ArrayRef;
+
+        let schema = Schema::new(vec![
+            Field::new("s", structs.data_type().clone(), false),
+            Field::new("m", map.data_type().clone(), false),
+        ]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![structs, map])?;
+
+        let table = pretty_format_batches(std::slice::from_ref(&batch))?.to_string();
+        let expected = vec![
+            "+------------------------------+-----------------+",
+            "| s                            | m               |",
+            "+------------------------------+-----------------+",
+            "| {name: a, values: [1, null]} | {x: 1, y: null} |",
+            "| {name: null, values: null}   | {}              |",
+            "| {name: c, values: [2]}       | {z: 3}          |",
+            "+------------------------------+-----------------+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        let options = FormatOptions::new().with_max_depth(Some(1));
+        let table = pretty_format_batches_with_options(&[batch], &options)?.to_string();
+        let expected = vec![
+            "+----------------------------+-----------------+",
+            "| s                          | m               |",
+            "+----------------------------+-----------------+",
+            "| {name: a, values: […]}     | {x: 1, y: null} |",
+            "| {name: null, values: null} | {}              |",
+            "| {name: c, values: […]}     | {z: 3}          |",
+            "+----------------------------+-----------------+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        Ok(())
+    }
+
+    fn markup_batch() -> Result<RecordBatch> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a|b", DataType::Utf8, true),
+            Field::new("<c>", DataType::Int32, true),
+        ]));
+        let a = StringArray::from(vec![Some("x|y"), None, Some("a & \"b\"")]);
+        let c = Int32Array::from(vec![Some(1), Some(2), None]);
+        RecordBatch::try_new(schema, vec![Arc::new(a), Arc::new(c)])
+    }
+
+    #[test]
+    fn test_markdown_format_batches() -> Result<()> {
+        let batch = markup_batch()?;
+
+        let options = FormatOptions::new();
+        let table =
+            markdown_format_batches(std::slice::from_ref(&batch), &options)?.to_string();
+        let expected = vec![
+            "| a\\|b | <c> |",
+            "|---|---|",
+            "| x\\|y | 1 |",
+            "|  | 2 |",
+            "| a & \"b\" |  |",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        let options = FormatOptions::new().with_max_rows(Some(1));
+        let table =
+            markdown_format_batches(std::slice::from_ref(&batch), &options)?.to_string();
+        let expected = vec![
+            "| a\\|b | <c> |",
+            "|---|---|",
+            "| x\\|y | 1 |",
+            "",
+            "… 2 more rows",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        let options = FormatOptions::new().with_summary(true);
+        let table = markdown_format_batches(&[batch], &options)?.to_string();
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(actual.len(), 7, "Actual result:\n{}", table);
+        assert_eq!(actual[5], "");
+        assert_eq!(actual[6], "3 rows, 2 columns (a|b: Utf8, <c>: Int32)");
+
+        let table = markdown_format_batches(&[], &options)?.to_string();
+        assert_eq!(table, "");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_html_format_batches() -> Result<()> {
+        let batch = markup_batch()?;
+
+        let options = FormatOptions::new().with_null("null");
+        let table =
+            html_format_batches(std::slice::from_ref(&batch), &options)?.to_string();
+        let expected = vec![
+            "<table>",
+            "<thead>",
+            "<tr><th>a|b</th><th>&lt;c&gt;</th></tr>",
+            "</thead>",
+            "<tbody>",
+            "<tr><td>x|y</td><td>1</td></tr>",
+            "<tr><td>null</td><td>2</td></tr>",
+            "<tr><td>a &amp; &quot;b&quot;</td><td>null</td></tr>",
+            "</tbody>",
+            "</table>",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        let options = FormatOptions::new().with_max_rows(Some(2));
+        let table = html_format_batches(&[batch], &options)?.to_string();
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(actual.len(), 10, "Actual result:\n{}", table);
+        assert_eq!(actual[8], "</table>");
+        assert_eq!(actual[9], "… 1 more row");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pretty_format_row_numbers_and_summary() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Int32, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from(vec![Some("x"), None, Some("z")])),
+                Arc::new(Int32Array::from(vec![Some(1), Some(2), None])),
+            ],
+        )?;
+
+        let options = FormatOptions::new()
+            .with_row_numbers(true)
+            .with_summary(true)
+            .with_max_rows(Some(2));
+        let table =
+            pretty_format_batches_with_options(&[batch.clone(), batch], &options)?
+                .to_string();
+        let expected = vec![
+            "+---+---+---+",
+            "| # | a | b |",
+            "+---+---+---+",
+            "| 0 | x | 1 |",
+            "| 1 |   | 2 |",
+            "+---+---+---+",
+            "… 4 more rows",
+            "6 rows, 2 columns (a: Utf8, b: Int32)",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        let column = Arc::new(Int32Array::from(vec![7])) as ArrayRef;
+        let options = FormatOptions::new().with_summary(true);
+        let table =
+            pretty_format_columns_with_options("c", &[column], &options)?.to_string();
+        let expected = vec![
+            "+---+",
+            "| c |",
+            "+---+",
+            "| 7 |",
+            "+---+",
+            "1 row, 1 column (c: Int32)",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        Ok(())
+    }
 }
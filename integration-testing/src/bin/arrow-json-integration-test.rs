@@ -86,24 +86,9 @@ fn arrow_to_json(arrow_name: &str, json_name: &str, verbose: bool) -> Result<()>
     let arrow_file = File::open(arrow_name)?;
     let reader = FileReader::try_new(arrow_file, None)?;
 
-    let mut fields: Vec<ArrowJsonField> = vec![];
-    for f in reader.schema().fields() {
-        fields.push(ArrowJsonField::from(f));
-    }
-    let schema = ArrowJsonSchema {
-        fields,
-        metadata: None,
-    };
-
-    let batches = reader
-        .map(|batch| Ok(ArrowJsonBatch::from_batch(&batch?)))
-        .collect::<Result<Vec<_>>>()?;
-
-    let arrow_json = ArrowJson {
-        schema,
-        batches,
-        dictionaries: None,
-    };
+    let schema = reader.schema();
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    let arrow_json = ArrowJson::try_from_batches(&schema, &batches)?;
 
     let json_file = File::create(json_name)?;
     serde_json::to_writer(&json_file, &arrow_json).unwrap();
@@ -292,7 +292,14 @@ pub fn data_type_to_json(data_type: &DataType) -> serde_json::Value {
             json!({"name": "fixedsizebinary", "byteWidth": byte_width})
         }
         DataType::Struct(_) => json!({"name": "struct"}),
-        DataType::Union(_, _, _) => json!({"name": "union"}),
+        DataType::Union(_, type_ids, mode) => json!({
+            "name": "union",
+            "mode": match mode {
+                UnionMode::Sparse => "SPARSE",
+                UnionMode::Dense => "DENSE",
+            },
+            "typeIds": type_ids,
+        }),
         DataType::List(_) => json!({ "name": "list"}),
         DataType::LargeList(_) => json!({ "name": "largelist"}),
         DataType::FixedSizeList(_, length) => {
@@ -265,8 +265,15 @@ pub fn field_from_json(json: &serde_json::Value) -> Result<Field> {
 
 /// Generate a JSON representation of the `Field`.
 pub fn field_to_json(field: &Field) -> serde_json::Value {
-    let children: Vec<serde_json::Value> = match field.data_type() {
-        DataType::Struct(fields) => fields.iter().map(field_to_json).collect(),
+    // the children of a dictionary field are those of its value type
+    let value_type = match field.data_type() {
+        DataType::Dictionary(_, value_type) => value_type.as_ref(),
+        data_type => data_type,
+    };
+    let children: Vec<serde_json::Value> = match value_type {
+        DataType::Struct(fields) | DataType::Union(fields, _, _) => {
+            fields.iter().map(field_to_json).collect()
+        }
         DataType::List(field)
         | DataType::LargeList(field)
         | DataType::FixedSizeList(field, _)
@@ -274,7 +281,7 @@ pub fn field_to_json(field: &Field) -> serde_json::Value {
         _ => vec![],
     };
 
-    match field.data_type() {
+    let mut json = match field.data_type() {
         DataType::Dictionary(ref index_type, ref value_type) => serde_json::json!({
             "name": field.name(),
             "nullable": field.is_nullable(),
@@ -292,7 +299,15 @@ pub fn field_to_json(field: &Field) -> serde_json::Value {
             "type": data_type_to_json(field.data_type()),
             "children": children
         }),
+    };
+
+    if let Some(metadata) = field.metadata().filter(|m| !m.is_empty()) {
+        json["metadata"] = metadata
+            .iter()
+            .map(|(k, v)| serde_json::json!({"key": k, "value": v}))
+            .collect();
     }
+    json
 }
 
 #[cfg(test)]
@@ -18,12 +18,19 @@
 //! Utils for JSON integration testing
 //!
 //! These utilities define structs that read the integration JSON format for integration testing purposes.
+//!
+//! [`ArrowJson`] can be converted to and from [`RecordBatch`]es, which allows
+//! other implementations to use the format for golden-file testing:
+//!
+//! * [`ArrowJson::try_from_batches`] converts a schema and record batches to JSON
+//! * [`ArrowJson::get_record_batches`] converts JSON back to record batches
 
 use hex::decode;
 use num::BigInt;
 use num::Signed;
 use serde::{Deserialize, Serialize};
-use serde_json::{Map as SJMap, Value};
+use serde_json::Value;
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -40,8 +47,8 @@ mod datatype;
 mod field;
 mod schema;
 
-use crate::util::datatype::data_type_to_json;
-use crate::util::field::field_from_json;
+pub use datatype::*;
+pub use field::*;
 pub use schema::*;
 
 /// A struct that represents an Arrow file with a schema and record batches
@@ -81,31 +88,8 @@ pub struct ArrowJsonField {
 
 impl From<&Field> for ArrowJsonField {
     fn from(field: &Field) -> Self {
-        let metadata_value = match field.metadata() {
-            Some(kv_list) => {
-                let mut array = Vec::new();
-                for (k, v) in kv_list {
-                    let mut kv_map = SJMap::new();
-                    kv_map.insert(k.clone(), Value::String(v.clone()));
-                    array.push(Value::Object(kv_map));
-                }
-                if !array.is_empty() {
-                    Some(Value::Array(array))
-                } else {
-                    None
-                }
-            }
-            _ => None,
-        };
-
-        Self {
-            name: field.name().to_string(),
-            field_type: data_type_to_json(field.data_type()),
-            nullable: field.is_nullable(),
-            children: vec![],
-            dictionary: None, // TODO: not enough info
-            metadata: metadata_value,
-        }
+        serde_json::from_value(field_to_json(field))
+            .expect("field_to_json produces a valid ArrowJsonField")
     }
 }
 
@@ -128,14 +112,14 @@ pub struct DictionaryIndexType {
 }
 
 /// A struct that partially reads the Arrow JSON record batch
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct ArrowJsonBatch {
     count: usize,
     pub columns: Vec<ArrowJsonColumn>,
 }
 
 /// A struct that partially reads the Arrow JSON dictionary batch
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[allow(non_snake_case)]
 pub struct ArrowJsonDictionaryBatch {
     pub id: i64,
@@ -143,7 +127,7 @@ pub struct ArrowJsonDictionaryBatch {
 }
 
 /// A struct that partially reads the Arrow JSON column/array
-#[derive(Deserialize, Serialize, Clone, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 pub struct ArrowJsonColumn {
     name: String,
     pub count: usize,
@@ -159,6 +143,31 @@ pub struct ArrowJsonColumn {
 }
 
 impl ArrowJson {
+    /// Convert a schema and its record batches to Arrow JSON
+    ///
+    /// The JSON format can only hold a single dictionary per dictionary id,
+    /// so an error is returned if the batches contain different dictionaries
+    /// for the same field.
+    pub fn try_from_batches(schema: &Schema, batches: &[RecordBatch]) -> Result<Self> {
+        let mut dictionaries = HashMap::new();
+        let batches = batches
+            .iter()
+            .map(|batch| ArrowJsonBatch::try_from_batch(batch, &mut dictionaries))
+            .collect::<Result<Vec<_>>>()?;
+
+        let dictionaries = (!dictionaries.is_empty()).then(|| {
+            let mut dictionaries: Vec<_> = dictionaries.into_values().collect();
+            dictionaries.sort_unstable_by_key(|d| d.id);
+            dictionaries
+        });
+
+        Ok(Self {
+            schema: schema.into(),
+            batches,
+            dictionaries,
+        })
+    }
+
     /// Compare the Arrow JSON with a record batch reader
     pub fn equals_reader(&self, reader: &mut dyn RecordBatchReader) -> Result<bool> {
         if !self.schema.equals_schema(&reader.schema()) {
@@ -182,6 +191,7 @@ impl ArrowJson {
         Ok(true)
     }
 
+    /// Convert the Arrow JSON to record batches
     pub fn get_record_batches(&self) -> Result<Vec<RecordBatch>> {
         let schema = self.schema.to_arrow_schema()?;
 
@@ -202,6 +212,30 @@ impl ArrowJson {
     }
 }
 
+impl From<&Schema> for ArrowJsonSchema {
+    fn from(schema: &Schema) -> Self {
+        let metadata = (!schema.metadata().is_empty()).then(|| {
+            let mut metadata: Vec<_> = schema
+                .metadata()
+                .iter()
+                .map(|(k, v)| {
+                    HashMap::from([
+                        ("key".to_string(), k.clone()),
+                        ("value".to_string(), v.clone()),
+                    ])
+                })
+                .collect();
+            metadata.sort_unstable_by(|a, b| a["key"].cmp(&b["key"]));
+            metadata
+        });
+
+        Self {
+            fields: schema.fields().iter().map(ArrowJsonField::from).collect(),
+            metadata,
+        }
+    }
+}
+
 impl ArrowJsonSchema {
     /// Compare the Arrow JSON schema with the Arrow `Schema`
     fn equals_schema(&self, schema: &Schema) -> bool {
@@ -219,7 +253,8 @@ impl ArrowJsonSchema {
         true
     }
 
-    fn to_arrow_schema(&self) -> Result<Schema> {
+    /// Convert to an Arrow `Schema`
+    pub fn to_arrow_schema(&self) -> Result<Schema> {
         let arrow_fields: Result<Vec<_>> = self
             .fields
             .iter()
@@ -828,10 +863,10 @@ pub fn array_from_json(
                         let str = value.as_str().unwrap();
                         let integer = BigInt::parse_bytes(str.as_bytes(), 10).unwrap();
                         let integer_bytes = integer.to_signed_bytes_le();
-                        let mut bytes = if integer.is_positive() {
-                            [0_u8; 32]
-                        } else {
+                        let mut bytes = if integer.is_negative() {
                             [255_u8; 32]
+                        } else {
+                            [0_u8; 32]
                         };
                         bytes[0..integer_bytes.len()]
                             .copy_from_slice(integer_bytes.as_slice());
@@ -998,56 +1033,366 @@ fn create_null_buf(json_col: &ArrowJsonColumn) -> Buffer {
 }
 
 impl ArrowJsonBatch {
-    pub fn from_batch(batch: &RecordBatch) -> ArrowJsonBatch {
-        let mut json_batch = ArrowJsonBatch {
+    /// Convert a record batch to Arrow JSON
+    ///
+    /// The dictionaries of any dictionary encoded columns are added to
+    /// `dictionaries`, keyed by their dictionary id
+    pub fn try_from_batch(
+        batch: &RecordBatch,
+        dictionaries: &mut HashMap<i64, ArrowJsonDictionaryBatch>,
+    ) -> Result<ArrowJsonBatch> {
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(batch.schema().fields())
+            .map(|(col, field)| array_to_json(field, col.as_ref(), dictionaries))
+            .collect::<Result<_>>()?;
+
+        Ok(ArrowJsonBatch {
             count: batch.num_rows(),
-            columns: Vec::with_capacity(batch.num_columns()),
-        };
+            columns,
+        })
+    }
+}
 
-        for (col, field) in batch.columns().iter().zip(batch.schema().fields.iter()) {
-            let json_col = match field.data_type() {
-                DataType::Int8 => {
-                    let col = col.as_any().downcast_ref::<Int8Array>().unwrap();
+/// Construct a partially typed JSON column from an Arrow array
+///
+/// The dictionaries of any dictionary encoded arrays are added to `dictionaries`
+pub fn array_to_json(
+    field: &Field,
+    array: &dyn Array,
+    dictionaries: &mut HashMap<i64, ArrowJsonDictionaryBatch>,
+) -> Result<ArrowJsonColumn> {
+    let count = array.len();
+    let mut json_col = ArrowJsonColumn {
+        name: field.name().clone(),
+        count,
+        validity: Some((0..count).map(|i| array.is_valid(i) as u8).collect()),
+        data: None,
+        offset: None,
+        type_id: None,
+        children: None,
+    };
 
-                    let mut validity: Vec<u8> = Vec::with_capacity(col.len());
-                    let mut data: Vec<Value> = Vec::with_capacity(col.len());
+    match field.data_type() {
+        DataType::Null => json_col.validity = None,
+        DataType::Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            let data =
+                (0..count).map(|i| Value::Bool(array.is_valid(i) && array.value(i)));
+            json_col.data = Some(data.collect());
+        }
+        DataType::Int8 => {
+            json_col.data = Some(primitive_to_json::<i8, _>(array, Value::from))
+        }
+        DataType::Int16 => {
+            json_col.data = Some(primitive_to_json::<i16, _>(array, Value::from))
+        }
+        DataType::Int32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => {
+            json_col.data = Some(primitive_to_json::<i32, _>(array, Value::from))
+        }
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => {
+            // 64-bit integers are written as strings
+            json_col.data = Some(primitive_to_json::<i64, _>(array, |v| {
+                Value::String(v.to_string())
+            }))
+        }
+        DataType::Interval(IntervalUnit::DayTime) => {
+            json_col.data = Some(primitive_to_json::<i64, _>(array, |v| {
+                serde_json::json!({
+                    "days": v as i32,
+                    "milliseconds": (v >> 32) as i32,
+                })
+            }))
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            json_col.data = Some(primitive_to_json::<i128, _>(array, |v| {
+                serde_json::json!({
+                    "months": v as i32,
+                    "days": (v >> 32) as i32,
+                    "nanoseconds": (v >> 64) as i64,
+                })
+            }))
+        }
+        DataType::UInt8 => {
+            json_col.data = Some(primitive_to_json::<u8, _>(array, Value::from))
+        }
+        DataType::UInt16 => {
+            json_col.data = Some(primitive_to_json::<u16, _>(array, Value::from))
+        }
+        DataType::UInt32 => {
+            json_col.data = Some(primitive_to_json::<u32, _>(array, Value::from))
+        }
+        DataType::UInt64 => {
+            json_col.data = Some(primitive_to_json::<u64, _>(array, |v| {
+                Value::String(v.to_string())
+            }))
+        }
+        DataType::Float32 => {
+            json_col.data = Some(primitive_to_json::<f32, _>(array, |v| {
+                Value::from(v as f64)
+            }))
+        }
+        DataType::Float64 => {
+            json_col.data = Some(primitive_to_json::<f64, _>(array, Value::from))
+        }
+        DataType::Binary => binary_to_json::<i32>(array, &mut json_col),
+        DataType::LargeBinary => binary_to_json::<i64>(array, &mut json_col),
+        DataType::Utf8 => string_to_json::<i32>(array, &mut json_col),
+        DataType::LargeUtf8 => string_to_json::<i64>(array, &mut json_col),
+        DataType::FixedSizeBinary(len) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            let null_value = vec![0_u8; *len as usize];
+            let data = (0..count).map(|i| match array.is_valid(i) {
+                true => Value::String(hex::encode_upper(array.value(i))),
+                false => Value::String(hex::encode_upper(&null_value)),
+            });
+            json_col.data = Some(data.collect());
+        }
+        DataType::List(child_field) => {
+            list_to_json::<i32>(child_field, array, &mut json_col, dictionaries)?
+        }
+        DataType::LargeList(child_field) => {
+            list_to_json::<i64>(child_field, array, &mut json_col, dictionaries)?
+        }
+        DataType::FixedSizeList(child_field, size) => {
+            let data = array.data();
+            let size = *size as usize;
+            let values = make_array(
+                data.child_data()[0].slice(data.offset() * size, count * size),
+            );
+            json_col.children = Some(vec![array_to_json(
+                child_field,
+                values.as_ref(),
+                dictionaries,
+            )?]);
+        }
+        DataType::Struct(fields) => {
+            // slicing a struct array also slices its children
+            let children = fields
+                .iter()
+                .zip(array.data().child_data())
+                .map(|(field, child)| {
+                    let child = make_array(child.clone());
+                    array_to_json(field, child.as_ref(), dictionaries)
+                })
+                .collect::<Result<_>>()?;
+            json_col.children = Some(children);
+        }
+        DataType::Map(child_field, _) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let offsets = array.value_offsets();
+            let start = offsets[0] as usize;
+            let end = offsets[count] as usize;
+            let entries =
+                make_array(array.data().child_data()[0].slice(start, end - start));
+            json_col.offset = Some(offsets_to_json(offsets));
+            json_col.children = Some(vec![array_to_json(
+                child_field,
+                entries.as_ref(),
+                dictionaries,
+            )?]);
+        }
+        DataType::Union(fields, _, mode) => {
+            // union arrays have no validity bitmap of their own
+            json_col.validity = None;
 
-                    for i in 0..col.len() {
-                        if col.is_null(i) {
-                            validity.push(1);
-                            data.push(0i8.into());
-                        } else {
-                            validity.push(0);
-                            data.push(col.value(i).into());
-                        }
-                    }
+            let data = array.data();
+            let offset = data.offset();
+            let type_ids = &data.buffers()[0].typed_data::<i8>()[offset..offset + count];
+            json_col.type_id = Some(type_ids.to_vec());
 
-                    ArrowJsonColumn {
-                        name: field.name().clone(),
-                        count: col.len(),
-                        validity: Some(validity),
-                        data: Some(data),
-                        offset: None,
-                        type_id: None,
-                        children: None,
-                    }
-                }
-                _ => ArrowJsonColumn {
-                    name: field.name().clone(),
-                    count: col.len(),
-                    validity: None,
-                    data: None,
-                    offset: None,
-                    type_id: None,
-                    children: None,
+            if *mode == UnionMode::Dense {
+                let offsets =
+                    &data.buffers()[1].typed_data::<i32>()[offset..offset + count];
+                json_col.offset = Some(offsets.iter().map(|o| Value::from(*o)).collect());
+            }
+
+            let children = fields
+                .iter()
+                .zip(data.child_data())
+                .map(|(field, child)| {
+                    let child = match mode {
+                        UnionMode::Sparse => make_array(child.slice(offset, count)),
+                        UnionMode::Dense => make_array(child.clone()),
+                    };
+                    array_to_json(field, child.as_ref(), dictionaries)
+                })
+                .collect::<Result<_>>()?;
+            json_col.children = Some(children);
+        }
+        DataType::Dictionary(key_type, value_type) => {
+            let dict_id = field.dict_id().ok_or_else(|| {
+                ArrowError::JsonError(format!(
+                    "Unable to find dict_id for field {:?}",
+                    field
+                ))
+            })?;
+
+            let data = array.data();
+            let keys = ArrayData::builder(key_type.as_ref().clone())
+                .len(count)
+                .offset(data.offset())
+                .add_buffer(data.buffers()[0].clone())
+                .null_bit_buffer(data.null_buffer().cloned())
+                .build()?;
+            let key_field = Field::new(field.name(), key_type.as_ref().clone(), true);
+            let keys =
+                array_to_json(&key_field, make_array(keys).as_ref(), dictionaries)?;
+            json_col.data = keys.data;
+
+            let values = make_array(data.child_data()[0].clone());
+            // note: not enough info on nullability of dictionary
+            let value_field = Field::new(field.name(), value_type.as_ref().clone(), true);
+            let dictionary = ArrowJsonDictionaryBatch {
+                id: dict_id,
+                data: ArrowJsonBatch {
+                    count: values.len(),
+                    columns: vec![array_to_json(
+                        &value_field,
+                        values.as_ref(),
+                        dictionaries,
+                    )?],
                 },
             };
 
-            json_batch.columns.push(json_col);
+            match dictionaries.entry(dict_id) {
+                Entry::Occupied(existing) if existing.get() != &dictionary => {
+                    return Err(ArrowError::JsonError(format!(
+                        "Dictionary with id {} differs between batches, which is not supported by the JSON format",
+                        dict_id
+                    )));
+                }
+                Entry::Occupied(_) => {}
+                Entry::Vacant(v) => {
+                    v.insert(dictionary);
+                }
+            }
+        }
+        DataType::Decimal128(_, _) => {
+            let array = array.as_any().downcast_ref::<Decimal128Array>().unwrap();
+            let data = (0..count).map(|i| match array.is_valid(i) {
+                true => {
+                    let value = i128::from_le_bytes(*array.value(i).raw_value());
+                    Value::String(value.to_string())
+                }
+                false => Value::String("0".to_string()),
+            });
+            json_col.data = Some(data.collect());
+        }
+        DataType::Decimal256(_, _) => {
+            let array = array.as_any().downcast_ref::<Decimal256Array>().unwrap();
+            let data = (0..count).map(|i| match array.is_valid(i) {
+                true => {
+                    let value = BigInt::from_signed_bytes_le(array.value(i).raw_value());
+                    Value::String(value.to_string())
+                }
+                false => Value::String("0".to_string()),
+            });
+            json_col.data = Some(data.collect());
+        }
+        t => {
+            return Err(ArrowError::JsonError(format!(
+                "data type {:?} not supported",
+                t
+            )))
         }
-
-        json_batch
     }
+
+    Ok(json_col)
+}
+
+/// Returns the JSON values of a primitive array, writing nulls as the default value
+fn primitive_to_json<T, F>(array: &dyn Array, f: F) -> Vec<Value>
+where
+    T: ArrowNativeType,
+    F: Fn(T) -> Value,
+{
+    // read the physical values, so that logical types such as timestamps are handled too
+    let data = array.data();
+    let values = &data.buffers()[0].typed_data::<T>()[data.offset()..];
+    values[..data.len()]
+        .iter()
+        .enumerate()
+        .map(|(i, v)| match array.is_valid(i) {
+            true => f(*v),
+            false => f(T::default()),
+        })
+        .collect()
+}
+
+/// Returns the JSON representation of `offsets`, rebased to start at zero
+///
+/// 64-bit offsets are written as strings
+fn offsets_to_json<O: OffsetSizeTrait>(offsets: &[O]) -> Vec<Value> {
+    let start = offsets[0].as_usize();
+    offsets
+        .iter()
+        .map(|o| {
+            let o = o.as_usize() - start;
+            match O::IS_LARGE {
+                true => Value::String(o.to_string()),
+                false => Value::from(o),
+            }
+        })
+        .collect()
+}
+
+fn binary_to_json<O: OffsetSizeTrait>(array: &dyn Array, json_col: &mut ArrowJsonColumn) {
+    let array = array
+        .as_any()
+        .downcast_ref::<GenericBinaryArray<O>>()
+        .unwrap();
+    let data = array
+        .iter()
+        .map(|v| Value::String(hex::encode_upper(v.unwrap_or_default())));
+    json_col.offset = Some(offsets_to_json(array.value_offsets()));
+    json_col.data = Some(data.collect());
+}
+
+fn string_to_json<O: OffsetSizeTrait>(array: &dyn Array, json_col: &mut ArrowJsonColumn) {
+    let array = array
+        .as_any()
+        .downcast_ref::<GenericStringArray<O>>()
+        .unwrap();
+    let data = array
+        .iter()
+        .map(|v| Value::String(v.unwrap_or_default().to_string()));
+    json_col.offset = Some(offsets_to_json(array.value_offsets()));
+    json_col.data = Some(data.collect());
+}
+
+fn list_to_json<O: OffsetSizeTrait>(
+    child_field: &Field,
+    array: &dyn Array,
+    json_col: &mut ArrowJsonColumn,
+    dictionaries: &mut HashMap<i64, ArrowJsonDictionaryBatch>,
+) -> Result<()> {
+    let array = array
+        .as_any()
+        .downcast_ref::<GenericListArray<O>>()
+        .unwrap();
+    let offsets = array.value_offsets();
+    let start = offsets[0].as_usize();
+    let end = offsets[array.len()].as_usize();
+    let values = array.values().slice(start, end - start);
+    json_col.offset = Some(offsets_to_json(offsets));
+    json_col.children = Some(vec![array_to_json(
+        child_field,
+        values.as_ref(),
+        dictionaries,
+    )?]);
+    Ok(())
 }
 
 #[cfg(test)]
@@ -1352,4 +1697,137 @@ mod tests {
         // test record batch
         assert_eq!(arrow_json.get_record_batches().unwrap()[0], record_batch);
     }
+
+    #[test]
+    fn test_arrow_json_roundtrip() {
+        let mut file = File::open("data/integration.json").unwrap();
+        let mut json = String::new();
+        file.read_to_string(&mut json).unwrap();
+        let arrow_json: ArrowJson = serde_json::from_str(&json).unwrap();
+        let schema = arrow_json.schema.to_arrow_schema().unwrap();
+        let batches = arrow_json.get_record_batches().unwrap();
+
+        let roundtrip = ArrowJson::try_from_batches(&schema, &batches).unwrap();
+        let json = serde_json::to_string(&roundtrip).unwrap();
+        let roundtrip: ArrowJson = serde_json::from_str(&json).unwrap();
+        assert!(roundtrip.schema.equals_schema(&schema));
+        assert_eq!(roundtrip.get_record_batches().unwrap(), batches);
+    }
+
+    #[test]
+    fn test_arrow_json_roundtrip_nested() {
+        let dictionary: Int8DictionaryArray = vec![Some("a"), None, Some("b"), Some("a")]
+            .into_iter()
+            .collect();
+
+        let mut map = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        map.keys().append_value("x");
+        map.values().append_value(1);
+        map.append(true).unwrap();
+        map.append(false).unwrap();
+        map.keys().append_value("y");
+        map.values().append_null();
+        map.keys().append_value("z");
+        map.values().append_value(3);
+        map.append(true).unwrap();
+        map.append(true).unwrap();
+        let map = map.finish();
+
+        let union = UnionArray::try_new(
+            &[0, 1],
+            Buffer::from_slice_ref(&[0_i8, 1, 0, 1]),
+            Some(Buffer::from_slice_ref(&[0_i32, 0, 1, 1])),
+            vec![
+                (
+                    Field::new("a", DataType::Int32, true),
+                    Arc::new(Int32Array::from(vec![Some(1), None])) as ArrayRef,
+                ),
+                (
+                    Field::new("b", DataType::Utf8, true),
+                    Arc::new(StringArray::from(vec!["foo", "bar"])),
+                ),
+            ],
+        )
+        .unwrap();
+
+        let decimal128 = [Some(123_i128), None, Some(-456), Some(0)]
+            .into_iter()
+            .collect::<Decimal128Array>()
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let decimal256 =
+            compute::cast(&decimal128.slice(0, 4), &DataType::Decimal256(40, 2)).unwrap();
+
+        let day_time =
+            IntervalDayTimeArray::from(vec![Some(-1_i64 << 32 | 5), None, Some(7), None]);
+        let month_day_nano = IntervalMonthDayNanoArray::from(vec![
+            Some(-3_i128 << 64 | 2 << 32 | 1),
+            None,
+            Some(i128::MAX),
+            Some(0),
+        ]);
+        let large_list = LargeListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(0), Some(1)]),
+            Some(vec![]),
+            None,
+            Some(vec![Some(2), None]),
+            Some(vec![Some(i64::MAX)]),
+        ])
+        .slice(1, 4);
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(dictionary),
+            Arc::new(map),
+            Arc::new(union),
+            Arc::new(decimal128),
+            decimal256,
+            Arc::new(day_time),
+            Arc::new(month_day_nano),
+            large_list,
+        ];
+        let fields = columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| match c.data_type() {
+                DataType::Dictionary(_, _) => Field::new_dict(
+                    &format!("c{}", i),
+                    c.data_type().clone(),
+                    true,
+                    0,
+                    false,
+                ),
+                DataType::Union(_, _, _) => {
+                    Field::new(&format!("c{}", i), c.data_type().clone(), false)
+                }
+                _ => Field::new(&format!("c{}", i), c.data_type().clone(), true),
+            })
+            .collect();
+        let schema = Arc::new(Schema::new(fields));
+        let batch = RecordBatch::try_new(schema.clone(), columns).unwrap();
+        let batches = vec![batch.clone(), batch.slice(1, 2)];
+
+        let arrow_json = ArrowJson::try_from_batches(&schema, &batches).unwrap();
+        assert_eq!(arrow_json.dictionaries.as_ref().unwrap().len(), 1);
+
+        let json = serde_json::to_string(&arrow_json).unwrap();
+        let arrow_json: ArrowJson = serde_json::from_str(&json).unwrap();
+        assert!(arrow_json.schema.equals_schema(&schema));
+        assert_eq!(arrow_json.get_record_batches().unwrap(), batches);
+
+        // the JSON format cannot represent replacement dictionaries
+        let other: Int8DictionaryArray = vec!["c"].into_iter().collect();
+        let other = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![schema.field(0).clone()])),
+            vec![Arc::new(other)],
+        )
+        .unwrap();
+        let batches = vec![batch.project(&[0]).unwrap(), other];
+        let err =
+            ArrowJson::try_from_batches(&batches[1].schema(), &batches).unwrap_err();
+        assert!(
+            err.to_string().contains("differs between batches"),
+            "{}",
+            err
+        );
+    }
 }
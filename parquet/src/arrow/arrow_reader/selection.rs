@@ -16,7 +16,7 @@
 // under the License.
 
 use arrow::array::{Array, BooleanArray};
-use arrow::compute::SlicesIterator;
+use arrow::compute::{Selection, SlicesIterator};
 use std::cmp::Ordering;
 use std::collections::VecDeque;
 use std::ops::Range;
@@ -307,6 +307,27 @@ impl From<RowSelection> for VecDeque<RowSelector> {
     }
 }
 
+impl From<&Selection> for RowSelection {
+    fn from(selection: &Selection) -> Self {
+        Self::from_consecutive_ranges(selection.iter_ranges(), selection.len())
+    }
+}
+
+impl From<&RowSelection> for Selection {
+    fn from(selection: &RowSelection) -> Self {
+        let mut ranges = vec![];
+        let mut offset = 0;
+        for selector in &selection.selectors {
+            if !selector.skip && selector.row_count != 0 {
+                ranges.push(offset..offset + selector.row_count);
+            }
+            offset += selector.row_count;
+        }
+        // The ranges are sorted, non-overlapping and within `offset` rows
+        Selection::try_from_ranges(ranges, offset).unwrap()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -359,6 +380,26 @@ mod tests {
         assert_eq!(selection.selectors, vec![RowSelector::skip(4)]);
     }
 
+    #[test]
+    fn test_selection_conversion() {
+        let filter = BooleanArray::from(vec![false, true, true, false, false, true]);
+        let selection = Selection::from_filter(&filter);
+        let row_selection = RowSelection::from(&selection);
+        assert_eq!(
+            row_selection.selectors,
+            vec![
+                RowSelector::skip(1),
+                RowSelector::select(2),
+                RowSelector::skip(2),
+                RowSelector::select(1)
+            ]
+        );
+
+        let round_trip = Selection::from(&row_selection);
+        assert_eq!(round_trip.len(), 6);
+        assert_eq!(round_trip.to_filter(), filter);
+    }
+
     #[test]
     fn test_split_off() {
         let mut selection = RowSelection::from(vec![
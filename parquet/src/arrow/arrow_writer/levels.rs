@@ -1355,16 +1355,16 @@ mod tests {
         let list_field = Field::new("col", list_type, true);
 
         let expected = vec![
-            r#"+-------------------------------------+"#,
-            r#"| col                                 |"#,
-            r#"+-------------------------------------+"#,
-            r#"|                                     |"#,
-            r#"|                                     |"#,
-            r#"| []                                  |"#,
-            r#"| [{"list": [3, ], "integers": null}] |"#,
-            r#"| [, {"list": null, "integers": 5}]   |"#,
-            r#"| []                                  |"#,
-            r#"+-------------------------------------+"#,
+            "+-------------------------------------+",
+            "| col                                 |",
+            "+-------------------------------------+",
+            "|                                     |",
+            "|                                     |",
+            "| []                                  |",
+            "| [{list: [3, null], integers: null}] |",
+            "| [null, {list: null, integers: 5}]   |",
+            "| []                                  |",
+            "+-------------------------------------+",
         ]
         .join("\n");
 